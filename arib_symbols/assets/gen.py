@@ -59,8 +59,26 @@ def read_ucm(inpath):
         return sorted(al, key=lambda x: x[0])
 
 
-def gen_symbol_table(inpath, outf):
+def read_supplement(inpath):
+    with open(inpath) as inf:
+        al = []
+        for l in inf:
+            if l.startswith("#"):
+                continue
+
+            kt, text = l.split()
+            k, t = (int(x) for x in kt.split("/"))
+            assert(90 <= k <= 94)
+            assert(1 <= t <= 94)
+            al.append(((k, t), text))
+
+        return al
+
+
+def gen_symbol_table(inpath, supplement, outf):
     al = read_ucm(inpath)
+    al += [(kt, text) for kt, text in supplement if len(text) == 1]
+    al.sort(key=lambda x: x[0])
 
     print("const SYMBOL_TABLE: &[char] = &[", file=outf)
 
@@ -96,9 +114,23 @@ def gen_kanji_table(inpath, outf):
     print("];\n", file=outf)
 
 
+def gen_sequence_table(supplement, outf):
+    print("const SEQUENCE_TABLE: &[(u16, &str)] = &[", file=outf)
+
+    for (k, t), text in supplement:
+        if len(text) == 1:
+            continue
+        cp = ((k + 0x20) << 8) | (t + 0x20)
+        print(rust_indent("(0x{:04x}, \"{}\"),".format(cp, text)), file=outf)
+
+    print("];\n", file=outf)
+
+
 def gen(inpaths, outf):
-    gen_symbol_table(inpaths[0], outf)
+    supplement = read_supplement(inpaths[2])
+    gen_symbol_table(inpaths[0], supplement, outf)
     gen_kanji_table(inpaths[1], outf)
+    gen_sequence_table(supplement, outf)
 
     func_str = """\
 pub fn code_point_to_char(cp: u16) -> Option<char> {
@@ -109,7 +141,7 @@ pub fn code_point_to_char(cp: u16) -> Option<char> {
         0x7a..=0x7e => (SYMBOL_TABLE, 0x7a),
         _ => return None,
     };
-    if col < 0x21 || col > 0x7e {
+    if !(0x21..=0x7e).contains(&col) {
         return None;
     }
     let pos = usize::from((row - base) * 94 + (col - 0x21));
@@ -120,15 +152,22 @@ pub fn code_point_to_char(cp: u16) -> Option<char> {
     if c as usize == 0 {
         return None;
     }
-    return Some(c);
+    Some(c)
+}
+
+/// Returns symbols which are sequences of characters, such as the
+/// abbreviations of musical instruments.
+pub fn code_point_to_str(cp: u16) -> Option<&'static str> {
+    let i = SEQUENCE_TABLE.binary_search_by_key(&cp, |(c, _)| *c).ok()?;
+    Some(SEQUENCE_TABLE[i].1)
 }"""
     print(func_str, file=outf)
 
 
 def main():
     inpaths = sys.argv[1:]
-    if len(inpaths) != 2:
-        sys.exit("give arib.ucm kanji.txt")
+    if len(inpaths) != 3:
+        sys.exit("give arib.ucm kanji.txt supplement.txt")
 
     gen(inpaths, sys.stdout)
 
@@ -212,12 +212,12 @@ const SYMBOL_TABLE: &[char] = &[
     '\u{248e}',
     '\u{248f}',
     '\u{2490}',
-    '\u{0000}',
-    '\u{0000}',
-    '\u{0000}',
-    '\u{0000}',
-    '\u{0000}',
-    '\u{0000}',
+    '\u{6c0f}',
+    '\u{526f}',
+    '\u{5143}',
+    '\u{6545}',
+    '\u{524d}',
+    '\u{65b0}',
     '\u{1f101}',
     '\u{1f102}',
     '\u{1f103}',
@@ -610,6 +610,39 @@ const KANJI_TABLE: &[char] = &[
     '\u{9eb5}',
 ];
 
+const SEQUENCE_TABLE: &[(u16, &str)] = &[
+    (0x7c58, "(vn)"),
+    (0x7c59, "(ob)"),
+    (0x7c5a, "(cb)"),
+    (0x7c5b, "(ce"),
+    (0x7c5c, "mb)"),
+    (0x7c5d, "(hp)"),
+    (0x7c5e, "(br)"),
+    (0x7c5f, "(p)"),
+    (0x7c60, "(s)"),
+    (0x7c61, "(ms)"),
+    (0x7c62, "(t)"),
+    (0x7c63, "(bs)"),
+    (0x7c64, "(b)"),
+    (0x7c65, "(tb)"),
+    (0x7c66, "(tp)"),
+    (0x7c67, "(ds)"),
+    (0x7c68, "(ag)"),
+    (0x7c69, "(eg)"),
+    (0x7c6a, "(vo)"),
+    (0x7c6b, "(fl)"),
+    (0x7c6c, "(ke"),
+    (0x7c6d, "y)"),
+    (0x7c6e, "(sa"),
+    (0x7c6f, "x)"),
+    (0x7c70, "(sy"),
+    (0x7c71, "n)"),
+    (0x7c72, "(or"),
+    (0x7c73, "g)"),
+    (0x7c74, "(pe"),
+    (0x7c75, "r)"),
+];
+
 pub fn code_point_to_char(cp: u16) -> Option<char> {
     let row = cp >> 8;
     let col = cp & 0xff;
@@ -618,7 +651,7 @@ pub fn code_point_to_char(cp: u16) -> Option<char> {
         0x7a..=0x7e => (SYMBOL_TABLE, 0x7a),
         _ => return None,
     };
-    if col < 0x21 || col > 0x7e {
+    if !(0x21..=0x7e).contains(&col) {
         return None;
     }
     let pos = usize::from((row - base) * 94 + (col - 0x21));
@@ -629,5 +662,12 @@ pub fn code_point_to_char(cp: u16) -> Option<char> {
     if c as usize == 0 {
         return None;
     }
-    return Some(c);
+    Some(c)
+}
+
+/// Returns symbols which are sequences of characters, such as the
+/// abbreviations of musical instruments.
+pub fn code_point_to_str(cp: u16) -> Option<&'static str> {
+    let i = SEQUENCE_TABLE.binary_search_by_key(&cp, |(c, _)| *c).ok()?;
+    Some(SEQUENCE_TABLE[i].1)
 }
@@ -1,5 +1,3 @@
-use arib_symbols;
-
 #[test]
 fn test() {
     assert_eq!(
@@ -23,3 +21,38 @@ fn test() {
         '\u{325b}'
     );
 }
+
+#[test]
+fn test_glyph_variants() {
+    assert_eq!(
+        arib_symbols::code_point_to_char(0x7c3a).unwrap(),
+        '\u{6c0f}'
+    );
+    assert_eq!(
+        arib_symbols::code_point_to_char(0x7c3f).unwrap(),
+        '\u{65b0}'
+    );
+}
+
+#[test]
+fn test_sequences() {
+    assert_eq!(arib_symbols::code_point_to_char(0x7c58), None);
+    assert_eq!(arib_symbols::code_point_to_str(0x7c58).unwrap(), "(vn)");
+    assert_eq!(
+        [
+            arib_symbols::code_point_to_str(0x7c5b).unwrap(),
+            arib_symbols::code_point_to_str(0x7c5c).unwrap(),
+        ]
+        .concat(),
+        "(cemb)"
+    );
+    assert_eq!(arib_symbols::code_point_to_str(0x7c75).unwrap(), "r)");
+    assert_eq!(arib_symbols::code_point_to_str(0x7c76), None);
+}
+
+#[test]
+fn test_unassigned() {
+    assert_eq!(arib_symbols::code_point_to_char(0x7a27), None);
+    assert_eq!(arib_symbols::code_point_to_str(0x7a27), None);
+    assert_eq!(arib_symbols::code_point_to_char(0x7721), None);
+}
@@ -18,6 +18,7 @@ def gen_src(inf, outf):
     block_start_rows = []
     block_end_rows = []
     multi_chars = []
+    reverse = []
 
     print("const TABLE: &[char] = &[", file=outf)
 
@@ -40,6 +41,7 @@ def gen_src(inf, outf):
 
         prev_row = row
         prev_col = col
+        reverse.append((tuple(parts[1:]), code_point))
 
         if len(parts) == 2:
             print(rust_indent(rust_unicode_literal(parts[1]) + ","), file=outf)
@@ -59,6 +61,21 @@ def gen_src(inf, outf):
         print(rust_indent("[" + ", ".join(rust_unicode_literal(c) for c in mc) + "],"), file=outf)
     print("];\n", file=outf)
 
+    # sorted by characters to be searched.
+    reverse.sort()
+    print("const CODE_POINT_TABLE: &[(char, u32)] = &[", file=outf)
+    for cs, cp in reverse:
+        if len(cs) == 1:
+            print(rust_indent("({}, 0x{:x}),".format(rust_unicode_literal(cs[0]), cp)), file=outf)
+    print("];\n", file=outf)
+
+    print("const MULTI_CHAR_CODE_POINT_TABLE: &[([char; 2], u32)] = &[", file=outf)
+    for cs, cp in reverse:
+        if len(cs) == 2:
+            chars = ", ".join(rust_unicode_literal(c) for c in cs)
+            print(rust_indent("([{}], 0x{:x}),".format(chars, cp)), file=outf)
+    print("];\n", file=outf)
+
     func_str = """\
 pub fn code_point_to_chars(cp: u32) -> Option<&'static [char]> {
     let row = cp >> 8;
@@ -82,11 +99,14 @@ pub fn code_point_to_chars(cp: u32) -> Option<&'static [char]> {
         _ => return None,
     };
     let col = cp & 0xff;
-    if col < 0x21 || col > 0x7e {
+    if !(0x21..=0x7e).contains(&col) {
         return None;
     }
     let col = col - 0x21;
     let offset = (row * 94 + col) as usize;
+    if offset >= TABLE.len() {
+        return None;
+    }
     let cp = &TABLE[offset..offset + 1];
     let c = cp[0] as usize;
     if c == 0 {
@@ -95,7 +115,25 @@ pub fn code_point_to_chars(cp: u32) -> Option<&'static [char]> {
     if c >= 0x80 {
         return Some(cp);
     }
-    return Some(&MULTI_CHAR_TABLE[c - 1]);
+    Some(&MULTI_CHAR_TABLE[c - 1])
+}
+
+/// Returns the code point of a character or a pair of characters, the
+/// inverse of code_point_to_chars.
+pub fn chars_to_code_point(chars: &[char]) -> Option<u32> {
+    match chars {
+        [c] => {
+            let i = CODE_POINT_TABLE.binary_search_by_key(c, |(c, _)| *c).ok()?;
+            Some(CODE_POINT_TABLE[i].1)
+        }
+        [c1, c2] => {
+            let i = MULTI_CHAR_CODE_POINT_TABLE
+                .binary_search_by_key(&[*c1, *c2], |(c, _)| *c)
+                .ok()?;
+            Some(MULTI_CHAR_CODE_POINT_TABLE[i].1)
+        }
+        _ => None,
+    }
 }"""
 
     print(func_str, file=outf)
@@ -11301,6 +11301,11245 @@ const MULTI_CHAR_TABLE: &[[char; 2]] = &[
     ['\u{02e5}', '\u{02e9}'],
 ];
 
+const CODE_POINT_TABLE: &[(char, u32)] = &[
+    ('\u{00a0}', 0x12922),
+    ('\u{00a1}', 0x12923),
+    ('\u{00a2}', 0x12171),
+    ('\u{00a3}', 0x12172),
+    ('\u{00a4}', 0x12924),
+    ('\u{00a6}', 0x12925),
+    ('\u{00a7}', 0x12178),
+    ('\u{00a8}', 0x1212f),
+    ('\u{00a9}', 0x12926),
+    ('\u{00aa}', 0x12927),
+    ('\u{00ab}', 0x12928),
+    ('\u{00ac}', 0x1224c),
+    ('\u{00ad}', 0x12929),
+    ('\u{00ae}', 0x1292a),
+    ('\u{00af}', 0x1292b),
+    ('\u{00b0}', 0x1216b),
+    ('\u{00b1}', 0x1215e),
+    ('\u{00b2}', 0x1292c),
+    ('\u{00b3}', 0x1292d),
+    ('\u{00b4}', 0x1212d),
+    ('\u{00b6}', 0x12279),
+    ('\u{00b7}', 0x1292e),
+    ('\u{00b8}', 0x1292f),
+    ('\u{00b9}', 0x12930),
+    ('\u{00ba}', 0x12931),
+    ('\u{00bb}', 0x12932),
+    ('\u{00bc}', 0x12933),
+    ('\u{00bd}', 0x12934),
+    ('\u{00be}', 0x12935),
+    ('\u{00bf}', 0x12936),
+    ('\u{00c0}', 0x12937),
+    ('\u{00c1}', 0x12938),
+    ('\u{00c2}', 0x12939),
+    ('\u{00c3}', 0x1293a),
+    ('\u{00c4}', 0x1293b),
+    ('\u{00c5}', 0x1293c),
+    ('\u{00c6}', 0x1293d),
+    ('\u{00c7}', 0x1293e),
+    ('\u{00c8}', 0x1293f),
+    ('\u{00c9}', 0x12940),
+    ('\u{00ca}', 0x12941),
+    ('\u{00cb}', 0x12942),
+    ('\u{00cc}', 0x12943),
+    ('\u{00cd}', 0x12944),
+    ('\u{00ce}', 0x12945),
+    ('\u{00cf}', 0x12946),
+    ('\u{00d0}', 0x12947),
+    ('\u{00d1}', 0x12948),
+    ('\u{00d2}', 0x12949),
+    ('\u{00d3}', 0x1294a),
+    ('\u{00d4}', 0x1294b),
+    ('\u{00d5}', 0x1294c),
+    ('\u{00d6}', 0x1294d),
+    ('\u{00d7}', 0x1215f),
+    ('\u{00d8}', 0x1294e),
+    ('\u{00d9}', 0x1294f),
+    ('\u{00da}', 0x12950),
+    ('\u{00db}', 0x12951),
+    ('\u{00dc}', 0x12952),
+    ('\u{00dd}', 0x12953),
+    ('\u{00de}', 0x12954),
+    ('\u{00df}', 0x12955),
+    ('\u{00e0}', 0x12956),
+    ('\u{00e1}', 0x12957),
+    ('\u{00e2}', 0x12958),
+    ('\u{00e3}', 0x12959),
+    ('\u{00e4}', 0x1295a),
+    ('\u{00e5}', 0x1295b),
+    ('\u{00e6}', 0x1295c),
+    ('\u{00e7}', 0x1295d),
+    ('\u{00e8}', 0x1295e),
+    ('\u{00e9}', 0x1295f),
+    ('\u{00ea}', 0x12960),
+    ('\u{00eb}', 0x12961),
+    ('\u{00ec}', 0x12962),
+    ('\u{00ed}', 0x12963),
+    ('\u{00ee}', 0x12964),
+    ('\u{00ef}', 0x12965),
+    ('\u{00f0}', 0x12966),
+    ('\u{00f1}', 0x12967),
+    ('\u{00f2}', 0x12968),
+    ('\u{00f3}', 0x12969),
+    ('\u{00f4}', 0x1296a),
+    ('\u{00f5}', 0x1296b),
+    ('\u{00f6}', 0x1296c),
+    ('\u{00f7}', 0x12160),
+    ('\u{00f8}', 0x1296d),
+    ('\u{00f9}', 0x1296e),
+    ('\u{00fa}', 0x1296f),
+    ('\u{00fb}', 0x12970),
+    ('\u{00fc}', 0x12971),
+    ('\u{00fd}', 0x12972),
+    ('\u{00fe}', 0x12973),
+    ('\u{00ff}', 0x12974),
+    ('\u{0100}', 0x12975),
+    ('\u{0101}', 0x1297a),
+    ('\u{0102}', 0x12a3a),
+    ('\u{0103}', 0x12a49),
+    ('\u{0104}', 0x12a21),
+    ('\u{0105}', 0x12a2c),
+    ('\u{0106}', 0x12a3c),
+    ('\u{0107}', 0x12a4b),
+    ('\u{0108}', 0x12a59),
+    ('\u{0109}', 0x12a5f),
+    ('\u{010c}', 0x12a3d),
+    ('\u{010d}', 0x12a4c),
+    ('\u{010e}', 0x12a40),
+    ('\u{010f}', 0x12a4f),
+    ('\u{0111}', 0x12a50),
+    ('\u{0112}', 0x12978),
+    ('\u{0113}', 0x1297d),
+    ('\u{0118}', 0x12a3e),
+    ('\u{0119}', 0x12a4d),
+    ('\u{011a}', 0x12a3f),
+    ('\u{011b}', 0x12a4e),
+    ('\u{011c}', 0x12a5a),
+    ('\u{011d}', 0x12a60),
+    ('\u{0124}', 0x12a5b),
+    ('\u{0125}', 0x12a61),
+    ('\u{0127}', 0x12a7d),
+    ('\u{012a}', 0x12976),
+    ('\u{012b}', 0x1297b),
+    ('\u{0134}', 0x12a5c),
+    ('\u{0135}', 0x12a62),
+    ('\u{0139}', 0x12a3b),
+    ('\u{013a}', 0x12a4a),
+    ('\u{013d}', 0x12a24),
+    ('\u{013e}', 0x12a2f),
+    ('\u{0141}', 0x12a23),
+    ('\u{0142}', 0x12a2e),
+    ('\u{0143}', 0x12a41),
+    ('\u{0144}', 0x12a51),
+    ('\u{0147}', 0x12a42),
+    ('\u{0148}', 0x12a52),
+    ('\u{014b}', 0x12a7a),
+    ('\u{014c}', 0x12979),
+    ('\u{014d}', 0x1297e),
+    ('\u{0150}', 0x12a43),
+    ('\u{0151}', 0x12a53),
+    ('\u{0152}', 0x12b2b),
+    ('\u{0153}', 0x12b2a),
+    ('\u{0154}', 0x12a39),
+    ('\u{0155}', 0x12a48),
+    ('\u{0158}', 0x12a44),
+    ('\u{0159}', 0x12a54),
+    ('\u{015a}', 0x12a25),
+    ('\u{015b}', 0x12a30),
+    ('\u{015c}', 0x12a5d),
+    ('\u{015d}', 0x12a63),
+    ('\u{015e}', 0x12a27),
+    ('\u{015f}', 0x12a33),
+    ('\u{0160}', 0x12a26),
+    ('\u{0161}', 0x12a32),
+    ('\u{0162}', 0x12a47),
+    ('\u{0163}', 0x12a57),
+    ('\u{0164}', 0x12a28),
+    ('\u{0165}', 0x12a34),
+    ('\u{016a}', 0x12977),
+    ('\u{016b}', 0x1297c),
+    ('\u{016c}', 0x12a5e),
+    ('\u{016d}', 0x12a64),
+    ('\u{016e}', 0x12a45),
+    ('\u{016f}', 0x12a55),
+    ('\u{0170}', 0x12a46),
+    ('\u{0171}', 0x12a56),
+    ('\u{0179}', 0x12a29),
+    ('\u{017a}', 0x12a35),
+    ('\u{017b}', 0x12a2b),
+    ('\u{017c}', 0x12a38),
+    ('\u{017d}', 0x12a2a),
+    ('\u{017e}', 0x12a37),
+    ('\u{0193}', 0x12b29),
+    ('\u{01c2}', 0x12b24),
+    ('\u{01cd}', 0x1286f),
+    ('\u{01ce}', 0x12870),
+    ('\u{01d0}', 0x12871),
+    ('\u{01d1}', 0x12876),
+    ('\u{01d2}', 0x12877),
+    ('\u{01d4}', 0x12878),
+    ('\u{01d6}', 0x12879),
+    ('\u{01d8}', 0x1287a),
+    ('\u{01da}', 0x1287b),
+    ('\u{01dc}', 0x1287c),
+    ('\u{01f8}', 0x12874),
+    ('\u{01f9}', 0x12875),
+    ('\u{01fd}', 0x12b45),
+    ('\u{0250}', 0x12b33),
+    ('\u{0251}', 0x12b39),
+    ('\u{0252}', 0x12b3a),
+    ('\u{0253}', 0x12b25),
+    ('\u{0254}', 0x12b38),
+    ('\u{0255}', 0x12b3f),
+    ('\u{0256}', 0x12a6e),
+    ('\u{0257}', 0x12b26),
+    ('\u{0258}', 0x12b2e),
+    ('\u{0259}', 0x12b30),
+    ('\u{025a}', 0x12b43),
+    ('\u{025c}', 0x12b31),
+    ('\u{025e}', 0x12b32),
+    ('\u{025f}', 0x12a75),
+    ('\u{0260}', 0x12b28),
+    ('\u{0261}', 0x12a79),
+    ('\u{0264}', 0x12b36),
+    ('\u{0265}', 0x12b3c),
+    ('\u{0266}', 0x12b22),
+    ('\u{0267}', 0x12b42),
+    ('\u{0268}', 0x12b2c),
+    ('\u{026c}', 0x12a6a),
+    ('\u{026d}', 0x12a74),
+    ('\u{026e}', 0x12a6b),
+    ('\u{026f}', 0x12b34),
+    ('\u{0270}', 0x12a7b),
+    ('\u{0271}', 0x12a65),
+    ('\u{0272}', 0x12a76),
+    ('\u{0273}', 0x12a6f),
+    ('\u{0275}', 0x12b2f),
+    ('\u{0279}', 0x12a6c),
+    ('\u{027a}', 0x12b41),
+    ('\u{027b}', 0x12a73),
+    ('\u{027d}', 0x12a70),
+    ('\u{027e}', 0x12a67),
+    ('\u{0281}', 0x12a7c),
+    ('\u{0282}', 0x12a71),
+    ('\u{0283}', 0x12a68),
+    ('\u{0284}', 0x12b27),
+    ('\u{0288}', 0x12a6d),
+    ('\u{0289}', 0x12b2d),
+    ('\u{028a}', 0x12b35),
+    ('\u{028b}', 0x12a66),
+    ('\u{028c}', 0x12b37),
+    ('\u{028d}', 0x12b3b),
+    ('\u{028e}', 0x12a78),
+    ('\u{0290}', 0x12a72),
+    ('\u{0291}', 0x12b40),
+    ('\u{0292}', 0x12a69),
+    ('\u{0294}', 0x12b21),
+    ('\u{0295}', 0x12a7e),
+    ('\u{0298}', 0x12b23),
+    ('\u{029d}', 0x12a77),
+    ('\u{02a1}', 0x12b3e),
+    ('\u{02a2}', 0x12b3d),
+    ('\u{02c7}', 0x12a31),
+    ('\u{02c8}', 0x12b53),
+    ('\u{02cc}', 0x12b54),
+    ('\u{02d0}', 0x12b55),
+    ('\u{02d1}', 0x12b56),
+    ('\u{02d8}', 0x12a22),
+    ('\u{02d9}', 0x12a58),
+    ('\u{02db}', 0x12a2d),
+    ('\u{02dd}', 0x12a36),
+    ('\u{02de}', 0x12b71),
+    ('\u{02e5}', 0x12b60),
+    ('\u{02e6}', 0x12b61),
+    ('\u{02e7}', 0x12b62),
+    ('\u{02e8}', 0x12b63),
+    ('\u{02e9}', 0x12b64),
+    ('\u{0300}', 0x12b5c),
+    ('\u{0301}', 0x12b5a),
+    ('\u{0302}', 0x12b5f),
+    ('\u{0303}', 0x12b7d),
+    ('\u{0304}', 0x12b5b),
+    ('\u{0306}', 0x12b57),
+    ('\u{0308}', 0x12b6d),
+    ('\u{030b}', 0x12b59),
+    ('\u{030c}', 0x12b5e),
+    ('\u{030f}', 0x12b5d),
+    ('\u{0318}', 0x12b78),
+    ('\u{0319}', 0x12b79),
+    ('\u{031a}', 0x12b7e),
+    ('\u{031c}', 0x12b6a),
+    ('\u{031d}', 0x12b76),
+    ('\u{031e}', 0x12b77),
+    ('\u{031f}', 0x12b6b),
+    ('\u{0320}', 0x12b6c),
+    ('\u{0324}', 0x12b72),
+    ('\u{0325}', 0x12b67),
+    ('\u{0329}', 0x12b6f),
+    ('\u{032a}', 0x12b7a),
+    ('\u{032c}', 0x12b68),
+    ('\u{032f}', 0x12b70),
+    ('\u{0330}', 0x12b73),
+    ('\u{0334}', 0x12b75),
+    ('\u{0339}', 0x12b69),
+    ('\u{033a}', 0x12b7b),
+    ('\u{033b}', 0x12b7c),
+    ('\u{033c}', 0x12b74),
+    ('\u{033d}', 0x12b6e),
+    ('\u{0361}', 0x12b52),
+    ('\u{0391}', 0x12621),
+    ('\u{0392}', 0x12622),
+    ('\u{0393}', 0x12623),
+    ('\u{0394}', 0x12624),
+    ('\u{0395}', 0x12625),
+    ('\u{0396}', 0x12626),
+    ('\u{0397}', 0x12627),
+    ('\u{0398}', 0x12628),
+    ('\u{0399}', 0x12629),
+    ('\u{039a}', 0x1262a),
+    ('\u{039b}', 0x1262b),
+    ('\u{039c}', 0x1262c),
+    ('\u{039d}', 0x1262d),
+    ('\u{039e}', 0x1262e),
+    ('\u{039f}', 0x1262f),
+    ('\u{03a0}', 0x12630),
+    ('\u{03a1}', 0x12631),
+    ('\u{03a3}', 0x12632),
+    ('\u{03a4}', 0x12633),
+    ('\u{03a5}', 0x12634),
+    ('\u{03a6}', 0x12635),
+    ('\u{03a7}', 0x12636),
+    ('\u{03a8}', 0x12637),
+    ('\u{03a9}', 0x12638),
+    ('\u{03b1}', 0x12641),
+    ('\u{03b2}', 0x12642),
+    ('\u{03b3}', 0x12643),
+    ('\u{03b4}', 0x12644),
+    ('\u{03b5}', 0x12645),
+    ('\u{03b6}', 0x12646),
+    ('\u{03b7}', 0x12647),
+    ('\u{03b8}', 0x12648),
+    ('\u{03b9}', 0x12649),
+    ('\u{03ba}', 0x1264a),
+    ('\u{03bb}', 0x1264b),
+    ('\u{03bc}', 0x1264c),
+    ('\u{03bd}', 0x1264d),
+    ('\u{03be}', 0x1264e),
+    ('\u{03bf}', 0x1264f),
+    ('\u{03c0}', 0x12650),
+    ('\u{03c1}', 0x12651),
+    ('\u{03c2}', 0x12659),
+    ('\u{03c3}', 0x12652),
+    ('\u{03c4}', 0x12653),
+    ('\u{03c5}', 0x12654),
+    ('\u{03c6}', 0x12655),
+    ('\u{03c7}', 0x12656),
+    ('\u{03c8}', 0x12657),
+    ('\u{03c9}', 0x12658),
+    ('\u{0401}', 0x12727),
+    ('\u{0410}', 0x12721),
+    ('\u{0411}', 0x12722),
+    ('\u{0412}', 0x12723),
+    ('\u{0413}', 0x12724),
+    ('\u{0414}', 0x12725),
+    ('\u{0415}', 0x12726),
+    ('\u{0416}', 0x12728),
+    ('\u{0417}', 0x12729),
+    ('\u{0418}', 0x1272a),
+    ('\u{0419}', 0x1272b),
+    ('\u{041a}', 0x1272c),
+    ('\u{041b}', 0x1272d),
+    ('\u{041c}', 0x1272e),
+    ('\u{041d}', 0x1272f),
+    ('\u{041e}', 0x12730),
+    ('\u{041f}', 0x12731),
+    ('\u{0420}', 0x12732),
+    ('\u{0421}', 0x12733),
+    ('\u{0422}', 0x12734),
+    ('\u{0423}', 0x12735),
+    ('\u{0424}', 0x12736),
+    ('\u{0425}', 0x12737),
+    ('\u{0426}', 0x12738),
+    ('\u{0427}', 0x12739),
+    ('\u{0428}', 0x1273a),
+    ('\u{0429}', 0x1273b),
+    ('\u{042a}', 0x1273c),
+    ('\u{042b}', 0x1273d),
+    ('\u{042c}', 0x1273e),
+    ('\u{042d}', 0x1273f),
+    ('\u{042e}', 0x12740),
+    ('\u{042f}', 0x12741),
+    ('\u{0430}', 0x12751),
+    ('\u{0431}', 0x12752),
+    ('\u{0432}', 0x12753),
+    ('\u{0433}', 0x12754),
+    ('\u{0434}', 0x12755),
+    ('\u{0435}', 0x12756),
+    ('\u{0436}', 0x12758),
+    ('\u{0437}', 0x12759),
+    ('\u{0438}', 0x1275a),
+    ('\u{0439}', 0x1275b),
+    ('\u{043a}', 0x1275c),
+    ('\u{043b}', 0x1275d),
+    ('\u{043c}', 0x1275e),
+    ('\u{043d}', 0x1275f),
+    ('\u{043e}', 0x12760),
+    ('\u{043f}', 0x12761),
+    ('\u{0440}', 0x12762),
+    ('\u{0441}', 0x12763),
+    ('\u{0442}', 0x12764),
+    ('\u{0443}', 0x12765),
+    ('\u{0444}', 0x12766),
+    ('\u{0445}', 0x12767),
+    ('\u{0446}', 0x12768),
+    ('\u{0447}', 0x12769),
+    ('\u{0448}', 0x1276a),
+    ('\u{0449}', 0x1276b),
+    ('\u{044a}', 0x1276c),
+    ('\u{044b}', 0x1276d),
+    ('\u{044c}', 0x1276e),
+    ('\u{044d}', 0x1276f),
+    ('\u{044e}', 0x12770),
+    ('\u{044f}', 0x12771),
+    ('\u{0451}', 0x12757),
+    ('\u{1e3e}', 0x12872),
+    ('\u{1e3f}', 0x12873),
+    ('\u{1f70}', 0x12b46),
+    ('\u{1f71}', 0x12b47),
+    ('\u{1f72}', 0x12b50),
+    ('\u{1f73}', 0x12b51),
+    ('\u{2010}', 0x1213e),
+    ('\u{2013}', 0x1237c),
+    ('\u{2014}', 0x1213d),
+    ('\u{2016}', 0x12142),
+    ('\u{2018}', 0x12146),
+    ('\u{2019}', 0x12147),
+    ('\u{201c}', 0x12148),
+    ('\u{201d}', 0x12149),
+    ('\u{2020}', 0x12277),
+    ('\u{2021}', 0x12278),
+    ('\u{2022}', 0x12340),
+    ('\u{2025}', 0x12145),
+    ('\u{2026}', 0x12144),
+    ('\u{2030}', 0x12273),
+    ('\u{2032}', 0x1216c),
+    ('\u{2033}', 0x1216d),
+    ('\u{203b}', 0x12228),
+    ('\u{203c}', 0x1286b),
+    ('\u{203f}', 0x12b58),
+    ('\u{2042}', 0x12c7e),
+    ('\u{2047}', 0x1286c),
+    ('\u{2048}', 0x1286d),
+    ('\u{2049}', 0x1286e),
+    ('\u{2051}', 0x12c7d),
+    ('\u{20ac}', 0x12921),
+    ('\u{2103}', 0x1216e),
+    ('\u{210f}', 0x1235d),
+    ('\u{2113}', 0x1235f),
+    ('\u{2116}', 0x12d62),
+    ('\u{2121}', 0x12d64),
+    ('\u{2127}', 0x12360),
+    ('\u{212b}', 0x12272),
+    ('\u{2135}', 0x1235c),
+    ('\u{2153}', 0x12778),
+    ('\u{2154}', 0x12779),
+    ('\u{2155}', 0x1277a),
+    ('\u{2160}', 0x12d35),
+    ('\u{2161}', 0x12d36),
+    ('\u{2162}', 0x12d37),
+    ('\u{2163}', 0x12d38),
+    ('\u{2164}', 0x12d39),
+    ('\u{2165}', 0x12d3a),
+    ('\u{2166}', 0x12d3b),
+    ('\u{2167}', 0x12d3c),
+    ('\u{2168}', 0x12d3d),
+    ('\u{2169}', 0x12d3e),
+    ('\u{216a}', 0x12d3f),
+    ('\u{216b}', 0x12d57),
+    ('\u{2170}', 0x12c35),
+    ('\u{2171}', 0x12c36),
+    ('\u{2172}', 0x12c37),
+    ('\u{2173}', 0x12c38),
+    ('\u{2174}', 0x12c39),
+    ('\u{2175}', 0x12c3a),
+    ('\u{2176}', 0x12c3b),
+    ('\u{2177}', 0x12c3c),
+    ('\u{2178}', 0x12c3d),
+    ('\u{2179}', 0x12c3e),
+    ('\u{217a}', 0x12c3f),
+    ('\u{217b}', 0x12c40),
+    ('\u{2190}', 0x1222b),
+    ('\u{2191}', 0x1222c),
+    ('\u{2192}', 0x1222a),
+    ('\u{2193}', 0x1222d),
+    ('\u{2194}', 0x12271),
+    ('\u{2196}', 0x12327),
+    ('\u{2197}', 0x12325),
+    ('\u{2198}', 0x12326),
+    ('\u{2199}', 0x12328),
+    ('\u{21c4}', 0x12329),
+    ('\u{21d2}', 0x1224d),
+    ('\u{21d4}', 0x1224e),
+    ('\u{21e6}', 0x1232b),
+    ('\u{21e7}', 0x1232c),
+    ('\u{21e8}', 0x1232a),
+    ('\u{21e9}', 0x1232d),
+    ('\u{2200}', 0x1224f),
+    ('\u{2202}', 0x1225f),
+    ('\u{2203}', 0x12250),
+    ('\u{2205}', 0x12247),
+    ('\u{2207}', 0x12260),
+    ('\u{2208}', 0x1223a),
+    ('\u{2209}', 0x12246),
+    ('\u{220b}', 0x1223b),
+    ('\u{2212}', 0x1215d),
+    ('\u{2213}', 0x1235b),
+    ('\u{221a}', 0x12265),
+    ('\u{221d}', 0x12267),
+    ('\u{221e}', 0x12167),
+    ('\u{221f}', 0x12d78),
+    ('\u{2220}', 0x1225c),
+    ('\u{2225}', 0x12254),
+    ('\u{2226}', 0x12255),
+    ('\u{2227}', 0x1224a),
+    ('\u{2228}', 0x1224b),
+    ('\u{2229}', 0x12241),
+    ('\u{222a}', 0x12240),
+    ('\u{222b}', 0x12269),
+    ('\u{222c}', 0x1226a),
+    ('\u{222e}', 0x12d73),
+    ('\u{2234}', 0x12168),
+    ('\u{2235}', 0x12268),
+    ('\u{223d}', 0x12266),
+    ('\u{2243}', 0x1226c),
+    ('\u{2245}', 0x1226d),
+    ('\u{2248}', 0x1226e),
+    ('\u{2252}', 0x12262),
+    ('\u{2260}', 0x12162),
+    ('\u{2261}', 0x12261),
+    ('\u{2262}', 0x1226b),
+    ('\u{2266}', 0x12165),
+    ('\u{2267}', 0x12166),
+    ('\u{226a}', 0x12263),
+    ('\u{226b}', 0x12264),
+    ('\u{2276}', 0x1226f),
+    ('\u{2277}', 0x12270),
+    ('\u{2282}', 0x1223e),
+    ('\u{2283}', 0x1223f),
+    ('\u{2284}', 0x12242),
+    ('\u{2285}', 0x12243),
+    ('\u{2286}', 0x1223c),
+    ('\u{2287}', 0x1223d),
+    ('\u{228a}', 0x12244),
+    ('\u{228b}', 0x12245),
+    ('\u{2295}', 0x12251),
+    ('\u{2296}', 0x12252),
+    ('\u{2297}', 0x12253),
+    ('\u{22a5}', 0x1225d),
+    ('\u{22bf}', 0x12d79),
+    ('\u{22da}', 0x12776),
+    ('\u{22db}', 0x12777),
+    ('\u{2305}', 0x12248),
+    ('\u{2306}', 0x12249),
+    ('\u{2312}', 0x1225e),
+    ('\u{2318}', 0x1277c),
+    ('\u{23be}', 0x12742),
+    ('\u{23bf}', 0x12743),
+    ('\u{23c0}', 0x12744),
+    ('\u{23c1}', 0x12745),
+    ('\u{23c2}', 0x12746),
+    ('\u{23c3}', 0x12747),
+    ('\u{23c4}', 0x12748),
+    ('\u{23c5}', 0x12749),
+    ('\u{23c6}', 0x1274a),
+    ('\u{23c7}', 0x1274b),
+    ('\u{23c8}', 0x1274c),
+    ('\u{23c9}', 0x1274d),
+    ('\u{23ca}', 0x1274e),
+    ('\u{23cb}', 0x1274f),
+    ('\u{23cc}', 0x12750),
+    ('\u{23ce}', 0x1277e),
+    ('\u{2423}', 0x1277d),
+    ('\u{2460}', 0x12d21),
+    ('\u{2461}', 0x12d22),
+    ('\u{2462}', 0x12d23),
+    ('\u{2463}', 0x12d24),
+    ('\u{2464}', 0x12d25),
+    ('\u{2465}', 0x12d26),
+    ('\u{2466}', 0x12d27),
+    ('\u{2467}', 0x12d28),
+    ('\u{2468}', 0x12d29),
+    ('\u{2469}', 0x12d2a),
+    ('\u{246a}', 0x12d2b),
+    ('\u{246b}', 0x12d2c),
+    ('\u{246c}', 0x12d2d),
+    ('\u{246d}', 0x12d2e),
+    ('\u{246e}', 0x12d2f),
+    ('\u{246f}', 0x12d30),
+    ('\u{2470}', 0x12d31),
+    ('\u{2471}', 0x12d32),
+    ('\u{2472}', 0x12d33),
+    ('\u{2473}', 0x12d34),
+    ('\u{24d0}', 0x12c41),
+    ('\u{24d1}', 0x12c42),
+    ('\u{24d2}', 0x12c43),
+    ('\u{24d3}', 0x12c44),
+    ('\u{24d4}', 0x12c45),
+    ('\u{24d5}', 0x12c46),
+    ('\u{24d6}', 0x12c47),
+    ('\u{24d7}', 0x12c48),
+    ('\u{24d8}', 0x12c49),
+    ('\u{24d9}', 0x12c4a),
+    ('\u{24da}', 0x12c4b),
+    ('\u{24db}', 0x12c4c),
+    ('\u{24dc}', 0x12c4d),
+    ('\u{24dd}', 0x12c4e),
+    ('\u{24de}', 0x12c4f),
+    ('\u{24df}', 0x12c50),
+    ('\u{24e0}', 0x12c51),
+    ('\u{24e1}', 0x12c52),
+    ('\u{24e2}', 0x12c53),
+    ('\u{24e3}', 0x12c54),
+    ('\u{24e4}', 0x12c55),
+    ('\u{24e5}', 0x12c56),
+    ('\u{24e6}', 0x12c57),
+    ('\u{24e7}', 0x12c58),
+    ('\u{24e8}', 0x12c59),
+    ('\u{24e9}', 0x12c5a),
+    ('\u{24eb}', 0x12c2b),
+    ('\u{24ec}', 0x12c2c),
+    ('\u{24ed}', 0x12c2d),
+    ('\u{24ee}', 0x12c2e),
+    ('\u{24ef}', 0x12c2f),
+    ('\u{24f0}', 0x12c30),
+    ('\u{24f1}', 0x12c31),
+    ('\u{24f2}', 0x12c32),
+    ('\u{24f3}', 0x12c33),
+    ('\u{24f4}', 0x12c34),
+    ('\u{24f5}', 0x1265a),
+    ('\u{24f6}', 0x1265b),
+    ('\u{24f7}', 0x1265c),
+    ('\u{24f8}', 0x1265d),
+    ('\u{24f9}', 0x1265e),
+    ('\u{24fa}', 0x1265f),
+    ('\u{24fb}', 0x12660),
+    ('\u{24fc}', 0x12661),
+    ('\u{24fd}', 0x12662),
+    ('\u{24fe}', 0x12663),
+    ('\u{2500}', 0x12821),
+    ('\u{2501}', 0x1282c),
+    ('\u{2502}', 0x12822),
+    ('\u{2503}', 0x1282d),
+    ('\u{250c}', 0x12823),
+    ('\u{250f}', 0x1282e),
+    ('\u{2510}', 0x12824),
+    ('\u{2513}', 0x1282f),
+    ('\u{2514}', 0x12826),
+    ('\u{2517}', 0x12831),
+    ('\u{2518}', 0x12825),
+    ('\u{251b}', 0x12830),
+    ('\u{251c}', 0x12827),
+    ('\u{251d}', 0x1283c),
+    ('\u{2520}', 0x12837),
+    ('\u{2523}', 0x12832),
+    ('\u{2524}', 0x12829),
+    ('\u{2525}', 0x1283e),
+    ('\u{2528}', 0x12839),
+    ('\u{252b}', 0x12834),
+    ('\u{252c}', 0x12828),
+    ('\u{252f}', 0x12838),
+    ('\u{2530}', 0x1283d),
+    ('\u{2533}', 0x12833),
+    ('\u{2534}', 0x1282a),
+    ('\u{2537}', 0x1283a),
+    ('\u{2538}', 0x1283f),
+    ('\u{253b}', 0x12835),
+    ('\u{253c}', 0x1282b),
+    ('\u{253f}', 0x1283b),
+    ('\u{2542}', 0x12840),
+    ('\u{254b}', 0x12836),
+    ('\u{25a0}', 0x12223),
+    ('\u{25a1}', 0x12222),
+    ('\u{25b1}', 0x1266d),
+    ('\u{25b2}', 0x12225),
+    ('\u{25b3}', 0x12224),
+    ('\u{25b6}', 0x12322),
+    ('\u{25b7}', 0x12321),
+    ('\u{25bc}', 0x12227),
+    ('\u{25bd}', 0x12226),
+    ('\u{25c0}', 0x12324),
+    ('\u{25c1}', 0x12323),
+    ('\u{25c6}', 0x12221),
+    ('\u{25c7}', 0x1217e),
+    ('\u{25c9}', 0x1233b),
+    ('\u{25cb}', 0x1217b),
+    ('\u{25ce}', 0x1217d),
+    ('\u{25cf}', 0x1217c),
+    ('\u{25d0}', 0x12867),
+    ('\u{25d1}', 0x12868),
+    ('\u{25d2}', 0x12869),
+    ('\u{25d3}', 0x1286a),
+    ('\u{25e6}', 0x1233f),
+    ('\u{25ef}', 0x1227e),
+    ('\u{2600}', 0x12668),
+    ('\u{2601}', 0x12669),
+    ('\u{2602}', 0x1266a),
+    ('\u{2603}', 0x1266b),
+    ('\u{2605}', 0x1217a),
+    ('\u{2606}', 0x12179),
+    ('\u{260e}', 0x12667),
+    ('\u{2616}', 0x12664),
+    ('\u{2617}', 0x12665),
+    ('\u{261e}', 0x12d7e),
+    ('\u{2640}', 0x1216a),
+    ('\u{2642}', 0x12169),
+    ('\u{2660}', 0x1263a),
+    ('\u{2661}', 0x1263d),
+    ('\u{2662}', 0x1263b),
+    ('\u{2663}', 0x12640),
+    ('\u{2664}', 0x12639),
+    ('\u{2665}', 0x1263e),
+    ('\u{2666}', 0x1263c),
+    ('\u{2667}', 0x1263f),
+    ('\u{2668}', 0x1266c),
+    ('\u{2669}', 0x1227d),
+    ('\u{266a}', 0x12276),
+    ('\u{266b}', 0x1227b),
+    ('\u{266c}', 0x1227c),
+    ('\u{266d}', 0x12275),
+    ('\u{266e}', 0x1227a),
+    ('\u{266f}', 0x12274),
+    ('\u{2713}', 0x1277b),
+    ('\u{2756}', 0x12d7d),
+    ('\u{2776}', 0x12c21),
+    ('\u{2777}', 0x12c22),
+    ('\u{2778}', 0x12c23),
+    ('\u{2779}', 0x12c24),
+    ('\u{277a}', 0x12c25),
+    ('\u{277b}', 0x12c26),
+    ('\u{277c}', 0x12c27),
+    ('\u{277d}', 0x12c28),
+    ('\u{277e}', 0x12c29),
+    ('\u{277f}', 0x12c2a),
+    ('\u{2934}', 0x1232e),
+    ('\u{2935}', 0x1232f),
+    ('\u{29bf}', 0x1233a),
+    ('\u{29fa}', 0x1237d),
+    ('\u{29fb}', 0x1237e),
+    ('\u{3000}', 0x12121),
+    ('\u{3001}', 0x12122),
+    ('\u{3002}', 0x12123),
+    ('\u{3003}', 0x12137),
+    ('\u{3005}', 0x12139),
+    ('\u{3006}', 0x1213a),
+    ('\u{3007}', 0x1213b),
+    ('\u{3008}', 0x12152),
+    ('\u{3009}', 0x12153),
+    ('\u{300a}', 0x12154),
+    ('\u{300b}', 0x12155),
+    ('\u{300c}', 0x12156),
+    ('\u{300d}', 0x12157),
+    ('\u{300e}', 0x12158),
+    ('\u{300f}', 0x12159),
+    ('\u{3010}', 0x1215a),
+    ('\u{3011}', 0x1215b),
+    ('\u{3012}', 0x12229),
+    ('\u{3013}', 0x1222e),
+    ('\u{3014}', 0x1214c),
+    ('\u{3015}', 0x1214d),
+    ('\u{3016}', 0x1225a),
+    ('\u{3017}', 0x1225b),
+    ('\u{3018}', 0x12258),
+    ('\u{3019}', 0x12259),
+    ('\u{301c}', 0x12141),
+    ('\u{301d}', 0x12d60),
+    ('\u{301f}', 0x12d61),
+    ('\u{3020}', 0x12666),
+    ('\u{3033}', 0x12233),
+    ('\u{3034}', 0x12234),
+    ('\u{3035}', 0x12235),
+    ('\u{303b}', 0x12236),
+    ('\u{303c}', 0x12237),
+    ('\u{303d}', 0x1233c),
+    ('\u{3041}', 0x12421),
+    ('\u{3042}', 0x12422),
+    ('\u{3043}', 0x12423),
+    ('\u{3044}', 0x12424),
+    ('\u{3045}', 0x12425),
+    ('\u{3046}', 0x12426),
+    ('\u{3047}', 0x12427),
+    ('\u{3048}', 0x12428),
+    ('\u{3049}', 0x12429),
+    ('\u{304a}', 0x1242a),
+    ('\u{304b}', 0x1242b),
+    ('\u{304c}', 0x1242c),
+    ('\u{304d}', 0x1242d),
+    ('\u{304e}', 0x1242e),
+    ('\u{304f}', 0x1242f),
+    ('\u{3050}', 0x12430),
+    ('\u{3051}', 0x12431),
+    ('\u{3052}', 0x12432),
+    ('\u{3053}', 0x12433),
+    ('\u{3054}', 0x12434),
+    ('\u{3055}', 0x12435),
+    ('\u{3056}', 0x12436),
+    ('\u{3057}', 0x12437),
+    ('\u{3058}', 0x12438),
+    ('\u{3059}', 0x12439),
+    ('\u{305a}', 0x1243a),
+    ('\u{305b}', 0x1243b),
+    ('\u{305c}', 0x1243c),
+    ('\u{305d}', 0x1243d),
+    ('\u{305e}', 0x1243e),
+    ('\u{305f}', 0x1243f),
+    ('\u{3060}', 0x12440),
+    ('\u{3061}', 0x12441),
+    ('\u{3062}', 0x12442),
+    ('\u{3063}', 0x12443),
+    ('\u{3064}', 0x12444),
+    ('\u{3065}', 0x12445),
+    ('\u{3066}', 0x12446),
+    ('\u{3067}', 0x12447),
+    ('\u{3068}', 0x12448),
+    ('\u{3069}', 0x12449),
+    ('\u{306a}', 0x1244a),
+    ('\u{306b}', 0x1244b),
+    ('\u{306c}', 0x1244c),
+    ('\u{306d}', 0x1244d),
+    ('\u{306e}', 0x1244e),
+    ('\u{306f}', 0x1244f),
+    ('\u{3070}', 0x12450),
+    ('\u{3071}', 0x12451),
+    ('\u{3072}', 0x12452),
+    ('\u{3073}', 0x12453),
+    ('\u{3074}', 0x12454),
+    ('\u{3075}', 0x12455),
+    ('\u{3076}', 0x12456),
+    ('\u{3077}', 0x12457),
+    ('\u{3078}', 0x12458),
+    ('\u{3079}', 0x12459),
+    ('\u{307a}', 0x1245a),
+    ('\u{307b}', 0x1245b),
+    ('\u{307c}', 0x1245c),
+    ('\u{307d}', 0x1245d),
+    ('\u{307e}', 0x1245e),
+    ('\u{307f}', 0x1245f),
+    ('\u{3080}', 0x12460),
+    ('\u{3081}', 0x12461),
+    ('\u{3082}', 0x12462),
+    ('\u{3083}', 0x12463),
+    ('\u{3084}', 0x12464),
+    ('\u{3085}', 0x12465),
+    ('\u{3086}', 0x12466),
+    ('\u{3087}', 0x12467),
+    ('\u{3088}', 0x12468),
+    ('\u{3089}', 0x12469),
+    ('\u{308a}', 0x1246a),
+    ('\u{308b}', 0x1246b),
+    ('\u{308c}', 0x1246c),
+    ('\u{308d}', 0x1246d),
+    ('\u{308e}', 0x1246e),
+    ('\u{308f}', 0x1246f),
+    ('\u{3090}', 0x12470),
+    ('\u{3091}', 0x12471),
+    ('\u{3092}', 0x12472),
+    ('\u{3093}', 0x12473),
+    ('\u{3094}', 0x12474),
+    ('\u{3095}', 0x12475),
+    ('\u{3096}', 0x12476),
+    ('\u{309b}', 0x1212b),
+    ('\u{309c}', 0x1212c),
+    ('\u{309d}', 0x12135),
+    ('\u{309e}', 0x12136),
+    ('\u{309f}', 0x12239),
+    ('\u{30a0}', 0x1237b),
+    ('\u{30a1}', 0x12521),
+    ('\u{30a2}', 0x12522),
+    ('\u{30a3}', 0x12523),
+    ('\u{30a4}', 0x12524),
+    ('\u{30a5}', 0x12525),
+    ('\u{30a6}', 0x12526),
+    ('\u{30a7}', 0x12527),
+    ('\u{30a8}', 0x12528),
+    ('\u{30a9}', 0x12529),
+    ('\u{30aa}', 0x1252a),
+    ('\u{30ab}', 0x1252b),
+    ('\u{30ac}', 0x1252c),
+    ('\u{30ad}', 0x1252d),
+    ('\u{30ae}', 0x1252e),
+    ('\u{30af}', 0x1252f),
+    ('\u{30b0}', 0x12530),
+    ('\u{30b1}', 0x12531),
+    ('\u{30b2}', 0x12532),
+    ('\u{30b3}', 0x12533),
+    ('\u{30b4}', 0x12534),
+    ('\u{30b5}', 0x12535),
+    ('\u{30b6}', 0x12536),
+    ('\u{30b7}', 0x12537),
+    ('\u{30b8}', 0x12538),
+    ('\u{30b9}', 0x12539),
+    ('\u{30ba}', 0x1253a),
+    ('\u{30bb}', 0x1253b),
+    ('\u{30bc}', 0x1253c),
+    ('\u{30bd}', 0x1253d),
+    ('\u{30be}', 0x1253e),
+    ('\u{30bf}', 0x1253f),
+    ('\u{30c0}', 0x12540),
+    ('\u{30c1}', 0x12541),
+    ('\u{30c2}', 0x12542),
+    ('\u{30c3}', 0x12543),
+    ('\u{30c4}', 0x12544),
+    ('\u{30c5}', 0x12545),
+    ('\u{30c6}', 0x12546),
+    ('\u{30c7}', 0x12547),
+    ('\u{30c8}', 0x12548),
+    ('\u{30c9}', 0x12549),
+    ('\u{30ca}', 0x1254a),
+    ('\u{30cb}', 0x1254b),
+    ('\u{30cc}', 0x1254c),
+    ('\u{30cd}', 0x1254d),
+    ('\u{30ce}', 0x1254e),
+    ('\u{30cf}', 0x1254f),
+    ('\u{30d0}', 0x12550),
+    ('\u{30d1}', 0x12551),
+    ('\u{30d2}', 0x12552),
+    ('\u{30d3}', 0x12553),
+    ('\u{30d4}', 0x12554),
+    ('\u{30d5}', 0x12555),
+    ('\u{30d6}', 0x12556),
+    ('\u{30d7}', 0x12557),
+    ('\u{30d8}', 0x12558),
+    ('\u{30d9}', 0x12559),
+    ('\u{30da}', 0x1255a),
+    ('\u{30db}', 0x1255b),
+    ('\u{30dc}', 0x1255c),
+    ('\u{30dd}', 0x1255d),
+    ('\u{30de}', 0x1255e),
+    ('\u{30df}', 0x1255f),
+    ('\u{30e0}', 0x12560),
+    ('\u{30e1}', 0x12561),
+    ('\u{30e2}', 0x12562),
+    ('\u{30e3}', 0x12563),
+    ('\u{30e4}', 0x12564),
+    ('\u{30e5}', 0x12565),
+    ('\u{30e6}', 0x12566),
+    ('\u{30e7}', 0x12567),
+    ('\u{30e8}', 0x12568),
+    ('\u{30e9}', 0x12569),
+    ('\u{30ea}', 0x1256a),
+    ('\u{30eb}', 0x1256b),
+    ('\u{30ec}', 0x1256c),
+    ('\u{30ed}', 0x1256d),
+    ('\u{30ee}', 0x1256e),
+    ('\u{30ef}', 0x1256f),
+    ('\u{30f0}', 0x12570),
+    ('\u{30f1}', 0x12571),
+    ('\u{30f2}', 0x12572),
+    ('\u{30f3}', 0x12573),
+    ('\u{30f4}', 0x12574),
+    ('\u{30f5}', 0x12575),
+    ('\u{30f6}', 0x12576),
+    ('\u{30f7}', 0x12772),
+    ('\u{30f8}', 0x12773),
+    ('\u{30f9}', 0x12774),
+    ('\u{30fa}', 0x12775),
+    ('\u{30fb}', 0x12126),
+    ('\u{30fc}', 0x1213c),
+    ('\u{30fd}', 0x12133),
+    ('\u{30fe}', 0x12134),
+    ('\u{30ff}', 0x12238),
+    ('\u{31f0}', 0x1266e),
+    ('\u{31f1}', 0x1266f),
+    ('\u{31f2}', 0x12670),
+    ('\u{31f3}', 0x12671),
+    ('\u{31f4}', 0x12672),
+    ('\u{31f5}', 0x12673),
+    ('\u{31f6}', 0x12674),
+    ('\u{31f7}', 0x12675),
+    ('\u{31f8}', 0x12676),
+    ('\u{31f9}', 0x12677),
+    ('\u{31fa}', 0x12679),
+    ('\u{31fb}', 0x1267a),
+    ('\u{31fc}', 0x1267b),
+    ('\u{31fd}', 0x1267c),
+    ('\u{31fe}', 0x1267d),
+    ('\u{31ff}', 0x1267e),
+    ('\u{3231}', 0x12d6a),
+    ('\u{3232}', 0x12d6b),
+    ('\u{3239}', 0x12d6c),
+    ('\u{3251}', 0x12841),
+    ('\u{3252}', 0x12842),
+    ('\u{3253}', 0x12843),
+    ('\u{3254}', 0x12844),
+    ('\u{3255}', 0x12845),
+    ('\u{3256}', 0x12846),
+    ('\u{3257}', 0x12847),
+    ('\u{3258}', 0x12848),
+    ('\u{3259}', 0x12849),
+    ('\u{325a}', 0x1284a),
+    ('\u{325b}', 0x1284b),
+    ('\u{325c}', 0x1284c),
+    ('\u{325d}', 0x1284d),
+    ('\u{325e}', 0x1284e),
+    ('\u{325f}', 0x1284f),
+    ('\u{32a4}', 0x12d65),
+    ('\u{32a5}', 0x12d66),
+    ('\u{32a6}', 0x12d67),
+    ('\u{32a7}', 0x12d68),
+    ('\u{32a8}', 0x12d69),
+    ('\u{32b1}', 0x12850),
+    ('\u{32b2}', 0x12851),
+    ('\u{32b3}', 0x12852),
+    ('\u{32b4}', 0x12853),
+    ('\u{32b5}', 0x12854),
+    ('\u{32b6}', 0x12855),
+    ('\u{32b7}', 0x12856),
+    ('\u{32b8}', 0x12857),
+    ('\u{32b9}', 0x12858),
+    ('\u{32ba}', 0x12859),
+    ('\u{32bb}', 0x1285a),
+    ('\u{32bc}', 0x1285b),
+    ('\u{32bd}', 0x1285c),
+    ('\u{32be}', 0x1285d),
+    ('\u{32bf}', 0x1285e),
+    ('\u{32d0}', 0x12c5b),
+    ('\u{32d1}', 0x12c5c),
+    ('\u{32d2}', 0x12c5d),
+    ('\u{32d3}', 0x12c5e),
+    ('\u{32d4}', 0x12c5f),
+    ('\u{32d5}', 0x12c60),
+    ('\u{32d6}', 0x12c61),
+    ('\u{32d7}', 0x12c62),
+    ('\u{32d8}', 0x12c63),
+    ('\u{32d9}', 0x12c64),
+    ('\u{32da}', 0x12c65),
+    ('\u{32db}', 0x12c66),
+    ('\u{32dc}', 0x12c67),
+    ('\u{32dd}', 0x12c68),
+    ('\u{32de}', 0x12c69),
+    ('\u{32df}', 0x12c6a),
+    ('\u{32e0}', 0x12c6b),
+    ('\u{32e1}', 0x12c6c),
+    ('\u{32e2}', 0x12c6d),
+    ('\u{32e3}', 0x12c6e),
+    ('\u{32e5}', 0x12c71),
+    ('\u{32e9}', 0x12c70),
+    ('\u{32ec}', 0x12c73),
+    ('\u{32ed}', 0x12c72),
+    ('\u{32fa}', 0x12c6f),
+    ('\u{3303}', 0x12d46),
+    ('\u{330d}', 0x12d4a),
+    ('\u{3314}', 0x12d41),
+    ('\u{3318}', 0x12d44),
+    ('\u{3322}', 0x12d42),
+    ('\u{3323}', 0x12d4c),
+    ('\u{3326}', 0x12d4b),
+    ('\u{3327}', 0x12d45),
+    ('\u{332b}', 0x12d4d),
+    ('\u{3336}', 0x12d47),
+    ('\u{333b}', 0x12d4f),
+    ('\u{3349}', 0x12d40),
+    ('\u{334a}', 0x12d4e),
+    ('\u{334d}', 0x12d43),
+    ('\u{3351}', 0x12d48),
+    ('\u{3357}', 0x12d49),
+    ('\u{337b}', 0x12d5f),
+    ('\u{337c}', 0x12d6f),
+    ('\u{337d}', 0x12d6e),
+    ('\u{337e}', 0x12d6d),
+    ('\u{338e}', 0x12d53),
+    ('\u{338f}', 0x12d54),
+    ('\u{339c}', 0x12d50),
+    ('\u{339d}', 0x12d51),
+    ('\u{339e}', 0x12d52),
+    ('\u{33a1}', 0x12d56),
+    ('\u{33c4}', 0x12d55),
+    ('\u{33cb}', 0x1235e),
+    ('\u{33cd}', 0x12d63),
+    ('\u{3402}', 0x12e23),
+    ('\u{3406}', 0x2212d),
+    ('\u{342c}', 0x22132),
+    ('\u{342e}', 0x22133),
+    ('\u{3468}', 0x2215e),
+    ('\u{346a}', 0x22156),
+    ('\u{3492}', 0x2217e),
+    ('\u{34b5}', 0x12e53),
+    ('\u{34bc}', 0x2232b),
+    ('\u{34c1}', 0x27468),
+    ('\u{34c7}', 0x2232f),
+    ('\u{34db}', 0x12e5b),
+    ('\u{351f}', 0x22348),
+    ('\u{355d}', 0x2235d),
+    ('\u{355e}', 0x2235e),
+    ('\u{3563}', 0x22361),
+    ('\u{356e}', 0x22367),
+    ('\u{35a6}', 0x22423),
+    ('\u{35a8}', 0x22426),
+    ('\u{35c5}', 0x2242f),
+    ('\u{35da}', 0x22438),
+    ('\u{35f4}', 0x22442),
+    ('\u{3605}', 0x2244a),
+    ('\u{364a}', 0x22479),
+    ('\u{3691}', 0x2253f),
+    ('\u{3696}', 0x22543),
+    ('\u{3699}', 0x22541),
+    ('\u{36cf}', 0x22557),
+    ('\u{3761}', 0x22823),
+    ('\u{3762}', 0x22825),
+    ('\u{376b}', 0x22829),
+    ('\u{376c}', 0x22828),
+    ('\u{3775}', 0x2282c),
+    ('\u{378d}', 0x14f5f),
+    ('\u{37c1}', 0x2283e),
+    ('\u{37e2}', 0x14f6f),
+    ('\u{37e8}', 0x22856),
+    ('\u{37f4}', 0x22859),
+    ('\u{37fd}', 0x2285c),
+    ('\u{3800}', 0x2285e),
+    ('\u{382f}', 0x2286f),
+    ('\u{3836}', 0x22871),
+    ('\u{3840}', 0x22874),
+    ('\u{385c}', 0x22879),
+    ('\u{3861}', 0x2287b),
+    ('\u{38fa}', 0x22c3b),
+    ('\u{3917}', 0x22c46),
+    ('\u{391a}', 0x22c4a),
+    ('\u{396f}', 0x22c60),
+    ('\u{3a6e}', 0x22d5b),
+    ('\u{3a73}', 0x22d5f),
+    ('\u{3ad6}', 0x22d71),
+    ('\u{3ad7}', 0x22e36),
+    ('\u{3aea}', 0x22d7c),
+    ('\u{3b0e}', 0x22e2e),
+    ('\u{3b1a}', 0x22e32),
+    ('\u{3b1c}', 0x22e34),
+    ('\u{3b22}', 0x17549),
+    ('\u{3b6d}', 0x22e6d),
+    ('\u{3b77}', 0x22e65),
+    ('\u{3b87}', 0x22f28),
+    ('\u{3b88}', 0x22f29),
+    ('\u{3b8d}', 0x22f2c),
+    ('\u{3ba4}', 0x22f34),
+    ('\u{3bb6}', 0x1757e),
+    ('\u{3bc3}', 0x17621),
+    ('\u{3bcd}', 0x22f48),
+    ('\u{3bf0}', 0x22f5d),
+    ('\u{3c0f}', 0x1763a),
+    ('\u{3c26}', 0x22f77),
+    ('\u{3cc3}', 0x26e3b),
+    ('\u{3cd2}', 0x26e42),
+    ('\u{3d11}', 0x26e71),
+    ('\u{3d1e}', 0x26e7e),
+    ('\u{3d64}', 0x26f40),
+    ('\u{3d9a}', 0x26f54),
+    ('\u{3dc0}', 0x26f70),
+    ('\u{3dd4}', 0x26f77),
+    ('\u{3e05}', 0x27028),
+    ('\u{3e3f}', 0x17766),
+    ('\u{3e60}', 0x2703f),
+    ('\u{3e66}', 0x27041),
+    ('\u{3e68}', 0x27042),
+    ('\u{3e83}', 0x27049),
+    ('\u{3e94}', 0x27050),
+    ('\u{3f57}', 0x27134),
+    ('\u{3f72}', 0x1784d),
+    ('\u{3f75}', 0x27146),
+    ('\u{3f77}', 0x27148),
+    ('\u{3fae}', 0x2715c),
+    ('\u{3fc9}', 0x27167),
+    ('\u{3fd7}', 0x2716c),
+    ('\u{4039}', 0x27222),
+    ('\u{4058}', 0x2722d),
+    ('\u{4093}', 0x27239),
+    ('\u{4105}', 0x27264),
+    ('\u{4148}', 0x27274),
+    ('\u{414f}', 0x27277),
+    ('\u{4163}', 0x2727d),
+    ('\u{41b4}', 0x27333),
+    ('\u{41bf}', 0x27337),
+    ('\u{41e6}', 0x27347),
+    ('\u{41ee}', 0x2734b),
+    ('\u{41f3}', 0x27348),
+    ('\u{4207}', 0x27353),
+    ('\u{420e}', 0x27357),
+    ('\u{4264}', 0x1796d),
+    ('\u{42c6}', 0x2742b),
+    ('\u{42d6}', 0x27436),
+    ('\u{42dd}', 0x2743b),
+    ('\u{4302}', 0x2744e),
+    ('\u{432b}', 0x2745d),
+    ('\u{4343}', 0x27461),
+    ('\u{43ee}', 0x2753e),
+    ('\u{43f0}', 0x27542),
+    ('\u{4408}', 0x27548),
+    ('\u{4417}', 0x2754a),
+    ('\u{441c}', 0x2754c),
+    ('\u{4422}', 0x2754f),
+    ('\u{4453}', 0x17a59),
+    ('\u{445b}', 0x17a5a),
+    ('\u{4476}', 0x2756c),
+    ('\u{447a}', 0x2756e),
+    ('\u{4491}', 0x27577),
+    ('\u{44b3}', 0x27635),
+    ('\u{44be}', 0x27632),
+    ('\u{44d4}', 0x27634),
+    ('\u{4508}', 0x27659),
+    ('\u{450d}', 0x27654),
+    ('\u{4525}', 0x2766d),
+    ('\u{4543}', 0x2766e),
+    ('\u{459d}', 0x17b51),
+    ('\u{45b8}', 0x2774f),
+    ('\u{45e5}', 0x2776c),
+    ('\u{45ea}', 0x17b60),
+    ('\u{460f}', 0x27824),
+    ('\u{4641}', 0x2783a),
+    ('\u{4665}', 0x27843),
+    ('\u{46a1}', 0x2784e),
+    ('\u{46af}', 0x27853),
+    ('\u{470c}', 0x2786b),
+    ('\u{4764}', 0x27929),
+    ('\u{47fd}', 0x2793f),
+    ('\u{4816}', 0x27949),
+    ('\u{4844}', 0x17c4b),
+    ('\u{484e}', 0x2795c),
+    ('\u{48b5}', 0x27a27),
+    ('\u{49b0}', 0x17d58),
+    ('\u{49e7}', 0x27b6a),
+    ('\u{49fa}', 0x27b70),
+    ('\u{4a04}', 0x27b75),
+    ('\u{4a29}', 0x27b78),
+    ('\u{4abc}', 0x27c37),
+    ('\u{4b3b}', 0x27c55),
+    ('\u{4bc2}', 0x27d26),
+    ('\u{4bca}', 0x27d28),
+    ('\u{4bd2}', 0x27d2a),
+    ('\u{4be8}', 0x27d31),
+    ('\u{4c17}', 0x17e3e),
+    ('\u{4c20}', 0x27d3f),
+    ('\u{4cc4}', 0x27e2a),
+    ('\u{4cd1}', 0x27e2d),
+    ('\u{4d07}', 0x27e4b),
+    ('\u{4d77}', 0x27e60),
+    ('\u{4e00}', 0x1306c),
+    ('\u{4e01}', 0x1437a),
+    ('\u{4e02}', 0x22122),
+    ('\u{4e03}', 0x13c37),
+    ('\u{4e07}', 0x14b7c),
+    ('\u{4e08}', 0x13e66),
+    ('\u{4e09}', 0x13b30),
+    ('\u{4e0a}', 0x13e65),
+    ('\u{4e0b}', 0x1323c),
+    ('\u{4e0d}', 0x14954),
+    ('\u{4e0e}', 0x14d3f),
+    ('\u{4e0f}', 0x22123),
+    ('\u{4e10}', 0x15022),
+    ('\u{4e11}', 0x1312f),
+    ('\u{4e12}', 0x22124),
+    ('\u{4e14}', 0x1336e),
+    ('\u{4e15}', 0x15023),
+    ('\u{4e16}', 0x14024),
+    ('\u{4e17}', 0x15242),
+    ('\u{4e18}', 0x13556),
+    ('\u{4e19}', 0x14a3a),
+    ('\u{4e1e}', 0x13e67),
+    ('\u{4e21}', 0x14e3e),
+    ('\u{4e26}', 0x14a42),
+    ('\u{4e28}', 0x12e24),
+    ('\u{4e29}', 0x22125),
+    ('\u{4e2a}', 0x15024),
+    ('\u{4e2b}', 0x22126),
+    ('\u{4e2c}', 0x2702e),
+    ('\u{4e2d}', 0x14366),
+    ('\u{4e2e}', 0x22127),
+    ('\u{4e2f}', 0x12e25),
+    ('\u{4e30}', 0x12e26),
+    ('\u{4e31}', 0x15025),
+    ('\u{4e32}', 0x1367a),
+    ('\u{4e36}', 0x15026),
+    ('\u{4e38}', 0x1345d),
+    ('\u{4e39}', 0x14330),
+    ('\u{4e3b}', 0x13c67),
+    ('\u{4e3c}', 0x15027),
+    ('\u{4e3f}', 0x15028),
+    ('\u{4e40}', 0x22128),
+    ('\u{4e42}', 0x15029),
+    ('\u{4e43}', 0x14735),
+    ('\u{4e45}', 0x13557),
+    ('\u{4e47}', 0x22129),
+    ('\u{4e48}', 0x2212a),
+    ('\u{4e4b}', 0x14737),
+    ('\u{4e4d}', 0x14663),
+    ('\u{4e4e}', 0x13843),
+    ('\u{4e4f}', 0x14b33),
+    ('\u{4e51}', 0x2212c),
+    ('\u{4e55}', 0x16949),
+    ('\u{4e56}', 0x1502a),
+    ('\u{4e57}', 0x13e68),
+    ('\u{4e58}', 0x1502b),
+    ('\u{4e59}', 0x13235),
+    ('\u{4e5a}', 0x2212f),
+    ('\u{4e5d}', 0x13665),
+    ('\u{4e5e}', 0x13870),
+    ('\u{4e5f}', 0x14c69),
+    ('\u{4e62}', 0x15626),
+    ('\u{4e69}', 0x22130),
+    ('\u{4e71}', 0x14d70),
+    ('\u{4e73}', 0x1467d),
+    ('\u{4e7e}', 0x13425),
+    ('\u{4e80}', 0x13535),
+    ('\u{4e82}', 0x1502c),
+    ('\u{4e85}', 0x1502d),
+    ('\u{4e86}', 0x14e3b),
+    ('\u{4e88}', 0x14d3d),
+    ('\u{4e89}', 0x14168),
+    ('\u{4e8a}', 0x1502f),
+    ('\u{4e8b}', 0x13b76),
+    ('\u{4e8c}', 0x14673),
+    ('\u{4e8d}', 0x12e27),
+    ('\u{4e8e}', 0x15032),
+    ('\u{4e91}', 0x1313e),
+    ('\u{4e92}', 0x1385f),
+    ('\u{4e94}', 0x1385e),
+    ('\u{4e95}', 0x13066),
+    ('\u{4e98}', 0x14f4b),
+    ('\u{4e99}', 0x14f4a),
+    ('\u{4e9b}', 0x13a33),
+    ('\u{4e9c}', 0x13021),
+    ('\u{4e9d}', 0x22131),
+    ('\u{4e9e}', 0x15033),
+    ('\u{4e9f}', 0x15034),
+    ('\u{4ea0}', 0x15035),
+    ('\u{4ea1}', 0x14b34),
+    ('\u{4ea2}', 0x15036),
+    ('\u{4ea4}', 0x13872),
+    ('\u{4ea5}', 0x13067),
+    ('\u{4ea6}', 0x14b72),
+    ('\u{4ea8}', 0x1357c),
+    ('\u{4eab}', 0x1357d),
+    ('\u{4eac}', 0x1357e),
+    ('\u{4ead}', 0x14462),
+    ('\u{4eae}', 0x14e3c),
+    ('\u{4eb0}', 0x15037),
+    ('\u{4eb3}', 0x15038),
+    ('\u{4eb6}', 0x15039),
+    ('\u{4eb9}', 0x22134),
+    ('\u{4eba}', 0x13f4d),
+    ('\u{4ebb}', 0x22135),
+    ('\u{4ebc}', 0x22137),
+    ('\u{4ec0}', 0x13d3a),
+    ('\u{4ec1}', 0x13f4e),
+    ('\u{4ec2}', 0x1503e),
+    ('\u{4ec3}', 0x22138),
+    ('\u{4ec4}', 0x1503c),
+    ('\u{4ec6}', 0x1503d),
+    ('\u{4ec7}', 0x13558),
+    ('\u{4ec8}', 0x22139),
+    ('\u{4eca}', 0x13a23),
+    ('\u{4ecb}', 0x13270),
+    ('\u{4ecd}', 0x1503b),
+    ('\u{4ece}', 0x1503a),
+    ('\u{4ecf}', 0x14a29),
+    ('\u{4ed0}', 0x2213a),
+    ('\u{4ed4}', 0x13b46),
+    ('\u{4ed5}', 0x13b45),
+    ('\u{4ed6}', 0x1423e),
+    ('\u{4ed7}', 0x1503f),
+    ('\u{4ed8}', 0x14955),
+    ('\u{4ed9}', 0x14067),
+    ('\u{4eda}', 0x2213c),
+    ('\u{4edd}', 0x12138),
+    ('\u{4ede}', 0x15040),
+    ('\u{4edf}', 0x15042),
+    ('\u{4ee1}', 0x12e28),
+    ('\u{4ee3}', 0x14265),
+    ('\u{4ee4}', 0x14e61),
+    ('\u{4ee5}', 0x1304a),
+    ('\u{4eeb}', 0x2213b),
+    ('\u{4eed}', 0x15041),
+    ('\u{4eee}', 0x1323e),
+    ('\u{4ef0}', 0x13644),
+    ('\u{4ef1}', 0x2213d),
+    ('\u{4ef2}', 0x14367),
+    ('\u{4ef5}', 0x2213e),
+    ('\u{4ef6}', 0x1376f),
+    ('\u{4ef7}', 0x15043),
+    ('\u{4efb}', 0x14724),
+    ('\u{4efd}', 0x12e29),
+    ('\u{4eff}', 0x12e2a),
+    ('\u{4f00}', 0x2213f),
+    ('\u{4f01}', 0x1346b),
+    ('\u{4f03}', 0x12e2b),
+    ('\u{4f09}', 0x15044),
+    ('\u{4f0a}', 0x1304b),
+    ('\u{4f0b}', 0x12e2c),
+    ('\u{4f0d}', 0x13860),
+    ('\u{4f0e}', 0x1346c),
+    ('\u{4f0f}', 0x1497a),
+    ('\u{4f10}', 0x14832),
+    ('\u{4f11}', 0x13559),
+    ('\u{4f16}', 0x22140),
+    ('\u{4f1a}', 0x13271),
+    ('\u{4f1c}', 0x15067),
+    ('\u{4f1d}', 0x14541),
+    ('\u{4f2f}', 0x1476c),
+    ('\u{4f30}', 0x15046),
+    ('\u{4f34}', 0x1483c),
+    ('\u{4f36}', 0x14e62),
+    ('\u{4f37}', 0x22142),
+    ('\u{4f38}', 0x13f2d),
+    ('\u{4f3a}', 0x13b47),
+    ('\u{4f3c}', 0x13b77),
+    ('\u{4f3d}', 0x13240),
+    ('\u{4f3e}', 0x22143),
+    ('\u{4f43}', 0x14451),
+    ('\u{4f46}', 0x14322),
+    ('\u{4f47}', 0x1504a),
+    ('\u{4f48}', 0x12e2e),
+    ('\u{4f49}', 0x12e2f),
+    ('\u{4f4d}', 0x1304c),
+    ('\u{4f4e}', 0x14463),
+    ('\u{4f4f}', 0x13d3b),
+    ('\u{4f50}', 0x13a34),
+    ('\u{4f51}', 0x14d24),
+    ('\u{4f53}', 0x1424e),
+    ('\u{4f54}', 0x22144),
+    ('\u{4f55}', 0x1323f),
+    ('\u{4f56}', 0x12e30),
+    ('\u{4f57}', 0x15049),
+    ('\u{4f58}', 0x22145),
+    ('\u{4f59}', 0x14d3e),
+    ('\u{4f5a}', 0x15045),
+    ('\u{4f5b}', 0x15047),
+    ('\u{4f5c}', 0x13a6e),
+    ('\u{4f5d}', 0x15048),
+    ('\u{4f5e}', 0x15524),
+    ('\u{4f5f}', 0x12e31),
+    ('\u{4f60}', 0x12e2d),
+    ('\u{4f64}', 0x22141),
+    ('\u{4f69}', 0x15050),
+    ('\u{4f6a}', 0x12e32),
+    ('\u{4f6c}', 0x12e33),
+    ('\u{4f6f}', 0x15053),
+    ('\u{4f70}', 0x15051),
+    ('\u{4f73}', 0x13242),
+    ('\u{4f75}', 0x14a3b),
+    ('\u{4f76}', 0x1504b),
+    ('\u{4f77}', 0x22147),
+    ('\u{4f78}', 0x22148),
+    ('\u{4f7a}', 0x22149),
+    ('\u{4f7b}', 0x1504f),
+    ('\u{4f7c}', 0x13873),
+    ('\u{4f7d}', 0x2214a),
+    ('\u{4f7e}', 0x12e34),
+    ('\u{4f7f}', 0x13b48),
+    ('\u{4f82}', 0x2214b),
+    ('\u{4f83}', 0x13426),
+    ('\u{4f85}', 0x2214c),
+    ('\u{4f86}', 0x15054),
+    ('\u{4f88}', 0x1504c),
+    ('\u{4f8a}', 0x12e35),
+    ('\u{4f8b}', 0x14e63),
+    ('\u{4f8d}', 0x13b78),
+    ('\u{4f8f}', 0x1504d),
+    ('\u{4f91}', 0x15052),
+    ('\u{4f92}', 0x2214d),
+    ('\u{4f94}', 0x12e36),
+    ('\u{4f96}', 0x15055),
+    ('\u{4f97}', 0x12e37),
+    ('\u{4f98}', 0x1504e),
+    ('\u{4f9a}', 0x2214e),
+    ('\u{4f9b}', 0x13621),
+    ('\u{4f9d}', 0x1304d),
+    ('\u{4fa0}', 0x13622),
+    ('\u{4fa1}', 0x13241),
+    ('\u{4fab}', 0x15525),
+    ('\u{4fad}', 0x14b79),
+    ('\u{4fae}', 0x1496e),
+    ('\u{4faf}', 0x13874),
+    ('\u{4fb2}', 0x22150),
+    ('\u{4fb5}', 0x13f2f),
+    ('\u{4fb6}', 0x14e37),
+    ('\u{4fbe}', 0x22151),
+    ('\u{4fbf}', 0x14a58),
+    ('\u{4fc2}', 0x13738),
+    ('\u{4fc3}', 0x14225),
+    ('\u{4fc4}', 0x13264),
+    ('\u{4fc5}', 0x22152),
+    ('\u{4fc9}', 0x12e39),
+    ('\u{4fca}', 0x13d53),
+    ('\u{4fcb}', 0x22153),
+    ('\u{4fce}', 0x15059),
+    ('\u{4fcf}', 0x22154),
+    ('\u{4fd0}', 0x1505e),
+    ('\u{4fd1}', 0x1505c),
+    ('\u{4fd2}', 0x22155),
+    ('\u{4fd4}', 0x15057),
+    ('\u{4fd7}', 0x1422f),
+    ('\u{4fd8}', 0x1505a),
+    ('\u{4fda}', 0x1505d),
+    ('\u{4fdb}', 0x1505b),
+    ('\u{4fdd}', 0x14a5d),
+    ('\u{4fdf}', 0x15058),
+    ('\u{4fe0}', 0x12e3a),
+    ('\u{4fe1}', 0x13f2e),
+    ('\u{4fe3}', 0x14b73),
+    ('\u{4fe4}', 0x1505f),
+    ('\u{4fe5}', 0x15060),
+    ('\u{4fe6}', 0x2214f),
+    ('\u{4fee}', 0x13d24),
+    ('\u{4fef}', 0x1506d),
+    ('\u{4ff1}', 0x12e21),
+    ('\u{4ff2}', 0x22157),
+    ('\u{4ff3}', 0x14750),
+    ('\u{4ff5}', 0x14936),
+    ('\u{4ff6}', 0x15068),
+    ('\u{4ff8}', 0x14a70),
+    ('\u{4ffa}', 0x13236),
+    ('\u{4ffe}', 0x1506c),
+    ('\u{5000}', 0x22158),
+    ('\u{5001}', 0x12e3b),
+    ('\u{5002}', 0x12e3c),
+    ('\u{5005}', 0x15066),
+    ('\u{5006}', 0x1506f),
+    ('\u{5009}', 0x14152),
+    ('\u{500b}', 0x13844),
+    ('\u{500d}', 0x1475c),
+    ('\u{500e}', 0x12e3d),
+    ('\u{500f}', 0x16047),
+    ('\u{5010}', 0x22159),
+    ('\u{5011}', 0x1506e),
+    ('\u{5012}', 0x1455d),
+    ('\u{5013}', 0x2215a),
+    ('\u{5014}', 0x15063),
+    ('\u{5016}', 0x13876),
+    ('\u{5018}', 0x12e3e),
+    ('\u{5019}', 0x13875),
+    ('\u{501a}', 0x15061),
+    ('\u{501c}', 0x2215b),
+    ('\u{501e}', 0x2215c),
+    ('\u{501f}', 0x13c5a),
+    ('\u{5021}', 0x15069),
+    ('\u{5022}', 0x2215d),
+    ('\u{5023}', 0x14a6f),
+    ('\u{5024}', 0x1434d),
+    ('\u{5025}', 0x15065),
+    ('\u{5026}', 0x13771),
+    ('\u{5027}', 0x12e3f),
+    ('\u{5028}', 0x15062),
+    ('\u{5029}', 0x1506a),
+    ('\u{502a}', 0x15064),
+    ('\u{502b}', 0x14e51),
+    ('\u{502c}', 0x1506b),
+    ('\u{502d}', 0x14f41),
+    ('\u{502e}', 0x12e40),
+    ('\u{5036}', 0x13666),
+    ('\u{5039}', 0x13770),
+    ('\u{503b}', 0x12e42),
+    ('\u{5040}', 0x12e41),
+    ('\u{5041}', 0x12e43),
+    ('\u{5042}', 0x2215f),
+    ('\u{5043}', 0x15070),
+    ('\u{5046}', 0x22160),
+    ('\u{5047}', 0x15071),
+    ('\u{5048}', 0x15075),
+    ('\u{5049}', 0x1304e),
+    ('\u{504e}', 0x22161),
+    ('\u{504f}', 0x14a50),
+    ('\u{5050}', 0x15074),
+    ('\u{5053}', 0x22162),
+    ('\u{5055}', 0x15073),
+    ('\u{5056}', 0x15077),
+    ('\u{5057}', 0x22163),
+    ('\u{505a}', 0x15076),
+    ('\u{505c}', 0x14464),
+    ('\u{5063}', 0x22164),
+    ('\u{5065}', 0x13772),
+    ('\u{5066}', 0x22165),
+    ('\u{506a}', 0x22166),
+    ('\u{506c}', 0x15078),
+    ('\u{5070}', 0x22167),
+    ('\u{5072}', 0x13c45),
+    ('\u{5074}', 0x14226),
+    ('\u{5075}', 0x14465),
+    ('\u{5076}', 0x13676),
+    ('\u{5078}', 0x15079),
+    ('\u{507d}', 0x13536),
+    ('\u{5080}', 0x1507a),
+    ('\u{5085}', 0x1507c),
+    ('\u{5088}', 0x22169),
+    ('\u{508d}', 0x14b35),
+    ('\u{5091}', 0x13766),
+    ('\u{5092}', 0x2216a),
+    ('\u{5093}', 0x2216b),
+    ('\u{5094}', 0x12e44),
+    ('\u{5095}', 0x2216c),
+    ('\u{5096}', 0x2216d),
+    ('\u{5098}', 0x13b31),
+    ('\u{5099}', 0x14877),
+    ('\u{509a}', 0x1507b),
+    ('\u{509c}', 0x2216e),
+    ('\u{50a3}', 0x22168),
+    ('\u{50aa}', 0x2216f),
+    ('\u{50ac}', 0x13a45),
+    ('\u{50ad}', 0x14d43),
+    ('\u{50b1}', 0x22171),
+    ('\u{50b2}', 0x1507e),
+    ('\u{50b3}', 0x15123),
+    ('\u{50b4}', 0x1507d),
+    ('\u{50b5}', 0x13a44),
+    ('\u{50b7}', 0x13d7d),
+    ('\u{50ba}', 0x22172),
+    ('\u{50bb}', 0x22173),
+    ('\u{50be}', 0x13739),
+    ('\u{50c2}', 0x15124),
+    ('\u{50c4}', 0x22174),
+    ('\u{50c5}', 0x1364f),
+    ('\u{50c7}', 0x22175),
+    ('\u{50c9}', 0x15121),
+    ('\u{50ca}', 0x15122),
+    ('\u{50cc}', 0x12e45),
+    ('\u{50cd}', 0x1462f),
+    ('\u{50ce}', 0x22178),
+    ('\u{50cf}', 0x1417c),
+    ('\u{50d0}', 0x12e47),
+    ('\u{50d1}', 0x13623),
+    ('\u{50d4}', 0x2217a),
+    ('\u{50d5}', 0x14b4d),
+    ('\u{50d6}', 0x15125),
+    ('\u{50d9}', 0x2217b),
+    ('\u{50da}', 0x14e3d),
+    ('\u{50de}', 0x15126),
+    ('\u{50e1}', 0x2217c),
+    ('\u{50e3}', 0x15129),
+    ('\u{50e5}', 0x15127),
+    ('\u{50e6}', 0x12e48),
+    ('\u{50e7}', 0x1414e),
+    ('\u{50e9}', 0x2217d),
+    ('\u{50ed}', 0x15128),
+    ('\u{50ee}', 0x1512a),
+    ('\u{50f2}', 0x12e46),
+    ('\u{50f3}', 0x22176),
+    ('\u{50f5}', 0x1512c),
+    ('\u{50f9}', 0x1512b),
+    ('\u{50fb}', 0x14a48),
+    ('\u{5100}', 0x13537),
+    ('\u{5101}', 0x1512e),
+    ('\u{5102}', 0x1512f),
+    ('\u{5103}', 0x12e4b),
+    ('\u{5104}', 0x1322f),
+    ('\u{5106}', 0x12e4a),
+    ('\u{5108}', 0x22321),
+    ('\u{5109}', 0x1512d),
+    ('\u{510b}', 0x12e4c),
+    ('\u{5112}', 0x13c74),
+    ('\u{5114}', 0x15132),
+    ('\u{5115}', 0x15131),
+    ('\u{5116}', 0x15130),
+    ('\u{5117}', 0x22323),
+    ('\u{5118}', 0x15056),
+    ('\u{511a}', 0x15133),
+    ('\u{511b}', 0x22324),
+    ('\u{511e}', 0x12e4d),
+    ('\u{511f}', 0x13d7e),
+    ('\u{5121}', 0x15134),
+    ('\u{512a}', 0x14d25),
+    ('\u{5132}', 0x14c59),
+    ('\u{5135}', 0x12e4e),
+    ('\u{5137}', 0x15136),
+    ('\u{513a}', 0x15135),
+    ('\u{513b}', 0x15138),
+    ('\u{513c}', 0x15137),
+    ('\u{513f}', 0x15139),
+    ('\u{5140}', 0x1513a),
+    ('\u{5141}', 0x13074),
+    ('\u{5143}', 0x13835),
+    ('\u{5144}', 0x1373b),
+    ('\u{5145}', 0x13d3c),
+    ('\u{5146}', 0x1437b),
+    ('\u{5147}', 0x13624),
+    ('\u{5148}', 0x14068),
+    ('\u{5149}', 0x13877),
+    ('\u{514a}', 0x12e4f),
+    ('\u{514b}', 0x1396e),
+    ('\u{514c}', 0x1513c),
+    ('\u{514d}', 0x14c48),
+    ('\u{514e}', 0x14546),
+    ('\u{5150}', 0x13b79),
+    ('\u{5152}', 0x1513b),
+    ('\u{5154}', 0x1513d),
+    ('\u{5155}', 0x12e51),
+    ('\u{5157}', 0x12e52),
+    ('\u{515a}', 0x1455e),
+    ('\u{515c}', 0x13375),
+    ('\u{5160}', 0x22326),
+    ('\u{5162}', 0x1513e),
+    ('\u{5165}', 0x1467e),
+    ('\u{5168}', 0x14134),
+    ('\u{5169}', 0x15140),
+    ('\u{516a}', 0x15141),
+    ('\u{516b}', 0x1482c),
+    ('\u{516c}', 0x13878),
+    ('\u{516d}', 0x14f3b),
+    ('\u{516e}', 0x15142),
+    ('\u{5171}', 0x13626),
+    ('\u{5173}', 0x22328),
+    ('\u{5175}', 0x14a3c),
+    ('\u{5176}', 0x14236),
+    ('\u{5177}', 0x13671),
+    ('\u{5178}', 0x14535),
+    ('\u{517b}', 0x27474),
+    ('\u{517c}', 0x13773),
+    ('\u{5180}', 0x15143),
+    ('\u{5182}', 0x15144),
+    ('\u{5183}', 0x22329),
+    ('\u{5185}', 0x14662),
+    ('\u{5186}', 0x1315f),
+    ('\u{5189}', 0x15147),
+    ('\u{518a}', 0x13a7d),
+    ('\u{518b}', 0x2232a),
+    ('\u{518c}', 0x15146),
+    ('\u{518d}', 0x13a46),
+    ('\u{518f}', 0x15148),
+    ('\u{5190}', 0x1666e),
+    ('\u{5191}', 0x15149),
+    ('\u{5192}', 0x14b41),
+    ('\u{5193}', 0x1514a),
+    ('\u{5195}', 0x1514b),
+    ('\u{5196}', 0x1514c),
+    ('\u{5197}', 0x13e69),
+    ('\u{5198}', 0x2232c),
+    ('\u{5199}', 0x13c4c),
+    ('\u{519d}', 0x12e54),
+    ('\u{51a0}', 0x13427),
+    ('\u{51a2}', 0x1514f),
+    ('\u{51a3}', 0x2232d),
+    ('\u{51a4}', 0x1514d),
+    ('\u{51a5}', 0x14c3d),
+    ('\u{51a6}', 0x1514e),
+    ('\u{51a8}', 0x1495a),
+    ('\u{51a9}', 0x15150),
+    ('\u{51aa}', 0x15151),
+    ('\u{51ab}', 0x15152),
+    ('\u{51ac}', 0x1455f),
+    ('\u{51ad}', 0x2232e),
+    ('\u{51b0}', 0x15156),
+    ('\u{51b1}', 0x15154),
+    ('\u{51b2}', 0x15155),
+    ('\u{51b3}', 0x15153),
+    ('\u{51b4}', 0x13a63),
+    ('\u{51b5}', 0x15157),
+    ('\u{51b6}', 0x14c6a),
+    ('\u{51b7}', 0x14e64),
+    ('\u{51bc}', 0x22330),
+    ('\u{51bd}', 0x15158),
+    ('\u{51c3}', 0x12e55),
+    ('\u{51c4}', 0x14028),
+    ('\u{51c5}', 0x15159),
+    ('\u{51c6}', 0x13d5a),
+    ('\u{51c9}', 0x1515a),
+    ('\u{51ca}', 0x12e56),
+    ('\u{51cb}', 0x1437c),
+    ('\u{51cc}', 0x14e3f),
+    ('\u{51cd}', 0x14560),
+    ('\u{51d6}', 0x15245),
+    ('\u{51db}', 0x1515b),
+    ('\u{51dc}', 0x17425),
+    ('\u{51dd}', 0x13645),
+    ('\u{51de}', 0x12e57),
+    ('\u{51e0}', 0x1515c),
+    ('\u{51e1}', 0x14b5e),
+    ('\u{51e2}', 0x12e58),
+    ('\u{51e6}', 0x13d68),
+    ('\u{51e7}', 0x1427c),
+    ('\u{51e9}', 0x1515e),
+    ('\u{51ea}', 0x14664),
+    ('\u{51ed}', 0x1515f),
+    ('\u{51ee}', 0x12e59),
+    ('\u{51f0}', 0x15160),
+    ('\u{51f1}', 0x1332e),
+    ('\u{51f3}', 0x22333),
+    ('\u{51f4}', 0x22334),
+    ('\u{51f5}', 0x15161),
+    ('\u{51f6}', 0x13627),
+    ('\u{51f8}', 0x1464c),
+    ('\u{51f9}', 0x1317a),
+    ('\u{51fa}', 0x13d50),
+    ('\u{51fd}', 0x14821),
+    ('\u{51fe}', 0x15162),
+    ('\u{5200}', 0x14561),
+    ('\u{5201}', 0x12e5a),
+    ('\u{5202}', 0x22335),
+    ('\u{5203}', 0x13f4f),
+    ('\u{5204}', 0x15163),
+    ('\u{5206}', 0x14a2c),
+    ('\u{5207}', 0x1405a),
+    ('\u{5208}', 0x13422),
+    ('\u{520a}', 0x13429),
+    ('\u{520b}', 0x15164),
+    ('\u{520e}', 0x15166),
+    ('\u{5211}', 0x1373a),
+    ('\u{5212}', 0x22336),
+    ('\u{5213}', 0x12e5c),
+    ('\u{5214}', 0x15165),
+    ('\u{5215}', 0x12e5d),
+    ('\u{5216}', 0x22337),
+    ('\u{5217}', 0x14e73),
+    ('\u{521d}', 0x13d69),
+    ('\u{5224}', 0x1483d),
+    ('\u{5225}', 0x14a4c),
+    ('\u{5227}', 0x15167),
+    ('\u{5229}', 0x14d78),
+    ('\u{522a}', 0x15168),
+    ('\u{522e}', 0x15169),
+    ('\u{5230}', 0x1457e),
+    ('\u{5233}', 0x1516a),
+    ('\u{5236}', 0x14029),
+    ('\u{5237}', 0x13a7e),
+    ('\u{5238}', 0x13774),
+    ('\u{5239}', 0x1516b),
+    ('\u{523a}', 0x13b49),
+    ('\u{523b}', 0x1396f),
+    ('\u{5243}', 0x14466),
+    ('\u{5244}', 0x1516d),
+    ('\u{5247}', 0x14227),
+    ('\u{5249}', 0x12e5e),
+    ('\u{524a}', 0x13a6f),
+    ('\u{524b}', 0x1516e),
+    ('\u{524c}', 0x1516f),
+    ('\u{524d}', 0x14130),
+    ('\u{524f}', 0x1516c),
+    ('\u{5254}', 0x15171),
+    ('\u{5255}', 0x22339),
+    ('\u{5256}', 0x14b36),
+    ('\u{5257}', 0x12e5f),
+    ('\u{525b}', 0x13964),
+    ('\u{525c}', 0x2233a),
+    ('\u{525d}', 0x12f7e),
+    ('\u{525e}', 0x15170),
+    ('\u{5261}', 0x12e60),
+    ('\u{5263}', 0x13775),
+    ('\u{5264}', 0x13a5e),
+    ('\u{5265}', 0x1476d),
+    ('\u{5269}', 0x15174),
+    ('\u{526a}', 0x15172),
+    ('\u{526c}', 0x2233b),
+    ('\u{526f}', 0x1497b),
+    ('\u{5270}', 0x13e6a),
+    ('\u{5271}', 0x1517b),
+    ('\u{5272}', 0x13364),
+    ('\u{5273}', 0x15175),
+    ('\u{5274}', 0x15173),
+    ('\u{5275}', 0x1414f),
+    ('\u{5277}', 0x2233c),
+    ('\u{527d}', 0x15177),
+    ('\u{527f}', 0x15176),
+    ('\u{5282}', 0x2233e),
+    ('\u{5283}', 0x13344),
+    ('\u{5284}', 0x2233d),
+    ('\u{5287}', 0x13760),
+    ('\u{5288}', 0x1517c),
+    ('\u{5289}', 0x14e2d),
+    ('\u{528d}', 0x15178),
+    ('\u{5291}', 0x1517d),
+    ('\u{5292}', 0x1517a),
+    ('\u{5293}', 0x12e61),
+    ('\u{5294}', 0x15179),
+    ('\u{5298}', 0x22340),
+    ('\u{529b}', 0x14e4f),
+    ('\u{529f}', 0x13879),
+    ('\u{52a0}', 0x13243),
+    ('\u{52a3}', 0x14e74),
+    ('\u{52a4}', 0x22342),
+    ('\u{52a6}', 0x22343),
+    ('\u{52a9}', 0x13d75),
+    ('\u{52aa}', 0x14558),
+    ('\u{52ab}', 0x13965),
+    ('\u{52ac}', 0x15222),
+    ('\u{52ad}', 0x15223),
+    ('\u{52af}', 0x22344),
+    ('\u{52b1}', 0x14e65),
+    ('\u{52b4}', 0x14f2b),
+    ('\u{52b5}', 0x15225),
+    ('\u{52b9}', 0x1387a),
+    ('\u{52ba}', 0x22345),
+    ('\u{52bb}', 0x22346),
+    ('\u{52bc}', 0x15224),
+    ('\u{52be}', 0x1332f),
+    ('\u{52c1}', 0x15226),
+    ('\u{52c3}', 0x14b56),
+    ('\u{52c5}', 0x1443c),
+    ('\u{52c7}', 0x14d26),
+    ('\u{52c8}', 0x12e62),
+    ('\u{52c9}', 0x14a59),
+    ('\u{52ca}', 0x22347),
+    ('\u{52cc}', 0x12e64),
+    ('\u{52cd}', 0x15227),
+    ('\u{52d0}', 0x12e65),
+    ('\u{52d1}', 0x22349),
+    ('\u{52d2}', 0x17055),
+    ('\u{52d5}', 0x14630),
+    ('\u{52d6}', 0x12e66),
+    ('\u{52d7}', 0x15228),
+    ('\u{52d8}', 0x1342a),
+    ('\u{52d9}', 0x14c33),
+    ('\u{52db}', 0x12e67),
+    ('\u{52dd}', 0x13e21),
+    ('\u{52de}', 0x15229),
+    ('\u{52df}', 0x14a67),
+    ('\u{52e0}', 0x1522d),
+    ('\u{52e2}', 0x1402a),
+    ('\u{52e3}', 0x1522a),
+    ('\u{52e4}', 0x13650),
+    ('\u{52e6}', 0x1522b),
+    ('\u{52e7}', 0x1342b),
+    ('\u{52f0}', 0x12e69),
+    ('\u{52f2}', 0x1372e),
+    ('\u{52f3}', 0x1522e),
+    ('\u{52f5}', 0x1522f),
+    ('\u{52f7}', 0x2234b),
+    ('\u{52f8}', 0x15230),
+    ('\u{52f9}', 0x15231),
+    ('\u{52fa}', 0x13c5b),
+    ('\u{52fb}', 0x12e6a),
+    ('\u{52fe}', 0x1387b),
+    ('\u{52ff}', 0x14c5e),
+    ('\u{5300}', 0x12e6b),
+    ('\u{5301}', 0x14c68),
+    ('\u{5302}', 0x14677),
+    ('\u{5305}', 0x14a71),
+    ('\u{5306}', 0x15232),
+    ('\u{5307}', 0x12e6c),
+    ('\u{5308}', 0x15233),
+    ('\u{530a}', 0x2234c),
+    ('\u{530b}', 0x2234d),
+    ('\u{530d}', 0x15235),
+    ('\u{530f}', 0x15237),
+    ('\u{5310}', 0x15236),
+    ('\u{5315}', 0x15238),
+    ('\u{5316}', 0x1323d),
+    ('\u{5317}', 0x14b4c),
+    ('\u{5319}', 0x13a7c),
+    ('\u{531a}', 0x15239),
+    ('\u{531c}', 0x12e6d),
+    ('\u{531d}', 0x14159),
+    ('\u{5320}', 0x13e22),
+    ('\u{5321}', 0x13629),
+    ('\u{5323}', 0x1523a),
+    ('\u{5324}', 0x2234e),
+    ('\u{532a}', 0x1485b),
+    ('\u{532f}', 0x1523b),
+    ('\u{5331}', 0x1523c),
+    ('\u{5333}', 0x1523d),
+    ('\u{5335}', 0x2234f),
+    ('\u{5338}', 0x1523e),
+    ('\u{5339}', 0x14924),
+    ('\u{533a}', 0x13668),
+    ('\u{533b}', 0x13065),
+    ('\u{533e}', 0x22350),
+    ('\u{533f}', 0x1463f),
+    ('\u{5340}', 0x1523f),
+    ('\u{5341}', 0x13d3d),
+    ('\u{5342}', 0x22351),
+    ('\u{5343}', 0x14069),
+    ('\u{5345}', 0x15241),
+    ('\u{5346}', 0x15240),
+    ('\u{5347}', 0x13e23),
+    ('\u{5348}', 0x13861),
+    ('\u{5349}', 0x15243),
+    ('\u{534a}', 0x1483e),
+    ('\u{534d}', 0x15244),
+    ('\u{5351}', 0x1485c),
+    ('\u{5352}', 0x14234),
+    ('\u{5353}', 0x1426e),
+    ('\u{5354}', 0x13628),
+    ('\u{5357}', 0x1466e),
+    ('\u{5358}', 0x14331),
+    ('\u{535a}', 0x1476e),
+    ('\u{535c}', 0x14b4e),
+    ('\u{535e}', 0x15246),
+    ('\u{5360}', 0x1406a),
+    ('\u{5361}', 0x12e6f),
+    ('\u{5363}', 0x12e70),
+    ('\u{5366}', 0x13735),
+    ('\u{5367}', 0x22354),
+    ('\u{5369}', 0x15247),
+    ('\u{536c}', 0x22355),
+    ('\u{536e}', 0x15248),
+    ('\u{536f}', 0x1312c),
+    ('\u{5370}', 0x13075),
+    ('\u{5371}', 0x1346d),
+    ('\u{5373}', 0x14228),
+    ('\u{5374}', 0x13551),
+    ('\u{5375}', 0x14d71),
+    ('\u{5377}', 0x1524b),
+    ('\u{5378}', 0x13237),
+    ('\u{537a}', 0x22356),
+    ('\u{537b}', 0x1524a),
+    ('\u{537d}', 0x12e71),
+    ('\u{537f}', 0x1362a),
+    ('\u{5382}', 0x1524c),
+    ('\u{5384}', 0x14c71),
+    ('\u{5393}', 0x12e72),
+    ('\u{5396}', 0x1524d),
+    ('\u{5398}', 0x14e52),
+    ('\u{539a}', 0x1387c),
+    ('\u{539d}', 0x12e73),
+    ('\u{539f}', 0x13836),
+    ('\u{53a0}', 0x1524e),
+    ('\u{53a4}', 0x22357),
+    ('\u{53a5}', 0x15250),
+    ('\u{53a6}', 0x1524f),
+    ('\u{53a8}', 0x13f5f),
+    ('\u{53a9}', 0x13139),
+    ('\u{53ad}', 0x1315e),
+    ('\u{53ae}', 0x15251),
+    ('\u{53b0}', 0x15252),
+    ('\u{53b2}', 0x12e74),
+    ('\u{53b3}', 0x13837),
+    ('\u{53b4}', 0x22358),
+    ('\u{53b6}', 0x15253),
+    ('\u{53b7}', 0x2235a),
+    ('\u{53bb}', 0x1356e),
+    ('\u{53c0}', 0x2235b),
+    ('\u{53c2}', 0x13b32),
+    ('\u{53c3}', 0x15254),
+    ('\u{53c8}', 0x14b74),
+    ('\u{53c9}', 0x13a35),
+    ('\u{53ca}', 0x1355a),
+    ('\u{53cb}', 0x14d27),
+    ('\u{53cc}', 0x14150),
+    ('\u{53cd}', 0x1483f),
+    ('\u{53ce}', 0x13c7d),
+    ('\u{53d4}', 0x13d47),
+    ('\u{53d5}', 0x2235f),
+    ('\u{53d6}', 0x13c68),
+    ('\u{53d7}', 0x13c75),
+    ('\u{53d9}', 0x13d76),
+    ('\u{53da}', 0x22360),
+    ('\u{53db}', 0x14840),
+    ('\u{53df}', 0x15257),
+    ('\u{53e1}', 0x13143),
+    ('\u{53e2}', 0x14151),
+    ('\u{53e3}', 0x1387d),
+    ('\u{53e4}', 0x13845),
+    ('\u{53e5}', 0x13667),
+    ('\u{53e8}', 0x1525b),
+    ('\u{53e9}', 0x14321),
+    ('\u{53ea}', 0x1427e),
+    ('\u{53eb}', 0x1362b),
+    ('\u{53ec}', 0x13e24),
+    ('\u{53ed}', 0x1525c),
+    ('\u{53ee}', 0x1525a),
+    ('\u{53ef}', 0x13244),
+    ('\u{53f0}', 0x14266),
+    ('\u{53f1}', 0x13c38),
+    ('\u{53f2}', 0x13b4b),
+    ('\u{53f3}', 0x13126),
+    ('\u{53f4}', 0x22362),
+    ('\u{53f5}', 0x22363),
+    ('\u{53f6}', 0x13370),
+    ('\u{53f7}', 0x13966),
+    ('\u{53f8}', 0x13b4a),
+    ('\u{53fa}', 0x1525d),
+    ('\u{5401}', 0x1525e),
+    ('\u{5403}', 0x13549),
+    ('\u{5404}', 0x13346),
+    ('\u{5408}', 0x13967),
+    ('\u{5409}', 0x13548),
+    ('\u{540a}', 0x1445f),
+    ('\u{540b}', 0x13125),
+    ('\u{540c}', 0x14631),
+    ('\u{540d}', 0x14c3e),
+    ('\u{540e}', 0x13921),
+    ('\u{540f}', 0x14d79),
+    ('\u{5410}', 0x14547),
+    ('\u{5411}', 0x1387e),
+    ('\u{5412}', 0x12e75),
+    ('\u{541b}', 0x1372f),
+    ('\u{541d}', 0x15267),
+    ('\u{541e}', 0x14f7e),
+    ('\u{541f}', 0x13663),
+    ('\u{5420}', 0x14b4a),
+    ('\u{5424}', 0x22365),
+    ('\u{5426}', 0x1485d),
+    ('\u{5427}', 0x12e76),
+    ('\u{5428}', 0x22366),
+    ('\u{5429}', 0x15266),
+    ('\u{542b}', 0x1345e),
+    ('\u{542c}', 0x15261),
+    ('\u{542d}', 0x15262),
+    ('\u{542e}', 0x15264),
+    ('\u{5436}', 0x15265),
+    ('\u{5438}', 0x1355b),
+    ('\u{5439}', 0x13f61),
+    ('\u{543b}', 0x14a2d),
+    ('\u{543c}', 0x15263),
+    ('\u{543d}', 0x1525f),
+    ('\u{543e}', 0x13863),
+    ('\u{5440}', 0x15260),
+    ('\u{5442}', 0x14f24),
+    ('\u{5443}', 0x22368),
+    ('\u{5446}', 0x14a72),
+    ('\u{5448}', 0x14468),
+    ('\u{5449}', 0x13862),
+    ('\u{544a}', 0x13970),
+    ('\u{544d}', 0x12e77),
+    ('\u{544e}', 0x15268),
+    ('\u{5451}', 0x1465d),
+    ('\u{5455}', 0x22364),
+    ('\u{545f}', 0x1526c),
+    ('\u{5462}', 0x22369),
+    ('\u{5466}', 0x2236a),
+    ('\u{5468}', 0x13c7e),
+    ('\u{546a}', 0x13c76),
+    ('\u{546b}', 0x12e79),
+    ('\u{546c}', 0x2236b),
+    ('\u{5470}', 0x1526f),
+    ('\u{5471}', 0x1526d),
+    ('\u{5473}', 0x14c23),
+    ('\u{5474}', 0x12e7a),
+    ('\u{5475}', 0x1526a),
+    ('\u{5476}', 0x15273),
+    ('\u{5477}', 0x1526e),
+    ('\u{547b}', 0x15271),
+    ('\u{547c}', 0x13846),
+    ('\u{547d}', 0x14c3f),
+    ('\u{547f}', 0x12e7b),
+    ('\u{5480}', 0x15272),
+    ('\u{5484}', 0x15274),
+    ('\u{5486}', 0x15276),
+    ('\u{5488}', 0x12e7c),
+    ('\u{548a}', 0x2236c),
+    ('\u{548b}', 0x13a70),
+    ('\u{548c}', 0x14f42),
+    ('\u{548d}', 0x2236d),
+    ('\u{548e}', 0x1526b),
+    ('\u{548f}', 0x15269),
+    ('\u{5490}', 0x15275),
+    ('\u{5492}', 0x15270),
+    ('\u{5495}', 0x2236e),
+    ('\u{5496}', 0x12e7d),
+    ('\u{549c}', 0x12e78),
+    ('\u{54a0}', 0x2236f),
+    ('\u{54a1}', 0x12e7e),
+    ('\u{54a2}', 0x15278),
+    ('\u{54a4}', 0x15323),
+    ('\u{54a5}', 0x1527a),
+    ('\u{54a6}', 0x22370),
+    ('\u{54a8}', 0x1527e),
+    ('\u{54a9}', 0x12f21),
+    ('\u{54ab}', 0x15321),
+    ('\u{54ac}', 0x1527b),
+    ('\u{54ad}', 0x22371),
+    ('\u{54ae}', 0x22372),
+    ('\u{54af}', 0x1533e),
+    ('\u{54b2}', 0x13a69),
+    ('\u{54b3}', 0x13331),
+    ('\u{54b7}', 0x22373),
+    ('\u{54b8}', 0x15279),
+    ('\u{54ba}', 0x22374),
+    ('\u{54bc}', 0x15325),
+    ('\u{54bd}', 0x13076),
+    ('\u{54be}', 0x15324),
+    ('\u{54bf}', 0x22375),
+    ('\u{54c0}', 0x13025),
+    ('\u{54c1}', 0x1494a),
+    ('\u{54c2}', 0x15322),
+    ('\u{54c3}', 0x22376),
+    ('\u{54c4}', 0x1527c),
+    ('\u{54c6}', 0x12f22),
+    ('\u{54c7}', 0x15277),
+    ('\u{54c8}', 0x1527d),
+    ('\u{54c9}', 0x13a48),
+    ('\u{54d8}', 0x15326),
+    ('\u{54e1}', 0x13077),
+    ('\u{54e2}', 0x1532f),
+    ('\u{54e5}', 0x15327),
+    ('\u{54e6}', 0x15328),
+    ('\u{54e8}', 0x13e25),
+    ('\u{54e9}', 0x14b69),
+    ('\u{54ec}', 0x22378),
+    ('\u{54ed}', 0x1532d),
+    ('\u{54ee}', 0x1532c),
+    ('\u{54ef}', 0x22379),
+    ('\u{54f1}', 0x2237a),
+    ('\u{54f2}', 0x1452f),
+    ('\u{54f3}', 0x2237b),
+    ('\u{54fa}', 0x1532e),
+    ('\u{54fd}', 0x1532b),
+    ('\u{54ff}', 0x12f23),
+    ('\u{5500}', 0x2237c),
+    ('\u{5501}', 0x2237d),
+    ('\u{5504}', 0x13134),
+    ('\u{5506}', 0x13a36),
+    ('\u{5507}', 0x13f30),
+    ('\u{5509}', 0x2237e),
+    ('\u{550e}', 0x12f24),
+    ('\u{550f}', 0x15329),
+    ('\u{5510}', 0x14562),
+    ('\u{5514}', 0x1532a),
+    ('\u{5516}', 0x13022),
+    ('\u{552b}', 0x12f25),
+    ('\u{552e}', 0x15334),
+    ('\u{552f}', 0x14d23),
+    ('\u{5531}', 0x13e27),
+    ('\u{5533}', 0x1533a),
+    ('\u{5535}', 0x12f26),
+    ('\u{5538}', 0x15339),
+    ('\u{5539}', 0x15330),
+    ('\u{553c}', 0x22421),
+    ('\u{553e}', 0x14243),
+    ('\u{5540}', 0x15331),
+    ('\u{5541}', 0x22422),
+    ('\u{5544}', 0x1426f),
+    ('\u{5545}', 0x15336),
+    ('\u{5546}', 0x13e26),
+    ('\u{5547}', 0x22424),
+    ('\u{554a}', 0x22425),
+    ('\u{554c}', 0x15333),
+    ('\u{554f}', 0x14c64),
+    ('\u{5550}', 0x12f27),
+    ('\u{5553}', 0x1373c),
+    ('\u{5556}', 0x15337),
+    ('\u{5557}', 0x15338),
+    ('\u{555c}', 0x15335),
+    ('\u{555d}', 0x1533b),
+    ('\u{555e}', 0x12f28),
+    ('\u{5560}', 0x22427),
+    ('\u{5561}', 0x22428),
+    ('\u{5563}', 0x15332),
+    ('\u{5564}', 0x22429),
+    ('\u{557b}', 0x15341),
+    ('\u{557c}', 0x15346),
+    ('\u{557d}', 0x2242b),
+    ('\u{557e}', 0x15342),
+    ('\u{5580}', 0x1533d),
+    ('\u{5581}', 0x12f29),
+    ('\u{5582}', 0x2242c),
+    ('\u{5583}', 0x15347),
+    ('\u{5584}', 0x14131),
+    ('\u{5586}', 0x12f2a),
+    ('\u{5587}', 0x15349),
+    ('\u{5588}', 0x2242d),
+    ('\u{5589}', 0x13922),
+    ('\u{558a}', 0x1533f),
+    ('\u{558b}', 0x1437d),
+    ('\u{558e}', 0x12f2b),
+    ('\u{5591}', 0x2242e),
+    ('\u{5598}', 0x15343),
+    ('\u{5599}', 0x1533c),
+    ('\u{559a}', 0x1342d),
+    ('\u{559c}', 0x1346e),
+    ('\u{559d}', 0x13365),
+    ('\u{559e}', 0x15344),
+    ('\u{559f}', 0x15340),
+    ('\u{55a7}', 0x13776),
+    ('\u{55a8}', 0x1534a),
+    ('\u{55a9}', 0x15348),
+    ('\u{55aa}', 0x14153),
+    ('\u{55ab}', 0x1354a),
+    ('\u{55ac}', 0x1362c),
+    ('\u{55ad}', 0x12f2d),
+    ('\u{55ae}', 0x15345),
+    ('\u{55b0}', 0x13674),
+    ('\u{55b6}', 0x13144),
+    ('\u{55bf}', 0x22433),
+    ('\u{55c4}', 0x1534e),
+    ('\u{55c5}', 0x1534c),
+    ('\u{55c7}', 0x15427),
+    ('\u{55c9}', 0x22434),
+    ('\u{55cc}', 0x22435),
+    ('\u{55ce}', 0x12f2e),
+    ('\u{55d1}', 0x22436),
+    ('\u{55d2}', 0x22430),
+    ('\u{55d4}', 0x15351),
+    ('\u{55da}', 0x1534b),
+    ('\u{55dc}', 0x1534f),
+    ('\u{55dd}', 0x22437),
+    ('\u{55df}', 0x1534d),
+    ('\u{55e2}', 0x22439),
+    ('\u{55e3}', 0x13b4c),
+    ('\u{55e4}', 0x15350),
+    ('\u{55e9}', 0x2243b),
+    ('\u{55f7}', 0x15353),
+    ('\u{55f9}', 0x15358),
+    ('\u{55fd}', 0x15356),
+    ('\u{55fe}', 0x15355),
+    ('\u{5606}', 0x14332),
+    ('\u{5607}', 0x2243e),
+    ('\u{5608}', 0x12f30),
+    ('\u{5609}', 0x13245),
+    ('\u{560e}', 0x12f31),
+    ('\u{5610}', 0x2243f),
+    ('\u{5614}', 0x15352),
+    ('\u{5616}', 0x15354),
+    ('\u{5617}', 0x13e28),
+    ('\u{5618}', 0x13133),
+    ('\u{561b}', 0x15357),
+    ('\u{5628}', 0x2243c),
+    ('\u{5629}', 0x1325e),
+    ('\u{562f}', 0x15362),
+    ('\u{5630}', 0x22440),
+    ('\u{5631}', 0x13e7c),
+    ('\u{5632}', 0x1535e),
+    ('\u{5634}', 0x1535c),
+    ('\u{5636}', 0x1535d),
+    ('\u{5637}', 0x22441),
+    ('\u{5638}', 0x1535f),
+    ('\u{563b}', 0x12f32),
+    ('\u{563d}', 0x22443),
+    ('\u{563f}', 0x22444),
+    ('\u{5640}', 0x22445),
+    ('\u{5642}', 0x1313d),
+    ('\u{5647}', 0x22446),
+    ('\u{5649}', 0x12f33),
+    ('\u{564c}', 0x14139),
+    ('\u{564e}', 0x15359),
+    ('\u{5650}', 0x1535a),
+    ('\u{5653}', 0x17427),
+    ('\u{565b}', 0x1337a),
+    ('\u{565e}', 0x22447),
+    ('\u{5660}', 0x22448),
+    ('\u{5664}', 0x15361),
+    ('\u{5666}', 0x12f35),
+    ('\u{5668}', 0x1346f),
+    ('\u{566a}', 0x15364),
+    ('\u{566b}', 0x15360),
+    ('\u{566c}', 0x15363),
+    ('\u{566d}', 0x22449),
+    ('\u{566f}', 0x12f37),
+    ('\u{5671}', 0x12f38),
+    ('\u{5672}', 0x12f39),
+    ('\u{5674}', 0x14a2e),
+    ('\u{5676}', 0x12f34),
+    ('\u{5678}', 0x14655),
+    ('\u{567a}', 0x14838),
+    ('\u{5680}', 0x15366),
+    ('\u{5686}', 0x15365),
+    ('\u{5687}', 0x13345),
+    ('\u{5688}', 0x2244b),
+    ('\u{568a}', 0x15367),
+    ('\u{568c}', 0x2244c),
+    ('\u{568f}', 0x1536a),
+    ('\u{5694}', 0x15369),
+    ('\u{5695}', 0x2244d),
+    ('\u{5699}', 0x12f3a),
+    ('\u{569a}', 0x2244e),
+    ('\u{569d}', 0x2244f),
+    ('\u{569e}', 0x12f3b),
+    ('\u{56a0}', 0x15368),
+    ('\u{56a2}', 0x14739),
+    ('\u{56a5}', 0x1536b),
+    ('\u{56a8}', 0x22450),
+    ('\u{56a9}', 0x12f3c),
+    ('\u{56ac}', 0x12f3d),
+    ('\u{56ad}', 0x22451),
+    ('\u{56ae}', 0x1536c),
+    ('\u{56b2}', 0x22452),
+    ('\u{56b3}', 0x12f3e),
+    ('\u{56b4}', 0x1536e),
+    ('\u{56b6}', 0x1536d),
+    ('\u{56bc}', 0x15370),
+    ('\u{56c0}', 0x15373),
+    ('\u{56c1}', 0x15371),
+    ('\u{56c2}', 0x1536f),
+    ('\u{56c3}', 0x15372),
+    ('\u{56c5}', 0x22453),
+    ('\u{56c8}', 0x15374),
+    ('\u{56c9}', 0x12f3f),
+    ('\u{56ca}', 0x12f40),
+    ('\u{56cd}', 0x22454),
+    ('\u{56ce}', 0x15375),
+    ('\u{56d1}', 0x15376),
+    ('\u{56d3}', 0x15377),
+    ('\u{56d7}', 0x15378),
+    ('\u{56d8}', 0x15145),
+    ('\u{56da}', 0x13c7c),
+    ('\u{56db}', 0x13b4d),
+    ('\u{56de}', 0x13273),
+    ('\u{56df}', 0x22455),
+    ('\u{56e0}', 0x13078),
+    ('\u{56e3}', 0x14344),
+    ('\u{56e8}', 0x22456),
+    ('\u{56ee}', 0x15379),
+    ('\u{56f0}', 0x13a24),
+    ('\u{56f2}', 0x1304f),
+    ('\u{56f3}', 0x13f5e),
+    ('\u{56f6}', 0x22457),
+    ('\u{56f7}', 0x22458),
+    ('\u{56f9}', 0x1537a),
+    ('\u{56fa}', 0x13847),
+    ('\u{56fd}', 0x13971),
+    ('\u{56ff}', 0x1537c),
+    ('\u{5700}', 0x1537b),
+    ('\u{5703}', 0x14a60),
+    ('\u{5704}', 0x1537d),
+    ('\u{5708}', 0x15421),
+    ('\u{5709}', 0x1537e),
+    ('\u{570a}', 0x12f41),
+    ('\u{570b}', 0x15422),
+    ('\u{570d}', 0x15423),
+    ('\u{570f}', 0x13777),
+    ('\u{5712}', 0x13160),
+    ('\u{5713}', 0x15424),
+    ('\u{5715}', 0x2245a),
+    ('\u{5716}', 0x15426),
+    ('\u{5718}', 0x15425),
+    ('\u{571c}', 0x15428),
+    ('\u{571f}', 0x1455a),
+    ('\u{5721}', 0x12f43),
+    ('\u{5723}', 0x2245b),
+    ('\u{5726}', 0x15429),
+    ('\u{5727}', 0x13035),
+    ('\u{5728}', 0x13a5f),
+    ('\u{5729}', 0x2245d),
+    ('\u{572d}', 0x1373d),
+    ('\u{572f}', 0x12f44),
+    ('\u{5730}', 0x1434f),
+    ('\u{5733}', 0x12f45),
+    ('\u{5734}', 0x12f46),
+    ('\u{5737}', 0x1542a),
+    ('\u{5738}', 0x1542b),
+    ('\u{573b}', 0x1542d),
+    ('\u{5740}', 0x1542e),
+    ('\u{5742}', 0x13a64),
+    ('\u{5745}', 0x2245f),
+    ('\u{5746}', 0x22460),
+    ('\u{5747}', 0x13651),
+    ('\u{574a}', 0x14b37),
+    ('\u{574c}', 0x22461),
+    ('\u{574d}', 0x22462),
+    ('\u{574e}', 0x1542c),
+    ('\u{574f}', 0x1542f),
+    ('\u{5750}', 0x13a41),
+    ('\u{5751}', 0x13923),
+    ('\u{5761}', 0x15433),
+    ('\u{5764}', 0x13a25),
+    ('\u{5766}', 0x14333),
+    ('\u{5768}', 0x22464),
+    ('\u{5769}', 0x15430),
+    ('\u{576a}', 0x1445a),
+    ('\u{576f}', 0x22465),
+    ('\u{5770}', 0x12f47),
+    ('\u{5773}', 0x22466),
+    ('\u{5774}', 0x22467),
+    ('\u{5775}', 0x22468),
+    ('\u{5777}', 0x12f48),
+    ('\u{577b}', 0x22469),
+    ('\u{577c}', 0x12f49),
+    ('\u{577f}', 0x15434),
+    ('\u{5782}', 0x13f62),
+    ('\u{5788}', 0x15432),
+    ('\u{5789}', 0x15435),
+    ('\u{578b}', 0x1373f),
+    ('\u{5793}', 0x15436),
+    ('\u{579a}', 0x2246d),
+    ('\u{579c}', 0x12f4a),
+    ('\u{579d}', 0x2246e),
+    ('\u{579e}', 0x2246f),
+    ('\u{57a0}', 0x15437),
+    ('\u{57a2}', 0x13924),
+    ('\u{57a3}', 0x13340),
+    ('\u{57a4}', 0x15439),
+    ('\u{57a8}', 0x22470),
+    ('\u{57aa}', 0x1543a),
+    ('\u{57ac}', 0x2246c),
+    ('\u{57b0}', 0x1543b),
+    ('\u{57b3}', 0x15438),
+    ('\u{57b8}', 0x12f4d),
+    ('\u{57c0}', 0x15431),
+    ('\u{57c3}', 0x1543c),
+    ('\u{57c6}', 0x1543d),
+    ('\u{57c7}', 0x12f4e),
+    ('\u{57c8}', 0x12f4f),
+    ('\u{57cb}', 0x14b64),
+    ('\u{57cc}', 0x22473),
+    ('\u{57ce}', 0x13e6b),
+    ('\u{57cf}', 0x12f50),
+    ('\u{57d2}', 0x1543f),
+    ('\u{57d3}', 0x15440),
+    ('\u{57d4}', 0x1543e),
+    ('\u{57d6}', 0x15442),
+    ('\u{57d7}', 0x22471),
+    ('\u{57dc}', 0x14738),
+    ('\u{57de}', 0x22476),
+    ('\u{57df}', 0x13068),
+    ('\u{57e0}', 0x14956),
+    ('\u{57e3}', 0x15443),
+    ('\u{57e4}', 0x12f51),
+    ('\u{57e6}', 0x22477),
+    ('\u{57ed}', 0x12f52),
+    ('\u{57f0}', 0x22478),
+    ('\u{57f4}', 0x13e7d),
+    ('\u{57f5}', 0x12f53),
+    ('\u{57f6}', 0x12f54),
+    ('\u{57f7}', 0x13c39),
+    ('\u{57f8}', 0x2247a),
+    ('\u{57f9}', 0x1475d),
+    ('\u{57fa}', 0x13470),
+    ('\u{57fb}', 0x2247b),
+    ('\u{57fc}', 0x13a6b),
+    ('\u{57fd}', 0x2247c),
+    ('\u{57ff}', 0x12f55),
+    ('\u{5800}', 0x14b59),
+    ('\u{5802}', 0x14632),
+    ('\u{5804}', 0x2247d),
+    ('\u{5805}', 0x13778),
+    ('\u{5806}', 0x1424f),
+    ('\u{5809}', 0x12f56),
+    ('\u{580a}', 0x15441),
+    ('\u{580b}', 0x15444),
+    ('\u{5815}', 0x14244),
+    ('\u{5819}', 0x15445),
+    ('\u{581d}', 0x15446),
+    ('\u{581e}', 0x2247e),
+    ('\u{5820}', 0x22521),
+    ('\u{5821}', 0x15448),
+    ('\u{5824}', 0x14469),
+    ('\u{5827}', 0x22522),
+    ('\u{582a}', 0x1342e),
+    ('\u{582f}', 0x17421),
+    ('\u{5830}', 0x13161),
+    ('\u{5831}', 0x14a73),
+    ('\u{5832}', 0x22523),
+    ('\u{5834}', 0x13e6c),
+    ('\u{5835}', 0x14548),
+    ('\u{5839}', 0x22524),
+    ('\u{583a}', 0x13a66),
+    ('\u{583d}', 0x1544e),
+    ('\u{5840}', 0x14a3d),
+    ('\u{5841}', 0x14e5d),
+    ('\u{5849}', 0x22526),
+    ('\u{584a}', 0x13274),
+    ('\u{584b}', 0x1544a),
+    ('\u{584c}', 0x22527),
+    ('\u{5851}', 0x1413a),
+    ('\u{5852}', 0x1544d),
+    ('\u{5854}', 0x14563),
+    ('\u{5857}', 0x14549),
+    ('\u{5858}', 0x14564),
+    ('\u{5859}', 0x14839),
+    ('\u{585a}', 0x1444d),
+    ('\u{585e}', 0x13a49),
+    ('\u{5861}', 0x12f58),
+    ('\u{5862}', 0x15449),
+    ('\u{5864}', 0x12f59),
+    ('\u{5867}', 0x22528),
+    ('\u{5869}', 0x13176),
+    ('\u{586b}', 0x14536),
+    ('\u{5870}', 0x1544b),
+    ('\u{5872}', 0x15447),
+    ('\u{5875}', 0x13f50),
+    ('\u{5879}', 0x1544f),
+    ('\u{587c}', 0x12f5b),
+    ('\u{587e}', 0x13d4e),
+    ('\u{5883}', 0x1362d),
+    ('\u{5885}', 0x15450),
+    ('\u{5889}', 0x12f5c),
+    ('\u{588a}', 0x22529),
+    ('\u{588b}', 0x2252a),
+    ('\u{588d}', 0x2252b),
+    ('\u{588f}', 0x2252c),
+    ('\u{5890}', 0x2252d),
+    ('\u{5893}', 0x14a68),
+    ('\u{5894}', 0x2252e),
+    ('\u{5897}', 0x1417d),
+    ('\u{589c}', 0x14446),
+    ('\u{589d}', 0x2252f),
+    ('\u{589e}', 0x12f5d),
+    ('\u{589f}', 0x15452),
+    ('\u{58a8}', 0x14b4f),
+    ('\u{58a9}', 0x12f5f),
+    ('\u{58aa}', 0x22530),
+    ('\u{58ab}', 0x15453),
+    ('\u{58ae}', 0x15458),
+    ('\u{58b1}', 0x22531),
+    ('\u{58b3}', 0x14a2f),
+    ('\u{58b8}', 0x15457),
+    ('\u{58b9}', 0x15451),
+    ('\u{58ba}', 0x15454),
+    ('\u{58bb}', 0x15456),
+    ('\u{58be}', 0x13a26),
+    ('\u{58c1}', 0x14a49),
+    ('\u{58c3}', 0x22533),
+    ('\u{58c5}', 0x15459),
+    ('\u{58c7}', 0x14345),
+    ('\u{58ca}', 0x13275),
+    ('\u{58cc}', 0x13e6d),
+    ('\u{58cd}', 0x22534),
+    ('\u{58ce}', 0x12f62),
+    ('\u{58d1}', 0x1545b),
+    ('\u{58d2}', 0x12f61),
+    ('\u{58d3}', 0x1545a),
+    ('\u{58d4}', 0x12f63),
+    ('\u{58d5}', 0x13968),
+    ('\u{58d7}', 0x1545c),
+    ('\u{58d8}', 0x1545e),
+    ('\u{58d9}', 0x1545d),
+    ('\u{58da}', 0x12f64),
+    ('\u{58dc}', 0x15460),
+    ('\u{58de}', 0x15455),
+    ('\u{58df}', 0x15462),
+    ('\u{58e0}', 0x12f65),
+    ('\u{58e2}', 0x22535),
+    ('\u{58e4}', 0x15461),
+    ('\u{58e5}', 0x1545f),
+    ('\u{58e9}', 0x12f66),
+    ('\u{58eb}', 0x13b4e),
+    ('\u{58ec}', 0x13f51),
+    ('\u{58ee}', 0x14154),
+    ('\u{58ef}', 0x15463),
+    ('\u{58f0}', 0x1403c),
+    ('\u{58f1}', 0x1306d),
+    ('\u{58f2}', 0x14764),
+    ('\u{58f3}', 0x22536),
+    ('\u{58f4}', 0x22537),
+    ('\u{58f7}', 0x1445b),
+    ('\u{58f9}', 0x15465),
+    ('\u{58fa}', 0x15464),
+    ('\u{58fb}', 0x15466),
+    ('\u{58fc}', 0x15467),
+    ('\u{58fd}', 0x15468),
+    ('\u{5902}', 0x15469),
+    ('\u{5905}', 0x22538),
+    ('\u{5906}', 0x22539),
+    ('\u{5909}', 0x14a51),
+    ('\u{590a}', 0x1546a),
+    ('\u{590b}', 0x2253a),
+    ('\u{590c}', 0x12f67),
+    ('\u{590d}', 0x2253b),
+    ('\u{590f}', 0x13246),
+    ('\u{5910}', 0x1546b),
+    ('\u{5914}', 0x2253c),
+    ('\u{5915}', 0x14d3c),
+    ('\u{5916}', 0x13330),
+    ('\u{5918}', 0x15249),
+    ('\u{5919}', 0x13d48),
+    ('\u{591a}', 0x1423f),
+    ('\u{591b}', 0x1546c),
+    ('\u{591c}', 0x14c6b),
+    ('\u{5922}', 0x14c34),
+    ('\u{5924}', 0x2253d),
+    ('\u{5925}', 0x1546e),
+    ('\u{5927}', 0x14267),
+    ('\u{5929}', 0x14537),
+    ('\u{592a}', 0x14240),
+    ('\u{592b}', 0x14957),
+    ('\u{592c}', 0x1546f),
+    ('\u{592d}', 0x15470),
+    ('\u{592e}', 0x1317b),
+    ('\u{5931}', 0x13c3a),
+    ('\u{5932}', 0x15471),
+    ('\u{5937}', 0x13050),
+    ('\u{5938}', 0x15472),
+    ('\u{593d}', 0x22540),
+    ('\u{593e}', 0x15473),
+    ('\u{5944}', 0x13162),
+    ('\u{5946}', 0x22542),
+    ('\u{5947}', 0x13471),
+    ('\u{5948}', 0x14660),
+    ('\u{5949}', 0x14a74),
+    ('\u{594e}', 0x15477),
+    ('\u{594f}', 0x14155),
+    ('\u{5950}', 0x15476),
+    ('\u{5951}', 0x13740),
+    ('\u{5954}', 0x14b5b),
+    ('\u{5955}', 0x15475),
+    ('\u{5957}', 0x14565),
+    ('\u{5958}', 0x15479),
+    ('\u{595a}', 0x15478),
+    ('\u{595b}', 0x22545),
+    ('\u{595d}', 0x12f69),
+    ('\u{595f}', 0x22546),
+    ('\u{5960}', 0x1547b),
+    ('\u{5962}', 0x1547a),
+    ('\u{5965}', 0x1317c),
+    ('\u{5967}', 0x1547c),
+    ('\u{5968}', 0x13e29),
+    ('\u{5969}', 0x1547e),
+    ('\u{596a}', 0x14325),
+    ('\u{596c}', 0x1547d),
+    ('\u{596d}', 0x12f6a),
+    ('\u{596e}', 0x14a33),
+    ('\u{5973}', 0x13d77),
+    ('\u{5974}', 0x1455b),
+    ('\u{5975}', 0x22548),
+    ('\u{5976}', 0x22549),
+    ('\u{5978}', 0x15521),
+    ('\u{597c}', 0x2254a),
+    ('\u{597d}', 0x13925),
+    ('\u{5981}', 0x15522),
+    ('\u{5982}', 0x14721),
+    ('\u{5983}', 0x1485e),
+    ('\u{5984}', 0x14c51),
+    ('\u{598a}', 0x14725),
+    ('\u{598b}', 0x12f6b),
+    ('\u{598d}', 0x1552b),
+    ('\u{5992}', 0x12f6c),
+    ('\u{5993}', 0x13538),
+    ('\u{5996}', 0x14d45),
+    ('\u{5999}', 0x14c2f),
+    ('\u{599b}', 0x1562c),
+    ('\u{599d}', 0x15523),
+    ('\u{599f}', 0x2254b),
+    ('\u{59a3}', 0x15526),
+    ('\u{59a4}', 0x12f6d),
+    ('\u{59a5}', 0x14245),
+    ('\u{59a8}', 0x14b38),
+    ('\u{59ac}', 0x1454a),
+    ('\u{59ae}', 0x2254c),
+    ('\u{59b2}', 0x15527),
+    ('\u{59b9}', 0x14b65),
+    ('\u{59bb}', 0x13a4a),
+    ('\u{59bc}', 0x2254d),
+    ('\u{59be}', 0x13e2a),
+    ('\u{59c3}', 0x12f6e),
+    ('\u{59c6}', 0x15528),
+    ('\u{59c8}', 0x2254e),
+    ('\u{59c9}', 0x13b50),
+    ('\u{59cb}', 0x13b4f),
+    ('\u{59cd}', 0x2254f),
+    ('\u{59d0}', 0x13039),
+    ('\u{59d1}', 0x13848),
+    ('\u{59d2}', 0x12f6f),
+    ('\u{59d3}', 0x1402b),
+    ('\u{59d4}', 0x13051),
+    ('\u{59d9}', 0x1552c),
+    ('\u{59da}', 0x1552d),
+    ('\u{59dc}', 0x1552a),
+    ('\u{59dd}', 0x12f70),
+    ('\u{59de}', 0x22550),
+    ('\u{59e3}', 0x22551),
+    ('\u{59e4}', 0x22552),
+    ('\u{59e5}', 0x13138),
+    ('\u{59e6}', 0x1342f),
+    ('\u{59e7}', 0x22553),
+    ('\u{59e8}', 0x15529),
+    ('\u{59ea}', 0x14c45),
+    ('\u{59eb}', 0x14931),
+    ('\u{59ee}', 0x22554),
+    ('\u{59f6}', 0x13028),
+    ('\u{59f8}', 0x17e7a),
+    ('\u{59fb}', 0x13079),
+    ('\u{59ff}', 0x13b51),
+    ('\u{5a01}', 0x13052),
+    ('\u{5a03}', 0x13023),
+    ('\u{5a09}', 0x15532),
+    ('\u{5a0c}', 0x22558),
+    ('\u{5a0d}', 0x22559),
+    ('\u{5a11}', 0x15530),
+    ('\u{5a13}', 0x12f71),
+    ('\u{5a17}', 0x2255a),
+    ('\u{5a18}', 0x14c3c),
+    ('\u{5a1a}', 0x15533),
+    ('\u{5a1c}', 0x15531),
+    ('\u{5a1f}', 0x1552f),
+    ('\u{5a20}', 0x13f31),
+    ('\u{5a23}', 0x12f72),
+    ('\u{5a25}', 0x1552e),
+    ('\u{5a27}', 0x2255b),
+    ('\u{5a29}', 0x14a5a),
+    ('\u{5a2d}', 0x2255c),
+    ('\u{5a2f}', 0x13864),
+    ('\u{5a35}', 0x15537),
+    ('\u{5a36}', 0x15538),
+    ('\u{5a3c}', 0x13e2b),
+    ('\u{5a40}', 0x15534),
+    ('\u{5a41}', 0x14f2c),
+    ('\u{5a46}', 0x1474c),
+    ('\u{5a49}', 0x15536),
+    ('\u{5a55}', 0x2255d),
+    ('\u{5a5a}', 0x13a27),
+    ('\u{5a62}', 0x15539),
+    ('\u{5a65}', 0x2255e),
+    ('\u{5a66}', 0x14958),
+    ('\u{5a67}', 0x12f73),
+    ('\u{5a6a}', 0x1553a),
+    ('\u{5a6c}', 0x15535),
+    ('\u{5a6d}', 0x12f74),
+    ('\u{5a77}', 0x12f75),
+    ('\u{5a7a}', 0x2255f),
+    ('\u{5a7e}', 0x12f76),
+    ('\u{5a7f}', 0x14c3b),
+    ('\u{5a84}', 0x12f77),
+    ('\u{5a8b}', 0x22560),
+    ('\u{5a92}', 0x1475e),
+    ('\u{5a9a}', 0x1553b),
+    ('\u{5a9b}', 0x14932),
+    ('\u{5a9c}', 0x22561),
+    ('\u{5a9e}', 0x12f78),
+    ('\u{5a9f}', 0x22562),
+    ('\u{5aa0}', 0x22563),
+    ('\u{5aa2}', 0x22564),
+    ('\u{5aa7}', 0x12f79),
+    ('\u{5ab1}', 0x22565),
+    ('\u{5ab3}', 0x22566),
+    ('\u{5ab5}', 0x22567),
+    ('\u{5aba}', 0x22568),
+    ('\u{5abc}', 0x1553c),
+    ('\u{5abd}', 0x15540),
+    ('\u{5abe}', 0x1553d),
+    ('\u{5abf}', 0x22569),
+    ('\u{5ac1}', 0x13247),
+    ('\u{5ac2}', 0x1553f),
+    ('\u{5ac4}', 0x12f7a),
+    ('\u{5ac9}', 0x13c3b),
+    ('\u{5acb}', 0x1553e),
+    ('\u{5acc}', 0x13779),
+    ('\u{5ad0}', 0x1554c),
+    ('\u{5ad6}', 0x15545),
+    ('\u{5ad7}', 0x15542),
+    ('\u{5ada}', 0x2256a),
+    ('\u{5adc}', 0x2256b),
+    ('\u{5ae0}', 0x2256c),
+    ('\u{5ae1}', 0x14364),
+    ('\u{5ae3}', 0x15541),
+    ('\u{5ae5}', 0x2256d),
+    ('\u{5ae6}', 0x15543),
+    ('\u{5ae9}', 0x15544),
+    ('\u{5aee}', 0x2256f),
+    ('\u{5af0}', 0x2256e),
+    ('\u{5af5}', 0x22570),
+    ('\u{5afa}', 0x15546),
+    ('\u{5afb}', 0x15547),
+    ('\u{5b00}', 0x22571),
+    ('\u{5b08}', 0x22572),
+    ('\u{5b09}', 0x13472),
+    ('\u{5b0b}', 0x15549),
+    ('\u{5b0c}', 0x15548),
+    ('\u{5b16}', 0x1554a),
+    ('\u{5b17}', 0x22573),
+    ('\u{5b19}', 0x12f7c),
+    ('\u{5b22}', 0x13e6e),
+    ('\u{5b25}', 0x12f7d),
+    ('\u{5b2a}', 0x1554d),
+    ('\u{5b2c}', 0x1445c),
+    ('\u{5b2d}', 0x22575),
+    ('\u{5b30}', 0x13145),
+    ('\u{5b32}', 0x1554b),
+    ('\u{5b34}', 0x22574),
+    ('\u{5b36}', 0x1554e),
+    ('\u{5b3e}', 0x1554f),
+    ('\u{5b40}', 0x15552),
+    ('\u{5b41}', 0x14f55),
+    ('\u{5b43}', 0x15550),
+    ('\u{5b45}', 0x15551),
+    ('\u{5b4c}', 0x22576),
+    ('\u{5b50}', 0x13b52),
+    ('\u{5b51}', 0x15553),
+    ('\u{5b52}', 0x22577),
+    ('\u{5b54}', 0x13926),
+    ('\u{5b55}', 0x15554),
+    ('\u{5b56}', 0x14f56),
+    ('\u{5b57}', 0x13b7a),
+    ('\u{5b58}', 0x14238),
+    ('\u{5b5a}', 0x15555),
+    ('\u{5b5b}', 0x15556),
+    ('\u{5b5c}', 0x13b5a),
+    ('\u{5b5d}', 0x13927),
+    ('\u{5b5f}', 0x14c52),
+    ('\u{5b63}', 0x13528),
+    ('\u{5b64}', 0x13849),
+    ('\u{5b65}', 0x15557),
+    ('\u{5b66}', 0x13358),
+    ('\u{5b68}', 0x22578),
+    ('\u{5b69}', 0x15558),
+    ('\u{5b6b}', 0x14239),
+    ('\u{5b6f}', 0x22579),
+    ('\u{5b70}', 0x15559),
+    ('\u{5b71}', 0x15623),
+    ('\u{5b73}', 0x1555a),
+    ('\u{5b75}', 0x1555b),
+    ('\u{5b78}', 0x1555c),
+    ('\u{5b7a}', 0x1555e),
+    ('\u{5b7c}', 0x2257a),
+    ('\u{5b7d}', 0x14f57),
+    ('\u{5b7f}', 0x2257b),
+    ('\u{5b80}', 0x1555f),
+    ('\u{5b81}', 0x2257c),
+    ('\u{5b83}', 0x15560),
+    ('\u{5b84}', 0x2257d),
+    ('\u{5b85}', 0x14270),
+    ('\u{5b87}', 0x13127),
+    ('\u{5b88}', 0x13c69),
+    ('\u{5b89}', 0x13042),
+    ('\u{5b8b}', 0x14157),
+    ('\u{5b8c}', 0x13430),
+    ('\u{5b8d}', 0x13c35),
+    ('\u{5b8f}', 0x13928),
+    ('\u{5b93}', 0x14f58),
+    ('\u{5b95}', 0x14566),
+    ('\u{5b96}', 0x22821),
+    ('\u{5b97}', 0x13d21),
+    ('\u{5b98}', 0x13431),
+    ('\u{5b99}', 0x14368),
+    ('\u{5b9a}', 0x1446a),
+    ('\u{5b9b}', 0x13038),
+    ('\u{5b9c}', 0x13539),
+    ('\u{5b9d}', 0x14a75),
+    ('\u{5b9f}', 0x13c42),
+    ('\u{5ba2}', 0x13552),
+    ('\u{5ba3}', 0x1406b),
+    ('\u{5ba4}', 0x13c3c),
+    ('\u{5ba5}', 0x14d28),
+    ('\u{5ba6}', 0x15561),
+    ('\u{5bac}', 0x22822),
+    ('\u{5bae}', 0x1355c),
+    ('\u{5bb0}', 0x13a4b),
+    ('\u{5bb3}', 0x13332),
+    ('\u{5bb4}', 0x13163),
+    ('\u{5bb5}', 0x13e2c),
+    ('\u{5bb6}', 0x13248),
+    ('\u{5bb8}', 0x15562),
+    ('\u{5bb9}', 0x14d46),
+    ('\u{5bbf}', 0x13d49),
+    ('\u{5bc0}', 0x22824),
+    ('\u{5bc2}', 0x13c64),
+    ('\u{5bc3}', 0x15563),
+    ('\u{5bc4}', 0x13473),
+    ('\u{5bc5}', 0x14652),
+    ('\u{5bc6}', 0x14c29),
+    ('\u{5bc7}', 0x15564),
+    ('\u{5bc9}', 0x15565),
+    ('\u{5bcc}', 0x14959),
+    ('\u{5bce}', 0x22826),
+    ('\u{5bd0}', 0x15567),
+    ('\u{5bd2}', 0x13428),
+    ('\u{5bd3}', 0x13677),
+    ('\u{5bd4}', 0x15566),
+    ('\u{5bd6}', 0x22827),
+    ('\u{5bd8}', 0x14f59),
+    ('\u{5bdb}', 0x13432),
+    ('\u{5bdd}', 0x13f32),
+    ('\u{5bde}', 0x1556b),
+    ('\u{5bdf}', 0x13b21),
+    ('\u{5be1}', 0x13249),
+    ('\u{5be2}', 0x1556a),
+    ('\u{5be4}', 0x15568),
+    ('\u{5be5}', 0x1556c),
+    ('\u{5be6}', 0x15569),
+    ('\u{5be7}', 0x1472b),
+    ('\u{5be8}', 0x15c4d),
+    ('\u{5be9}', 0x13f33),
+    ('\u{5beb}', 0x1556d),
+    ('\u{5bec}', 0x14f5a),
+    ('\u{5bee}', 0x14e40),
+    ('\u{5bf0}', 0x1556e),
+    ('\u{5bf1}', 0x2282a),
+    ('\u{5bf3}', 0x15570),
+    ('\u{5bf5}', 0x1437e),
+    ('\u{5bf6}', 0x1556f),
+    ('\u{5bf8}', 0x14023),
+    ('\u{5bfa}', 0x13b7b),
+    ('\u{5bfd}', 0x2282b),
+    ('\u{5bfe}', 0x14250),
+    ('\u{5bff}', 0x13c77),
+    ('\u{5c01}', 0x14975),
+    ('\u{5c02}', 0x1406c),
+    ('\u{5c03}', 0x2282d),
+    ('\u{5c04}', 0x13c4d),
+    ('\u{5c05}', 0x15571),
+    ('\u{5c06}', 0x13e2d),
+    ('\u{5c07}', 0x15572),
+    ('\u{5c08}', 0x15573),
+    ('\u{5c09}', 0x13053),
+    ('\u{5c0a}', 0x1423a),
+    ('\u{5c0b}', 0x13f52),
+    ('\u{5c0d}', 0x15574),
+    ('\u{5c0e}', 0x14633),
+    ('\u{5c0f}', 0x13e2e),
+    ('\u{5c11}', 0x13e2f),
+    ('\u{5c12}', 0x14f5b),
+    ('\u{5c13}', 0x15575),
+    ('\u{5c16}', 0x1406d),
+    ('\u{5c1a}', 0x13e30),
+    ('\u{5c1e}', 0x14f5c),
+    ('\u{5c20}', 0x15576),
+    ('\u{5c22}', 0x15577),
+    ('\u{5c23}', 0x14f5d),
+    ('\u{5c24}', 0x14c60),
+    ('\u{5c28}', 0x15578),
+    ('\u{5c29}', 0x2282e),
+    ('\u{5c2b}', 0x14f5e),
+    ('\u{5c2d}', 0x13646),
+    ('\u{5c30}', 0x2282f),
+    ('\u{5c31}', 0x13d22),
+    ('\u{5c38}', 0x15579),
+    ('\u{5c39}', 0x1557a),
+    ('\u{5c3a}', 0x13c5c),
+    ('\u{5c3b}', 0x13f2c),
+    ('\u{5c3c}', 0x14674),
+    ('\u{5c3d}', 0x13f54),
+    ('\u{5c3e}', 0x14878),
+    ('\u{5c3f}', 0x14722),
+    ('\u{5c40}', 0x13649),
+    ('\u{5c41}', 0x1557b),
+    ('\u{5c45}', 0x1356f),
+    ('\u{5c46}', 0x1557c),
+    ('\u{5c48}', 0x1367e),
+    ('\u{5c4a}', 0x1464f),
+    ('\u{5c4b}', 0x13230),
+    ('\u{5c4d}', 0x13b53),
+    ('\u{5c4e}', 0x1557d),
+    ('\u{5c4f}', 0x15622),
+    ('\u{5c50}', 0x15621),
+    ('\u{5c51}', 0x1367d),
+    ('\u{5c53}', 0x1557e),
+    ('\u{5c55}', 0x14538),
+    ('\u{5c5b}', 0x17e7b),
+    ('\u{5c5e}', 0x14230),
+    ('\u{5c5f}', 0x22831),
+    ('\u{5c60}', 0x1454b),
+    ('\u{5c61}', 0x13c48),
+    ('\u{5c62}', 0x14f60),
+    ('\u{5c63}', 0x22832),
+    ('\u{5c64}', 0x14158),
+    ('\u{5c65}', 0x14d7a),
+    ('\u{5c67}', 0x22833),
+    ('\u{5c68}', 0x22834),
+    ('\u{5c69}', 0x22835),
+    ('\u{5c6c}', 0x15624),
+    ('\u{5c6e}', 0x15625),
+    ('\u{5c6f}', 0x14656),
+    ('\u{5c70}', 0x22836),
+    ('\u{5c71}', 0x13b33),
+    ('\u{5c76}', 0x15627),
+    ('\u{5c79}', 0x15628),
+    ('\u{5c7a}', 0x14f64),
+    ('\u{5c7c}', 0x22839),
+    ('\u{5c88}', 0x2283c),
+    ('\u{5c8a}', 0x2283d),
+    ('\u{5c8c}', 0x15629),
+    ('\u{5c8f}', 0x14f65),
+    ('\u{5c90}', 0x13474),
+    ('\u{5c91}', 0x1562a),
+    ('\u{5c94}', 0x1562b),
+    ('\u{5c9f}', 0x14f66),
+    ('\u{5ca0}', 0x22841),
+    ('\u{5ca1}', 0x1322c),
+    ('\u{5ca2}', 0x22842),
+    ('\u{5ca3}', 0x14f67),
+    ('\u{5ca6}', 0x22843),
+    ('\u{5ca7}', 0x22844),
+    ('\u{5ca8}', 0x1413b),
+    ('\u{5ca9}', 0x13464),
+    ('\u{5caa}', 0x14f68),
+    ('\u{5cab}', 0x1562d),
+    ('\u{5cac}', 0x14c28),
+    ('\u{5cad}', 0x22846),
+    ('\u{5cb1}', 0x14252),
+    ('\u{5cb3}', 0x13359),
+    ('\u{5cb5}', 0x22847),
+    ('\u{5cb6}', 0x1562f),
+    ('\u{5cb7}', 0x15631),
+    ('\u{5cb8}', 0x1345f),
+    ('\u{5cba}', 0x14f69),
+    ('\u{5cbb}', 0x1562e),
+    ('\u{5cbc}', 0x15630),
+    ('\u{5cbe}', 0x15633),
+    ('\u{5cc5}', 0x15632),
+    ('\u{5cc7}', 0x15634),
+    ('\u{5cc9}', 0x22849),
+    ('\u{5ccb}', 0x14f6a),
+    ('\u{5cd0}', 0x14f6b),
+    ('\u{5cd2}', 0x14f6c),
+    ('\u{5cd9}', 0x15635),
+    ('\u{5ce0}', 0x1463d),
+    ('\u{5ce1}', 0x1362e),
+    ('\u{5ce8}', 0x13265),
+    ('\u{5ce9}', 0x15636),
+    ('\u{5cea}', 0x1563b),
+    ('\u{5ced}', 0x15639),
+    ('\u{5cef}', 0x14a77),
+    ('\u{5cf0}', 0x14a76),
+    ('\u{5cf4}', 0x14f6d),
+    ('\u{5cf6}', 0x14567),
+    ('\u{5cfa}', 0x15638),
+    ('\u{5cfb}', 0x13d54),
+    ('\u{5cfd}', 0x15637),
+    ('\u{5d06}', 0x2284c),
+    ('\u{5d07}', 0x13f72),
+    ('\u{5d0b}', 0x1563c),
+    ('\u{5d0d}', 0x14f70),
+    ('\u{5d0e}', 0x13a6a),
+    ('\u{5d10}', 0x2284d),
+    ('\u{5d11}', 0x15642),
+    ('\u{5d14}', 0x15643),
+    ('\u{5d15}', 0x1563d),
+    ('\u{5d16}', 0x13333),
+    ('\u{5d17}', 0x1563e),
+    ('\u{5d18}', 0x15647),
+    ('\u{5d19}', 0x15646),
+    ('\u{5d1a}', 0x15645),
+    ('\u{5d1b}', 0x15641),
+    ('\u{5d1d}', 0x2284f),
+    ('\u{5d1f}', 0x15640),
+    ('\u{5d20}', 0x22850),
+    ('\u{5d22}', 0x15644),
+    ('\u{5d24}', 0x22851),
+    ('\u{5d26}', 0x22852),
+    ('\u{5d27}', 0x14f71),
+    ('\u{5d29}', 0x14a78),
+    ('\u{5d2b}', 0x2284e),
+    ('\u{5d31}', 0x22853),
+    ('\u{5d39}', 0x22854),
+    ('\u{5d42}', 0x22855),
+    ('\u{5d46}', 0x14f73),
+    ('\u{5d47}', 0x14f74),
+    ('\u{5d4a}', 0x14f76),
+    ('\u{5d4b}', 0x1564b),
+    ('\u{5d4c}', 0x15648),
+    ('\u{5d4e}', 0x1564a),
+    ('\u{5d50}', 0x14d72),
+    ('\u{5d52}', 0x15649),
+    ('\u{5d53}', 0x14f75),
+    ('\u{5d5c}', 0x1563f),
+    ('\u{5d61}', 0x22857),
+    ('\u{5d69}', 0x13f73),
+    ('\u{5d6a}', 0x22858),
+    ('\u{5d6c}', 0x1564c),
+    ('\u{5d6d}', 0x14f77),
+    ('\u{5d6f}', 0x13a37),
+    ('\u{5d70}', 0x2285a),
+    ('\u{5d73}', 0x1564d),
+    ('\u{5d76}', 0x1564e),
+    ('\u{5d81}', 0x14f78),
+    ('\u{5d82}', 0x15651),
+    ('\u{5d84}', 0x15650),
+    ('\u{5d87}', 0x1564f),
+    ('\u{5d88}', 0x2285d),
+    ('\u{5d8b}', 0x14568),
+    ('\u{5d8c}', 0x1563a),
+    ('\u{5d90}', 0x15657),
+    ('\u{5d92}', 0x2285f),
+    ('\u{5d94}', 0x22860),
+    ('\u{5d97}', 0x22861),
+    ('\u{5d99}', 0x22862),
+    ('\u{5d9d}', 0x15653),
+    ('\u{5da0}', 0x14f79),
+    ('\u{5da2}', 0x15652),
+    ('\u{5da4}', 0x14f7a),
+    ('\u{5da7}', 0x14f7b),
+    ('\u{5dac}', 0x15654),
+    ('\u{5dae}', 0x15655),
+    ('\u{5db0}', 0x22863),
+    ('\u{5db2}', 0x22864),
+    ('\u{5db4}', 0x22865),
+    ('\u{5db7}', 0x15658),
+    ('\u{5db8}', 0x14f7c),
+    ('\u{5db9}', 0x22867),
+    ('\u{5dba}', 0x14e66),
+    ('\u{5dbc}', 0x15659),
+    ('\u{5dbd}', 0x15656),
+    ('\u{5dc9}', 0x1565a),
+    ('\u{5dcb}', 0x14f7d),
+    ('\u{5dcc}', 0x13460),
+    ('\u{5dcd}', 0x1565b),
+    ('\u{5dd1}', 0x22868),
+    ('\u{5dd2}', 0x1565d),
+    ('\u{5dd3}', 0x1565c),
+    ('\u{5dd6}', 0x1565e),
+    ('\u{5dd7}', 0x22869),
+    ('\u{5dd8}', 0x2286a),
+    ('\u{5ddb}', 0x1565f),
+    ('\u{5ddd}', 0x1406e),
+    ('\u{5dde}', 0x13d23),
+    ('\u{5de0}', 0x2286b),
+    ('\u{5de1}', 0x13d64),
+    ('\u{5de2}', 0x17428),
+    ('\u{5de3}', 0x14163),
+    ('\u{5de4}', 0x2286d),
+    ('\u{5de5}', 0x13929),
+    ('\u{5de6}', 0x13a38),
+    ('\u{5de7}', 0x1392a),
+    ('\u{5de8}', 0x13570),
+    ('\u{5de9}', 0x2286e),
+    ('\u{5deb}', 0x15660),
+    ('\u{5dee}', 0x13a39),
+    ('\u{5df1}', 0x1384a),
+    ('\u{5df2}', 0x15661),
+    ('\u{5df3}', 0x14c26),
+    ('\u{5df4}', 0x14743),
+    ('\u{5df5}', 0x15662),
+    ('\u{5df7}', 0x1392b),
+    ('\u{5dfb}', 0x1342c),
+    ('\u{5dfd}', 0x14327),
+    ('\u{5dfe}', 0x13652),
+    ('\u{5e00}', 0x22870),
+    ('\u{5e02}', 0x13b54),
+    ('\u{5e03}', 0x1495b),
+    ('\u{5e06}', 0x14841),
+    ('\u{5e0b}', 0x15663),
+    ('\u{5e0c}', 0x13475),
+    ('\u{5e11}', 0x15666),
+    ('\u{5e12}', 0x22872),
+    ('\u{5e14}', 0x17429),
+    ('\u{5e15}', 0x22873),
+    ('\u{5e16}', 0x14421),
+    ('\u{5e18}', 0x1742a),
+    ('\u{5e19}', 0x15665),
+    ('\u{5e1a}', 0x15664),
+    ('\u{5e1b}', 0x15667),
+    ('\u{5e1d}', 0x1446b),
+    ('\u{5e1f}', 0x22875),
+    ('\u{5e25}', 0x13f63),
+    ('\u{5e2b}', 0x13b55),
+    ('\u{5e2d}', 0x1404a),
+    ('\u{5e2e}', 0x22876),
+    ('\u{5e2f}', 0x14253),
+    ('\u{5e30}', 0x13522),
+    ('\u{5e33}', 0x14422),
+    ('\u{5e36}', 0x15668),
+    ('\u{5e37}', 0x15669),
+    ('\u{5e38}', 0x13e6f),
+    ('\u{5e3d}', 0x14b39),
+    ('\u{5e3e}', 0x22877),
+    ('\u{5e40}', 0x1566c),
+    ('\u{5e43}', 0x1566b),
+    ('\u{5e44}', 0x1566a),
+    ('\u{5e45}', 0x1497d),
+    ('\u{5e47}', 0x15673),
+    ('\u{5e49}', 0x22878),
+    ('\u{5e4c}', 0x14b5a),
+    ('\u{5e4e}', 0x1566d),
+    ('\u{5e54}', 0x1566f),
+    ('\u{5e55}', 0x14b6b),
+    ('\u{5e56}', 0x2287a),
+    ('\u{5e57}', 0x1566e),
+    ('\u{5e58}', 0x1742b),
+    ('\u{5e5e}', 0x1742c),
+    ('\u{5e5f}', 0x15670),
+    ('\u{5e61}', 0x14828),
+    ('\u{5e62}', 0x15671),
+    ('\u{5e63}', 0x14a3e),
+    ('\u{5e64}', 0x15672),
+    ('\u{5e6b}', 0x2287c),
+    ('\u{5e6c}', 0x2287d),
+    ('\u{5e6d}', 0x2287e),
+    ('\u{5e6e}', 0x22c21),
+    ('\u{5e72}', 0x13433),
+    ('\u{5e73}', 0x14a3f),
+    ('\u{5e74}', 0x1472f),
+    ('\u{5e75}', 0x15674),
+    ('\u{5e76}', 0x15675),
+    ('\u{5e77}', 0x17e7c),
+    ('\u{5e78}', 0x1392c),
+    ('\u{5e79}', 0x13434),
+    ('\u{5e7a}', 0x15676),
+    ('\u{5e7b}', 0x13838),
+    ('\u{5e7c}', 0x14d44),
+    ('\u{5e7d}', 0x14d29),
+    ('\u{5e7e}', 0x13476),
+    ('\u{5e7f}', 0x15678),
+    ('\u{5e81}', 0x14423),
+    ('\u{5e83}', 0x1392d),
+    ('\u{5e84}', 0x13e31),
+    ('\u{5e87}', 0x1485f),
+    ('\u{5e8a}', 0x13e32),
+    ('\u{5e8f}', 0x13d78),
+    ('\u{5e95}', 0x1446c),
+    ('\u{5e96}', 0x14a79),
+    ('\u{5e97}', 0x14539),
+    ('\u{5e9a}', 0x1392e),
+    ('\u{5e9c}', 0x1495c),
+    ('\u{5ea0}', 0x15679),
+    ('\u{5ea5}', 0x22c23),
+    ('\u{5ea6}', 0x14559),
+    ('\u{5ea7}', 0x13a42),
+    ('\u{5eaa}', 0x22c24),
+    ('\u{5eab}', 0x1384b),
+    ('\u{5eac}', 0x22c25),
+    ('\u{5ead}', 0x1446d),
+    ('\u{5eb5}', 0x13043),
+    ('\u{5eb6}', 0x13d6e),
+    ('\u{5eb7}', 0x1392f),
+    ('\u{5eb8}', 0x14d47),
+    ('\u{5eb9}', 0x22c26),
+    ('\u{5ebe}', 0x1742d),
+    ('\u{5ebf}', 0x22c27),
+    ('\u{5ec1}', 0x1567a),
+    ('\u{5ec2}', 0x1567b),
+    ('\u{5ec3}', 0x14751),
+    ('\u{5ec6}', 0x22c28),
+    ('\u{5ec8}', 0x1567c),
+    ('\u{5ec9}', 0x14e77),
+    ('\u{5eca}', 0x14f2d),
+    ('\u{5ecb}', 0x1742f),
+    ('\u{5ecf}', 0x1567e),
+    ('\u{5ed0}', 0x1567d),
+    ('\u{5ed2}', 0x22c29),
+    ('\u{5ed3}', 0x13347),
+    ('\u{5ed6}', 0x15721),
+    ('\u{5ed9}', 0x22c2a),
+    ('\u{5eda}', 0x15724),
+    ('\u{5edb}', 0x15725),
+    ('\u{5edd}', 0x15723),
+    ('\u{5edf}', 0x14940),
+    ('\u{5ee0}', 0x13e33),
+    ('\u{5ee1}', 0x15727),
+    ('\u{5ee2}', 0x15726),
+    ('\u{5ee3}', 0x15722),
+    ('\u{5ee8}', 0x15728),
+    ('\u{5ee9}', 0x15729),
+    ('\u{5eec}', 0x1572a),
+    ('\u{5ef0}', 0x1572d),
+    ('\u{5ef1}', 0x1572b),
+    ('\u{5ef3}', 0x1572c),
+    ('\u{5ef4}', 0x1572e),
+    ('\u{5ef6}', 0x13164),
+    ('\u{5ef7}', 0x1446e),
+    ('\u{5ef8}', 0x1572f),
+    ('\u{5ef9}', 0x17430),
+    ('\u{5efa}', 0x1377a),
+    ('\u{5efb}', 0x13276),
+    ('\u{5efc}', 0x14736),
+    ('\u{5efd}', 0x22c2c),
+    ('\u{5efe}', 0x15730),
+    ('\u{5eff}', 0x1467b),
+    ('\u{5f00}', 0x17431),
+    ('\u{5f01}', 0x14a5b),
+    ('\u{5f02}', 0x17432),
+    ('\u{5f03}', 0x15731),
+    ('\u{5f04}', 0x14f2e),
+    ('\u{5f07}', 0x17433),
+    ('\u{5f08}', 0x22c2d),
+    ('\u{5f09}', 0x15732),
+    ('\u{5f0a}', 0x14a40),
+    ('\u{5f0b}', 0x15735),
+    ('\u{5f0c}', 0x15021),
+    ('\u{5f0d}', 0x15031),
+    ('\u{5f0e}', 0x22c2e),
+    ('\u{5f0f}', 0x13c30),
+    ('\u{5f10}', 0x14675),
+    ('\u{5f11}', 0x15736),
+    ('\u{5f13}', 0x1355d),
+    ('\u{5f14}', 0x14424),
+    ('\u{5f15}', 0x1307a),
+    ('\u{5f16}', 0x15737),
+    ('\u{5f17}', 0x14a26),
+    ('\u{5f18}', 0x13930),
+    ('\u{5f1b}', 0x14350),
+    ('\u{5f1c}', 0x22c2f),
+    ('\u{5f1d}', 0x17434),
+    ('\u{5f1e}', 0x22c31),
+    ('\u{5f1f}', 0x1446f),
+    ('\u{5f23}', 0x17435),
+    ('\u{5f25}', 0x14c6f),
+    ('\u{5f26}', 0x13839),
+    ('\u{5f27}', 0x1384c),
+    ('\u{5f29}', 0x15738),
+    ('\u{5f2d}', 0x15739),
+    ('\u{5f2f}', 0x1573f),
+    ('\u{5f31}', 0x13c65),
+    ('\u{5f34}', 0x17436),
+    ('\u{5f35}', 0x14425),
+    ('\u{5f36}', 0x17437),
+    ('\u{5f37}', 0x1362f),
+    ('\u{5f38}', 0x1573a),
+    ('\u{5f3c}', 0x1492b),
+    ('\u{5f3d}', 0x17438),
+    ('\u{5f3e}', 0x14346),
+    ('\u{5f40}', 0x17439),
+    ('\u{5f41}', 0x1573b),
+    ('\u{5f45}', 0x1743a),
+    ('\u{5f47}', 0x22c32),
+    ('\u{5f48}', 0x1573c),
+    ('\u{5f4a}', 0x13630),
+    ('\u{5f4c}', 0x1573d),
+    ('\u{5f4e}', 0x1573e),
+    ('\u{5f51}', 0x15740),
+    ('\u{5f53}', 0x14576),
+    ('\u{5f54}', 0x1743b),
+    ('\u{5f56}', 0x15741),
+    ('\u{5f57}', 0x15742),
+    ('\u{5f58}', 0x1743c),
+    ('\u{5f59}', 0x15743),
+    ('\u{5f5c}', 0x15734),
+    ('\u{5f5d}', 0x15733),
+    ('\u{5f61}', 0x15744),
+    ('\u{5f62}', 0x13741),
+    ('\u{5f63}', 0x22c33),
+    ('\u{5f64}', 0x1743d),
+    ('\u{5f66}', 0x14927),
+    ('\u{5f67}', 0x1743e),
+    ('\u{5f69}', 0x13a4c),
+    ('\u{5f6a}', 0x14937),
+    ('\u{5f6b}', 0x14426),
+    ('\u{5f6c}', 0x1494b),
+    ('\u{5f6d}', 0x15745),
+    ('\u{5f70}', 0x13e34),
+    ('\u{5f71}', 0x13146),
+    ('\u{5f72}', 0x22c34),
+    ('\u{5f73}', 0x15746),
+    ('\u{5f77}', 0x15747),
+    ('\u{5f79}', 0x14c72),
+    ('\u{5f7c}', 0x14860),
+    ('\u{5f7d}', 0x1743f),
+    ('\u{5f7e}', 0x22c35),
+    ('\u{5f7f}', 0x1574a),
+    ('\u{5f80}', 0x1317d),
+    ('\u{5f81}', 0x1402c),
+    ('\u{5f82}', 0x15749),
+    ('\u{5f83}', 0x15748),
+    ('\u{5f84}', 0x13742),
+    ('\u{5f85}', 0x14254),
+    ('\u{5f87}', 0x1574e),
+    ('\u{5f88}', 0x1574c),
+    ('\u{5f89}', 0x17440),
+    ('\u{5f8a}', 0x1574b),
+    ('\u{5f8b}', 0x14e27),
+    ('\u{5f8c}', 0x13865),
+    ('\u{5f8f}', 0x22c36),
+    ('\u{5f90}', 0x13d79),
+    ('\u{5f91}', 0x1574d),
+    ('\u{5f92}', 0x1454c),
+    ('\u{5f93}', 0x13d3e),
+    ('\u{5f97}', 0x14640),
+    ('\u{5f98}', 0x15751),
+    ('\u{5f99}', 0x15750),
+    ('\u{5f9c}', 0x17441),
+    ('\u{5f9e}', 0x1574f),
+    ('\u{5fa0}', 0x15752),
+    ('\u{5fa1}', 0x13866),
+    ('\u{5fa2}', 0x22c37),
+    ('\u{5fa4}', 0x22c38),
+    ('\u{5fa7}', 0x17442),
+    ('\u{5fa8}', 0x15753),
+    ('\u{5fa9}', 0x1497c),
+    ('\u{5faa}', 0x13d5b),
+    ('\u{5fad}', 0x15754),
+    ('\u{5fae}', 0x14879),
+    ('\u{5faf}', 0x17443),
+    ('\u{5fb3}', 0x14641),
+    ('\u{5fb4}', 0x14427),
+    ('\u{5fb5}', 0x17444),
+    ('\u{5fb7}', 0x17445),
+    ('\u{5fb8}', 0x22c39),
+    ('\u{5fb9}', 0x14530),
+    ('\u{5fbc}', 0x15755),
+    ('\u{5fbd}', 0x1352b),
+    ('\u{5fc3}', 0x13f34),
+    ('\u{5fc4}', 0x22c3a),
+    ('\u{5fc5}', 0x1492c),
+    ('\u{5fc7}', 0x22c3c),
+    ('\u{5fc9}', 0x17446),
+    ('\u{5fcb}', 0x22c3d),
+    ('\u{5fcc}', 0x13477),
+    ('\u{5fcd}', 0x14726),
+    ('\u{5fd2}', 0x22c3e),
+    ('\u{5fd3}', 0x22c3f),
+    ('\u{5fd4}', 0x22c40),
+    ('\u{5fd6}', 0x15756),
+    ('\u{5fd7}', 0x13b56),
+    ('\u{5fd8}', 0x14b3a),
+    ('\u{5fd9}', 0x14b3b),
+    ('\u{5fdc}', 0x1317e),
+    ('\u{5fdd}', 0x1575b),
+    ('\u{5fde}', 0x17447),
+    ('\u{5fe0}', 0x14369),
+    ('\u{5fe1}', 0x17448),
+    ('\u{5fe2}', 0x22c41),
+    ('\u{5fe4}', 0x15758),
+    ('\u{5fe9}', 0x17449),
+    ('\u{5feb}', 0x13277),
+    ('\u{5fee}', 0x22c42),
+    ('\u{5fef}', 0x22c43),
+    ('\u{5ff0}', 0x1582d),
+    ('\u{5ff1}', 0x1575a),
+    ('\u{5ff3}', 0x22c44),
+    ('\u{5ff5}', 0x14730),
+    ('\u{5ff8}', 0x15759),
+    ('\u{5ffb}', 0x15757),
+    ('\u{5ffc}', 0x22c45),
+    ('\u{5ffd}', 0x1397a),
+    ('\u{5fff}', 0x1575d),
+    ('\u{600d}', 0x1744a),
+    ('\u{600e}', 0x15763),
+    ('\u{600f}', 0x15769),
+    ('\u{6010}', 0x15761),
+    ('\u{6012}', 0x1455c),
+    ('\u{6014}', 0x1744b),
+    ('\u{6015}', 0x15766),
+    ('\u{6016}', 0x1495d),
+    ('\u{6017}', 0x22c47),
+    ('\u{6018}', 0x1744c),
+    ('\u{6019}', 0x15760),
+    ('\u{601b}', 0x15765),
+    ('\u{601c}', 0x14e67),
+    ('\u{601d}', 0x13b57),
+    ('\u{6020}', 0x14255),
+    ('\u{6021}', 0x1575e),
+    ('\u{6022}', 0x22c48),
+    ('\u{6024}', 0x22c49),
+    ('\u{6025}', 0x1355e),
+    ('\u{6026}', 0x15768),
+    ('\u{6027}', 0x1402d),
+    ('\u{6028}', 0x13165),
+    ('\u{6029}', 0x15762),
+    ('\u{602a}', 0x13278),
+    ('\u{602b}', 0x15767),
+    ('\u{602f}', 0x13631),
+    ('\u{6031}', 0x15764),
+    ('\u{6033}', 0x1744d),
+    ('\u{6035}', 0x1744e),
+    ('\u{603a}', 0x1576a),
+    ('\u{6041}', 0x1576c),
+    ('\u{6042}', 0x15776),
+    ('\u{6043}', 0x15774),
+    ('\u{6046}', 0x15771),
+    ('\u{6047}', 0x1744f),
+    ('\u{604a}', 0x15770),
+    ('\u{604b}', 0x14e78),
+    ('\u{604c}', 0x22c4b),
+    ('\u{604d}', 0x15772),
+    ('\u{6050}', 0x13632),
+    ('\u{6052}', 0x13931),
+    ('\u{6055}', 0x13d7a),
+    ('\u{6059}', 0x15779),
+    ('\u{605a}', 0x1576b),
+    ('\u{605f}', 0x1576f),
+    ('\u{6060}', 0x1575f),
+    ('\u{6062}', 0x1327a),
+    ('\u{6063}', 0x15773),
+    ('\u{6064}', 0x15775),
+    ('\u{6065}', 0x14351),
+    ('\u{6068}', 0x13a28),
+    ('\u{6069}', 0x13238),
+    ('\u{606a}', 0x1576d),
+    ('\u{606b}', 0x15778),
+    ('\u{606c}', 0x15777),
+    ('\u{606d}', 0x13633),
+    ('\u{606f}', 0x14229),
+    ('\u{6070}', 0x13366),
+    ('\u{6075}', 0x13743),
+    ('\u{6077}', 0x1576e),
+    ('\u{607f}', 0x22c4c),
+    ('\u{6081}', 0x1577a),
+    ('\u{6083}', 0x1577d),
+    ('\u{6084}', 0x15821),
+    ('\u{6089}', 0x13c3d),
+    ('\u{608a}', 0x22c4d),
+    ('\u{608b}', 0x15827),
+    ('\u{608c}', 0x14470),
+    ('\u{608d}', 0x1577b),
+    ('\u{6092}', 0x15825),
+    ('\u{6094}', 0x13279),
+    ('\u{6095}', 0x22c4e),
+    ('\u{6096}', 0x15823),
+    ('\u{6097}', 0x15824),
+    ('\u{609a}', 0x1577e),
+    ('\u{609b}', 0x15822),
+    ('\u{609d}', 0x17451),
+    ('\u{609e}', 0x17452),
+    ('\u{609f}', 0x13867),
+    ('\u{60a0}', 0x14d2a),
+    ('\u{60a3}', 0x13435),
+    ('\u{60a6}', 0x13159),
+    ('\u{60a7}', 0x15826),
+    ('\u{60a8}', 0x22c4f),
+    ('\u{60a9}', 0x1473a),
+    ('\u{60aa}', 0x1302d),
+    ('\u{60b0}', 0x22c51),
+    ('\u{60b1}', 0x22c52),
+    ('\u{60b2}', 0x14861),
+    ('\u{60b3}', 0x1575c),
+    ('\u{60b4}', 0x1582c),
+    ('\u{60b5}', 0x15830),
+    ('\u{60b6}', 0x14c65),
+    ('\u{60b8}', 0x15829),
+    ('\u{60bc}', 0x14569),
+    ('\u{60bd}', 0x1582e),
+    ('\u{60be}', 0x22c53),
+    ('\u{60c5}', 0x13e70),
+    ('\u{60c6}', 0x1582f),
+    ('\u{60c7}', 0x14657),
+    ('\u{60c8}', 0x22c54),
+    ('\u{60cb}', 0x17453),
+    ('\u{60d1}', 0x14f47),
+    ('\u{60d3}', 0x1582b),
+    ('\u{60d4}', 0x17454),
+    ('\u{60d5}', 0x17455),
+    ('\u{60d8}', 0x15831),
+    ('\u{60d9}', 0x22c55),
+    ('\u{60da}', 0x1397b),
+    ('\u{60db}', 0x22c56),
+    ('\u{60dc}', 0x1404b),
+    ('\u{60dd}', 0x17456),
+    ('\u{60df}', 0x13054),
+    ('\u{60e0}', 0x1582a),
+    ('\u{60e1}', 0x15828),
+    ('\u{60e3}', 0x1415a),
+    ('\u{60e7}', 0x1577c),
+    ('\u{60e8}', 0x13b34),
+    ('\u{60ee}', 0x22c57),
+    ('\u{60f0}', 0x14246),
+    ('\u{60f1}', 0x1583d),
+    ('\u{60f2}', 0x22c58),
+    ('\u{60f3}', 0x1415b),
+    ('\u{60f4}', 0x15838),
+    ('\u{60f5}', 0x22c59),
+    ('\u{60f6}', 0x15835),
+    ('\u{60f7}', 0x15836),
+    ('\u{60f8}', 0x17457),
+    ('\u{60f9}', 0x13c66),
+    ('\u{60fa}', 0x15839),
+    ('\u{60fb}', 0x1583c),
+    ('\u{6100}', 0x15837),
+    ('\u{6101}', 0x13d25),
+    ('\u{6103}', 0x1583a),
+    ('\u{6106}', 0x15834),
+    ('\u{6108}', 0x14c7c),
+    ('\u{6109}', 0x14c7b),
+    ('\u{610d}', 0x1583e),
+    ('\u{610e}', 0x1583f),
+    ('\u{610f}', 0x13055),
+    ('\u{6110}', 0x22c5a),
+    ('\u{6112}', 0x22c5b),
+    ('\u{6113}', 0x22c5c),
+    ('\u{6115}', 0x15833),
+    ('\u{6119}', 0x22c5d),
+    ('\u{611a}', 0x13672),
+    ('\u{611b}', 0x13026),
+    ('\u{611c}', 0x17458),
+    ('\u{611e}', 0x22c5e),
+    ('\u{611f}', 0x13436),
+    ('\u{6121}', 0x1583b),
+    ('\u{6127}', 0x15843),
+    ('\u{6128}', 0x15842),
+    ('\u{612b}', 0x17459),
+    ('\u{612c}', 0x15847),
+    ('\u{6130}', 0x1745a),
+    ('\u{6134}', 0x15848),
+    ('\u{6137}', 0x1745b),
+    ('\u{613a}', 0x22c5f),
+    ('\u{613c}', 0x15846),
+    ('\u{613d}', 0x15849),
+    ('\u{613e}', 0x15841),
+    ('\u{613f}', 0x15845),
+    ('\u{6141}', 0x22c61),
+    ('\u{6142}', 0x1584a),
+    ('\u{6144}', 0x1584b),
+    ('\u{6146}', 0x22c62),
+    ('\u{6147}', 0x15840),
+    ('\u{6148}', 0x13b7c),
+    ('\u{614a}', 0x15844),
+    ('\u{614b}', 0x14256),
+    ('\u{614c}', 0x13932),
+    ('\u{614d}', 0x15832),
+    ('\u{614e}', 0x13f35),
+    ('\u{6153}', 0x15858),
+    ('\u{6155}', 0x14a69),
+    ('\u{6158}', 0x1584e),
+    ('\u{6159}', 0x1584f),
+    ('\u{615a}', 0x15850),
+    ('\u{615d}', 0x15857),
+    ('\u{615f}', 0x15856),
+    ('\u{6160}', 0x22c63),
+    ('\u{6162}', 0x14b7d),
+    ('\u{6163}', 0x13437),
+    ('\u{6165}', 0x15854),
+    ('\u{6167}', 0x13745),
+    ('\u{6168}', 0x13334),
+    ('\u{616b}', 0x15851),
+    ('\u{616e}', 0x14e38),
+    ('\u{616f}', 0x15853),
+    ('\u{6170}', 0x13056),
+    ('\u{6171}', 0x15855),
+    ('\u{6173}', 0x1584c),
+    ('\u{6174}', 0x15852),
+    ('\u{6175}', 0x15859),
+    ('\u{6176}', 0x13744),
+    ('\u{6177}', 0x1584d),
+    ('\u{617c}', 0x22c64),
+    ('\u{617e}', 0x14d5d),
+    ('\u{6182}', 0x14d2b),
+    ('\u{6187}', 0x1585c),
+    ('\u{618a}', 0x15860),
+    ('\u{618d}', 0x1745d),
+    ('\u{618e}', 0x1417e),
+    ('\u{6190}', 0x14e79),
+    ('\u{6191}', 0x15861),
+    ('\u{6192}', 0x22c66),
+    ('\u{6193}', 0x22c67),
+    ('\u{6194}', 0x1585e),
+    ('\u{6196}', 0x1585b),
+    ('\u{6197}', 0x22c68),
+    ('\u{6198}', 0x22c69),
+    ('\u{6199}', 0x1585a),
+    ('\u{619a}', 0x1585f),
+    ('\u{61a4}', 0x14a30),
+    ('\u{61a5}', 0x22c6a),
+    ('\u{61a7}', 0x14634),
+    ('\u{61a8}', 0x22c6b),
+    ('\u{61a9}', 0x13746),
+    ('\u{61ab}', 0x15862),
+    ('\u{61ac}', 0x1585d),
+    ('\u{61ad}', 0x22c6c),
+    ('\u{61ae}', 0x15863),
+    ('\u{61b2}', 0x1377b),
+    ('\u{61b6}', 0x13231),
+    ('\u{61b9}', 0x17460),
+    ('\u{61ba}', 0x1586b),
+    ('\u{61bc}', 0x1745f),
+    ('\u{61be}', 0x13438),
+    ('\u{61c3}', 0x15869),
+    ('\u{61c6}', 0x1586a),
+    ('\u{61c7}', 0x13a29),
+    ('\u{61c8}', 0x15868),
+    ('\u{61c9}', 0x15866),
+    ('\u{61ca}', 0x15865),
+    ('\u{61cb}', 0x1586c),
+    ('\u{61cc}', 0x15864),
+    ('\u{61cd}', 0x1586e),
+    ('\u{61d0}', 0x1327b),
+    ('\u{61d5}', 0x22c6e),
+    ('\u{61dd}', 0x22c6f),
+    ('\u{61df}', 0x22c70),
+    ('\u{61e3}', 0x15870),
+    ('\u{61e6}', 0x1586f),
+    ('\u{61f2}', 0x14428),
+    ('\u{61f4}', 0x15873),
+    ('\u{61f5}', 0x22c71),
+    ('\u{61f6}', 0x15871),
+    ('\u{61f7}', 0x15867),
+    ('\u{61f8}', 0x1377c),
+    ('\u{61fa}', 0x15872),
+    ('\u{61fc}', 0x15876),
+    ('\u{61fd}', 0x15875),
+    ('\u{61fe}', 0x15877),
+    ('\u{61ff}', 0x15874),
+    ('\u{6200}', 0x15878),
+    ('\u{6208}', 0x15879),
+    ('\u{6209}', 0x1587a),
+    ('\u{620a}', 0x14a6a),
+    ('\u{620c}', 0x1587c),
+    ('\u{620d}', 0x1587b),
+    ('\u{620e}', 0x13d3f),
+    ('\u{6210}', 0x1402e),
+    ('\u{6211}', 0x13266),
+    ('\u{6212}', 0x1327c),
+    ('\u{6214}', 0x1587d),
+    ('\u{6215}', 0x22c73),
+    ('\u{6216}', 0x1303f),
+    ('\u{621a}', 0x1404c),
+    ('\u{621b}', 0x1587e),
+    ('\u{621d}', 0x16c43),
+    ('\u{621e}', 0x15921),
+    ('\u{621f}', 0x13761),
+    ('\u{6221}', 0x15922),
+    ('\u{6222}', 0x17462),
+    ('\u{6223}', 0x22c74),
+    ('\u{6226}', 0x1406f),
+    ('\u{6229}', 0x22c75),
+    ('\u{622a}', 0x15923),
+    ('\u{622e}', 0x15924),
+    ('\u{622f}', 0x1353a),
+    ('\u{6230}', 0x15925),
+    ('\u{6232}', 0x15926),
+    ('\u{6233}', 0x15927),
+    ('\u{6234}', 0x14257),
+    ('\u{6238}', 0x1384d),
+    ('\u{623b}', 0x14c61),
+    ('\u{623e}', 0x17463),
+    ('\u{623f}', 0x14b3c),
+    ('\u{6240}', 0x13d6a),
+    ('\u{6241}', 0x15928),
+    ('\u{6243}', 0x17464),
+    ('\u{6246}', 0x22c76),
+    ('\u{6247}', 0x14070),
+    ('\u{6248}', 0x16e3d),
+    ('\u{6249}', 0x14862),
+    ('\u{624b}', 0x13c6a),
+    ('\u{624c}', 0x22c77),
+    ('\u{624d}', 0x13a4d),
+    ('\u{624e}', 0x15929),
+    ('\u{6251}', 0x22c78),
+    ('\u{6252}', 0x22c79),
+    ('\u{6253}', 0x14247),
+    ('\u{6255}', 0x14a27),
+    ('\u{6256}', 0x17465),
+    ('\u{6258}', 0x14271),
+    ('\u{625a}', 0x17466),
+    ('\u{625b}', 0x1592c),
+    ('\u{625e}', 0x1592a),
+    ('\u{6260}', 0x1592d),
+    ('\u{6261}', 0x22c7a),
+    ('\u{6263}', 0x1592b),
+    ('\u{6264}', 0x22c7b),
+    ('\u{6268}', 0x1592e),
+    ('\u{626d}', 0x22c7d),
+    ('\u{626e}', 0x14a31),
+    ('\u{626f}', 0x17467),
+    ('\u{6271}', 0x13037),
+    ('\u{6273}', 0x22c7e),
+    ('\u{6276}', 0x1495e),
+    ('\u{6279}', 0x14863),
+    ('\u{627b}', 0x22c7c),
+    ('\u{627c}', 0x1592f),
+    ('\u{627e}', 0x15932),
+    ('\u{627f}', 0x13e35),
+    ('\u{6280}', 0x1353b),
+    ('\u{6282}', 0x15930),
+    ('\u{6283}', 0x15937),
+    ('\u{6284}', 0x13e36),
+    ('\u{6285}', 0x17468),
+    ('\u{6289}', 0x15931),
+    ('\u{628a}', 0x14744),
+    ('\u{6291}', 0x14d5e),
+    ('\u{6292}', 0x15933),
+    ('\u{6293}', 0x15934),
+    ('\u{6294}', 0x15938),
+    ('\u{6295}', 0x1456a),
+    ('\u{6296}', 0x15935),
+    ('\u{6297}', 0x13933),
+    ('\u{6298}', 0x1405e),
+    ('\u{6299}', 0x22d21),
+    ('\u{629b}', 0x15946),
+    ('\u{629c}', 0x14834),
+    ('\u{629e}', 0x14272),
+    ('\u{62a6}', 0x22d22),
+    ('\u{62ab}', 0x14864),
+    ('\u{62ac}', 0x15a2d),
+    ('\u{62b1}', 0x14a7a),
+    ('\u{62b5}', 0x14471),
+    ('\u{62b9}', 0x14b75),
+    ('\u{62bb}', 0x1593b),
+    ('\u{62bc}', 0x13221),
+    ('\u{62bd}', 0x1436a),
+    ('\u{62c2}', 0x15944),
+    ('\u{62c4}', 0x17469),
+    ('\u{62c5}', 0x14334),
+    ('\u{62c6}', 0x1593e),
+    ('\u{62c7}', 0x15945),
+    ('\u{62c8}', 0x15940),
+    ('\u{62c9}', 0x15947),
+    ('\u{62ca}', 0x15943),
+    ('\u{62cc}', 0x15942),
+    ('\u{62cd}', 0x1476f),
+    ('\u{62cf}', 0x1593c),
+    ('\u{62d0}', 0x1327d),
+    ('\u{62d1}', 0x1593a),
+    ('\u{62d2}', 0x13571),
+    ('\u{62d3}', 0x14273),
+    ('\u{62d4}', 0x15936),
+    ('\u{62d5}', 0x22d23),
+    ('\u{62d6}', 0x1746a),
+    ('\u{62d7}', 0x15939),
+    ('\u{62d8}', 0x13934),
+    ('\u{62d9}', 0x1405b),
+    ('\u{62db}', 0x13e37),
+    ('\u{62dc}', 0x15941),
+    ('\u{62dd}', 0x14752),
+    ('\u{62e0}', 0x13572),
+    ('\u{62e1}', 0x13348),
+    ('\u{62ec}', 0x13367),
+    ('\u{62ed}', 0x13f21),
+    ('\u{62ee}', 0x15949),
+    ('\u{62ef}', 0x1594e),
+    ('\u{62f1}', 0x1594a),
+    ('\u{62f3}', 0x1377d),
+    ('\u{62f5}', 0x1594f),
+    ('\u{62f6}', 0x13b22),
+    ('\u{62f7}', 0x13969),
+    ('\u{62fc}', 0x1746b),
+    ('\u{62fd}', 0x22d25),
+    ('\u{62fe}', 0x13d26),
+    ('\u{62ff}', 0x1593d),
+    ('\u{6301}', 0x13b7d),
+    ('\u{6302}', 0x1594c),
+    ('\u{6303}', 0x22d26),
+    ('\u{6307}', 0x13b58),
+    ('\u{6308}', 0x1594d),
+    ('\u{6309}', 0x13044),
+    ('\u{630a}', 0x1746c),
+    ('\u{630c}', 0x15948),
+    ('\u{630d}', 0x22d27),
+    ('\u{6310}', 0x22d28),
+    ('\u{6311}', 0x14429),
+    ('\u{6318}', 0x1746d),
+    ('\u{6319}', 0x13573),
+    ('\u{631f}', 0x13634),
+    ('\u{6327}', 0x1594b),
+    ('\u{6328}', 0x13027),
+    ('\u{632b}', 0x13a43),
+    ('\u{632f}', 0x13f36),
+    ('\u{6332}', 0x22d2b),
+    ('\u{6335}', 0x22d2c),
+    ('\u{6339}', 0x1746e),
+    ('\u{633a}', 0x14472),
+    ('\u{633b}', 0x22d2d),
+    ('\u{633c}', 0x22d2e),
+    ('\u{633d}', 0x14854),
+    ('\u{633e}', 0x15951),
+    ('\u{633f}', 0x1415e),
+    ('\u{6341}', 0x22d2f),
+    ('\u{6343}', 0x1746f),
+    ('\u{6344}', 0x22d30),
+    ('\u{6349}', 0x1422a),
+    ('\u{634c}', 0x13b2b),
+    ('\u{634d}', 0x15952),
+    ('\u{634e}', 0x22d31),
+    ('\u{634f}', 0x15954),
+    ('\u{6350}', 0x15950),
+    ('\u{6355}', 0x14a61),
+    ('\u{6357}', 0x1443d),
+    ('\u{6359}', 0x22d33),
+    ('\u{635c}', 0x1415c),
+    ('\u{6365}', 0x17470),
+    ('\u{6367}', 0x14a7b),
+    ('\u{6368}', 0x13c4e),
+    ('\u{6369}', 0x15960),
+    ('\u{636b}', 0x1595f),
+    ('\u{636c}', 0x22d36),
+    ('\u{636e}', 0x13f78),
+    ('\u{6372}', 0x1377e),
+    ('\u{6376}', 0x15959),
+    ('\u{6377}', 0x13e39),
+    ('\u{637a}', 0x14668),
+    ('\u{637b}', 0x14731),
+    ('\u{637c}', 0x17471),
+    ('\u{6380}', 0x15957),
+    ('\u{6383}', 0x1415d),
+    ('\u{6384}', 0x22d37),
+    ('\u{6388}', 0x13c78),
+    ('\u{6389}', 0x1595c),
+    ('\u{638c}', 0x13e38),
+    ('\u{638e}', 0x15956),
+    ('\u{638f}', 0x1595b),
+    ('\u{6392}', 0x14753),
+    ('\u{6394}', 0x22d3a),
+    ('\u{6396}', 0x15955),
+    ('\u{6398}', 0x13721),
+    ('\u{6399}', 0x22d38),
+    ('\u{639b}', 0x1335d),
+    ('\u{639f}', 0x1595d),
+    ('\u{63a0}', 0x14e2b),
+    ('\u{63a1}', 0x13a4e),
+    ('\u{63a2}', 0x14335),
+    ('\u{63a3}', 0x1595a),
+    ('\u{63a5}', 0x1405c),
+    ('\u{63a7}', 0x13935),
+    ('\u{63a8}', 0x13f64),
+    ('\u{63a9}', 0x13166),
+    ('\u{63aa}', 0x1413c),
+    ('\u{63ab}', 0x15958),
+    ('\u{63ac}', 0x13545),
+    ('\u{63b2}', 0x13747),
+    ('\u{63b4}', 0x1444f),
+    ('\u{63b5}', 0x1595e),
+    ('\u{63bb}', 0x1415f),
+    ('\u{63bd}', 0x22d3b),
+    ('\u{63be}', 0x15961),
+    ('\u{63c0}', 0x15963),
+    ('\u{63c3}', 0x14237),
+    ('\u{63c4}', 0x15969),
+    ('\u{63c6}', 0x15964),
+    ('\u{63c9}', 0x15966),
+    ('\u{63cf}', 0x14941),
+    ('\u{63d0}', 0x14473),
+    ('\u{63d2}', 0x15967),
+    ('\u{63d4}', 0x22d3d),
+    ('\u{63d5}', 0x22d3e),
+    ('\u{63d6}', 0x14d2c),
+    ('\u{63da}', 0x14d48),
+    ('\u{63db}', 0x13439),
+    ('\u{63dc}', 0x22d3f),
+    ('\u{63e0}', 0x22d40),
+    ('\u{63e1}', 0x1302e),
+    ('\u{63e3}', 0x15965),
+    ('\u{63e5}', 0x17472),
+    ('\u{63e9}', 0x15962),
+    ('\u{63eb}', 0x22d41),
+    ('\u{63ec}', 0x22d42),
+    ('\u{63ed}', 0x17473),
+    ('\u{63ee}', 0x13478),
+    ('\u{63f2}', 0x22d43),
+    ('\u{63f4}', 0x13167),
+    ('\u{63f5}', 0x17474),
+    ('\u{63f6}', 0x15968),
+    ('\u{63f7}', 0x22d3c),
+    ('\u{63fa}', 0x14d49),
+    ('\u{6406}', 0x1596c),
+    ('\u{6409}', 0x22d44),
+    ('\u{640d}', 0x1423b),
+    ('\u{640f}', 0x15973),
+    ('\u{6410}', 0x17475),
+    ('\u{6413}', 0x1596d),
+    ('\u{6414}', 0x17476),
+    ('\u{6416}', 0x1596a),
+    ('\u{6417}', 0x15971),
+    ('\u{641c}', 0x15953),
+    ('\u{641e}', 0x22d45),
+    ('\u{6422}', 0x17477),
+    ('\u{6425}', 0x22d46),
+    ('\u{6426}', 0x1596e),
+    ('\u{6428}', 0x15972),
+    ('\u{6429}', 0x22d47),
+    ('\u{642c}', 0x14842),
+    ('\u{642d}', 0x1456b),
+    ('\u{642f}', 0x22d48),
+    ('\u{6434}', 0x1596b),
+    ('\u{6436}', 0x1596f),
+    ('\u{643a}', 0x13748),
+    ('\u{643e}', 0x13a71),
+    ('\u{6442}', 0x1405d),
+    ('\u{644e}', 0x15977),
+    ('\u{6451}', 0x17479),
+    ('\u{6458}', 0x14526),
+    ('\u{645a}', 0x22d49),
+    ('\u{645b}', 0x22d4a),
+    ('\u{645d}', 0x22d4b),
+    ('\u{6460}', 0x1747a),
+    ('\u{6467}', 0x15974),
+    ('\u{6469}', 0x14b60),
+    ('\u{646d}', 0x1747b),
+    ('\u{646f}', 0x15975),
+    ('\u{6473}', 0x22d4c),
+    ('\u{6476}', 0x15976),
+    ('\u{6478}', 0x14c4e),
+    ('\u{6479}', 0x17478),
+    ('\u{647a}', 0x14022),
+    ('\u{647d}', 0x22d4d),
+    ('\u{6483}', 0x13762),
+    ('\u{6487}', 0x22d4e),
+    ('\u{6488}', 0x1597d),
+    ('\u{6491}', 0x22d4f),
+    ('\u{6492}', 0x13b35),
+    ('\u{6493}', 0x1597a),
+    ('\u{6495}', 0x15979),
+    ('\u{649a}', 0x14732),
+    ('\u{649d}', 0x22d50),
+    ('\u{649e}', 0x14635),
+    ('\u{649f}', 0x22d51),
+    ('\u{64a4}', 0x14531),
+    ('\u{64a5}', 0x1597b),
+    ('\u{64a9}', 0x1597c),
+    ('\u{64ab}', 0x1496f),
+    ('\u{64ad}', 0x14745),
+    ('\u{64ae}', 0x13b23),
+    ('\u{64b0}', 0x14071),
+    ('\u{64b2}', 0x14b50),
+    ('\u{64b9}', 0x13349),
+    ('\u{64bb}', 0x15a25),
+    ('\u{64bc}', 0x1597e),
+    ('\u{64be}', 0x1747d),
+    ('\u{64bf}', 0x1747e),
+    ('\u{64c1}', 0x14d4a),
+    ('\u{64c2}', 0x15a27),
+    ('\u{64c4}', 0x17521),
+    ('\u{64c5}', 0x15a23),
+    ('\u{64c7}', 0x15a24),
+    ('\u{64ca}', 0x17522),
+    ('\u{64cb}', 0x22d52),
+    ('\u{64cc}', 0x22d53),
+    ('\u{64cd}', 0x14160),
+    ('\u{64ce}', 0x1747c),
+    ('\u{64d0}', 0x17523),
+    ('\u{64d2}', 0x15a22),
+    ('\u{64d4}', 0x1593f),
+    ('\u{64d5}', 0x22d54),
+    ('\u{64d7}', 0x22d55),
+    ('\u{64d8}', 0x15a26),
+    ('\u{64da}', 0x15a21),
+    ('\u{64e0}', 0x15a2b),
+    ('\u{64e1}', 0x15a2c),
+    ('\u{64e2}', 0x14527),
+    ('\u{64e3}', 0x15a2e),
+    ('\u{64e4}', 0x22d57),
+    ('\u{64e5}', 0x22d58),
+    ('\u{64e6}', 0x13b24),
+    ('\u{64e7}', 0x15a29),
+    ('\u{64ec}', 0x1353c),
+    ('\u{64ef}', 0x15a2f),
+    ('\u{64f1}', 0x15a28),
+    ('\u{64f2}', 0x15a33),
+    ('\u{64f4}', 0x15a32),
+    ('\u{64f6}', 0x15a31),
+    ('\u{64f7}', 0x17524),
+    ('\u{64fa}', 0x15a34),
+    ('\u{64fb}', 0x17525),
+    ('\u{64fd}', 0x15a36),
+    ('\u{64fe}', 0x13e71),
+    ('\u{64ff}', 0x22d59),
+    ('\u{6500}', 0x15a35),
+    ('\u{6504}', 0x22d5a),
+    ('\u{6505}', 0x15a39),
+    ('\u{650f}', 0x22d5c),
+    ('\u{6514}', 0x22d5d),
+    ('\u{6516}', 0x22d5e),
+    ('\u{6518}', 0x15a37),
+    ('\u{651c}', 0x15a38),
+    ('\u{651d}', 0x15970),
+    ('\u{651e}', 0x22d60),
+    ('\u{6522}', 0x17526),
+    ('\u{6523}', 0x15a3b),
+    ('\u{6524}', 0x15a3a),
+    ('\u{6529}', 0x17527),
+    ('\u{652a}', 0x15978),
+    ('\u{652b}', 0x15a3c),
+    ('\u{652c}', 0x15a30),
+    ('\u{652f}', 0x13b59),
+    ('\u{6532}', 0x22d61),
+    ('\u{6534}', 0x15a3d),
+    ('\u{6535}', 0x15a3e),
+    ('\u{6536}', 0x15a40),
+    ('\u{6537}', 0x15a3f),
+    ('\u{6538}', 0x15a41),
+    ('\u{6539}', 0x1327e),
+    ('\u{653b}', 0x13936),
+    ('\u{653e}', 0x14a7c),
+    ('\u{653f}', 0x1402f),
+    ('\u{6544}', 0x22d62),
+    ('\u{6545}', 0x1384e),
+    ('\u{6548}', 0x15a43),
+    ('\u{654d}', 0x15a46),
+    ('\u{654f}', 0x14952),
+    ('\u{6551}', 0x1355f),
+    ('\u{6554}', 0x22d63),
+    ('\u{6555}', 0x15a45),
+    ('\u{6556}', 0x15a44),
+    ('\u{6557}', 0x14754),
+    ('\u{6558}', 0x15a47),
+    ('\u{6559}', 0x13635),
+    ('\u{655d}', 0x15a49),
+    ('\u{655e}', 0x15a48),
+    ('\u{6562}', 0x1343a),
+    ('\u{6563}', 0x13b36),
+    ('\u{6566}', 0x14658),
+    ('\u{6567}', 0x17529),
+    ('\u{656b}', 0x22d64),
+    ('\u{656c}', 0x13749),
+    ('\u{6570}', 0x13f74),
+    ('\u{6572}', 0x15a4a),
+    ('\u{6574}', 0x14030),
+    ('\u{6575}', 0x14528),
+    ('\u{6577}', 0x1495f),
+    ('\u{6578}', 0x15a4b),
+    ('\u{657a}', 0x22d65),
+    ('\u{6581}', 0x22d66),
+    ('\u{6582}', 0x15a4c),
+    ('\u{6583}', 0x15a4d),
+    ('\u{6584}', 0x22d67),
+    ('\u{6585}', 0x22d68),
+    ('\u{6587}', 0x14a38),
+    ('\u{6588}', 0x1555d),
+    ('\u{6589}', 0x14046),
+    ('\u{658a}', 0x22d69),
+    ('\u{658c}', 0x1494c),
+    ('\u{658e}', 0x13a58),
+    ('\u{6590}', 0x14865),
+    ('\u{6591}', 0x14843),
+    ('\u{6597}', 0x1454d),
+    ('\u{6599}', 0x14e41),
+    ('\u{659b}', 0x15a4f),
+    ('\u{659c}', 0x13c50),
+    ('\u{659d}', 0x1752a),
+    ('\u{659f}', 0x15a50),
+    ('\u{65a1}', 0x13036),
+    ('\u{65a4}', 0x13654),
+    ('\u{65a5}', 0x1404d),
+    ('\u{65a7}', 0x14960),
+    ('\u{65ab}', 0x15a51),
+    ('\u{65ac}', 0x13b42),
+    ('\u{65ad}', 0x14347),
+    ('\u{65af}', 0x13b5b),
+    ('\u{65b0}', 0x13f37),
+    ('\u{65b2}', 0x22d6a),
+    ('\u{65b5}', 0x22d6b),
+    ('\u{65b7}', 0x15a52),
+    ('\u{65b8}', 0x22d6c),
+    ('\u{65b9}', 0x14a7d),
+    ('\u{65bc}', 0x13177),
+    ('\u{65bd}', 0x13b5c),
+    ('\u{65bf}', 0x22d6d),
+    ('\u{65c1}', 0x15a55),
+    ('\u{65c2}', 0x22d6e),
+    ('\u{65c3}', 0x15a53),
+    ('\u{65c4}', 0x15a56),
+    ('\u{65c5}', 0x14e39),
+    ('\u{65c6}', 0x15a54),
+    ('\u{65c9}', 0x22d6f),
+    ('\u{65cb}', 0x1407b),
+    ('\u{65cc}', 0x15a57),
+    ('\u{65cf}', 0x14232),
+    ('\u{65d2}', 0x15a58),
+    ('\u{65d4}', 0x22d70),
+    ('\u{65d7}', 0x1347a),
+    ('\u{65d9}', 0x15a5a),
+    ('\u{65db}', 0x15a59),
+    ('\u{65e0}', 0x15a5b),
+    ('\u{65e1}', 0x15a5c),
+    ('\u{65e2}', 0x1347b),
+    ('\u{65e5}', 0x1467c),
+    ('\u{65e6}', 0x14336),
+    ('\u{65e7}', 0x1356c),
+    ('\u{65e8}', 0x13b5d),
+    ('\u{65e9}', 0x14161),
+    ('\u{65ec}', 0x13d5c),
+    ('\u{65ed}', 0x13030),
+    ('\u{65f1}', 0x15a5d),
+    ('\u{65f2}', 0x22d72),
+    ('\u{65f9}', 0x22d73),
+    ('\u{65fa}', 0x13222),
+    ('\u{65fb}', 0x15a61),
+    ('\u{65fc}', 0x22d74),
+    ('\u{6600}', 0x1752c),
+    ('\u{6602}', 0x13937),
+    ('\u{6603}', 0x15a60),
+    ('\u{6604}', 0x22d75),
+    ('\u{6606}', 0x13a2b),
+    ('\u{6607}', 0x13e3a),
+    ('\u{6608}', 0x22d76),
+    ('\u{6609}', 0x1752d),
+    ('\u{660a}', 0x15a5f),
+    ('\u{660c}', 0x13e3b),
+    ('\u{660e}', 0x14c40),
+    ('\u{660f}', 0x13a2a),
+    ('\u{6613}', 0x13057),
+    ('\u{6614}', 0x1404e),
+    ('\u{6615}', 0x1752e),
+    ('\u{661c}', 0x15a66),
+    ('\u{661e}', 0x1752f),
+    ('\u{661f}', 0x14031),
+    ('\u{6620}', 0x13147),
+    ('\u{6621}', 0x22d77),
+    ('\u{6622}', 0x17531),
+    ('\u{6624}', 0x17532),
+    ('\u{6625}', 0x13d55),
+    ('\u{6627}', 0x14b66),
+    ('\u{6628}', 0x13a72),
+    ('\u{662a}', 0x22d78),
+    ('\u{662b}', 0x17533),
+    ('\u{662d}', 0x13e3c),
+    ('\u{662f}', 0x14027),
+    ('\u{6630}', 0x17534),
+    ('\u{6631}', 0x17535),
+    ('\u{6633}', 0x17536),
+    ('\u{6634}', 0x15a65),
+    ('\u{6635}', 0x15a63),
+    ('\u{6636}', 0x15a64),
+    ('\u{663a}', 0x17530),
+    ('\u{663c}', 0x1436b),
+    ('\u{663f}', 0x15b26),
+    ('\u{6641}', 0x15a6a),
+    ('\u{6642}', 0x13b7e),
+    ('\u{6643}', 0x13938),
+    ('\u{6644}', 0x15a68),
+    ('\u{6645}', 0x22d79),
+    ('\u{6648}', 0x17538),
+    ('\u{6649}', 0x15a69),
+    ('\u{664b}', 0x13f38),
+    ('\u{664c}', 0x17539),
+    ('\u{664e}', 0x22d7b),
+    ('\u{664f}', 0x15a67),
+    ('\u{6651}', 0x22d7a),
+    ('\u{6652}', 0x13b2f),
+    ('\u{6657}', 0x22d7e),
+    ('\u{6659}', 0x1753b),
+    ('\u{665a}', 0x1753c),
+    ('\u{665b}', 0x22e21),
+    ('\u{665d}', 0x15a6c),
+    ('\u{665e}', 0x15a6b),
+    ('\u{665f}', 0x15a70),
+    ('\u{6661}', 0x1753d),
+    ('\u{6662}', 0x15a71),
+    ('\u{6663}', 0x22e22),
+    ('\u{6664}', 0x15a6d),
+    ('\u{6665}', 0x1753e),
+    ('\u{6666}', 0x13322),
+    ('\u{6667}', 0x15a6e),
+    ('\u{6668}', 0x15a6f),
+    ('\u{6669}', 0x14855),
+    ('\u{666a}', 0x22e25),
+    ('\u{666b}', 0x22e26),
+    ('\u{666c}', 0x22e27),
+    ('\u{666d}', 0x22e28),
+    ('\u{666e}', 0x14961),
+    ('\u{666f}', 0x1374a),
+    ('\u{6670}', 0x15a72),
+    ('\u{6673}', 0x1753f),
+    ('\u{6674}', 0x14032),
+    ('\u{6676}', 0x13e3d),
+    ('\u{6677}', 0x17540),
+    ('\u{6678}', 0x17541),
+    ('\u{667a}', 0x14352),
+    ('\u{667b}', 0x22e29),
+    ('\u{6680}', 0x22e2a),
+    ('\u{6681}', 0x13647),
+    ('\u{6683}', 0x15a73),
+    ('\u{6684}', 0x15a77),
+    ('\u{6687}', 0x1324b),
+    ('\u{6688}', 0x15a74),
+    ('\u{6689}', 0x15a76),
+    ('\u{668d}', 0x17542),
+    ('\u{668e}', 0x15a75),
+    ('\u{6690}', 0x22e2b),
+    ('\u{6691}', 0x13d6b),
+    ('\u{6692}', 0x22e2c),
+    ('\u{6696}', 0x14348),
+    ('\u{6697}', 0x13045),
+    ('\u{6698}', 0x15a78),
+    ('\u{6699}', 0x22e2d),
+    ('\u{669d}', 0x15a79),
+    ('\u{66a0}', 0x17544),
+    ('\u{66a2}', 0x1442a),
+    ('\u{66a6}', 0x14e71),
+    ('\u{66ab}', 0x13b43),
+    ('\u{66ad}', 0x22e2f),
+    ('\u{66ae}', 0x14a6b),
+    ('\u{66b1}', 0x22e30),
+    ('\u{66b2}', 0x17545),
+    ('\u{66b4}', 0x14b3d),
+    ('\u{66b5}', 0x22e31),
+    ('\u{66b8}', 0x15b22),
+    ('\u{66b9}', 0x15a7b),
+    ('\u{66bb}', 0x17546),
+    ('\u{66bc}', 0x15a7e),
+    ('\u{66be}', 0x15a7d),
+    ('\u{66bf}', 0x22e33),
+    ('\u{66c1}', 0x15a7a),
+    ('\u{66c4}', 0x15b21),
+    ('\u{66c6}', 0x17547),
+    ('\u{66c7}', 0x1465e),
+    ('\u{66c8}', 0x17548),
+    ('\u{66c9}', 0x15a7c),
+    ('\u{66d6}', 0x15b23),
+    ('\u{66d9}', 0x13d6c),
+    ('\u{66da}', 0x15b24),
+    ('\u{66db}', 0x1754a),
+    ('\u{66dc}', 0x14d4b),
+    ('\u{66dd}', 0x14778),
+    ('\u{66e0}', 0x15b25),
+    ('\u{66e6}', 0x15b27),
+    ('\u{66e8}', 0x1754b),
+    ('\u{66e9}', 0x15b28),
+    ('\u{66ec}', 0x22e35),
+    ('\u{66f0}', 0x15b29),
+    ('\u{66f2}', 0x1364a),
+    ('\u{66f3}', 0x13148),
+    ('\u{66f4}', 0x13939),
+    ('\u{66f5}', 0x15b2a),
+    ('\u{66f7}', 0x15b2b),
+    ('\u{66f8}', 0x13d71),
+    ('\u{66f9}', 0x14162),
+    ('\u{66fa}', 0x1754c),
+    ('\u{66fb}', 0x17537),
+    ('\u{66fc}', 0x15258),
+    ('\u{66fd}', 0x1413e),
+    ('\u{66fe}', 0x1413d),
+    ('\u{66ff}', 0x14258),
+    ('\u{6700}', 0x13a47),
+    ('\u{6701}', 0x22e37),
+    ('\u{6703}', 0x15072),
+    ('\u{6705}', 0x22e38),
+    ('\u{6708}', 0x1376e),
+    ('\u{6709}', 0x14d2d),
+    ('\u{670b}', 0x14a7e),
+    ('\u{670d}', 0x1497e),
+    ('\u{670f}', 0x15b2c),
+    ('\u{6712}', 0x22e39),
+    ('\u{6713}', 0x1754d),
+    ('\u{6714}', 0x13a73),
+    ('\u{6715}', 0x1443f),
+    ('\u{6716}', 0x15b2d),
+    ('\u{6717}', 0x14f2f),
+    ('\u{6719}', 0x22e3b),
+    ('\u{671b}', 0x14b3e),
+    ('\u{671d}', 0x1442b),
+    ('\u{671e}', 0x15b2e),
+    ('\u{671f}', 0x1347c),
+    ('\u{6726}', 0x15b2f),
+    ('\u{6727}', 0x15b30),
+    ('\u{6728}', 0x14c5a),
+    ('\u{672a}', 0x14c24),
+    ('\u{672b}', 0x14b76),
+    ('\u{672c}', 0x14b5c),
+    ('\u{672d}', 0x13b25),
+    ('\u{672e}', 0x15b32),
+    ('\u{6731}', 0x13c6b),
+    ('\u{6733}', 0x1754f),
+    ('\u{6734}', 0x14b51),
+    ('\u{6736}', 0x15b34),
+    ('\u{6737}', 0x15b37),
+    ('\u{6738}', 0x15b36),
+    ('\u{673a}', 0x13479),
+    ('\u{673d}', 0x13560),
+    ('\u{673f}', 0x15b33),
+    ('\u{6741}', 0x15b35),
+    ('\u{6746}', 0x15b38),
+    ('\u{6747}', 0x17551),
+    ('\u{6748}', 0x17552),
+    ('\u{6749}', 0x13f79),
+    ('\u{674c}', 0x22e3e),
+    ('\u{674d}', 0x22e3f),
+    ('\u{674e}', 0x14d7b),
+    ('\u{674f}', 0x13049),
+    ('\u{6750}', 0x13a60),
+    ('\u{6751}', 0x1423c),
+    ('\u{6753}', 0x13c5d),
+    ('\u{6754}', 0x22e40),
+    ('\u{6756}', 0x13e73),
+    ('\u{6759}', 0x15b3b),
+    ('\u{675c}', 0x1454e),
+    ('\u{675d}', 0x22e41),
+    ('\u{675e}', 0x15b39),
+    ('\u{675f}', 0x1422b),
+    ('\u{6760}', 0x15b3a),
+    ('\u{6761}', 0x13e72),
+    ('\u{6762}', 0x14c5d),
+    ('\u{6763}', 0x15b3c),
+    ('\u{6764}', 0x15b3d),
+    ('\u{6765}', 0x14d68),
+    ('\u{6766}', 0x17550),
+    ('\u{676a}', 0x15b42),
+    ('\u{676d}', 0x1393a),
+    ('\u{676f}', 0x14755),
+    ('\u{6770}', 0x15b3f),
+    ('\u{6771}', 0x1456c),
+    ('\u{6772}', 0x15a5e),
+    ('\u{6773}', 0x15a62),
+    ('\u{6774}', 0x22e45),
+    ('\u{6775}', 0x1354f),
+    ('\u{6776}', 0x22e46),
+    ('\u{6777}', 0x14747),
+    ('\u{677b}', 0x17553),
+    ('\u{677c}', 0x15b41),
+    ('\u{677e}', 0x13e3e),
+    ('\u{677f}', 0x14844),
+    ('\u{6781}', 0x17554),
+    ('\u{6785}', 0x15b47),
+    ('\u{6787}', 0x1487a),
+    ('\u{6789}', 0x15b3e),
+    ('\u{678b}', 0x15b44),
+    ('\u{678c}', 0x15b43),
+    ('\u{6790}', 0x1404f),
+    ('\u{6792}', 0x22e48),
+    ('\u{6793}', 0x17555),
+    ('\u{6795}', 0x14b6d),
+    ('\u{6797}', 0x14e53),
+    ('\u{6798}', 0x17556),
+    ('\u{679a}', 0x14b67),
+    ('\u{679b}', 0x17557),
+    ('\u{679c}', 0x1324c),
+    ('\u{679d}', 0x13b5e),
+    ('\u{67a0}', 0x14f48),
+    ('\u{67a1}', 0x15b46),
+    ('\u{67a2}', 0x13f75),
+    ('\u{67a6}', 0x15b45),
+    ('\u{67a9}', 0x15b40),
+    ('\u{67af}', 0x1384f),
+    ('\u{67b0}', 0x22e4c),
+    ('\u{67b2}', 0x22e4d),
+    ('\u{67b3}', 0x15b4c),
+    ('\u{67b4}', 0x15b4a),
+    ('\u{67b6}', 0x1324d),
+    ('\u{67b7}', 0x15b48),
+    ('\u{67b8}', 0x15b4e),
+    ('\u{67b9}', 0x15b54),
+    ('\u{67bb}', 0x17558),
+    ('\u{67c0}', 0x1755a),
+    ('\u{67c1}', 0x14248),
+    ('\u{67c3}', 0x22e4e),
+    ('\u{67c4}', 0x14a41),
+    ('\u{67c6}', 0x15b56),
+    ('\u{67c8}', 0x22e4f),
+    ('\u{67ca}', 0x14922),
+    ('\u{67ce}', 0x15b55),
+    ('\u{67cf}', 0x14770),
+    ('\u{67d0}', 0x14b3f),
+    ('\u{67d1}', 0x1343b),
+    ('\u{67d2}', 0x22e50),
+    ('\u{67d3}', 0x14077),
+    ('\u{67d4}', 0x13d40),
+    ('\u{67d7}', 0x1755b),
+    ('\u{67d8}', 0x14453),
+    ('\u{67d9}', 0x22e51),
+    ('\u{67da}', 0x14d2e),
+    ('\u{67db}', 0x22e52),
+    ('\u{67dd}', 0x15b51),
+    ('\u{67de}', 0x15b50),
+    ('\u{67e2}', 0x15b52),
+    ('\u{67e4}', 0x15b4f),
+    ('\u{67e7}', 0x15b57),
+    ('\u{67e9}', 0x15b4d),
+    ('\u{67ec}', 0x15b4b),
+    ('\u{67ee}', 0x15b53),
+    ('\u{67ef}', 0x15b49),
+    ('\u{67f0}', 0x22e53),
+    ('\u{67f1}', 0x1436c),
+    ('\u{67f3}', 0x14c78),
+    ('\u{67f4}', 0x13c46),
+    ('\u{67f5}', 0x13a74),
+    ('\u{67f7}', 0x22e54),
+    ('\u{67f9}', 0x17559),
+    ('\u{67fb}', 0x13a3a),
+    ('\u{67fc}', 0x1755c),
+    ('\u{67fe}', 0x14b6f),
+    ('\u{67ff}', 0x13341),
+    ('\u{6801}', 0x1755d),
+    ('\u{6802}', 0x1444e),
+    ('\u{6803}', 0x1464a),
+    ('\u{6804}', 0x13149),
+    ('\u{6810}', 0x22e4b),
+    ('\u{6813}', 0x14072),
+    ('\u{6816}', 0x14034),
+    ('\u{6817}', 0x1372a),
+    ('\u{6818}', 0x22e58),
+    ('\u{681d}', 0x1755f),
+    ('\u{681e}', 0x15b59),
+    ('\u{681f}', 0x22e59),
+    ('\u{6821}', 0x1393b),
+    ('\u{6822}', 0x1337c),
+    ('\u{6829}', 0x15b5b),
+    ('\u{682a}', 0x13374),
+    ('\u{682b}', 0x15b61),
+    ('\u{682c}', 0x17560),
+    ('\u{682d}', 0x22e5a),
+    ('\u{6831}', 0x17561),
+    ('\u{6832}', 0x15b5e),
+    ('\u{6833}', 0x22e5c),
+    ('\u{6834}', 0x14073),
+    ('\u{6838}', 0x1334b),
+    ('\u{6839}', 0x13a2c),
+    ('\u{683b}', 0x22e5d),
+    ('\u{683c}', 0x1334a),
+    ('\u{683d}', 0x13a4f),
+    ('\u{683e}', 0x22e5e),
+    ('\u{6840}', 0x15b5c),
+    ('\u{6841}', 0x13765),
+    ('\u{6842}', 0x1374b),
+    ('\u{6843}', 0x1456d),
+    ('\u{6844}', 0x22e5f),
+    ('\u{6845}', 0x22e60),
+    ('\u{6846}', 0x15b5a),
+    ('\u{6848}', 0x13046),
+    ('\u{6849}', 0x22e61),
+    ('\u{684c}', 0x22e62),
+    ('\u{684d}', 0x15b5d),
+    ('\u{684e}', 0x15b5f),
+    ('\u{6850}', 0x1364d),
+    ('\u{6851}', 0x1372c),
+    ('\u{6852}', 0x1755e),
+    ('\u{6853}', 0x1343c),
+    ('\u{6854}', 0x1354b),
+    ('\u{6855}', 0x22e63),
+    ('\u{6857}', 0x22e64),
+    ('\u{6859}', 0x15b62),
+    ('\u{685b}', 0x17562),
+    ('\u{685c}', 0x13a79),
+    ('\u{685d}', 0x14b71),
+    ('\u{685f}', 0x13b37),
+    ('\u{6863}', 0x15b63),
+    ('\u{6867}', 0x14930),
+    ('\u{686b}', 0x22e66),
+    ('\u{686e}', 0x22e67),
+    ('\u{6872}', 0x17563),
+    ('\u{6874}', 0x15b6f),
+    ('\u{6875}', 0x17564),
+    ('\u{6876}', 0x13233),
+    ('\u{6877}', 0x15b64),
+    ('\u{687a}', 0x22e68),
+    ('\u{687c}', 0x22e69),
+    ('\u{687e}', 0x15b75),
+    ('\u{687f}', 0x15b65),
+    ('\u{6881}', 0x14e42),
+    ('\u{6882}', 0x22e6a),
+    ('\u{6883}', 0x15b6c),
+    ('\u{6885}', 0x1475f),
+    ('\u{688d}', 0x15b74),
+    ('\u{688f}', 0x15b67),
+    ('\u{6890}', 0x22e6b),
+    ('\u{6893}', 0x13034),
+    ('\u{6894}', 0x15b69),
+    ('\u{6896}', 0x22e6c),
+    ('\u{6897}', 0x1393c),
+    ('\u{6898}', 0x22e6e),
+    ('\u{6899}', 0x22e6f),
+    ('\u{689a}', 0x22e70),
+    ('\u{689b}', 0x15b6b),
+    ('\u{689c}', 0x22e71),
+    ('\u{689d}', 0x15b6a),
+    ('\u{689f}', 0x15b66),
+    ('\u{68a0}', 0x15b71),
+    ('\u{68a2}', 0x13e3f),
+    ('\u{68a3}', 0x17566),
+    ('\u{68a5}', 0x17567),
+    ('\u{68a6}', 0x1546d),
+    ('\u{68a7}', 0x13868),
+    ('\u{68a8}', 0x14d7c),
+    ('\u{68aa}', 0x22e72),
+    ('\u{68ab}', 0x22e73),
+    ('\u{68ad}', 0x15b68),
+    ('\u{68af}', 0x14474),
+    ('\u{68b0}', 0x13323),
+    ('\u{68b1}', 0x13a2d),
+    ('\u{68b2}', 0x17568),
+    ('\u{68b3}', 0x15b60),
+    ('\u{68b4}', 0x22e74),
+    ('\u{68b5}', 0x15b70),
+    ('\u{68b6}', 0x13361),
+    ('\u{68b9}', 0x15b6e),
+    ('\u{68ba}', 0x15b72),
+    ('\u{68bb}', 0x22e75),
+    ('\u{68bc}', 0x1456e),
+    ('\u{68c3}', 0x22e7a),
+    ('\u{68c4}', 0x1347e),
+    ('\u{68c5}', 0x22e7b),
+    ('\u{68c6}', 0x15c32),
+    ('\u{68c8}', 0x17569),
+    ('\u{68c9}', 0x14c49),
+    ('\u{68ca}', 0x15b77),
+    ('\u{68cb}', 0x1347d),
+    ('\u{68cc}', 0x22e7c),
+    ('\u{68cd}', 0x15b7e),
+    ('\u{68cf}', 0x22e7d),
+    ('\u{68d0}', 0x1756a),
+    ('\u{68d2}', 0x14b40),
+    ('\u{68d4}', 0x15c21),
+    ('\u{68d5}', 0x15c23),
+    ('\u{68d6}', 0x22e7e),
+    ('\u{68d7}', 0x15c27),
+    ('\u{68d8}', 0x15b79),
+    ('\u{68d9}', 0x22f21),
+    ('\u{68da}', 0x1432a),
+    ('\u{68df}', 0x1456f),
+    ('\u{68e0}', 0x15c2b),
+    ('\u{68e1}', 0x15b7c),
+    ('\u{68e3}', 0x15c28),
+    ('\u{68e4}', 0x22f22),
+    ('\u{68e5}', 0x22f23),
+    ('\u{68e7}', 0x15c22),
+    ('\u{68e8}', 0x1756b),
+    ('\u{68ec}', 0x22f24),
+    ('\u{68ed}', 0x1756c),
+    ('\u{68ee}', 0x13f39),
+    ('\u{68ef}', 0x15c2c),
+    ('\u{68f0}', 0x1756d),
+    ('\u{68f1}', 0x1756e),
+    ('\u{68f2}', 0x14033),
+    ('\u{68f7}', 0x22f25),
+    ('\u{68f9}', 0x15c2a),
+    ('\u{68fa}', 0x1343d),
+    ('\u{68fb}', 0x22e76),
+    ('\u{68fc}', 0x1756f),
+    ('\u{6900}', 0x14f50),
+    ('\u{6901}', 0x15b76),
+    ('\u{6903}', 0x22f26),
+    ('\u{6904}', 0x15c26),
+    ('\u{6905}', 0x13058),
+    ('\u{6907}', 0x22f27),
+    ('\u{6908}', 0x15b78),
+    ('\u{690a}', 0x17570),
+    ('\u{690b}', 0x14c3a),
+    ('\u{690c}', 0x15b7d),
+    ('\u{690d}', 0x13f22),
+    ('\u{690e}', 0x14447),
+    ('\u{690f}', 0x15b73),
+    ('\u{6912}', 0x15c25),
+    ('\u{6919}', 0x13f7a),
+    ('\u{691a}', 0x15c2f),
+    ('\u{691b}', 0x13371),
+    ('\u{691c}', 0x13821),
+    ('\u{6921}', 0x15c31),
+    ('\u{6922}', 0x15b7a),
+    ('\u{6923}', 0x15c30),
+    ('\u{6925}', 0x15c29),
+    ('\u{6926}', 0x15b7b),
+    ('\u{6928}', 0x15c2d),
+    ('\u{692a}', 0x15c2e),
+    ('\u{6930}', 0x15c3f),
+    ('\u{6934}', 0x1464e),
+    ('\u{6935}', 0x17573),
+    ('\u{6936}', 0x15c24),
+    ('\u{6939}', 0x15c3b),
+    ('\u{693b}', 0x22f2b),
+    ('\u{693d}', 0x15c3d),
+    ('\u{693f}', 0x14458),
+    ('\u{6942}', 0x17574),
+    ('\u{6946}', 0x22f2d),
+    ('\u{6949}', 0x17571),
+    ('\u{694a}', 0x14d4c),
+    ('\u{6953}', 0x14976),
+    ('\u{6954}', 0x15c38),
+    ('\u{6955}', 0x1424a),
+    ('\u{6957}', 0x17575),
+    ('\u{6959}', 0x15c3e),
+    ('\u{695a}', 0x1413f),
+    ('\u{695c}', 0x15c35),
+    ('\u{695d}', 0x15c42),
+    ('\u{695e}', 0x15c41),
+    ('\u{6960}', 0x1466f),
+    ('\u{6961}', 0x15c40),
+    ('\u{6962}', 0x1466a),
+    ('\u{6963}', 0x17576),
+    ('\u{6964}', 0x17577),
+    ('\u{6968}', 0x17578),
+    ('\u{6969}', 0x22f2e),
+    ('\u{696a}', 0x15c44),
+    ('\u{696b}', 0x15c37),
+    ('\u{696c}', 0x22f2f),
+    ('\u{696d}', 0x13648),
+    ('\u{696e}', 0x15c3a),
+    ('\u{696f}', 0x13d5d),
+    ('\u{6972}', 0x22f30),
+    ('\u{6973}', 0x14760),
+    ('\u{6974}', 0x15c3c),
+    ('\u{6975}', 0x1364b),
+    ('\u{6977}', 0x15c34),
+    ('\u{6978}', 0x15c36),
+    ('\u{6979}', 0x15c33),
+    ('\u{697a}', 0x22f31),
+    ('\u{697c}', 0x14f30),
+    ('\u{697d}', 0x1335a),
+    ('\u{697e}', 0x15c39),
+    ('\u{697f}', 0x22f32),
+    ('\u{6980}', 0x17579),
+    ('\u{6981}', 0x15c43),
+    ('\u{6982}', 0x13335),
+    ('\u{698a}', 0x13a67),
+    ('\u{698e}', 0x1315d),
+    ('\u{6991}', 0x15c54),
+    ('\u{6992}', 0x22f33),
+    ('\u{6994}', 0x14f31),
+    ('\u{6995}', 0x15c57),
+    ('\u{6996}', 0x22f35),
+    ('\u{6998}', 0x22f36),
+    ('\u{699b}', 0x13f3a),
+    ('\u{699c}', 0x15c56),
+    ('\u{69a0}', 0x15c55),
+    ('\u{69a5}', 0x1757b),
+    ('\u{69a6}', 0x22f37),
+    ('\u{69a7}', 0x15c52),
+    ('\u{69ad}', 0x1757c),
+    ('\u{69ae}', 0x15c46),
+    ('\u{69b0}', 0x22f38),
+    ('\u{69b1}', 0x15c63),
+    ('\u{69b2}', 0x15c45),
+    ('\u{69b4}', 0x15c58),
+    ('\u{69b7}', 0x22f39),
+    ('\u{69ba}', 0x22f3a),
+    ('\u{69bb}', 0x15c50),
+    ('\u{69bc}', 0x22f3b),
+    ('\u{69be}', 0x15c4b),
+    ('\u{69bf}', 0x15c48),
+    ('\u{69c0}', 0x22f3c),
+    ('\u{69c1}', 0x15c49),
+    ('\u{69c3}', 0x15c51),
+    ('\u{69c7}', 0x17422),
+    ('\u{69ca}', 0x15c4e),
+    ('\u{69cb}', 0x1393d),
+    ('\u{69cc}', 0x14448),
+    ('\u{69cd}', 0x14164),
+    ('\u{69ce}', 0x15c4c),
+    ('\u{69cf}', 0x1757d),
+    ('\u{69d0}', 0x15c47),
+    ('\u{69d1}', 0x22f3d),
+    ('\u{69d3}', 0x15c4a),
+    ('\u{69d6}', 0x22f3e),
+    ('\u{69d8}', 0x14d4d),
+    ('\u{69d9}', 0x14b6a),
+    ('\u{69dd}', 0x15c4f),
+    ('\u{69de}', 0x15c59),
+    ('\u{69e2}', 0x17622),
+    ('\u{69e3}', 0x22f44),
+    ('\u{69e7}', 0x15c61),
+    ('\u{69e8}', 0x15c5a),
+    ('\u{69e9}', 0x17623),
+    ('\u{69ea}', 0x17624),
+    ('\u{69eb}', 0x15c67),
+    ('\u{69ed}', 0x15c65),
+    ('\u{69ee}', 0x22f45),
+    ('\u{69ef}', 0x22f46),
+    ('\u{69f2}', 0x15c60),
+    ('\u{69f3}', 0x22f47),
+    ('\u{69f4}', 0x22f49),
+    ('\u{69f5}', 0x17625),
+    ('\u{69f6}', 0x17626),
+    ('\u{69f9}', 0x15c5f),
+    ('\u{69fb}', 0x14450),
+    ('\u{69fd}', 0x14165),
+    ('\u{69fe}', 0x22f4a),
+    ('\u{69ff}', 0x15c5d),
+    ('\u{6a02}', 0x15c5b),
+    ('\u{6a05}', 0x15c62),
+    ('\u{6a0a}', 0x15c68),
+    ('\u{6a0b}', 0x14875),
+    ('\u{6a0c}', 0x15c6e),
+    ('\u{6a0f}', 0x17627),
+    ('\u{6a11}', 0x22f4b),
+    ('\u{6a12}', 0x15c69),
+    ('\u{6a13}', 0x15c6c),
+    ('\u{6a14}', 0x15c66),
+    ('\u{6a15}', 0x17628),
+    ('\u{6a17}', 0x14374),
+    ('\u{6a19}', 0x14938),
+    ('\u{6a1a}', 0x22f4c),
+    ('\u{6a1b}', 0x15c5c),
+    ('\u{6a1d}', 0x22f4d),
+    ('\u{6a1e}', 0x15c64),
+    ('\u{6a1f}', 0x13e40),
+    ('\u{6a21}', 0x14c4f),
+    ('\u{6a22}', 0x15c78),
+    ('\u{6a23}', 0x15c6b),
+    ('\u{6a29}', 0x13822),
+    ('\u{6a2a}', 0x13223),
+    ('\u{6a2b}', 0x1335f),
+    ('\u{6a2e}', 0x15c53),
+    ('\u{6a30}', 0x22f41),
+    ('\u{6a32}', 0x22f4f),
+    ('\u{6a33}', 0x22f50),
+    ('\u{6a34}', 0x22f51),
+    ('\u{6a35}', 0x13e41),
+    ('\u{6a36}', 0x15c70),
+    ('\u{6a38}', 0x15c77),
+    ('\u{6a39}', 0x13c79),
+    ('\u{6a3a}', 0x13372),
+    ('\u{6a3b}', 0x1762a),
+    ('\u{6a3d}', 0x1432e),
+    ('\u{6a3e}', 0x1762b),
+    ('\u{6a3f}', 0x22f52),
+    ('\u{6a44}', 0x15c6d),
+    ('\u{6a45}', 0x1762c),
+    ('\u{6a46}', 0x22f53),
+    ('\u{6a47}', 0x15c72),
+    ('\u{6a48}', 0x15c76),
+    ('\u{6a49}', 0x22f54),
+    ('\u{6a4b}', 0x13636),
+    ('\u{6a4e}', 0x22f56),
+    ('\u{6a50}', 0x1762d),
+    ('\u{6a52}', 0x22f57),
+    ('\u{6a56}', 0x1762e),
+    ('\u{6a58}', 0x1354c),
+    ('\u{6a59}', 0x15c74),
+    ('\u{6a5b}', 0x1762f),
+    ('\u{6a5f}', 0x13521),
+    ('\u{6a61}', 0x1464b),
+    ('\u{6a62}', 0x15c73),
+    ('\u{6a64}', 0x22f58),
+    ('\u{6a66}', 0x15c75),
+    ('\u{6a6b}', 0x17630),
+    ('\u{6a72}', 0x15c6f),
+    ('\u{6a73}', 0x17631),
+    ('\u{6a78}', 0x15c71),
+    ('\u{6a7a}', 0x22f55),
+    ('\u{6a7e}', 0x22f5a),
+    ('\u{6a7f}', 0x13360),
+    ('\u{6a80}', 0x14349),
+    ('\u{6a83}', 0x22f5b),
+    ('\u{6a84}', 0x15c7c),
+    ('\u{6a89}', 0x17633),
+    ('\u{6a8b}', 0x22f5c),
+    ('\u{6a8d}', 0x15c7a),
+    ('\u{6a8e}', 0x13869),
+    ('\u{6a90}', 0x15c79),
+    ('\u{6a91}', 0x22f5e),
+    ('\u{6a94}', 0x17634),
+    ('\u{6a97}', 0x15d21),
+    ('\u{6a9c}', 0x15b58),
+    ('\u{6a9d}', 0x17635),
+    ('\u{6a9e}', 0x17636),
+    ('\u{6a9f}', 0x22f5f),
+    ('\u{6aa0}', 0x15c7b),
+    ('\u{6aa1}', 0x22f60),
+    ('\u{6aa2}', 0x15c7d),
+    ('\u{6aa3}', 0x15c7e),
+    ('\u{6aa5}', 0x17637),
+    ('\u{6aaa}', 0x15d2c),
+    ('\u{6aab}', 0x22f62),
+    ('\u{6aac}', 0x15d28),
+    ('\u{6aae}', 0x15b6d),
+    ('\u{6ab3}', 0x15d27),
+    ('\u{6ab8}', 0x15d26),
+    ('\u{6abb}', 0x15d23),
+    ('\u{6abd}', 0x22f63),
+    ('\u{6ac1}', 0x15c6a),
+    ('\u{6ac2}', 0x15d25),
+    ('\u{6ac3}', 0x15d24),
+    ('\u{6ac6}', 0x22f64),
+    ('\u{6ad0}', 0x22f66),
+    ('\u{6ad1}', 0x15d2a),
+    ('\u{6ad3}', 0x14f26),
+    ('\u{6ad4}', 0x22f65),
+    ('\u{6ada}', 0x15d2d),
+    ('\u{6adb}', 0x1367b),
+    ('\u{6adc}', 0x22f67),
+    ('\u{6add}', 0x22f68),
+    ('\u{6ade}', 0x15d29),
+    ('\u{6adf}', 0x15d2b),
+    ('\u{6ae4}', 0x17638),
+    ('\u{6ae7}', 0x17639),
+    ('\u{6ae8}', 0x14827),
+    ('\u{6aea}', 0x15d2e),
+    ('\u{6aec}', 0x22f6b),
+    ('\u{6af1}', 0x22f6c),
+    ('\u{6af2}', 0x22f6d),
+    ('\u{6af3}', 0x22f6e),
+    ('\u{6afa}', 0x15d32),
+    ('\u{6afb}', 0x15d2f),
+    ('\u{6afd}', 0x22f6f),
+    ('\u{6b04}', 0x14d73),
+    ('\u{6b05}', 0x15d30),
+    ('\u{6b0a}', 0x15c5e),
+    ('\u{6b0b}', 0x22f71),
+    ('\u{6b0f}', 0x22f72),
+    ('\u{6b10}', 0x22f73),
+    ('\u{6b11}', 0x22f74),
+    ('\u{6b12}', 0x15d33),
+    ('\u{6b16}', 0x15d34),
+    ('\u{6b17}', 0x22f76),
+    ('\u{6b1b}', 0x1763c),
+    ('\u{6b1d}', 0x13135),
+    ('\u{6b1e}', 0x1763d),
+    ('\u{6b1f}', 0x15d36),
+    ('\u{6b20}', 0x13767),
+    ('\u{6b21}', 0x13c21),
+    ('\u{6b23}', 0x13655),
+    ('\u{6b27}', 0x13224),
+    ('\u{6b2c}', 0x1763e),
+    ('\u{6b2f}', 0x22f78),
+    ('\u{6b32}', 0x14d5f),
+    ('\u{6b35}', 0x1763f),
+    ('\u{6b37}', 0x15d38),
+    ('\u{6b38}', 0x15d37),
+    ('\u{6b39}', 0x15d3a),
+    ('\u{6b3a}', 0x1353d),
+    ('\u{6b3d}', 0x13656),
+    ('\u{6b3e}', 0x1343e),
+    ('\u{6b43}', 0x15d3d),
+    ('\u{6b46}', 0x17640),
+    ('\u{6b47}', 0x15d3c),
+    ('\u{6b49}', 0x15d3e),
+    ('\u{6b4a}', 0x22f79),
+    ('\u{6b4c}', 0x1324e),
+    ('\u{6b4e}', 0x14337),
+    ('\u{6b50}', 0x15d3f),
+    ('\u{6b53}', 0x1343f),
+    ('\u{6b54}', 0x15d41),
+    ('\u{6b56}', 0x17641),
+    ('\u{6b58}', 0x22f7a),
+    ('\u{6b59}', 0x15d40),
+    ('\u{6b5b}', 0x15d42),
+    ('\u{6b5f}', 0x15d43),
+    ('\u{6b60}', 0x17642),
+    ('\u{6b61}', 0x15d44),
+    ('\u{6b62}', 0x13b5f),
+    ('\u{6b63}', 0x14035),
+    ('\u{6b64}', 0x13a21),
+    ('\u{6b65}', 0x17643),
+    ('\u{6b66}', 0x14970),
+    ('\u{6b67}', 0x17644),
+    ('\u{6b69}', 0x14a62),
+    ('\u{6b6a}', 0x14f44),
+    ('\u{6b6c}', 0x22f7b),
+    ('\u{6b6f}', 0x13b75),
+    ('\u{6b73}', 0x13a50),
+    ('\u{6b74}', 0x14e72),
+    ('\u{6b75}', 0x22f7c),
+    ('\u{6b77}', 0x17645),
+    ('\u{6b78}', 0x15d45),
+    ('\u{6b79}', 0x15d46),
+    ('\u{6b7a}', 0x22f7d),
+    ('\u{6b7b}', 0x13b60),
+    ('\u{6b7f}', 0x15d47),
+    ('\u{6b80}', 0x15d48),
+    ('\u{6b81}', 0x22f7e),
+    ('\u{6b82}', 0x17646),
+    ('\u{6b83}', 0x15d4a),
+    ('\u{6b84}', 0x15d49),
+    ('\u{6b86}', 0x14b58),
+    ('\u{6b89}', 0x13d5e),
+    ('\u{6b8a}', 0x13c6c),
+    ('\u{6b8b}', 0x13b44),
+    ('\u{6b8d}', 0x15d4b),
+    ('\u{6b95}', 0x15d4d),
+    ('\u{6b96}', 0x13f23),
+    ('\u{6b98}', 0x15d4c),
+    ('\u{6b9b}', 0x26e21),
+    ('\u{6b9e}', 0x15d4e),
+    ('\u{6ba4}', 0x15d4f),
+    ('\u{6ba9}', 0x17647),
+    ('\u{6baa}', 0x15d50),
+    ('\u{6bab}', 0x15d51),
+    ('\u{6bad}', 0x17648),
+    ('\u{6bae}', 0x26e22),
+    ('\u{6baf}', 0x15d52),
+    ('\u{6bb1}', 0x15d54),
+    ('\u{6bb2}', 0x15d53),
+    ('\u{6bb3}', 0x15d55),
+    ('\u{6bb4}', 0x13225),
+    ('\u{6bb5}', 0x1434a),
+    ('\u{6bb7}', 0x15d56),
+    ('\u{6bba}', 0x13b26),
+    ('\u{6bbb}', 0x1334c),
+    ('\u{6bbc}', 0x15d57),
+    ('\u{6bbd}', 0x26e24),
+    ('\u{6bbe}', 0x26e25),
+    ('\u{6bbf}', 0x14542),
+    ('\u{6bc0}', 0x1544c),
+    ('\u{6bc5}', 0x13523),
+    ('\u{6bc6}', 0x15d58),
+    ('\u{6bc7}', 0x26e26),
+    ('\u{6bc8}', 0x26e27),
+    ('\u{6bc9}', 0x26e28),
+    ('\u{6bcb}', 0x15d59),
+    ('\u{6bcd}', 0x14a6c),
+    ('\u{6bce}', 0x14b68),
+    ('\u{6bcf}', 0x1764a),
+    ('\u{6bd2}', 0x14647),
+    ('\u{6bd3}', 0x15d5a),
+    ('\u{6bd4}', 0x14866),
+    ('\u{6bd6}', 0x1764b),
+    ('\u{6bd7}', 0x1764c),
+    ('\u{6bd8}', 0x1487b),
+    ('\u{6bda}', 0x26e29),
+    ('\u{6bdb}', 0x14c53),
+    ('\u{6bdf}', 0x15d5b),
+    ('\u{6be6}', 0x26e2a),
+    ('\u{6be7}', 0x26e2b),
+    ('\u{6beb}', 0x15d5d),
+    ('\u{6bec}', 0x15d5c),
+    ('\u{6bee}', 0x26e2c),
+    ('\u{6bef}', 0x15d5f),
+    ('\u{6bf1}', 0x26e2d),
+    ('\u{6bf3}', 0x15d5e),
+    ('\u{6bff}', 0x1764d),
+    ('\u{6c02}', 0x26e2e),
+    ('\u{6c05}', 0x1764e),
+    ('\u{6c08}', 0x15d61),
+    ('\u{6c0a}', 0x26e2f),
+    ('\u{6c0e}', 0x26e30),
+    ('\u{6c0f}', 0x13b61),
+    ('\u{6c10}', 0x1764f),
+    ('\u{6c11}', 0x14c31),
+    ('\u{6c13}', 0x15d62),
+    ('\u{6c14}', 0x15d63),
+    ('\u{6c17}', 0x13524),
+    ('\u{6c1b}', 0x15d64),
+    ('\u{6c23}', 0x15d66),
+    ('\u{6c24}', 0x15d65),
+    ('\u{6c33}', 0x17650),
+    ('\u{6c34}', 0x13f65),
+    ('\u{6c35}', 0x26e31),
+    ('\u{6c36}', 0x26e32),
+    ('\u{6c37}', 0x14939),
+    ('\u{6c38}', 0x1314a),
+    ('\u{6c3a}', 0x26e33),
+    ('\u{6c3e}', 0x14845),
+    ('\u{6c3f}', 0x26e35),
+    ('\u{6c40}', 0x14475),
+    ('\u{6c41}', 0x13d41),
+    ('\u{6c42}', 0x13561),
+    ('\u{6c4d}', 0x26e36),
+    ('\u{6c4e}', 0x14846),
+    ('\u{6c50}', 0x13c2e),
+    ('\u{6c55}', 0x15d68),
+    ('\u{6c57}', 0x13440),
+    ('\u{6c59}', 0x17651),
+    ('\u{6c5a}', 0x13178),
+    ('\u{6c5b}', 0x26e37),
+    ('\u{6c5c}', 0x17652),
+    ('\u{6c5d}', 0x14672),
+    ('\u{6c5e}', 0x15d67),
+    ('\u{6c5f}', 0x1393e),
+    ('\u{6c60}', 0x14353),
+    ('\u{6c62}', 0x15d69),
+    ('\u{6c67}', 0x26e4f),
+    ('\u{6c68}', 0x15d71),
+    ('\u{6c6a}', 0x15d6a),
+    ('\u{6c6d}', 0x26e38),
+    ('\u{6c70}', 0x14241),
+    ('\u{6c72}', 0x13562),
+    ('\u{6c73}', 0x15d72),
+    ('\u{6c74}', 0x17654),
+    ('\u{6c76}', 0x17655),
+    ('\u{6c7a}', 0x13768),
+    ('\u{6c7d}', 0x13525),
+    ('\u{6c7e}', 0x15d70),
+    ('\u{6c81}', 0x15d6e),
+    ('\u{6c82}', 0x15d6b),
+    ('\u{6c83}', 0x14d60),
+    ('\u{6c84}', 0x26e39),
+    ('\u{6c85}', 0x17656),
+    ('\u{6c86}', 0x17657),
+    ('\u{6c88}', 0x14440),
+    ('\u{6c89}', 0x26e3a),
+    ('\u{6c8c}', 0x14659),
+    ('\u{6c8d}', 0x15d6c),
+    ('\u{6c90}', 0x15d74),
+    ('\u{6c92}', 0x15d73),
+    ('\u{6c93}', 0x13723),
+    ('\u{6c94}', 0x26e3c),
+    ('\u{6c95}', 0x26e3d),
+    ('\u{6c96}', 0x1322d),
+    ('\u{6c97}', 0x26e3e),
+    ('\u{6c98}', 0x17658),
+    ('\u{6c99}', 0x13a3b),
+    ('\u{6c9a}', 0x15d6d),
+    ('\u{6c9b}', 0x15d6f),
+    ('\u{6c9c}', 0x17659),
+    ('\u{6ca1}', 0x14b57),
+    ('\u{6ca2}', 0x14274),
+    ('\u{6caa}', 0x17653),
+    ('\u{6cab}', 0x14b77),
+    ('\u{6cad}', 0x26e3f),
+    ('\u{6cae}', 0x15d7c),
+    ('\u{6cb1}', 0x15d7d),
+    ('\u{6cb3}', 0x1324f),
+    ('\u{6cb8}', 0x14a28),
+    ('\u{6cb9}', 0x14c7d),
+    ('\u{6cba}', 0x15e21),
+    ('\u{6cbb}', 0x13c23),
+    ('\u{6cbc}', 0x13e42),
+    ('\u{6cbd}', 0x15d78),
+    ('\u{6cbe}', 0x15d7e),
+    ('\u{6cbf}', 0x13168),
+    ('\u{6cc1}', 0x13637),
+    ('\u{6cc2}', 0x26e40),
+    ('\u{6cc4}', 0x15d75),
+    ('\u{6cc5}', 0x15d7a),
+    ('\u{6cc6}', 0x1765b),
+    ('\u{6cc9}', 0x14074),
+    ('\u{6cca}', 0x14771),
+    ('\u{6ccc}', 0x14867),
+    ('\u{6cd0}', 0x26e41),
+    ('\u{6cd3}', 0x15d77),
+    ('\u{6cd4}', 0x1765c),
+    ('\u{6cd5}', 0x14b21),
+    ('\u{6cd6}', 0x26e43),
+    ('\u{6cd7}', 0x15d79),
+    ('\u{6cd9}', 0x15e24),
+    ('\u{6cda}', 0x26e44),
+    ('\u{6cdb}', 0x15e22),
+    ('\u{6cdc}', 0x26e45),
+    ('\u{6cdd}', 0x15d7b),
+    ('\u{6ce0}', 0x1765d),
+    ('\u{6ce1}', 0x14b22),
+    ('\u{6ce2}', 0x14748),
+    ('\u{6ce3}', 0x13563),
+    ('\u{6ce5}', 0x14525),
+    ('\u{6ce8}', 0x1436d),
+    ('\u{6ce9}', 0x26e46),
+    ('\u{6cea}', 0x15e25),
+    ('\u{6ceb}', 0x1765e),
+    ('\u{6cec}', 0x26e47),
+    ('\u{6ced}', 0x26e48),
+    ('\u{6cee}', 0x1765f),
+    ('\u{6cef}', 0x15e23),
+    ('\u{6cf0}', 0x14259),
+    ('\u{6cf1}', 0x15d76),
+    ('\u{6cf3}', 0x1314b),
+    ('\u{6cfb}', 0x1765a),
+    ('\u{6d00}', 0x26e4a),
+    ('\u{6d04}', 0x17661),
+    ('\u{6d0a}', 0x26e4b),
+    ('\u{6d0b}', 0x14d4e),
+    ('\u{6d0c}', 0x15e30),
+    ('\u{6d0e}', 0x17662),
+    ('\u{6d12}', 0x15e2f),
+    ('\u{6d17}', 0x14076),
+    ('\u{6d19}', 0x15e2c),
+    ('\u{6d1b}', 0x14d6c),
+    ('\u{6d1e}', 0x14636),
+    ('\u{6d1f}', 0x15e26),
+    ('\u{6d24}', 0x26e4c),
+    ('\u{6d25}', 0x14445),
+    ('\u{6d26}', 0x26e4d),
+    ('\u{6d27}', 0x26e4e),
+    ('\u{6d29}', 0x1314c),
+    ('\u{6d2a}', 0x1393f),
+    ('\u{6d2b}', 0x15e29),
+    ('\u{6d2e}', 0x17663),
+    ('\u{6d2f}', 0x26e50),
+    ('\u{6d31}', 0x17664),
+    ('\u{6d32}', 0x13d27),
+    ('\u{6d33}', 0x15e2e),
+    ('\u{6d34}', 0x26e65),
+    ('\u{6d35}', 0x15e2d),
+    ('\u{6d36}', 0x15e28),
+    ('\u{6d38}', 0x15e2b),
+    ('\u{6d39}', 0x17665),
+    ('\u{6d3b}', 0x13368),
+    ('\u{6d3c}', 0x26e51),
+    ('\u{6d3d}', 0x15e2a),
+    ('\u{6d3e}', 0x14749),
+    ('\u{6d3f}', 0x17666),
+    ('\u{6d41}', 0x14e2e),
+    ('\u{6d44}', 0x13e74),
+    ('\u{6d45}', 0x14075),
+    ('\u{6d58}', 0x17667),
+    ('\u{6d59}', 0x15e36),
+    ('\u{6d5a}', 0x15e34),
+    ('\u{6d5b}', 0x26e52),
+    ('\u{6d5c}', 0x1494d),
+    ('\u{6d5e}', 0x26e53),
+    ('\u{6d60}', 0x26e54),
+    ('\u{6d63}', 0x15e31),
+    ('\u{6d64}', 0x15e33),
+    ('\u{6d65}', 0x17668),
+    ('\u{6d66}', 0x1313a),
+    ('\u{6d69}', 0x13940),
+    ('\u{6d6a}', 0x14f32),
+    ('\u{6d6c}', 0x1333d),
+    ('\u{6d6e}', 0x14962),
+    ('\u{6d70}', 0x26e55),
+    ('\u{6d74}', 0x14d61),
+    ('\u{6d77}', 0x13324),
+    ('\u{6d78}', 0x13f3b),
+    ('\u{6d79}', 0x15e35),
+    ('\u{6d80}', 0x26e56),
+    ('\u{6d81}', 0x26e57),
+    ('\u{6d82}', 0x1766a),
+    ('\u{6d85}', 0x15e3a),
+    ('\u{6d87}', 0x1766b),
+    ('\u{6d88}', 0x13e43),
+    ('\u{6d89}', 0x1766c),
+    ('\u{6d8a}', 0x26e58),
+    ('\u{6d8c}', 0x14d30),
+    ('\u{6d8d}', 0x26e59),
+    ('\u{6d8e}', 0x15e37),
+    ('\u{6d91}', 0x26e5a),
+    ('\u{6d93}', 0x15e32),
+    ('\u{6d94}', 0x1766d),
+    ('\u{6d95}', 0x15e38),
+    ('\u{6d98}', 0x26e5b),
+    ('\u{6d99}', 0x14e5e),
+    ('\u{6d9b}', 0x14573),
+    ('\u{6d9c}', 0x14642),
+    ('\u{6daa}', 0x1766e),
+    ('\u{6dab}', 0x26e61),
+    ('\u{6dac}', 0x1766f),
+    ('\u{6dae}', 0x26e62),
+    ('\u{6daf}', 0x13336),
+    ('\u{6db2}', 0x13155),
+    ('\u{6db4}', 0x26e63),
+    ('\u{6db5}', 0x15e3e),
+    ('\u{6db8}', 0x15e41),
+    ('\u{6dbc}', 0x14e43),
+    ('\u{6dbf}', 0x17670),
+    ('\u{6dc0}', 0x14d64),
+    ('\u{6dc2}', 0x26e64),
+    ('\u{6dc4}', 0x17671),
+    ('\u{6dc5}', 0x15e48),
+    ('\u{6dc6}', 0x15e42),
+    ('\u{6dc7}', 0x15e3f),
+    ('\u{6dc8}', 0x26e66),
+    ('\u{6dcb}', 0x14e54),
+    ('\u{6dcc}', 0x15e45),
+    ('\u{6dce}', 0x26e67),
+    ('\u{6dcf}', 0x26e68),
+    ('\u{6dd0}', 0x26e69),
+    ('\u{6dd1}', 0x13d4a),
+    ('\u{6dd2}', 0x15e47),
+    ('\u{6dd5}', 0x15e4c),
+    ('\u{6dd6}', 0x17672),
+    ('\u{6dd8}', 0x14571),
+    ('\u{6dd9}', 0x15e4a),
+    ('\u{6dda}', 0x17673),
+    ('\u{6ddb}', 0x17674),
+    ('\u{6ddd}', 0x17675),
+    ('\u{6dde}', 0x15e44),
+    ('\u{6ddf}', 0x26e6a),
+    ('\u{6de1}', 0x14338),
+    ('\u{6de4}', 0x15e4b),
+    ('\u{6de6}', 0x15e40),
+    ('\u{6de8}', 0x15e46),
+    ('\u{6de9}', 0x26e6b),
+    ('\u{6dea}', 0x15e4d),
+    ('\u{6deb}', 0x1307c),
+    ('\u{6dec}', 0x15e43),
+    ('\u{6dee}', 0x15e4e),
+    ('\u{6df1}', 0x13f3c),
+    ('\u{6df3}', 0x13d5f),
+    ('\u{6df5}', 0x14a25),
+    ('\u{6df6}', 0x26e6c),
+    ('\u{6df7}', 0x13a2e),
+    ('\u{6df9}', 0x15e3b),
+    ('\u{6dfa}', 0x15e49),
+    ('\u{6dfb}', 0x1453a),
+    ('\u{6dfc}', 0x17676),
+    ('\u{6e05}', 0x14036),
+    ('\u{6e07}', 0x13369),
+    ('\u{6e08}', 0x13a51),
+    ('\u{6e09}', 0x13e44),
+    ('\u{6e0a}', 0x15e3d),
+    ('\u{6e0b}', 0x13d42),
+    ('\u{6e13}', 0x1374c),
+    ('\u{6e15}', 0x15e3c),
+    ('\u{6e17}', 0x26e5d),
+    ('\u{6e19}', 0x15e52),
+    ('\u{6e1a}', 0x13d6d),
+    ('\u{6e1b}', 0x1383a),
+    ('\u{6e1d}', 0x15e61),
+    ('\u{6e1e}', 0x26e6e),
+    ('\u{6e1f}', 0x15e5b),
+    ('\u{6e20}', 0x13574),
+    ('\u{6e21}', 0x1454f),
+    ('\u{6e22}', 0x26e6f),
+    ('\u{6e23}', 0x15e56),
+    ('\u{6e24}', 0x15e5f),
+    ('\u{6e25}', 0x1302f),
+    ('\u{6e26}', 0x13132),
+    ('\u{6e27}', 0x26e70),
+    ('\u{6e29}', 0x13239),
+    ('\u{6e2b}', 0x15e58),
+    ('\u{6e2c}', 0x1422c),
+    ('\u{6e2d}', 0x15e4f),
+    ('\u{6e2e}', 0x15e51),
+    ('\u{6e2f}', 0x13941),
+    ('\u{6e32}', 0x26e72),
+    ('\u{6e34}', 0x17678),
+    ('\u{6e36}', 0x26e6d),
+    ('\u{6e38}', 0x15e62),
+    ('\u{6e3a}', 0x15e5d),
+    ('\u{6e3c}', 0x26e73),
+    ('\u{6e3e}', 0x15e55),
+    ('\u{6e43}', 0x15e5c),
+    ('\u{6e44}', 0x17679),
+    ('\u{6e48}', 0x26e74),
+    ('\u{6e49}', 0x26e75),
+    ('\u{6e4a}', 0x14c2b),
+    ('\u{6e4b}', 0x26e76),
+    ('\u{6e4c}', 0x26e77),
+    ('\u{6e4d}', 0x15e5a),
+    ('\u{6e4e}', 0x15e5e),
+    ('\u{6e4f}', 0x26e78),
+    ('\u{6e51}', 0x26e79),
+    ('\u{6e53}', 0x26e7a),
+    ('\u{6e54}', 0x26e7b),
+    ('\u{6e56}', 0x13850),
+    ('\u{6e57}', 0x26e7c),
+    ('\u{6e58}', 0x13e45),
+    ('\u{6e5b}', 0x14339),
+    ('\u{6e5c}', 0x1767a),
+    ('\u{6e5e}', 0x1767b),
+    ('\u{6e5f}', 0x15e54),
+    ('\u{6e63}', 0x26e7d),
+    ('\u{6e67}', 0x14d2f),
+    ('\u{6e6b}', 0x15e57),
+    ('\u{6e6e}', 0x15e50),
+    ('\u{6e6f}', 0x14572),
+    ('\u{6e72}', 0x15e53),
+    ('\u{6e76}', 0x15e59),
+    ('\u{6e7e}', 0x14f51),
+    ('\u{6e7f}', 0x13c3e),
+    ('\u{6e80}', 0x14b7e),
+    ('\u{6e82}', 0x15e63),
+    ('\u{6e8c}', 0x1482e),
+    ('\u{6e8f}', 0x15e6f),
+    ('\u{6e90}', 0x1383b),
+    ('\u{6e93}', 0x26f21),
+    ('\u{6e96}', 0x13d60),
+    ('\u{6e98}', 0x15e65),
+    ('\u{6e9c}', 0x14e2f),
+    ('\u{6e9d}', 0x13942),
+    ('\u{6e9f}', 0x15e72),
+    ('\u{6ea2}', 0x1306e),
+    ('\u{6ea5}', 0x15e70),
+    ('\u{6ea7}', 0x26f22),
+    ('\u{6eaa}', 0x15e64),
+    ('\u{6eab}', 0x1767c),
+    ('\u{6eaf}', 0x15e6a),
+    ('\u{6eb1}', 0x1767d),
+    ('\u{6eb2}', 0x15e6c),
+    ('\u{6eb4}', 0x26f23),
+    ('\u{6eb6}', 0x14d4f),
+    ('\u{6eb7}', 0x15e67),
+    ('\u{6eba}', 0x1452e),
+    ('\u{6ebd}', 0x15e69),
+    ('\u{6ebf}', 0x26f24),
+    ('\u{6ec1}', 0x1767e),
+    ('\u{6ec2}', 0x15e71),
+    ('\u{6ec3}', 0x26f25),
+    ('\u{6ec4}', 0x15e6b),
+    ('\u{6ec5}', 0x14c47),
+    ('\u{6ec7}', 0x17721),
+    ('\u{6ec9}', 0x15e66),
+    ('\u{6eca}', 0x26f26),
+    ('\u{6ecb}', 0x13c22),
+    ('\u{6ecc}', 0x15e7e),
+    ('\u{6ece}', 0x17722),
+    ('\u{6ed1}', 0x1336a),
+    ('\u{6ed3}', 0x15e68),
+    ('\u{6ed4}', 0x15e6d),
+    ('\u{6ed5}', 0x15e6e),
+    ('\u{6ed9}', 0x26f27),
+    ('\u{6edd}', 0x1426c),
+    ('\u{6ede}', 0x1425a),
+    ('\u{6eeb}', 0x26f29),
+    ('\u{6eec}', 0x15e76),
+    ('\u{6eef}', 0x15e7c),
+    ('\u{6ef2}', 0x15e7a),
+    ('\u{6ef4}', 0x14529),
+    ('\u{6ef7}', 0x15f23),
+    ('\u{6ef8}', 0x15e77),
+    ('\u{6ef9}', 0x26f2a),
+    ('\u{6efb}', 0x26f2b),
+    ('\u{6efe}', 0x15e78),
+    ('\u{6eff}', 0x15e60),
+    ('\u{6f01}', 0x13579),
+    ('\u{6f02}', 0x1493a),
+    ('\u{6f06}', 0x13c3f),
+    ('\u{6f09}', 0x13977),
+    ('\u{6f0a}', 0x26f2c),
+    ('\u{6f0c}', 0x26f2d),
+    ('\u{6f0f}', 0x14f33),
+    ('\u{6f10}', 0x17723),
+    ('\u{6f11}', 0x15e74),
+    ('\u{6f13}', 0x15f22),
+    ('\u{6f14}', 0x13169),
+    ('\u{6f15}', 0x14166),
+    ('\u{6f18}', 0x26f2e),
+    ('\u{6f1a}', 0x17724),
+    ('\u{6f20}', 0x14779),
+    ('\u{6f22}', 0x13441),
+    ('\u{6f23}', 0x14e7a),
+    ('\u{6f25}', 0x26f2f),
+    ('\u{6f2a}', 0x17726),
+    ('\u{6f2b}', 0x14c21),
+    ('\u{6f2c}', 0x14452),
+    ('\u{6f2f}', 0x17727),
+    ('\u{6f31}', 0x15e7b),
+    ('\u{6f32}', 0x15e7d),
+    ('\u{6f33}', 0x17728),
+    ('\u{6f35}', 0x26f28),
+    ('\u{6f36}', 0x26f30),
+    ('\u{6f38}', 0x14132),
+    ('\u{6f3c}', 0x26f31),
+    ('\u{6f3e}', 0x15f21),
+    ('\u{6f3f}', 0x15e79),
+    ('\u{6f41}', 0x15e73),
+    ('\u{6f45}', 0x13443),
+    ('\u{6f51}', 0x17729),
+    ('\u{6f52}', 0x26f33),
+    ('\u{6f54}', 0x13769),
+    ('\u{6f57}', 0x26f34),
+    ('\u{6f58}', 0x15f2f),
+    ('\u{6f59}', 0x1772a),
+    ('\u{6f5a}', 0x26f35),
+    ('\u{6f5b}', 0x15f2a),
+    ('\u{6f5c}', 0x14078),
+    ('\u{6f5e}', 0x1772b),
+    ('\u{6f5f}', 0x13363),
+    ('\u{6f60}', 0x26f36),
+    ('\u{6f61}', 0x1772c),
+    ('\u{6f62}', 0x1772d),
+    ('\u{6f64}', 0x13d61),
+    ('\u{6f66}', 0x15f33),
+    ('\u{6f68}', 0x26f37),
+    ('\u{6f6d}', 0x15f2c),
+    ('\u{6f6e}', 0x1442c),
+    ('\u{6f6f}', 0x15f29),
+    ('\u{6f70}', 0x14459),
+    ('\u{6f74}', 0x15f4c),
+    ('\u{6f78}', 0x15f26),
+    ('\u{6f7a}', 0x15f25),
+    ('\u{6f7c}', 0x15f2e),
+    ('\u{6f7d}', 0x26f39),
+    ('\u{6f7e}', 0x1772e),
+    ('\u{6f80}', 0x15f28),
+    ('\u{6f81}', 0x15f27),
+    ('\u{6f82}', 0x15f2d),
+    ('\u{6f84}', 0x14021),
+    ('\u{6f86}', 0x15f24),
+    ('\u{6f88}', 0x1772f),
+    ('\u{6f8c}', 0x17730),
+    ('\u{6f8d}', 0x17731),
+    ('\u{6f8e}', 0x15f30),
+    ('\u{6f90}', 0x26f3a),
+    ('\u{6f91}', 0x15f31),
+    ('\u{6f94}', 0x17732),
+    ('\u{6f96}', 0x26f3b),
+    ('\u{6f97}', 0x13442),
+    ('\u{6f98}', 0x26f38),
+    ('\u{6f9f}', 0x26f3d),
+    ('\u{6fa0}', 0x17733),
+    ('\u{6fa1}', 0x15f36),
+    ('\u{6fa3}', 0x15f35),
+    ('\u{6fa4}', 0x15f37),
+    ('\u{6fa5}', 0x26f3e),
+    ('\u{6fa7}', 0x17734),
+    ('\u{6faa}', 0x15f3a),
+    ('\u{6faf}', 0x26f3f),
+    ('\u{6fb1}', 0x14543),
+    ('\u{6fb3}', 0x15f34),
+    ('\u{6fb5}', 0x26f41),
+    ('\u{6fb6}', 0x17735),
+    ('\u{6fb9}', 0x15f38),
+    ('\u{6fbc}', 0x17736),
+    ('\u{6fbe}', 0x26f3c),
+    ('\u{6fc0}', 0x13763),
+    ('\u{6fc1}', 0x14279),
+    ('\u{6fc2}', 0x15f32),
+    ('\u{6fc3}', 0x1473b),
+    ('\u{6fc6}', 0x15f39),
+    ('\u{6fc7}', 0x17737),
+    ('\u{6fc8}', 0x26f42),
+    ('\u{6fc9}', 0x26f43),
+    ('\u{6fca}', 0x17738),
+    ('\u{6fd4}', 0x15f3e),
+    ('\u{6fd5}', 0x15f3c),
+    ('\u{6fd8}', 0x15f3f),
+    ('\u{6fda}', 0x26f44),
+    ('\u{6fdb}', 0x15f42),
+    ('\u{6fde}', 0x26f45),
+    ('\u{6fdf}', 0x15f3b),
+    ('\u{6fe0}', 0x1396a),
+    ('\u{6fe1}', 0x14728),
+    ('\u{6fe4}', 0x15e39),
+    ('\u{6fe9}', 0x26f46),
+    ('\u{6feb}', 0x14d74),
+    ('\u{6fec}', 0x15f3d),
+    ('\u{6fee}', 0x15f41),
+    ('\u{6fef}', 0x14275),
+    ('\u{6ff0}', 0x1773a),
+    ('\u{6ff1}', 0x15f40),
+    ('\u{6ff3}', 0x15f2b),
+    ('\u{6ff5}', 0x1773b),
+    ('\u{6ff6}', 0x16f69),
+    ('\u{6ff9}', 0x17739),
+    ('\u{6ffa}', 0x15f45),
+    ('\u{6ffc}', 0x26f48),
+    ('\u{6ffe}', 0x15f49),
+    ('\u{7000}', 0x26f49),
+    ('\u{7001}', 0x15f47),
+    ('\u{7005}', 0x1773c),
+    ('\u{7006}', 0x1773d),
+    ('\u{7007}', 0x26f4a),
+    ('\u{7009}', 0x15f43),
+    ('\u{700a}', 0x26f4b),
+    ('\u{700b}', 0x15f44),
+    ('\u{700f}', 0x15f48),
+    ('\u{7011}', 0x15f46),
+    ('\u{7015}', 0x1494e),
+    ('\u{7018}', 0x15f4e),
+    ('\u{701a}', 0x15f4b),
+    ('\u{701b}', 0x15f4a),
+    ('\u{701d}', 0x15f4d),
+    ('\u{701e}', 0x14654),
+    ('\u{701f}', 0x15f4f),
+    ('\u{7023}', 0x26f4c),
+    ('\u{7026}', 0x14375),
+    ('\u{7027}', 0x1426d),
+    ('\u{7028}', 0x1773e),
+    ('\u{702c}', 0x14025),
+    ('\u{7030}', 0x15f50),
+    ('\u{7032}', 0x15f52),
+    ('\u{7039}', 0x26f4e),
+    ('\u{703a}', 0x26f4f),
+    ('\u{703c}', 0x26f50),
+    ('\u{703e}', 0x15f51),
+    ('\u{7043}', 0x26f51),
+    ('\u{7047}', 0x26f52),
+    ('\u{704a}', 0x1773f),
+    ('\u{704b}', 0x26f53),
+    ('\u{704c}', 0x15e75),
+    ('\u{704e}', 0x17742),
+    ('\u{7051}', 0x15f53),
+    ('\u{7054}', 0x26f55),
+    ('\u{7058}', 0x14667),
+    ('\u{705d}', 0x17740),
+    ('\u{705e}', 0x17741),
+    ('\u{7063}', 0x15f54),
+    ('\u{7064}', 0x17743),
+    ('\u{7065}', 0x26f56),
+    ('\u{7069}', 0x26f57),
+    ('\u{706b}', 0x13250),
+    ('\u{706c}', 0x26f58),
+    ('\u{706e}', 0x26f59),
+    ('\u{706f}', 0x14574),
+    ('\u{7070}', 0x13325),
+    ('\u{7075}', 0x17744),
+    ('\u{7076}', 0x26f5a),
+    ('\u{7078}', 0x13564),
+    ('\u{707c}', 0x13c5e),
+    ('\u{707d}', 0x13a52),
+    ('\u{707e}', 0x26f5b),
+    ('\u{7081}', 0x26f5c),
+    ('\u{7085}', 0x17745),
+    ('\u{7086}', 0x26f5d),
+    ('\u{7089}', 0x14f27),
+    ('\u{708a}', 0x13f66),
+    ('\u{708e}', 0x1316a),
+    ('\u{7092}', 0x15f56),
+    ('\u{7095}', 0x26f5e),
+    ('\u{7097}', 0x26f5f),
+    ('\u{7099}', 0x15f55),
+    ('\u{709f}', 0x26f62),
+    ('\u{70a4}', 0x17746),
+    ('\u{70ab}', 0x17747),
+    ('\u{70ac}', 0x15f59),
+    ('\u{70ad}', 0x1433a),
+    ('\u{70ae}', 0x15f5c),
+    ('\u{70af}', 0x15f57),
+    ('\u{70b1}', 0x26f63),
+    ('\u{70b3}', 0x15f5b),
+    ('\u{70b7}', 0x17748),
+    ('\u{70b8}', 0x15f5a),
+    ('\u{70b9}', 0x14540),
+    ('\u{70ba}', 0x13059),
+    ('\u{70bb}', 0x26f60),
+    ('\u{70c8}', 0x14e75),
+    ('\u{70ca}', 0x26f66),
+    ('\u{70cb}', 0x15f5e),
+    ('\u{70cf}', 0x13128),
+    ('\u{70d1}', 0x26f67),
+    ('\u{70d3}', 0x26f68),
+    ('\u{70d4}', 0x17749),
+    ('\u{70d8}', 0x1774a),
+    ('\u{70d9}', 0x15f60),
+    ('\u{70dc}', 0x26f69),
+    ('\u{70dd}', 0x15f5f),
+    ('\u{70df}', 0x15f5d),
+    ('\u{70e4}', 0x1774b),
+    ('\u{70ec}', 0x26f65),
+    ('\u{70f1}', 0x15f58),
+    ('\u{70f9}', 0x14b23),
+    ('\u{70fd}', 0x15f62),
+    ('\u{7103}', 0x26f6a),
+    ('\u{7104}', 0x26f6b),
+    ('\u{7106}', 0x26f6c),
+    ('\u{7107}', 0x26f6d),
+    ('\u{7108}', 0x26f6e),
+    ('\u{7109}', 0x15f61),
+    ('\u{710c}', 0x26f6f),
+    ('\u{710f}', 0x1774c),
+    ('\u{7114}', 0x1316b),
+    ('\u{7119}', 0x15f64),
+    ('\u{711a}', 0x14a32),
+    ('\u{711c}', 0x15f63),
+    ('\u{711e}', 0x1774e),
+    ('\u{7120}', 0x1774f),
+    ('\u{7121}', 0x14c35),
+    ('\u{7126}', 0x13e47),
+    ('\u{712b}', 0x1774d),
+    ('\u{712e}', 0x17750),
+    ('\u{712f}', 0x26f71),
+    ('\u{7130}', 0x17751),
+    ('\u{7131}', 0x26f72),
+    ('\u{7136}', 0x14133),
+    ('\u{713c}', 0x13e46),
+    ('\u{7146}', 0x17752),
+    ('\u{7147}', 0x17753),
+    ('\u{7149}', 0x14e7b),
+    ('\u{714a}', 0x26f74),
+    ('\u{714c}', 0x15f6a),
+    ('\u{714e}', 0x14079),
+    ('\u{7150}', 0x26f73),
+    ('\u{7151}', 0x17754),
+    ('\u{7152}', 0x17756),
+    ('\u{7153}', 0x26f75),
+    ('\u{7155}', 0x15f66),
+    ('\u{7156}', 0x15f6b),
+    ('\u{7159}', 0x1316c),
+    ('\u{715c}', 0x17757),
+    ('\u{715e}', 0x26f76),
+    ('\u{7160}', 0x17758),
+    ('\u{7162}', 0x15f69),
+    ('\u{7164}', 0x14761),
+    ('\u{7165}', 0x15f65),
+    ('\u{7166}', 0x15f68),
+    ('\u{7167}', 0x13e48),
+    ('\u{7168}', 0x17759),
+    ('\u{7169}', 0x14851),
+    ('\u{716c}', 0x15f6c),
+    ('\u{716e}', 0x13c51),
+    ('\u{717d}', 0x1407a),
+    ('\u{7180}', 0x26f79),
+    ('\u{7184}', 0x15f6f),
+    ('\u{7185}', 0x1775b),
+    ('\u{7187}', 0x1775c),
+    ('\u{7188}', 0x15f67),
+    ('\u{718a}', 0x13727),
+    ('\u{718f}', 0x15f6d),
+    ('\u{7192}', 0x1775d),
+    ('\u{7194}', 0x14d50),
+    ('\u{7195}', 0x15f70),
+    ('\u{7196}', 0x26f78),
+    ('\u{7199}', 0x17426),
+    ('\u{719b}', 0x26f7a),
+    ('\u{719f}', 0x13d4f),
+    ('\u{71a0}', 0x26f7b),
+    ('\u{71a2}', 0x26f7c),
+    ('\u{71a8}', 0x15f71),
+    ('\u{71ac}', 0x15f72),
+    ('\u{71ae}', 0x26f7d),
+    ('\u{71af}', 0x26f7e),
+    ('\u{71b1}', 0x1472e),
+    ('\u{71b3}', 0x27021),
+    ('\u{71b9}', 0x15f74),
+    ('\u{71ba}', 0x1775f),
+    ('\u{71be}', 0x15f75),
+    ('\u{71c1}', 0x1775e),
+    ('\u{71c3}', 0x14733),
+    ('\u{71c4}', 0x17760),
+    ('\u{71c8}', 0x14575),
+    ('\u{71c9}', 0x15f77),
+    ('\u{71cb}', 0x27023),
+    ('\u{71ce}', 0x15f79),
+    ('\u{71d0}', 0x14e55),
+    ('\u{71d2}', 0x15f76),
+    ('\u{71d3}', 0x27024),
+    ('\u{71d4}', 0x15f78),
+    ('\u{71d5}', 0x1316d),
+    ('\u{71d7}', 0x15f73),
+    ('\u{71d9}', 0x27025),
+    ('\u{71dc}', 0x27026),
+    ('\u{71df}', 0x1535b),
+    ('\u{71e0}', 0x15f7a),
+    ('\u{71e5}', 0x14167),
+    ('\u{71e6}', 0x13b38),
+    ('\u{71e7}', 0x15f7c),
+    ('\u{71ec}', 0x15f7b),
+    ('\u{71ed}', 0x13f24),
+    ('\u{71ee}', 0x15259),
+    ('\u{71f5}', 0x15f7d),
+    ('\u{71f9}', 0x16021),
+    ('\u{71fb}', 0x15f6e),
+    ('\u{71fc}', 0x15f7e),
+    ('\u{71fe}', 0x17761),
+    ('\u{71ff}', 0x16022),
+    ('\u{7200}', 0x17762),
+    ('\u{7206}', 0x1477a),
+    ('\u{7207}', 0x27027),
+    ('\u{720d}', 0x16023),
+    ('\u{7210}', 0x16024),
+    ('\u{7215}', 0x17763),
+    ('\u{721b}', 0x16025),
+    ('\u{7228}', 0x16026),
+    ('\u{722a}', 0x1445e),
+    ('\u{722b}', 0x2702a),
+    ('\u{722c}', 0x16028),
+    ('\u{722d}', 0x16027),
+    ('\u{7230}', 0x16029),
+    ('\u{7232}', 0x1602a),
+    ('\u{7234}', 0x2702b),
+    ('\u{7235}', 0x13c5f),
+    ('\u{7236}', 0x14963),
+    ('\u{7238}', 0x2702c),
+    ('\u{7239}', 0x2702d),
+    ('\u{723a}', 0x14c6c),
+    ('\u{723b}', 0x1602b),
+    ('\u{723c}', 0x1602c),
+    ('\u{723d}', 0x14156),
+    ('\u{723e}', 0x13c24),
+    ('\u{723f}', 0x1602d),
+    ('\u{7240}', 0x1602e),
+    ('\u{7242}', 0x2702f),
+    ('\u{7246}', 0x1602f),
+    ('\u{7247}', 0x14a52),
+    ('\u{7248}', 0x14847),
+    ('\u{724b}', 0x16030),
+    ('\u{724c}', 0x14757),
+    ('\u{7252}', 0x1442d),
+    ('\u{7253}', 0x27030),
+    ('\u{7255}', 0x17764),
+    ('\u{7256}', 0x17765),
+    ('\u{7257}', 0x27031),
+    ('\u{7258}', 0x16031),
+    ('\u{7259}', 0x13267),
+    ('\u{725b}', 0x1356d),
+    ('\u{725d}', 0x14c46),
+    ('\u{725f}', 0x14c36),
+    ('\u{7261}', 0x13234),
+    ('\u{7262}', 0x14f34),
+    ('\u{7263}', 0x27032),
+    ('\u{7267}', 0x14b52),
+    ('\u{7269}', 0x14a2a),
+    ('\u{726e}', 0x27034),
+    ('\u{726f}', 0x27035),
+    ('\u{7272}', 0x14037),
+    ('\u{7274}', 0x16032),
+    ('\u{7278}', 0x27036),
+    ('\u{7279}', 0x14643),
+    ('\u{727d}', 0x13823),
+    ('\u{727e}', 0x16033),
+    ('\u{727f}', 0x27037),
+    ('\u{7280}', 0x13a54),
+    ('\u{7281}', 0x16035),
+    ('\u{7282}', 0x16034),
+    ('\u{7287}', 0x16036),
+    ('\u{728d}', 0x17767),
+    ('\u{728e}', 0x27038),
+    ('\u{7292}', 0x16037),
+    ('\u{7296}', 0x16038),
+    ('\u{729b}', 0x17768),
+    ('\u{72a0}', 0x1353e),
+    ('\u{72a2}', 0x16039),
+    ('\u{72a7}', 0x1603a),
+    ('\u{72ac}', 0x13824),
+    ('\u{72ad}', 0x2703a),
+    ('\u{72ae}', 0x2703b),
+    ('\u{72af}', 0x14848),
+    ('\u{72b0}', 0x2703c),
+    ('\u{72b1}', 0x2703d),
+    ('\u{72b2}', 0x1603c),
+    ('\u{72b6}', 0x13e75),
+    ('\u{72b9}', 0x1603b),
+    ('\u{72be}', 0x17769),
+    ('\u{72c0}', 0x1776a),
+    ('\u{72c1}', 0x2703e),
+    ('\u{72c2}', 0x13638),
+    ('\u{72c3}', 0x1603d),
+    ('\u{72c4}', 0x1603f),
+    ('\u{72c6}', 0x1603e),
+    ('\u{72cc}', 0x27040),
+    ('\u{72ce}', 0x16040),
+    ('\u{72d0}', 0x13851),
+    ('\u{72d2}', 0x16041),
+    ('\u{72d7}', 0x13669),
+    ('\u{72d9}', 0x14140),
+    ('\u{72db}', 0x1397d),
+    ('\u{72e0}', 0x16043),
+    ('\u{72e1}', 0x16044),
+    ('\u{72e2}', 0x16042),
+    ('\u{72e9}', 0x13c6d),
+    ('\u{72ec}', 0x14648),
+    ('\u{72ed}', 0x13639),
+    ('\u{72f3}', 0x27043),
+    ('\u{72f7}', 0x16046),
+    ('\u{72f8}', 0x1432c),
+    ('\u{72f9}', 0x16045),
+    ('\u{72fa}', 0x27044),
+    ('\u{72fb}', 0x1776b),
+    ('\u{72fc}', 0x14f35),
+    ('\u{72fd}', 0x14762),
+    ('\u{7307}', 0x27045),
+    ('\u{730a}', 0x16049),
+    ('\u{7312}', 0x27046),
+    ('\u{7316}', 0x1604b),
+    ('\u{7317}', 0x16048),
+    ('\u{7318}', 0x27047),
+    ('\u{7319}', 0x27048),
+    ('\u{731b}', 0x14c54),
+    ('\u{731c}', 0x1604a),
+    ('\u{731d}', 0x1604c),
+    ('\u{731f}', 0x14e44),
+    ('\u{7325}', 0x16050),
+    ('\u{7327}', 0x1776d),
+    ('\u{7328}', 0x1776e),
+    ('\u{7329}', 0x1604f),
+    ('\u{732a}', 0x14376),
+    ('\u{732b}', 0x1472d),
+    ('\u{732c}', 0x2704b),
+    ('\u{732e}', 0x13825),
+    ('\u{732f}', 0x1604e),
+    ('\u{7331}', 0x2704c),
+    ('\u{7333}', 0x2704d),
+    ('\u{7334}', 0x1604d),
+    ('\u{7336}', 0x14d31),
+    ('\u{7337}', 0x14d32),
+    ('\u{7339}', 0x2704a),
+    ('\u{733d}', 0x2704e),
+    ('\u{733e}', 0x16051),
+    ('\u{733f}', 0x1316e),
+    ('\u{7344}', 0x13976),
+    ('\u{7345}', 0x13b62),
+    ('\u{734e}', 0x16052),
+    ('\u{734f}', 0x16053),
+    ('\u{7350}', 0x17770),
+    ('\u{7352}', 0x2704f),
+    ('\u{7357}', 0x16055),
+    ('\u{7363}', 0x13d43),
+    ('\u{7366}', 0x17771),
+    ('\u{7368}', 0x16057),
+    ('\u{736a}', 0x16056),
+    ('\u{736b}', 0x27051),
+    ('\u{736c}', 0x27052),
+    ('\u{736e}', 0x27054),
+    ('\u{736f}', 0x27055),
+    ('\u{7370}', 0x16058),
+    ('\u{7371}', 0x27056),
+    ('\u{7372}', 0x1334d),
+    ('\u{7375}', 0x1605a),
+    ('\u{7377}', 0x27057),
+    ('\u{7378}', 0x16059),
+    ('\u{737a}', 0x1605c),
+    ('\u{737b}', 0x1605b),
+    ('\u{737c}', 0x17772),
+    ('\u{7381}', 0x27058),
+    ('\u{7384}', 0x1383c),
+    ('\u{7385}', 0x27059),
+    ('\u{7387}', 0x14e28),
+    ('\u{7389}', 0x1364c),
+    ('\u{738a}', 0x2705a),
+    ('\u{738b}', 0x13226),
+    ('\u{7394}', 0x2705b),
+    ('\u{7395}', 0x17773),
+    ('\u{7396}', 0x1366a),
+    ('\u{7398}', 0x2705c),
+    ('\u{739c}', 0x2705d),
+    ('\u{739e}', 0x2705e),
+    ('\u{739f}', 0x17774),
+    ('\u{73a0}', 0x17775),
+    ('\u{73a2}', 0x17776),
+    ('\u{73a5}', 0x2705f),
+    ('\u{73a6}', 0x17777),
+    ('\u{73a8}', 0x27060),
+    ('\u{73a9}', 0x13461),
+    ('\u{73ab}', 0x17778),
+    ('\u{73b2}', 0x14e68),
+    ('\u{73b3}', 0x1605e),
+    ('\u{73b5}', 0x27061),
+    ('\u{73b7}', 0x27062),
+    ('\u{73b9}', 0x27063),
+    ('\u{73bb}', 0x16060),
+    ('\u{73bc}', 0x27064),
+    ('\u{73bf}', 0x27065),
+    ('\u{73c0}', 0x16061),
+    ('\u{73c2}', 0x13251),
+    ('\u{73c5}', 0x27066),
+    ('\u{73c8}', 0x1605d),
+    ('\u{73c9}', 0x17779),
+    ('\u{73ca}', 0x13b39),
+    ('\u{73cb}', 0x27067),
+    ('\u{73cd}', 0x14441),
+    ('\u{73ce}', 0x1605f),
+    ('\u{73cf}', 0x1777a),
+    ('\u{73d6}', 0x1777b),
+    ('\u{73d9}', 0x1777c),
+    ('\u{73de}', 0x16064),
+    ('\u{73e0}', 0x13c6e),
+    ('\u{73e1}', 0x27068),
+    ('\u{73e3}', 0x1777d),
+    ('\u{73e5}', 0x16062),
+    ('\u{73e7}', 0x27069),
+    ('\u{73e9}', 0x1777e),
+    ('\u{73ea}', 0x1373e),
+    ('\u{73ed}', 0x14849),
+    ('\u{73ee}', 0x16063),
+    ('\u{73f1}', 0x1607e),
+    ('\u{73f8}', 0x16069),
+    ('\u{73f9}', 0x2706a),
+    ('\u{73fa}', 0x2706c),
+    ('\u{73fe}', 0x1383d),
+    ('\u{7401}', 0x2706d),
+    ('\u{7403}', 0x13565),
+    ('\u{7405}', 0x16066),
+    ('\u{7406}', 0x14d7d),
+    ('\u{7407}', 0x17821),
+    ('\u{7409}', 0x14e30),
+    ('\u{740a}', 0x17822),
+    ('\u{7413}', 0x2706b),
+    ('\u{741a}', 0x17823),
+    ('\u{741b}', 0x17824),
+    ('\u{7422}', 0x14276),
+    ('\u{7424}', 0x2706e),
+    ('\u{7425}', 0x16068),
+    ('\u{7426}', 0x17826),
+    ('\u{7428}', 0x17827),
+    ('\u{742a}', 0x17828),
+    ('\u{742b}', 0x17829),
+    ('\u{742c}', 0x1782a),
+    ('\u{742e}', 0x1782b),
+    ('\u{742f}', 0x1782c),
+    ('\u{7430}', 0x1782d),
+    ('\u{7431}', 0x2706f),
+    ('\u{7432}', 0x1606a),
+    ('\u{7433}', 0x14e56),
+    ('\u{7434}', 0x13657),
+    ('\u{7435}', 0x1487c),
+    ('\u{7436}', 0x1474a),
+    ('\u{7439}', 0x27070),
+    ('\u{743a}', 0x1606b),
+    ('\u{743f}', 0x1606d),
+    ('\u{7440}', 0x27072),
+    ('\u{7441}', 0x16070),
+    ('\u{7443}', 0x27073),
+    ('\u{7444}', 0x1782e),
+    ('\u{7446}', 0x1782f),
+    ('\u{7447}', 0x17830),
+    ('\u{744b}', 0x17831),
+    ('\u{744d}', 0x27074),
+    ('\u{7452}', 0x27075),
+    ('\u{7453}', 0x27071),
+    ('\u{7455}', 0x1606c),
+    ('\u{7457}', 0x17832),
+    ('\u{7459}', 0x1606f),
+    ('\u{745a}', 0x1386a),
+    ('\u{745b}', 0x1314d),
+    ('\u{745c}', 0x16071),
+    ('\u{745d}', 0x27076),
+    ('\u{745e}', 0x13f70),
+    ('\u{745f}', 0x1606e),
+    ('\u{7460}', 0x14e5c),
+    ('\u{7462}', 0x17833),
+    ('\u{7463}', 0x16074),
+    ('\u{7464}', 0x17424),
+    ('\u{7469}', 0x16072),
+    ('\u{746a}', 0x16075),
+    ('\u{746b}', 0x17834),
+    ('\u{746d}', 0x17835),
+    ('\u{746f}', 0x16067),
+    ('\u{7470}', 0x16073),
+    ('\u{7471}', 0x27077),
+    ('\u{7473}', 0x13a3c),
+    ('\u{7476}', 0x16076),
+    ('\u{747e}', 0x16077),
+    ('\u{7481}', 0x27078),
+    ('\u{7483}', 0x14d7e),
+    ('\u{7485}', 0x27079),
+    ('\u{7486}', 0x17836),
+    ('\u{7487}', 0x17837),
+    ('\u{7488}', 0x2707a),
+    ('\u{7489}', 0x17838),
+    ('\u{748b}', 0x16078),
+    ('\u{7490}', 0x1783d),
+    ('\u{7492}', 0x2707c),
+    ('\u{7497}', 0x2707d),
+    ('\u{7498}', 0x17839),
+    ('\u{7499}', 0x2707e),
+    ('\u{749c}', 0x1783a),
+    ('\u{749e}', 0x16079),
+    ('\u{749f}', 0x1783b),
+    ('\u{74a0}', 0x27121),
+    ('\u{74a1}', 0x27122),
+    ('\u{74a2}', 0x16065),
+    ('\u{74a3}', 0x1783c),
+    ('\u{74a5}', 0x27123),
+    ('\u{74a6}', 0x1783e),
+    ('\u{74a7}', 0x1607a),
+    ('\u{74a8}', 0x1783f),
+    ('\u{74a9}', 0x17840),
+    ('\u{74aa}', 0x27124),
+    ('\u{74ab}', 0x27125),
+    ('\u{74b0}', 0x13444),
+    ('\u{74b5}', 0x17841),
+    ('\u{74b9}', 0x27126),
+    ('\u{74ba}', 0x27128),
+    ('\u{74bb}', 0x27127),
+    ('\u{74bd}', 0x13c25),
+    ('\u{74bf}', 0x17842),
+    ('\u{74c8}', 0x17843),
+    ('\u{74c9}', 0x17844),
+    ('\u{74ca}', 0x1607b),
+    ('\u{74cf}', 0x1607c),
+    ('\u{74d4}', 0x1607d),
+    ('\u{74d6}', 0x27129),
+    ('\u{74d8}', 0x2712a),
+    ('\u{74da}', 0x17845),
+    ('\u{74dc}', 0x1313b),
+    ('\u{74de}', 0x2712b),
+    ('\u{74e0}', 0x16121),
+    ('\u{74e2}', 0x1493b),
+    ('\u{74e3}', 0x16122),
+    ('\u{74e6}', 0x13424),
+    ('\u{74e7}', 0x16123),
+    ('\u{74e9}', 0x16124),
+    ('\u{74eb}', 0x2712d),
+    ('\u{74ee}', 0x16125),
+    ('\u{74ef}', 0x2712c),
+    ('\u{74f0}', 0x16127),
+    ('\u{74f1}', 0x16128),
+    ('\u{74f2}', 0x16126),
+    ('\u{74f6}', 0x14953),
+    ('\u{74f7}', 0x1612a),
+    ('\u{74f8}', 0x16129),
+    ('\u{74fa}', 0x2712f),
+    ('\u{74ff}', 0x17846),
+    ('\u{7501}', 0x17847),
+    ('\u{7503}', 0x1612c),
+    ('\u{7504}', 0x1612b),
+    ('\u{7505}', 0x1612d),
+    ('\u{750c}', 0x1612e),
+    ('\u{750d}', 0x16130),
+    ('\u{750e}', 0x1612f),
+    ('\u{7511}', 0x13979),
+    ('\u{7513}', 0x16132),
+    ('\u{7515}', 0x16131),
+    ('\u{7517}', 0x17848),
+    ('\u{7518}', 0x13445),
+    ('\u{751a}', 0x13f53),
+    ('\u{751c}', 0x1453c),
+    ('\u{751e}', 0x16133),
+    ('\u{751f}', 0x14038),
+    ('\u{7520}', 0x27131),
+    ('\u{7523}', 0x13b3a),
+    ('\u{7524}', 0x27132),
+    ('\u{7525}', 0x13179),
+    ('\u{7526}', 0x16134),
+    ('\u{7528}', 0x14d51),
+    ('\u{752a}', 0x27133),
+    ('\u{752b}', 0x14a63),
+    ('\u{752c}', 0x16135),
+    ('\u{752f}', 0x17849),
+    ('\u{7530}', 0x14544),
+    ('\u{7531}', 0x14d33),
+    ('\u{7532}', 0x13943),
+    ('\u{7533}', 0x13f3d),
+    ('\u{7537}', 0x1434b),
+    ('\u{7538}', 0x15234),
+    ('\u{753a}', 0x1442e),
+    ('\u{753b}', 0x13268),
+    ('\u{753c}', 0x16136),
+    ('\u{753d}', 0x27136),
+    ('\u{753e}', 0x27137),
+    ('\u{7540}', 0x27138),
+    ('\u{7544}', 0x16137),
+    ('\u{7546}', 0x1613c),
+    ('\u{7548}', 0x27139),
+    ('\u{7549}', 0x1613a),
+    ('\u{754a}', 0x16139),
+    ('\u{754b}', 0x15a42),
+    ('\u{754c}', 0x13326),
+    ('\u{754d}', 0x16138),
+    ('\u{754e}', 0x2713a),
+    ('\u{754f}', 0x1305a),
+    ('\u{7550}', 0x2713b),
+    ('\u{7551}', 0x1482a),
+    ('\u{7552}', 0x2713c),
+    ('\u{7554}', 0x1484a),
+    ('\u{7559}', 0x14e31),
+    ('\u{755a}', 0x1613d),
+    ('\u{755b}', 0x1613b),
+    ('\u{755c}', 0x1435c),
+    ('\u{755d}', 0x14026),
+    ('\u{7560}', 0x1482b),
+    ('\u{7562}', 0x1492d),
+    ('\u{7564}', 0x1613f),
+    ('\u{7565}', 0x14e2c),
+    ('\u{7566}', 0x1374d),
+    ('\u{7567}', 0x16140),
+    ('\u{7569}', 0x1613e),
+    ('\u{756a}', 0x14856),
+    ('\u{756b}', 0x16141),
+    ('\u{756c}', 0x2713d),
+    ('\u{756d}', 0x16142),
+    ('\u{756f}', 0x1784a),
+    ('\u{7570}', 0x1305b),
+    ('\u{7571}', 0x2713f),
+    ('\u{7572}', 0x2713e),
+    ('\u{7573}', 0x13e76),
+    ('\u{7574}', 0x16147),
+    ('\u{7576}', 0x16144),
+    ('\u{7577}', 0x1466d),
+    ('\u{7578}', 0x16143),
+    ('\u{7579}', 0x1784b),
+    ('\u{757a}', 0x27140),
+    ('\u{757d}', 0x27141),
+    ('\u{757e}', 0x27142),
+    ('\u{757f}', 0x13526),
+    ('\u{7581}', 0x27143),
+    ('\u{7582}', 0x1614a),
+    ('\u{7586}', 0x16145),
+    ('\u{7587}', 0x16146),
+    ('\u{7589}', 0x16149),
+    ('\u{758a}', 0x16148),
+    ('\u{758b}', 0x14925),
+    ('\u{758c}', 0x27145),
+    ('\u{758e}', 0x14142),
+    ('\u{758f}', 0x14141),
+    ('\u{7591}', 0x1353f),
+    ('\u{7592}', 0x1784c),
+    ('\u{7594}', 0x1614b),
+    ('\u{759a}', 0x1614c),
+    ('\u{759d}', 0x1614d),
+    ('\u{75a2}', 0x27147),
+    ('\u{75a3}', 0x1614f),
+    ('\u{75a5}', 0x1614e),
+    ('\u{75ab}', 0x13156),
+    ('\u{75b0}', 0x27149),
+    ('\u{75b1}', 0x16157),
+    ('\u{75b2}', 0x14868),
+    ('\u{75b3}', 0x16151),
+    ('\u{75b5}', 0x16153),
+    ('\u{75b7}', 0x2714a),
+    ('\u{75b8}', 0x16155),
+    ('\u{75b9}', 0x13f3e),
+    ('\u{75bc}', 0x16156),
+    ('\u{75bd}', 0x16154),
+    ('\u{75be}', 0x13c40),
+    ('\u{75bf}', 0x2714b),
+    ('\u{75c0}', 0x2714c),
+    ('\u{75c2}', 0x16150),
+    ('\u{75c3}', 0x16152),
+    ('\u{75c5}', 0x14942),
+    ('\u{75c6}', 0x2714d),
+    ('\u{75c7}', 0x13e49),
+    ('\u{75ca}', 0x16159),
+    ('\u{75cd}', 0x16158),
+    ('\u{75ce}', 0x1784e),
+    ('\u{75cf}', 0x2714e),
+    ('\u{75d2}', 0x1615a),
+    ('\u{75d3}', 0x2714f),
+    ('\u{75d4}', 0x13c26),
+    ('\u{75d5}', 0x13a2f),
+    ('\u{75d8}', 0x14577),
+    ('\u{75d9}', 0x1615b),
+    ('\u{75db}', 0x1444b),
+    ('\u{75dd}', 0x27150),
+    ('\u{75de}', 0x1615d),
+    ('\u{75df}', 0x27151),
+    ('\u{75e0}', 0x27152),
+    ('\u{75e2}', 0x14e21),
+    ('\u{75e3}', 0x1615c),
+    ('\u{75e4}', 0x1784f),
+    ('\u{75e7}', 0x27153),
+    ('\u{75e9}', 0x14169),
+    ('\u{75ec}', 0x27154),
+    ('\u{75ee}', 0x27155),
+    ('\u{75f0}', 0x16162),
+    ('\u{75f1}', 0x27156),
+    ('\u{75f2}', 0x16164),
+    ('\u{75f3}', 0x16165),
+    ('\u{75f4}', 0x14354),
+    ('\u{75f9}', 0x27157),
+    ('\u{75fa}', 0x16163),
+    ('\u{75fc}', 0x16160),
+    ('\u{75fe}', 0x1615e),
+    ('\u{75ff}', 0x1615f),
+    ('\u{7600}', 0x17850),
+    ('\u{7601}', 0x16161),
+    ('\u{7602}', 0x17851),
+    ('\u{7603}', 0x27158),
+    ('\u{7607}', 0x2715a),
+    ('\u{7608}', 0x17852),
+    ('\u{7609}', 0x16168),
+    ('\u{760b}', 0x16166),
+    ('\u{760d}', 0x16167),
+    ('\u{760f}', 0x2715b),
+    ('\u{7613}', 0x2715e),
+    ('\u{7615}', 0x17853),
+    ('\u{7616}', 0x17854),
+    ('\u{7618}', 0x27159),
+    ('\u{7619}', 0x17855),
+    ('\u{761b}', 0x2715f),
+    ('\u{761c}', 0x27160),
+    ('\u{761e}', 0x17856),
+    ('\u{761f}', 0x16169),
+    ('\u{7620}', 0x1616b),
+    ('\u{7621}', 0x1616c),
+    ('\u{7622}', 0x1616d),
+    ('\u{7624}', 0x1616e),
+    ('\u{7625}', 0x27162),
+    ('\u{7626}', 0x17e7d),
+    ('\u{7627}', 0x1616a),
+    ('\u{7628}', 0x27163),
+    ('\u{762d}', 0x17857),
+    ('\u{7630}', 0x16170),
+    ('\u{7633}', 0x27165),
+    ('\u{7634}', 0x1616f),
+    ('\u{7635}', 0x17858),
+    ('\u{763b}', 0x16171),
+    ('\u{763c}', 0x27164),
+    ('\u{7641}', 0x27168),
+    ('\u{7642}', 0x14e45),
+    ('\u{7643}', 0x17859),
+    ('\u{7646}', 0x16174),
+    ('\u{7647}', 0x16172),
+    ('\u{7648}', 0x16173),
+    ('\u{7649}', 0x2716a),
+    ('\u{764b}', 0x1785a),
+    ('\u{764c}', 0x13462),
+    ('\u{7652}', 0x14c7e),
+    ('\u{7655}', 0x2716b),
+    ('\u{7656}', 0x14a4a),
+    ('\u{7658}', 0x16176),
+    ('\u{765c}', 0x16175),
+    ('\u{7661}', 0x16177),
+    ('\u{7662}', 0x16178),
+    ('\u{7664}', 0x1785b),
+    ('\u{7665}', 0x1785c),
+    ('\u{7667}', 0x1617c),
+    ('\u{7668}', 0x16179),
+    ('\u{7669}', 0x1617a),
+    ('\u{766a}', 0x1617b),
+    ('\u{766c}', 0x1617d),
+    ('\u{766d}', 0x1785d),
+    ('\u{766e}', 0x2716d),
+    ('\u{766f}', 0x1785e),
+    ('\u{7670}', 0x1617e),
+    ('\u{7671}', 0x1785f),
+    ('\u{7672}', 0x16221),
+    ('\u{7676}', 0x16222),
+    ('\u{7678}', 0x16223),
+    ('\u{767a}', 0x1482f),
+    ('\u{767b}', 0x14550),
+    ('\u{767c}', 0x16224),
+    ('\u{767d}', 0x14772),
+    ('\u{767e}', 0x14934),
+    ('\u{7680}', 0x16225),
+    ('\u{7681}', 0x17860),
+    ('\u{7683}', 0x16226),
+    ('\u{7684}', 0x1452a),
+    ('\u{7686}', 0x13327),
+    ('\u{7687}', 0x13944),
+    ('\u{7688}', 0x16227),
+    ('\u{768b}', 0x16228),
+    ('\u{768e}', 0x16229),
+    ('\u{7690}', 0x13b29),
+    ('\u{7693}', 0x1622b),
+    ('\u{7695}', 0x2716e),
+    ('\u{7696}', 0x1622a),
+    ('\u{7699}', 0x1622c),
+    ('\u{769a}', 0x1622d),
+    ('\u{769b}', 0x17861),
+    ('\u{769c}', 0x2716f),
+    ('\u{769d}', 0x17862),
+    ('\u{769e}', 0x17863),
+    ('\u{76a0}', 0x27171),
+    ('\u{76a1}', 0x27170),
+    ('\u{76a6}', 0x17864),
+    ('\u{76a7}', 0x27172),
+    ('\u{76a8}', 0x27173),
+    ('\u{76aa}', 0x17865),
+    ('\u{76ae}', 0x14869),
+    ('\u{76af}', 0x27174),
+    ('\u{76b0}', 0x1622e),
+    ('\u{76b4}', 0x1622f),
+    ('\u{76b6}', 0x17866),
+    ('\u{76b7}', 0x17369),
+    ('\u{76b8}', 0x16230),
+    ('\u{76b9}', 0x16231),
+    ('\u{76ba}', 0x16232),
+    ('\u{76bf}', 0x13b2e),
+    ('\u{76c2}', 0x16233),
+    ('\u{76c3}', 0x14756),
+    ('\u{76c5}', 0x17867),
+    ('\u{76c6}', 0x14b5f),
+    ('\u{76c8}', 0x1314e),
+    ('\u{76c9}', 0x27176),
+    ('\u{76ca}', 0x13157),
+    ('\u{76cc}', 0x17868),
+    ('\u{76cd}', 0x16234),
+    ('\u{76ce}', 0x17869),
+    ('\u{76d2}', 0x16236),
+    ('\u{76d4}', 0x1786a),
+    ('\u{76d6}', 0x16235),
+    ('\u{76d7}', 0x14570),
+    ('\u{76db}', 0x14039),
+    ('\u{76dc}', 0x15d39),
+    ('\u{76de}', 0x16237),
+    ('\u{76df}', 0x14c41),
+    ('\u{76e1}', 0x16238),
+    ('\u{76e3}', 0x13446),
+    ('\u{76e4}', 0x14857),
+    ('\u{76e5}', 0x16239),
+    ('\u{76e6}', 0x1786b),
+    ('\u{76e7}', 0x1623a),
+    ('\u{76e8}', 0x27178),
+    ('\u{76ea}', 0x1623b),
+    ('\u{76ec}', 0x27179),
+    ('\u{76ee}', 0x14c5c),
+    ('\u{76f1}', 0x1786c),
+    ('\u{76f2}', 0x14c55),
+    ('\u{76f4}', 0x1443e),
+    ('\u{76f8}', 0x1416a),
+    ('\u{76fb}', 0x1623d),
+    ('\u{76fc}', 0x1786d),
+    ('\u{76fe}', 0x13d62),
+    ('\u{7701}', 0x13e4a),
+    ('\u{7704}', 0x16240),
+    ('\u{7707}', 0x1623f),
+    ('\u{7708}', 0x1623e),
+    ('\u{7709}', 0x1487d),
+    ('\u{770a}', 0x1786e),
+    ('\u{770b}', 0x13447),
+    ('\u{770c}', 0x13829),
+    ('\u{7717}', 0x2717b),
+    ('\u{7719}', 0x1786f),
+    ('\u{771a}', 0x2717c),
+    ('\u{771b}', 0x16246),
+    ('\u{771e}', 0x16243),
+    ('\u{771f}', 0x13f3f),
+    ('\u{7720}', 0x14c32),
+    ('\u{7724}', 0x16242),
+    ('\u{7725}', 0x16244),
+    ('\u{7726}', 0x16245),
+    ('\u{7729}', 0x16241),
+    ('\u{772d}', 0x2717d),
+    ('\u{7734}', 0x17870),
+    ('\u{7735}', 0x2717e),
+    ('\u{7736}', 0x17871),
+    ('\u{7737}', 0x16247),
+    ('\u{7738}', 0x16248),
+    ('\u{773a}', 0x1442f),
+    ('\u{773c}', 0x13463),
+    ('\u{7740}', 0x14365),
+    ('\u{7746}', 0x17872),
+    ('\u{7747}', 0x16249),
+    ('\u{774d}', 0x17873),
+    ('\u{774e}', 0x17874),
+    ('\u{7758}', 0x27225),
+    ('\u{775a}', 0x1624a),
+    ('\u{775b}', 0x1624d),
+    ('\u{775c}', 0x17875),
+    ('\u{775f}', 0x17876),
+    ('\u{7760}', 0x27226),
+    ('\u{7761}', 0x13f67),
+    ('\u{7762}', 0x17877),
+    ('\u{7763}', 0x14644),
+    ('\u{7765}', 0x1624e),
+    ('\u{7766}', 0x14b53),
+    ('\u{7768}', 0x1624b),
+    ('\u{776a}', 0x27227),
+    ('\u{776b}', 0x1624c),
+    ('\u{7772}', 0x27229),
+    ('\u{7779}', 0x16251),
+    ('\u{777a}', 0x17878),
+    ('\u{777c}', 0x2722a),
+    ('\u{777d}', 0x2722b),
+    ('\u{777e}', 0x16250),
+    ('\u{777f}', 0x1624f),
+    ('\u{7780}', 0x17879),
+    ('\u{778b}', 0x16253),
+    ('\u{778e}', 0x16252),
+    ('\u{7791}', 0x16254),
+    ('\u{7794}', 0x1787a),
+    ('\u{779a}', 0x2722e),
+    ('\u{779e}', 0x16256),
+    ('\u{779f}', 0x2722f),
+    ('\u{77a0}', 0x16255),
+    ('\u{77a2}', 0x27230),
+    ('\u{77a4}', 0x27231),
+    ('\u{77a5}', 0x14a4d),
+    ('\u{77a9}', 0x27232),
+    ('\u{77aa}', 0x1787b),
+    ('\u{77ac}', 0x13d56),
+    ('\u{77ad}', 0x14e46),
+    ('\u{77b0}', 0x16257),
+    ('\u{77b3}', 0x14637),
+    ('\u{77b6}', 0x16258),
+    ('\u{77b9}', 0x16259),
+    ('\u{77bb}', 0x1625d),
+    ('\u{77bc}', 0x1625b),
+    ('\u{77bd}', 0x1625c),
+    ('\u{77bf}', 0x1625a),
+    ('\u{77c7}', 0x1625e),
+    ('\u{77cd}', 0x1625f),
+    ('\u{77d7}', 0x16260),
+    ('\u{77da}', 0x16261),
+    ('\u{77db}', 0x14c37),
+    ('\u{77dc}', 0x16262),
+    ('\u{77de}', 0x27233),
+    ('\u{77df}', 0x27234),
+    ('\u{77e0}', 0x1787c),
+    ('\u{77e2}', 0x14c70),
+    ('\u{77e3}', 0x16263),
+    ('\u{77e4}', 0x27235),
+    ('\u{77e5}', 0x1434e),
+    ('\u{77e6}', 0x27236),
+    ('\u{77e7}', 0x1476a),
+    ('\u{77e9}', 0x1366b),
+    ('\u{77ea}', 0x27237),
+    ('\u{77ec}', 0x27238),
+    ('\u{77ed}', 0x1433b),
+    ('\u{77ee}', 0x16264),
+    ('\u{77ef}', 0x1363a),
+    ('\u{77f0}', 0x2723a),
+    ('\u{77f3}', 0x14050),
+    ('\u{77f4}', 0x2723b),
+    ('\u{77fb}', 0x2723c),
+    ('\u{77fc}', 0x16265),
+    ('\u{7802}', 0x13a3d),
+    ('\u{7805}', 0x2723e),
+    ('\u{7806}', 0x2723f),
+    ('\u{7809}', 0x27240),
+    ('\u{780c}', 0x16266),
+    ('\u{780d}', 0x27241),
+    ('\u{7812}', 0x16267),
+    ('\u{7814}', 0x13826),
+    ('\u{7815}', 0x13a55),
+    ('\u{7819}', 0x27242),
+    ('\u{7820}', 0x16269),
+    ('\u{7821}', 0x27243),
+    ('\u{7825}', 0x14556),
+    ('\u{7826}', 0x13a56),
+    ('\u{7827}', 0x1354e),
+    ('\u{782c}', 0x27244),
+    ('\u{782d}', 0x1787d),
+    ('\u{7832}', 0x14b24),
+    ('\u{7834}', 0x1474b),
+    ('\u{783a}', 0x14557),
+    ('\u{783f}', 0x1395c),
+    ('\u{7843}', 0x17921),
+    ('\u{7845}', 0x1626b),
+    ('\u{7847}', 0x27245),
+    ('\u{784e}', 0x17922),
+    ('\u{784f}', 0x17923),
+    ('\u{7851}', 0x17924),
+    ('\u{785d}', 0x13e4b),
+    ('\u{7864}', 0x27246),
+    ('\u{7868}', 0x17925),
+    ('\u{786a}', 0x27247),
+    ('\u{786b}', 0x14e32),
+    ('\u{786c}', 0x13945),
+    ('\u{786e}', 0x17926),
+    ('\u{786f}', 0x13827),
+    ('\u{7872}', 0x14823),
+    ('\u{7874}', 0x1626d),
+    ('\u{787c}', 0x1626f),
+    ('\u{7881}', 0x1386b),
+    ('\u{7886}', 0x1626e),
+    ('\u{7887}', 0x14476),
+    ('\u{788a}', 0x27249),
+    ('\u{788c}', 0x16271),
+    ('\u{788d}', 0x13337),
+    ('\u{788e}', 0x1626c),
+    ('\u{7891}', 0x1486a),
+    ('\u{7893}', 0x13130),
+    ('\u{7894}', 0x2724a),
+    ('\u{7895}', 0x13a6c),
+    ('\u{7897}', 0x14f52),
+    ('\u{789a}', 0x16270),
+    ('\u{789d}', 0x2724c),
+    ('\u{789e}', 0x2724d),
+    ('\u{789f}', 0x2724e),
+    ('\u{78a3}', 0x16272),
+    ('\u{78a4}', 0x2724b),
+    ('\u{78a7}', 0x14a4b),
+    ('\u{78a9}', 0x14059),
+    ('\u{78aa}', 0x16274),
+    ('\u{78ad}', 0x1792a),
+    ('\u{78af}', 0x16275),
+    ('\u{78b0}', 0x17928),
+    ('\u{78b5}', 0x16273),
+    ('\u{78ba}', 0x1334e),
+    ('\u{78bb}', 0x2724f),
+    ('\u{78bc}', 0x1627b),
+    ('\u{78be}', 0x1627a),
+    ('\u{78c1}', 0x13c27),
+    ('\u{78c5}', 0x1627c),
+    ('\u{78c6}', 0x16277),
+    ('\u{78c8}', 0x27250),
+    ('\u{78ca}', 0x1627d),
+    ('\u{78cb}', 0x16278),
+    ('\u{78cc}', 0x27251),
+    ('\u{78ce}', 0x27252),
+    ('\u{78d0}', 0x14858),
+    ('\u{78d1}', 0x16276),
+    ('\u{78d4}', 0x16279),
+    ('\u{78d5}', 0x27253),
+    ('\u{78da}', 0x16322),
+    ('\u{78e0}', 0x27254),
+    ('\u{78e1}', 0x27255),
+    ('\u{78e4}', 0x1792b),
+    ('\u{78e6}', 0x27256),
+    ('\u{78e7}', 0x16321),
+    ('\u{78e8}', 0x14b61),
+    ('\u{78ec}', 0x1627e),
+    ('\u{78ef}', 0x1306b),
+    ('\u{78f2}', 0x1792c),
+    ('\u{78f4}', 0x16324),
+    ('\u{78f7}', 0x1792e),
+    ('\u{78f9}', 0x27257),
+    ('\u{78fa}', 0x27258),
+    ('\u{78fb}', 0x27259),
+    ('\u{78fd}', 0x16323),
+    ('\u{78fe}', 0x2725a),
+    ('\u{7900}', 0x1792d),
+    ('\u{7901}', 0x13e4c),
+    ('\u{7907}', 0x16325),
+    ('\u{790e}', 0x14143),
+    ('\u{7910}', 0x2725c),
+    ('\u{7911}', 0x16327),
+    ('\u{7912}', 0x16326),
+    ('\u{7919}', 0x16328),
+    ('\u{791b}', 0x2725d),
+    ('\u{791c}', 0x1792f),
+    ('\u{7925}', 0x2725f),
+    ('\u{7926}', 0x16268),
+    ('\u{792a}', 0x1626a),
+    ('\u{792b}', 0x1632a),
+    ('\u{792c}', 0x16329),
+    ('\u{792e}', 0x17930),
+    ('\u{7930}', 0x2725e),
+    ('\u{7931}', 0x17931),
+    ('\u{7934}', 0x17932),
+    ('\u{793a}', 0x13c28),
+    ('\u{793b}', 0x27260),
+    ('\u{793c}', 0x14e69),
+    ('\u{793e}', 0x13c52),
+    ('\u{7940}', 0x1632b),
+    ('\u{7941}', 0x13737),
+    ('\u{7945}', 0x17935),
+    ('\u{7946}', 0x17936),
+    ('\u{7947}', 0x13540),
+    ('\u{7948}', 0x13527),
+    ('\u{7949}', 0x13b63),
+    ('\u{794a}', 0x27261),
+    ('\u{7950}', 0x14d34),
+    ('\u{7953}', 0x16331),
+    ('\u{7955}', 0x16330),
+    ('\u{7956}', 0x14144),
+    ('\u{7957}', 0x1632d),
+    ('\u{7958}', 0x27262),
+    ('\u{795a}', 0x1632f),
+    ('\u{795b}', 0x27263),
+    ('\u{795c}', 0x1793a),
+    ('\u{795d}', 0x13d4b),
+    ('\u{795e}', 0x13f40),
+    ('\u{795f}', 0x1632e),
+    ('\u{7960}', 0x1632c),
+    ('\u{7962}', 0x1472a),
+    ('\u{7965}', 0x13e4d),
+    ('\u{7967}', 0x27265),
+    ('\u{7968}', 0x1493c),
+    ('\u{796d}', 0x13a57),
+    ('\u{7972}', 0x27266),
+    ('\u{7977}', 0x14578),
+    ('\u{7979}', 0x1793e),
+    ('\u{797a}', 0x16332),
+    ('\u{797f}', 0x16333),
+    ('\u{7980}', 0x16349),
+    ('\u{7981}', 0x13658),
+    ('\u{7984}', 0x14f3d),
+    ('\u{7985}', 0x14135),
+    ('\u{798a}', 0x16334),
+    ('\u{798d}', 0x13252),
+    ('\u{798e}', 0x14477),
+    ('\u{798f}', 0x14a21),
+    ('\u{7994}', 0x27267),
+    ('\u{7995}', 0x27268),
+    ('\u{7996}', 0x27269),
+    ('\u{7998}', 0x17942),
+    ('\u{799b}', 0x2726a),
+    ('\u{799d}', 0x16335),
+    ('\u{79a1}', 0x2726b),
+    ('\u{79a6}', 0x1357a),
+    ('\u{79a7}', 0x16336),
+    ('\u{79a9}', 0x2726c),
+    ('\u{79aa}', 0x16338),
+    ('\u{79ae}', 0x16339),
+    ('\u{79b0}', 0x14729),
+    ('\u{79b1}', 0x17943),
+    ('\u{79b3}', 0x1633a),
+    ('\u{79b4}', 0x2726d),
+    ('\u{79b8}', 0x17944),
+    ('\u{79b9}', 0x1633b),
+    ('\u{79ba}', 0x1633c),
+    ('\u{79bb}', 0x2726e),
+    ('\u{79bd}', 0x13659),
+    ('\u{79be}', 0x13253),
+    ('\u{79bf}', 0x14645),
+    ('\u{79c0}', 0x13d28),
+    ('\u{79c1}', 0x13b64),
+    ('\u{79c2}', 0x2726f),
+    ('\u{79c7}', 0x27270),
+    ('\u{79c8}', 0x17945),
+    ('\u{79c9}', 0x1633d),
+    ('\u{79ca}', 0x17946),
+    ('\u{79cb}', 0x13d29),
+    ('\u{79cc}', 0x27271),
+    ('\u{79cd}', 0x27272),
+    ('\u{79d1}', 0x1324a),
+    ('\u{79d2}', 0x14943),
+    ('\u{79d4}', 0x17948),
+    ('\u{79d5}', 0x1633e),
+    ('\u{79d6}', 0x27273),
+    ('\u{79d8}', 0x1486b),
+    ('\u{79de}', 0x17949),
+    ('\u{79df}', 0x14145),
+    ('\u{79e1}', 0x16341),
+    ('\u{79e3}', 0x16342),
+    ('\u{79e4}', 0x14769),
+    ('\u{79e6}', 0x13f41),
+    ('\u{79e7}', 0x1633f),
+    ('\u{79e9}', 0x14361),
+    ('\u{79eb}', 0x1794a),
+    ('\u{79ec}', 0x16340),
+    ('\u{79ed}', 0x1794b),
+    ('\u{79f0}', 0x13e4e),
+    ('\u{79fb}', 0x1305c),
+    ('\u{7a00}', 0x13529),
+    ('\u{7a03}', 0x1794c),
+    ('\u{7a08}', 0x16343),
+    ('\u{7a0a}', 0x27278),
+    ('\u{7a0b}', 0x14478),
+    ('\u{7a0d}', 0x16344),
+    ('\u{7a0e}', 0x14047),
+    ('\u{7a11}', 0x27279),
+    ('\u{7a14}', 0x14c2d),
+    ('\u{7a15}', 0x2727a),
+    ('\u{7a17}', 0x14923),
+    ('\u{7a18}', 0x16345),
+    ('\u{7a19}', 0x16346),
+    ('\u{7a1a}', 0x14355),
+    ('\u{7a1b}', 0x2727b),
+    ('\u{7a1c}', 0x14e47),
+    ('\u{7a1e}', 0x2727c),
+    ('\u{7a1f}', 0x16348),
+    ('\u{7a20}', 0x16347),
+    ('\u{7a2d}', 0x2727e),
+    ('\u{7a2e}', 0x13c6f),
+    ('\u{7a31}', 0x1634a),
+    ('\u{7a32}', 0x13070),
+    ('\u{7a37}', 0x1634d),
+    ('\u{7a38}', 0x27321),
+    ('\u{7a39}', 0x1794e),
+    ('\u{7a3b}', 0x1634b),
+    ('\u{7a3c}', 0x13254),
+    ('\u{7a3d}', 0x1374e),
+    ('\u{7a3e}', 0x1634c),
+    ('\u{7a3f}', 0x13946),
+    ('\u{7a40}', 0x13972),
+    ('\u{7a42}', 0x14a66),
+    ('\u{7a43}', 0x1634e),
+    ('\u{7a46}', 0x14b54),
+    ('\u{7a47}', 0x27322),
+    ('\u{7a49}', 0x16350),
+    ('\u{7a4c}', 0x27323),
+    ('\u{7a4d}', 0x14051),
+    ('\u{7a4e}', 0x1314f),
+    ('\u{7a4f}', 0x1323a),
+    ('\u{7a50}', 0x1302c),
+    ('\u{7a56}', 0x27324),
+    ('\u{7a57}', 0x1634f),
+    ('\u{7a59}', 0x27325),
+    ('\u{7a5c}', 0x27326),
+    ('\u{7a5d}', 0x1794f),
+    ('\u{7a5f}', 0x27327),
+    ('\u{7a60}', 0x27328),
+    ('\u{7a61}', 0x16351),
+    ('\u{7a62}', 0x16352),
+    ('\u{7a63}', 0x13e77),
+    ('\u{7a67}', 0x27329),
+    ('\u{7a69}', 0x16353),
+    ('\u{7a6a}', 0x2732a),
+    ('\u{7a6b}', 0x1334f),
+    ('\u{7a6d}', 0x17950),
+    ('\u{7a70}', 0x16355),
+    ('\u{7a74}', 0x1376a),
+    ('\u{7a75}', 0x2732b),
+    ('\u{7a76}', 0x13566),
+    ('\u{7a78}', 0x2732c),
+    ('\u{7a79}', 0x16356),
+    ('\u{7a7a}', 0x13675),
+    ('\u{7a7d}', 0x16357),
+    ('\u{7a7f}', 0x1407c),
+    ('\u{7a81}', 0x1464d),
+    ('\u{7a82}', 0x2732d),
+    ('\u{7a83}', 0x14060),
+    ('\u{7a84}', 0x13a75),
+    ('\u{7a85}', 0x17952),
+    ('\u{7a88}', 0x16358),
+    ('\u{7a8a}', 0x2732e),
+    ('\u{7a90}', 0x2732f),
+    ('\u{7a92}', 0x14362),
+    ('\u{7a93}', 0x1416b),
+    ('\u{7a95}', 0x1635a),
+    ('\u{7a96}', 0x1635c),
+    ('\u{7a97}', 0x16359),
+    ('\u{7a98}', 0x1635b),
+    ('\u{7a9f}', 0x13722),
+    ('\u{7aa0}', 0x17953),
+    ('\u{7aa3}', 0x27330),
+    ('\u{7aa9}', 0x1635d),
+    ('\u{7aaa}', 0x13726),
+    ('\u{7aac}', 0x27331),
+    ('\u{7aae}', 0x13567),
+    ('\u{7aaf}', 0x14d52),
+    ('\u{7ab0}', 0x1635f),
+    ('\u{7ab3}', 0x17955),
+    ('\u{7ab6}', 0x16360),
+    ('\u{7ab9}', 0x27334),
+    ('\u{7aba}', 0x1312e),
+    ('\u{7abb}', 0x17956),
+    ('\u{7abc}', 0x27335),
+    ('\u{7abe}', 0x27336),
+    ('\u{7abf}', 0x16363),
+    ('\u{7ac3}', 0x13376),
+    ('\u{7ac4}', 0x16362),
+    ('\u{7ac5}', 0x16361),
+    ('\u{7ac7}', 0x16365),
+    ('\u{7ac8}', 0x1635e),
+    ('\u{7aca}', 0x16366),
+    ('\u{7acb}', 0x14e29),
+    ('\u{7acc}', 0x27338),
+    ('\u{7acd}', 0x16367),
+    ('\u{7ace}', 0x17957),
+    ('\u{7acf}', 0x16368),
+    ('\u{7ad1}', 0x27339),
+    ('\u{7ad2}', 0x15474),
+    ('\u{7ad3}', 0x1636a),
+    ('\u{7ad5}', 0x16369),
+    ('\u{7ad9}', 0x1636b),
+    ('\u{7ada}', 0x1636c),
+    ('\u{7adc}', 0x14e35),
+    ('\u{7add}', 0x1636d),
+    ('\u{7adf}', 0x1706f),
+    ('\u{7ae0}', 0x13e4f),
+    ('\u{7ae1}', 0x1636e),
+    ('\u{7ae2}', 0x1636f),
+    ('\u{7ae3}', 0x13d57),
+    ('\u{7ae5}', 0x14638),
+    ('\u{7ae6}', 0x16370),
+    ('\u{7ae7}', 0x2733a),
+    ('\u{7ae8}', 0x2733b),
+    ('\u{7aea}', 0x14328),
+    ('\u{7aeb}', 0x17958),
+    ('\u{7aed}', 0x16371),
+    ('\u{7aef}', 0x1433c),
+    ('\u{7af0}', 0x16372),
+    ('\u{7af4}', 0x2733c),
+    ('\u{7af6}', 0x13625),
+    ('\u{7af8}', 0x1513f),
+    ('\u{7af9}', 0x1435d),
+    ('\u{7afa}', 0x13c33),
+    ('\u{7afd}', 0x17959),
+    ('\u{7aff}', 0x13448),
+    ('\u{7b02}', 0x16373),
+    ('\u{7b04}', 0x16422),
+    ('\u{7b06}', 0x16376),
+    ('\u{7b07}', 0x2733f),
+    ('\u{7b08}', 0x13568),
+    ('\u{7b0a}', 0x16375),
+    ('\u{7b0b}', 0x16424),
+    ('\u{7b0f}', 0x16374),
+    ('\u{7b11}', 0x13e50),
+    ('\u{7b12}', 0x1795a),
+    ('\u{7b18}', 0x16378),
+    ('\u{7b19}', 0x16379),
+    ('\u{7b1b}', 0x1452b),
+    ('\u{7b1e}', 0x1637a),
+    ('\u{7b20}', 0x1335e),
+    ('\u{7b25}', 0x13f5a),
+    ('\u{7b26}', 0x14964),
+    ('\u{7b27}', 0x27342),
+    ('\u{7b28}', 0x1637c),
+    ('\u{7b2a}', 0x27343),
+    ('\u{7b2c}', 0x14268),
+    ('\u{7b2d}', 0x1795b),
+    ('\u{7b2e}', 0x27344),
+    ('\u{7b2f}', 0x27345),
+    ('\u{7b31}', 0x27346),
+    ('\u{7b33}', 0x16377),
+    ('\u{7b35}', 0x1637b),
+    ('\u{7b36}', 0x1637d),
+    ('\u{7b39}', 0x13a7b),
+    ('\u{7b3b}', 0x1795c),
+    ('\u{7b3d}', 0x27341),
+    ('\u{7b41}', 0x2734a),
+    ('\u{7b45}', 0x16426),
+    ('\u{7b46}', 0x1492e),
+    ('\u{7b47}', 0x1795d),
+    ('\u{7b48}', 0x14826),
+    ('\u{7b49}', 0x14579),
+    ('\u{7b4b}', 0x1365a),
+    ('\u{7b4c}', 0x16425),
+    ('\u{7b4d}', 0x16423),
+    ('\u{7b4e}', 0x1795e),
+    ('\u{7b4f}', 0x14835),
+    ('\u{7b50}', 0x1637e),
+    ('\u{7b51}', 0x1435e),
+    ('\u{7b52}', 0x1457b),
+    ('\u{7b54}', 0x1457a),
+    ('\u{7b55}', 0x2734c),
+    ('\u{7b56}', 0x13a76),
+    ('\u{7b5d}', 0x16438),
+    ('\u{7b60}', 0x1795f),
+    ('\u{7b64}', 0x2734e),
+    ('\u{7b65}', 0x16428),
+    ('\u{7b66}', 0x2734f),
+    ('\u{7b67}', 0x1642a),
+    ('\u{7b69}', 0x27350),
+    ('\u{7b6c}', 0x1642d),
+    ('\u{7b6d}', 0x17960),
+    ('\u{7b6e}', 0x1642e),
+    ('\u{7b6f}', 0x17961),
+    ('\u{7b70}', 0x1642b),
+    ('\u{7b71}', 0x1642c),
+    ('\u{7b72}', 0x17962),
+    ('\u{7b73}', 0x27351),
+    ('\u{7b74}', 0x16429),
+    ('\u{7b75}', 0x16427),
+    ('\u{7b79}', 0x2734d),
+    ('\u{7b7a}', 0x16421),
+    ('\u{7b7f}', 0x27349),
+    ('\u{7b86}', 0x14a4f),
+    ('\u{7b87}', 0x13255),
+    ('\u{7b8b}', 0x16435),
+    ('\u{7b8d}', 0x16432),
+    ('\u{7b8f}', 0x16437),
+    ('\u{7b90}', 0x27354),
+    ('\u{7b91}', 0x27355),
+    ('\u{7b92}', 0x16436),
+    ('\u{7b94}', 0x14773),
+    ('\u{7b95}', 0x14c27),
+    ('\u{7b97}', 0x13b3b),
+    ('\u{7b98}', 0x16430),
+    ('\u{7b99}', 0x16439),
+    ('\u{7b9a}', 0x16434),
+    ('\u{7b9b}', 0x27356),
+    ('\u{7b9c}', 0x16433),
+    ('\u{7b9d}', 0x1642f),
+    ('\u{7b9e}', 0x17963),
+    ('\u{7b9f}', 0x16431),
+    ('\u{7ba1}', 0x13449),
+    ('\u{7baa}', 0x1433d),
+    ('\u{7bad}', 0x1407d),
+    ('\u{7baf}', 0x27358),
+    ('\u{7bb1}', 0x14822),
+    ('\u{7bb4}', 0x1643e),
+    ('\u{7bb5}', 0x27359),
+    ('\u{7bb8}', 0x14824),
+    ('\u{7bbc}', 0x2735a),
+    ('\u{7bc0}', 0x14061),
+    ('\u{7bc1}', 0x1643b),
+    ('\u{7bc4}', 0x1484f),
+    ('\u{7bc5}', 0x2735b),
+    ('\u{7bc6}', 0x1643f),
+    ('\u{7bc7}', 0x14a53),
+    ('\u{7bc9}', 0x1435b),
+    ('\u{7bca}', 0x2735c),
+    ('\u{7bcb}', 0x1643a),
+    ('\u{7bcc}', 0x1643c),
+    ('\u{7bcf}', 0x1643d),
+    ('\u{7bd4}', 0x2735f),
+    ('\u{7bd6}', 0x27360),
+    ('\u{7bd7}', 0x17965),
+    ('\u{7bd9}', 0x17966),
+    ('\u{7bda}', 0x27361),
+    ('\u{7bdd}', 0x16440),
+    ('\u{7be0}', 0x13c44),
+    ('\u{7be4}', 0x14646),
+    ('\u{7be5}', 0x16445),
+    ('\u{7be6}', 0x16444),
+    ('\u{7be9}', 0x16441),
+    ('\u{7bea}', 0x27362),
+    ('\u{7bed}', 0x14f36),
+    ('\u{7bf0}', 0x27363),
+    ('\u{7bf3}', 0x1644a),
+    ('\u{7bf6}', 0x1644e),
+    ('\u{7bf7}', 0x1644b),
+    ('\u{7c00}', 0x16447),
+    ('\u{7c01}', 0x17967),
+    ('\u{7c03}', 0x27364),
+    ('\u{7c07}', 0x16448),
+    ('\u{7c0b}', 0x27365),
+    ('\u{7c0d}', 0x1644d),
+    ('\u{7c0e}', 0x27366),
+    ('\u{7c0f}', 0x27367),
+    ('\u{7c11}', 0x16442),
+    ('\u{7c12}', 0x15255),
+    ('\u{7c13}', 0x16449),
+    ('\u{7c14}', 0x16443),
+    ('\u{7c17}', 0x1644c),
+    ('\u{7c1e}', 0x17969),
+    ('\u{7c1f}', 0x16452),
+    ('\u{7c20}', 0x1796a),
+    ('\u{7c21}', 0x1344a),
+    ('\u{7c23}', 0x1644f),
+    ('\u{7c26}', 0x27368),
+    ('\u{7c27}', 0x16450),
+    ('\u{7c2a}', 0x16451),
+    ('\u{7c2b}', 0x16454),
+    ('\u{7c31}', 0x17968),
+    ('\u{7c33}', 0x1796b),
+    ('\u{7c36}', 0x1796c),
+    ('\u{7c37}', 0x16453),
+    ('\u{7c38}', 0x14876),
+    ('\u{7c3d}', 0x16455),
+    ('\u{7c3e}', 0x14e7c),
+    ('\u{7c3f}', 0x14a6d),
+    ('\u{7c40}', 0x1645a),
+    ('\u{7c43}', 0x16457),
+    ('\u{7c45}', 0x27369),
+    ('\u{7c4a}', 0x2736a),
+    ('\u{7c4c}', 0x16456),
+    ('\u{7c4d}', 0x14052),
+    ('\u{7c4f}', 0x16459),
+    ('\u{7c50}', 0x1645b),
+    ('\u{7c51}', 0x2736b),
+    ('\u{7c54}', 0x16458),
+    ('\u{7c56}', 0x1645f),
+    ('\u{7c57}', 0x2736c),
+    ('\u{7c58}', 0x1645c),
+    ('\u{7c59}', 0x1796f),
+    ('\u{7c5e}', 0x2736d),
+    ('\u{7c5f}', 0x1645d),
+    ('\u{7c60}', 0x16446),
+    ('\u{7c61}', 0x2736e),
+    ('\u{7c64}', 0x1645e),
+    ('\u{7c65}', 0x16460),
+    ('\u{7c69}', 0x2736f),
+    ('\u{7c6c}', 0x16461),
+    ('\u{7c6d}', 0x17970),
+    ('\u{7c6e}', 0x27370),
+    ('\u{7c6f}', 0x27371),
+    ('\u{7c70}', 0x27372),
+    ('\u{7c73}', 0x14a46),
+    ('\u{7c75}', 0x16462),
+    ('\u{7c79}', 0x17971),
+    ('\u{7c7e}', 0x14c62),
+    ('\u{7c81}', 0x1364e),
+    ('\u{7c82}', 0x13729),
+    ('\u{7c83}', 0x16463),
+    ('\u{7c89}', 0x14a34),
+    ('\u{7c8b}', 0x13f68),
+    ('\u{7c8d}', 0x14c30),
+    ('\u{7c8f}', 0x17972),
+    ('\u{7c90}', 0x16464),
+    ('\u{7c92}', 0x14e33),
+    ('\u{7c94}', 0x17973),
+    ('\u{7c95}', 0x14774),
+    ('\u{7c97}', 0x14146),
+    ('\u{7c98}', 0x14734),
+    ('\u{7c9b}', 0x13d4d),
+    ('\u{7c9f}', 0x13040),
+    ('\u{7ca0}', 0x17974),
+    ('\u{7ca1}', 0x16469),
+    ('\u{7ca2}', 0x16467),
+    ('\u{7ca4}', 0x16465),
+    ('\u{7ca5}', 0x13421),
+    ('\u{7ca6}', 0x27376),
+    ('\u{7ca7}', 0x13e51),
+    ('\u{7ca8}', 0x1646a),
+    ('\u{7cab}', 0x16468),
+    ('\u{7cad}', 0x16466),
+    ('\u{7cae}', 0x1646e),
+    ('\u{7cb1}', 0x1646d),
+    ('\u{7cb2}', 0x1646c),
+    ('\u{7cb3}', 0x1646b),
+    ('\u{7cb6}', 0x27378),
+    ('\u{7cb7}', 0x27379),
+    ('\u{7cb9}', 0x1646f),
+    ('\u{7cbc}', 0x17975),
+    ('\u{7cbd}', 0x16470),
+    ('\u{7cbe}', 0x1403a),
+    ('\u{7cbf}', 0x2737a),
+    ('\u{7cc0}', 0x16471),
+    ('\u{7cc2}', 0x16473),
+    ('\u{7cc4}', 0x2737c),
+    ('\u{7cc5}', 0x16472),
+    ('\u{7cc8}', 0x2737e),
+    ('\u{7cca}', 0x13852),
+    ('\u{7ccd}', 0x27421),
+    ('\u{7cce}', 0x14138),
+    ('\u{7cd2}', 0x16475),
+    ('\u{7cd5}', 0x17976),
+    ('\u{7cd6}', 0x1457c),
+    ('\u{7cd7}', 0x27423),
+    ('\u{7cd8}', 0x16474),
+    ('\u{7cd9}', 0x17977),
+    ('\u{7cdc}', 0x16476),
+    ('\u{7cdd}', 0x17978),
+    ('\u{7cde}', 0x14a35),
+    ('\u{7cdf}', 0x1416c),
+    ('\u{7ce0}', 0x13947),
+    ('\u{7ce2}', 0x16477),
+    ('\u{7ce6}', 0x27425),
+    ('\u{7ce7}', 0x14e48),
+    ('\u{7ceb}', 0x27426),
+    ('\u{7cef}', 0x16479),
+    ('\u{7cf2}', 0x1647a),
+    ('\u{7cf4}', 0x1647b),
+    ('\u{7cf5}', 0x27428),
+    ('\u{7cf6}', 0x1647c),
+    ('\u{7cf8}', 0x13b65),
+    ('\u{7cfa}', 0x1647d),
+    ('\u{7cfb}', 0x1374f),
+    ('\u{7cfe}', 0x1356a),
+    ('\u{7d00}', 0x1352a),
+    ('\u{7d02}', 0x16521),
+    ('\u{7d03}', 0x27429),
+    ('\u{7d04}', 0x14c73),
+    ('\u{7d05}', 0x13948),
+    ('\u{7d06}', 0x1647e),
+    ('\u{7d07}', 0x17979),
+    ('\u{7d08}', 0x1797a),
+    ('\u{7d09}', 0x2742a),
+    ('\u{7d0a}', 0x16524),
+    ('\u{7d0b}', 0x14c66),
+    ('\u{7d0d}', 0x1473c),
+    ('\u{7d10}', 0x14933),
+    ('\u{7d12}', 0x2742c),
+    ('\u{7d13}', 0x1797b),
+    ('\u{7d14}', 0x13d63),
+    ('\u{7d15}', 0x16523),
+    ('\u{7d17}', 0x13c53),
+    ('\u{7d18}', 0x13949),
+    ('\u{7d19}', 0x13b66),
+    ('\u{7d1a}', 0x13569),
+    ('\u{7d1b}', 0x14a36),
+    ('\u{7d1c}', 0x16522),
+    ('\u{7d1d}', 0x1797c),
+    ('\u{7d1e}', 0x2742d),
+    ('\u{7d20}', 0x14147),
+    ('\u{7d21}', 0x14b42),
+    ('\u{7d22}', 0x13a77),
+    ('\u{7d23}', 0x1797d),
+    ('\u{7d2b}', 0x13b67),
+    ('\u{7d2c}', 0x1445d),
+    ('\u{7d2e}', 0x16527),
+    ('\u{7d2f}', 0x14e5f),
+    ('\u{7d30}', 0x13a59),
+    ('\u{7d31}', 0x1797e),
+    ('\u{7d32}', 0x16528),
+    ('\u{7d33}', 0x13f42),
+    ('\u{7d35}', 0x1652a),
+    ('\u{7d39}', 0x13e52),
+    ('\u{7d3a}', 0x13a30),
+    ('\u{7d3d}', 0x27430),
+    ('\u{7d3e}', 0x27431),
+    ('\u{7d3f}', 0x16529),
+    ('\u{7d40}', 0x27432),
+    ('\u{7d41}', 0x17a21),
+    ('\u{7d42}', 0x13d2a),
+    ('\u{7d43}', 0x1383e),
+    ('\u{7d44}', 0x14148),
+    ('\u{7d45}', 0x16525),
+    ('\u{7d46}', 0x1652b),
+    ('\u{7d47}', 0x27433),
+    ('\u{7d48}', 0x17a22),
+    ('\u{7d4b}', 0x16526),
+    ('\u{7d4c}', 0x13750),
+    ('\u{7d4e}', 0x1652e),
+    ('\u{7d4f}', 0x16532),
+    ('\u{7d50}', 0x1376b),
+    ('\u{7d53}', 0x17a23),
+    ('\u{7d56}', 0x1652d),
+    ('\u{7d59}', 0x27437),
+    ('\u{7d5a}', 0x27438),
+    ('\u{7d5b}', 0x16536),
+    ('\u{7d5c}', 0x17a24),
+    ('\u{7d5e}', 0x1394a),
+    ('\u{7d61}', 0x14d6d),
+    ('\u{7d62}', 0x1303c),
+    ('\u{7d63}', 0x16533),
+    ('\u{7d66}', 0x1356b),
+    ('\u{7d68}', 0x16530),
+    ('\u{7d6a}', 0x27439),
+    ('\u{7d6e}', 0x16531),
+    ('\u{7d70}', 0x2743a),
+    ('\u{7d71}', 0x1457d),
+    ('\u{7d72}', 0x1652f),
+    ('\u{7d73}', 0x1652c),
+    ('\u{7d75}', 0x13328),
+    ('\u{7d76}', 0x14064),
+    ('\u{7d79}', 0x13828),
+    ('\u{7d7a}', 0x17a25),
+    ('\u{7d7d}', 0x16538),
+    ('\u{7d7f}', 0x2743c),
+    ('\u{7d83}', 0x17a26),
+    ('\u{7d86}', 0x2743e),
+    ('\u{7d88}', 0x2743f),
+    ('\u{7d89}', 0x16535),
+    ('\u{7d8b}', 0x17a27),
+    ('\u{7d8c}', 0x27440),
+    ('\u{7d8f}', 0x16537),
+    ('\u{7d93}', 0x16534),
+    ('\u{7d97}', 0x27441),
+    ('\u{7d99}', 0x13751),
+    ('\u{7d9a}', 0x14233),
+    ('\u{7d9b}', 0x16539),
+    ('\u{7d9c}', 0x1416e),
+    ('\u{7d9d}', 0x27443),
+    ('\u{7d9f}', 0x16546),
+    ('\u{7da0}', 0x17a28),
+    ('\u{7da2}', 0x16542),
+    ('\u{7da3}', 0x1653c),
+    ('\u{7da6}', 0x17a29),
+    ('\u{7da7}', 0x27444),
+    ('\u{7daa}', 0x27445),
+    ('\u{7dab}', 0x16540),
+    ('\u{7dac}', 0x13c7a),
+    ('\u{7dad}', 0x1305d),
+    ('\u{7dae}', 0x1653b),
+    ('\u{7daf}', 0x16543),
+    ('\u{7db0}', 0x16547),
+    ('\u{7db1}', 0x1394b),
+    ('\u{7db2}', 0x14c56),
+    ('\u{7db4}', 0x14456),
+    ('\u{7db5}', 0x1653d),
+    ('\u{7db6}', 0x27446),
+    ('\u{7db7}', 0x27447),
+    ('\u{7db8}', 0x16545),
+    ('\u{7dba}', 0x1653a),
+    ('\u{7dbb}', 0x1433e),
+    ('\u{7dbd}', 0x1653f),
+    ('\u{7dbe}', 0x1303d),
+    ('\u{7dbf}', 0x14c4a),
+    ('\u{7dc0}', 0x27448),
+    ('\u{7dc2}', 0x17a2a),
+    ('\u{7dc7}', 0x1653e),
+    ('\u{7dca}', 0x1365b),
+    ('\u{7dcb}', 0x1486c),
+    ('\u{7dcc}', 0x17a2b),
+    ('\u{7dcf}', 0x1416d),
+    ('\u{7dd1}', 0x14e50),
+    ('\u{7dd2}', 0x13d6f),
+    ('\u{7dd5}', 0x1656e),
+    ('\u{7dd6}', 0x17a2c),
+    ('\u{7dd7}', 0x27449),
+    ('\u{7dd8}', 0x16548),
+    ('\u{7dd9}', 0x2744a),
+    ('\u{7dda}', 0x1407e),
+    ('\u{7ddc}', 0x16544),
+    ('\u{7ddd}', 0x16549),
+    ('\u{7dde}', 0x1654b),
+    ('\u{7de0}', 0x14479),
+    ('\u{7de1}', 0x1654e),
+    ('\u{7de3}', 0x17a2d),
+    ('\u{7de4}', 0x1654a),
+    ('\u{7de6}', 0x2744b),
+    ('\u{7de8}', 0x14a54),
+    ('\u{7de9}', 0x1344b),
+    ('\u{7dec}', 0x14c4b),
+    ('\u{7def}', 0x1305e),
+    ('\u{7df1}', 0x2744c),
+    ('\u{7df2}', 0x1654d),
+    ('\u{7df4}', 0x14e7d),
+    ('\u{7df9}', 0x2744d),
+    ('\u{7dfb}', 0x1654c),
+    ('\u{7e01}', 0x1316f),
+    ('\u{7e04}', 0x1466c),
+    ('\u{7e05}', 0x1654f),
+    ('\u{7e08}', 0x17a30),
+    ('\u{7e09}', 0x16556),
+    ('\u{7e0a}', 0x16550),
+    ('\u{7e0b}', 0x16557),
+    ('\u{7e10}', 0x27451),
+    ('\u{7e11}', 0x17a31),
+    ('\u{7e12}', 0x16553),
+    ('\u{7e15}', 0x17a32),
+    ('\u{7e17}', 0x27452),
+    ('\u{7e1b}', 0x1477b),
+    ('\u{7e1d}', 0x27453),
+    ('\u{7e1e}', 0x13c4a),
+    ('\u{7e1f}', 0x16555),
+    ('\u{7e20}', 0x27454),
+    ('\u{7e21}', 0x16552),
+    ('\u{7e22}', 0x16558),
+    ('\u{7e23}', 0x16551),
+    ('\u{7e26}', 0x13d44),
+    ('\u{7e27}', 0x27455),
+    ('\u{7e28}', 0x17a2f),
+    ('\u{7e2b}', 0x14b25),
+    ('\u{7e2c}', 0x27456),
+    ('\u{7e2e}', 0x13d4c),
+    ('\u{7e31}', 0x16554),
+    ('\u{7e32}', 0x16560),
+    ('\u{7e35}', 0x1655c),
+    ('\u{7e37}', 0x1655f),
+    ('\u{7e39}', 0x1655d),
+    ('\u{7e3a}', 0x16561),
+    ('\u{7e3b}', 0x1655b),
+    ('\u{7e3d}', 0x16541),
+    ('\u{7e3e}', 0x14053),
+    ('\u{7e41}', 0x1484b),
+    ('\u{7e43}', 0x1655e),
+    ('\u{7e45}', 0x27457),
+    ('\u{7e46}', 0x16559),
+    ('\u{7e47}', 0x17a34),
+    ('\u{7e4a}', 0x14121),
+    ('\u{7e4b}', 0x13752),
+    ('\u{7e4d}', 0x13d2b),
+    ('\u{7e52}', 0x17a35),
+    ('\u{7e54}', 0x13f25),
+    ('\u{7e55}', 0x14136),
+    ('\u{7e56}', 0x16564),
+    ('\u{7e59}', 0x16566),
+    ('\u{7e5a}', 0x16567),
+    ('\u{7e5d}', 0x16563),
+    ('\u{7e5e}', 0x16565),
+    ('\u{7e61}', 0x17a36),
+    ('\u{7e66}', 0x1655a),
+    ('\u{7e67}', 0x16562),
+    ('\u{7e69}', 0x1656a),
+    ('\u{7e6a}', 0x16569),
+    ('\u{7e6b}', 0x17e7e),
+    ('\u{7e6d}', 0x14b7a),
+    ('\u{7e70}', 0x1372b),
+    ('\u{7e73}', 0x27458),
+    ('\u{7e75}', 0x27459),
+    ('\u{7e79}', 0x16568),
+    ('\u{7e7b}', 0x1656c),
+    ('\u{7e7c}', 0x1656b),
+    ('\u{7e7d}', 0x1656f),
+    ('\u{7e7e}', 0x2745a),
+    ('\u{7e7f}', 0x16571),
+    ('\u{7e82}', 0x13b3c),
+    ('\u{7e83}', 0x1656d),
+    ('\u{7e86}', 0x2745b),
+    ('\u{7e87}', 0x2745c),
+    ('\u{7e88}', 0x16572),
+    ('\u{7e89}', 0x16573),
+    ('\u{7e8a}', 0x17a37),
+    ('\u{7e8c}', 0x16574),
+    ('\u{7e8d}', 0x17a38),
+    ('\u{7e8e}', 0x1657a),
+    ('\u{7e8f}', 0x1453b),
+    ('\u{7e90}', 0x16576),
+    ('\u{7e91}', 0x2745e),
+    ('\u{7e92}', 0x16575),
+    ('\u{7e93}', 0x16577),
+    ('\u{7e94}', 0x16578),
+    ('\u{7e96}', 0x16579),
+    ('\u{7e98}', 0x2745f),
+    ('\u{7e9a}', 0x27460),
+    ('\u{7e9b}', 0x1657b),
+    ('\u{7e9c}', 0x1657c),
+    ('\u{7f36}', 0x1344c),
+    ('\u{7f38}', 0x1657d),
+    ('\u{7f3a}', 0x1657e),
+    ('\u{7f3b}', 0x27463),
+    ('\u{7f3c}', 0x27462),
+    ('\u{7f3e}', 0x27464),
+    ('\u{7f43}', 0x27465),
+    ('\u{7f44}', 0x27466),
+    ('\u{7f45}', 0x16621),
+    ('\u{7f47}', 0x17a39),
+    ('\u{7f4c}', 0x16622),
+    ('\u{7f4d}', 0x16623),
+    ('\u{7f4e}', 0x16624),
+    ('\u{7f4f}', 0x27467),
+    ('\u{7f50}', 0x16625),
+    ('\u{7f51}', 0x16626),
+    ('\u{7f52}', 0x2746a),
+    ('\u{7f54}', 0x16628),
+    ('\u{7f55}', 0x16627),
+    ('\u{7f58}', 0x16629),
+    ('\u{7f5f}', 0x1662a),
+    ('\u{7f60}', 0x1662b),
+    ('\u{7f61}', 0x2746c),
+    ('\u{7f63}', 0x2746d),
+    ('\u{7f64}', 0x2746e),
+    ('\u{7f67}', 0x1662e),
+    ('\u{7f68}', 0x1662c),
+    ('\u{7f69}', 0x1662d),
+    ('\u{7f6a}', 0x13a61),
+    ('\u{7f6b}', 0x13753),
+    ('\u{7f6d}', 0x2746f),
+    ('\u{7f6e}', 0x14356),
+    ('\u{7f70}', 0x14833),
+    ('\u{7f72}', 0x13d70),
+    ('\u{7f75}', 0x1474d),
+    ('\u{7f77}', 0x1486d),
+    ('\u{7f78}', 0x1662f),
+    ('\u{7f79}', 0x1586d),
+    ('\u{7f7d}', 0x27470),
+    ('\u{7f7e}', 0x27471),
+    ('\u{7f82}', 0x16630),
+    ('\u{7f83}', 0x16632),
+    ('\u{7f85}', 0x14d65),
+    ('\u{7f86}', 0x16631),
+    ('\u{7f87}', 0x16634),
+    ('\u{7f88}', 0x16633),
+    ('\u{7f8a}', 0x14d53),
+    ('\u{7f8c}', 0x16635),
+    ('\u{7f8e}', 0x1487e),
+    ('\u{7f90}', 0x27473),
+    ('\u{7f91}', 0x17a3b),
+    ('\u{7f94}', 0x16636),
+    ('\u{7f96}', 0x27476),
+    ('\u{7f97}', 0x17a3c),
+    ('\u{7f9a}', 0x16639),
+    ('\u{7f9c}', 0x27477),
+    ('\u{7f9d}', 0x16638),
+    ('\u{7f9e}', 0x16637),
+    ('\u{7fa3}', 0x1663a),
+    ('\u{7fa4}', 0x13732),
+    ('\u{7fa8}', 0x14122),
+    ('\u{7fa9}', 0x13541),
+    ('\u{7fad}', 0x27478),
+    ('\u{7fae}', 0x1663e),
+    ('\u{7faf}', 0x1663b),
+    ('\u{7fb2}', 0x1663c),
+    ('\u{7fb6}', 0x1663f),
+    ('\u{7fb8}', 0x16640),
+    ('\u{7fb9}', 0x1663d),
+    ('\u{7fbd}', 0x13129),
+    ('\u{7fbf}', 0x17a3d),
+    ('\u{7fc1}', 0x13227),
+    ('\u{7fc3}', 0x2747a),
+    ('\u{7fc5}', 0x16642),
+    ('\u{7fc6}', 0x16643),
+    ('\u{7fca}', 0x16644),
+    ('\u{7fcc}', 0x14d62),
+    ('\u{7fce}', 0x17a3e),
+    ('\u{7fcf}', 0x2747b),
+    ('\u{7fd2}', 0x13d2c),
+    ('\u{7fd4}', 0x16646),
+    ('\u{7fd5}', 0x16645),
+    ('\u{7fdb}', 0x17a3f),
+    ('\u{7fdf}', 0x17a40),
+    ('\u{7fe0}', 0x13f69),
+    ('\u{7fe1}', 0x16647),
+    ('\u{7fe3}', 0x2747c),
+    ('\u{7fe5}', 0x2747d),
+    ('\u{7fe6}', 0x16648),
+    ('\u{7fe9}', 0x16649),
+    ('\u{7feb}', 0x13465),
+    ('\u{7fec}', 0x17a41),
+    ('\u{7fee}', 0x17a42),
+    ('\u{7fef}', 0x2747e),
+    ('\u{7ff0}', 0x1344d),
+    ('\u{7ff2}', 0x27521),
+    ('\u{7ff3}', 0x1664a),
+    ('\u{7ff9}', 0x1664b),
+    ('\u{7ffa}', 0x17a43),
+    ('\u{7ffb}', 0x14b5d),
+    ('\u{7ffc}', 0x14d63),
+    ('\u{8000}', 0x14d54),
+    ('\u{8001}', 0x14f37),
+    ('\u{8002}', 0x27522),
+    ('\u{8003}', 0x1394d),
+    ('\u{8004}', 0x1664e),
+    ('\u{8005}', 0x13c54),
+    ('\u{8006}', 0x1664d),
+    ('\u{8008}', 0x27524),
+    ('\u{800a}', 0x27523),
+    ('\u{800b}', 0x1664f),
+    ('\u{800c}', 0x13c29),
+    ('\u{800e}', 0x27525),
+    ('\u{8010}', 0x14251),
+    ('\u{8011}', 0x27526),
+    ('\u{8012}', 0x16650),
+    ('\u{8014}', 0x17a45),
+    ('\u{8015}', 0x1394c),
+    ('\u{8016}', 0x27527),
+    ('\u{8017}', 0x14c57),
+    ('\u{8018}', 0x16651),
+    ('\u{8019}', 0x16652),
+    ('\u{801c}', 0x16653),
+    ('\u{8021}', 0x16654),
+    ('\u{8024}', 0x27528),
+    ('\u{8026}', 0x17a46),
+    ('\u{8028}', 0x16655),
+    ('\u{802c}', 0x27529),
+    ('\u{8030}', 0x2752a),
+    ('\u{8033}', 0x13c2a),
+    ('\u{8035}', 0x17a47),
+    ('\u{8036}', 0x14c6d),
+    ('\u{8037}', 0x17a48),
+    ('\u{803b}', 0x16657),
+    ('\u{803c}', 0x17a49),
+    ('\u{803d}', 0x1433f),
+    ('\u{803f}', 0x16656),
+    ('\u{8043}', 0x2752b),
+    ('\u{8046}', 0x16659),
+    ('\u{804a}', 0x16658),
+    ('\u{8052}', 0x1665a),
+    ('\u{8056}', 0x1403b),
+    ('\u{8058}', 0x1665b),
+    ('\u{805a}', 0x1665c),
+    ('\u{805e}', 0x14a39),
+    ('\u{805f}', 0x1665d),
+    ('\u{8061}', 0x1416f),
+    ('\u{8062}', 0x1665e),
+    ('\u{8066}', 0x2752c),
+    ('\u{8068}', 0x1665f),
+    ('\u{806f}', 0x14e7e),
+    ('\u{8070}', 0x16662),
+    ('\u{8071}', 0x2752d),
+    ('\u{8072}', 0x16661),
+    ('\u{8073}', 0x16660),
+    ('\u{8074}', 0x14430),
+    ('\u{8075}', 0x2752e),
+    ('\u{8076}', 0x16663),
+    ('\u{8077}', 0x13f26),
+    ('\u{8079}', 0x16664),
+    ('\u{807b}', 0x2752f),
+    ('\u{807d}', 0x16665),
+    ('\u{807e}', 0x14f38),
+    ('\u{807f}', 0x16666),
+    ('\u{8084}', 0x16667),
+    ('\u{8085}', 0x16669),
+    ('\u{8086}', 0x16668),
+    ('\u{8087}', 0x14825),
+    ('\u{8089}', 0x14679),
+    ('\u{808b}', 0x14f3e),
+    ('\u{808c}', 0x14829),
+    ('\u{8093}', 0x1666b),
+    ('\u{8096}', 0x13e53),
+    ('\u{8098}', 0x1492a),
+    ('\u{8099}', 0x27530),
+    ('\u{809a}', 0x1666c),
+    ('\u{809b}', 0x1666a),
+    ('\u{809c}', 0x27531),
+    ('\u{809d}', 0x1344e),
+    ('\u{80a1}', 0x13854),
+    ('\u{80a2}', 0x13b68),
+    ('\u{80a4}', 0x27532),
+    ('\u{80a5}', 0x1486e),
+    ('\u{80a7}', 0x27533),
+    ('\u{80a9}', 0x1382a),
+    ('\u{80aa}', 0x14b43),
+    ('\u{80ac}', 0x1666f),
+    ('\u{80ad}', 0x1666d),
+    ('\u{80af}', 0x1394e),
+    ('\u{80b1}', 0x1394f),
+    ('\u{80b2}', 0x13069),
+    ('\u{80b4}', 0x13a68),
+    ('\u{80b8}', 0x27534),
+    ('\u{80ba}', 0x14759),
+    ('\u{80c3}', 0x1305f),
+    ('\u{80c4}', 0x16674),
+    ('\u{80c5}', 0x27536),
+    ('\u{80c6}', 0x14340),
+    ('\u{80ca}', 0x17a4a),
+    ('\u{80cc}', 0x14758),
+    ('\u{80ce}', 0x1425b),
+    ('\u{80d5}', 0x27537),
+    ('\u{80d6}', 0x16676),
+    ('\u{80d7}', 0x17a4b),
+    ('\u{80d8}', 0x27538),
+    ('\u{80d9}', 0x16672),
+    ('\u{80da}', 0x16675),
+    ('\u{80db}', 0x16670),
+    ('\u{80dd}', 0x16673),
+    ('\u{80de}', 0x14b26),
+    ('\u{80e0}', 0x17a4c),
+    ('\u{80e1}', 0x13855),
+    ('\u{80e4}', 0x1307d),
+    ('\u{80e5}', 0x16671),
+    ('\u{80e6}', 0x27539),
+    ('\u{80ef}', 0x16678),
+    ('\u{80f1}', 0x16679),
+    ('\u{80f3}', 0x17a4d),
+    ('\u{80f4}', 0x14639),
+    ('\u{80f5}', 0x2753c),
+    ('\u{80f8}', 0x1363b),
+    ('\u{80fb}', 0x2753d),
+    ('\u{80fc}', 0x16726),
+    ('\u{80fd}', 0x1473d),
+    ('\u{8102}', 0x13b69),
+    ('\u{8105}', 0x1363c),
+    ('\u{8106}', 0x14048),
+    ('\u{8107}', 0x14f46),
+    ('\u{8108}', 0x14c2e),
+    ('\u{8109}', 0x16677),
+    ('\u{810a}', 0x14054),
+    ('\u{810d}', 0x2753b),
+    ('\u{8116}', 0x27540),
+    ('\u{8118}', 0x17a4e),
+    ('\u{811a}', 0x13553),
+    ('\u{811b}', 0x1667a),
+    ('\u{811e}', 0x27541),
+    ('\u{8123}', 0x1667c),
+    ('\u{8124}', 0x27543),
+    ('\u{8127}', 0x27544),
+    ('\u{8129}', 0x1667b),
+    ('\u{812c}', 0x27545),
+    ('\u{812f}', 0x1667d),
+    ('\u{8131}', 0x14326),
+    ('\u{8133}', 0x1473e),
+    ('\u{8135}', 0x2753f),
+    ('\u{8139}', 0x14431),
+    ('\u{813d}', 0x27547),
+    ('\u{813e}', 0x16723),
+    ('\u{8146}', 0x16722),
+    ('\u{814a}', 0x17a4f),
+    ('\u{814b}', 0x1667e),
+    ('\u{814e}', 0x13f55),
+    ('\u{8150}', 0x14965),
+    ('\u{8151}', 0x16725),
+    ('\u{8153}', 0x16724),
+    ('\u{8154}', 0x13950),
+    ('\u{8155}', 0x14f53),
+    ('\u{815f}', 0x16735),
+    ('\u{8160}', 0x17a50),
+    ('\u{8165}', 0x16729),
+    ('\u{8166}', 0x1672a),
+    ('\u{8167}', 0x17a51),
+    ('\u{8168}', 0x17a52),
+    ('\u{8169}', 0x27549),
+    ('\u{816b}', 0x13c70),
+    ('\u{816d}', 0x17a53),
+    ('\u{816e}', 0x16728),
+    ('\u{8170}', 0x13978),
+    ('\u{8171}', 0x16727),
+    ('\u{8174}', 0x1672b),
+    ('\u{8178}', 0x14432),
+    ('\u{8179}', 0x14a22),
+    ('\u{817a}', 0x14123),
+    ('\u{817f}', 0x1425c),
+    ('\u{8180}', 0x1672f),
+    ('\u{8181}', 0x2754b),
+    ('\u{8182}', 0x16730),
+    ('\u{8183}', 0x1672c),
+    ('\u{8184}', 0x2754d),
+    ('\u{8185}', 0x2754e),
+    ('\u{8188}', 0x1672d),
+    ('\u{818a}', 0x1672e),
+    ('\u{818f}', 0x13951),
+    ('\u{8193}', 0x16736),
+    ('\u{8195}', 0x16732),
+    ('\u{8198}', 0x27550),
+    ('\u{819a}', 0x14966),
+    ('\u{819c}', 0x14b6c),
+    ('\u{819d}', 0x14928),
+    ('\u{81a0}', 0x16731),
+    ('\u{81a3}', 0x16734),
+    ('\u{81a4}', 0x16733),
+    ('\u{81a8}', 0x14b44),
+    ('\u{81a9}', 0x16737),
+    ('\u{81b0}', 0x16738),
+    ('\u{81b2}', 0x27551),
+    ('\u{81b3}', 0x14137),
+    ('\u{81b5}', 0x16739),
+    ('\u{81b8}', 0x1673b),
+    ('\u{81ba}', 0x1673f),
+    ('\u{81bb}', 0x17a54),
+    ('\u{81bd}', 0x1673c),
+    ('\u{81be}', 0x1673a),
+    ('\u{81bf}', 0x1473f),
+    ('\u{81c0}', 0x1673d),
+    ('\u{81c1}', 0x27552),
+    ('\u{81c2}', 0x1673e),
+    ('\u{81c3}', 0x27553),
+    ('\u{81c6}', 0x13232),
+    ('\u{81c8}', 0x16745),
+    ('\u{81c9}', 0x16740),
+    ('\u{81ca}', 0x17a55),
+    ('\u{81cd}', 0x16741),
+    ('\u{81cf}', 0x17a56),
+    ('\u{81d1}', 0x16742),
+    ('\u{81d3}', 0x14221),
+    ('\u{81d6}', 0x27554),
+    ('\u{81d7}', 0x17a57),
+    ('\u{81d8}', 0x16744),
+    ('\u{81d9}', 0x16743),
+    ('\u{81da}', 0x16746),
+    ('\u{81db}', 0x27555),
+    ('\u{81df}', 0x16747),
+    ('\u{81e0}', 0x16748),
+    ('\u{81e3}', 0x13f43),
+    ('\u{81e4}', 0x27557),
+    ('\u{81e5}', 0x13269),
+    ('\u{81e7}', 0x16749),
+    ('\u{81e8}', 0x14e57),
+    ('\u{81ea}', 0x13c2b),
+    ('\u{81ec}', 0x27559),
+    ('\u{81ed}', 0x13d2d),
+    ('\u{81f3}', 0x13b6a),
+    ('\u{81f4}', 0x14357),
+    ('\u{81fa}', 0x1674a),
+    ('\u{81fb}', 0x1674b),
+    ('\u{81fc}', 0x13131),
+    ('\u{81fd}', 0x2755b),
+    ('\u{81fe}', 0x1674c),
+    ('\u{81ff}', 0x2755c),
+    ('\u{8201}', 0x1674d),
+    ('\u{8202}', 0x1674e),
+    ('\u{8204}', 0x2755e),
+    ('\u{8205}', 0x1674f),
+    ('\u{8207}', 0x16750),
+    ('\u{8208}', 0x1363d),
+    ('\u{8209}', 0x15a2a),
+    ('\u{820a}', 0x16751),
+    ('\u{820c}', 0x14065),
+    ('\u{820d}', 0x16752),
+    ('\u{820e}', 0x13c4b),
+    ('\u{8210}', 0x16753),
+    ('\u{8212}', 0x15030),
+    ('\u{8216}', 0x16754),
+    ('\u{8217}', 0x14a5e),
+    ('\u{8218}', 0x1345c),
+    ('\u{8219}', 0x27560),
+    ('\u{821b}', 0x14124),
+    ('\u{821c}', 0x13d58),
+    ('\u{821e}', 0x14971),
+    ('\u{821f}', 0x13d2e),
+    ('\u{8221}', 0x27561),
+    ('\u{8222}', 0x27562),
+    ('\u{8229}', 0x16755),
+    ('\u{822a}', 0x13952),
+    ('\u{822b}', 0x16756),
+    ('\u{822c}', 0x1484c),
+    ('\u{822e}', 0x16764),
+    ('\u{8232}', 0x27564),
+    ('\u{8233}', 0x16758),
+    ('\u{8234}', 0x27565),
+    ('\u{8235}', 0x14249),
+    ('\u{8236}', 0x14775),
+    ('\u{8237}', 0x1383f),
+    ('\u{8238}', 0x16757),
+    ('\u{8239}', 0x14125),
+    ('\u{823c}', 0x27566),
+    ('\u{8240}', 0x16759),
+    ('\u{8245}', 0x27569),
+    ('\u{8246}', 0x27567),
+    ('\u{8247}', 0x1447a),
+    ('\u{8249}', 0x27568),
+    ('\u{824b}', 0x2756b),
+    ('\u{824f}', 0x2756d),
+    ('\u{8257}', 0x2756f),
+    ('\u{8258}', 0x1675b),
+    ('\u{8259}', 0x1675a),
+    ('\u{825a}', 0x1675d),
+    ('\u{825c}', 0x27571),
+    ('\u{825d}', 0x1675c),
+    ('\u{825f}', 0x1675e),
+    ('\u{8260}', 0x17a5b),
+    ('\u{8262}', 0x16760),
+    ('\u{8263}', 0x27572),
+    ('\u{8264}', 0x1675f),
+    ('\u{8266}', 0x1344f),
+    ('\u{8268}', 0x16761),
+    ('\u{826a}', 0x16762),
+    ('\u{826b}', 0x16763),
+    ('\u{826e}', 0x13a31),
+    ('\u{826f}', 0x14e49),
+    ('\u{8271}', 0x16765),
+    ('\u{8272}', 0x13f27),
+    ('\u{8274}', 0x17a5c),
+    ('\u{8276}', 0x13170),
+    ('\u{8277}', 0x16766),
+    ('\u{8278}', 0x16767),
+    ('\u{8279}', 0x27576),
+    ('\u{827d}', 0x27578),
+    ('\u{827e}', 0x16768),
+    ('\u{827f}', 0x27579),
+    ('\u{8283}', 0x2757a),
+    ('\u{828a}', 0x2757b),
+    ('\u{828b}', 0x13072),
+    ('\u{828d}', 0x16769),
+    ('\u{828e}', 0x17a5e),
+    ('\u{8292}', 0x1676a),
+    ('\u{8293}', 0x2757c),
+    ('\u{8299}', 0x14967),
+    ('\u{829d}', 0x13c47),
+    ('\u{829f}', 0x1676c),
+    ('\u{82a1}', 0x17a5f),
+    ('\u{82a3}', 0x17a60),
+    ('\u{82a4}', 0x17a61),
+    ('\u{82a5}', 0x13329),
+    ('\u{82a6}', 0x13032),
+    ('\u{82a7}', 0x2757d),
+    ('\u{82a8}', 0x2757e),
+    ('\u{82a9}', 0x17a62),
+    ('\u{82ab}', 0x1676b),
+    ('\u{82ac}', 0x1676e),
+    ('\u{82ad}', 0x1474e),
+    ('\u{82ae}', 0x17a63),
+    ('\u{82af}', 0x13f44),
+    ('\u{82b1}', 0x13256),
+    ('\u{82b2}', 0x27621),
+    ('\u{82b3}', 0x14b27),
+    ('\u{82b4}', 0x27622),
+    ('\u{82b7}', 0x17a64),
+    ('\u{82b8}', 0x1375d),
+    ('\u{82b9}', 0x1365c),
+    ('\u{82ba}', 0x27623),
+    ('\u{82bb}', 0x1676d),
+    ('\u{82bc}', 0x27624),
+    ('\u{82bd}', 0x1326a),
+    ('\u{82be}', 0x17a65),
+    ('\u{82bf}', 0x17a66),
+    ('\u{82c5}', 0x13423),
+    ('\u{82c6}', 0x17a67),
+    ('\u{82d1}', 0x13171),
+    ('\u{82d2}', 0x16772),
+    ('\u{82d3}', 0x14e6a),
+    ('\u{82d4}', 0x1425d),
+    ('\u{82d5}', 0x17a68),
+    ('\u{82d7}', 0x14944),
+    ('\u{82d9}', 0x1677e),
+    ('\u{82db}', 0x13257),
+    ('\u{82dc}', 0x1677c),
+    ('\u{82de}', 0x1677a),
+    ('\u{82df}', 0x16771),
+    ('\u{82e1}', 0x1676f),
+    ('\u{82e2}', 0x27625),
+    ('\u{82e3}', 0x16770),
+    ('\u{82e5}', 0x13c63),
+    ('\u{82e6}', 0x1366c),
+    ('\u{82e7}', 0x14377),
+    ('\u{82e8}', 0x27626),
+    ('\u{82eb}', 0x14651),
+    ('\u{82f1}', 0x13151),
+    ('\u{82f3}', 0x16774),
+    ('\u{82f4}', 0x16773),
+    ('\u{82f7}', 0x27627),
+    ('\u{82f9}', 0x16779),
+    ('\u{82fa}', 0x16775),
+    ('\u{82fb}', 0x16778),
+    ('\u{82fd}', 0x17a69),
+    ('\u{82fe}', 0x17a6a),
+    ('\u{8300}', 0x17a6b),
+    ('\u{8301}', 0x17a6c),
+    ('\u{8302}', 0x14c50),
+    ('\u{8303}', 0x16777),
+    ('\u{8304}', 0x13258),
+    ('\u{8305}', 0x1337d),
+    ('\u{8306}', 0x1677b),
+    ('\u{8307}', 0x27628),
+    ('\u{8308}', 0x27629),
+    ('\u{8309}', 0x1677d),
+    ('\u{830c}', 0x2762a),
+    ('\u{830e}', 0x13754),
+    ('\u{8316}', 0x16823),
+    ('\u{8317}', 0x1682c),
+    ('\u{8318}', 0x1682d),
+    ('\u{831b}', 0x2762c),
+    ('\u{831c}', 0x1302b),
+    ('\u{831d}', 0x2762d),
+    ('\u{8322}', 0x17a6e),
+    ('\u{8323}', 0x16834),
+    ('\u{8328}', 0x13071),
+    ('\u{832b}', 0x1682b),
+    ('\u{832d}', 0x17a6f),
+    ('\u{832f}', 0x1682a),
+    ('\u{8330}', 0x2762e),
+    ('\u{8331}', 0x16825),
+    ('\u{8332}', 0x16824),
+    ('\u{8334}', 0x16822),
+    ('\u{8335}', 0x16821),
+    ('\u{8336}', 0x14363),
+    ('\u{8338}', 0x1427b),
+    ('\u{8339}', 0x16827),
+    ('\u{833a}', 0x17a70),
+    ('\u{833c}', 0x2762f),
+    ('\u{8340}', 0x16826),
+    ('\u{8343}', 0x17a71),
+    ('\u{8344}', 0x27630),
+    ('\u{8345}', 0x16829),
+    ('\u{8347}', 0x17a72),
+    ('\u{8349}', 0x14170),
+    ('\u{834a}', 0x13755),
+    ('\u{834f}', 0x13141),
+    ('\u{8350}', 0x16828),
+    ('\u{8351}', 0x17a73),
+    ('\u{8352}', 0x13953),
+    ('\u{8354}', 0x2762b),
+    ('\u{8355}', 0x17a74),
+    ('\u{8357}', 0x27631),
+    ('\u{8358}', 0x14171),
+    ('\u{8362}', 0x17a6d),
+    ('\u{8363}', 0x22e4a),
+    ('\u{8373}', 0x1683a),
+    ('\u{8375}', 0x1683b),
+    ('\u{8377}', 0x13259),
+    ('\u{837b}', 0x1322e),
+    ('\u{837c}', 0x16838),
+    ('\u{837d}', 0x17a75),
+    ('\u{837f}', 0x27633),
+    ('\u{8385}', 0x1682e),
+    ('\u{8386}', 0x17a76),
+    ('\u{8387}', 0x16836),
+    ('\u{8389}', 0x1683d),
+    ('\u{838a}', 0x16837),
+    ('\u{838d}', 0x27636),
+    ('\u{838e}', 0x16835),
+    ('\u{8392}', 0x17a77),
+    ('\u{8393}', 0x16776),
+    ('\u{8394}', 0x27637),
+    ('\u{8395}', 0x27638),
+    ('\u{8396}', 0x16833),
+    ('\u{8398}', 0x17a78),
+    ('\u{839a}', 0x1682f),
+    ('\u{839b}', 0x27639),
+    ('\u{839d}', 0x2763a),
+    ('\u{839e}', 0x13450),
+    ('\u{839f}', 0x16831),
+    ('\u{83a0}', 0x1683c),
+    ('\u{83a2}', 0x16832),
+    ('\u{83a7}', 0x17a79),
+    ('\u{83a8}', 0x1683e),
+    ('\u{83a9}', 0x17a7a),
+    ('\u{83aa}', 0x16830),
+    ('\u{83ab}', 0x1477c),
+    ('\u{83b1}', 0x14d69),
+    ('\u{83b5}', 0x16839),
+    ('\u{83bd}', 0x1684f),
+    ('\u{83bf}', 0x17a7b),
+    ('\u{83c0}', 0x17a7c),
+    ('\u{83c1}', 0x16847),
+    ('\u{83c5}', 0x13f7b),
+    ('\u{83c7}', 0x17a7d),
+    ('\u{83c9}', 0x2763b),
+    ('\u{83ca}', 0x13546),
+    ('\u{83cc}', 0x1365d),
+    ('\u{83ce}', 0x16842),
+    ('\u{83cf}', 0x17a7e),
+    ('\u{83d0}', 0x2763c),
+    ('\u{83d1}', 0x17b21),
+    ('\u{83d3}', 0x1325b),
+    ('\u{83d4}', 0x2763d),
+    ('\u{83d6}', 0x13e54),
+    ('\u{83d8}', 0x16845),
+    ('\u{83dc}', 0x13a5a),
+    ('\u{83dd}', 0x2763e),
+    ('\u{83df}', 0x14551),
+    ('\u{83e0}', 0x1684a),
+    ('\u{83e1}', 0x17b22),
+    ('\u{83e5}', 0x2763f),
+    ('\u{83e9}', 0x14a6e),
+    ('\u{83ea}', 0x17b23),
+    ('\u{83eb}', 0x16841),
+    ('\u{83ef}', 0x1325a),
+    ('\u{83f0}', 0x13856),
+    ('\u{83f1}', 0x14929),
+    ('\u{83f2}', 0x1684b),
+    ('\u{83f4}', 0x1683f),
+    ('\u{83f7}', 0x16848),
+    ('\u{83f9}', 0x27640),
+    ('\u{83fb}', 0x16852),
+    ('\u{83fd}', 0x16843),
+    ('\u{8401}', 0x17b24),
+    ('\u{8403}', 0x16844),
+    ('\u{8404}', 0x1463a),
+    ('\u{8406}', 0x17b25),
+    ('\u{8407}', 0x16849),
+    ('\u{840a}', 0x17b26),
+    ('\u{840b}', 0x16846),
+    ('\u{840c}', 0x14b28),
+    ('\u{840d}', 0x1684c),
+    ('\u{840e}', 0x13060),
+    ('\u{840f}', 0x27641),
+    ('\u{8411}', 0x27642),
+    ('\u{8413}', 0x16840),
+    ('\u{8415}', 0x27643),
+    ('\u{8417}', 0x27645),
+    ('\u{8420}', 0x1684e),
+    ('\u{8422}', 0x1684d),
+    ('\u{8429}', 0x1476b),
+    ('\u{842a}', 0x16854),
+    ('\u{842c}', 0x1685f),
+    ('\u{8431}', 0x1337e),
+    ('\u{8435}', 0x16862),
+    ('\u{8438}', 0x16850),
+    ('\u{8439}', 0x27646),
+    ('\u{843c}', 0x16855),
+    ('\u{843d}', 0x14d6e),
+    ('\u{8446}', 0x1685e),
+    ('\u{8448}', 0x17b28),
+    ('\u{8449}', 0x14d55),
+    ('\u{844a}', 0x27647),
+    ('\u{844e}', 0x14e2a),
+    ('\u{844f}', 0x27648),
+    ('\u{8451}', 0x27649),
+    ('\u{8452}', 0x2764a),
+    ('\u{8457}', 0x14378),
+    ('\u{8459}', 0x2764b),
+    ('\u{845a}', 0x2764c),
+    ('\u{845b}', 0x1336b),
+    ('\u{845c}', 0x2764d),
+    ('\u{845f}', 0x17b29),
+    ('\u{8461}', 0x14972),
+    ('\u{8462}', 0x16864),
+    ('\u{8463}', 0x14621),
+    ('\u{8465}', 0x2764f),
+    ('\u{8466}', 0x13031),
+    ('\u{8469}', 0x1685d),
+    ('\u{846b}', 0x16859),
+    ('\u{846c}', 0x14172),
+    ('\u{846d}', 0x16853),
+    ('\u{846e}', 0x1685b),
+    ('\u{846f}', 0x16860),
+    ('\u{8470}', 0x17b2a),
+    ('\u{8471}', 0x1472c),
+    ('\u{8473}', 0x17b2b),
+    ('\u{8475}', 0x1302a),
+    ('\u{8476}', 0x27650),
+    ('\u{8477}', 0x16858),
+    ('\u{8478}', 0x27651),
+    ('\u{8479}', 0x16861),
+    ('\u{847a}', 0x14978),
+    ('\u{847c}', 0x27652),
+    ('\u{8481}', 0x27653),
+    ('\u{8482}', 0x1685c),
+    ('\u{8484}', 0x16857),
+    ('\u{8485}', 0x17b2c),
+    ('\u{848b}', 0x13e55),
+    ('\u{8490}', 0x13d2f),
+    ('\u{8494}', 0x13c2c),
+    ('\u{8497}', 0x27656),
+    ('\u{8499}', 0x14c58),
+    ('\u{849c}', 0x14947),
+    ('\u{849e}', 0x17b2d),
+    ('\u{849f}', 0x16867),
+    ('\u{84a1}', 0x16870),
+    ('\u{84a6}', 0x27657),
+    ('\u{84ad}', 0x1685a),
+    ('\u{84af}', 0x17b2e),
+    ('\u{84b2}', 0x13377),
+    ('\u{84b4}', 0x17b2f),
+    ('\u{84b8}', 0x13e78),
+    ('\u{84b9}', 0x16865),
+    ('\u{84ba}', 0x17b30),
+    ('\u{84bb}', 0x1686a),
+    ('\u{84bc}', 0x14173),
+    ('\u{84be}', 0x27658),
+    ('\u{84bf}', 0x16866),
+    ('\u{84c0}', 0x17b31),
+    ('\u{84c1}', 0x1686d),
+    ('\u{84c2}', 0x17b32),
+    ('\u{84c4}', 0x1435f),
+    ('\u{84c6}', 0x1686e),
+    ('\u{84c9}', 0x14d56),
+    ('\u{84ca}', 0x16863),
+    ('\u{84cb}', 0x13338),
+    ('\u{84cd}', 0x16869),
+    ('\u{84ce}', 0x2765a),
+    ('\u{84cf}', 0x2765b),
+    ('\u{84d0}', 0x1686c),
+    ('\u{84d1}', 0x14c2c),
+    ('\u{84d3}', 0x2765c),
+    ('\u{84d6}', 0x1686f),
+    ('\u{84d9}', 0x16868),
+    ('\u{84da}', 0x1686b),
+    ('\u{84dc}', 0x27655),
+    ('\u{84e7}', 0x2765e),
+    ('\u{84ea}', 0x2765f),
+    ('\u{84ec}', 0x14b29),
+    ('\u{84ee}', 0x14f21),
+    ('\u{84ef}', 0x27660),
+    ('\u{84f0}', 0x27661),
+    ('\u{84f1}', 0x27662),
+    ('\u{84f4}', 0x16873),
+    ('\u{84fa}', 0x27663),
+    ('\u{84fc}', 0x1687a),
+    ('\u{84fd}', 0x27664),
+    ('\u{84ff}', 0x16872),
+    ('\u{8500}', 0x13c43),
+    ('\u{8506}', 0x16851),
+    ('\u{850c}', 0x27665),
+    ('\u{8511}', 0x14a4e),
+    ('\u{8513}', 0x14c22),
+    ('\u{8514}', 0x16879),
+    ('\u{8515}', 0x16878),
+    ('\u{8517}', 0x16874),
+    ('\u{8518}', 0x16875),
+    ('\u{851a}', 0x13136),
+    ('\u{851b}', 0x27666),
+    ('\u{851e}', 0x17b35),
+    ('\u{851f}', 0x16877),
+    ('\u{8521}', 0x16871),
+    ('\u{8523}', 0x17b36),
+    ('\u{8524}', 0x27667),
+    ('\u{8525}', 0x27668),
+    ('\u{8526}', 0x14455),
+    ('\u{852b}', 0x27669),
+    ('\u{852c}', 0x16876),
+    ('\u{852d}', 0x1307e),
+    ('\u{852f}', 0x17b37),
+    ('\u{8532}', 0x17b34),
+    ('\u{8534}', 0x2766a),
+    ('\u{8535}', 0x14222),
+    ('\u{853d}', 0x14a43),
+    ('\u{853e}', 0x2766f),
+    ('\u{8540}', 0x1687b),
+    ('\u{8541}', 0x16921),
+    ('\u{8543}', 0x14859),
+    ('\u{8548}', 0x1687e),
+    ('\u{8549}', 0x13e56),
+    ('\u{854a}', 0x13c49),
+    ('\u{854b}', 0x16923),
+    ('\u{854e}', 0x1363e),
+    ('\u{854f}', 0x2766b),
+    ('\u{8551}', 0x27670),
+    ('\u{8553}', 0x27671),
+    ('\u{8555}', 0x16924),
+    ('\u{8557}', 0x14979),
+    ('\u{8558}', 0x1687d),
+    ('\u{8559}', 0x17b38),
+    ('\u{855a}', 0x16856),
+    ('\u{855e}', 0x27672),
+    ('\u{8561}', 0x27673),
+    ('\u{8562}', 0x27674),
+    ('\u{8563}', 0x1687c),
+    ('\u{8564}', 0x17b39),
+    ('\u{8568}', 0x14f4f),
+    ('\u{8569}', 0x14622),
+    ('\u{856a}', 0x14973),
+    ('\u{856d}', 0x1692b),
+    ('\u{856f}', 0x2766c),
+    ('\u{8577}', 0x16931),
+    ('\u{857a}', 0x17b3c),
+    ('\u{857b}', 0x27676),
+    ('\u{857d}', 0x27677),
+    ('\u{857e}', 0x16932),
+    ('\u{857f}', 0x27678),
+    ('\u{8580}', 0x16925),
+    ('\u{8581}', 0x27679),
+    ('\u{8584}', 0x14776),
+    ('\u{8586}', 0x2767a),
+    ('\u{8587}', 0x1692f),
+    ('\u{8588}', 0x16927),
+    ('\u{858a}', 0x16929),
+    ('\u{858c}', 0x17b3d),
+    ('\u{858f}', 0x17b3e),
+    ('\u{8590}', 0x16933),
+    ('\u{8591}', 0x16928),
+    ('\u{8593}', 0x2767b),
+    ('\u{8594}', 0x1692c),
+    ('\u{8597}', 0x13172),
+    ('\u{8599}', 0x14665),
+    ('\u{859b}', 0x1692d),
+    ('\u{859c}', 0x16930),
+    ('\u{859d}', 0x2767c),
+    ('\u{859f}', 0x2767d),
+    ('\u{85a2}', 0x17b3f),
+    ('\u{85a4}', 0x16926),
+    ('\u{85a6}', 0x14126),
+    ('\u{85a8}', 0x1692a),
+    ('\u{85a9}', 0x13b27),
+    ('\u{85aa}', 0x13f45),
+    ('\u{85ab}', 0x13730),
+    ('\u{85ac}', 0x14c74),
+    ('\u{85ad}', 0x17b3b),
+    ('\u{85ae}', 0x14c79),
+    ('\u{85af}', 0x13d72),
+    ('\u{85b0}', 0x17b40),
+    ('\u{85b7}', 0x27723),
+    ('\u{85b9}', 0x16937),
+    ('\u{85ba}', 0x16935),
+    ('\u{85bc}', 0x27724),
+    ('\u{85c1}', 0x14f4e),
+    ('\u{85c7}', 0x27725),
+    ('\u{85c9}', 0x16934),
+    ('\u{85ca}', 0x27726),
+    ('\u{85cb}', 0x17b41),
+    ('\u{85cd}', 0x14d75),
+    ('\u{85ce}', 0x17b42),
+    ('\u{85cf}', 0x16936),
+    ('\u{85d0}', 0x16938),
+    ('\u{85d5}', 0x16939),
+    ('\u{85d8}', 0x27727),
+    ('\u{85d9}', 0x27728),
+    ('\u{85dc}', 0x1693c),
+    ('\u{85dd}', 0x1693a),
+    ('\u{85df}', 0x27729),
+    ('\u{85e1}', 0x2772a),
+    ('\u{85e4}', 0x14623),
+    ('\u{85e5}', 0x1693b),
+    ('\u{85e6}', 0x2772b),
+    ('\u{85e9}', 0x1484d),
+    ('\u{85ea}', 0x1692e),
+    ('\u{85ed}', 0x17b43),
+    ('\u{85f6}', 0x2772c),
+    ('\u{85f7}', 0x13d73),
+    ('\u{85f9}', 0x1693d),
+    ('\u{85fa}', 0x16942),
+    ('\u{85fb}', 0x14174),
+    ('\u{85fe}', 0x16941),
+    ('\u{85ff}', 0x17b45),
+    ('\u{8600}', 0x2772d),
+    ('\u{8602}', 0x16922),
+    ('\u{8604}', 0x17b46),
+    ('\u{8605}', 0x17b47),
+    ('\u{8606}', 0x16943),
+    ('\u{8607}', 0x14149),
+    ('\u{860a}', 0x1693e),
+    ('\u{860b}', 0x16940),
+    ('\u{8610}', 0x17b48),
+    ('\u{8611}', 0x2772e),
+    ('\u{8612}', 0x17b44),
+    ('\u{8613}', 0x1693f),
+    ('\u{8616}', 0x15d31),
+    ('\u{8617}', 0x15d22),
+    ('\u{8618}', 0x17b4a),
+    ('\u{861a}', 0x16945),
+    ('\u{861e}', 0x2772f),
+    ('\u{8621}', 0x27730),
+    ('\u{8622}', 0x16944),
+    ('\u{8624}', 0x27731),
+    ('\u{8627}', 0x27732),
+    ('\u{8629}', 0x17b4b),
+    ('\u{862d}', 0x14d76),
+    ('\u{862f}', 0x1623c),
+    ('\u{8630}', 0x16946),
+    ('\u{8638}', 0x17b4c),
+    ('\u{8639}', 0x27734),
+    ('\u{863c}', 0x27735),
+    ('\u{863f}', 0x16947),
+    ('\u{8640}', 0x27737),
+    ('\u{8641}', 0x12f68),
+    ('\u{864d}', 0x16948),
+    ('\u{864e}', 0x13857),
+    ('\u{8650}', 0x13554),
+    ('\u{8653}', 0x27739),
+    ('\u{8654}', 0x1694a),
+    ('\u{8655}', 0x1515d),
+    ('\u{8656}', 0x2773a),
+    ('\u{8657}', 0x17b4d),
+    ('\u{865a}', 0x13575),
+    ('\u{865b}', 0x17b4e),
+    ('\u{865c}', 0x14e3a),
+    ('\u{865e}', 0x13673),
+    ('\u{865f}', 0x1694b),
+    ('\u{8662}', 0x17b50),
+    ('\u{8667}', 0x1694c),
+    ('\u{866b}', 0x1436e),
+    ('\u{866c}', 0x17b52),
+    ('\u{866f}', 0x2773b),
+    ('\u{8671}', 0x1694d),
+    ('\u{8675}', 0x17b53),
+    ('\u{8677}', 0x2773c),
+    ('\u{8679}', 0x1467a),
+    ('\u{867a}', 0x2773d),
+    ('\u{867b}', 0x1303a),
+    ('\u{8687}', 0x2773e),
+    ('\u{8689}', 0x2773f),
+    ('\u{868a}', 0x13263),
+    ('\u{868b}', 0x16952),
+    ('\u{868c}', 0x16953),
+    ('\u{868d}', 0x27740),
+    ('\u{8691}', 0x27741),
+    ('\u{8693}', 0x1694e),
+    ('\u{8695}', 0x13b3d),
+    ('\u{8698}', 0x17b54),
+    ('\u{869c}', 0x27742),
+    ('\u{869d}', 0x27743),
+    ('\u{86a3}', 0x1694f),
+    ('\u{86a4}', 0x14742),
+    ('\u{86a8}', 0x27744),
+    ('\u{86a9}', 0x16950),
+    ('\u{86aa}', 0x16951),
+    ('\u{86ab}', 0x1695b),
+    ('\u{86af}', 0x16955),
+    ('\u{86b0}', 0x16958),
+    ('\u{86b1}', 0x27746),
+    ('\u{86b3}', 0x27747),
+    ('\u{86b6}', 0x16954),
+    ('\u{86b8}', 0x17b55),
+    ('\u{86c1}', 0x27748),
+    ('\u{86c3}', 0x27749),
+    ('\u{86c4}', 0x16956),
+    ('\u{86c6}', 0x16957),
+    ('\u{86c7}', 0x13c58),
+    ('\u{86c9}', 0x16959),
+    ('\u{86cb}', 0x14341),
+    ('\u{86cd}', 0x13756),
+    ('\u{86ce}', 0x13342),
+    ('\u{86d1}', 0x2774a),
+    ('\u{86d4}', 0x1695c),
+    ('\u{86d5}', 0x2774b),
+    ('\u{86d7}', 0x2774c),
+    ('\u{86d9}', 0x1333f),
+    ('\u{86db}', 0x16961),
+    ('\u{86de}', 0x1695d),
+    ('\u{86df}', 0x16960),
+    ('\u{86e3}', 0x2774d),
+    ('\u{86e4}', 0x1483a),
+    ('\u{86e6}', 0x2774e),
+    ('\u{86e9}', 0x1695e),
+    ('\u{86ec}', 0x1695f),
+    ('\u{86ed}', 0x14948),
+    ('\u{86ee}', 0x1485a),
+    ('\u{86ef}', 0x16962),
+    ('\u{86f8}', 0x1427d),
+    ('\u{86f9}', 0x1696c),
+    ('\u{86fa}', 0x17b56),
+    ('\u{86fb}', 0x16968),
+    ('\u{86fc}', 0x17b57),
+    ('\u{86fd}', 0x17b58),
+    ('\u{86fe}', 0x1326b),
+    ('\u{8700}', 0x16966),
+    ('\u{8702}', 0x14b2a),
+    ('\u{8703}', 0x16967),
+    ('\u{8705}', 0x27750),
+    ('\u{8706}', 0x16964),
+    ('\u{8707}', 0x27751),
+    ('\u{8708}', 0x16965),
+    ('\u{8709}', 0x1696a),
+    ('\u{870a}', 0x1696d),
+    ('\u{870b}', 0x17b59),
+    ('\u{870d}', 0x1696b),
+    ('\u{870e}', 0x27752),
+    ('\u{8710}', 0x27753),
+    ('\u{8711}', 0x16969),
+    ('\u{8712}', 0x16963),
+    ('\u{8713}', 0x27754),
+    ('\u{8718}', 0x14358),
+    ('\u{8719}', 0x27755),
+    ('\u{871a}', 0x16974),
+    ('\u{871c}', 0x14c2a),
+    ('\u{871f}', 0x27756),
+    ('\u{8721}', 0x27757),
+    ('\u{8723}', 0x27758),
+    ('\u{8725}', 0x16972),
+    ('\u{8729}', 0x16973),
+    ('\u{8731}', 0x27759),
+    ('\u{8734}', 0x1696e),
+    ('\u{8737}', 0x16970),
+    ('\u{873a}', 0x2775a),
+    ('\u{873b}', 0x16971),
+    ('\u{873e}', 0x2775b),
+    ('\u{873f}', 0x1696f),
+    ('\u{8740}', 0x2775c),
+    ('\u{8743}', 0x2775d),
+    ('\u{8749}', 0x14066),
+    ('\u{874b}', 0x14f39),
+    ('\u{874c}', 0x16978),
+    ('\u{874e}', 0x16979),
+    ('\u{8751}', 0x2775e),
+    ('\u{8753}', 0x16a21),
+    ('\u{8755}', 0x13f2a),
+    ('\u{8757}', 0x1697b),
+    ('\u{8758}', 0x2775f),
+    ('\u{8759}', 0x1697e),
+    ('\u{875f}', 0x16976),
+    ('\u{8760}', 0x16975),
+    ('\u{8763}', 0x16a22),
+    ('\u{8764}', 0x27760),
+    ('\u{8765}', 0x27761),
+    ('\u{8766}', 0x1325c),
+    ('\u{8768}', 0x1697c),
+    ('\u{876a}', 0x16a23),
+    ('\u{876e}', 0x1697d),
+    ('\u{8771}', 0x17b5a),
+    ('\u{8772}', 0x27762),
+    ('\u{8774}', 0x1697a),
+    ('\u{8776}', 0x14433),
+    ('\u{8778}', 0x16977),
+    ('\u{877c}', 0x27763),
+    ('\u{877f}', 0x14768),
+    ('\u{8782}', 0x16a27),
+    ('\u{8787}', 0x17b5b),
+    ('\u{8788}', 0x17b5c),
+    ('\u{8789}', 0x27767),
+    ('\u{878b}', 0x27768),
+    ('\u{878d}', 0x14d3b),
+    ('\u{8793}', 0x27769),
+    ('\u{879f}', 0x16a26),
+    ('\u{87a0}', 0x2776a),
+    ('\u{87a2}', 0x16a25),
+    ('\u{87a7}', 0x27766),
+    ('\u{87ab}', 0x16a2e),
+    ('\u{87ac}', 0x17b5d),
+    ('\u{87ad}', 0x17b5e),
+    ('\u{87af}', 0x16a28),
+    ('\u{87b3}', 0x16a30),
+    ('\u{87b5}', 0x17b5f),
+    ('\u{87ba}', 0x14d66),
+    ('\u{87bb}', 0x16a33),
+    ('\u{87bd}', 0x16a2a),
+    ('\u{87be}', 0x2776d),
+    ('\u{87c0}', 0x16a2b),
+    ('\u{87c1}', 0x2776f),
+    ('\u{87c4}', 0x16a2f),
+    ('\u{87c6}', 0x16a32),
+    ('\u{87c7}', 0x16a31),
+    ('\u{87cb}', 0x16a29),
+    ('\u{87ce}', 0x27770),
+    ('\u{87d0}', 0x16a2c),
+    ('\u{87d2}', 0x16a3d),
+    ('\u{87d6}', 0x17b61),
+    ('\u{87df}', 0x27772),
+    ('\u{87e0}', 0x16a36),
+    ('\u{87e3}', 0x27774),
+    ('\u{87e5}', 0x27775),
+    ('\u{87e6}', 0x27776),
+    ('\u{87ea}', 0x27777),
+    ('\u{87eb}', 0x27778),
+    ('\u{87ec}', 0x17b62),
+    ('\u{87ed}', 0x27779),
+    ('\u{87ef}', 0x16a34),
+    ('\u{87f2}', 0x16a35),
+    ('\u{87f5}', 0x27771),
+    ('\u{87f6}', 0x16a3a),
+    ('\u{87f7}', 0x16a3b),
+    ('\u{87f9}', 0x1332a),
+    ('\u{87fb}', 0x13542),
+    ('\u{87fe}', 0x16a39),
+    ('\u{8801}', 0x2777a),
+    ('\u{8803}', 0x2777b),
+    ('\u{8805}', 0x16a24),
+    ('\u{8806}', 0x17b63),
+    ('\u{880a}', 0x17b64),
+    ('\u{880b}', 0x2777c),
+    ('\u{880d}', 0x16a38),
+    ('\u{880e}', 0x16a3c),
+    ('\u{880f}', 0x16a37),
+    ('\u{8810}', 0x17b65),
+    ('\u{8811}', 0x16a3e),
+    ('\u{8813}', 0x2777d),
+    ('\u{8814}', 0x17b66),
+    ('\u{8815}', 0x16a40),
+    ('\u{8816}', 0x16a3f),
+    ('\u{881f}', 0x17b67),
+    ('\u{8821}', 0x16a42),
+    ('\u{8822}', 0x16a41),
+    ('\u{8823}', 0x1695a),
+    ('\u{8827}', 0x16a46),
+    ('\u{8828}', 0x2777e),
+    ('\u{882e}', 0x27821),
+    ('\u{8831}', 0x16a43),
+    ('\u{8832}', 0x27822),
+    ('\u{8836}', 0x16a44),
+    ('\u{8839}', 0x16a45),
+    ('\u{883b}', 0x16a47),
+    ('\u{883c}', 0x27823),
+    ('\u{8840}', 0x1376c),
+    ('\u{8842}', 0x16a49),
+    ('\u{8844}', 0x16a48),
+    ('\u{8846}', 0x13d30),
+    ('\u{884a}', 0x27825),
+    ('\u{884c}', 0x13954),
+    ('\u{884d}', 0x15e27),
+    ('\u{8852}', 0x16a4a),
+    ('\u{8853}', 0x13d51),
+    ('\u{8857}', 0x13339),
+    ('\u{8858}', 0x27826),
+    ('\u{8859}', 0x16a4b),
+    ('\u{885b}', 0x13152),
+    ('\u{885d}', 0x13e57),
+    ('\u{885e}', 0x16a4c),
+    ('\u{885f}', 0x27827),
+    ('\u{8861}', 0x13955),
+    ('\u{8862}', 0x16a4d),
+    ('\u{8863}', 0x13061),
+    ('\u{8864}', 0x27828),
+    ('\u{8868}', 0x1493d),
+    ('\u{8869}', 0x2782b),
+    ('\u{886b}', 0x16a4e),
+    ('\u{886f}', 0x2782d),
+    ('\u{8870}', 0x13f6a),
+    ('\u{8872}', 0x16a55),
+    ('\u{8875}', 0x16a52),
+    ('\u{8877}', 0x1436f),
+    ('\u{887d}', 0x16a53),
+    ('\u{887e}', 0x16a50),
+    ('\u{887f}', 0x1365e),
+    ('\u{8881}', 0x16a4f),
+    ('\u{8882}', 0x16a56),
+    ('\u{8888}', 0x13736),
+    ('\u{888b}', 0x1425e),
+    ('\u{888d}', 0x16a5c),
+    ('\u{8892}', 0x16a58),
+    ('\u{8896}', 0x14235),
+    ('\u{8897}', 0x16a57),
+    ('\u{8898}', 0x17b68),
+    ('\u{8899}', 0x16a5a),
+    ('\u{889e}', 0x16a51),
+    ('\u{88a0}', 0x2782e),
+    ('\u{88a2}', 0x16a5b),
+    ('\u{88a4}', 0x16a5d),
+    ('\u{88aa}', 0x17b69),
+    ('\u{88ab}', 0x1486f),
+    ('\u{88ae}', 0x16a59),
+    ('\u{88b0}', 0x16a5e),
+    ('\u{88b1}', 0x16a60),
+    ('\u{88b4}', 0x13853),
+    ('\u{88b5}', 0x16a54),
+    ('\u{88b7}', 0x13041),
+    ('\u{88bc}', 0x2782f),
+    ('\u{88bd}', 0x27830),
+    ('\u{88be}', 0x27831),
+    ('\u{88bf}', 0x16a5f),
+    ('\u{88c0}', 0x27832),
+    ('\u{88c1}', 0x13a5b),
+    ('\u{88c2}', 0x14e76),
+    ('\u{88c3}', 0x16a61),
+    ('\u{88c4}', 0x16a62),
+    ('\u{88c5}', 0x14175),
+    ('\u{88ca}', 0x17b6a),
+    ('\u{88ce}', 0x17b6b),
+    ('\u{88cf}', 0x14e22),
+    ('\u{88d1}', 0x27835),
+    ('\u{88d2}', 0x27833),
+    ('\u{88d3}', 0x27836),
+    ('\u{88d4}', 0x16a63),
+    ('\u{88d5}', 0x14d35),
+    ('\u{88d8}', 0x16a64),
+    ('\u{88d9}', 0x16a65),
+    ('\u{88db}', 0x27837),
+    ('\u{88dc}', 0x14a64),
+    ('\u{88dd}', 0x16a66),
+    ('\u{88df}', 0x13a40),
+    ('\u{88e1}', 0x14e23),
+    ('\u{88e8}', 0x16a6b),
+    ('\u{88f0}', 0x27838),
+    ('\u{88f1}', 0x27839),
+    ('\u{88f2}', 0x16a6c),
+    ('\u{88f3}', 0x13e58),
+    ('\u{88f4}', 0x16a6a),
+    ('\u{88f5}', 0x17b6d),
+    ('\u{88f8}', 0x14d67),
+    ('\u{88f9}', 0x16a67),
+    ('\u{88fc}', 0x16a69),
+    ('\u{88fd}', 0x1403d),
+    ('\u{88fe}', 0x13f7e),
+    ('\u{8901}', 0x2783b),
+    ('\u{8902}', 0x16a68),
+    ('\u{8904}', 0x16a6d),
+    ('\u{8907}', 0x14a23),
+    ('\u{890a}', 0x16a6f),
+    ('\u{890c}', 0x16a6e),
+    ('\u{8910}', 0x1336c),
+    ('\u{8912}', 0x14b2b),
+    ('\u{8913}', 0x16a70),
+    ('\u{8918}', 0x17b70),
+    ('\u{8919}', 0x17b71),
+    ('\u{891a}', 0x17b72),
+    ('\u{891c}', 0x17b6e),
+    ('\u{891d}', 0x16a7c),
+    ('\u{891e}', 0x16a72),
+    ('\u{8925}', 0x16a73),
+    ('\u{8927}', 0x17b73),
+    ('\u{892a}', 0x16a74),
+    ('\u{892b}', 0x16a75),
+    ('\u{8930}', 0x17b74),
+    ('\u{8932}', 0x17b75),
+    ('\u{8936}', 0x16a79),
+    ('\u{8937}', 0x2783d),
+    ('\u{8938}', 0x16a7a),
+    ('\u{8939}', 0x17b76),
+    ('\u{893b}', 0x16a78),
+    ('\u{8940}', 0x17b77),
+    ('\u{8941}', 0x16a76),
+    ('\u{8942}', 0x2783f),
+    ('\u{8943}', 0x16a71),
+    ('\u{8944}', 0x16a77),
+    ('\u{8945}', 0x27840),
+    ('\u{8949}', 0x27841),
+    ('\u{894c}', 0x16a7b),
+    ('\u{894d}', 0x17037),
+    ('\u{8956}', 0x13228),
+    ('\u{895e}', 0x16a7e),
+    ('\u{895f}', 0x1365f),
+    ('\u{8960}', 0x16a7d),
+    ('\u{8962}', 0x27844),
+    ('\u{8964}', 0x16b22),
+    ('\u{8966}', 0x16b21),
+    ('\u{896a}', 0x16b24),
+    ('\u{896d}', 0x16b23),
+    ('\u{896f}', 0x16b25),
+    ('\u{8972}', 0x13d31),
+    ('\u{8974}', 0x16b26),
+    ('\u{8977}', 0x16b27),
+    ('\u{897e}', 0x16b28),
+    ('\u{897f}', 0x1403e),
+    ('\u{8980}', 0x27845),
+    ('\u{8981}', 0x14d57),
+    ('\u{8983}', 0x16b29),
+    ('\u{8986}', 0x14a24),
+    ('\u{8987}', 0x14746),
+    ('\u{8988}', 0x16b2a),
+    ('\u{8989}', 0x27846),
+    ('\u{898a}', 0x16b2b),
+    ('\u{898b}', 0x1382b),
+    ('\u{898f}', 0x1352c),
+    ('\u{8990}', 0x27847),
+    ('\u{8993}', 0x16b2c),
+    ('\u{8994}', 0x17b78),
+    ('\u{8996}', 0x13b6b),
+    ('\u{8997}', 0x14741),
+    ('\u{8998}', 0x16b2d),
+    ('\u{899a}', 0x13350),
+    ('\u{899f}', 0x27848),
+    ('\u{89a1}', 0x16b2e),
+    ('\u{89a6}', 0x16b30),
+    ('\u{89a7}', 0x14d77),
+    ('\u{89a9}', 0x16b2f),
+    ('\u{89aa}', 0x13f46),
+    ('\u{89ac}', 0x16b31),
+    ('\u{89af}', 0x16b32),
+    ('\u{89b0}', 0x27849),
+    ('\u{89b2}', 0x16b33),
+    ('\u{89b3}', 0x13451),
+    ('\u{89b7}', 0x2784a),
+    ('\u{89ba}', 0x16b34),
+    ('\u{89bd}', 0x16b35),
+    ('\u{89bf}', 0x16b36),
+    ('\u{89c0}', 0x16b37),
+    ('\u{89d2}', 0x13351),
+    ('\u{89d4}', 0x17b7a),
+    ('\u{89d6}', 0x2784b),
+    ('\u{89d8}', 0x2784c),
+    ('\u{89da}', 0x16b38),
+    ('\u{89dc}', 0x16b39),
+    ('\u{89dd}', 0x16b3a),
+    ('\u{89e3}', 0x13272),
+    ('\u{89e5}', 0x17b7b),
+    ('\u{89e6}', 0x13f28),
+    ('\u{89e7}', 0x16b3b),
+    ('\u{89eb}', 0x2784d),
+    ('\u{89f1}', 0x2784f),
+    ('\u{89f3}', 0x27850),
+    ('\u{89f4}', 0x16b3c),
+    ('\u{89f6}', 0x17b7c),
+    ('\u{89f8}', 0x16b3d),
+    ('\u{89fd}', 0x27851),
+    ('\u{89ff}', 0x27852),
+    ('\u{8a00}', 0x13840),
+    ('\u{8a02}', 0x1447b),
+    ('\u{8a03}', 0x16b3e),
+    ('\u{8a08}', 0x13757),
+    ('\u{8a0a}', 0x13f56),
+    ('\u{8a0c}', 0x16b41),
+    ('\u{8a0e}', 0x14624),
+    ('\u{8a10}', 0x16b40),
+    ('\u{8a11}', 0x27854),
+    ('\u{8a12}', 0x17b7d),
+    ('\u{8a13}', 0x13731),
+    ('\u{8a14}', 0x27855),
+    ('\u{8a15}', 0x17b7e),
+    ('\u{8a16}', 0x16b3f),
+    ('\u{8a17}', 0x14277),
+    ('\u{8a18}', 0x1352d),
+    ('\u{8a1b}', 0x16b42),
+    ('\u{8a1d}', 0x16b43),
+    ('\u{8a1f}', 0x13e59),
+    ('\u{8a21}', 0x27857),
+    ('\u{8a22}', 0x17c21),
+    ('\u{8a23}', 0x1376d),
+    ('\u{8a25}', 0x16b44),
+    ('\u{8a2a}', 0x14b2c),
+    ('\u{8a2d}', 0x1405f),
+    ('\u{8a31}', 0x13576),
+    ('\u{8a33}', 0x14c75),
+    ('\u{8a34}', 0x1414a),
+    ('\u{8a35}', 0x27858),
+    ('\u{8a36}', 0x16b45),
+    ('\u{8a37}', 0x17c22),
+    ('\u{8a3a}', 0x13f47),
+    ('\u{8a3b}', 0x14370),
+    ('\u{8a3c}', 0x13e5a),
+    ('\u{8a3e}', 0x27859),
+    ('\u{8a41}', 0x16b46),
+    ('\u{8a45}', 0x2785a),
+    ('\u{8a46}', 0x16b49),
+    ('\u{8a47}', 0x17c23),
+    ('\u{8a48}', 0x16b4a),
+    ('\u{8a4d}', 0x2785b),
+    ('\u{8a4e}', 0x17c24),
+    ('\u{8a50}', 0x13a3e),
+    ('\u{8a51}', 0x14242),
+    ('\u{8a52}', 0x16b48),
+    ('\u{8a54}', 0x13e5b),
+    ('\u{8a55}', 0x1493e),
+    ('\u{8a58}', 0x2785c),
+    ('\u{8a5b}', 0x16b47),
+    ('\u{8a5d}', 0x17c25),
+    ('\u{8a5e}', 0x13b6c),
+    ('\u{8a60}', 0x13153),
+    ('\u{8a61}', 0x17c26),
+    ('\u{8a62}', 0x16b4e),
+    ('\u{8a63}', 0x13758),
+    ('\u{8a66}', 0x13b6e),
+    ('\u{8a69}', 0x13b6d),
+    ('\u{8a6b}', 0x14f4d),
+    ('\u{8a6c}', 0x16b4d),
+    ('\u{8a6d}', 0x16b4c),
+    ('\u{8a6e}', 0x14127),
+    ('\u{8a70}', 0x1354d),
+    ('\u{8a71}', 0x14f43),
+    ('\u{8a72}', 0x1333a),
+    ('\u{8a73}', 0x13e5c),
+    ('\u{8a75}', 0x17c27),
+    ('\u{8a79}', 0x17c28),
+    ('\u{8a7c}', 0x16b4b),
+    ('\u{8a82}', 0x16b50),
+    ('\u{8a84}', 0x16b51),
+    ('\u{8a85}', 0x16b4f),
+    ('\u{8a87}', 0x13858),
+    ('\u{8a89}', 0x14d40),
+    ('\u{8a8c}', 0x13b6f),
+    ('\u{8a8d}', 0x14727),
+    ('\u{8a90}', 0x2785e),
+    ('\u{8a91}', 0x16b54),
+    ('\u{8a93}', 0x14040),
+    ('\u{8a95}', 0x14342),
+    ('\u{8a98}', 0x14d36),
+    ('\u{8a9a}', 0x16b57),
+    ('\u{8a9e}', 0x1386c),
+    ('\u{8aa0}', 0x1403f),
+    ('\u{8aa1}', 0x16b53),
+    ('\u{8aa3}', 0x16b58),
+    ('\u{8aa4}', 0x1386d),
+    ('\u{8aa5}', 0x16b55),
+    ('\u{8aa6}', 0x16b56),
+    ('\u{8aa7}', 0x17c29),
+    ('\u{8aa8}', 0x16b52),
+    ('\u{8aac}', 0x14062),
+    ('\u{8aad}', 0x14649),
+    ('\u{8aae}', 0x2785d),
+    ('\u{8ab0}', 0x1432f),
+    ('\u{8ab2}', 0x1325d),
+    ('\u{8ab7}', 0x2785f),
+    ('\u{8ab9}', 0x14870),
+    ('\u{8abc}', 0x13543),
+    ('\u{8abe}', 0x27860),
+    ('\u{8abf}', 0x14434),
+    ('\u{8ac2}', 0x16b5b),
+    ('\u{8ac4}', 0x16b59),
+    ('\u{8ac7}', 0x1434c),
+    ('\u{8acb}', 0x14041),
+    ('\u{8acc}', 0x13452),
+    ('\u{8acd}', 0x16b5a),
+    ('\u{8acf}', 0x13f5b),
+    ('\u{8ad0}', 0x17c2a),
+    ('\u{8ad2}', 0x14e4a),
+    ('\u{8ad6}', 0x14f40),
+    ('\u{8ad7}', 0x27861),
+    ('\u{8ada}', 0x16b5c),
+    ('\u{8adb}', 0x16b67),
+    ('\u{8adc}', 0x14435),
+    ('\u{8ade}', 0x16b66),
+    ('\u{8adf}', 0x17c2b),
+    ('\u{8ae0}', 0x16b63),
+    ('\u{8ae1}', 0x16b6b),
+    ('\u{8ae2}', 0x16b64),
+    ('\u{8ae4}', 0x16b60),
+    ('\u{8ae6}', 0x1447c),
+    ('\u{8ae7}', 0x16b5f),
+    ('\u{8aeb}', 0x16b5d),
+    ('\u{8aed}', 0x14d21),
+    ('\u{8aee}', 0x13b70),
+    ('\u{8af1}', 0x16b61),
+    ('\u{8af3}', 0x16b5e),
+    ('\u{8af4}', 0x17c2c),
+    ('\u{8af6}', 0x17c2d),
+    ('\u{8af7}', 0x16b65),
+    ('\u{8af8}', 0x13d74),
+    ('\u{8afa}', 0x13841),
+    ('\u{8afc}', 0x27862),
+    ('\u{8afe}', 0x1427a),
+    ('\u{8b00}', 0x14b45),
+    ('\u{8b01}', 0x1315a),
+    ('\u{8b02}', 0x13062),
+    ('\u{8b04}', 0x14625),
+    ('\u{8b05}', 0x27865),
+    ('\u{8b07}', 0x16b69),
+    ('\u{8b0a}', 0x27864),
+    ('\u{8b0c}', 0x16b68),
+    ('\u{8b0d}', 0x27866),
+    ('\u{8b0e}', 0x14666),
+    ('\u{8b10}', 0x16b6d),
+    ('\u{8b14}', 0x16b62),
+    ('\u{8b16}', 0x16b6c),
+    ('\u{8b17}', 0x16b6e),
+    ('\u{8b19}', 0x1382c),
+    ('\u{8b1a}', 0x16b6a),
+    ('\u{8b1b}', 0x13956),
+    ('\u{8b1c}', 0x27867),
+    ('\u{8b1d}', 0x13c55),
+    ('\u{8b1f}', 0x27868),
+    ('\u{8b20}', 0x16b6f),
+    ('\u{8b21}', 0x14d58),
+    ('\u{8b26}', 0x16b72),
+    ('\u{8b28}', 0x16b75),
+    ('\u{8b2b}', 0x16b73),
+    ('\u{8b2c}', 0x14935),
+    ('\u{8b2d}', 0x27869),
+    ('\u{8b33}', 0x16b70),
+    ('\u{8b39}', 0x13660),
+    ('\u{8b3e}', 0x16b74),
+    ('\u{8b41}', 0x16b76),
+    ('\u{8b43}', 0x2786a),
+    ('\u{8b46}', 0x17c31),
+    ('\u{8b49}', 0x16b7a),
+    ('\u{8b4c}', 0x16b77),
+    ('\u{8b4e}', 0x16b79),
+    ('\u{8b4f}', 0x16b78),
+    ('\u{8b51}', 0x2786c),
+    ('\u{8b54}', 0x17c32),
+    ('\u{8b56}', 0x16b7b),
+    ('\u{8b58}', 0x13c31),
+    ('\u{8b59}', 0x17c33),
+    ('\u{8b5a}', 0x16b7d),
+    ('\u{8b5b}', 0x16b7c),
+    ('\u{8b5c}', 0x14968),
+    ('\u{8b5e}', 0x2786d),
+    ('\u{8b5f}', 0x16c21),
+    ('\u{8b66}', 0x13759),
+    ('\u{8b69}', 0x17c34),
+    ('\u{8b6b}', 0x16b7e),
+    ('\u{8b6c}', 0x16c22),
+    ('\u{8b6f}', 0x16c23),
+    ('\u{8b70}', 0x13544),
+    ('\u{8b71}', 0x16641),
+    ('\u{8b72}', 0x13e79),
+    ('\u{8b74}', 0x16c24),
+    ('\u{8b76}', 0x2786e),
+    ('\u{8b77}', 0x1386e),
+    ('\u{8b7d}', 0x16c25),
+    ('\u{8b7f}', 0x2786f),
+    ('\u{8b80}', 0x16c26),
+    ('\u{8b81}', 0x27870),
+    ('\u{8b83}', 0x13b3e),
+    ('\u{8b8a}', 0x15a4e),
+    ('\u{8b8b}', 0x27871),
+    ('\u{8b8c}', 0x16c27),
+    ('\u{8b8e}', 0x16c28),
+    ('\u{8b90}', 0x13d32),
+    ('\u{8b92}', 0x16c29),
+    ('\u{8b93}', 0x16c2a),
+    ('\u{8b94}', 0x27872),
+    ('\u{8b95}', 0x27873),
+    ('\u{8b96}', 0x16c2b),
+    ('\u{8b99}', 0x16c2c),
+    ('\u{8b9a}', 0x16c2d),
+    ('\u{8b9c}', 0x27874),
+    ('\u{8b9d}', 0x17c35),
+    ('\u{8b9e}', 0x27875),
+    ('\u{8c37}', 0x1432b),
+    ('\u{8c39}', 0x27876),
+    ('\u{8c3a}', 0x16c2e),
+    ('\u{8c3d}', 0x27878),
+    ('\u{8c3f}', 0x16c30),
+    ('\u{8c41}', 0x16c2f),
+    ('\u{8c45}', 0x2787b),
+    ('\u{8c46}', 0x14626),
+    ('\u{8c47}', 0x2787c),
+    ('\u{8c48}', 0x16c31),
+    ('\u{8c49}', 0x17c36),
+    ('\u{8c4a}', 0x14b2d),
+    ('\u{8c4c}', 0x16c32),
+    ('\u{8c4e}', 0x16c33),
+    ('\u{8c4f}', 0x2787d),
+    ('\u{8c50}', 0x16c34),
+    ('\u{8c54}', 0x2787e),
+    ('\u{8c55}', 0x16c35),
+    ('\u{8c57}', 0x27921),
+    ('\u{8c5a}', 0x1465a),
+    ('\u{8c61}', 0x13e5d),
+    ('\u{8c62}', 0x16c36),
+    ('\u{8c68}', 0x17c37),
+    ('\u{8c69}', 0x27922),
+    ('\u{8c6a}', 0x1396b),
+    ('\u{8c6b}', 0x1502e),
+    ('\u{8c6c}', 0x16c37),
+    ('\u{8c6d}', 0x27923),
+    ('\u{8c73}', 0x27924),
+    ('\u{8c78}', 0x16c38),
+    ('\u{8c79}', 0x1493f),
+    ('\u{8c7a}', 0x16c39),
+    ('\u{8c7c}', 0x16c41),
+    ('\u{8c82}', 0x16c3a),
+    ('\u{8c85}', 0x16c3c),
+    ('\u{8c89}', 0x16c3b),
+    ('\u{8c8a}', 0x16c3d),
+    ('\u{8c8c}', 0x14b46),
+    ('\u{8c8d}', 0x16c3e),
+    ('\u{8c8e}', 0x16c3f),
+    ('\u{8c92}', 0x27927),
+    ('\u{8c93}', 0x27926),
+    ('\u{8c94}', 0x16c40),
+    ('\u{8c98}', 0x16c42),
+    ('\u{8c99}', 0x27928),
+    ('\u{8c9b}', 0x2792a),
+    ('\u{8c9d}', 0x1332d),
+    ('\u{8c9e}', 0x14467),
+    ('\u{8ca0}', 0x14969),
+    ('\u{8ca1}', 0x13a62),
+    ('\u{8ca2}', 0x13957),
+    ('\u{8ca4}', 0x2792b),
+    ('\u{8ca7}', 0x1494f),
+    ('\u{8ca8}', 0x1325f),
+    ('\u{8ca9}', 0x1484e),
+    ('\u{8caa}', 0x16c45),
+    ('\u{8cab}', 0x13453),
+    ('\u{8cac}', 0x14055),
+    ('\u{8cad}', 0x16c44),
+    ('\u{8cae}', 0x16c49),
+    ('\u{8caf}', 0x14379),
+    ('\u{8cb0}', 0x14c63),
+    ('\u{8cb2}', 0x16c47),
+    ('\u{8cb3}', 0x16c48),
+    ('\u{8cb4}', 0x1352e),
+    ('\u{8cb6}', 0x16c4a),
+    ('\u{8cb7}', 0x14763),
+    ('\u{8cb8}', 0x1425f),
+    ('\u{8cbb}', 0x14871),
+    ('\u{8cbc}', 0x1453d),
+    ('\u{8cbd}', 0x16c46),
+    ('\u{8cbf}', 0x14b47),
+    ('\u{8cc0}', 0x1326c),
+    ('\u{8cc1}', 0x16c4c),
+    ('\u{8cc2}', 0x14f28),
+    ('\u{8cc3}', 0x14442),
+    ('\u{8cc4}', 0x14f45),
+    ('\u{8cc7}', 0x13b71),
+    ('\u{8cc8}', 0x16c4b),
+    ('\u{8cca}', 0x14231),
+    ('\u{8ccd}', 0x16c5c),
+    ('\u{8cce}', 0x14128),
+    ('\u{8cd1}', 0x14678),
+    ('\u{8cd3}', 0x14950),
+    ('\u{8cd5}', 0x2792d),
+    ('\u{8cd6}', 0x2792c),
+    ('\u{8cd9}', 0x2792e),
+    ('\u{8cda}', 0x16c4f),
+    ('\u{8cdb}', 0x13b3f),
+    ('\u{8cdc}', 0x13b72),
+    ('\u{8cde}', 0x13e5e),
+    ('\u{8ce0}', 0x14765),
+    ('\u{8ce1}', 0x17c39),
+    ('\u{8ce2}', 0x1382d),
+    ('\u{8ce3}', 0x16c4e),
+    ('\u{8ce4}', 0x16c4d),
+    ('\u{8ce6}', 0x1496a),
+    ('\u{8cea}', 0x13c41),
+    ('\u{8ced}', 0x14552),
+    ('\u{8cf0}', 0x27930),
+    ('\u{8cf1}', 0x27931),
+    ('\u{8cf4}', 0x17c3a),
+    ('\u{8cf8}', 0x17c3b),
+    ('\u{8cfa}', 0x16c51),
+    ('\u{8cfb}', 0x16c52),
+    ('\u{8cfc}', 0x13958),
+    ('\u{8cfd}', 0x16c50),
+    ('\u{8cfe}', 0x17c3c),
+    ('\u{8d04}', 0x16c53),
+    ('\u{8d05}', 0x16c54),
+    ('\u{8d07}', 0x16c56),
+    ('\u{8d08}', 0x14223),
+    ('\u{8d09}', 0x27933),
+    ('\u{8d0a}', 0x16c55),
+    ('\u{8d0b}', 0x13466),
+    ('\u{8d0d}', 0x16c58),
+    ('\u{8d0e}', 0x27934),
+    ('\u{8d0f}', 0x16c57),
+    ('\u{8d10}', 0x16c59),
+    ('\u{8d12}', 0x17c3e),
+    ('\u{8d13}', 0x16c5b),
+    ('\u{8d14}', 0x16c5d),
+    ('\u{8d16}', 0x16c5e),
+    ('\u{8d1b}', 0x17c3f),
+    ('\u{8d64}', 0x14056),
+    ('\u{8d66}', 0x13c4f),
+    ('\u{8d67}', 0x16c5f),
+    ('\u{8d6b}', 0x13352),
+    ('\u{8d6c}', 0x27935),
+    ('\u{8d6d}', 0x16c60),
+    ('\u{8d70}', 0x14176),
+    ('\u{8d71}', 0x16c61),
+    ('\u{8d73}', 0x16c62),
+    ('\u{8d74}', 0x1496b),
+    ('\u{8d77}', 0x1352f),
+    ('\u{8d81}', 0x16c63),
+    ('\u{8d84}', 0x27936),
+    ('\u{8d85}', 0x14436),
+    ('\u{8d8a}', 0x1315b),
+    ('\u{8d95}', 0x27937),
+    ('\u{8d99}', 0x16c64),
+    ('\u{8da3}', 0x13c71),
+    ('\u{8da6}', 0x27938),
+    ('\u{8da8}', 0x13f76),
+    ('\u{8daf}', 0x17c40),
+    ('\u{8db3}', 0x1422d),
+    ('\u{8dba}', 0x16c67),
+    ('\u{8dbe}', 0x16c66),
+    ('\u{8dc2}', 0x16c65),
+    ('\u{8dc6}', 0x2793a),
+    ('\u{8dc8}', 0x2793b),
+    ('\u{8dcb}', 0x16c6d),
+    ('\u{8dcc}', 0x16c6b),
+    ('\u{8dce}', 0x17c41),
+    ('\u{8dcf}', 0x16c68),
+    ('\u{8dd1}', 0x17c42),
+    ('\u{8dd6}', 0x16c6a),
+    ('\u{8dd7}', 0x17c43),
+    ('\u{8dd9}', 0x2793c),
+    ('\u{8dda}', 0x16c69),
+    ('\u{8ddb}', 0x16c6c),
+    ('\u{8ddd}', 0x13577),
+    ('\u{8ddf}', 0x16c70),
+    ('\u{8de1}', 0x14057),
+    ('\u{8de3}', 0x16c71),
+    ('\u{8de8}', 0x13859),
+    ('\u{8dea}', 0x16c6e),
+    ('\u{8deb}', 0x16c6f),
+    ('\u{8dec}', 0x2793d),
+    ('\u{8def}', 0x14f29),
+    ('\u{8df3}', 0x14437),
+    ('\u{8df5}', 0x14129),
+    ('\u{8dfc}', 0x16c72),
+    ('\u{8dfd}', 0x27940),
+    ('\u{8dff}', 0x16c75),
+    ('\u{8e06}', 0x27941),
+    ('\u{8e08}', 0x16c73),
+    ('\u{8e09}', 0x16c74),
+    ('\u{8e0a}', 0x14d59),
+    ('\u{8e0c}', 0x2793e),
+    ('\u{8e0f}', 0x14627),
+    ('\u{8e10}', 0x16c78),
+    ('\u{8e14}', 0x27943),
+    ('\u{8e16}', 0x27944),
+    ('\u{8e1d}', 0x16c76),
+    ('\u{8e1e}', 0x16c77),
+    ('\u{8e1f}', 0x16c79),
+    ('\u{8e20}', 0x17c44),
+    ('\u{8e21}', 0x27945),
+    ('\u{8e22}', 0x27946),
+    ('\u{8e23}', 0x17c45),
+    ('\u{8e27}', 0x27947),
+    ('\u{8e2a}', 0x16d29),
+    ('\u{8e30}', 0x16c7c),
+    ('\u{8e34}', 0x16c7d),
+    ('\u{8e35}', 0x16c7b),
+    ('\u{8e36}', 0x2794a),
+    ('\u{8e39}', 0x2794b),
+    ('\u{8e3d}', 0x17c46),
+    ('\u{8e42}', 0x16c7a),
+    ('\u{8e44}', 0x1447d),
+    ('\u{8e47}', 0x16d21),
+    ('\u{8e48}', 0x16d25),
+    ('\u{8e49}', 0x16d22),
+    ('\u{8e4a}', 0x16c7e),
+    ('\u{8e4b}', 0x2794c),
+    ('\u{8e4c}', 0x16d23),
+    ('\u{8e50}', 0x16d24),
+    ('\u{8e54}', 0x2794d),
+    ('\u{8e55}', 0x16d2b),
+    ('\u{8e59}', 0x16d26),
+    ('\u{8e5f}', 0x14058),
+    ('\u{8e60}', 0x16d28),
+    ('\u{8e62}', 0x2794e),
+    ('\u{8e63}', 0x16d2a),
+    ('\u{8e64}', 0x16d27),
+    ('\u{8e6c}', 0x2794f),
+    ('\u{8e6d}', 0x27950),
+    ('\u{8e6f}', 0x27951),
+    ('\u{8e70}', 0x17c47),
+    ('\u{8e72}', 0x16d2d),
+    ('\u{8e74}', 0x13d33),
+    ('\u{8e76}', 0x16d2c),
+    ('\u{8e7b}', 0x17c48),
+    ('\u{8e7c}', 0x16d2e),
+    ('\u{8e81}', 0x16d2f),
+    ('\u{8e84}', 0x16d32),
+    ('\u{8e85}', 0x16d31),
+    ('\u{8e87}', 0x16d30),
+    ('\u{8e8a}', 0x16d34),
+    ('\u{8e8b}', 0x16d33),
+    ('\u{8e8d}', 0x14c76),
+    ('\u{8e91}', 0x16d36),
+    ('\u{8e93}', 0x16d35),
+    ('\u{8e94}', 0x16d37),
+    ('\u{8e98}', 0x27952),
+    ('\u{8e99}', 0x16d38),
+    ('\u{8e9e}', 0x27953),
+    ('\u{8ea1}', 0x16d3a),
+    ('\u{8eaa}', 0x16d39),
+    ('\u{8eab}', 0x13f48),
+    ('\u{8eac}', 0x16d3b),
+    ('\u{8eae}', 0x27954),
+    ('\u{8eaf}', 0x1366d),
+    ('\u{8eb0}', 0x16d3c),
+    ('\u{8eb1}', 0x16d3e),
+    ('\u{8eb3}', 0x27955),
+    ('\u{8eb5}', 0x27956),
+    ('\u{8eb6}', 0x27957),
+    ('\u{8ebb}', 0x27958),
+    ('\u{8ebe}', 0x16d3f),
+    ('\u{8ec0}', 0x17c4a),
+    ('\u{8ec5}', 0x16d40),
+    ('\u{8ec6}', 0x16d3d),
+    ('\u{8ec8}', 0x16d41),
+    ('\u{8eca}', 0x13c56),
+    ('\u{8ecb}', 0x16d42),
+    ('\u{8ecc}', 0x13530),
+    ('\u{8ecd}', 0x13733),
+    ('\u{8ed1}', 0x2795a),
+    ('\u{8ed2}', 0x1382e),
+    ('\u{8ed4}', 0x2795b),
+    ('\u{8edb}', 0x16d43),
+    ('\u{8edf}', 0x14670),
+    ('\u{8ee2}', 0x1453e),
+    ('\u{8ee3}', 0x16d44),
+    ('\u{8eeb}', 0x16d47),
+    ('\u{8ef8}', 0x13c34),
+    ('\u{8ef9}', 0x2795d),
+    ('\u{8efa}', 0x17c4c),
+    ('\u{8efb}', 0x16d46),
+    ('\u{8efc}', 0x16d45),
+    ('\u{8efd}', 0x1375a),
+    ('\u{8efe}', 0x16d48),
+    ('\u{8f00}', 0x2795f),
+    ('\u{8f03}', 0x13353),
+    ('\u{8f05}', 0x16d4a),
+    ('\u{8f08}', 0x27960),
+    ('\u{8f09}', 0x13a5c),
+    ('\u{8f0a}', 0x16d49),
+    ('\u{8f0c}', 0x16d52),
+    ('\u{8f12}', 0x16d4c),
+    ('\u{8f13}', 0x16d4e),
+    ('\u{8f14}', 0x14a65),
+    ('\u{8f15}', 0x16d4b),
+    ('\u{8f17}', 0x27961),
+    ('\u{8f19}', 0x16d4d),
+    ('\u{8f1b}', 0x16d51),
+    ('\u{8f1c}', 0x16d4f),
+    ('\u{8f1d}', 0x13531),
+    ('\u{8f1e}', 0x17c4d),
+    ('\u{8f1f}', 0x16d50),
+    ('\u{8f26}', 0x16d53),
+    ('\u{8f29}', 0x1475a),
+    ('\u{8f2a}', 0x14e58),
+    ('\u{8f2b}', 0x27962),
+    ('\u{8f2d}', 0x17c4e),
+    ('\u{8f2f}', 0x13d34),
+    ('\u{8f33}', 0x16d54),
+    ('\u{8f36}', 0x17c4f),
+    ('\u{8f38}', 0x14d22),
+    ('\u{8f39}', 0x16d56),
+    ('\u{8f3b}', 0x16d55),
+    ('\u{8f3e}', 0x16d59),
+    ('\u{8f3f}', 0x14d41),
+    ('\u{8f40}', 0x27963),
+    ('\u{8f42}', 0x16d58),
+    ('\u{8f44}', 0x1336d),
+    ('\u{8f45}', 0x16d57),
+    ('\u{8f46}', 0x16d5c),
+    ('\u{8f49}', 0x16d5b),
+    ('\u{8f4a}', 0x27964),
+    ('\u{8f4c}', 0x16d5a),
+    ('\u{8f4d}', 0x14532),
+    ('\u{8f4e}', 0x16d5d),
+    ('\u{8f54}', 0x17c50),
+    ('\u{8f57}', 0x16d5e),
+    ('\u{8f58}', 0x27965),
+    ('\u{8f5c}', 0x16d5f),
+    ('\u{8f5f}', 0x1396c),
+    ('\u{8f61}', 0x13725),
+    ('\u{8f62}', 0x16d60),
+    ('\u{8f63}', 0x16d61),
+    ('\u{8f64}', 0x16d62),
+    ('\u{8f9b}', 0x13f49),
+    ('\u{8f9c}', 0x16d63),
+    ('\u{8f9e}', 0x13c2d),
+    ('\u{8f9f}', 0x16d64),
+    ('\u{8fa3}', 0x16d65),
+    ('\u{8fa4}', 0x27967),
+    ('\u{8fa6}', 0x17c52),
+    ('\u{8fa7}', 0x15221),
+    ('\u{8fa8}', 0x1517e),
+    ('\u{8fad}', 0x16d66),
+    ('\u{8fae}', 0x16570),
+    ('\u{8faf}', 0x16d67),
+    ('\u{8fb0}', 0x14324),
+    ('\u{8fb1}', 0x13f2b),
+    ('\u{8fb2}', 0x14740),
+    ('\u{8fb4}', 0x27968),
+    ('\u{8fb5}', 0x17c53),
+    ('\u{8fb6}', 0x2796a),
+    ('\u{8fb7}', 0x16d68),
+    ('\u{8fba}', 0x14a55),
+    ('\u{8fbb}', 0x14454),
+    ('\u{8fbc}', 0x1397e),
+    ('\u{8fbf}', 0x14329),
+    ('\u{8fc1}', 0x2796c),
+    ('\u{8fc2}', 0x1312a),
+    ('\u{8fc4}', 0x14b78),
+    ('\u{8fc5}', 0x13f57),
+    ('\u{8fc6}', 0x2796d),
+    ('\u{8fca}', 0x2796f),
+    ('\u{8fcd}', 0x27970),
+    ('\u{8fce}', 0x1375e),
+    ('\u{8fd1}', 0x13661),
+    ('\u{8fd3}', 0x27971),
+    ('\u{8fd4}', 0x14a56),
+    ('\u{8fd5}', 0x27972),
+    ('\u{8fda}', 0x16d69),
+    ('\u{8fe0}', 0x27973),
+    ('\u{8fe2}', 0x16d6b),
+    ('\u{8fe4}', 0x17c54),
+    ('\u{8fe5}', 0x16d6a),
+    ('\u{8fe6}', 0x13260),
+    ('\u{8fe8}', 0x17c55),
+    ('\u{8fe9}', 0x14676),
+    ('\u{8fea}', 0x16d6c),
+    ('\u{8feb}', 0x14777),
+    ('\u{8fed}', 0x14533),
+    ('\u{8fee}', 0x17c56),
+    ('\u{8fef}', 0x16d6d),
+    ('\u{8ff0}', 0x13d52),
+    ('\u{8ff1}', 0x27974),
+    ('\u{8ff4}', 0x16d6f),
+    ('\u{8ff5}', 0x27975),
+    ('\u{8ff7}', 0x14c42),
+    ('\u{8ff8}', 0x16d7e),
+    ('\u{8ff9}', 0x16d71),
+    ('\u{8ffa}', 0x16d72),
+    ('\u{8ffb}', 0x27976),
+    ('\u{8ffd}', 0x14449),
+    ('\u{9000}', 0x14260),
+    ('\u{9001}', 0x14177),
+    ('\u{9002}', 0x27977),
+    ('\u{9003}', 0x14628),
+    ('\u{9005}', 0x16d70),
+    ('\u{9006}', 0x13555),
+    ('\u{9008}', 0x17c57),
+    ('\u{900b}', 0x16d79),
+    ('\u{900c}', 0x27978),
+    ('\u{900d}', 0x16d76),
+    ('\u{900e}', 0x16e25),
+    ('\u{900f}', 0x14629),
+    ('\u{9010}', 0x14360),
+    ('\u{9011}', 0x16d73),
+    ('\u{9013}', 0x1447e),
+    ('\u{9014}', 0x14553),
+    ('\u{9015}', 0x16d74),
+    ('\u{9016}', 0x16d78),
+    ('\u{9017}', 0x13f60),
+    ('\u{9019}', 0x14767),
+    ('\u{901a}', 0x1444c),
+    ('\u{901d}', 0x14042),
+    ('\u{901e}', 0x16d77),
+    ('\u{901f}', 0x1422e),
+    ('\u{9020}', 0x14224),
+    ('\u{9021}', 0x16d75),
+    ('\u{9022}', 0x13029),
+    ('\u{9023}', 0x14f22),
+    ('\u{9027}', 0x16d7a),
+    ('\u{902d}', 0x17c58),
+    ('\u{902e}', 0x14261),
+    ('\u{9031}', 0x13d35),
+    ('\u{9032}', 0x13f4a),
+    ('\u{9035}', 0x16d7c),
+    ('\u{9036}', 0x16d7b),
+    ('\u{9037}', 0x27979),
+    ('\u{9038}', 0x1306f),
+    ('\u{9039}', 0x16d7d),
+    ('\u{903c}', 0x1492f),
+    ('\u{903e}', 0x16e27),
+    ('\u{9041}', 0x1465b),
+    ('\u{9042}', 0x13f6b),
+    ('\u{9043}', 0x2797b),
+    ('\u{9044}', 0x2797c),
+    ('\u{9045}', 0x14359),
+    ('\u{9047}', 0x13678),
+    ('\u{9049}', 0x16e26),
+    ('\u{904a}', 0x14d37),
+    ('\u{904b}', 0x1313f),
+    ('\u{904d}', 0x14a57),
+    ('\u{904e}', 0x13261),
+    ('\u{904f}', 0x16e21),
+    ('\u{9050}', 0x16e22),
+    ('\u{9051}', 0x16e23),
+    ('\u{9052}', 0x16e24),
+    ('\u{9053}', 0x1463b),
+    ('\u{9054}', 0x14323),
+    ('\u{9055}', 0x13063),
+    ('\u{9056}', 0x16e28),
+    ('\u{9058}', 0x16e29),
+    ('\u{9059}', 0x17423),
+    ('\u{905c}', 0x1423d),
+    ('\u{905d}', 0x2797d),
+    ('\u{905e}', 0x16e2a),
+    ('\u{9060}', 0x13173),
+    ('\u{9061}', 0x1414c),
+    ('\u{9063}', 0x1382f),
+    ('\u{9065}', 0x14d5a),
+    ('\u{9068}', 0x16e2b),
+    ('\u{9069}', 0x1452c),
+    ('\u{906d}', 0x14178),
+    ('\u{906e}', 0x13c57),
+    ('\u{906f}', 0x16e2c),
+    ('\u{9072}', 0x16e2f),
+    ('\u{9075}', 0x13d65),
+    ('\u{9076}', 0x16e2d),
+    ('\u{9077}', 0x1412b),
+    ('\u{9078}', 0x1412a),
+    ('\u{907a}', 0x13064),
+    ('\u{907c}', 0x14e4b),
+    ('\u{907d}', 0x16e31),
+    ('\u{907f}', 0x14872),
+    ('\u{9080}', 0x16e33),
+    ('\u{9081}', 0x16e32),
+    ('\u{9082}', 0x16e30),
+    ('\u{9083}', 0x16364),
+    ('\u{9084}', 0x13454),
+    ('\u{9085}', 0x27a22),
+    ('\u{9087}', 0x16d6e),
+    ('\u{9088}', 0x17c5a),
+    ('\u{9089}', 0x16e35),
+    ('\u{908a}', 0x16e34),
+    ('\u{908c}', 0x27a23),
+    ('\u{908f}', 0x16e36),
+    ('\u{9090}', 0x27a24),
+    ('\u{9091}', 0x14d38),
+    ('\u{9095}', 0x17c5b),
+    ('\u{9097}', 0x17c5c),
+    ('\u{9099}', 0x17c5d),
+    ('\u{909b}', 0x17c5e),
+    ('\u{90a1}', 0x27a26),
+    ('\u{90a2}', 0x17c5f),
+    ('\u{90a3}', 0x14661),
+    ('\u{90a6}', 0x14b2e),
+    ('\u{90a8}', 0x16e37),
+    ('\u{90aa}', 0x13c59),
+    ('\u{90af}', 0x16e38),
+    ('\u{90b0}', 0x27a28),
+    ('\u{90b1}', 0x16e39),
+    ('\u{90b3}', 0x17c60),
+    ('\u{90b5}', 0x16e3a),
+    ('\u{90b6}', 0x27a29),
+    ('\u{90b8}', 0x14521),
+    ('\u{90be}', 0x17c61),
+    ('\u{90c1}', 0x1306a),
+    ('\u{90c3}', 0x27a2a),
+    ('\u{90c4}', 0x17c62),
+    ('\u{90c5}', 0x17c63),
+    ('\u{90c7}', 0x17c64),
+    ('\u{90c8}', 0x27a2b),
+    ('\u{90ca}', 0x13959),
+    ('\u{90ce}', 0x14f3a),
+    ('\u{90d7}', 0x17c65),
+    ('\u{90db}', 0x16e3e),
+    ('\u{90dc}', 0x27a2d),
+    ('\u{90dd}', 0x17c66),
+    ('\u{90de}', 0x17c67),
+    ('\u{90df}', 0x27a2e),
+    ('\u{90e1}', 0x13734),
+    ('\u{90e2}', 0x16e3b),
+    ('\u{90e4}', 0x16e3c),
+    ('\u{90e8}', 0x14974),
+    ('\u{90eb}', 0x27a33),
+    ('\u{90ed}', 0x13354),
+    ('\u{90ef}', 0x17c68),
+    ('\u{90f2}', 0x27a31),
+    ('\u{90f4}', 0x17c69),
+    ('\u{90f5}', 0x14d39),
+    ('\u{90f6}', 0x27a30),
+    ('\u{90f7}', 0x1363f),
+    ('\u{90fd}', 0x14554),
+    ('\u{90fe}', 0x27a34),
+    ('\u{90ff}', 0x27a35),
+    ('\u{9100}', 0x27a32),
+    ('\u{9102}', 0x16e3f),
+    ('\u{9104}', 0x27a36),
+    ('\u{9106}', 0x27a37),
+    ('\u{9112}', 0x16e40),
+    ('\u{9114}', 0x17c6b),
+    ('\u{9115}', 0x17c6c),
+    ('\u{9116}', 0x17c6d),
+    ('\u{9118}', 0x27a38),
+    ('\u{9119}', 0x16e41),
+    ('\u{911c}', 0x27a39),
+    ('\u{911e}', 0x27a3a),
+    ('\u{9122}', 0x17c6e),
+    ('\u{9123}', 0x17c6f),
+    ('\u{9127}', 0x17c70),
+    ('\u{912d}', 0x14522),
+    ('\u{912f}', 0x17c71),
+    ('\u{9130}', 0x16e43),
+    ('\u{9131}', 0x17c72),
+    ('\u{9132}', 0x16e42),
+    ('\u{9134}', 0x17c73),
+    ('\u{9137}', 0x27a3b),
+    ('\u{9139}', 0x27a3c),
+    ('\u{913a}', 0x27a3d),
+    ('\u{913d}', 0x17c74),
+    ('\u{9146}', 0x27a3e),
+    ('\u{9147}', 0x27a3f),
+    ('\u{9148}', 0x17c75),
+    ('\u{9149}', 0x14653),
+    ('\u{914a}', 0x16e44),
+    ('\u{914b}', 0x13d36),
+    ('\u{914c}', 0x13c60),
+    ('\u{914d}', 0x1475b),
+    ('\u{914e}', 0x14371),
+    ('\u{9152}', 0x13c72),
+    ('\u{9154}', 0x13f6c),
+    ('\u{9156}', 0x16e45),
+    ('\u{9157}', 0x27a40),
+    ('\u{9158}', 0x16e46),
+    ('\u{9159}', 0x27a41),
+    ('\u{915b}', 0x17c76),
+    ('\u{9161}', 0x27a42),
+    ('\u{9162}', 0x13f5d),
+    ('\u{9163}', 0x16e47),
+    ('\u{9164}', 0x27a43),
+    ('\u{9165}', 0x16e48),
+    ('\u{9169}', 0x16e49),
+    ('\u{916a}', 0x14d6f),
+    ('\u{916c}', 0x13d37),
+    ('\u{9172}', 0x16e4b),
+    ('\u{9173}', 0x16e4a),
+    ('\u{9174}', 0x27a44),
+    ('\u{9175}', 0x1395a),
+    ('\u{9177}', 0x13973),
+    ('\u{9178}', 0x13b40),
+    ('\u{9179}', 0x27a45),
+    ('\u{9182}', 0x16e4e),
+    ('\u{9183}', 0x17c77),
+    ('\u{9185}', 0x27a46),
+    ('\u{9187}', 0x13d66),
+    ('\u{9189}', 0x16e4d),
+    ('\u{918b}', 0x16e4c),
+    ('\u{918d}', 0x14269),
+    ('\u{918e}', 0x27a47),
+    ('\u{9190}', 0x1386f),
+    ('\u{9192}', 0x14043),
+    ('\u{9197}', 0x14830),
+    ('\u{919c}', 0x13d39),
+    ('\u{919e}', 0x17c78),
+    ('\u{91a2}', 0x16e4f),
+    ('\u{91a4}', 0x13e5f),
+    ('\u{91a8}', 0x27a48),
+    ('\u{91aa}', 0x16e52),
+    ('\u{91ab}', 0x16e50),
+    ('\u{91ac}', 0x17c79),
+    ('\u{91ae}', 0x27a49),
+    ('\u{91af}', 0x16e51),
+    ('\u{91b1}', 0x17c7a),
+    ('\u{91b3}', 0x27a4a),
+    ('\u{91b4}', 0x16e54),
+    ('\u{91b5}', 0x16e53),
+    ('\u{91b6}', 0x27a4b),
+    ('\u{91b8}', 0x13e7a),
+    ('\u{91ba}', 0x16e55),
+    ('\u{91bc}', 0x17c7b),
+    ('\u{91c0}', 0x16e56),
+    ('\u{91c1}', 0x16e57),
+    ('\u{91c3}', 0x27a4c),
+    ('\u{91c4}', 0x27a4d),
+    ('\u{91c6}', 0x14850),
+    ('\u{91c7}', 0x13a53),
+    ('\u{91c8}', 0x13c61),
+    ('\u{91c9}', 0x16e58),
+    ('\u{91cb}', 0x16e59),
+    ('\u{91cc}', 0x14e24),
+    ('\u{91cd}', 0x13d45),
+    ('\u{91ce}', 0x14c6e),
+    ('\u{91cf}', 0x14e4c),
+    ('\u{91d0}', 0x16e5a),
+    ('\u{91d1}', 0x13662),
+    ('\u{91d6}', 0x16e5b),
+    ('\u{91d7}', 0x17c7c),
+    ('\u{91d8}', 0x14523),
+    ('\u{91da}', 0x27a4e),
+    ('\u{91db}', 0x16e5e),
+    ('\u{91dc}', 0x13378),
+    ('\u{91dd}', 0x13f4b),
+    ('\u{91df}', 0x16e5c),
+    ('\u{91e1}', 0x16e5d),
+    ('\u{91e3}', 0x14460),
+    ('\u{91e4}', 0x17c7e),
+    ('\u{91e5}', 0x17d21),
+    ('\u{91e6}', 0x14b55),
+    ('\u{91e7}', 0x1367c),
+    ('\u{91ec}', 0x27a51),
+    ('\u{91ed}', 0x17d22),
+    ('\u{91ee}', 0x27a52),
+    ('\u{91f1}', 0x17d23),
+    ('\u{91f5}', 0x16e60),
+    ('\u{91f6}', 0x16e61),
+    ('\u{91fb}', 0x17c7d),
+    ('\u{91fc}', 0x16e5f),
+    ('\u{91ff}', 0x16e63),
+    ('\u{9201}', 0x27a53),
+    ('\u{9207}', 0x17d24),
+    ('\u{920a}', 0x27a54),
+    ('\u{920d}', 0x1465f),
+    ('\u{920e}', 0x13343),
+    ('\u{9210}', 0x17d25),
+    ('\u{9211}', 0x16e67),
+    ('\u{9214}', 0x16e64),
+    ('\u{9215}', 0x16e66),
+    ('\u{9216}', 0x27a55),
+    ('\u{9217}', 0x27a56),
+    ('\u{921e}', 0x16e62),
+    ('\u{9229}', 0x16f4f),
+    ('\u{922c}', 0x16e65),
+    ('\u{9233}', 0x27a58),
+    ('\u{9234}', 0x14e6b),
+    ('\u{9237}', 0x1385a),
+    ('\u{9238}', 0x17d26),
+    ('\u{9239}', 0x17d27),
+    ('\u{923a}', 0x17d28),
+    ('\u{923c}', 0x17d29),
+    ('\u{923f}', 0x16e6f),
+    ('\u{9240}', 0x17d2a),
+    ('\u{9242}', 0x27a59),
+    ('\u{9243}', 0x17d2b),
+    ('\u{9244}', 0x14534),
+    ('\u{9245}', 0x16e6a),
+    ('\u{9247}', 0x27a5a),
+    ('\u{9248}', 0x16e6d),
+    ('\u{9249}', 0x16e6b),
+    ('\u{924a}', 0x27a5b),
+    ('\u{924b}', 0x16e70),
+    ('\u{924e}', 0x27a5c),
+    ('\u{924f}', 0x17d2c),
+    ('\u{9250}', 0x16e71),
+    ('\u{9251}', 0x27a5d),
+    ('\u{9256}', 0x27a5e),
+    ('\u{9257}', 0x16e69),
+    ('\u{9259}', 0x27a5f),
+    ('\u{925a}', 0x16e76),
+    ('\u{925b}', 0x13174),
+    ('\u{925e}', 0x16e68),
+    ('\u{9260}', 0x27a60),
+    ('\u{9261}', 0x27a61),
+    ('\u{9262}', 0x1482d),
+    ('\u{9264}', 0x16e6c),
+    ('\u{9265}', 0x27a62),
+    ('\u{9266}', 0x13e60),
+    ('\u{9267}', 0x27a63),
+    ('\u{9268}', 0x27a64),
+    ('\u{9271}', 0x1395b),
+    ('\u{9278}', 0x17d2d),
+    ('\u{927c}', 0x27a67),
+    ('\u{927d}', 0x27a68),
+    ('\u{927e}', 0x14b48),
+    ('\u{927f}', 0x27a69),
+    ('\u{9280}', 0x13664),
+    ('\u{9283}', 0x13d46),
+    ('\u{9285}', 0x1463c),
+    ('\u{9288}', 0x17d2e),
+    ('\u{9289}', 0x27a6a),
+    ('\u{928d}', 0x27a6b),
+    ('\u{9291}', 0x1412d),
+    ('\u{9293}', 0x16e74),
+    ('\u{9295}', 0x16e6e),
+    ('\u{9296}', 0x16e73),
+    ('\u{9297}', 0x27a6c),
+    ('\u{9298}', 0x14c43),
+    ('\u{9299}', 0x27a6d),
+    ('\u{929a}', 0x14438),
+    ('\u{929b}', 0x16e75),
+    ('\u{929c}', 0x16e72),
+    ('\u{929f}', 0x27a6e),
+    ('\u{92a7}', 0x27a6f),
+    ('\u{92ab}', 0x27a70),
+    ('\u{92ad}', 0x1412c),
+    ('\u{92b2}', 0x27a73),
+    ('\u{92b7}', 0x16e79),
+    ('\u{92b9}', 0x16e78),
+    ('\u{92bf}', 0x27a74),
+    ('\u{92c0}', 0x27a75),
+    ('\u{92c2}', 0x17d2f),
+    ('\u{92c6}', 0x27a76),
+    ('\u{92cb}', 0x17d30),
+    ('\u{92cc}', 0x17d31),
+    ('\u{92ce}', 0x27a77),
+    ('\u{92cf}', 0x16e77),
+    ('\u{92d0}', 0x27a78),
+    ('\u{92d2}', 0x14b2f),
+    ('\u{92d3}', 0x17d32),
+    ('\u{92d7}', 0x27a79),
+    ('\u{92d9}', 0x27a7a),
+    ('\u{92e0}', 0x17d33),
+    ('\u{92e4}', 0x13d7b),
+    ('\u{92e5}', 0x27a7b),
+    ('\u{92e7}', 0x27a7c),
+    ('\u{92e9}', 0x16e7a),
+    ('\u{92ea}', 0x14a5f),
+    ('\u{92ed}', 0x13154),
+    ('\u{92f2}', 0x14946),
+    ('\u{92f3}', 0x14372),
+    ('\u{92f7}', 0x27b22),
+    ('\u{92f8}', 0x13578),
+    ('\u{92f9}', 0x27b23),
+    ('\u{92fa}', 0x16e7c),
+    ('\u{92fb}', 0x27b24),
+    ('\u{92fc}', 0x1395d),
+    ('\u{92ff}', 0x17d34),
+    ('\u{9302}', 0x27b25),
+    ('\u{9304}', 0x17d35),
+    ('\u{9306}', 0x13b2c),
+    ('\u{930d}', 0x27b26),
+    ('\u{930f}', 0x16e7b),
+    ('\u{9310}', 0x13f6d),
+    ('\u{9311}', 0x27a7d),
+    ('\u{9315}', 0x27b27),
+    ('\u{9318}', 0x13f6e),
+    ('\u{9319}', 0x16f21),
+    ('\u{931a}', 0x16f23),
+    ('\u{931d}', 0x27b28),
+    ('\u{931e}', 0x27b29),
+    ('\u{931f}', 0x17d36),
+    ('\u{9320}', 0x13e7b),
+    ('\u{9321}', 0x17d37),
+    ('\u{9322}', 0x16f22),
+    ('\u{9323}', 0x16f24),
+    ('\u{9325}', 0x17d38),
+    ('\u{9326}', 0x13653),
+    ('\u{9327}', 0x27b2a),
+    ('\u{9328}', 0x14945),
+    ('\u{9329}', 0x27b2b),
+    ('\u{932b}', 0x13c62),
+    ('\u{932c}', 0x14f23),
+    ('\u{932e}', 0x16e7e),
+    ('\u{932f}', 0x13a78),
+    ('\u{9332}', 0x14f3f),
+    ('\u{9335}', 0x16f26),
+    ('\u{933a}', 0x16f25),
+    ('\u{933b}', 0x16f27),
+    ('\u{9344}', 0x16e7d),
+    ('\u{9347}', 0x27b2e),
+    ('\u{9348}', 0x17d39),
+    ('\u{9349}', 0x17d3a),
+    ('\u{934a}', 0x17d3b),
+    ('\u{934b}', 0x14669),
+    ('\u{934d}', 0x14555),
+    ('\u{9351}', 0x27b2f),
+    ('\u{9354}', 0x14457),
+    ('\u{9356}', 0x16f2c),
+    ('\u{9357}', 0x27b30),
+    ('\u{935a}', 0x27b31),
+    ('\u{935b}', 0x14343),
+    ('\u{935c}', 0x16f28),
+    ('\u{9360}', 0x16f29),
+    ('\u{9364}', 0x17d3c),
+    ('\u{9365}', 0x17d3d),
+    ('\u{936a}', 0x17d3e),
+    ('\u{936b}', 0x27b32),
+    ('\u{936c}', 0x1372d),
+    ('\u{936e}', 0x16f2b),
+    ('\u{9370}', 0x17d3f),
+    ('\u{9371}', 0x27b33),
+    ('\u{9373}', 0x27b34),
+    ('\u{9375}', 0x13830),
+    ('\u{937c}', 0x16f2a),
+    ('\u{937e}', 0x13e61),
+    ('\u{9388}', 0x27b38),
+    ('\u{938b}', 0x27b39),
+    ('\u{938c}', 0x13379),
+    ('\u{938f}', 0x27b3a),
+    ('\u{9394}', 0x16f30),
+    ('\u{9396}', 0x13a3f),
+    ('\u{9397}', 0x14179),
+    ('\u{939a}', 0x1444a),
+    ('\u{939b}', 0x17d40),
+    ('\u{939e}', 0x27b3b),
+    ('\u{93a1}', 0x27b35),
+    ('\u{93a3}', 0x17d41),
+    ('\u{93a7}', 0x1333b),
+    ('\u{93ac}', 0x16f2e),
+    ('\u{93ad}', 0x16f2f),
+    ('\u{93ae}', 0x14443),
+    ('\u{93b0}', 0x16f2d),
+    ('\u{93b9}', 0x16f31),
+    ('\u{93ba}', 0x17d42),
+    ('\u{93c1}', 0x27b40),
+    ('\u{93c3}', 0x16f37),
+    ('\u{93c6}', 0x17d43),
+    ('\u{93c7}', 0x27b41),
+    ('\u{93c8}', 0x16f3a),
+    ('\u{93d0}', 0x16f39),
+    ('\u{93d1}', 0x1452d),
+    ('\u{93d6}', 0x16f32),
+    ('\u{93d7}', 0x16f33),
+    ('\u{93d8}', 0x16f36),
+    ('\u{93dc}', 0x27b42),
+    ('\u{93dd}', 0x16f38),
+    ('\u{93de}', 0x17d44),
+    ('\u{93df}', 0x17d45),
+    ('\u{93e1}', 0x13640),
+    ('\u{93e2}', 0x27b43),
+    ('\u{93e4}', 0x16f3b),
+    ('\u{93e5}', 0x16f35),
+    ('\u{93e7}', 0x27b44),
+    ('\u{93e8}', 0x16f34),
+    ('\u{93f1}', 0x27b3f),
+    ('\u{93f5}', 0x27b3c),
+    ('\u{93fb}', 0x27b49),
+    ('\u{93fd}', 0x17d47),
+    ('\u{9403}', 0x16f3f),
+    ('\u{9404}', 0x17d46),
+    ('\u{9407}', 0x16f40),
+    ('\u{9409}', 0x27b45),
+    ('\u{940f}', 0x27b46),
+    ('\u{9410}', 0x16f41),
+    ('\u{9413}', 0x16f3e),
+    ('\u{9414}', 0x16f3d),
+    ('\u{9416}', 0x27b47),
+    ('\u{9417}', 0x27b48),
+    ('\u{9418}', 0x13e62),
+    ('\u{9419}', 0x1462a),
+    ('\u{941a}', 0x16f3c),
+    ('\u{9421}', 0x16f45),
+    ('\u{942b}', 0x16f43),
+    ('\u{9432}', 0x27b4a),
+    ('\u{9433}', 0x17d48),
+    ('\u{9434}', 0x27b4b),
+    ('\u{9435}', 0x16f44),
+    ('\u{9436}', 0x16f42),
+    ('\u{9438}', 0x14278),
+    ('\u{943a}', 0x16f46),
+    ('\u{943b}', 0x27b4c),
+    ('\u{9441}', 0x16f47),
+    ('\u{9444}', 0x16f49),
+    ('\u{9445}', 0x27b4d),
+    ('\u{944a}', 0x17d49),
+    ('\u{9451}', 0x13455),
+    ('\u{9452}', 0x16f48),
+    ('\u{9453}', 0x14c7a),
+    ('\u{945a}', 0x16f54),
+    ('\u{945b}', 0x16f4a),
+    ('\u{945e}', 0x16f4d),
+    ('\u{9460}', 0x16f4b),
+    ('\u{9462}', 0x16f4c),
+    ('\u{9463}', 0x17d4a),
+    ('\u{946a}', 0x16f4e),
+    ('\u{946b}', 0x17d4b),
+    ('\u{946d}', 0x27b50),
+    ('\u{946f}', 0x27b51),
+    ('\u{9470}', 0x16f50),
+    ('\u{9471}', 0x17d4c),
+    ('\u{9472}', 0x17d4d),
+    ('\u{9475}', 0x16f51),
+    ('\u{9477}', 0x16f52),
+    ('\u{947c}', 0x16f55),
+    ('\u{947d}', 0x16f53),
+    ('\u{947e}', 0x16f56),
+    ('\u{947f}', 0x16f58),
+    ('\u{9481}', 0x16f57),
+    ('\u{9577}', 0x14439),
+    ('\u{9578}', 0x27b52),
+    ('\u{9579}', 0x27b53),
+    ('\u{9580}', 0x14c67),
+    ('\u{9582}', 0x16f59),
+    ('\u{9583}', 0x1412e),
+    ('\u{9586}', 0x27b54),
+    ('\u{9587}', 0x16f5a),
+    ('\u{9589}', 0x14a44),
+    ('\u{958a}', 0x16f5b),
+    ('\u{958b}', 0x1332b),
+    ('\u{958c}', 0x27b55),
+    ('\u{958d}', 0x27b56),
+    ('\u{958e}', 0x17d4e),
+    ('\u{958f}', 0x1313c),
+    ('\u{9591}', 0x13457),
+    ('\u{9593}', 0x13456),
+    ('\u{9594}', 0x16f5c),
+    ('\u{9596}', 0x16f5d),
+    ('\u{9598}', 0x16f5e),
+    ('\u{9599}', 0x16f5f),
+    ('\u{959f}', 0x17d4f),
+    ('\u{95a0}', 0x16f60),
+    ('\u{95a2}', 0x13458),
+    ('\u{95a3}', 0x13355),
+    ('\u{95a4}', 0x1395e),
+    ('\u{95a5}', 0x14836),
+    ('\u{95a6}', 0x17d50),
+    ('\u{95a7}', 0x16f62),
+    ('\u{95a8}', 0x16f61),
+    ('\u{95a9}', 0x17d51),
+    ('\u{95ab}', 0x27b58),
+    ('\u{95ac}', 0x17d52),
+    ('\u{95ad}', 0x16f63),
+    ('\u{95b2}', 0x1315c),
+    ('\u{95b4}', 0x27b59),
+    ('\u{95b6}', 0x17d53),
+    ('\u{95b9}', 0x16f66),
+    ('\u{95bb}', 0x16f65),
+    ('\u{95bc}', 0x16f64),
+    ('\u{95bd}', 0x17d54),
+    ('\u{95be}', 0x16f67),
+    ('\u{95c3}', 0x16f6a),
+    ('\u{95c7}', 0x13047),
+    ('\u{95c8}', 0x27b5b),
+    ('\u{95ca}', 0x16f68),
+    ('\u{95cb}', 0x17d55),
+    ('\u{95cc}', 0x16f6c),
+    ('\u{95cd}', 0x16f6b),
+    ('\u{95d0}', 0x17d56),
+    ('\u{95d3}', 0x17d57),
+    ('\u{95d4}', 0x16f6e),
+    ('\u{95d5}', 0x16f6d),
+    ('\u{95d6}', 0x16f6f),
+    ('\u{95d8}', 0x1462e),
+    ('\u{95da}', 0x17d59),
+    ('\u{95dc}', 0x16f70),
+    ('\u{95de}', 0x17d5a),
+    ('\u{95e1}', 0x16f71),
+    ('\u{95e2}', 0x16f73),
+    ('\u{95e5}', 0x16f72),
+    ('\u{961c}', 0x1496c),
+    ('\u{961d}', 0x27a25),
+    ('\u{9621}', 0x16f74),
+    ('\u{9628}', 0x16f75),
+    ('\u{962a}', 0x13a65),
+    ('\u{962c}', 0x27b5e),
+    ('\u{962e}', 0x16f76),
+    ('\u{962f}', 0x16f77),
+    ('\u{9632}', 0x14b49),
+    ('\u{9633}', 0x27b5f),
+    ('\u{9634}', 0x27b60),
+    ('\u{963b}', 0x1414b),
+    ('\u{963c}', 0x27b62),
+    ('\u{963f}', 0x13024),
+    ('\u{9640}', 0x1424b),
+    ('\u{9641}', 0x27b63),
+    ('\u{9642}', 0x16f78),
+    ('\u{9644}', 0x1496d),
+    ('\u{964b}', 0x16f7b),
+    ('\u{964c}', 0x16f79),
+    ('\u{964d}', 0x1395f),
+    ('\u{964f}', 0x16f7a),
+    ('\u{9650}', 0x13842),
+    ('\u{9658}', 0x17d5b),
+    ('\u{965b}', 0x14a45),
+    ('\u{965c}', 0x16f7d),
+    ('\u{965d}', 0x17021),
+    ('\u{965e}', 0x16f7e),
+    ('\u{965f}', 0x17022),
+    ('\u{9661}', 0x27b64),
+    ('\u{9662}', 0x13121),
+    ('\u{9663}', 0x13f58),
+    ('\u{9664}', 0x13d7c),
+    ('\u{9665}', 0x13459),
+    ('\u{9666}', 0x17023),
+    ('\u{966a}', 0x14766),
+    ('\u{966c}', 0x17025),
+    ('\u{9670}', 0x13122),
+    ('\u{9672}', 0x17024),
+    ('\u{9673}', 0x14444),
+    ('\u{9675}', 0x14e4d),
+    ('\u{9676}', 0x1462b),
+    ('\u{9677}', 0x16f7c),
+    ('\u{9678}', 0x14e26),
+    ('\u{967a}', 0x13831),
+    ('\u{967d}', 0x14d5b),
+    ('\u{9682}', 0x27b66),
+    ('\u{9684}', 0x17d5c),
+    ('\u{9685}', 0x13679),
+    ('\u{9686}', 0x14e34),
+    ('\u{9688}', 0x13728),
+    ('\u{968a}', 0x14262),
+    ('\u{968b}', 0x16721),
+    ('\u{968d}', 0x17026),
+    ('\u{968e}', 0x1332c),
+    ('\u{968f}', 0x13f6f),
+    ('\u{9694}', 0x13356),
+    ('\u{9695}', 0x17028),
+    ('\u{9697}', 0x17029),
+    ('\u{9698}', 0x17027),
+    ('\u{9699}', 0x13764),
+    ('\u{969a}', 0x27b68),
+    ('\u{969b}', 0x13a5d),
+    ('\u{969c}', 0x13e63),
+    ('\u{969d}', 0x17d5e),
+    ('\u{96a0}', 0x13123),
+    ('\u{96a3}', 0x14e59),
+    ('\u{96a4}', 0x17d5f),
+    ('\u{96a5}', 0x17d60),
+    ('\u{96a7}', 0x1702b),
+    ('\u{96a8}', 0x16e2e),
+    ('\u{96a9}', 0x27b6b),
+    ('\u{96aa}', 0x1702a),
+    ('\u{96af}', 0x27b6c),
+    ('\u{96b0}', 0x1702e),
+    ('\u{96b1}', 0x1702c),
+    ('\u{96b2}', 0x1702d),
+    ('\u{96b3}', 0x27b6d),
+    ('\u{96b4}', 0x1702f),
+    ('\u{96b6}', 0x17030),
+    ('\u{96b7}', 0x14e6c),
+    ('\u{96b8}', 0x17031),
+    ('\u{96b9}', 0x17032),
+    ('\u{96ba}', 0x27b6e),
+    ('\u{96bb}', 0x14049),
+    ('\u{96bc}', 0x1483b),
+    ('\u{96bd}', 0x27b6f),
+    ('\u{96c0}', 0x13f7d),
+    ('\u{96c1}', 0x13467),
+    ('\u{96c4}', 0x14d3a),
+    ('\u{96c5}', 0x1326d),
+    ('\u{96c6}', 0x13d38),
+    ('\u{96c7}', 0x1385b),
+    ('\u{96c9}', 0x17035),
+    ('\u{96cb}', 0x17034),
+    ('\u{96cc}', 0x13b73),
+    ('\u{96cd}', 0x17036),
+    ('\u{96ce}', 0x17033),
+    ('\u{96d1}', 0x13b28),
+    ('\u{96d2}', 0x17d61),
+    ('\u{96d5}', 0x1703a),
+    ('\u{96d6}', 0x16a2d),
+    ('\u{96d8}', 0x27b72),
+    ('\u{96d9}', 0x15256),
+    ('\u{96da}', 0x27b73),
+    ('\u{96db}', 0x13f77),
+    ('\u{96dc}', 0x17038),
+    ('\u{96dd}', 0x27b74),
+    ('\u{96de}', 0x17d62),
+    ('\u{96e2}', 0x14e25),
+    ('\u{96e3}', 0x14671),
+    ('\u{96e8}', 0x1312b),
+    ('\u{96e9}', 0x17d64),
+    ('\u{96ea}', 0x14063),
+    ('\u{96eb}', 0x13c36),
+    ('\u{96ef}', 0x17d65),
+    ('\u{96f0}', 0x14a37),
+    ('\u{96f2}', 0x13140),
+    ('\u{96f6}', 0x14e6d),
+    ('\u{96f7}', 0x14d6b),
+    ('\u{96f9}', 0x1703b),
+    ('\u{96fb}', 0x14545),
+    ('\u{9700}', 0x13c7b),
+    ('\u{9704}', 0x1703c),
+    ('\u{9706}', 0x1703d),
+    ('\u{9707}', 0x13f4c),
+    ('\u{9708}', 0x1703e),
+    ('\u{970a}', 0x14e6e),
+    ('\u{970d}', 0x17039),
+    ('\u{970e}', 0x17040),
+    ('\u{970f}', 0x17042),
+    ('\u{9711}', 0x17041),
+    ('\u{9713}', 0x1703f),
+    ('\u{9714}', 0x27b76),
+    ('\u{9716}', 0x17043),
+    ('\u{9719}', 0x17044),
+    ('\u{971c}', 0x1417a),
+    ('\u{971e}', 0x13262),
+    ('\u{9723}', 0x27b77),
+    ('\u{9724}', 0x17045),
+    ('\u{9727}', 0x14c38),
+    ('\u{972a}', 0x17046),
+    ('\u{9730}', 0x17047),
+    ('\u{9732}', 0x14f2a),
+    ('\u{9733}', 0x17d66),
+    ('\u{9736}', 0x27b79),
+    ('\u{9738}', 0x15b31),
+    ('\u{9739}', 0x17048),
+    ('\u{973b}', 0x17d67),
+    ('\u{973d}', 0x17049),
+    ('\u{973e}', 0x1704a),
+    ('\u{9741}', 0x27b7a),
+    ('\u{9742}', 0x1704e),
+    ('\u{9744}', 0x1704b),
+    ('\u{9746}', 0x1704c),
+    ('\u{9747}', 0x27b7b),
+    ('\u{9748}', 0x1704d),
+    ('\u{9749}', 0x1704f),
+    ('\u{974d}', 0x17d68),
+    ('\u{974e}', 0x17d69),
+    ('\u{974f}', 0x17d6a),
+    ('\u{9752}', 0x14044),
+    ('\u{9755}', 0x27b7c),
+    ('\u{9756}', 0x14c77),
+    ('\u{9757}', 0x27b7d),
+    ('\u{9759}', 0x14045),
+    ('\u{975a}', 0x17d6b),
+    ('\u{975b}', 0x27b7e),
+    ('\u{975c}', 0x17050),
+    ('\u{975e}', 0x14873),
+    ('\u{9760}', 0x17051),
+    ('\u{9761}', 0x17353),
+    ('\u{9762}', 0x14c4c),
+    ('\u{9764}', 0x17052),
+    ('\u{9766}', 0x17053),
+    ('\u{9768}', 0x17054),
+    ('\u{9769}', 0x13357),
+    ('\u{976a}', 0x27c21),
+    ('\u{976b}', 0x17056),
+    ('\u{976d}', 0x13f59),
+    ('\u{976e}', 0x17d6c),
+    ('\u{9771}', 0x17057),
+    ('\u{9773}', 0x17d6d),
+    ('\u{9774}', 0x13724),
+    ('\u{9779}', 0x17058),
+    ('\u{977a}', 0x1705c),
+    ('\u{977c}', 0x1705a),
+    ('\u{9781}', 0x1705b),
+    ('\u{9784}', 0x13373),
+    ('\u{9785}', 0x17059),
+    ('\u{9786}', 0x1705d),
+    ('\u{978b}', 0x1705e),
+    ('\u{978d}', 0x13048),
+    ('\u{978f}', 0x1705f),
+    ('\u{9790}', 0x17060),
+    ('\u{9795}', 0x17d6e),
+    ('\u{9796}', 0x27c24),
+    ('\u{9798}', 0x13e64),
+    ('\u{979a}', 0x27c25),
+    ('\u{979c}', 0x17061),
+    ('\u{979e}', 0x27c26),
+    ('\u{97a0}', 0x13547),
+    ('\u{97a2}', 0x27c27),
+    ('\u{97a3}', 0x17064),
+    ('\u{97a6}', 0x17063),
+    ('\u{97a8}', 0x17062),
+    ('\u{97ab}', 0x16b71),
+    ('\u{97ad}', 0x14a5c),
+    ('\u{97ae}', 0x17d6f),
+    ('\u{97b1}', 0x27c28),
+    ('\u{97b2}', 0x27c29),
+    ('\u{97b3}', 0x17065),
+    ('\u{97b4}', 0x17066),
+    ('\u{97ba}', 0x17d70),
+    ('\u{97be}', 0x27c2a),
+    ('\u{97c1}', 0x17d71),
+    ('\u{97c3}', 0x17067),
+    ('\u{97c6}', 0x17068),
+    ('\u{97c8}', 0x17069),
+    ('\u{97c9}', 0x17d72),
+    ('\u{97cb}', 0x1706a),
+    ('\u{97cc}', 0x27c2b),
+    ('\u{97d1}', 0x27c2c),
+    ('\u{97d3}', 0x1345a),
+    ('\u{97d4}', 0x27c2d),
+    ('\u{97d8}', 0x27c2e),
+    ('\u{97d9}', 0x27c2f),
+    ('\u{97db}', 0x17d74),
+    ('\u{97dc}', 0x1706b),
+    ('\u{97de}', 0x17d73),
+    ('\u{97e1}', 0x27c30),
+    ('\u{97ed}', 0x1706c),
+    ('\u{97ee}', 0x14723),
+    ('\u{97f1}', 0x27c31),
+    ('\u{97f2}', 0x1706e),
+    ('\u{97f3}', 0x1323b),
+    ('\u{97f4}', 0x17d75),
+    ('\u{97f5}', 0x17071),
+    ('\u{97f6}', 0x17070),
+    ('\u{97fb}', 0x13124),
+    ('\u{97ff}', 0x13641),
+    ('\u{9801}', 0x14a47),
+    ('\u{9802}', 0x1443a),
+    ('\u{9803}', 0x13a22),
+    ('\u{9804}', 0x27c32),
+    ('\u{9805}', 0x13960),
+    ('\u{9806}', 0x13d67),
+    ('\u{9808}', 0x13f5c),
+    ('\u{980a}', 0x17d77),
+    ('\u{980c}', 0x17073),
+    ('\u{980d}', 0x27c33),
+    ('\u{980e}', 0x27c34),
+    ('\u{980f}', 0x17072),
+    ('\u{9810}', 0x14d42),
+    ('\u{9811}', 0x13468),
+    ('\u{9812}', 0x14852),
+    ('\u{9813}', 0x1465c),
+    ('\u{9814}', 0x27c35),
+    ('\u{9816}', 0x27c36),
+    ('\u{9817}', 0x13f7c),
+    ('\u{9818}', 0x14e4e),
+    ('\u{981a}', 0x1375b),
+    ('\u{981e}', 0x17d78),
+    ('\u{9821}', 0x17076),
+    ('\u{9823}', 0x27c39),
+    ('\u{9824}', 0x17075),
+    ('\u{9825}', 0x27c3c),
+    ('\u{982b}', 0x17d79),
+    ('\u{982c}', 0x14b4b),
+    ('\u{982d}', 0x1462c),
+    ('\u{9830}', 0x17d7a),
+    ('\u{9832}', 0x27c3a),
+    ('\u{9833}', 0x27c3b),
+    ('\u{9834}', 0x13150),
+    ('\u{9837}', 0x17077),
+    ('\u{9838}', 0x17074),
+    ('\u{983b}', 0x14951),
+    ('\u{983c}', 0x14d6a),
+    ('\u{983d}', 0x17078),
+    ('\u{9846}', 0x17079),
+    ('\u{9847}', 0x27c3d),
+    ('\u{984b}', 0x1707b),
+    ('\u{984c}', 0x1426a),
+    ('\u{984d}', 0x1335b),
+    ('\u{984e}', 0x1335c),
+    ('\u{984f}', 0x1707a),
+    ('\u{9852}', 0x17d7c),
+    ('\u{9853}', 0x17d7d),
+    ('\u{9854}', 0x13469),
+    ('\u{9855}', 0x13832),
+    ('\u{9856}', 0x17d7e),
+    ('\u{9857}', 0x17e21),
+    ('\u{9858}', 0x1346a),
+    ('\u{9859}', 0x17e22),
+    ('\u{985a}', 0x17e23),
+    ('\u{985b}', 0x1453f),
+    ('\u{985e}', 0x14e60),
+    ('\u{9865}', 0x17e25),
+    ('\u{9866}', 0x27c3e),
+    ('\u{9867}', 0x1385c),
+    ('\u{986b}', 0x1707c),
+    ('\u{986c}', 0x17e26),
+    ('\u{986f}', 0x1707d),
+    ('\u{9870}', 0x1707e),
+    ('\u{9871}', 0x17121),
+    ('\u{9873}', 0x17123),
+    ('\u{9874}', 0x17122),
+    ('\u{98a8}', 0x14977),
+    ('\u{98aa}', 0x17124),
+    ('\u{98ab}', 0x27c3f),
+    ('\u{98ad}', 0x27c40),
+    ('\u{98af}', 0x17125),
+    ('\u{98b0}', 0x27c41),
+    ('\u{98b1}', 0x17126),
+    ('\u{98b6}', 0x17127),
+    ('\u{98b7}', 0x27c43),
+    ('\u{98b8}', 0x27c44),
+    ('\u{98ba}', 0x17e27),
+    ('\u{98bb}', 0x27c45),
+    ('\u{98bc}', 0x27c46),
+    ('\u{98bf}', 0x27c47),
+    ('\u{98c2}', 0x27c48),
+    ('\u{98c3}', 0x17129),
+    ('\u{98c4}', 0x17128),
+    ('\u{98c6}', 0x1712a),
+    ('\u{98c7}', 0x27c49),
+    ('\u{98c8}', 0x17e28),
+    ('\u{98cb}', 0x27c4a),
+    ('\u{98db}', 0x14874),
+    ('\u{98dc}', 0x1664c),
+    ('\u{98df}', 0x13f29),
+    ('\u{98e0}', 0x27c4b),
+    ('\u{98e1}', 0x27c4d),
+    ('\u{98e2}', 0x13532),
+    ('\u{98e3}', 0x27c4e),
+    ('\u{98e5}', 0x27c4f),
+    ('\u{98e7}', 0x17e29),
+    ('\u{98e9}', 0x1712b),
+    ('\u{98ea}', 0x27c50),
+    ('\u{98eb}', 0x1712c),
+    ('\u{98ed}', 0x1522c),
+    ('\u{98ee}', 0x15d3b),
+    ('\u{98ef}', 0x14853),
+    ('\u{98f0}', 0x27c51),
+    ('\u{98f1}', 0x27c52),
+    ('\u{98f2}', 0x1307b),
+    ('\u{98f3}', 0x27c53),
+    ('\u{98f4}', 0x1303b),
+    ('\u{98fc}', 0x13b74),
+    ('\u{98fd}', 0x14b30),
+    ('\u{98fe}', 0x13e7e),
+    ('\u{9903}', 0x1712d),
+    ('\u{9905}', 0x14c5f),
+    ('\u{9908}', 0x27c54),
+    ('\u{9909}', 0x1712e),
+    ('\u{990a}', 0x14d5c),
+    ('\u{990c}', 0x13142),
+    ('\u{9910}', 0x13b41),
+    ('\u{9912}', 0x1712f),
+    ('\u{9913}', 0x1326e),
+    ('\u{9914}', 0x17130),
+    ('\u{9916}', 0x27c57),
+    ('\u{9917}', 0x27c58),
+    ('\u{9918}', 0x17131),
+    ('\u{991a}', 0x27c5a),
+    ('\u{991b}', 0x27c5b),
+    ('\u{991c}', 0x27c5c),
+    ('\u{991d}', 0x17133),
+    ('\u{991e}', 0x17134),
+    ('\u{9920}', 0x17136),
+    ('\u{9921}', 0x17132),
+    ('\u{9924}', 0x17135),
+    ('\u{9928}', 0x1345b),
+    ('\u{992c}', 0x17137),
+    ('\u{992e}', 0x17138),
+    ('\u{9931}', 0x27c5e),
+    ('\u{9932}', 0x27c5f),
+    ('\u{9933}', 0x27c60),
+    ('\u{993a}', 0x27c61),
+    ('\u{993b}', 0x27c62),
+    ('\u{993c}', 0x27c63),
+    ('\u{993d}', 0x17139),
+    ('\u{993e}', 0x1713a),
+    ('\u{9940}', 0x27c64),
+    ('\u{9941}', 0x27c65),
+    ('\u{9942}', 0x1713b),
+    ('\u{9945}', 0x1713d),
+    ('\u{9946}', 0x27c66),
+    ('\u{9949}', 0x1713c),
+    ('\u{994b}', 0x1713f),
+    ('\u{994c}', 0x17142),
+    ('\u{994d}', 0x27c67),
+    ('\u{994e}', 0x27c68),
+    ('\u{9950}', 0x1713e),
+    ('\u{9951}', 0x17140),
+    ('\u{9952}', 0x17141),
+    ('\u{9955}', 0x17143),
+    ('\u{9957}', 0x13642),
+    ('\u{9958}', 0x17e2a),
+    ('\u{995c}', 0x27c69),
+    ('\u{995f}', 0x27c6a),
+    ('\u{9960}', 0x27c6b),
+    ('\u{9996}', 0x13c73),
+    ('\u{9997}', 0x17144),
+    ('\u{9998}', 0x17145),
+    ('\u{9999}', 0x13961),
+    ('\u{999e}', 0x17e2b),
+    ('\u{99a3}', 0x27c6c),
+    ('\u{99a5}', 0x17146),
+    ('\u{99a6}', 0x27c6d),
+    ('\u{99a8}', 0x1333e),
+    ('\u{99ac}', 0x1474f),
+    ('\u{99ad}', 0x17147),
+    ('\u{99ae}', 0x17148),
+    ('\u{99b3}', 0x1435a),
+    ('\u{99b4}', 0x1466b),
+    ('\u{99b9}', 0x27c6e),
+    ('\u{99bc}', 0x17149),
+    ('\u{99bd}', 0x27c6f),
+    ('\u{99bf}', 0x27c70),
+    ('\u{99c1}', 0x1477d),
+    ('\u{99c3}', 0x27c71),
+    ('\u{99c4}', 0x1424c),
+    ('\u{99c5}', 0x13158),
+    ('\u{99c6}', 0x1366e),
+    ('\u{99c8}', 0x1366f),
+    ('\u{99c9}', 0x27c72),
+    ('\u{99d0}', 0x14373),
+    ('\u{99d1}', 0x1714e),
+    ('\u{99d2}', 0x13670),
+    ('\u{99d4}', 0x27c73),
+    ('\u{99d5}', 0x1326f),
+    ('\u{99d8}', 0x1714d),
+    ('\u{99d9}', 0x27c74),
+    ('\u{99db}', 0x1714b),
+    ('\u{99dd}', 0x1714c),
+    ('\u{99de}', 0x27c75),
+    ('\u{99df}', 0x1714a),
+    ('\u{99e2}', 0x17158),
+    ('\u{99ed}', 0x1714f),
+    ('\u{99ee}', 0x17150),
+    ('\u{99f0}', 0x27c77),
+    ('\u{99f1}', 0x17151),
+    ('\u{99f2}', 0x17152),
+    ('\u{99f8}', 0x17154),
+    ('\u{99f9}', 0x27c78),
+    ('\u{99fb}', 0x17153),
+    ('\u{99fc}', 0x27c79),
+    ('\u{99ff}', 0x13d59),
+    ('\u{9a01}', 0x17155),
+    ('\u{9a02}', 0x17e2c),
+    ('\u{9a03}', 0x17e2d),
+    ('\u{9a05}', 0x17157),
+    ('\u{9a0a}', 0x27c7a),
+    ('\u{9a0e}', 0x13533),
+    ('\u{9a0f}', 0x17156),
+    ('\u{9a11}', 0x27c7b),
+    ('\u{9a12}', 0x1417b),
+    ('\u{9a13}', 0x13833),
+    ('\u{9a16}', 0x27c7c),
+    ('\u{9a19}', 0x17159),
+    ('\u{9a1a}', 0x27c7d),
+    ('\u{9a20}', 0x27c7e),
+    ('\u{9a24}', 0x17e2e),
+    ('\u{9a28}', 0x1424d),
+    ('\u{9a2b}', 0x1715a),
+    ('\u{9a2d}', 0x17e2f),
+    ('\u{9a2e}', 0x17e30),
+    ('\u{9a30}', 0x1462d),
+    ('\u{9a31}', 0x27d21),
+    ('\u{9a36}', 0x27d22),
+    ('\u{9a37}', 0x1715b),
+    ('\u{9a38}', 0x17e31),
+    ('\u{9a3e}', 0x17160),
+    ('\u{9a40}', 0x1715e),
+    ('\u{9a42}', 0x1715d),
+    ('\u{9a43}', 0x1715f),
+    ('\u{9a44}', 0x27d23),
+    ('\u{9a45}', 0x1715c),
+    ('\u{9a4a}', 0x17e32),
+    ('\u{9a4c}', 0x27d24),
+    ('\u{9a4d}', 0x17162),
+    ('\u{9a4e}', 0x17e33),
+    ('\u{9a52}', 0x17e34),
+    ('\u{9a55}', 0x17161),
+    ('\u{9a57}', 0x17164),
+    ('\u{9a58}', 0x27d25),
+    ('\u{9a5a}', 0x13643),
+    ('\u{9a5b}', 0x17163),
+    ('\u{9a5f}', 0x17165),
+    ('\u{9a62}', 0x17166),
+    ('\u{9a64}', 0x17168),
+    ('\u{9a65}', 0x17167),
+    ('\u{9a69}', 0x17169),
+    ('\u{9a6a}', 0x1716b),
+    ('\u{9a6b}', 0x1716a),
+    ('\u{9aa8}', 0x1397c),
+    ('\u{9aad}', 0x1716c),
+    ('\u{9aaf}', 0x27d27),
+    ('\u{9ab0}', 0x1716d),
+    ('\u{9ab6}', 0x17e35),
+    ('\u{9ab7}', 0x27d29),
+    ('\u{9ab8}', 0x1333c),
+    ('\u{9ab9}', 0x27d2b),
+    ('\u{9abc}', 0x1716e),
+    ('\u{9ac0}', 0x1716f),
+    ('\u{9ac1}', 0x17e36),
+    ('\u{9ac3}', 0x17e37),
+    ('\u{9ac4}', 0x13f71),
+    ('\u{9ac6}', 0x27d2d),
+    ('\u{9ace}', 0x17e38),
+    ('\u{9acf}', 0x17170),
+    ('\u{9ad0}', 0x27d2e),
+    ('\u{9ad1}', 0x17171),
+    ('\u{9ad2}', 0x27d2f),
+    ('\u{9ad3}', 0x17172),
+    ('\u{9ad4}', 0x17173),
+    ('\u{9ad5}', 0x27d30),
+    ('\u{9ad6}', 0x17e39),
+    ('\u{9ad8}', 0x13962),
+    ('\u{9adc}', 0x27d32),
+    ('\u{9ade}', 0x17174),
+    ('\u{9adf}', 0x17175),
+    ('\u{9ae0}', 0x27d33),
+    ('\u{9ae2}', 0x17176),
+    ('\u{9ae3}', 0x17177),
+    ('\u{9ae5}', 0x27d34),
+    ('\u{9ae6}', 0x17178),
+    ('\u{9ae9}', 0x27d35),
+    ('\u{9aea}', 0x14831),
+    ('\u{9aeb}', 0x1717a),
+    ('\u{9aed}', 0x14926),
+    ('\u{9aee}', 0x1717b),
+    ('\u{9aef}', 0x17179),
+    ('\u{9af1}', 0x1717d),
+    ('\u{9af4}', 0x1717c),
+    ('\u{9af7}', 0x1717e),
+    ('\u{9af9}', 0x17e3a),
+    ('\u{9afb}', 0x17221),
+    ('\u{9b02}', 0x17e3b),
+    ('\u{9b03}', 0x27d36),
+    ('\u{9b06}', 0x17222),
+    ('\u{9b08}', 0x17e3c),
+    ('\u{9b0c}', 0x27d37),
+    ('\u{9b10}', 0x27d38),
+    ('\u{9b12}', 0x27d39),
+    ('\u{9b16}', 0x27d3a),
+    ('\u{9b18}', 0x17223),
+    ('\u{9b1a}', 0x17224),
+    ('\u{9b1c}', 0x27d3b),
+    ('\u{9b1f}', 0x17225),
+    ('\u{9b20}', 0x17e3d),
+    ('\u{9b22}', 0x17226),
+    ('\u{9b23}', 0x17227),
+    ('\u{9b25}', 0x17228),
+    ('\u{9b27}', 0x17229),
+    ('\u{9b28}', 0x1722a),
+    ('\u{9b29}', 0x1722b),
+    ('\u{9b2a}', 0x1722c),
+    ('\u{9b2b}', 0x27d3c),
+    ('\u{9b2d}', 0x17e3f),
+    ('\u{9b2e}', 0x1722d),
+    ('\u{9b2f}', 0x1722e),
+    ('\u{9b31}', 0x15d35),
+    ('\u{9b32}', 0x1722f),
+    ('\u{9b33}', 0x27d3d),
+    ('\u{9b3b}', 0x16478),
+    ('\u{9b3c}', 0x13534),
+    ('\u{9b3d}', 0x27d3e),
+    ('\u{9b41}', 0x13321),
+    ('\u{9b42}', 0x13a32),
+    ('\u{9b43}', 0x17231),
+    ('\u{9b44}', 0x17230),
+    ('\u{9b45}', 0x14c25),
+    ('\u{9b4b}', 0x27d40),
+    ('\u{9b4d}', 0x17233),
+    ('\u{9b4e}', 0x17234),
+    ('\u{9b4f}', 0x17232),
+    ('\u{9b51}', 0x17235),
+    ('\u{9b54}', 0x14b62),
+    ('\u{9b58}', 0x17236),
+    ('\u{9b5a}', 0x1357b),
+    ('\u{9b5e}', 0x17e40),
+    ('\u{9b63}', 0x27d41),
+    ('\u{9b65}', 0x27d42),
+    ('\u{9b66}', 0x17e42),
+    ('\u{9b6b}', 0x27d43),
+    ('\u{9b6c}', 0x27d44),
+    ('\u{9b6f}', 0x14f25),
+    ('\u{9b72}', 0x17e43),
+    ('\u{9b73}', 0x27d45),
+    ('\u{9b74}', 0x17237),
+    ('\u{9b75}', 0x17e44),
+    ('\u{9b76}', 0x27d46),
+    ('\u{9b77}', 0x27d47),
+    ('\u{9b79}', 0x17e41),
+    ('\u{9b83}', 0x17239),
+    ('\u{9b84}', 0x17e45),
+    ('\u{9b8a}', 0x17e46),
+    ('\u{9b8e}', 0x1303e),
+    ('\u{9b8f}', 0x17e47),
+    ('\u{9b91}', 0x1723a),
+    ('\u{9b92}', 0x14a2b),
+    ('\u{9b93}', 0x17238),
+    ('\u{9b96}', 0x1723b),
+    ('\u{9b97}', 0x1723c),
+    ('\u{9b9e}', 0x17e48),
+    ('\u{9b9f}', 0x1723d),
+    ('\u{9ba0}', 0x1723e),
+    ('\u{9ba6}', 0x27d48),
+    ('\u{9ba7}', 0x17e49),
+    ('\u{9ba8}', 0x1723f),
+    ('\u{9baa}', 0x14b6e),
+    ('\u{9bab}', 0x13b2d),
+    ('\u{9bac}', 0x27d49),
+    ('\u{9bad}', 0x13a7a),
+    ('\u{9bae}', 0x1412f),
+    ('\u{9bb1}', 0x27d4a),
+    ('\u{9bb2}', 0x27d4d),
+    ('\u{9bb4}', 0x17240),
+    ('\u{9bb8}', 0x27d4e),
+    ('\u{9bb9}', 0x17243),
+    ('\u{9bbe}', 0x27d4f),
+    ('\u{9bc0}', 0x17241),
+    ('\u{9bc1}', 0x17e4a),
+    ('\u{9bc6}', 0x17244),
+    ('\u{9bc7}', 0x27d50),
+    ('\u{9bc9}', 0x13871),
+    ('\u{9bca}', 0x17242),
+    ('\u{9bce}', 0x17e4b),
+    ('\u{9bcf}', 0x17245),
+    ('\u{9bd1}', 0x17246),
+    ('\u{9bd2}', 0x17247),
+    ('\u{9bd4}', 0x1724b),
+    ('\u{9bd6}', 0x13b2a),
+    ('\u{9bd8}', 0x27d52),
+    ('\u{9bdb}', 0x14264),
+    ('\u{9bdd}', 0x27d53),
+    ('\u{9be1}', 0x1724c),
+    ('\u{9be2}', 0x17249),
+    ('\u{9be3}', 0x17248),
+    ('\u{9be4}', 0x1724a),
+    ('\u{9be5}', 0x17e4c),
+    ('\u{9be7}', 0x27d54),
+    ('\u{9be8}', 0x1375f),
+    ('\u{9bea}', 0x27d55),
+    ('\u{9beb}', 0x27d56),
+    ('\u{9bee}', 0x27d58),
+    ('\u{9bef}', 0x27d57),
+    ('\u{9bf0}', 0x17250),
+    ('\u{9bf1}', 0x1724f),
+    ('\u{9bf2}', 0x1724e),
+    ('\u{9bf3}', 0x27d51),
+    ('\u{9bf5}', 0x13033),
+    ('\u{9bf7}', 0x27d5c),
+    ('\u{9bf8}', 0x17e4d),
+    ('\u{9bfa}', 0x27d5a),
+    ('\u{9bfd}', 0x17e4e),
+    ('\u{9c00}', 0x17e4f),
+    ('\u{9c04}', 0x1725a),
+    ('\u{9c06}', 0x17256),
+    ('\u{9c08}', 0x17257),
+    ('\u{9c09}', 0x17253),
+    ('\u{9c0a}', 0x17259),
+    ('\u{9c0c}', 0x17255),
+    ('\u{9c0d}', 0x13362),
+    ('\u{9c10}', 0x14f4c),
+    ('\u{9c12}', 0x17258),
+    ('\u{9c13}', 0x17254),
+    ('\u{9c14}', 0x17252),
+    ('\u{9c15}', 0x17251),
+    ('\u{9c16}', 0x27d5e),
+    ('\u{9c18}', 0x27d5f),
+    ('\u{9c19}', 0x27d60),
+    ('\u{9c1a}', 0x27d61),
+    ('\u{9c1b}', 0x1725c),
+    ('\u{9c1d}', 0x27d62),
+    ('\u{9c21}', 0x1725f),
+    ('\u{9c22}', 0x27d63),
+    ('\u{9c23}', 0x17e50),
+    ('\u{9c24}', 0x1725e),
+    ('\u{9c25}', 0x1725d),
+    ('\u{9c27}', 0x27d64),
+    ('\u{9c29}', 0x27d65),
+    ('\u{9c2a}', 0x27d66),
+    ('\u{9c2d}', 0x14949),
+    ('\u{9c2e}', 0x1725b),
+    ('\u{9c2f}', 0x13073),
+    ('\u{9c30}', 0x17260),
+    ('\u{9c31}', 0x27d68),
+    ('\u{9c32}', 0x17262),
+    ('\u{9c36}', 0x27d69),
+    ('\u{9c37}', 0x27d6a),
+    ('\u{9c39}', 0x1336f),
+    ('\u{9c3a}', 0x1724d),
+    ('\u{9c3b}', 0x13137),
+    ('\u{9c3e}', 0x17264),
+    ('\u{9c41}', 0x17e51),
+    ('\u{9c45}', 0x27d6b),
+    ('\u{9c46}', 0x17263),
+    ('\u{9c47}', 0x17261),
+    ('\u{9c48}', 0x1432d),
+    ('\u{9c49}', 0x27d6e),
+    ('\u{9c4a}', 0x27d6f),
+    ('\u{9c4f}', 0x17e52),
+    ('\u{9c50}', 0x17e53),
+    ('\u{9c52}', 0x14b70),
+    ('\u{9c53}', 0x17e54),
+    ('\u{9c54}', 0x27d71),
+    ('\u{9c57}', 0x14e5a),
+    ('\u{9c58}', 0x27d72),
+    ('\u{9c5a}', 0x17265),
+    ('\u{9c5b}', 0x27d73),
+    ('\u{9c5c}', 0x27d6c),
+    ('\u{9c5d}', 0x27d74),
+    ('\u{9c5f}', 0x27d75),
+    ('\u{9c60}', 0x17266),
+    ('\u{9c63}', 0x17e55),
+    ('\u{9c65}', 0x17e56),
+    ('\u{9c67}', 0x17267),
+    ('\u{9c69}', 0x27d76),
+    ('\u{9c6a}', 0x27d77),
+    ('\u{9c6b}', 0x27d78),
+    ('\u{9c6d}', 0x27d79),
+    ('\u{9c6e}', 0x27d7a),
+    ('\u{9c70}', 0x27d7b),
+    ('\u{9c72}', 0x27d7c),
+    ('\u{9c75}', 0x27d7d),
+    ('\u{9c76}', 0x17268),
+    ('\u{9c77}', 0x17e57),
+    ('\u{9c78}', 0x17269),
+    ('\u{9c7a}', 0x27d7e),
+    ('\u{9ce5}', 0x1443b),
+    ('\u{9ce6}', 0x27e21),
+    ('\u{9ce7}', 0x1726a),
+    ('\u{9ce9}', 0x14837),
+    ('\u{9ceb}', 0x1726f),
+    ('\u{9cec}', 0x1726b),
+    ('\u{9cf0}', 0x1726c),
+    ('\u{9cf2}', 0x27e22),
+    ('\u{9cf3}', 0x14b31),
+    ('\u{9cf4}', 0x14c44),
+    ('\u{9cf6}', 0x14650),
+    ('\u{9d02}', 0x27e24),
+    ('\u{9d03}', 0x17270),
+    ('\u{9d06}', 0x17271),
+    ('\u{9d07}', 0x1463e),
+    ('\u{9d08}', 0x1726e),
+    ('\u{9d09}', 0x1726d),
+    ('\u{9d0b}', 0x27e23),
+    ('\u{9d0e}', 0x1322a),
+    ('\u{9d11}', 0x27e26),
+    ('\u{9d12}', 0x17279),
+    ('\u{9d15}', 0x17278),
+    ('\u{9d17}', 0x27e27),
+    ('\u{9d18}', 0x27e28),
+    ('\u{9d1b}', 0x13175),
+    ('\u{9d1d}', 0x17e58),
+    ('\u{9d1e}', 0x17e59),
+    ('\u{9d1f}', 0x17276),
+    ('\u{9d23}', 0x17275),
+    ('\u{9d26}', 0x17273),
+    ('\u{9d28}', 0x1337b),
+    ('\u{9d2a}', 0x17272),
+    ('\u{9d2b}', 0x13c32),
+    ('\u{9d2c}', 0x13229),
+    ('\u{9d32}', 0x27e2c),
+    ('\u{9d3b}', 0x13963),
+    ('\u{9d3e}', 0x1727c),
+    ('\u{9d3f}', 0x1727b),
+    ('\u{9d41}', 0x1727a),
+    ('\u{9d42}', 0x27e2e),
+    ('\u{9d43}', 0x17e5a),
+    ('\u{9d44}', 0x17277),
+    ('\u{9d46}', 0x1727d),
+    ('\u{9d47}', 0x17e5b),
+    ('\u{9d48}', 0x1727e),
+    ('\u{9d4a}', 0x27e2f),
+    ('\u{9d50}', 0x17325),
+    ('\u{9d51}', 0x17324),
+    ('\u{9d52}', 0x17e5c),
+    ('\u{9d59}', 0x17326),
+    ('\u{9d5c}', 0x1312d),
+    ('\u{9d5d}', 0x17321),
+    ('\u{9d5e}', 0x17322),
+    ('\u{9d5f}', 0x27e30),
+    ('\u{9d60}', 0x13974),
+    ('\u{9d61}', 0x14c39),
+    ('\u{9d62}', 0x27e31),
+    ('\u{9d63}', 0x17e5d),
+    ('\u{9d64}', 0x17323),
+    ('\u{9d69}', 0x27e33),
+    ('\u{9d6b}', 0x27e34),
+    ('\u{9d6c}', 0x14b32),
+    ('\u{9d6f}', 0x1732b),
+    ('\u{9d70}', 0x17e5e),
+    ('\u{9d72}', 0x17327),
+    ('\u{9d73}', 0x27e36),
+    ('\u{9d76}', 0x27e37),
+    ('\u{9d77}', 0x27e38),
+    ('\u{9d7a}', 0x1732c),
+    ('\u{9d7c}', 0x17e5f),
+    ('\u{9d7e}', 0x27e39),
+    ('\u{9d84}', 0x27e3a),
+    ('\u{9d87}', 0x17329),
+    ('\u{9d89}', 0x17328),
+    ('\u{9d8a}', 0x17e60),
+    ('\u{9d8d}', 0x27e3b),
+    ('\u{9d8f}', 0x1375c),
+    ('\u{9d96}', 0x17e61),
+    ('\u{9d99}', 0x27e3c),
+    ('\u{9d9a}', 0x1732d),
+    ('\u{9da1}', 0x27e3d),
+    ('\u{9da4}', 0x1732e),
+    ('\u{9da9}', 0x1732f),
+    ('\u{9dab}', 0x1732a),
+    ('\u{9dac}', 0x17e63),
+    ('\u{9daf}', 0x17274),
+    ('\u{9db2}', 0x17330),
+    ('\u{9db4}', 0x14461),
+    ('\u{9db5}', 0x27e3f),
+    ('\u{9db8}', 0x17334),
+    ('\u{9db9}', 0x27e40),
+    ('\u{9dba}', 0x17335),
+    ('\u{9dbb}', 0x17333),
+    ('\u{9dbc}', 0x17e64),
+    ('\u{9dbd}', 0x27e41),
+    ('\u{9dbf}', 0x27e3e),
+    ('\u{9dc0}', 0x17e62),
+    ('\u{9dc1}', 0x17332),
+    ('\u{9dc2}', 0x17338),
+    ('\u{9dc3}', 0x27e42),
+    ('\u{9dc4}', 0x17331),
+    ('\u{9dc6}', 0x17336),
+    ('\u{9dc7}', 0x27e43),
+    ('\u{9dc9}', 0x27e44),
+    ('\u{9dcf}', 0x17337),
+    ('\u{9dd3}', 0x1733a),
+    ('\u{9dd6}', 0x27e45),
+    ('\u{9dd7}', 0x17e65),
+    ('\u{9dd9}', 0x17339),
+    ('\u{9dda}', 0x27e46),
+    ('\u{9ddf}', 0x27e47),
+    ('\u{9de0}', 0x27e48),
+    ('\u{9de3}', 0x27e49),
+    ('\u{9de6}', 0x1733c),
+    ('\u{9de7}', 0x17e67),
+    ('\u{9ded}', 0x1733d),
+    ('\u{9def}', 0x1733e),
+    ('\u{9df2}', 0x14f49),
+    ('\u{9df4}', 0x27e4a),
+    ('\u{9df8}', 0x1733b),
+    ('\u{9df9}', 0x1426b),
+    ('\u{9dfa}', 0x13a6d),
+    ('\u{9dfd}', 0x1733f),
+    ('\u{9e02}', 0x27e4d),
+    ('\u{9e07}', 0x17e68),
+    ('\u{9e0a}', 0x27e4c),
+    ('\u{9e0d}', 0x27e4e),
+    ('\u{9e15}', 0x17e69),
+    ('\u{9e19}', 0x27e4f),
+    ('\u{9e1a}', 0x17340),
+    ('\u{9e1b}', 0x17341),
+    ('\u{9e1c}', 0x27e50),
+    ('\u{9e1d}', 0x27e51),
+    ('\u{9e1e}', 0x17342),
+    ('\u{9e75}', 0x17343),
+    ('\u{9e78}', 0x13834),
+    ('\u{9e79}', 0x17344),
+    ('\u{9e7b}', 0x27e52),
+    ('\u{9e7c}', 0x17e6a),
+    ('\u{9e7d}', 0x17345),
+    ('\u{9e7f}', 0x13c2f),
+    ('\u{9e80}', 0x27e54),
+    ('\u{9e81}', 0x17346),
+    ('\u{9e85}', 0x27e55),
+    ('\u{9e88}', 0x17347),
+    ('\u{9e8b}', 0x17348),
+    ('\u{9e8c}', 0x17349),
+    ('\u{9e91}', 0x1734c),
+    ('\u{9e92}', 0x1734a),
+    ('\u{9e93}', 0x14f3c),
+    ('\u{9e95}', 0x1734b),
+    ('\u{9e97}', 0x14e6f),
+    ('\u{9e9b}', 0x27e56),
+    ('\u{9e9d}', 0x1734d),
+    ('\u{9e9e}', 0x17e6b),
+    ('\u{9e9f}', 0x14e5b),
+    ('\u{9ea4}', 0x17e6c),
+    ('\u{9ea5}', 0x1734e),
+    ('\u{9ea6}', 0x1477e),
+    ('\u{9ea8}', 0x27e57),
+    ('\u{9ea9}', 0x1734f),
+    ('\u{9eaa}', 0x17351),
+    ('\u{9eac}', 0x17e6d),
+    ('\u{9ead}', 0x17352),
+    ('\u{9eaf}', 0x17e6e),
+    ('\u{9eb4}', 0x17e6f),
+    ('\u{9eb5}', 0x17e70),
+    ('\u{9eb8}', 0x17350),
+    ('\u{9eb9}', 0x1396d),
+    ('\u{9eba}', 0x14c4d),
+    ('\u{9ebb}', 0x14b63),
+    ('\u{9ebc}', 0x15677),
+    ('\u{9ebd}', 0x27e59),
+    ('\u{9ebe}', 0x15d60),
+    ('\u{9ebf}', 0x14b7b),
+    ('\u{9ec3}', 0x17e71),
+    ('\u{9ec4}', 0x1322b),
+    ('\u{9ecc}', 0x17354),
+    ('\u{9ecd}', 0x13550),
+    ('\u{9ece}', 0x17355),
+    ('\u{9ecf}', 0x17356),
+    ('\u{9ed0}', 0x17357),
+    ('\u{9ed1}', 0x17e72),
+    ('\u{9ed2}', 0x13975),
+    ('\u{9ed4}', 0x17358),
+    ('\u{9ed8}', 0x16054),
+    ('\u{9ed9}', 0x14c5b),
+    ('\u{9edb}', 0x14263),
+    ('\u{9edc}', 0x17359),
+    ('\u{9edd}', 0x1735b),
+    ('\u{9ede}', 0x1735a),
+    ('\u{9edf}', 0x27e5b),
+    ('\u{9ee0}', 0x1735c),
+    ('\u{9ee5}', 0x1735d),
+    ('\u{9ee7}', 0x27e5c),
+    ('\u{9ee8}', 0x1735e),
+    ('\u{9eee}', 0x27e5d),
+    ('\u{9eef}', 0x1735f),
+    ('\u{9ef4}', 0x17360),
+    ('\u{9ef6}', 0x17361),
+    ('\u{9ef7}', 0x17362),
+    ('\u{9ef9}', 0x17363),
+    ('\u{9efb}', 0x17364),
+    ('\u{9efc}', 0x17365),
+    ('\u{9efd}', 0x17366),
+    ('\u{9eff}', 0x27e5e),
+    ('\u{9f02}', 0x27e5f),
+    ('\u{9f03}', 0x27e61),
+    ('\u{9f07}', 0x17367),
+    ('\u{9f08}', 0x17368),
+    ('\u{9f0e}', 0x14524),
+    ('\u{9f10}', 0x17e73),
+    ('\u{9f13}', 0x1385d),
+    ('\u{9f15}', 0x1736a),
+    ('\u{9f17}', 0x27e62),
+    ('\u{9f19}', 0x27e63),
+    ('\u{9f20}', 0x1414d),
+    ('\u{9f21}', 0x1736b),
+    ('\u{9f2c}', 0x1736c),
+    ('\u{9f2f}', 0x27e64),
+    ('\u{9f37}', 0x27e65),
+    ('\u{9f39}', 0x17e74),
+    ('\u{9f3a}', 0x27e66),
+    ('\u{9f3b}', 0x14921),
+    ('\u{9f3d}', 0x27e67),
+    ('\u{9f3e}', 0x1736d),
+    ('\u{9f41}', 0x27e68),
+    ('\u{9f45}', 0x27e69),
+    ('\u{9f46}', 0x27e6a),
+    ('\u{9f4a}', 0x1736e),
+    ('\u{9f4b}', 0x16337),
+    ('\u{9f4e}', 0x16c5a),
+    ('\u{9f4f}', 0x1706d),
+    ('\u{9f52}', 0x1736f),
+    ('\u{9f53}', 0x27e6b),
+    ('\u{9f54}', 0x17370),
+    ('\u{9f55}', 0x27e6c),
+    ('\u{9f57}', 0x17e75),
+    ('\u{9f58}', 0x27e6d),
+    ('\u{9f5d}', 0x27e6f),
+    ('\u{9f5f}', 0x17372),
+    ('\u{9f60}', 0x17373),
+    ('\u{9f61}', 0x17374),
+    ('\u{9f62}', 0x14e70),
+    ('\u{9f63}', 0x17371),
+    ('\u{9f66}', 0x17375),
+    ('\u{9f67}', 0x17376),
+    ('\u{9f69}', 0x27e71),
+    ('\u{9f6a}', 0x17378),
+    ('\u{9f6c}', 0x17377),
+    ('\u{9f6d}', 0x27e73),
+    ('\u{9f70}', 0x27e74),
+    ('\u{9f72}', 0x1737a),
+    ('\u{9f75}', 0x27e75),
+    ('\u{9f76}', 0x1737b),
+    ('\u{9f77}', 0x17379),
+    ('\u{9f8d}', 0x14e36),
+    ('\u{9f90}', 0x17e76),
+    ('\u{9f94}', 0x17e77),
+    ('\u{9f95}', 0x1737c),
+    ('\u{9f97}', 0x17e78),
+    ('\u{9f9c}', 0x1737d),
+    ('\u{9f9d}', 0x16354),
+    ('\u{9fa0}', 0x1737e),
+    ('\u{9fa2}', 0x17e79),
+    ('\u{f91d}', 0x1763b),
+    ('\u{f928}', 0x1742e),
+    ('\u{f929}', 0x1754e),
+    ('\u{f936}', 0x17b4f),
+    ('\u{f970}', 0x17649),
+    ('\u{f9d0}', 0x17e24),
+    ('\u{f9dc}', 0x17d5d),
+    ('\u{fa0f}', 0x12f4b),
+    ('\u{fa10}', 0x12f57),
+    ('\u{fa11}', 0x14f72),
+    ('\u{fa13}', 0x22e79),
+    ('\u{fa14}', 0x1757a),
+    ('\u{fa15}', 0x1775a),
+    ('\u{fa16}', 0x1776f),
+    ('\u{fa19}', 0x1793c),
+    ('\u{fa1a}', 0x1793d),
+    ('\u{fa1b}', 0x17941),
+    ('\u{fa1f}', 0x17b3a),
+    ('\u{fa20}', 0x27738),
+    ('\u{fa21}', 0x27745),
+    ('\u{fa22}', 0x17c2e),
+    ('\u{fa24}', 0x2796e),
+    ('\u{fa26}', 0x17c6a),
+    ('\u{fa30}', 0x12e38),
+    ('\u{fa31}', 0x12e49),
+    ('\u{fa32}', 0x12e50),
+    ('\u{fa33}', 0x12e63),
+    ('\u{fa34}', 0x12e68),
+    ('\u{fa35}', 0x12e6e),
+    ('\u{fa36}', 0x12f2c),
+    ('\u{fa37}', 0x12f2f),
+    ('\u{fa38}', 0x12f36),
+    ('\u{fa39}', 0x12f5a),
+    ('\u{fa3a}', 0x12f5e),
+    ('\u{fa3b}', 0x14f61),
+    ('\u{fa3c}', 0x14f62),
+    ('\u{fa3d}', 0x17450),
+    ('\u{fa3e}', 0x1745c),
+    ('\u{fa3f}', 0x1745e),
+    ('\u{fa40}', 0x17461),
+    ('\u{fa41}', 0x17528),
+    ('\u{fa42}', 0x1752b),
+    ('\u{fa43}', 0x17543),
+    ('\u{fa44}', 0x17565),
+    ('\u{fa45}', 0x17669),
+    ('\u{fa46}', 0x17677),
+    ('\u{fa47}', 0x17725),
+    ('\u{fa48}', 0x17755),
+    ('\u{fa49}', 0x27029),
+    ('\u{fa4a}', 0x17825),
+    ('\u{fa4b}', 0x17927),
+    ('\u{fa4c}', 0x17933),
+    ('\u{fa4d}', 0x17934),
+    ('\u{fa4e}', 0x17937),
+    ('\u{fa4f}', 0x17938),
+    ('\u{fa50}', 0x17939),
+    ('\u{fa51}', 0x1793b),
+    ('\u{fa52}', 0x1793f),
+    ('\u{fa53}', 0x17940),
+    ('\u{fa54}', 0x1794d),
+    ('\u{fa55}', 0x17951),
+    ('\u{fa56}', 0x17964),
+    ('\u{fa57}', 0x17a2e),
+    ('\u{fa58}', 0x27450),
+    ('\u{fa59}', 0x17a33),
+    ('\u{fa5a}', 0x17a3a),
+    ('\u{fa5b}', 0x17a44),
+    ('\u{fa5c}', 0x17a58),
+    ('\u{fa5d}', 0x27574),
+    ('\u{fa5e}', 0x27575),
+    ('\u{fa5f}', 0x17b27),
+    ('\u{fa60}', 0x17b6f),
+    ('\u{fa61}', 0x17b79),
+    ('\u{fa62}', 0x17c2f),
+    ('\u{fa63}', 0x17c30),
+    ('\u{fa64}', 0x17c38),
+    ('\u{fa65}', 0x17c3d),
+    ('\u{fa66}', 0x27969),
+    ('\u{fa67}', 0x17c59),
+    ('\u{fa68}', 0x17d63),
+    ('\u{fa69}', 0x17d76),
+    ('\u{fa6a}', 0x17d7b),
+    ('\u{fe45}', 0x1233e),
+    ('\u{fe46}', 0x1233d),
+    ('\u{ff01}', 0x1212a),
+    ('\u{ff02}', 0x12230),
+    ('\u{ff03}', 0x12174),
+    ('\u{ff04}', 0x12170),
+    ('\u{ff05}', 0x12173),
+    ('\u{ff06}', 0x12175),
+    ('\u{ff07}', 0x1222f),
+    ('\u{ff08}', 0x1214a),
+    ('\u{ff09}', 0x1214b),
+    ('\u{ff0a}', 0x12176),
+    ('\u{ff0b}', 0x1215c),
+    ('\u{ff0c}', 0x12124),
+    ('\u{ff0d}', 0x12231),
+    ('\u{ff0e}', 0x12125),
+    ('\u{ff0f}', 0x1213f),
+    ('\u{ff10}', 0x12330),
+    ('\u{ff11}', 0x12331),
+    ('\u{ff12}', 0x12332),
+    ('\u{ff13}', 0x12333),
+    ('\u{ff14}', 0x12334),
+    ('\u{ff15}', 0x12335),
+    ('\u{ff16}', 0x12336),
+    ('\u{ff17}', 0x12337),
+    ('\u{ff18}', 0x12338),
+    ('\u{ff19}', 0x12339),
+    ('\u{ff1a}', 0x12127),
+    ('\u{ff1b}', 0x12128),
+    ('\u{ff1c}', 0x12163),
+    ('\u{ff1d}', 0x12161),
+    ('\u{ff1e}', 0x12164),
+    ('\u{ff1f}', 0x12129),
+    ('\u{ff20}', 0x12177),
+    ('\u{ff21}', 0x12341),
+    ('\u{ff22}', 0x12342),
+    ('\u{ff23}', 0x12343),
+    ('\u{ff24}', 0x12344),
+    ('\u{ff25}', 0x12345),
+    ('\u{ff26}', 0x12346),
+    ('\u{ff27}', 0x12347),
+    ('\u{ff28}', 0x12348),
+    ('\u{ff29}', 0x12349),
+    ('\u{ff2a}', 0x1234a),
+    ('\u{ff2b}', 0x1234b),
+    ('\u{ff2c}', 0x1234c),
+    ('\u{ff2d}', 0x1234d),
+    ('\u{ff2e}', 0x1234e),
+    ('\u{ff2f}', 0x1234f),
+    ('\u{ff30}', 0x12350),
+    ('\u{ff31}', 0x12351),
+    ('\u{ff32}', 0x12352),
+    ('\u{ff33}', 0x12353),
+    ('\u{ff34}', 0x12354),
+    ('\u{ff35}', 0x12355),
+    ('\u{ff36}', 0x12356),
+    ('\u{ff37}', 0x12357),
+    ('\u{ff38}', 0x12358),
+    ('\u{ff39}', 0x12359),
+    ('\u{ff3a}', 0x1235a),
+    ('\u{ff3b}', 0x1214e),
+    ('\u{ff3c}', 0x12140),
+    ('\u{ff3d}', 0x1214f),
+    ('\u{ff3e}', 0x12130),
+    ('\u{ff3f}', 0x12132),
+    ('\u{ff40}', 0x1212e),
+    ('\u{ff41}', 0x12361),
+    ('\u{ff42}', 0x12362),
+    ('\u{ff43}', 0x12363),
+    ('\u{ff44}', 0x12364),
+    ('\u{ff45}', 0x12365),
+    ('\u{ff46}', 0x12366),
+    ('\u{ff47}', 0x12367),
+    ('\u{ff48}', 0x12368),
+    ('\u{ff49}', 0x12369),
+    ('\u{ff4a}', 0x1236a),
+    ('\u{ff4b}', 0x1236b),
+    ('\u{ff4c}', 0x1236c),
+    ('\u{ff4d}', 0x1236d),
+    ('\u{ff4e}', 0x1236e),
+    ('\u{ff4f}', 0x1236f),
+    ('\u{ff50}', 0x12370),
+    ('\u{ff51}', 0x12371),
+    ('\u{ff52}', 0x12372),
+    ('\u{ff53}', 0x12373),
+    ('\u{ff54}', 0x12374),
+    ('\u{ff55}', 0x12375),
+    ('\u{ff56}', 0x12376),
+    ('\u{ff57}', 0x12377),
+    ('\u{ff58}', 0x12378),
+    ('\u{ff59}', 0x12379),
+    ('\u{ff5a}', 0x1237a),
+    ('\u{ff5b}', 0x12150),
+    ('\u{ff5c}', 0x12143),
+    ('\u{ff5d}', 0x12151),
+    ('\u{ff5e}', 0x12232),
+    ('\u{ff5f}', 0x12256),
+    ('\u{ff60}', 0x12257),
+    ('\u{ffe3}', 0x12131),
+    ('\u{ffe5}', 0x1216f),
+    ('\u{2000b}', 0x12e22),
+    ('\u{20089}', 0x22121),
+    ('\u{200a2}', 0x2212b),
+    ('\u{200a4}', 0x2212e),
+    ('\u{201a2}', 0x22136),
+    ('\u{20213}', 0x22146),
+    ('\u{2032b}', 0x22170),
+    ('\u{20371}', 0x22179),
+    ('\u{20381}', 0x22177),
+    ('\u{203f9}', 0x22322),
+    ('\u{2044a}', 0x22325),
+    ('\u{20509}', 0x22327),
+    ('\u{205d6}', 0x22331),
+    ('\u{20628}', 0x22332),
+    ('\u{2074f}', 0x22338),
+    ('\u{20807}', 0x2233f),
+    ('\u{2083a}', 0x22341),
+    ('\u{208b9}', 0x2234a),
+    ('\u{2097c}', 0x22352),
+    ('\u{2099d}', 0x22353),
+    ('\u{20ad3}', 0x22359),
+    ('\u{20b1d}', 0x2235c),
+    ('\u{20b9f}', 0x14f54),
+    ('\u{20d45}', 0x22377),
+    ('\u{20de1}', 0x2242a),
+    ('\u{20e64}', 0x2243a),
+    ('\u{20e6d}', 0x22432),
+    ('\u{20e95}', 0x22431),
+    ('\u{20f5f}', 0x2243d),
+    ('\u{21201}', 0x22459),
+    ('\u{2123d}', 0x12f42),
+    ('\u{21255}', 0x2245c),
+    ('\u{21274}', 0x22463),
+    ('\u{2127b}', 0x2245e),
+    ('\u{212d7}', 0x2246b),
+    ('\u{212e4}', 0x2246a),
+    ('\u{212fd}', 0x22472),
+    ('\u{2131b}', 0x12f4c),
+    ('\u{21336}', 0x22474),
+    ('\u{21344}', 0x22475),
+    ('\u{213c4}', 0x22525),
+    ('\u{2146d}', 0x22532),
+    ('\u{2146e}', 0x12f60),
+    ('\u{215d7}', 0x2253e),
+    ('\u{21647}', 0x22547),
+    ('\u{216b4}', 0x14f63),
+    ('\u{21706}', 0x22555),
+    ('\u{21742}', 0x22556),
+    ('\u{218bd}', 0x12f7b),
+    ('\u{219c3}', 0x2257e),
+    ('\u{21c56}', 0x22830),
+    ('\u{21d2d}', 0x22837),
+    ('\u{21d45}', 0x22838),
+    ('\u{21d62}', 0x2283b),
+    ('\u{21d78}', 0x2283a),
+    ('\u{21d92}', 0x22845),
+    ('\u{21d9c}', 0x22840),
+    ('\u{21da1}', 0x2283f),
+    ('\u{21db7}', 0x22848),
+    ('\u{21de0}', 0x2284a),
+    ('\u{21e33}', 0x2284b),
+    ('\u{21e34}', 0x14f6e),
+    ('\u{21f1e}', 0x2285b),
+    ('\u{21f76}', 0x22866),
+    ('\u{21ffa}', 0x2286c),
+    ('\u{2217b}', 0x22c22),
+    ('\u{22218}', 0x27e53),
+    ('\u{2231e}', 0x22c2b),
+    ('\u{223ad}', 0x22c30),
+    ('\u{226f3}', 0x22c50),
+    ('\u{2285b}', 0x22c65),
+    ('\u{228ab}', 0x22c6d),
+    ('\u{2298f}', 0x22c72),
+    ('\u{22ab8}', 0x22d24),
+    ('\u{22b46}', 0x22d32),
+    ('\u{22b4f}', 0x22d29),
+    ('\u{22b50}', 0x22d2a),
+    ('\u{22ba6}', 0x22d35),
+    ('\u{22c1d}', 0x22d34),
+    ('\u{22c24}', 0x22d39),
+    ('\u{22de1}', 0x22d56),
+    ('\u{231b6}', 0x22e24),
+    ('\u{231c3}', 0x22d7d),
+    ('\u{231c4}', 0x1753a),
+    ('\u{231f5}', 0x22e23),
+    ('\u{23372}', 0x22e3a),
+    ('\u{233d0}', 0x22e42),
+    ('\u{233d2}', 0x22e3d),
+    ('\u{233d3}', 0x22e3c),
+    ('\u{233d5}', 0x22e44),
+    ('\u{233da}', 0x22e47),
+    ('\u{233df}', 0x22e49),
+    ('\u{233e4}', 0x22e43),
+    ('\u{2344a}', 0x22e55),
+    ('\u{2344b}', 0x22e57),
+    ('\u{23451}', 0x22e56),
+    ('\u{23465}', 0x22e5b),
+    ('\u{234e4}', 0x22e77),
+    ('\u{2355a}', 0x22e78),
+    ('\u{23594}', 0x22f2a),
+    ('\u{235c4}', 0x17572),
+    ('\u{23638}', 0x22f42),
+    ('\u{23639}', 0x22f3f),
+    ('\u{2363a}', 0x22f43),
+    ('\u{23647}', 0x22f40),
+    ('\u{2370c}', 0x22f59),
+    ('\u{2371c}', 0x22f4e),
+    ('\u{2373f}', 0x17629),
+    ('\u{23763}', 0x17632),
+    ('\u{23764}', 0x22f61),
+    ('\u{237e7}', 0x22f6a),
+    ('\u{237ff}', 0x22f69),
+    ('\u{23824}', 0x22f70),
+    ('\u{2383d}', 0x22f75),
+    ('\u{23a98}', 0x26e23),
+    ('\u{23c7f}', 0x26e34),
+    ('\u{23cfe}', 0x17660),
+    ('\u{23d00}', 0x26e49),
+    ('\u{23d0e}', 0x27475),
+    ('\u{23d40}', 0x26e5c),
+    ('\u{23dd3}', 0x26e60),
+    ('\u{23df9}', 0x26e5f),
+    ('\u{23dfa}', 0x26e5e),
+    ('\u{23f7e}', 0x26f32),
+    ('\u{24096}', 0x26f47),
+    ('\u{24103}', 0x26f4d),
+    ('\u{241c6}', 0x26f61),
+    ('\u{241fe}', 0x26f64),
+    ('\u{243bc}', 0x27022),
+    ('\u{24629}', 0x27033),
+    ('\u{246a5}', 0x27039),
+    ('\u{247f1}', 0x1776c),
+    ('\u{24896}', 0x27053),
+    ('\u{24a4d}', 0x2707b),
+    ('\u{24b56}', 0x2712e),
+    ('\u{24b6f}', 0x27130),
+    ('\u{24c16}', 0x27135),
+    ('\u{24d14}', 0x27144),
+    ('\u{24e0e}', 0x2715d),
+    ('\u{24e37}', 0x27161),
+    ('\u{24e6a}', 0x27166),
+    ('\u{24e8b}', 0x27169),
+    ('\u{2504a}', 0x27175),
+    ('\u{25055}', 0x27177),
+    ('\u{25122}', 0x2717a),
+    ('\u{251a9}', 0x27221),
+    ('\u{251cd}', 0x27224),
+    ('\u{251e5}', 0x27223),
+    ('\u{2521e}', 0x27228),
+    ('\u{2524c}', 0x2722c),
+    ('\u{2542e}', 0x2723d),
+    ('\u{2548e}', 0x1787e),
+    ('\u{254d9}', 0x27248),
+    ('\u{2550e}', 0x17929),
+    ('\u{255a7}', 0x2725b),
+    ('\u{25771}', 0x17947),
+    ('\u{257a9}', 0x27275),
+    ('\u{257b4}', 0x27276),
+    ('\u{259c4}', 0x17954),
+    ('\u{259d4}', 0x27332),
+    ('\u{25ae3}', 0x2733e),
+    ('\u{25ae4}', 0x2733d),
+    ('\u{25af1}', 0x27340),
+    ('\u{25bb2}', 0x27352),
+    ('\u{25c4b}', 0x2735d),
+    ('\u{25c64}', 0x2735e),
+    ('\u{25da1}', 0x1796e),
+    ('\u{25e2e}', 0x27373),
+    ('\u{25e56}', 0x27374),
+    ('\u{25e62}', 0x27377),
+    ('\u{25e65}', 0x27375),
+    ('\u{25ec2}', 0x2737d),
+    ('\u{25ed8}', 0x2737b),
+    ('\u{25ee8}', 0x27422),
+    ('\u{25f23}', 0x27424),
+    ('\u{25f5c}', 0x27427),
+    ('\u{25fd4}', 0x2742f),
+    ('\u{25fe0}', 0x2742e),
+    ('\u{25ffb}', 0x27435),
+    ('\u{2600c}', 0x27434),
+    ('\u{26017}', 0x2743d),
+    ('\u{26060}', 0x27442),
+    ('\u{260ed}', 0x2744f),
+    ('\u{26270}', 0x27469),
+    ('\u{26286}', 0x2746b),
+    ('\u{2634c}', 0x27472),
+    ('\u{26402}', 0x27479),
+    ('\u{2667e}', 0x27535),
+    ('\u{266b0}', 0x2753a),
+    ('\u{2671d}', 0x27546),
+    ('\u{268dd}', 0x27556),
+    ('\u{268ea}', 0x27558),
+    ('\u{26951}', 0x2755a),
+    ('\u{2696f}', 0x2755d),
+    ('\u{269dd}', 0x2755f),
+    ('\u{26a1e}', 0x27563),
+    ('\u{26a58}', 0x2756a),
+    ('\u{26a8c}', 0x27570),
+    ('\u{26ab7}', 0x27573),
+    ('\u{26aff}', 0x17a5d),
+    ('\u{26c29}', 0x22544),
+    ('\u{26c73}', 0x27644),
+    ('\u{26cdd}', 0x2764e),
+    ('\u{26e40}', 0x17b33),
+    ('\u{26e65}', 0x2765d),
+    ('\u{26f94}', 0x27675),
+    ('\u{26ff6}', 0x27721),
+    ('\u{26ff7}', 0x27722),
+    ('\u{26ff8}', 0x2767e),
+    ('\u{270f4}', 0x17b49),
+    ('\u{2710d}', 0x27733),
+    ('\u{27139}', 0x27736),
+    ('\u{273da}', 0x27765),
+    ('\u{273db}', 0x27764),
+    ('\u{273fe}', 0x2776b),
+    ('\u{27410}', 0x2776e),
+    ('\u{27449}', 0x27773),
+    ('\u{27614}', 0x2782a),
+    ('\u{27615}', 0x27829),
+    ('\u{27631}', 0x2782c),
+    ('\u{27684}', 0x17b6c),
+    ('\u{27693}', 0x27834),
+    ('\u{2770e}', 0x2783c),
+    ('\u{27723}', 0x2783e),
+    ('\u{27752}', 0x27842),
+    ('\u{27985}', 0x27856),
+    ('\u{27a84}', 0x27863),
+    ('\u{27bb3}', 0x27877),
+    ('\u{27bbe}', 0x27879),
+    ('\u{27bc7}', 0x2787a),
+    ('\u{27cb8}', 0x27925),
+    ('\u{27da0}', 0x2792f),
+    ('\u{27e10}', 0x27932),
+    ('\u{27fb7}', 0x27939),
+    ('\u{2808a}', 0x27942),
+    ('\u{280bb}', 0x27948),
+    ('\u{28277}', 0x17c49),
+    ('\u{28282}', 0x27959),
+    ('\u{282f3}', 0x2795e),
+    ('\u{283cd}', 0x17c51),
+    ('\u{2840c}', 0x27966),
+    ('\u{28455}', 0x2796b),
+    ('\u{2856b}', 0x2797a),
+    ('\u{285c8}', 0x2797e),
+    ('\u{285c9}', 0x27a21),
+    ('\u{286d7}', 0x27a2c),
+    ('\u{286fa}', 0x27a2f),
+    ('\u{28946}', 0x27a50),
+    ('\u{28949}', 0x27a4f),
+    ('\u{2896b}', 0x27a57),
+    ('\u{28987}', 0x27a65),
+    ('\u{28988}', 0x27a66),
+    ('\u{289ba}', 0x27a71),
+    ('\u{289bb}', 0x27a72),
+    ('\u{28a1e}', 0x27a7e),
+    ('\u{28a29}', 0x27b21),
+    ('\u{28a43}', 0x27b2d),
+    ('\u{28a71}', 0x27b2c),
+    ('\u{28a99}', 0x27b36),
+    ('\u{28acd}', 0x27b37),
+    ('\u{28add}', 0x27b3e),
+    ('\u{28ae4}', 0x27b3d),
+    ('\u{28bc1}', 0x27b4e),
+    ('\u{28bef}', 0x27b4f),
+    ('\u{28d10}', 0x27b57),
+    ('\u{28d71}', 0x27b5a),
+    ('\u{28dfb}', 0x27b5c),
+    ('\u{28e1f}', 0x27b5d),
+    ('\u{28e36}', 0x27b61),
+    ('\u{28e89}', 0x27b65),
+    ('\u{28eeb}', 0x27b67),
+    ('\u{28f32}', 0x27b69),
+    ('\u{28ff8}', 0x27b71),
+    ('\u{292a0}', 0x27c22),
+    ('\u{292b1}', 0x27c23),
+    ('\u{29490}', 0x27c38),
+    ('\u{295cf}', 0x27c42),
+    ('\u{2967f}', 0x27c4c),
+    ('\u{296f0}', 0x27c56),
+    ('\u{29719}', 0x27c59),
+    ('\u{29750}', 0x27c5d),
+    ('\u{298c6}', 0x27c76),
+    ('\u{29a72}', 0x27d2c),
+    ('\u{29ddb}', 0x27d4b),
+    ('\u{29e15}', 0x27d59),
+    ('\u{29e3d}', 0x27d4c),
+    ('\u{29e49}', 0x27d5d),
+    ('\u{29e8a}', 0x27d5b),
+    ('\u{29ec4}', 0x27d67),
+    ('\u{29edb}', 0x27d70),
+    ('\u{29ee9}', 0x27d6d),
+    ('\u{29fce}', 0x27e25),
+    ('\u{2a01a}', 0x27e2b),
+    ('\u{2a02f}', 0x27e29),
+    ('\u{2a082}', 0x27e35),
+    ('\u{2a0f9}', 0x27e32),
+    ('\u{2a190}', 0x17e66),
+    ('\u{2a38c}', 0x27e58),
+    ('\u{2a437}', 0x27e5a),
+    ('\u{2a5f1}', 0x27e6e),
+    ('\u{2a602}', 0x27e70),
+    ('\u{2a61a}', 0x27e72),
+    ('\u{2a6b2}', 0x27e76),
+];
+
+const MULTI_CHAR_CODE_POINT_TABLE: &[([char; 2], u32)] = &[
+    (['\u{00e6}', '\u{0300}'], 0x12b44),
+    (['\u{0254}', '\u{0300}'], 0x12b48),
+    (['\u{0254}', '\u{0301}'], 0x12b49),
+    (['\u{0259}', '\u{0300}'], 0x12b4c),
+    (['\u{0259}', '\u{0301}'], 0x12b4d),
+    (['\u{025a}', '\u{0300}'], 0x12b4e),
+    (['\u{025a}', '\u{0301}'], 0x12b4f),
+    (['\u{028c}', '\u{0300}'], 0x12b4a),
+    (['\u{028c}', '\u{0301}'], 0x12b4b),
+    (['\u{02e5}', '\u{02e9}'], 0x12b66),
+    (['\u{02e9}', '\u{02e5}'], 0x12b65),
+    (['\u{304b}', '\u{309a}'], 0x12477),
+    (['\u{304d}', '\u{309a}'], 0x12478),
+    (['\u{304f}', '\u{309a}'], 0x12479),
+    (['\u{3051}', '\u{309a}'], 0x1247a),
+    (['\u{3053}', '\u{309a}'], 0x1247b),
+    (['\u{30ab}', '\u{309a}'], 0x12577),
+    (['\u{30ad}', '\u{309a}'], 0x12578),
+    (['\u{30af}', '\u{309a}'], 0x12579),
+    (['\u{30b1}', '\u{309a}'], 0x1257a),
+    (['\u{30b3}', '\u{309a}'], 0x1257b),
+    (['\u{30bb}', '\u{309a}'], 0x1257c),
+    (['\u{30c4}', '\u{309a}'], 0x1257d),
+    (['\u{30c8}', '\u{309a}'], 0x1257e),
+    (['\u{31f7}', '\u{309a}'], 0x12678),
+];
+
 pub fn code_point_to_chars(cp: u32) -> Option<&'static [char]> {
     let row = cp >> 8;
     let row = match row {
@@ -11313,11 +22552,14 @@ pub fn code_point_to_chars(cp: u32) -> Option<&'static [char]> {
         _ => return None,
     };
     let col = cp & 0xff;
-    if col < 0x21 || col > 0x7e {
+    if !(0x21..=0x7e).contains(&col) {
         return None;
     }
     let col = col - 0x21;
     let offset = (row * 94 + col) as usize;
+    if offset >= TABLE.len() {
+        return None;
+    }
     let cp = &TABLE[offset..offset + 1];
     let c = cp[0] as usize;
     if c == 0 {
@@ -11326,5 +22568,23 @@ pub fn code_point_to_chars(cp: u32) -> Option<&'static [char]> {
     if c >= 0x80 {
         return Some(cp);
     }
-    return Some(&MULTI_CHAR_TABLE[c - 1]);
+    Some(&MULTI_CHAR_TABLE[c - 1])
+}
+
+/// Returns the code point of a character or a pair of characters, the
+/// inverse of code_point_to_chars.
+pub fn chars_to_code_point(chars: &[char]) -> Option<u32> {
+    match chars {
+        [c] => {
+            let i = CODE_POINT_TABLE.binary_search_by_key(c, |(c, _)| *c).ok()?;
+            Some(CODE_POINT_TABLE[i].1)
+        }
+        [c1, c2] => {
+            let i = MULTI_CHAR_CODE_POINT_TABLE
+                .binary_search_by_key(&[*c1, *c2], |(c, _)| *c)
+                .ok()?;
+            Some(MULTI_CHAR_CODE_POINT_TABLE[i].1)
+        }
+        _ => None,
+    }
 }
@@ -26,3 +26,35 @@ fn test() {
         '\u{7CD7}'
     );
 }
+
+#[test]
+fn test_chars_to_code_point() {
+    // 1-82-02
+    assert_eq!(jisx0213::chars_to_code_point(&['\u{9B06}']), Some(0x17222));
+
+    // 1-4-91
+    assert_eq!(
+        jisx0213::chars_to_code_point(&['\u{3053}', '\u{309a}']),
+        Some(0x1247b)
+    );
+
+    // 2-84-03
+    assert_eq!(jisx0213::chars_to_code_point(&['\u{7CD7}']), Some(0x27423));
+
+    assert_eq!(jisx0213::chars_to_code_point(&['A']), None);
+    assert_eq!(jisx0213::chars_to_code_point(&[]), None);
+}
+
+#[test]
+fn test_round_trip() {
+    for plane in [0x10000, 0x20000] {
+        for row in 0x21..=0x7e {
+            for col in 0x21..=0x7e {
+                let cp = plane | (row << 8) | col;
+                if let Some(chars) = jisx0213::code_point_to_chars(cp) {
+                    assert_eq!(jisx0213::chars_to_code_point(chars), Some(cp));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,165 @@
+pub const SYNC_WORD: u16 = 0xfff;
+pub const HEADER_LENGTH: usize = 7;
+
+const SAMPLING_FREQUENCIES: [u32; 13] = [
+    96000, 88200, 64000, 48000, 44100, 32000, 24000, 22050, 16000, 12000, 11025, 8000, 7350,
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("too short {len}, expect {expr}({expected})")]
+    TooShort {
+        len: usize,
+        expected: usize,
+        expr: &'static str,
+    },
+    #[error("invalid sync word: 0x{0:x}")]
+    InvalidSyncWord(u16),
+    #[error("invalid frame length: {0}")]
+    InvalidFrameLength(usize),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct Header {
+    pub id: u8,
+    pub layer: u8,
+    pub protection_absent: bool,
+    pub profile: u8,
+    pub sampling_frequency_index: u8,
+    pub private_bit: bool,
+    pub channel_configuration: u8,
+    pub frame_length: usize,
+    pub adts_buffer_fullness: u16,
+    pub number_of_raw_data_blocks_in_frame: u8,
+}
+
+impl Header {
+    pub fn parse(bytes: &[u8]) -> Result<Header> {
+        check_len!(bytes.len(), HEADER_LENGTH);
+        let sync_word = (u16::from(bytes[0]) << 4) | (u16::from(bytes[1]) >> 4);
+        if sync_word != SYNC_WORD {
+            return Err(Error::InvalidSyncWord(sync_word));
+        }
+        let id = (bytes[1] >> 3) & 0x1;
+        let layer = (bytes[1] >> 1) & 0x3;
+        let protection_absent = bytes[1] & 0x1 > 0;
+        let profile = bytes[2] >> 6;
+        let sampling_frequency_index = (bytes[2] >> 2) & 0xf;
+        let private_bit = (bytes[2] >> 1) & 0x1 > 0;
+        let channel_configuration = ((bytes[2] & 0x1) << 2) | (bytes[3] >> 6);
+        let frame_length = (usize::from(bytes[3] & 0x3) << 11)
+            | (usize::from(bytes[4]) << 3)
+            | (usize::from(bytes[5]) >> 5);
+        if frame_length < HEADER_LENGTH {
+            return Err(Error::InvalidFrameLength(frame_length));
+        }
+        let adts_buffer_fullness = (u16::from(bytes[5] & 0x1f) << 6) | (u16::from(bytes[6]) >> 2);
+        let number_of_raw_data_blocks_in_frame = bytes[6] & 0x3;
+        Ok(Header {
+            id,
+            layer,
+            protection_absent,
+            profile,
+            sampling_frequency_index,
+            private_bit,
+            channel_configuration,
+            frame_length,
+            adts_buffer_fullness,
+            number_of_raw_data_blocks_in_frame,
+        })
+    }
+
+    /// Sampling frequency in Hz, None for reserved or escape indices.
+    pub fn sample_rate(&self) -> Option<u32> {
+        SAMPLING_FREQUENCIES
+            .get(usize::from(self.sampling_frequency_index))
+            .copied()
+    }
+
+    /// Number of output channels, None when the configuration is carried
+    /// in a program config element (e.g. dual mono).
+    pub fn channels(&self) -> Option<u8> {
+        match self.channel_configuration {
+            0 => None,
+            7 => Some(8),
+            n => Some(n),
+        }
+    }
+
+    pub fn profile_name(&self) -> &'static str {
+        match self.profile {
+            0 => "main",
+            1 => "lc",
+            2 => "ssr",
+            _ => "ltp",
+        }
+    }
+}
+
+/// Iterates ADTS frames in a byte sequence, skipping garbage between them.
+pub struct Frames<'a> {
+    bytes: &'a [u8],
+}
+
+pub fn frames(bytes: &[u8]) -> Frames<'_> {
+    Frames { bytes }
+}
+
+impl<'a> Iterator for Frames<'a> {
+    type Item = (Header, &'a [u8]);
+
+    fn next(&mut self) -> Option<(Header, &'a [u8])> {
+        while self.bytes.len() >= HEADER_LENGTH {
+            if self.bytes[0] != 0xff || self.bytes[1] & 0xf0 != 0xf0 {
+                self.bytes = &self.bytes[1..];
+                continue;
+            }
+            match Header::parse(self.bytes) {
+                Ok(header) if header.frame_length <= self.bytes.len() => {
+                    let (frame, rest) = self.bytes.split_at(header.frame_length);
+                    self.bytes = rest;
+                    return Some((header, frame));
+                }
+                Ok(_) => {
+                    // truncated frame at the end of the payload.
+                    self.bytes = &[];
+                }
+                Err(_) => {
+                    self.bytes = &self.bytes[1..];
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Audio format derived from an ADTS header.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct AudioFormat {
+    pub profile: &'static str,
+    pub sample_rate: Option<u32>,
+    pub channels: Option<u8>,
+    pub channel_configuration: u8,
+}
+
+impl AudioFormat {
+    pub fn new(header: &Header) -> AudioFormat {
+        AudioFormat {
+            profile: header.profile_name(),
+            sample_rate: header.sample_rate(),
+            channels: header.channels(),
+            channel_configuration: header.channel_configuration,
+        }
+    }
+}
+
+/// Finds the first ADTS header in `bytes`.
+pub fn find_audio_format(bytes: &[u8]) -> Option<AudioFormat> {
+    frames(bytes)
+        .next()
+        .map(|(header, _)| AudioFormat::new(&header))
+}
@@ -1,8 +1,19 @@
-use anyhow::{bail, Result};
+use bytes::{BufMut, Bytes, BytesMut};
 
+use crate::arib::{Error, Result};
 use crate::psi;
 
+const MANAGEMENT_DATA_GROUP_ID: u8 = 0x0;
+// statements of the first language.
+const STATEMENT_DATA_GROUP_ID: u8 = 0x1;
+const GROUP_B: u8 = 0x20;
+const UNIT_SEPARATOR: u8 = 0x1f;
+const TEXT_DATA_UNIT: u8 = 0x20;
+// horizontal writing in 960x540, which HD services use.
+const FORMAT_HORIZONTAL_960X540: u8 = 0b1000;
+
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct DataGroup<'a> {
     pub data_group_id: u8,
     pub data_group_version: u8,
@@ -13,12 +24,15 @@ pub struct DataGroup<'a> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub enum DataGroupData<'a> {
     CaptionManagementData(CaptionManagementData<'a>),
     CaptionData(CaptionData<'a>),
 }
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub enum TMD {
     Free,
     RealTime,
@@ -39,11 +53,12 @@ impl TMD {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct Time {
-    h: u8,
-    m: u8,
-    s: u8,
-    ms: u8,
+    pub h: u8,
+    pub m: u8,
+    pub s: u8,
+    pub ms: u8,
 }
 
 impl Time {
@@ -61,6 +76,7 @@ impl Time {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct CaptionManagementData<'a> {
     pub tmd: TMD,
     pub otm: Option<Time>,
@@ -68,8 +84,10 @@ pub struct CaptionManagementData<'a> {
     pub data_units: Vec<DataUnit<'a>>,
 }
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug)]
-enum TCS {
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub enum TCS {
     Char8,
     UCS,
     Reseved,
@@ -87,7 +105,8 @@ impl TCS {
 }
 
 #[derive(Debug)]
-enum RollupMode {
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub enum RollupMode {
     NonRollup,
     Rollup,
     Reseved,
@@ -105,17 +124,19 @@ impl RollupMode {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct Language {
-    language_tag: u8,
-    dmf: u8,
-    dc: Option<u8>,
-    iso_639_language_code: String,
-    format: u8,
-    tcs: TCS,
-    rollup_mode: RollupMode,
+    pub language_tag: u8,
+    pub dmf: u8,
+    pub dc: Option<u8>,
+    pub iso_639_language_code: String,
+    pub format: u8,
+    pub tcs: TCS,
+    pub rollup_mode: RollupMode,
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub enum DataUnitParameter {
     Text,
     Geometric,
@@ -143,6 +164,7 @@ impl DataUnitParameter {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct DataUnit<'a> {
     pub unit_separator: u8,
     pub data_unit_parameter: DataUnitParameter,
@@ -150,20 +172,24 @@ pub struct DataUnit<'a> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct CaptionData<'a> {
     pub tmd: TMD,
     pub stm: Option<Time>,
     pub data_units: Vec<DataUnit<'a>>,
 }
 
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct DrcsDataStructure<'a> {
     pub codes: Vec<Code<'a>>,
 }
 
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct Code<'a> {
     pub character_code: u16,
     pub fonts: Vec<Font<'a>>,
 }
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct Font<'a> {
     pub font_id: u8,
     pub depth: u8,
@@ -173,7 +199,7 @@ pub struct Font<'a> {
 }
 
 impl<'a> DataGroup<'a> {
-    pub fn parse(bytes: &[u8]) -> Result<DataGroup> {
+    pub fn parse(bytes: &[u8]) -> Result<DataGroup<'_>> {
         let data_group_id = bytes[0] >> 2;
         let data_group_version = bytes[0] & 0x3;
         let data_group_link_number = bytes[1];
@@ -206,7 +232,7 @@ impl Language {
         let language_tag = bytes[0] >> 5;
         let dmf = bytes[0] & 0xf;
         let dc = match dmf {
-            0b1100 | 0b1101 | 0b1110 => {
+            0b1100..=0b1110 => {
                 let dc = bytes[1];
                 bytes = &bytes[2..];
                 n += 1;
@@ -237,7 +263,7 @@ impl Language {
 }
 
 impl<'a> CaptionManagementData<'a> {
-    fn parse(mut bytes: &[u8]) -> Result<CaptionManagementData> {
+    fn parse(mut bytes: &[u8]) -> Result<CaptionManagementData<'_>> {
         let tmd = TMD::from(bytes[0] >> 6);
         let otm = match tmd {
             TMD::OffsetTime => {
@@ -263,7 +289,7 @@ impl<'a> CaptionManagementData<'a> {
         let mut data_units = Vec::new();
         {
             let mut bytes = &bytes[3..3 + data_unit_loop_length];
-            while bytes.len() > 0 {
+            while !bytes.is_empty() {
                 let (du, n) = DataUnit::parse(bytes)?;
                 data_units.push(du);
                 bytes = &bytes[n..];
@@ -279,7 +305,7 @@ impl<'a> CaptionManagementData<'a> {
 }
 
 impl<'a> CaptionData<'a> {
-    fn parse(mut bytes: &[u8]) -> Result<CaptionData> {
+    fn parse(mut bytes: &[u8]) -> Result<CaptionData<'_>> {
         let tmd = TMD::from(bytes[0] >> 6);
         let stm = match tmd {
             TMD::RealTime | TMD::OffsetTime => {
@@ -297,7 +323,7 @@ impl<'a> CaptionData<'a> {
         let mut data_units = Vec::new();
         {
             let mut bytes = &bytes[3..3 + data_unit_loop_length];
-            while bytes.len() > 0 {
+            while !bytes.is_empty() {
                 let (du, n) = DataUnit::parse(bytes)?;
                 data_units.push(du);
                 bytes = &bytes[n..];
@@ -312,7 +338,7 @@ impl<'a> CaptionData<'a> {
 }
 
 impl<'a> DataUnit<'a> {
-    fn parse(bytes: &[u8]) -> Result<(DataUnit, usize)> {
+    fn parse(bytes: &[u8]) -> Result<(DataUnit<'_>, usize)> {
         check_len!(bytes.len(), 5);
         let unit_separator = bytes[0];
         let data_unit_parameter = DataUnitParameter::from(bytes[1]);
@@ -331,7 +357,7 @@ impl<'a> DataUnit<'a> {
 }
 
 impl<'a> DrcsDataStructure<'a> {
-    pub fn parse(bytes: &[u8]) -> Result<DrcsDataStructure> {
+    pub fn parse(bytes: &[u8]) -> Result<DrcsDataStructure<'_>> {
         let number_of_code = bytes[0];
         let mut bytes = &bytes[1..];
         let mut codes = Vec::new();
@@ -345,12 +371,12 @@ impl<'a> DrcsDataStructure<'a> {
                 let mode = bytes[0] & 0xf;
                 if mode != 1 {
                     // TR-B14 says mode must be 0001
-                    bail!("mode must be 1, but {}", mode);
+                    return Err(Error::InvalidDrcsMode(mode));
                 }
                 let depth = bytes[1];
                 if depth != 2 {
                     // TR-B14 says depth must be 2
-                    bail!("depth must be 2, but {}", depth);
+                    return Err(Error::InvalidDrcsDepth(depth));
                 }
                 let width = bytes[2];
                 let height = bytes[3];
@@ -376,14 +402,110 @@ impl<'a> DrcsDataStructure<'a> {
     }
 }
 
-fn is_non_partial_reception_caption(component_tag: u8) -> bool {
-    match component_tag {
-        0x30..=0x3f => true,
-        _ => false,
+/// Constructs a data group of caption management data or statements of
+/// the first language in free timing, filling data_group_size and CRC_16.
+#[derive(Debug, Clone)]
+pub struct DataGroupBuilder {
+    data_group_id: u8,
+    data_group_version: u8,
+    data_group_data: BytesMut,
+}
+
+impl DataGroupBuilder {
+    /// Caption management data of a language, displayed automatically in
+    /// the 8-unit code.
+    pub fn management(iso_639_language_code: &[u8; 3]) -> DataGroupBuilder {
+        let mut data = BytesMut::new();
+        // TMD is free, and the rest are reserved.
+        data.put_u8(0x3f);
+        // num_languages
+        data.put_u8(1);
+        // language_tag 0 and DMF 0000.
+        data.put_u8(0x10);
+        data.extend_from_slice(iso_639_language_code);
+        // Format, TCS of 8-unit code and no rollup.
+        data.put_u8(FORMAT_HORIZONTAL_960X540 << 4);
+        // data_unit_loop_length
+        data.put_slice(&[0, 0, 0]);
+        DataGroupBuilder {
+            data_group_id: MANAGEMENT_DATA_GROUP_ID,
+            data_group_version: 0,
+            data_group_data: data,
+        }
+    }
+
+    /// Caption statement data with a data unit of text in the 8-unit code.
+    pub fn statement(text: &[u8]) -> Result<DataGroupBuilder> {
+        let data_unit_length = 5 + text.len();
+        if data_unit_length >= 1 << 24 {
+            return Err(Error::TooLong(data_unit_length));
+        }
+        let mut data = BytesMut::with_capacity(4 + data_unit_length);
+        // TMD is free, and the rest are reserved.
+        data.put_u8(0x3f);
+        data.put_uint(data_unit_length as u64, 3);
+        data.put_u8(UNIT_SEPARATOR);
+        data.put_u8(TEXT_DATA_UNIT);
+        data.put_uint(text.len() as u64, 3);
+        data.extend_from_slice(text);
+        Ok(DataGroupBuilder {
+            data_group_id: STATEMENT_DATA_GROUP_ID,
+            data_group_version: 0,
+            data_group_data: data,
+        })
+    }
+
+    /// Sends the data group in group B, which receivers take as a change
+    /// of the management data from group A.
+    pub fn group_b(mut self, v: bool) -> Self {
+        self.data_group_id = (self.data_group_id & !GROUP_B) | if v { GROUP_B } else { 0 };
+        self
+    }
+
+    pub fn data_group_version(mut self, v: u8) -> Self {
+        self.data_group_version = v & 0x3;
+        self
     }
+
+    /// Serializes the data group including CRC_16.
+    pub fn build(self) -> Result<Bytes> {
+        let data_group_size = self.data_group_data.len();
+        if data_group_size > usize::from(u16::MAX) {
+            return Err(Error::TooLong(data_group_size));
+        }
+        let mut out = BytesMut::with_capacity(5 + data_group_size + 2);
+        out.put_u8((self.data_group_id << 2) | self.data_group_version);
+        // data_group_link_number and last_data_group_link_number
+        out.put_slice(&[0, 0]);
+        out.put_u16(data_group_size as u16);
+        out.extend_from_slice(&self.data_group_data[..]);
+        let crc = crc16(&out[..]);
+        out.put_u16(crc);
+        Ok(out.freeze())
+    }
+}
+
+// CRC-16-CCITT of data groups.
+fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for b in data.iter() {
+        crc ^= u16::from(*b) << 8;
+        for _ in 0..8 {
+            if crc & 0x8000 != 0 {
+                crc = (crc << 1) ^ 0x1021;
+            } else {
+                crc <<= 1;
+            }
+        }
+    }
+    crc
+}
+
+fn is_non_partial_reception_caption(component_tag: u8) -> bool {
+    matches!(component_tag, 0x30..=0x3f)
 }
 
-fn is_caption_component(desc: &psi::Descriptor) -> bool {
+fn is_caption_component(desc: psi::Descriptor) -> bool {
     if let psi::Descriptor::StreamIdentifierDescriptor(sid) = desc {
         return is_non_partial_reception_caption(sid.component_tag);
     }
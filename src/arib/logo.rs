@@ -0,0 +1,103 @@
+use crate::arib::{Error, Result};
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+const CLUT_SIZE: usize = 128;
+
+/// Returns the common fixed color table of ARIB STD-B24 as RGBA.
+pub fn common_fixed_clut() -> [[u8; 4]; CLUT_SIZE] {
+    let mut clut = [[0u8; 4]; CLUT_SIZE];
+    let mut n = 0;
+    // 0-7: full intensity colors.
+    for i in 0..8u8 {
+        let level = |bit: u8| if i & bit > 0 { 255 } else { 0 };
+        clut[n] = [level(1), level(2), level(4), 255];
+        n += 1;
+    }
+    // 8: transparent.
+    clut[n] = [0, 0, 0, 0];
+    n += 1;
+    // 9-15: half intensity colors.
+    for i in 1..8u8 {
+        let level = |bit: u8| if i & bit > 0 { 170 } else { 0 };
+        clut[n] = [level(1), level(2), level(4), 255];
+        n += 1;
+    }
+    // 16-64: the other colors of 4 levels.
+    let levels = [0u8, 85, 170, 255];
+    for r in levels {
+        for g in levels {
+            for b in levels {
+                let color = [r, g, b, 255];
+                if !clut[..16].contains(&color) {
+                    clut[n] = color;
+                    n += 1;
+                }
+            }
+        }
+    }
+    // 65-127: half transparent colors of 1-64 except the transparent.
+    for i in (1..65).filter(|i| *i != 8) {
+        let [r, g, b, _] = clut[i];
+        clut[n] = [r, g, b, 128];
+        n += 1;
+    }
+    clut
+}
+
+// CRC-32 of PNG chunks, which differs from the one of MPEG-2.
+fn png_crc(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for b in bytes.iter() {
+        crc ^= u32::from(*b);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn push_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    let crc = png_crc(&out[start..]);
+    out.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// Makes a logo transmitted without PLTE and tRNS viewable, by inserting the
+/// common fixed color table before IDAT.
+pub fn logo_to_png(bytes: &[u8]) -> Result<Vec<u8>> {
+    check_len!(bytes.len(), PNG_SIGNATURE.len());
+    if bytes[..PNG_SIGNATURE.len()] != PNG_SIGNATURE {
+        return Err(Error::InvalidPng);
+    }
+    let mut out = Vec::with_capacity(bytes.len() + CLUT_SIZE * 4 + 24);
+    out.extend_from_slice(&PNG_SIGNATURE);
+    let mut rest = &bytes[PNG_SIGNATURE.len()..];
+    let mut has_palette = false;
+    while !rest.is_empty() {
+        check_len!(rest.len(), 8);
+        let length = u32::from_be_bytes([rest[0], rest[1], rest[2], rest[3]]) as usize;
+        check_len!(rest.len(), 12 + length);
+        let kind = &rest[4..8];
+        if kind == b"PLTE" {
+            has_palette = true;
+        }
+        if kind == b"IDAT" && !has_palette {
+            let clut = common_fixed_clut();
+            let plte: Vec<u8> = clut.iter().flat_map(|c| c[..3].to_vec()).collect();
+            let trns: Vec<u8> = clut.iter().map(|c| c[3]).collect();
+            push_chunk(&mut out, b"PLTE", &plte);
+            push_chunk(&mut out, b"tRNS", &trns);
+            has_palette = true;
+        }
+        out.extend_from_slice(&rest[..12 + length]);
+        rest = &rest[12 + length..];
+    }
+    Ok(out)
+}
@@ -1,3 +1,27 @@
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("too short {len}, expect {expr}({expected})")]
+    TooShort {
+        len: usize,
+        expected: usize,
+        expr: &'static str,
+    },
+    #[error("drcs mode must be 1, but {0}")]
+    InvalidDrcsMode(u8),
+    #[error("drcs depth must be 2, but {0}")]
+    InvalidDrcsDepth(u8),
+    #[error("invalid string: {0}")]
+    InvalidString(#[from] std::string::FromUtf8Error),
+    #[error("invalid png")]
+    InvalidPng,
+    #[error("too long {0}")]
+    TooLong(usize),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
 pub mod caption;
+pub mod logo;
+pub mod normalize;
 pub mod pes;
 pub mod string;
@@ -0,0 +1,179 @@
+//! Normalization of decoded ARIB strings, which mix full-width and
+//! half-width forms and enclosed symbols freely.
+//!
+//! There is no Unicode database here, so NFKC covers the compatibility
+//! characters that ARIB strings decode to: full-width and half-width forms,
+//! enclosed numbers and ideographs, roman numerals and the ARIB symbols.
+
+const PARENTHESIZED_IDEOGRAPHS: &str =
+    "一二三四五六七八九十月火水木金土日株有社名特財祝労代呼学監企資協祭休自至";
+const CIRCLED_POSITIONS: &str = "上中下左右";
+const SQUARED_IDEOGRAPHS: &str =
+    "手字双デ二多解天交映無料前後再新初終生販声吹演投捕一三遊左中右指走打禁空合満有月申割営配";
+const BRACKETED_IDEOGRAPHS: &str = "本三二安点打盗勝敗";
+const ROMAN_NUMERALS: [&str; 16] = [
+    "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI", "XII", "L", "C", "D", "M",
+];
+// half-width katakana from U+FF66.
+const HALF_WIDTH_KATAKANA: &str =
+    "ヲァィゥェォャュョッーアイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワン";
+const VOICED_SOUND_MARK: char = '\u{3099}';
+const SEMI_VOICED_SOUND_MARK: char = '\u{309a}';
+
+/// Normalizations applied to decoded strings, none of them by default.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Normalizer {
+    /// Full-width alphanumerics into ASCII.
+    pub half_width_alphanumerics: bool,
+    /// Compatibility characters into their canonical forms, like NFKC.
+    pub nfkc: bool,
+    /// FULLWIDTH TILDE into WAVE DASH, which decoders disagree on.
+    pub wave_dash: bool,
+}
+
+impl Normalizer {
+    pub fn is_noop(&self) -> bool {
+        !(self.half_width_alphanumerics || self.nfkc || self.wave_dash)
+    }
+
+    pub fn normalize(&self, s: &str) -> String {
+        if self.is_noop() {
+            return String::from(s);
+        }
+        let mut out = String::with_capacity(s.len());
+        for c in s.chars() {
+            // the wave dash is unified first, not to be turned into ASCII.
+            let c = match c {
+                '\u{ff5e}' if self.wave_dash => '\u{301c}',
+                c => c,
+            };
+            if self.nfkc {
+                push_compatibility(c, &mut out);
+            } else if self.half_width_alphanumerics {
+                out.push(half_width_alphanumeric(c).unwrap_or(c));
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+}
+
+fn half_width_alphanumeric(c: char) -> Option<char> {
+    match c {
+        '０'..='９' | 'Ａ'..='Ｚ' | 'ａ'..='ｚ' => char::from_u32(u32::from(c) - 0xfee0),
+        _ => None,
+    }
+}
+
+fn nth(table: &str, n: u32) -> Option<char> {
+    table.chars().nth(n as usize)
+}
+
+// composes a voiced or semi-voiced sound mark with the preceding kana.
+fn compose(out: &mut String, mark: char) {
+    let last = match out.chars().last() {
+        Some(c) => u32::from(c),
+        None => {
+            out.push(mark);
+            return;
+        }
+    };
+    // hiragana are placed 0x60 before the katakana.
+    let katakana = match last {
+        0x3041..=0x3096 => last + 0x60,
+        _ => last,
+    };
+    let composed = match (katakana, mark) {
+        (0x30ab..=0x30c1, VOICED_SOUND_MARK) if katakana % 2 == 1 => Some(last + 1),
+        (0x30c4..=0x30c8, VOICED_SOUND_MARK) if katakana % 2 == 0 => Some(last + 1),
+        (0x30cf..=0x30db, VOICED_SOUND_MARK) if (katakana - 0x30cf) % 3 == 0 => Some(last + 1),
+        (0x30cf..=0x30db, SEMI_VOICED_SOUND_MARK) if (katakana - 0x30cf) % 3 == 0 => Some(last + 2),
+        (0x30a6, VOICED_SOUND_MARK) => Some(last + 0x4e),
+        (0x30ef..=0x30f2, VOICED_SOUND_MARK) if last == katakana => Some(last + 8),
+        _ => None,
+    };
+    match composed.and_then(char::from_u32) {
+        Some(c) => {
+            out.pop();
+            out.push(c);
+        }
+        None => out.push(mark),
+    }
+}
+
+fn push_compatibility(c: char, out: &mut String) {
+    let code = u32::from(c);
+    match code {
+        0xa0 | 0x2000..=0x200a | 0x3000 => out.push(' '),
+        0xff01..=0xff5e => out.extend(char::from_u32(code - 0xfee0)),
+        0xff5f => out.push('⦅'),
+        0xff60 => out.push('⦆'),
+        0xff61 => out.push('。'),
+        0xff62 => out.push('「'),
+        0xff63 => out.push('」'),
+        0xff64 => out.push('、'),
+        0xff65 => out.push('・'),
+        0xff66..=0xff9d => out.extend(nth(HALF_WIDTH_KATAKANA, code - 0xff66)),
+        0xff9e => compose(out, VOICED_SOUND_MARK),
+        0xff9f => compose(out, SEMI_VOICED_SOUND_MARK),
+        0xffe0 => out.push('¢'),
+        0xffe1 => out.push('£'),
+        0xffe2 => out.push('¬'),
+        0xffe4 => out.push('¦'),
+        0xffe5 => out.push('¥'),
+        0xffe6 => out.push('₩'),
+        0x2116 => out.push_str("No"),
+        0x2121 => out.push_str("TEL"),
+        0x2122 => out.push_str("TM"),
+        0x213b => out.push_str("FAX"),
+        0x2160..=0x216f => out.push_str(ROMAN_NUMERALS[(code - 0x2160) as usize]),
+        0x2170..=0x217f => {
+            out.push_str(&ROMAN_NUMERALS[(code - 0x2170) as usize].to_ascii_lowercase())
+        }
+        0x2460..=0x2473 => out.push_str(&(code - 0x2460 + 1).to_string()),
+        0x2474..=0x2487 => out.push_str(&format!("({})", code - 0x2474 + 1)),
+        0x2488..=0x249b => out.push_str(&format!("{}.", code - 0x2488 + 1)),
+        0x3220..=0x3243 => {
+            out.push('(');
+            out.extend(nth(PARENTHESIZED_IDEOGRAPHS, code - 0x3220));
+            out.push(')');
+        }
+        0x3280..=0x3289 => out.extend(nth(PARENTHESIZED_IDEOGRAPHS, code - 0x3280)),
+        0x32a4..=0x32a8 => out.extend(nth(CIRCLED_POSITIONS, code - 0x32a4)),
+        0x33cd => out.push_str("KK"),
+        0x1f100 => out.push_str("0."),
+        0x1f101..=0x1f10a => out.push_str(&format!("{},", code - 0x1f101)),
+        0x1f110..=0x1f129 => {
+            out.push('(');
+            out.extend(char::from_u32(code - 0x1f110 + u32::from(b'A')));
+            out.push(')');
+        }
+        0x1f12a => out.push_str("〔S〕"),
+        0x1f12b => out.push('C'),
+        0x1f12c => out.push('R'),
+        0x1f12d => out.push_str("CD"),
+        0x1f12e => out.push_str("WZ"),
+        0x1f130..=0x1f149 => out.extend(char::from_u32(code - 0x1f130 + u32::from(b'A'))),
+        0x1f14a => out.push_str("HV"),
+        0x1f14b => out.push_str("MV"),
+        0x1f14c => out.push_str("SD"),
+        0x1f14d => out.push_str("SS"),
+        0x1f14e => out.push_str("PPV"),
+        0x1f14f => out.push_str("WC"),
+        0x1f16a => out.push_str("MC"),
+        0x1f16b => out.push_str("MD"),
+        0x1f190 => out.push_str("DJ"),
+        0x1f200 => out.push_str("ほか"),
+        0x1f201 => out.push_str("ココ"),
+        0x1f202 => out.push('サ'),
+        0x1f210..=0x1f23b => out.extend(nth(SQUARED_IDEOGRAPHS, code - 0x1f210)),
+        0x1f240..=0x1f248 => {
+            out.push('〔');
+            out.extend(nth(BRACKETED_IDEOGRAPHS, code - 0x1f240));
+            out.push('〕');
+        }
+        0x3099 | 0x309a => compose(out, c),
+        _ => out.push(c),
+    }
+}
@@ -1,8 +1,9 @@
-use anyhow::Result;
+use crate::arib::Result;
 
 pub const SYNCHRONIZED_PES_STREAM_ID: u8 = 0xbd;
 pub const ASYNCHRONOUS_PES_STREAM_ID: u8 = 0xbf;
 
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct SynchronizedPESData<'a> {
     pub data_identifier: u8,
     pub private_stream_id: u8,
@@ -11,7 +12,7 @@ pub struct SynchronizedPESData<'a> {
 }
 
 impl<'a> SynchronizedPESData<'a> {
-    pub fn parse(bytes: &[u8]) -> Result<SynchronizedPESData> {
+    pub fn parse(bytes: &[u8]) -> Result<SynchronizedPESData<'_>> {
         let data_identifier = bytes[0];
         let private_stream_id = bytes[1];
         let pes_data_packet_header_length = usize::from(bytes[2] & 0xf);
@@ -26,6 +27,7 @@ impl<'a> SynchronizedPESData<'a> {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct AsynchronousPESData<'a> {
     pub data_identifier: u8,
     pub private_stream_id: u8,
@@ -34,7 +36,7 @@ pub struct AsynchronousPESData<'a> {
 }
 
 impl<'a> AsynchronousPESData<'a> {
-    pub fn parse(bytes: &[u8]) -> Result<AsynchronousPESData> {
+    pub fn parse(bytes: &[u8]) -> Result<AsynchronousPESData<'_>> {
         let data_identifier = bytes[0];
         let private_stream_id = bytes[1];
         let pes_data_packet_header_length = usize::from(bytes[2] & 0xf);
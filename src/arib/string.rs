@@ -1,9 +1,8 @@
 use std::char;
 use std::collections::HashMap;
+use std::iter::Peekable;
 
-use anyhow::Result;
 use log::trace;
-use thiserror;
 
 #[derive(Debug)]
 enum Charset {
@@ -22,35 +21,30 @@ enum Charset {
     JISGokanKanji1,
     JISGokanKanji2,
     Symbol,
+    #[allow(clippy::upper_case_acronyms)]
     DRCS(u8),
     Macro,
 }
 
-enum DesignatePos {
-    G0 = 0,
-    G1 = 1,
-    G2 = 2,
-    G3 = 3,
-}
-
-enum InvokePos {
-    GL,
-    GR,
-}
-
-trait State {
-    fn designate(&mut self, dst: DesignatePos, cs: Charset);
-    fn lock(&mut self, dst: InvokePos, src: DesignatePos);
-    fn single(&mut self, src: DesignatePos);
+// pushes an additional symbol, some of which are sequences of characters.
+fn push_symbol(cp: u16, out: &mut String, charset: &str) -> Result<()> {
+    match arib_symbols::code_point_to_char(cp) {
+        Some(c) => out.push(c),
+        None => out.push_str(
+            arib_symbols::code_point_to_str(cp)
+                .ok_or(Error::UnknownCodepoint(cp as u32, String::from(charset)))?,
+        ),
+    }
+    Ok(())
 }
 
 impl Charset {
-    fn decode<I: Iterator<Item = u8>, S: State>(
+    fn decode<I: Iterator<Item = u8>>(
         &self,
         iter: &mut I,
         out: &mut String,
         drcs_map: &HashMap<u16, String>,
-        state: &mut S,
+        mosaic_placeholder: Option<char>,
     ) -> Result<()> {
         macro_rules! next {
             () => {
@@ -66,9 +60,7 @@ impl Charset {
                         .ok_or(Error::UnknownCodepoint(code_point, String::from("kanji")))?;
                     out.extend(chars);
                 } else {
-                    out.push(arib_symbols::code_point_to_char(code_point).ok_or(
-                        Error::UnknownCodepoint(code_point as u32, String::from("kanji")),
-                    )?);
+                    push_symbol(code_point, out, "kanji")?;
                 }
             }
             Charset::JISGokanKanji1 => {
@@ -110,7 +102,11 @@ impl Charset {
                 out.push(unsafe { char::from_u32_unchecked(c) });
             }
             Charset::MosaicA | Charset::MosaicB | Charset::MosaicC | Charset::MosaicD => {
-                return Err(Error::UnimplementedCharset(String::from("mosaic")).into());
+                let b = next!();
+                match mosaic_placeholder {
+                    Some(c) => out.push(c),
+                    None => out.push(mosaic_to_char(b)),
+                }
             }
             Charset::JISX0201 => {
                 let c = 0xff61 + u32::from(next!()) - 0x21;
@@ -124,10 +120,7 @@ impl Charset {
             }
             Charset::Symbol => {
                 let cp = (u16::from(next!()) << 8) | u16::from(next!());
-                out.push(
-                    arib_symbols::code_point_to_char(cp)
-                        .ok_or(Error::UnknownCodepoint(cp as u32, String::from("symbol")))?,
-                );
+                push_symbol(cp, out, "symbol")?;
             }
             Charset::DRCS(n) => {
                 let cc = if *n == 0 {
@@ -138,51 +131,200 @@ impl Charset {
                 match drcs_map.get(&cc) {
                     Some(s) => out.push_str(s),
                     None => {
-                        return Err(
-                            Error::UnknownCodepoint(cc as u32, format!("drcs({})", *n)).into()
-                        );
+                        return Err(Error::UnknownCodepoint(cc as u32, format!("drcs({})", *n)));
                     }
                 }
             }
             Charset::Macro => {
+                // macros are invoked by AribDecoder, and never decoded as
+                // characters.
                 let n = next!();
-                match n {
-                    0x60 => {
-                        state.designate(DesignatePos::G0, Charset::Kanji);
-                        state.designate(DesignatePos::G1, Charset::Alnum);
-                        state.designate(DesignatePos::G2, Charset::Hiragana);
-                        state.designate(DesignatePos::G3, Charset::Macro);
-                        state.lock(InvokePos::GL, DesignatePos::G0);
-                        state.lock(InvokePos::GR, DesignatePos::G2);
-                    }
-                    0x61 => {
-                        state.designate(DesignatePos::G0, Charset::Kanji);
-                        state.designate(DesignatePos::G1, Charset::Katakana);
-                        state.designate(DesignatePos::G2, Charset::Hiragana);
-                        state.designate(DesignatePos::G3, Charset::Macro);
-                        state.lock(InvokePos::GL, DesignatePos::G0);
-                        state.lock(InvokePos::GR, DesignatePos::G2);
-                    }
-                    _ => {
-                        return Err(Error::UnknownCodepoint(n as u32, String::from("macro")).into());
-                    }
-                }
+                return Err(Error::UnknownCodepoint(u32::from(n), String::from("macro")));
             }
         }
         Ok(())
     }
 }
 
+// Mosaic characters are 2x3 blocks laid out as in teletext: b1..b5 are the
+// lower five bits and b6 is bit 6, from top-left to bottom-right.
+// Columns 4 and 5 (0x40..0x5f) are not mosaics and are rendered as is.
+fn mosaic_to_char(b: u8) -> char {
+    if (0x40..=0x5f).contains(&b) {
+        return char::from(b);
+    }
+    let v = u32::from(b & 0x1f) | u32::from((b & 0x40) >> 1);
+    match v {
+        0 => ' ',
+        0b010101 => '\u{258c}',
+        0b101010 => '\u{2590}',
+        0b111111 => '\u{2588}',
+        v => {
+            // Symbols for Legacy Computing skips the 4 sextants above.
+            let skip = u32::from(v > 0b010101) + u32::from(v > 0b101010);
+            char::from_u32(0x1fb00 + v - 1 - skip).unwrap()
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
-enum Error {
-    #[error("unknown code point: 0x{0:x} in {0:}")]
+pub enum Error {
+    #[error("unknown code point: 0x{0:x} in {1}")]
     UnknownCodepoint(u32, String),
-    #[error("unimplemented charset: {0:}")]
-    UnimplementedCharset(String),
     #[error("unimplemented control: 0x{0:x}")]
     UnimplementedControl(u8),
     #[error("malformed short bytes")]
     MalformedShortBytes,
+    #[error("macro nested too deeply")]
+    MacroTooDeep,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Character size selected by SSZ/MSZ/NSZ or SZX.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub enum CharSize {
+    Small,
+    Middle,
+    Normal,
+    Tiny,
+    DoubleHeight,
+    DoubleWidth,
+    DoubleSize,
+}
+
+/// Active position set by APS (character cell) or CSI ACPS (dots).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub enum Position {
+    Cell { row: u8, column: u8 },
+    Dot { x: u16, y: u16 },
+}
+
+/// A run of text decoded under the same presentation state.
+///
+/// `foreground` is a CLUT index; BKF..WHF select 0..7.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct Span {
+    pub text: String,
+    pub foreground: u8,
+    pub size: CharSize,
+    pub position: Option<Position>,
+}
+
+impl Span {
+    fn new() -> Span {
+        Span {
+            text: String::new(),
+            // white is the initial foreground color in captions.
+            foreground: 7,
+            size: CharSize::Normal,
+            position: None,
+        }
+    }
+}
+
+struct SpanWriter {
+    spans: Vec<Span>,
+    current: Span,
+}
+
+impl SpanWriter {
+    fn new() -> Self {
+        SpanWriter {
+            spans: Vec::new(),
+            current: Span::new(),
+        }
+    }
+
+    fn text(&mut self) -> &mut String {
+        &mut self.current.text
+    }
+
+    fn push(&mut self, c: char) {
+        self.current.text.push(c);
+    }
+
+    // starts a new span unless nothing has been written with the current state.
+    fn modify<F: FnOnce(&mut Span)>(&mut self, f: F) {
+        if !self.current.text.is_empty() {
+            let mut next = self.current.clone();
+            next.text.clear();
+            self.spans.push(std::mem::replace(&mut self.current, next));
+        }
+        f(&mut self.current);
+    }
+
+    fn set_foreground(&mut self, foreground: u8) {
+        self.modify(|span| span.foreground = foreground);
+    }
+
+    fn set_size(&mut self, size: CharSize) {
+        self.modify(|span| span.size = size);
+    }
+
+    fn set_position(&mut self, position: Position) {
+        self.modify(|span| span.position = Some(position));
+    }
+
+    fn into_spans(mut self) -> Vec<Span> {
+        if !self.current.text.is_empty() {
+            self.spans.push(self.current);
+        }
+        self.spans
+    }
+}
+
+// the display format and the horizontal position in a line, in dots, which
+// tell how many characters RPC repeats to the end of the line.
+struct Layout {
+    display_x: u16,
+    display_width: u16,
+    char_width: u16,
+    spacing: u16,
+    x: u16,
+}
+
+impl Default for Layout {
+    // the format of 960x540 captions in ARIB TR-B14.
+    fn default() -> Layout {
+        Layout {
+            display_x: 0,
+            display_width: 960,
+            char_width: 36,
+            spacing: 4,
+            x: 0,
+        }
+    }
+}
+
+impl Layout {
+    // width of a character cell of the size.
+    fn cell(&self, size: CharSize) -> u16 {
+        let cell = self.char_width + self.spacing;
+        match size {
+            CharSize::Small | CharSize::Middle | CharSize::Tiny => cell / 2,
+            CharSize::DoubleWidth | CharSize::DoubleSize => cell * 2,
+            CharSize::Normal | CharSize::DoubleHeight => cell,
+        }
+        .max(1)
+    }
+
+    fn forward(&mut self, size: CharSize, n: usize) {
+        let n = u16::try_from(n).unwrap_or(u16::MAX);
+        self.x = self.x.saturating_add(self.cell(size).saturating_mul(n));
+    }
+
+    fn backward(&mut self, size: CharSize) {
+        self.x = self.x.saturating_sub(self.cell(size));
+    }
+
+    // characters of the size fitting from the position to the end of the line.
+    fn remaining(&self, size: CharSize) -> usize {
+        usize::from(self.display_width.saturating_sub(self.x) / self.cell(size))
+    }
 }
 
 pub struct AribDecoder {
@@ -191,8 +333,16 @@ pub struct AribDecoder {
     gr: usize,
     g: [Charset; 4],
     drcs_map: HashMap<u16, String>,
+    mosaic_placeholder: Option<char>,
+    macros: HashMap<u8, Vec<u8>>,
+    macro_depth: usize,
+    // 0 repeats to the end of the line.
+    repeat: Option<usize>,
+    layout: Layout,
 }
 
+const MAX_MACRO_DEPTH: usize = 16;
+
 // escape sequence
 const LS2: u8 = 0x6e;
 const LS3: u8 = 0x6f;
@@ -249,39 +399,34 @@ const STL: u8 = 0x9a;
 const CSI: u8 = 0x9b;
 const TIME: u8 = 0x9d;
 
-struct StateModification {
-    single: Option<usize>,
-    gl: Option<usize>,
-    gr: Option<usize>,
-    g: [Option<Charset>; 4],
-}
-
-impl StateModification {
-    fn new() -> Self {
-        StateModification {
-            single: None,
-            gl: None,
-            gr: None,
-            g: [None, None, None, None],
-        }
-    }
-}
+// MACRO parameters
+const MACRO_DEFINE: u8 = 0x40;
+const MACRO_DEFINE_AND_EXECUTE: u8 = 0x41;
+const MACRO_END: u8 = 0x4f;
 
-impl State for StateModification {
-    fn designate(&mut self, dst: DesignatePos, cs: Charset) {
-        self.g[dst as usize] = Some(cs);
-    }
+// CSI final bytes
+const SDF: u8 = 0x56;
+const SSM: u8 = 0x57;
+const SHS: u8 = 0x58;
+const SDP: u8 = 0x5f;
+const ACPS: u8 = 0x61;
 
-    fn lock(&mut self, dst: InvokePos, src: DesignatePos) {
-        match dst {
-            InvokePos::GL => self.gl = Some(src as usize),
-            InvokePos::GR => self.gr = Some(src as usize),
+// splits "P1;P2;...Pn 0x20 F" into the final byte and numeric parameters.
+fn parse_csi(seq: &[u8]) -> Option<(u8, Vec<u16>)> {
+    let (&f, rest) = seq.split_last()?;
+    let rest = rest.strip_suffix(&[0x20])?;
+    let mut params = Vec::new();
+    for p in rest.split(|&c| c == 0x3b) {
+        let mut n = 0u16;
+        for &c in p {
+            if !c.is_ascii_digit() {
+                return None;
+            }
+            n = n.saturating_mul(10).saturating_add(u16::from(c - b'0'));
         }
+        params.push(n);
     }
-
-    fn single(&mut self, src: DesignatePos) {
-        self.single = Some(src as usize)
-    }
+    Some((f, params))
 }
 
 fn is_control(b: u8) -> bool {
@@ -310,6 +455,31 @@ fn g_set_from_termination(f: u8) -> Charset {
     }
 }
 
+// default macros defined in STD-B24 table 7-20.
+#[rustfmt::skip]
+fn default_macro(n: u8) -> Option<&'static [u8]> {
+    let body: &[u8] = match n {
+        0x60 => &[ESC, 0x24, 0x42, ESC, 0x29, 0x4a, ESC, 0x2a, 0x30, ESC, 0x2b, 0x20, 0x70, LS0, ESC, LS2R],
+        0x61 => &[ESC, 0x24, 0x42, ESC, 0x29, 0x31, ESC, 0x2a, 0x30, ESC, 0x2b, 0x20, 0x70, LS0, ESC, LS2R],
+        0x62 => &[ESC, 0x24, 0x42, ESC, 0x29, 0x20, 0x41, ESC, 0x2a, 0x30, ESC, 0x2b, 0x20, 0x70, LS0, ESC, LS2R],
+        0x63 => &[ESC, 0x28, 0x32, ESC, 0x29, 0x34, ESC, 0x2a, 0x35, ESC, 0x2b, 0x20, 0x70, LS0, ESC, LS2R],
+        0x64 => &[ESC, 0x28, 0x32, ESC, 0x29, 0x33, ESC, 0x2a, 0x35, ESC, 0x2b, 0x20, 0x70, LS0, ESC, LS2R],
+        0x65 => &[ESC, 0x28, 0x32, ESC, 0x29, 0x20, 0x41, ESC, 0x2a, 0x35, ESC, 0x2b, 0x20, 0x70, LS0, ESC, LS2R],
+        0x66 => &[ESC, 0x28, 0x20, 0x41, ESC, 0x29, 0x20, 0x42, ESC, 0x2a, 0x20, 0x43, ESC, 0x2b, 0x20, 0x70, LS0, ESC, LS2R],
+        0x67 => &[ESC, 0x28, 0x20, 0x44, ESC, 0x29, 0x20, 0x45, ESC, 0x2a, 0x20, 0x46, ESC, 0x2b, 0x20, 0x70, LS0, ESC, LS2R],
+        0x68 => &[ESC, 0x28, 0x20, 0x47, ESC, 0x29, 0x20, 0x48, ESC, 0x2a, 0x20, 0x49, ESC, 0x2b, 0x20, 0x70, LS0, ESC, LS2R],
+        0x69 => &[ESC, 0x28, 0x20, 0x4a, ESC, 0x29, 0x20, 0x4b, ESC, 0x2a, 0x20, 0x4c, ESC, 0x2b, 0x20, 0x70, LS0, ESC, LS2R],
+        0x6a => &[ESC, 0x28, 0x20, 0x4d, ESC, 0x29, 0x20, 0x4e, ESC, 0x2a, 0x20, 0x4f, ESC, 0x2b, 0x20, 0x70, LS0, ESC, LS2R],
+        0x6b => &[ESC, 0x24, 0x42, ESC, 0x29, 0x20, 0x42, ESC, 0x2a, 0x30, ESC, 0x2b, 0x20, 0x70, LS0, ESC, LS2R],
+        0x6c => &[ESC, 0x24, 0x42, ESC, 0x29, 0x20, 0x43, ESC, 0x2a, 0x30, ESC, 0x2b, 0x20, 0x70, LS0, ESC, LS2R],
+        0x6d => &[ESC, 0x24, 0x42, ESC, 0x29, 0x20, 0x44, ESC, 0x2a, 0x30, ESC, 0x2b, 0x20, 0x70, LS0, ESC, LS2R],
+        0x6e => &[ESC, 0x28, 0x31, ESC, 0x29, 0x30, ESC, 0x2a, 0x4a, ESC, 0x2b, 0x20, 0x70, LS0, ESC, LS2R],
+        0x6f => &[ESC, 0x28, 0x4a, ESC, 0x29, 0x32, ESC, 0x2a, 0x20, 0x41, ESC, 0x2b, 0x20, 0x70, LS0, ESC, LS2R],
+        _ => return None,
+    };
+    Some(body)
+}
+
 fn drcs_from_termination(f: u8) -> Charset {
     match f {
         0x40..=0x4f => Charset::DRCS(f - 0x40),
@@ -331,6 +501,11 @@ impl AribDecoder {
                 Charset::Katakana,
             ],
             drcs_map: HashMap::new(),
+            mosaic_placeholder: None,
+            macros: HashMap::new(),
+            macro_depth: 0,
+            repeat: None,
+            layout: Layout::default(),
         }
     }
 
@@ -346,6 +521,33 @@ impl AribDecoder {
                 Charset::Macro,
             ],
             drcs_map: HashMap::new(),
+            mosaic_placeholder: None,
+            macros: HashMap::new(),
+            macro_depth: 0,
+            repeat: None,
+            layout: Layout::default(),
+        }
+    }
+
+    /// Decodes BML documents of data broadcasting, which are EUC-JP: ASCII
+    /// in GL and kanji in GR. Single shifts of EUC-JP are not supported.
+    pub fn with_bml_initialization() -> AribDecoder {
+        AribDecoder {
+            single: None,
+            gl: 0,
+            gr: 1,
+            g: [
+                Charset::Alnum,
+                Charset::Kanji,
+                Charset::Hiragana,
+                Charset::Katakana,
+            ],
+            drcs_map: HashMap::new(),
+            mosaic_placeholder: None,
+            macros: HashMap::new(),
+            macro_depth: 0,
+            repeat: None,
+            layout: Layout::default(),
         }
     }
 
@@ -353,54 +555,94 @@ impl AribDecoder {
         self.drcs_map = drcs_map;
     }
 
-    pub fn decode<'a, I: Iterator<Item = &'a u8>>(mut self, iter: I) -> Result<String> {
-        let mut iter = iter.cloned().peekable();
-        let mut string = String::new();
+    /// Replaces every mosaic character with `placeholder` instead of block elements.
+    pub fn set_mosaic_placeholder(&mut self, placeholder: char) {
+        self.mosaic_placeholder = Some(placeholder);
+    }
+
+    pub fn decode<'a, I: Iterator<Item = &'a u8>>(self, iter: I) -> Result<String> {
+        let spans = self.decode_spans(iter)?;
+        Ok(spans.into_iter().map(|span| span.text).collect())
+    }
+
+    /// Decodes into spans, splitting the text whenever color, size or position changes.
+    pub fn decode_spans<'a, I: Iterator<Item = &'a u8>>(mut self, iter: I) -> Result<Vec<Span>> {
+        let mut out = SpanWriter::new();
+        self.decode_into(&mut iter.cloned().peekable(), &mut out)?;
+        Ok(out.into_spans())
+    }
+
+    fn decode_into<I: Iterator<Item = u8>>(
+        &mut self,
+        iter: &mut Peekable<I>,
+        out: &mut SpanWriter,
+    ) -> Result<()> {
         while let Some(&b) = iter.peek() {
+            let start = out.text().len();
+            let repeat = self.repeat;
             if is_control(b) {
-                self.control(&mut iter, &mut string)?
+                self.control(iter, out)?
             } else {
-                let charset = if b < 0x80 {
-                    match self.single {
-                        Some(pos) => {
-                            self.single = None;
-                            &self.g[pos]
-                        }
-                        None => &self.g[self.gl],
-                    }
+                let pos = if b < 0x80 {
+                    self.single.take().unwrap_or(self.gl)
                 } else {
-                    &self.g[self.gr]
+                    self.gr
                 };
-                let mut iter = (&mut iter).map(move |x| x & 0x7f);
-                let mut modification = StateModification::new();
-                charset.decode(&mut iter, &mut string, &self.drcs_map, &mut modification)?;
-                self.apply(modification);
+                if let Charset::Macro = self.g[pos] {
+                    iter.next();
+                    self.invoke_macro(b & 0x7f, out)?;
+                    continue;
+                }
+                let mut iter = (&mut *iter).map(move |x| x & 0x7f);
+                self.g[pos].decode(
+                    &mut iter,
+                    out.text(),
+                    &self.drcs_map,
+                    self.mosaic_placeholder,
+                )?;
+                self.layout.forward(out.current.size, 1);
+            }
+            if let Some(n) = repeat {
+                if !is_control(b) || b == SP {
+                    self.repeat = None;
+                    let n = match n {
+                        // the character written is counted in.
+                        0 => self.layout.remaining(out.current.size) + 1,
+                        n => n,
+                    };
+                    let c = out.text()[start..].to_string();
+                    for _ in 1..n {
+                        out.text().push_str(&c);
+                    }
+                    self.layout.forward(out.current.size, n.saturating_sub(1));
+                }
             }
         }
-        Ok(string)
+        Ok(())
     }
 
-    fn apply(&mut self, mut modification: StateModification) {
-        if modification.single.is_some() {
-            self.single = modification.single;
-        }
-        match modification.gl {
-            Some(gl) => self.gl = gl,
-            None => {}
-        }
-        match modification.gr {
-            Some(gr) => self.gr = gr,
-            None => {}
-        }
-        for i in 0..4 {
-            match modification.g[i].take() {
-                Some(cs) => self.g[i] = cs,
-                None => {}
-            }
+    fn invoke_macro(&mut self, n: u8, out: &mut SpanWriter) -> Result<()> {
+        if self.macro_depth >= MAX_MACRO_DEPTH {
+            return Err(Error::MacroTooDeep);
         }
+        let body = match self.macros.get(&n) {
+            Some(body) => body.clone(),
+            None => default_macro(n)
+                .ok_or(Error::UnknownCodepoint(u32::from(n), String::from("macro")))?
+                .to_vec(),
+        };
+        trace!("invoke macro 0x{:x}: {:?}", n, body);
+        self.macro_depth += 1;
+        let result = self.decode_into(&mut body.into_iter().peekable(), out);
+        self.macro_depth -= 1;
+        result
     }
 
-    fn control<I: Iterator<Item = u8>>(&mut self, s: &mut I, out: &mut String) -> Result<()> {
+    fn control<I: Iterator<Item = u8>>(
+        &mut self,
+        s: &mut Peekable<I>,
+        out: &mut SpanWriter,
+    ) -> Result<()> {
         macro_rules! next {
             () => {
                 s.next().ok_or(Error::MalformedShortBytes)?
@@ -495,11 +737,13 @@ impl AribDecoder {
             APB => {
                 // retract cursor
                 out.push('\x08');
+                self.layout.backward(out.current.size);
             }
             APF => {
                 trace!("APF");
                 // advance cursor
                 out.push('\t');
+                self.layout.forward(out.current.size, 1);
             }
             APD => {
                 // down cursor
@@ -511,6 +755,7 @@ impl AribDecoder {
             }
             APR => {
                 out.push('\r');
+                self.layout.x = 0;
             }
             PAPF => {
                 let x = next!();
@@ -518,16 +763,20 @@ impl AribDecoder {
                 for _ in 0..x {
                     out.push('\t');
                 }
+                self.layout.forward(out.current.size, usize::from(x & 0x3f));
             }
             APS => {
-                let x = next!();
-                let y = next!();
-                trace!("APS {} {}", x, y);
-                // todo
+                let row = next!() & 0x3f;
+                let column = next!() & 0x3f;
+                trace!("APS {} {}", row, column);
                 out.push('\n');
+                out.set_position(Position::Cell { row, column });
+                self.layout.x = 0;
+                self.layout.forward(out.current.size, usize::from(column));
             }
             CS => {
                 trace!("clear display");
+                self.layout.x = 0;
             }
             CAN => {
                 trace!("cancel");
@@ -538,7 +787,10 @@ impl AribDecoder {
             US => {
                 trace!("begin data unit");
             }
-            SP => out.push(' '),
+            SP => {
+                out.push(' ');
+                self.layout.forward(out.current.size, 1);
+            }
             DEL => {
                 trace!("del");
             }
@@ -546,10 +798,19 @@ impl AribDecoder {
             // C1
             BKF | RDF | GRF | YLF | BLF | MGF | CNF | WHF => {
                 trace!("color: {}", s0);
+                out.set_foreground(s0 - BKF);
             }
             COL => {
                 let param = param1or2!();
                 trace!("COL {:?}", param);
+                // 0x4X designates a foreground color, 0x20 P2 sets the palette.
+                if param.len() == 1 && param[0] & 0x70 == 0x40 {
+                    let palette = out.current.foreground & 0xf0;
+                    out.set_foreground(palette | (param[0] & 0xf));
+                } else if param.len() == 2 {
+                    let palette = (param[1] & 0xf) << 4;
+                    out.set_foreground(palette | (out.current.foreground & 0xf));
+                }
             }
             POL => {
                 let param = next!();
@@ -557,10 +818,22 @@ impl AribDecoder {
             }
             SSZ | MSZ | NSZ => {
                 trace!("font size: {}", s0);
+                out.set_size(match s0 {
+                    SSZ => CharSize::Small,
+                    MSZ => CharSize::Middle,
+                    _ => CharSize::Normal,
+                });
             }
             SZX => {
                 let param = next!();
                 trace!("font size param: {}", param);
+                match param {
+                    0x60 => out.set_size(CharSize::Tiny),
+                    0x41 => out.set_size(CharSize::DoubleHeight),
+                    0x44 => out.set_size(CharSize::DoubleWidth),
+                    0x45 => out.set_size(CharSize::DoubleSize),
+                    _ => {}
+                }
             }
             FLC => {
                 let param = next!();
@@ -594,10 +867,32 @@ impl AribDecoder {
                 trace!("TIME {:?}", seq);
             }
             MACRO => {
-                return Err(Error::UnimplementedControl(s0).into());
+                let p1 = next!();
+                match p1 {
+                    MACRO_DEFINE | MACRO_DEFINE_AND_EXECUTE => {
+                        let n = next!() & 0x7f;
+                        let mut body = Vec::new();
+                        loop {
+                            let c = next!();
+                            if c == MACRO && s.peek() == Some(&MACRO_END) {
+                                s.next();
+                                break;
+                            }
+                            body.push(c);
+                        }
+                        trace!("define macro 0x{:x}: {:?}", n, body);
+                        self.macros.insert(n, body);
+                        if p1 == MACRO_DEFINE_AND_EXECUTE {
+                            self.invoke_macro(n, out)?;
+                        }
+                    }
+                    _ => return Err(Error::UnimplementedControl(s0)),
+                }
             }
             RPC => {
-                return Err(Error::UnimplementedControl(s0).into());
+                let p1 = next!();
+                trace!("RPC {}", p1);
+                self.repeat = Some(usize::from(p1 & 0x3f));
             }
             STL => {
                 trace!("STL");
@@ -619,6 +914,28 @@ impl AribDecoder {
                     }
                 }
                 trace!("CSI {:?}", seq);
+                match parse_csi(&seq) {
+                    Some((ACPS, params)) if params.len() == 2 => {
+                        out.set_position(Position::Dot {
+                            x: params[0],
+                            y: params[1],
+                        });
+                        self.layout.x = params[0].saturating_sub(self.layout.display_x);
+                    }
+                    Some((SDF, params)) if !params.is_empty() => {
+                        self.layout.display_width = params[0];
+                    }
+                    Some((SDP, params)) if !params.is_empty() => {
+                        self.layout.display_x = params[0];
+                    }
+                    Some((SSM, params)) if !params.is_empty() => {
+                        self.layout.char_width = params[0];
+                    }
+                    Some((SHS, params)) if !params.is_empty() => {
+                        self.layout.spacing = params[0];
+                    }
+                    _ => {}
+                }
             }
             0xa0 => {}
             0xff => {}
@@ -628,3 +945,61 @@ impl AribDecoder {
         Ok(())
     }
 }
+
+// GETA MARK in the kanji set, for characters which cannot be encoded.
+const GETA_MARK: u32 = 0x1222e;
+// rows from 0x75 of the kanji set are additional symbols and kanji.
+const KANJI_SET_END: u32 = 0x17500;
+
+/// Encodes text into the 8-unit code with the initialization of captions:
+/// ASCII in the alphanumeric set of G1, and everything else in the kanji
+/// set of G0. Characters out of them are replaced by GETA MARK, and line
+/// feeds by APR.
+pub fn encode_caption(s: &str) -> Vec<u8> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut out = Vec::with_capacity(s.len() * 2);
+    let mut gl = 0;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        i += 1;
+        match c {
+            '\n' => out.push(APR),
+            ' ' => out.push(SP),
+            '!'..='~' => {
+                if gl != 1 {
+                    out.push(LS1);
+                    gl = 1;
+                }
+                out.push(c as u8);
+            }
+            _ => {
+                // some kana with a combining mark are a character.
+                let pair = chars
+                    .get(i)
+                    .and_then(|next| jisx0213::chars_to_code_point(&[c, *next]));
+                let code_point = match pair {
+                    Some(code_point) => {
+                        i += 1;
+                        Some(code_point)
+                    }
+                    None => jisx0213::chars_to_code_point(&[c]),
+                };
+                let code_point = match code_point {
+                    Some(code_point) if code_point < KANJI_SET_END => code_point,
+                    _ => {
+                        trace!("unencodable {:?}", c);
+                        GETA_MARK
+                    }
+                };
+                if gl != 0 {
+                    out.push(LS0);
+                    gl = 0;
+                }
+                out.push((code_point >> 8) as u8);
+                out.push(code_point as u8);
+            }
+        }
+    }
+    out
+}
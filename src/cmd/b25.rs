@@ -0,0 +1,62 @@
+use std::process::Stdio;
+use std::sync::OnceLock;
+
+use anyhow::{anyhow, bail, Context, Result};
+use log::{info, warn};
+use tokio::io::AsyncRead;
+use tokio::process::{ChildStdout, Command};
+
+// the program and arguments of the command, set once from the command line.
+static COMMAND: OnceLock<Vec<String>> = OnceLock::new();
+
+/// Sets the command through which every input is decrypted. The command
+/// reads the scrambled stream from stdin and writes the decrypted one to
+/// stdout, such as `arib-b25-stream-test`. Arguments are separated by
+/// whitespace.
+pub fn set_command(command: &str) -> Result<()> {
+    let args: Vec<String> = command.split_whitespace().map(String::from).collect();
+    if args.is_empty() {
+        bail!("empty b25 command");
+    }
+    COMMAND
+        .set(args)
+        .map_err(|_| anyhow!("b25 command is already set"))
+}
+
+pub(super) fn is_enabled() -> bool {
+    COMMAND.get().is_some()
+}
+
+/// Spawns the command, feeds the input to it on a task, and returns its
+/// output.
+pub(super) fn decrypt<R: AsyncRead + Send + Unpin + 'static>(mut input: R) -> Result<ChildStdout> {
+    let args = COMMAND
+        .get()
+        .ok_or_else(|| anyhow!("no b25 command is set"))?;
+    info!("decrypt by {:?}", args);
+    let mut child = Command::new(&args[0])
+        .args(&args[1..])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .with_context(|| format!("failed to run {:?}", args[0]))?;
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let stdout = child.stdout.take().expect("stdout is piped");
+    tokio::spawn(async move {
+        // the command goes away early if the output is no longer read.
+        if let Err(e) = tokio::io::copy(&mut input, &mut stdin).await {
+            if e.kind() != std::io::ErrorKind::BrokenPipe {
+                warn!("feed b25 command: {:?}", e);
+            }
+        }
+        // closing stdin lets the command flush the rest and exit.
+        drop(stdin);
+        match child.wait().await {
+            Ok(status) if !status.success() => warn!("b25 command exited with {}", status),
+            Ok(_) => {}
+            Err(e) => warn!("wait b25 command: {:?}", e),
+        }
+    });
+    Ok(stdout)
+}
@@ -0,0 +1,172 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use clap::ValueEnum;
+use log::info;
+use serde_derive::Serialize;
+use tokio_stream::StreamExt;
+
+use super::common;
+use super::io::{self, path_to_packets, RecordWriter};
+use tstools::stream::cueable;
+use tstools::ts;
+
+// PCR wraps around at 2^33 * 300.
+const PCR_MAX: u64 = (1 << 33) * 300;
+// PCR intervals longer than this are treated as discontinuities.
+const MAX_PCR_INTERVAL: u64 = ts::PCR_HZ;
+const CSV_COLUMNS: [&str; 6] = ["time", "duration", "kind", "id", "packets", "bit_rate"];
+
+#[derive(ValueEnum, Clone, Copy)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+#[derive(Serialize)]
+struct Sample {
+    // seconds of PCR from the first PCR to the start of the window.
+    time: f64,
+    duration: f64,
+    // "pid", "service" or "total".
+    kind: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<u16>,
+    packets: u64,
+    bit_rate: f64,
+}
+
+impl Sample {
+    fn csv(&self) -> String {
+        format!(
+            "{:.3},{:.3},{},{},{},{:.0}",
+            self.time,
+            self.duration,
+            self.kind,
+            self.id.map(|id| id.to_string()).unwrap_or_default(),
+            self.packets,
+            self.bit_rate
+        )
+    }
+}
+
+// packets counted in the current window.
+struct Window {
+    // PCR ticks from the first PCR.
+    start: u64,
+    elapsed: u64,
+    pids: BTreeMap<u16, u64>,
+}
+
+impl Window {
+    fn samples(&self, services: &BTreeMap<u16, BTreeSet<u16>>) -> Vec<Sample> {
+        let duration = self.elapsed as f64 / ts::PCR_HZ as f64;
+        let sample = |kind, id, packets| Sample {
+            time: self.start as f64 / ts::PCR_HZ as f64,
+            duration,
+            kind,
+            id,
+            packets,
+            bit_rate: (packets * ts::TS_PACKET_LENGTH as u64 * 8) as f64 / duration,
+        };
+        let mut samples = vec![sample("total", None, self.pids.values().sum())];
+        for (service_id, pids) in services.iter() {
+            let packets = pids.iter().filter_map(|pid| self.pids.get(pid)).sum();
+            samples.push(sample("service", Some(*service_id), packets));
+        }
+        for (pid, packets) in self.pids.iter() {
+            samples.push(sample("pid", Some(*pid), *packets));
+        }
+        samples
+    }
+
+    // starts the next window, keeping PIDs seen so far to report them with
+    // no packets.
+    fn next(&mut self) {
+        self.start += self.elapsed;
+        self.elapsed = 0;
+        self.pids.values_mut().for_each(|packets| *packets = 0);
+    }
+}
+
+fn print_window(
+    window: &Window,
+    services: &BTreeMap<u16, BTreeSet<u16>>,
+    format: Format,
+    out: &mut RecordWriter,
+) -> Result<()> {
+    for sample in window.samples(services).iter() {
+        let line = match format {
+            Format::Json => serde_json::to_string(sample)?,
+            Format::Csv => sample.csv(),
+        };
+        out.write_line(&line)?;
+    }
+    out.end_batch()
+}
+
+/// Prints bitrates of each PID, service and the whole stream over windows
+/// of the PCR of the first service.
+pub async fn run(input: Option<PathBuf>, window: f64, format: Format) -> Result<()> {
+    if window <= 0.0 {
+        bail!("window must be positive: {}", window);
+    }
+    let window_ticks = (window * ts::PCR_HZ as f64) as u64;
+    let mut out = RecordWriter::stdout(io::is_live(input.as_slice()));
+    let packets = path_to_packets(input, false).await?;
+    let packets = common::interrupt_on_ctrl_c(common::strip_error_packets(packets));
+    let mut cueable_packets = cueable(packets);
+    let programs = common::find_programs(&mut cueable_packets).await?;
+    let mut packets = cueable_packets.cue_up();
+    let pcr_pid = match programs.first() {
+        Some(program) => program.pcr_pid,
+        None => bail!("no program found"),
+    };
+    // PIDs of each service, which may share some of them.
+    let services: BTreeMap<u16, BTreeSet<u16>> = programs
+        .iter()
+        .map(|program| {
+            let mut pids: BTreeSet<u16> = program.streams.iter().map(|es| es.pid).collect();
+            pids.insert(program.pmt_pid);
+            pids.insert(program.pcr_pid);
+            (program.program_number, pids)
+        })
+        .collect();
+
+    if let Format::Csv = format {
+        out.write_line(&CSV_COLUMNS.join(","))?;
+    }
+    let mut current = Window {
+        start: 0,
+        elapsed: 0,
+        pids: BTreeMap::new(),
+    };
+    let mut last_pcr = None;
+    while let Some(packet) = packets.next().await {
+        *current.pids.entry(packet.pid).or_default() += 1;
+        if packet.pid != pcr_pid {
+            continue;
+        }
+        let pcr = match packet.adaptation_field.as_ref().and_then(|af| af.pcr()) {
+            Some(pcr) => pcr,
+            None => continue,
+        };
+        if let Some(prev_pcr) = last_pcr.replace(pcr) {
+            let interval = (pcr + PCR_MAX - prev_pcr) % PCR_MAX;
+            if interval > MAX_PCR_INTERVAL {
+                info!("pcr discontinuity from {} to {}", prev_pcr, pcr);
+                continue;
+            }
+            current.elapsed += interval;
+        }
+        if current.elapsed >= window_ticks {
+            print_window(&current, &services, format, &mut out)?;
+            current.next();
+        }
+    }
+    if current.elapsed > 0 {
+        print_window(&current, &services, format, &mut out)?;
+    }
+    out.flush()
+}
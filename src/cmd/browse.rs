@@ -0,0 +1,402 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use anyhow::Result;
+use chrono::{DateTime, FixedOffset};
+use log::info;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::{Block, List, ListItem, Paragraph, Row, Table};
+use ratatui::{DefaultTerminal, Frame};
+use tokio_stream::{Stream, StreamExt};
+
+use super::caption::{builtin_drcs_map, caption_text};
+use super::common;
+use super::errors;
+use super::io::path_to_packets;
+use super::monitor::{self, Monitor};
+use tstools::pes;
+use tstools::psi;
+use tstools::stream::cueable;
+use tstools::ts;
+
+const REDRAW_INTERVAL: Duration = Duration::from_millis(250);
+const MAX_CAPTIONS: usize = 200;
+const PRESENT_FOLLOWING_TABLE_ID: u8 = 0x4e;
+
+struct Service {
+    pmt_pid: u16,
+    streams: usize,
+    name: Option<String>,
+    provider_name: Option<String>,
+}
+
+struct PresentFollowing {
+    start: Option<DateTime<FixedOffset>>,
+    duration: Option<chrono::Duration>,
+    title: String,
+}
+
+#[derive(Default)]
+struct State {
+    monitor: Monitor,
+    done: bool,
+    error: Option<String>,
+    services: BTreeMap<u16, Service>,
+    // keyed by service_id and section_number, 0 for present and 1 for
+    // following.
+    events: BTreeMap<(u16, u8), PresentFollowing>,
+    captions: VecDeque<String>,
+}
+
+type Shared = Arc<Mutex<State>>;
+
+fn lock(state: &Shared) -> MutexGuard<'_, State> {
+    // a panicked reader leaves the state as consistent as a live input.
+    state.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn new_state(programs: &[common::Program]) -> State {
+    let mut state = State {
+        monitor: Monitor::new(programs),
+        ..Default::default()
+    };
+    for program in programs.iter() {
+        state.services.insert(
+            program.program_number,
+            Service {
+                pmt_pid: program.pmt_pid,
+                streams: program.streams.len(),
+                name: None,
+                provider_name: None,
+            },
+        );
+    }
+    state
+}
+
+fn count_packet(state: &Shared, packet: &ts::TSPacket) {
+    lock(state).monitor.count(packet);
+}
+
+async fn watch_sdt<S: Stream<Item = ts::TSPacket> + Unpin>(s: S, state: Shared) {
+    monitor::watch_sdt(s, |service_id, name, provider_name| {
+        if let Some(entry) = lock(&state).services.get_mut(&service_id) {
+            entry.name = name;
+            entry.provider_name = provider_name;
+        }
+    })
+    .await
+}
+
+async fn watch_eit<S: Stream<Item = ts::TSPacket> + Unpin>(s: S, state: Shared) {
+    let mut buffer = psi::Buffer::new(s).verify_crc();
+    while let Some(bytes) = buffer.next().await {
+        let bytes = match bytes {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                info!("eit buffer error: {:?}", e);
+                continue;
+            }
+        };
+        if bytes[0] != PRESENT_FOLLOWING_TABLE_ID {
+            continue;
+        }
+        let eit = match psi::EventInformationSection::parse(&bytes[..]) {
+            Ok(eit) => eit,
+            Err(e) => {
+                info!("eit parse error: {:?}", e);
+                continue;
+            }
+        };
+        let key = (eit.service_id, eit.section_number);
+        let mut state = lock(&state);
+        // an empty section means no event.
+        let event = match eit.events.first() {
+            Some(event) => event,
+            None => {
+                state.events.remove(&key);
+                continue;
+            }
+        };
+        let title = event
+            .descriptors
+            .iter()
+            .find_map(|desc| match desc {
+                psi::Descriptor::ShortEventDescriptor(e) => common::decode_string(e.event_name),
+                _ => None,
+            })
+            .unwrap_or_default();
+        state.events.insert(
+            key,
+            PresentFollowing {
+                start: event.start_time,
+                duration: event.duration,
+                title,
+            },
+        );
+    }
+}
+
+async fn watch_captions<S: Stream<Item = ts::TSPacket> + Unpin>(
+    service_id: u16,
+    s: S,
+    state: Shared,
+) {
+    let drcs_map = builtin_drcs_map();
+    let mut buffer = pes::Buffer::new(s);
+    while let Some(bytes) = buffer.next().await {
+        let bytes = match bytes {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                info!("pes buffer error: {:?}", e);
+                continue;
+            }
+        };
+        let pes = match pes::PESPacket::parse(&bytes[..]) {
+            Ok(pes) => pes,
+            Err(e) => {
+                info!("pes parse error: {:?}", e);
+                errors::count(errors::Kind::Pes);
+                continue;
+            }
+        };
+        let text = match caption_text(&pes, &drcs_map) {
+            Some(text) if !text.trim().is_empty() => text,
+            _ => continue,
+        };
+        let time = pes.get_pts().map_or_else(
+            || String::from("--:--:--"),
+            |pts| {
+                let seconds = pts / pes::PTS_HZ;
+                format!(
+                    "{:02}:{:02}:{:02}",
+                    seconds / 3600 % 24,
+                    seconds / 60 % 60,
+                    seconds % 60
+                )
+            },
+        );
+        let mut state = lock(&state);
+        if state.captions.len() == MAX_CAPTIONS {
+            state.captions.pop_front();
+        }
+        let line = format!("{} {} {}", time, service_id, text.replace('\n', " "));
+        state.captions.push_back(line);
+    }
+}
+
+// reads the input to the end, updating the state.
+async fn read<S: Stream<Item = ts::TSPacket> + Unpin>(
+    packets: S,
+    programs: Vec<common::Program>,
+    state: Shared,
+) -> Result<()> {
+    let mut demuxer = ts::Demuxer::new();
+    let mut tasks = vec![tokio::spawn(watch_sdt(
+        demuxer.register(psi::SDT_PID),
+        state.clone(),
+    ))];
+    for pid in ts::EIT_PIDS.iter() {
+        tasks.push(tokio::spawn(watch_eit(
+            demuxer.register(*pid),
+            state.clone(),
+        )));
+    }
+    for program in programs.iter() {
+        for es in program.streams.iter().filter(|es| es.caption) {
+            tasks.push(tokio::spawn(watch_captions(
+                program.program_number,
+                demuxer.register(es.pid),
+                state.clone(),
+            )));
+        }
+    }
+    let counter = state.clone();
+    demuxer
+        .run(packets.map(move |packet| {
+            count_packet(&counter, &packet);
+            packet
+        }))
+        .await;
+    for task in tasks.into_iter() {
+        task.await?;
+    }
+    Ok(())
+}
+
+fn format_event(event: &PresentFollowing) -> String {
+    let start = event
+        .start
+        .map_or_else(|| String::from("--:--"), |t| t.format("%H:%M").to_string());
+    let end = match (event.start, event.duration) {
+        (Some(start), Some(duration)) => (start + duration).format("%H:%M").to_string(),
+        _ => String::from("--:--"),
+    };
+    format!("{}-{} {}", start, end, event.title)
+}
+
+fn draw_services(frame: &mut Frame, area: Rect, state: &State) {
+    let rows = state.services.iter().map(|(service_id, service)| {
+        Row::new(vec![
+            service_id.to_string(),
+            format!("{:#06x}", service.pmt_pid),
+            service.streams.to_string(),
+            service.name.clone().unwrap_or_default(),
+            service.provider_name.clone().unwrap_or_default(),
+        ])
+    });
+    let widths = [
+        Constraint::Length(7),
+        Constraint::Length(7),
+        Constraint::Length(7),
+        Constraint::Fill(2),
+        Constraint::Fill(1),
+    ];
+    let table = Table::new(rows, widths)
+        .header(
+            Row::new(vec!["sid", "pmt", "streams", "name", "provider"])
+                .style(Style::new().add_modifier(Modifier::BOLD)),
+        )
+        .block(Block::bordered().title("Services"));
+    frame.render_widget(table, area);
+}
+
+fn draw_events(frame: &mut Frame, area: Rect, state: &State) {
+    let rows = state
+        .events
+        .iter()
+        .map(|((service_id, section_number), event)| {
+            let kind = match section_number {
+                0 => "present",
+                _ => "following",
+            };
+            Row::new(vec![
+                service_id.to_string(),
+                String::from(kind),
+                format_event(event),
+            ])
+        });
+    let widths = [
+        Constraint::Length(7),
+        Constraint::Length(10),
+        Constraint::Fill(1),
+    ];
+    let table = Table::new(rows, widths)
+        .header(Row::new(vec!["sid", "", "event"]).style(Style::new().add_modifier(Modifier::BOLD)))
+        .block(Block::bordered().title("EIT present/following"));
+    frame.render_widget(table, area);
+}
+
+fn draw_pids(frame: &mut Frame, area: Rect, state: &State) {
+    let total = state.monitor.packets.max(1);
+    let rows = state.monitor.pids.iter().map(|(pid, p)| {
+        Row::new(vec![
+            format!("{:#06x}", pid),
+            p.role.clone(),
+            p.packets.to_string(),
+            format!("{:.1}%", p.packets as f64 * 100.0 / total as f64),
+            format!("{:.2}", p.bits_per_second / 1e6),
+        ])
+    });
+    let widths = [
+        Constraint::Length(7),
+        Constraint::Fill(1),
+        Constraint::Length(10),
+        Constraint::Length(7),
+        Constraint::Length(8),
+    ];
+    let table = Table::new(rows, widths)
+        .header(
+            Row::new(vec!["pid", "role", "packets", "share", "Mbps"])
+                .style(Style::new().add_modifier(Modifier::BOLD)),
+        )
+        .block(Block::bordered().title("PIDs"));
+    frame.render_widget(table, area);
+}
+
+fn draw_captions(frame: &mut Frame, area: Rect, state: &State) {
+    // the latest captions at the bottom.
+    let height = usize::from(area.height.saturating_sub(2));
+    let skip = state.captions.len().saturating_sub(height);
+    let items: Vec<ListItem> = state
+        .captions
+        .iter()
+        .skip(skip)
+        .map(|line| ListItem::new(line.as_str()))
+        .collect();
+    let list = List::new(items).block(Block::bordered().title("Captions"));
+    frame.render_widget(list, area);
+}
+
+fn draw(frame: &mut Frame, state: &State) {
+    let [top, bottom, status] = Layout::vertical([
+        Constraint::Percentage(40),
+        Constraint::Fill(1),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+    let [services, events] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(top);
+    let [pids, captions] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(bottom);
+    draw_services(frame, services, state);
+    draw_events(frame, events, state);
+    draw_pids(frame, pids, state);
+    draw_captions(frame, captions, state);
+    let reading = match (&state.error, state.done) {
+        (Some(e), _) => format!("error: {}", e),
+        (None, true) => String::from("end of input"),
+        (None, false) => String::from("reading"),
+    };
+    let line = format!("{} packets, {}; q to quit", state.monitor.packets, reading);
+    frame.render_widget(Paragraph::new(line), status);
+}
+
+// draws the state until a quit key is pressed.
+fn ui(mut terminal: DefaultTerminal, state: Shared) -> Result<()> {
+    loop {
+        {
+            let state = lock(&state);
+            terminal.draw(|frame| draw(frame, &state))?;
+        }
+        if !event::poll(REDRAW_INTERVAL)? {
+            continue;
+        }
+        if let Event::Key(key) = event::read()? {
+            let ctrl_c =
+                key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL);
+            if key.kind == KeyEventKind::Press
+                && (matches!(key.code, KeyCode::Char('q') | KeyCode::Esc) || ctrl_c)
+            {
+                return Ok(());
+            }
+        }
+    }
+}
+
+pub async fn run(input: Option<PathBuf>) -> Result<()> {
+    let packets = path_to_packets(input, false).await?;
+    let packets = common::strip_error_packets(packets);
+    let mut cueable_packets = cueable(packets);
+    let programs = common::find_programs(&mut cueable_packets).await?;
+    let packets = cueable_packets.cue_up();
+
+    let state = Arc::new(Mutex::new(new_state(&programs)));
+    let reader_state = state.clone();
+    let reader = tokio::spawn(async move {
+        let result = read(packets, programs, reader_state.clone()).await;
+        let mut state = lock(&reader_state);
+        state.done = true;
+        state.error = result.err().map(|e| e.to_string());
+    });
+
+    let terminal = ratatui::try_init()?;
+    let result = tokio::task::spawn_blocking(move || ui(terminal, state)).await;
+    ratatui::restore();
+    reader.abort();
+    result?
+}
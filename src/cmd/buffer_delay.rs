@@ -0,0 +1,121 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use log::info;
+use serde_derive::Serialize;
+use tokio_stream::StreamExt;
+
+use super::common;
+use super::io::path_to_packets;
+use tstools::pes;
+use tstools::ts;
+
+const PCR_PER_PTS: u64 = ts::PCR_HZ / pes::PTS_HZ;
+// PCR wraps around at 2^33 * 300.
+const PCR_MAX: u64 = (1 << 33) * 300;
+// PCR intervals longer than this are treated as discontinuities.
+const MAX_PCR_INTERVAL: u64 = ts::PCR_HZ;
+
+#[derive(Serialize)]
+struct Delay {
+    pid: u16,
+    offset: u64,
+    pts: u64,
+    dts: Option<u64>,
+    pcr: u64,
+    pts_delay: f64,
+    dts_delay: Option<f64>,
+}
+
+#[derive(Serialize)]
+struct Summary {
+    pid: u16,
+    samples: usize,
+    min: f64,
+    max: f64,
+    mean: f64,
+}
+
+struct AccessUnit {
+    pid: u16,
+    offset: u64,
+    timestamps: pes::Timestamps,
+}
+
+// delay in seconds between a 90kHz timestamp and a 27MHz clock.
+fn delay(timestamp: u64, pcr: u64) -> f64 {
+    common::pts_diff(timestamp, pcr / PCR_PER_PTS)
+}
+
+pub async fn run(input: Option<PathBuf>) -> Result<()> {
+    let packets = path_to_packets(input, false).await?;
+    let mut packets = common::strip_error_packets(packets);
+
+    let mut pcr_pid = None;
+    // offset and value of the latest PCR.
+    let mut last_pcr: Option<(u64, u64)> = None;
+    let mut pending = Vec::new();
+    let mut delays: BTreeMap<u16, Vec<f64>> = BTreeMap::new();
+    let mut offset = 0u64;
+    while let Some(packet) = packets.next().await {
+        let packet_offset = offset;
+        offset += ts::TS_PACKET_LENGTH as u64;
+
+        if let Some(timestamps) = common::packet_timestamps(&packet) {
+            if timestamps.pts.is_some() && last_pcr.is_some() {
+                pending.push(AccessUnit {
+                    pid: packet.pid,
+                    offset: packet_offset,
+                    timestamps,
+                });
+            }
+        }
+
+        if pcr_pid.is_some() && pcr_pid != Some(packet.pid) {
+            continue;
+        }
+        let pcr = match packet.adaptation_field.as_ref().and_then(|af| af.pcr()) {
+            Some(pcr) => pcr,
+            None => continue,
+        };
+        pcr_pid = Some(packet.pid);
+        if let Some((prev_offset, prev_pcr)) = last_pcr.replace((packet_offset, pcr)) {
+            let interval = (pcr + PCR_MAX - prev_pcr) % PCR_MAX;
+            if interval > MAX_PCR_INTERVAL {
+                info!("pcr discontinuity at {}", packet_offset);
+                pending.clear();
+                continue;
+            }
+            for au in pending.drain(..) {
+                let pcr =
+                    prev_pcr + interval * (au.offset - prev_offset) / (packet_offset - prev_offset);
+                let pcr = pcr % PCR_MAX;
+                let pts = au.timestamps.pts.unwrap();
+                let d = Delay {
+                    pid: au.pid,
+                    offset: au.offset,
+                    pts,
+                    dts: au.timestamps.dts,
+                    pcr,
+                    pts_delay: delay(pts, pcr),
+                    dts_delay: au.timestamps.dts.map(|dts| delay(dts, pcr)),
+                };
+                delays.entry(d.pid).or_default().push(d.pts_delay);
+                println!("{}", serde_json::to_string(&d)?);
+            }
+        }
+    }
+
+    for (pid, delays) in delays.into_iter() {
+        let summary = Summary {
+            pid,
+            samples: delays.len(),
+            min: delays.iter().cloned().fold(f64::INFINITY, f64::min),
+            max: delays.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+            mean: delays.iter().sum::<f64>() / delays.len() as f64,
+        };
+        println!("{}", serde_json::to_string(&summary)?);
+    }
+    Ok(())
+}
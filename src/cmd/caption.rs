@@ -3,25 +3,32 @@ use std::fs::File;
 use std::path::PathBuf;
 
 use anyhow::{bail, Result};
-use clap::ValueEnum;
-use log::{debug, info};
+use chrono::offset::FixedOffset;
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use clap::{Args, ValueEnum};
+use log::{debug, info, warn};
 use md5::{Digest, Md5};
+use schemars::schema::RootSchema;
+use schemars::JsonSchema;
 use serde_derive::{Deserialize, Serialize};
 use serde_json;
 use tokio_stream::{Stream, StreamExt};
-use tokio_util::codec::FramedRead;
 
 use super::common;
-use super::io::path_to_async_read;
-use crate::arib;
-use crate::pes;
-use crate::stream::cueable;
-use crate::ts;
+use super::errors;
+use super::index::SeekOptions;
+use super::io::{self, path_to_packets_at, paths_to_packets, RecordWriter};
+use tstools::arib;
+use tstools::pes;
+use tstools::stream::cueable;
+use tstools::ts;
 
 fn sync_caption<'a>(pes: &'a pes::PESPacket) -> Result<arib::caption::DataGroup<'a>> {
     if let pes::PESPacketBody::NormalPESPacketBody(ref body) = pes.body {
-        arib::pes::SynchronizedPESData::parse(body.pes_packet_data_byte)
-            .and_then(|data| arib::caption::DataGroup::parse(data.synchronized_pes_data_byte))
+        let data = arib::pes::SynchronizedPESData::parse(body.pes_packet_data_byte)?;
+        Ok(arib::caption::DataGroup::parse(
+            data.synchronized_pes_data_byte,
+        )?)
     } else {
         unreachable!();
     }
@@ -29,14 +36,16 @@ fn sync_caption<'a>(pes: &'a pes::PESPacket) -> Result<arib::caption::DataGroup<
 
 fn async_caption<'a>(pes: &'a pes::PESPacket) -> Result<arib::caption::DataGroup<'a>> {
     if let pes::PESPacketBody::DataBytes(bytes) = pes.body {
-        arib::pes::AsynchronousPESData::parse(bytes)
-            .and_then(|data| arib::caption::DataGroup::parse(data.asynchronous_pes_data_byte))
+        let data = arib::pes::AsynchronousPESData::parse(bytes)?;
+        Ok(arib::caption::DataGroup::parse(
+            data.asynchronous_pes_data_byte,
+        )?)
     } else {
         unreachable!();
     }
 }
 
-fn get_caption<'a>(pes: &'a pes::PESPacket) -> Result<arib::caption::DataGroup<'a>> {
+pub fn get_caption<'a>(pes: &'a pes::PESPacket) -> Result<arib::caption::DataGroup<'a>> {
     match pes.stream_id {
         arib::pes::SYNCHRONIZED_PES_STREAM_ID => sync_caption(pes),
         arib::pes::ASYNCHRONOUS_PES_STREAM_ID => async_caption(pes),
@@ -44,6 +53,11 @@ fn get_caption<'a>(pes: &'a pes::PESPacket) -> Result<arib::caption::DataGroup<'
     }
 }
 
+/// The key of a DRCS font in a DRCS map, which is MD5 of the pattern.
+pub fn font_hash(font: &arib::caption::Font) -> u128 {
+    u128::from_ne_bytes(Md5::digest(font.pattern_data).into())
+}
+
 fn print_aa(cc: u16, hash: u128, font: &arib::caption::Font) {
     info!("cc = {}, hash = {:032x}", cc, hash);
     for y in 0..font.height {
@@ -63,6 +77,57 @@ fn print_aa(cc: u16, hash: u128, font: &arib::caption::Font) {
     }
 }
 
+/// Decodes the text of a caption PES, replacing DRCS by the map.
+pub fn caption_text(pes: &pes::PESPacket, drcs_map: &HashMap<u128, String>) -> Option<String> {
+    let dg = match get_caption(pes) {
+        Ok(dg) => dg,
+        Err(e) => {
+            debug!("retrieving caption error: {:?}", e);
+            return None;
+        }
+    };
+    let data_units = match dg.data_group_data {
+        arib::caption::DataGroupData::CaptionData(ref cd) => &cd.data_units,
+        arib::caption::DataGroupData::CaptionManagementData(_) => return None,
+    };
+    let mut code_map = HashMap::new();
+    let mut text = String::new();
+    for du in data_units.iter() {
+        match du.data_unit_parameter {
+            arib::caption::DataUnitParameter::DRCS1 | arib::caption::DataUnitParameter::DRCS2 => {
+                let drcs = match arib::caption::DrcsDataStructure::parse(du.data_unit_data) {
+                    Ok(drcs) => drcs,
+                    Err(e) => {
+                        debug!("drcs parse error: {:?}", e);
+                        continue;
+                    }
+                };
+                for code in drcs.codes.iter() {
+                    let s = code
+                        .fonts
+                        .iter()
+                        .find_map(|font| drcs_map.get(&font_hash(font)).cloned())
+                        .unwrap_or_else(|| String::from("\u{fffd}"));
+                    code_map.insert(code.character_code, s);
+                }
+            }
+            arib::caption::DataUnitParameter::Text => {
+                let mut decoder = arib::string::AribDecoder::with_caption_initialization();
+                decoder.set_drcs(code_map.clone());
+                match decoder.decode(du.data_unit_data.iter()) {
+                    Ok(s) => text.push_str(&s),
+                    Err(e) => {
+                        debug!("string decode error: {:?}", e);
+                        errors::count(errors::Kind::String);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Some(text)
+}
+
 #[derive(Hash, PartialEq, Eq)]
 struct U128(u128);
 
@@ -99,6 +164,22 @@ struct DRCSMap {
     drcs: HashMap<U128, String>,
 }
 
+// DRCS fonts commonly used by broadcasters, in the format of --drcs-map.
+// Hashes of new fonts are listed by the drcs command.
+#[cfg(feature = "builtin-drcs")]
+const BUILTIN_DRCS_MAP: &str = include_str!("builtin_drcs.json");
+
+pub fn builtin_drcs_map() -> HashMap<u128, String> {
+    #[cfg(feature = "builtin-drcs")]
+    {
+        let map: DRCSMap =
+            serde_json::from_str(BUILTIN_DRCS_MAP).expect("the built-in drcs map is valid");
+        map.drcs.into_iter().map(|(k, v)| (k.0, v)).collect()
+    }
+    #[cfg(not(feature = "builtin-drcs"))]
+    HashMap::new()
+}
+
 struct DRCSProcessor {
     unknown: HashSet<u128>,
     drcs_map: HashMap<u128, String>,
@@ -110,7 +191,7 @@ impl DRCSProcessor {
     fn new(handle_drcs: HandleDRCS) -> DRCSProcessor {
         DRCSProcessor {
             unknown: HashSet::new(),
-            drcs_map: HashMap::new(),
+            drcs_map: builtin_drcs_map(),
             code_map: HashMap::new(),
             handle_drcs,
         }
@@ -119,7 +200,10 @@ impl DRCSProcessor {
     fn load_map(&mut self, path: PathBuf) -> Result<()> {
         let file = File::open(path)?;
         let map: DRCSMap = serde_json::from_reader(file)?;
-        self.drcs_map = map.drcs.into_iter().map(|(k, v)| (k.0, v)).collect();
+        // the built-in map is consulted first.
+        for (k, v) in map.drcs.into_iter() {
+            self.drcs_map.entry(k.0).or_insert(v);
+        }
         Ok(())
     }
 
@@ -129,7 +213,7 @@ impl DRCSProcessor {
             let mut code_str = String::new();
             let mut found_font = false;
             for font in code.fonts {
-                let hash = u128::from_ne_bytes(Md5::digest(font.pattern_data).into());
+                let hash = font_hash(&font);
                 match self.drcs_map.get(&hash) {
                     Some(s) => {
                         code_str.push_str(s);
@@ -177,17 +261,207 @@ impl DRCSProcessor {
     }
 }
 
-#[derive(Serialize)]
+/// The broadcast time of a caption in ISO 8601.
+#[derive(Serialize, JsonSchema)]
+struct Wallclock {
+    time_utc: String,
+    time_jst: String,
+}
+
+impl From<DateTime<FixedOffset>> for Wallclock {
+    fn from(t: DateTime<FixedOffset>) -> Self {
+        Wallclock {
+            time_utc: t
+                .with_timezone(&Utc)
+                .to_rfc3339_opts(SecondsFormat::Millis, true),
+            time_jst: t.to_rfc3339_opts(SecondsFormat::Millis, false),
+        }
+    }
+}
+
+/// The data group a caption was sent in.
+#[derive(Serialize, JsonSchema, Clone)]
+struct Origin {
+    data_group_id: u8,
+    /// `management` for caption management data, or `statement` for caption
+    /// statement data.
+    data_group_type: &'static str,
+    /// The ISO 639 language code of the caption, declared by the latest
+    /// caption management data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+}
+
+impl Origin {
+    // statement data groups 1 to 8 carry the languages of tags 0 to 7, in
+    // either the group A or B.
+    fn new(dg: &arib::caption::DataGroup, languages: &HashMap<u8, String>) -> Origin {
+        let (data_group_type, language) = match dg.data_group_data {
+            arib::caption::DataGroupData::CaptionManagementData(ref cmd) => (
+                "management",
+                cmd.languages
+                    .first()
+                    .map(|l| l.iso_639_language_code.clone()),
+            ),
+            arib::caption::DataGroupData::CaptionData(_) => {
+                let tag = (dg.data_group_id & 0xf).wrapping_sub(1);
+                ("statement", languages.get(&tag).cloned())
+            }
+        };
+        Origin {
+            data_group_id: dg.data_group_id,
+            data_group_type,
+            language,
+        }
+    }
+}
+
+#[derive(Serialize, JsonSchema)]
 struct Caption {
     time_sec: u64,
     time_ms: u64,
+    #[serde(flatten)]
+    wallclock: Option<Wallclock>,
+    #[serde(flatten)]
+    origin: Origin,
     caption: String,
+    /// Ruby left out of the caption with `--ruby separate`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    ruby: Vec<String>,
+}
+
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+enum SpanPosition {
+    Cell { row: u8, column: u8 },
+    Dot { x: u16, y: u16 },
+}
+
+#[derive(Serialize, JsonSchema)]
+struct CaptionSpan {
+    text: String,
+    color: u8,
+    size: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position: Option<SpanPosition>,
+}
+
+impl From<arib::string::Span> for CaptionSpan {
+    fn from(span: arib::string::Span) -> Self {
+        use arib::string::{CharSize, Position};
+        let size = match span.size {
+            CharSize::Small => "small",
+            CharSize::Middle => "middle",
+            CharSize::Normal => "normal",
+            CharSize::Tiny => "tiny",
+            CharSize::DoubleHeight => "double_height",
+            CharSize::DoubleWidth => "double_width",
+            CharSize::DoubleSize => "double_size",
+        };
+        let position = span.position.map(|p| match p {
+            Position::Cell { row, column } => SpanPosition::Cell { row, column },
+            Position::Dot { x, y } => SpanPosition::Dot { x, y },
+        });
+        CaptionSpan {
+            text: span.text,
+            color: span.foreground,
+            size,
+            position,
+        }
+    }
 }
 
+#[derive(Serialize, JsonSchema)]
+struct RichCaption {
+    time_sec: u64,
+    time_ms: u64,
+    #[serde(flatten)]
+    wallclock: Option<Wallclock>,
+    #[serde(flatten)]
+    origin: Origin,
+    spans: Vec<CaptionSpan>,
+}
+
+#[derive(ValueEnum, Clone, Copy)]
+pub enum Format {
+    Json,
+    JsonRich,
+}
+
+/// How ruby, the small text placed above the base text, is printed in the
+/// json format.
+#[derive(ValueEnum, Clone, Copy)]
+pub enum Ruby {
+    /// Interleaved into the caption as broadcast.
+    Inline,
+    /// Left out of the caption, and listed in `ruby`.
+    Separate,
+    /// As `<ruby>base<rt>ruby</rt></ruby>` around the base text which follows.
+    Markup,
+}
+
+// small text is ruby in ARIB captions.
+fn is_ruby(span: &arib::string::Span) -> bool {
+    span.size == arib::string::CharSize::Small
+}
+
+// joins the text of spans into a caption, and returns it with the ruby
+// left out of it.
+fn join_spans(spans: Vec<arib::string::Span>, ruby: Ruby) -> (String, Vec<String>) {
+    match ruby {
+        Ruby::Inline => (
+            spans.into_iter().map(|span| span.text).collect(),
+            Vec::new(),
+        ),
+        Ruby::Separate => {
+            let (rubies, bases): (Vec<_>, Vec<_>) = spans.into_iter().partition(is_ruby);
+            let rubies = rubies
+                .iter()
+                .map(|span| span.text.trim())
+                .filter(|text| !text.is_empty())
+                .map(String::from)
+                .collect();
+            (bases.into_iter().map(|span| span.text).collect(), rubies)
+        }
+        Ruby::Markup => {
+            let mut caption = String::new();
+            let mut pending = String::new();
+            for span in spans.into_iter() {
+                if is_ruby(&span) {
+                    pending.push_str(span.text.trim());
+                    continue;
+                }
+                let base = span.text.trim();
+                if pending.is_empty() || base.is_empty() {
+                    caption.push_str(&span.text);
+                    continue;
+                }
+                // the control characters around the base text, such as line
+                // breaks, stay out of the markup.
+                let leading = span.text.len() - span.text.trim_start().len();
+                caption.push_str(&span.text[..leading]);
+                caption.push_str(&format!("<ruby>{}<rt>{}</rt></ruby>", base, pending));
+                caption.push_str(&span.text[leading + base.len()..]);
+                pending.clear();
+            }
+            // ruby without base text after it is kept as text.
+            caption.push_str(&pending);
+            (caption, Vec::new())
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn dump_caption<'a>(
     data_units: &Vec<arib::caption::DataUnit<'a>>,
     offset: u64,
+    wallclock: Option<DateTime<FixedOffset>>,
+    origin: &Origin,
     drcs_processor: &mut DRCSProcessor,
+    format: Format,
+    ruby: Ruby,
+    normalizer: &arib::normalize::Normalizer,
+    out: &mut RecordWriter,
 ) -> Result<()> {
     drcs_processor.clear_code_map();
 
@@ -196,21 +470,42 @@ fn dump_caption<'a>(
             arib::caption::DataUnitParameter::Text => {
                 let mut decoder = arib::string::AribDecoder::with_caption_initialization();
                 decoder.set_drcs(drcs_processor.code_map());
-                let caption_string = match decoder.decode(du.data_unit_data.iter()) {
+                let mut spans = match decoder.decode_spans(du.data_unit_data.iter()) {
                     Ok(s) => s,
                     Err(e) => {
                         debug!("raw: {:?}", du.data_unit_data);
-                        return Err(e);
+                        return Err(e.into());
                     }
                 };
-                if !caption_string.is_empty() {
-                    let caption = Caption {
-                        time_sec: offset / pes::PTS_HZ,
-                        time_ms: offset % pes::PTS_HZ * 1000 / pes::PTS_HZ,
-                        caption: caption_string,
-                    };
-                    println!("{}", serde_json::to_string(&caption)?);
+                if spans.is_empty() {
+                    continue;
                 }
+                for span in spans.iter_mut() {
+                    span.text = normalizer.normalize(&span.text);
+                }
+                let time_sec = offset / pes::PTS_HZ;
+                let time_ms = offset % pes::PTS_HZ * 1000 / pes::PTS_HZ;
+                let line = match format {
+                    Format::Json => {
+                        let (caption, ruby) = join_spans(spans, ruby);
+                        serde_json::to_string(&Caption {
+                            time_sec,
+                            time_ms,
+                            wallclock: wallclock.map(Wallclock::from),
+                            origin: origin.clone(),
+                            caption,
+                            ruby,
+                        })?
+                    }
+                    Format::JsonRich => serde_json::to_string(&RichCaption {
+                        time_sec,
+                        time_ms,
+                        wallclock: wallclock.map(Wallclock::from),
+                        origin: origin.clone(),
+                        spans: spans.into_iter().map(CaptionSpan::from).collect(),
+                    })?,
+                };
+                out.write_line(&line)?;
             }
             arib::caption::DataUnitParameter::DRCS1 => drcs_processor.process(du.data_unit_data)?,
             param => {
@@ -221,34 +516,47 @@ fn dump_caption<'a>(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn process_captions<S: Stream<Item = ts::TSPacket> + Unpin>(
-    pid: u16,
     base_pts: u64,
+    offset_ms: i64,
+    anchor: Option<common::ClockAnchor>,
     mut drcs_processor: DRCSProcessor,
-    s: S,
+    format: Format,
+    ruby: Ruby,
+    normalizer: arib::normalize::Normalizer,
+    caption_stream: S,
+    out: &mut RecordWriter,
 ) -> Result<()> {
-    let caption_stream = s.filter(move |packet| packet.pid == pid);
     let mut buffer = pes::Buffer::new(caption_stream);
+    // the language codes by language tag, from caption management data.
+    let mut languages = HashMap::new();
     while let Some(bytes) = buffer.try_next().await? {
         let pes = match pes::PESPacket::parse(&bytes[..]) {
             Ok(pes) => pes,
             Err(e) => {
                 info!("pes parse error: {:?}", e);
+                errors::count(errors::Kind::Pes);
                 continue;
             }
         };
-        let offset = match pes.get_pts() {
+        let (offset, now) = match pes.get_pts() {
             Some(now) => {
+                let offset = pes::PtsTime::new(now).diff(pes::PtsTime::new(base_pts))
+                    + offset_ms * (pes::PTS_HZ / 1000) as i64;
                 // if the caption is designated to be display
-                // before the first picture,
+                // before the base,
                 // ignore it.
-                if now < base_pts {
+                if offset < 0 {
                     continue;
                 }
-                now - base_pts
+                (offset as u64, now)
             }
             _ => continue,
         };
+        let wallclock = anchor.map(|(jst_time, pts)| {
+            jst_time + Duration::milliseconds((common::pts_diff(now, pts) * 1000.0) as i64)
+        });
         let dg = match get_caption(&pes) {
             Ok(dg) => dg,
             Err(e) => {
@@ -256,15 +564,59 @@ async fn process_captions<S: Stream<Item = ts::TSPacket> + Unpin>(
                 continue;
             }
         };
+        if let arib::caption::DataGroupData::CaptionManagementData(ref cmd) = dg.data_group_data {
+            languages = cmd
+                .languages
+                .iter()
+                .map(|l| (l.language_tag, l.iso_639_language_code.clone()))
+                .collect();
+        }
+        let origin = Origin::new(&dg, &languages);
         let data_units = match dg.data_group_data {
             arib::caption::DataGroupData::CaptionManagementData(ref cmd) => &cmd.data_units,
             arib::caption::DataGroupData::CaptionData(ref cd) => &cd.data_units,
         };
-        dump_caption(data_units, offset, &mut drcs_processor)?;
+        dump_caption(
+            data_units,
+            offset,
+            wallclock,
+            &origin,
+            &mut drcs_processor,
+            format,
+            ruby,
+            &normalizer,
+            out,
+        )?;
+        out.end_batch()?;
     }
+    out.flush()?;
     drcs_processor.report_error()
 }
 
+/// What caption times are measured from.
+#[derive(ValueEnum, Clone, Copy)]
+pub enum Base {
+    /// The PTS of the first key picture.
+    FirstPicture,
+    /// The first PCR of the service.
+    FirstPcr,
+    /// PTS zero, giving the raw PTS.
+    Zero,
+}
+
+#[derive(Args)]
+pub struct TimeOptions {
+    /// What caption times are measured from.
+    #[arg(long = "base", value_enum, default_value = "first-picture")]
+    base: Base,
+    /// Shift caption times by this, in milliseconds.
+    #[arg(long = "offset-ms", default_value = "0", allow_negative_numbers = true)]
+    offset_ms: i64,
+    /// Add the broadcast time in UTC and JST, derived from TOT.
+    #[arg(long = "wallclock")]
+    wallclock: bool,
+}
+
 #[derive(ValueEnum, Clone)]
 pub enum HandleDRCS {
     Ignore,
@@ -272,24 +624,117 @@ pub enum HandleDRCS {
     ErrorExit,
 }
 
+/// The JSON Schema of a caption printed in the format.
+pub fn schema(format: Format) -> RootSchema {
+    match format {
+        Format::Json => schemars::schema_for!(Caption),
+        Format::JsonRich => schemars::schema_for!(RichCaption),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn run(
-    input: Option<PathBuf>,
+    input: Vec<PathBuf>,
     drcs_map: Option<PathBuf>,
     handle_drcs: HandleDRCS,
+    format: Format,
+    ruby: Ruby,
+    time: TimeOptions,
+    normalizer: arib::normalize::Normalizer,
+    service_id: Option<u16>,
+    progress: bool,
+    seek: SeekOptions,
 ) -> Result<()> {
     let mut drcs_processor = DRCSProcessor::new(handle_drcs);
     if let Some(path) = drcs_map {
         drcs_processor.load_map(path)?;
     }
 
-    let input = path_to_async_read(input).await?;
-    let packets = FramedRead::new(input, ts::TSPacketDecoder::new());
-    let packets = common::strip_error_packets(packets);
+    // the stream is found from the head, and captions are read from the
+    // offset.
+    let seek = match seek.offset()? {
+        Some(offset) if input.len() == 1 => Some((input[0].clone(), offset)),
+        Some(_) => bail!("--index needs exactly one input"),
+        None => None,
+    };
+    let mut out = RecordWriter::stdout(io::is_live(&input));
+    let packets = paths_to_packets(input, progress && seek.is_none()).await?;
+    let packets = common::interrupt_on_ctrl_c(common::strip_error_packets(packets));
     let mut cueable_packets = cueable(packets);
-    let meta = common::find_main_meta(&mut cueable_packets).await?;
+    let meta = common::find_main_meta(
+        &mut cueable_packets,
+        service_id,
+        &[common::StreamKind::Caption],
+    )
+    .await?;
     let packets = cueable_packets.cue_up();
     let mut cueable_packets = cueable(packets);
-    let pts = common::find_first_picture_pts(meta.video_pid, &mut cueable_packets).await?;
+    // without video, or without PCR such as some stripped files, the base
+    // falls back to the first PCR and then the first caption.
+    let mut base_pts = None;
+    let mut video = meta.video;
+    match (time.base, video) {
+        (Base::FirstPicture, Some((pid, stream_type))) => {
+            base_pts = common::find_first_picture_pts(pid, stream_type, &mut cueable_packets)
+                .await
+                .ok();
+            if base_pts.is_none() {
+                info!("no picture found, the first pcr is the base");
+                video = None;
+            }
+        }
+        (Base::Zero, _) => base_pts = Some(0),
+        _ => {}
+    }
     let packets = cueable_packets.cue_up();
-    process_captions(meta.caption_pid, pts, drcs_processor, packets).await
+    let mut cueable_packets = cueable(packets);
+    if let (None, Some(pcr_pid)) = (base_pts, meta.pcr_pid) {
+        base_pts = common::find_first_pcr(pcr_pid, &mut cueable_packets)
+            .await
+            .ok()
+            .map(|pcr| pcr / (ts::PCR_HZ / pes::PTS_HZ));
+    }
+    let packets = cueable_packets.cue_up();
+    let mut cueable_packets = cueable(packets);
+    let base_pts = match base_pts {
+        Some(pts) => pts,
+        None => {
+            info!("no pcr found, the first caption is the base");
+            common::find_first_pts(meta.caption_pid(), &mut cueable_packets).await?
+        }
+    };
+    let packets = cueable_packets.cue_up();
+    let mut cueable_packets = cueable(packets);
+    let mut anchor = None;
+    if time.wallclock {
+        let video_pid = video.map(|(pid, _)| pid);
+        anchor = common::find_clock_anchor(video_pid, meta.pcr_pid, &mut cueable_packets).await;
+        if anchor.is_none() {
+            warn!("no tot found, captions have no wallclock time");
+        }
+    }
+    let mut demuxer = ts::Demuxer::new();
+    let caption_stream = demuxer.register(meta.caption_pid());
+    match seek {
+        Some((path, offset)) => {
+            let packets = path_to_packets_at(&path, offset, progress).await?;
+            let packets = common::interrupt_on_ctrl_c(common::strip_error_packets(packets));
+            tokio::spawn(demuxer.run(packets));
+        }
+        None => {
+            tokio::spawn(demuxer.run(cueable_packets.cue_up()));
+        }
+    }
+    process_captions(
+        base_pts,
+        time.offset_ms,
+        anchor,
+        drcs_processor,
+        format,
+        ruby,
+        normalizer,
+        caption_stream,
+        &mut out,
+    )
+    .await
 }
@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Result};
+use bytes::Bytes;
+use log::{info, warn};
+use serde_derive::Serialize;
+use tokio_stream::StreamExt;
+
+use super::common;
+use super::io::path_to_packets;
+use tstools::arib;
+use tstools::dsmcc;
+use tstools::psi;
+use tstools::stream::cueable;
+
+#[derive(Serialize)]
+struct Module {
+    pid: u16,
+    download_id: u32,
+    module_id: u16,
+    module_version: u8,
+    module_type: Option<String>,
+    name: Option<String>,
+    compression: Option<Compression>,
+    size: usize,
+    path: PathBuf,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    resources: Vec<Resource>,
+}
+
+#[derive(Serialize)]
+struct Compression {
+    // 0 is zlib.
+    compression_type: u8,
+    original_size: u32,
+}
+
+#[derive(Serialize)]
+struct Resource {
+    location: Option<String>,
+    content_type: Option<String>,
+    path: PathBuf,
+}
+
+impl Module {
+    fn new(pid: u16, module: &dsmcc::Module, path: PathBuf) -> Module {
+        Module {
+            pid,
+            download_id: module.download_id,
+            module_id: module.module_id,
+            module_version: module.module_version,
+            module_type: module.module_type.clone(),
+            name: module.name.clone(),
+            compression: module.compression.map(|c| Compression {
+                compression_type: c.compression_type,
+                original_size: c.original_size,
+            }),
+            size: module.data.len(),
+            path,
+            resources: Vec::new(),
+        }
+    }
+}
+
+async fn save_module(pid: u16, module: &dsmcc::Module, dir: &Path) -> Result<Module> {
+    // names are not unique across downloads, so modules are stored by ids.
+    let path = dir.join(format!(
+        "{:04x}_{:08x}_{:04x}",
+        pid, module.download_id, module.module_id
+    ));
+    info!("write {:?}", path);
+    tokio::fs::write(&path, &module.data).await?;
+    Ok(Module::new(pid, module, path))
+}
+
+// BML documents declare EUC-JP, which no longer holds after decoding.
+fn declare_utf8(document: String) -> String {
+    if !document.starts_with("<?xml") {
+        return document;
+    }
+    let end = match document.find("?>") {
+        Some(end) => end,
+        None => return document,
+    };
+    // lowercasing ASCII keeps the byte offsets.
+    let declaration = document[..end].to_ascii_lowercase();
+    match declaration.find("euc-jp") {
+        Some(start) => format!(
+            "{}UTF-8{}",
+            &document[..start],
+            &document[start + "euc-jp".len()..]
+        ),
+        None => document,
+    }
+}
+
+// converts a resource into a form which a browser can show, falling back to
+// the transmitted bytes.
+fn browsable(resource: &dsmcc::Resource) -> Vec<u8> {
+    match resource.kind() {
+        dsmcc::ResourceKind::Bml => {
+            let decoder = arib::string::AribDecoder::with_bml_initialization();
+            match decoder.decode(resource.data.iter()) {
+                Ok(document) => return declare_utf8(document).into_bytes(),
+                Err(e) => warn!("bml decode error in {:?}: {:?}", resource.location, e),
+            }
+        }
+        dsmcc::ResourceKind::Png => match arib::logo::logo_to_png(resource.data) {
+            Ok(png) => return png,
+            Err(e) => warn!("png error in {:?}: {:?}", resource.location, e),
+        },
+        dsmcc::ResourceKind::Clut | dsmcc::ResourceKind::Other => {}
+    }
+    resource.data.to_vec()
+}
+
+// writes resources as `<component_tag>/<module_id>/<name>`, which is how BML
+// documents refer to each other.
+async fn save_resources(
+    pid: u16,
+    component_tag: Option<u8>,
+    module: &dsmcc::Module,
+    dir: &Path,
+) -> Result<Module> {
+    if module.compression.is_some() {
+        warn!(
+            "module {:#x} is compressed, write it as is",
+            module.module_id
+        );
+        return save_module(pid, module, dir).await;
+    }
+    let resources = match module.resources() {
+        Ok(resources) => resources,
+        Err(e) => {
+            warn!("module {:#x}: {:?}", module.module_id, e);
+            return save_module(pid, module, dir).await;
+        }
+    };
+    let component = match component_tag {
+        Some(tag) => format!("{:02x}", tag),
+        None => format!("pid_{:04x}", pid),
+    };
+    let module_dir = dir
+        .join(component)
+        .join(format!("{:04x}", module.module_id));
+    tokio::fs::create_dir_all(&module_dir).await?;
+    let mut saved = Module::new(pid, module, module_dir.clone());
+    for (i, resource) in resources.iter().enumerate() {
+        // only the file name is taken not to escape from the directory.
+        let name = resource
+            .location
+            .as_deref()
+            .and_then(|l| Path::new(l).file_name())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(format!("{:04x}_{}", module.module_id, i)));
+        let path = module_dir.join(name);
+        info!("write {:?}", path);
+        tokio::fs::write(&path, browsable(resource)).await?;
+        saved.resources.push(Resource {
+            location: resource.location.clone(),
+            content_type: resource.content_type.clone(),
+            path,
+        });
+    }
+    Ok(saved)
+}
+
+pub async fn run(
+    input: Option<PathBuf>,
+    output_dir: PathBuf,
+    pids: Vec<u16>,
+    bml: bool,
+) -> Result<()> {
+    tokio::fs::create_dir_all(&output_dir).await?;
+    let packets = path_to_packets(input, false).await?;
+    let packets = common::strip_error_packets(packets);
+    let mut cueable_packets = cueable(packets);
+    let mut streams: Vec<(u16, Option<u8>)> = if pids.is_empty() {
+        let programs = common::find_programs(&mut cueable_packets).await?;
+        programs
+            .iter()
+            .flat_map(|p| p.streams.iter())
+            .filter(|s| s.stream_type == psi::STREAM_TYPE_DSMCC_TYPE_D)
+            .map(|s| (s.pid, s.component_tag))
+            .collect()
+    } else {
+        pids.into_iter().map(|pid| (pid, None)).collect()
+    };
+    streams.sort_unstable();
+    streams.dedup_by_key(|(pid, _)| *pid);
+    if streams.is_empty() {
+        bail!("no data carousel found");
+    }
+    info!("carousel pids {:?}", streams);
+    let mut packets = cueable_packets.cue_up();
+
+    let mut carousels: HashMap<u16, (Option<u8>, psi::Buffer<()>, dsmcc::Carousel)> = streams
+        .into_iter()
+        .map(|(pid, component_tag)| {
+            (
+                pid,
+                (
+                    component_tag,
+                    psi::Buffer::detached().verify_crc(),
+                    dsmcc::Carousel::new(),
+                ),
+            )
+        })
+        .collect();
+    while let Some(packet) = packets.next().await {
+        let pid = packet.pid;
+        let (component_tag, buffer, carousel) = match carousels.get_mut(&pid) {
+            Some(c) => c,
+            None => continue,
+        };
+        if let Err(e) = buffer.feed(packet) {
+            info!("pid {:#x}: {:?}", pid, e);
+            continue;
+        }
+        let sections: Vec<Bytes> = std::iter::from_fn(|| buffer.next_section()).collect();
+        for section in sections.into_iter() {
+            let modules = match carousel.feed_section(&section[..]) {
+                Ok(modules) => modules,
+                Err(e) => {
+                    warn!("pid {:#x}: dsmcc error: {:?}", pid, e);
+                    continue;
+                }
+            };
+            for module in modules.iter() {
+                let module = if bml {
+                    save_resources(pid, *component_tag, module, &output_dir).await?
+                } else {
+                    save_module(pid, module, &output_dir).await?
+                };
+                println!("{}", serde_json::to_string(&module)?);
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,290 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use bytes::Bytes;
+use chrono::offset::FixedOffset;
+use chrono::{DateTime, Duration};
+use clap::ValueEnum;
+use log::info;
+use tokio::io::AsyncWriteExt;
+use tokio_stream::{Stream, StreamExt};
+
+use super::cm;
+use super::common;
+use super::errors;
+use super::io::{path_to_async_write, path_to_packets};
+use tstools::arib;
+use tstools::psi;
+use tstools::stream::cueable;
+use tstools::ts;
+
+const PRESENT_FOLLOWING_TABLE_ID: u8 = 0x4e;
+const CM_TITLE: &str = "CM";
+// chapters shorter than this are merged into the next one.
+const MIN_CHAPTER: f64 = 1.0;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum Format {
+    Ffmetadata,
+    Ogm,
+}
+
+struct Event {
+    start: DateTime<FixedOffset>,
+    duration: Duration,
+    title: String,
+}
+
+#[derive(Default)]
+struct Schedule {
+    // the JST time of the first TOT and the video PTS when it was received.
+    anchor: Option<common::ClockAnchor>,
+    events: BTreeMap<u16, Event>,
+}
+
+struct Chapter {
+    start: f64,
+    end: f64,
+    title: String,
+}
+
+fn decode_string(bytes: &[u8]) -> Option<String> {
+    let decoder = arib::string::AribDecoder::with_event_initialization();
+    match decoder.decode(bytes.iter()) {
+        Ok(s) => Some(s),
+        Err(e) => {
+            info!("string decode error: {:?}", e);
+            errors::count(errors::Kind::String);
+            None
+        }
+    }
+}
+
+fn update_events(section: &[u8], service_id: u16, events: &mut BTreeMap<u16, Event>) {
+    if section[0] != PRESENT_FOLLOWING_TABLE_ID {
+        return;
+    }
+    let eit = match psi::EventInformationSection::parse(section) {
+        Ok(eit) => eit,
+        Err(e) => {
+            info!("eit parse error: {:?}", e);
+            return;
+        }
+    };
+    if eit.service_id != service_id {
+        return;
+    }
+    for event in eit.events.iter() {
+        let (start, duration) = match (event.start_time, event.duration) {
+            (Some(start), Some(duration)) => (start, duration),
+            _ => continue,
+        };
+        let title = event
+            .descriptors
+            .iter()
+            .find_map(|desc| match desc {
+                psi::Descriptor::ShortEventDescriptor(e) => decode_string(e.event_name),
+                _ => None,
+            })
+            .unwrap_or_default();
+        events.insert(
+            event.event_id,
+            Event {
+                start,
+                duration,
+                title,
+            },
+        );
+    }
+}
+
+// collects events of the service in EIT p/f, and relates the JST time to
+// the video PTS with TOT.
+async fn scan_schedule<S: Stream<Item = ts::TSPacket> + Unpin>(
+    meta: &common::Meta,
+    mut s: S,
+) -> Schedule {
+    let mut schedule = Schedule::default();
+    let mut eit_buffer = psi::Buffer::detached().verify_crc();
+    let mut video_pts = None;
+    while let Some(packet) = s.next().await {
+        let pid = packet.pid;
+        if pid == meta.video_pid() {
+            if let Some(pts) = common::packet_timestamps(&packet).and_then(|t| t.pts) {
+                video_pts = Some(pts);
+            }
+        } else if pid == psi::TOT_PID {
+            if schedule.anchor.is_some() {
+                continue;
+            }
+            if let (Some(jst_time), Some(pts)) = (common::tot_jst_time(&packet), video_pts) {
+                schedule.anchor = Some((jst_time, pts));
+            }
+        } else {
+            if let Err(e) = eit_buffer.feed(packet) {
+                info!("pid {:#x}: {:?}", pid, e);
+                continue;
+            }
+            let sections: Vec<Bytes> = std::iter::from_fn(|| eit_buffer.next_section()).collect();
+            for section in sections.into_iter() {
+                update_events(&section[..], meta.service_id, &mut schedule.events);
+            }
+        }
+    }
+    schedule
+}
+
+// splits the recording at the starts of events and at CM breaks, naming
+// each part by the event on air.
+fn make_chapters(schedule: &Schedule, cms: &[cm::Cm], base: u64, end: f64) -> Vec<Chapter> {
+    // events in seconds from the base.
+    let events: Vec<(f64, f64, &str)> = match schedule.anchor {
+        Some((jst, pts)) => {
+            let offset = common::pts_diff(pts, base);
+            schedule
+                .events
+                .values()
+                .map(|e| {
+                    let start = (e.start - jst).num_milliseconds() as f64 / 1000.0 + offset;
+                    let end = start + e.duration.num_milliseconds() as f64 / 1000.0;
+                    (start, end, e.title.as_str())
+                })
+                .collect()
+        }
+        None => {
+            info!("no tot found, events are not used");
+            Vec::new()
+        }
+    };
+    let title_at = |t: f64| {
+        events
+            .iter()
+            .find(|(start, end, _)| *start <= t && t < *end)
+            .map(|(_, _, title)| title.to_string())
+    };
+
+    let mut points: Vec<(f64, Option<String>)> = vec![(0.0, title_at(0.0))];
+    let in_cm = |t: f64| cms.iter().any(|cm| cm.start < t && t < cm.end);
+    for (start, _, title) in events.iter() {
+        // programs start after the CM break around them.
+        if *start > 0.0 && *start < end && !in_cm(*start) {
+            points.push((*start, Some(title.to_string())));
+        }
+    }
+    for cm in cms.iter() {
+        points.push((cm.start, Some(String::from(CM_TITLE))));
+        points.push((cm.end, title_at(cm.end)));
+    }
+    points.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut chapters: Vec<Chapter> = Vec::new();
+    for (i, (start, title)) in points.iter().enumerate() {
+        let chapter_end = points.get(i + 1).map_or(end, |p| p.0);
+        if chapter_end - start < MIN_CHAPTER {
+            continue;
+        }
+        let title = title
+            .clone()
+            .unwrap_or_else(|| format!("Chapter {}", chapters.len() + 1));
+        chapters.push(Chapter {
+            start: *start,
+            end: chapter_end,
+            title,
+        });
+    }
+    // skipped points leave gaps.
+    for i in 1..chapters.len() {
+        chapters[i - 1].end = chapters[i].start;
+    }
+    if let Some(first) = chapters.first_mut() {
+        first.start = 0.0;
+    }
+    chapters
+}
+
+fn escape_ffmetadata(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '=' | ';' | '#' | '\\' | '\n') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+fn format_ogm_time(t: f64) -> String {
+    let ms = (t * 1000.0).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        ms / 3_600_000,
+        ms / 60_000 % 60,
+        ms / 1000 % 60,
+        ms % 1000
+    )
+}
+
+fn render(chapters: &[Chapter], format: Format) -> String {
+    let mut out = String::new();
+    match format {
+        Format::Ffmetadata => {
+            out.push_str(";FFMETADATA1\n");
+            for c in chapters.iter() {
+                out.push_str("\n[CHAPTER]\nTIMEBASE=1/1000\n");
+                out.push_str(&format!("START={}\n", (c.start * 1000.0).round() as u64));
+                out.push_str(&format!("END={}\n", (c.end * 1000.0).round() as u64));
+                out.push_str(&format!("title={}\n", escape_ffmetadata(&c.title)));
+            }
+        }
+        Format::Ogm => {
+            for (i, c) in chapters.iter().enumerate() {
+                out.push_str(&format!(
+                    "CHAPTER{:02}={}\n",
+                    i + 1,
+                    format_ogm_time(c.start)
+                ));
+                out.push_str(&format!("CHAPTER{:02}NAME={}\n", i + 1, c.title));
+            }
+        }
+    }
+    out
+}
+
+pub async fn run(input: Option<PathBuf>, output: Option<PathBuf>, format: Format) -> Result<()> {
+    let mut output = path_to_async_write(output).await?;
+    let packets = path_to_packets(input, false).await?;
+    let packets = common::strip_error_packets(packets);
+    let mut cueable_packets = cueable(packets);
+    let meta = common::find_main_meta(
+        &mut cueable_packets,
+        None,
+        &[common::StreamKind::Video, common::StreamKind::Audio],
+    )
+    .await?;
+    let packets = cueable_packets.cue_up();
+
+    let mut demuxer = ts::Demuxer::new();
+    let video = cm::scan_video(meta.video_stream_type(), demuxer.register(meta.video_pid()));
+    let audio = cm::scan_audio(demuxer.register(meta.audio_pid()));
+    let schedule_stream = demuxer
+        .register(meta.video_pid())
+        .merge(demuxer.register(psi::TOT_PID))
+        .merge(demuxer.register(ts::EIT_PIDS[0]));
+    let schedule = scan_schedule(&meta, schedule_stream);
+    let (_, key_pictures, audio, schedule) =
+        tokio::join!(demuxer.run(packets), video, audio, schedule);
+    let (key_pictures, audio) = (key_pictures?, audio?);
+    let base = cm::base_pts(&key_pictures, &audio)?;
+    let end = key_pictures
+        .last()
+        .map_or(0.0, |pts| common::pts_diff(*pts, base));
+
+    let cms = cm::find_cm_breaks(&key_pictures, &audio, base);
+    let chapters = make_chapters(&schedule, &cms, base, end);
+    output
+        .write_all(render(&chapters, format).as_bytes())
+        .await?;
+    output.flush().await?;
+    Ok(())
+}
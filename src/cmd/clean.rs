@@ -1,30 +1,40 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::PathBuf;
 
 use anyhow::{bail, Result};
 use bytes::{Bytes, BytesMut};
-use log::info;
+use log::{info, warn};
+use serde_derive::Serialize;
 use tokio;
 use tokio::fs::File;
 use tokio::io::AsyncWriteExt;
-use tokio::sync::mpsc::channel;
+use tokio::sync::mpsc::{channel, Receiver, Sender};
 use tokio_stream::wrappers::ReceiverStream;
 use tokio_stream::{Stream, StreamExt};
-use tokio_util::codec::FramedRead;
 
-use super::common::strip_error_packets;
-use super::io::{path_to_async_read, path_to_async_write};
-use crate::crc32;
-use crate::psi;
-use crate::stream::cueable;
-use crate::ts;
+use super::common::{interrupt_on_ctrl_c, strip_error_packets};
+use super::errors;
+use super::io::{path_to_async_write, paths_to_packets};
+use tstools::psi;
+use tstools::stream::cueable;
+use tstools::ts;
+
+// writing each 188 bytes packet directly makes clean bound on syscalls, so
+// packets are written in chunks of this size.
+const OUTPUT_CHUNK: usize = 1 << 20;
+// bounds of the queues between the reader, filter and writer tasks.
+const PACKET_QUEUE: usize = 4096;
+const CHUNK_QUEUE: usize = 4;
+// EIT tables of the actual stream, p/f and schedule.
+const EIT_ACTUAL_PF_TABLE_ID: u8 = 0x4e;
+const EIT_ACTUAL_SCHEDULE_TABLE_IDS: std::ops::RangeInclusive<u8> = 0x50..=0x5f;
 
 async fn find_pids_from_pat<S: Stream<Item = ts::TSPacket> + Unpin>(
     s: &mut S,
     service_index: Option<usize>,
-) -> Result<(Option<u16>, HashSet<u16>)> {
+) -> Result<(Option<u16>, HashMap<u16, u16>)> {
     let pat_stream = s.filter(|packet| packet.pid == ts::PAT_PID);
-    let mut buffer = psi::Buffer::new(pat_stream);
+    let mut buffer = psi::Buffer::new(pat_stream).verify_crc();
     loop {
         match buffer.next().await {
             Some(Ok(bytes)) => {
@@ -39,7 +49,8 @@ async fn find_pids_from_pat<S: Stream<Item = ts::TSPacket> + Unpin>(
                         }
                     };
                     let mut network_pid = None;
-                    let mut pmt_pids = HashSet::new();
+                    // program numbers by PMT pid.
+                    let mut pmt_pids = HashMap::new();
                     let mut idx = 0usize;
                     for (program_number, pid) in pas.program_association {
                         if program_number == 0 {
@@ -50,7 +61,7 @@ async fn find_pids_from_pat<S: Stream<Item = ts::TSPacket> + Unpin>(
                                 program_number, pid
                             );
                             if service_index.is_none() || idx == service_index.unwrap() {
-                                pmt_pids.insert(pid);
+                                pmt_pids.insert(pid, program_number);
                             }
                             idx += 1;
                         }
@@ -69,7 +80,7 @@ async fn find_keep_pids_from_pmt<S: Stream<Item = ts::TSPacket> + Unpin>(
     pmt_pid: u16,
     pmt_stream: S,
 ) -> Result<HashSet<u16>> {
-    let mut buffer = psi::Buffer::new(pmt_stream);
+    let mut buffer = psi::Buffer::new(pmt_stream).verify_crc();
     loop {
         match buffer.next().await {
             Some(Ok(bytes)) => {
@@ -103,33 +114,18 @@ async fn find_keep_pids_from_pmt<S: Stream<Item = ts::TSPacket> + Unpin>(
 }
 
 async fn find_keep_pids_from_pmts<S: Stream<Item = ts::TSPacket> + Unpin>(
-    pmt_pids: HashSet<u16>,
+    pmt_pids: &HashMap<u16, u16>,
     s: &mut S,
 ) -> Result<HashSet<u16>> {
+    let mut demuxer = ts::Demuxer::new();
     let mut handles = Vec::new();
-    let mut tx_map = HashMap::new();
-    for pid in pmt_pids.iter() {
-        let (tx, rx) = channel(1);
-        tx_map.insert(pid, tx);
+    for pid in pmt_pids.keys() {
         handles.push(tokio::spawn(find_keep_pids_from_pmt(
             *pid,
-            ReceiverStream::new(rx),
+            demuxer.register(*pid),
         )));
     }
 
-    let transfer = async move {
-        while !tx_map.is_empty() {
-            if let Some(packet) = s.next().await {
-                let pid = packet.pid;
-                if let Some(tx) = tx_map.get_mut(&pid) {
-                    if tx.send(packet).await.is_err() {
-                        tx_map.remove(&pid);
-                    }
-                }
-            }
-        }
-    };
-
     let receiver = async move {
         let mut pids = HashSet::new();
         for handle in handles.into_iter() {
@@ -140,93 +136,352 @@ async fn find_keep_pids_from_pmts<S: Stream<Item = ts::TSPacket> + Unpin>(
         Ok(pids)
     };
 
-    tokio::join!(transfer, receiver).1
+    tokio::join!(demuxer.run(s), receiver).1
 }
 
+// returns the kept pids and the program numbers of the kept services.
 async fn find_keep_pids<S: Stream<Item = ts::TSPacket> + Unpin>(
     s: &mut S,
     service_index: Option<usize>,
-) -> Result<HashSet<u16>> {
+) -> Result<(HashSet<u16>, HashSet<u16>)> {
     let (network_pid, pmt_pids) = find_pids_from_pat(s, service_index).await?;
-    let mut keep_pids = find_keep_pids_from_pmts(pmt_pids, s).await?;
+    let mut keep_pids = find_keep_pids_from_pmts(&pmt_pids, s).await?;
+    let services = pmt_pids
+        .iter()
+        .filter(|(pid, _)| keep_pids.contains(pid))
+        .map(|(_, program_number)| *program_number)
+        .collect();
     if let Some(network_pid) = network_pid {
         keep_pids.insert(network_pid);
     }
-    Ok(keep_pids)
+    Ok((keep_pids, services))
 }
 
-fn retain_keep_pids(packet: ts::TSPacket, pids: &HashSet<u16>) -> Bytes {
-    let mut out = BytesMut::with_capacity(ts::TS_PACKET_LENGTH);
+fn retain_keep_pids(section: &[u8], pids: &HashSet<u16>) -> Result<Bytes> {
+    let pas = psi::ProgramAssociationSection::parse(section)?;
+    let program_association: Vec<_> = pas
+        .program_association
+        .iter()
+        .filter(|(program_number, pid)| *program_number == 0 || pids.contains(pid))
+        .cloned()
+        .collect();
+    let section = psi::SectionBuilder::pat(pas.transport_stream_id, &program_association)
+        .version_number(pas.version_number)
+        .current_next_indicator(pas.current_next_indicator)
+        .section_number(pas.section_number)
+        .last_section_number(pas.last_section_number)
+        .build()?;
+    Ok(section)
+}
 
-    let bytes = packet.into_raw();
-    let adaptation_field_control = (bytes[3] & 0x30) >> 4;
-    let data_offset = match adaptation_field_control {
-        0b10 | 0b11 => 4 + 1 + usize::from(bytes[4]),
-        _ => 4,
-    };
-    let data = &bytes[data_offset..];
-    let pat_offset = data_offset + 1 + usize::from(data[0]);
-    let pat = &bytes[pat_offset..];
-    let section_length = (usize::from(pat[1] & 0xf) << 8) | usize::from(pat[2]);
+fn retain_keep_services(section: &[u8], services: &HashSet<u16>) -> Result<Bytes> {
+    let sds = psi::ServiceDescriptionSection::parse(section)?;
+    let kept: Vec<_> = sds
+        .services
+        .iter()
+        .filter(|service| services.contains(&service.service_id))
+        .map(|service| psi::SdtService {
+            service_id: service.service_id,
+            eit_user_defined_flags: service.eit_user_defined_flags,
+            eit_schedule_flag: service.eit_schedule_flag,
+            eit_present_following_flag: service.eit_present_following_flag,
+            running_status: service.running_status,
+            free_ca_mode: service.free_ca_mode,
+            descriptors: service.descriptors.as_bytes(),
+        })
+        .collect();
+    let section = psi::SectionBuilder::sdt(sds.transport_stream_id, sds.original_network_id, &kept)
+        .version_number(sds.version_number)
+        .current_next_indicator(sds.current_next_indicator)
+        .section_number(sds.section_number)
+        .last_section_number(sds.last_section_number)
+        .build()?;
+    Ok(section)
+}
 
-    // copy data before the map.
-    out.extend_from_slice(&bytes[..pat_offset + 8]);
+// rewrites a section of PAT or SDT to refer only to the kept pids and
+// services, or returns None to drop the section, such as EIT of other
+// services and tables of other streams.
+fn rewrite_section(
+    section: Bytes,
+    pids: &HashSet<u16>,
+    services: &HashSet<u16>,
+) -> Result<Option<Bytes>> {
+    let table_id = section[0];
+    match table_id {
+        psi::PROGRAM_ASSOCIATION_SECTION => Ok(Some(retain_keep_pids(&section[..], pids)?)),
+        psi::SELF_STREAM_TABLE_ID => Ok(Some(retain_keep_services(&section[..], services)?)),
+        _ if table_id == EIT_ACTUAL_PF_TABLE_ID
+            || EIT_ACTUAL_SCHEDULE_TABLE_IDS.contains(&table_id) =>
+        {
+            let eit = psi::EventInformationSection::parse(&section[..])?;
+            let kept = services.contains(&eit.service_id);
+            Ok(kept.then_some(section))
+        }
+        _ => Ok(None),
+    }
+}
 
-    let mut map = &pat[8..3 + section_length - 4];
-    let mut new_map_bytes: usize = 0;
-    while map.len() > 0 {
-        let program_number = (u16::from(map[0]) << 8) | u16::from(map[1]);
-        let pid = (u16::from(map[2] & 0x1f) << 8) | u16::from(map[3]);
-        if program_number == 0 || pids.contains(&pid) {
-            out.extend_from_slice(&map[0..4]);
-            new_map_bytes += 4;
+/// How packets with nonzero transport_scrambling_control are handled.
+#[derive(Clone, Copy)]
+pub enum Scrambled {
+    Keep,
+    DropPackets,
+    /// Drops the whole PID once this many scrambled packets are found in it.
+    DropPid(u64),
+}
+
+// sections of a PID being rebuilt.
+struct SectionRewriter {
+    buffer: psi::Buffer<()>,
+    packetizer: Option<psi::SectionPacketizer>,
+}
+
+#[derive(Serialize, Default)]
+struct PidStats {
+    pid: u16,
+    read: u64,
+    kept: u64,
+    dropped: u64,
+    cc_errors: u64,
+    scrambled: u64,
+    // the PID is dropped as it is scrambled.
+    #[serde(skip)]
+    dropped_pid: bool,
+}
+
+impl PidStats {
+    // counts a scrambled packet, and returns true if the packet is dropped.
+    fn check_scrambled(&mut self, packet: &ts::TSPacket, policy: Scrambled) -> bool {
+        if packet.transport_scrambling_control != 0 {
+            self.scrambled += 1;
+        }
+        match policy {
+            Scrambled::Keep => false,
+            Scrambled::DropPackets => packet.transport_scrambling_control != 0,
+            Scrambled::DropPid(threshold) => {
+                if !self.dropped_pid && self.scrambled >= threshold {
+                    warn!("drop pid {:#06x} as it is scrambled", self.pid);
+                    self.dropped_pid = true;
+                }
+                self.dropped_pid || packet.transport_scrambling_control != 0
+            }
         }
-        map = &map[4..];
     }
+}
 
-    // set new section_length
-    let new_section_length = 5 + new_map_bytes + 4;
-    out[pat_offset + 1] &= 0xf0;
-    out[pat_offset + 1] |= (new_section_length >> 8) as u8;
-    out[pat_offset + 2] = new_section_length as u8;
+#[derive(Serialize, Default)]
+struct Stats {
+    packets_read: u64,
+    packets_kept: u64,
+    packets_dropped: u64,
+    bytes_written: u64,
+    cc_errors: u64,
+    scrambled: u64,
+    pids: Vec<PidStats>,
+}
 
-    let crc = crc32::crc32(&out[pat_offset..pat_offset + 3 + new_section_length - 4]);
-    out.extend_from_slice(&crc.to_be_bytes()[..]);
+impl Stats {
+    fn new(pids: BTreeMap<u16, PidStats>) -> Stats {
+        let mut stats = Stats::default();
+        for (_, mut pid) in pids.into_iter() {
+            // rebuilt sections may have fewer packets than read.
+            pid.dropped = pid.read.saturating_sub(pid.kept);
+            stats.packets_read += pid.read;
+            stats.packets_kept += pid.kept;
+            stats.packets_dropped += pid.dropped;
+            stats.bytes_written += pid.kept * ts::TS_PACKET_LENGTH as u64;
+            stats.cc_errors += pid.cc_errors;
+            stats.scrambled += pid.scrambled;
+            stats.pids.push(pid);
+        }
+        stats
+    }
 
-    // fill padding.
-    out.resize(ts::TS_PACKET_LENGTH, 0);
+    fn print(&self, json: bool) -> Result<()> {
+        if json {
+            eprintln!("{}", serde_json::to_string(self)?);
+            return Ok(());
+        }
+        eprintln!(
+            "read {} packets, kept {}, dropped {}, wrote {} bytes, {} cc errors, {} scrambled",
+            self.packets_read,
+            self.packets_kept,
+            self.packets_dropped,
+            self.bytes_written,
+            self.cc_errors,
+            self.scrambled
+        );
+        for pid in self.pids.iter() {
+            eprintln!(
+                "  pid {:#06x}: read {}, kept {}, dropped {}, {} cc errors, {} scrambled",
+                pid.pid, pid.read, pid.kept, pid.dropped, pid.cc_errors, pid.scrambled
+            );
+        }
+        Ok(())
+    }
+}
+
+async fn read_packets<S: Stream<Item = ts::TSPacket> + Unpin>(mut s: S, tx: Sender<ts::TSPacket>) {
+    while let Some(packet) = s.next().await {
+        if tx.send(packet).await.is_err() {
+            break;
+        }
+    }
+}
 
-    out.freeze()
+async fn write_chunks(mut rx: Receiver<Bytes>, mut out: File) -> Result<()> {
+    while let Some(chunk) = rx.recv().await {
+        out.write_all(&chunk[..]).await?;
+    }
+    out.flush().await?;
+    Ok(())
 }
 
-async fn dump_packets<S: Stream<Item = ts::TSPacket> + Unpin>(
+// sends kept packets to the writer, stopping early if the writer is gone.
+// With keep_si, SDT, EIT and TOT are kept too.
+async fn filter_packets<S: Stream<Item = ts::TSPacket> + Unpin>(
     mut s: S,
-    pids: HashSet<u16>,
-    mut out: File,
-) -> Result<()> {
+    mut pids: HashSet<u16>,
+    services: HashSet<u16>,
+    keep_si: bool,
+    scrambled: Scrambled,
+    mut restamper: Option<ts::Restamper>,
+    tx: Sender<Bytes>,
+) -> Result<Stats> {
+    let mut chunk = BytesMut::with_capacity(OUTPUT_CHUNK);
+    let mut emit = |chunk: &mut BytesMut, packet: ts::TSPacket| match restamper.as_mut() {
+        Some(restamper) => {
+            for raw in restamper.push(packet) {
+                chunk.extend_from_slice(&raw[..]);
+            }
+        }
+        None => chunk.extend_from_slice(&packet.into_raw()[..]),
+    };
+    // sections may span packets, so they are rebuilt from whole sections.
+    let mut rewrite_pids = vec![ts::PAT_PID];
+    if keep_si {
+        rewrite_pids.push(psi::SDT_PID);
+        rewrite_pids.extend_from_slice(&ts::EIT_PIDS);
+        pids.insert(psi::TOT_PID);
+    }
+    let mut rewriters: HashMap<u16, SectionRewriter> = rewrite_pids
+        .into_iter()
+        .map(|pid| {
+            let rewriter = SectionRewriter {
+                buffer: psi::Buffer::detached().verify_crc(),
+                packetizer: None,
+            };
+            (pid, rewriter)
+        })
+        .collect();
+    let mut pid_stats: BTreeMap<u16, PidStats> = BTreeMap::new();
+    let mut continuity = errors::Continuity::new();
     while let Some(packet) = s.next().await {
-        if packet.pid == ts::PAT_PID {
-            if !packet.transport_error_indicator {
-                out.write(&retain_keep_pids(packet, &pids)[..]).await?;
+        let stats = pid_stats.entry(packet.pid).or_insert_with(|| PidStats {
+            pid: packet.pid,
+            ..Default::default()
+        });
+        stats.read += 1;
+        if continuity.check(&packet) {
+            stats.cc_errors += 1;
+        }
+        if let Some(rewriter) = rewriters.get_mut(&packet.pid) {
+            let pid = packet.pid;
+            let packetizer = rewriter
+                .packetizer
+                .get_or_insert_with(|| psi::SectionPacketizer::new(pid, packet.continuity_counter));
+            if let Err(e) = rewriter.buffer.feed(packet) {
+                warn!("drop packet of pid {:#06x}: {:?}", pid, e);
+                continue;
+            }
+            while let Some(section) = rewriter.buffer.next_section() {
+                let section = match rewrite_section(section, &pids, &services) {
+                    Ok(Some(section)) => section,
+                    Ok(None) => continue,
+                    Err(e) => {
+                        warn!("drop section of pid {:#06x}: {:?}", pid, e);
+                        continue;
+                    }
+                };
+                for packet in packetizer.packetize(&section[..])?.into_iter() {
+                    emit(&mut chunk, packet);
+                    stats.kept += 1;
+                }
             }
-        } else if pids.contains(&packet.pid) {
-            out.write(&packet.into_raw()[..]).await?;
+        } else if pids.contains(&packet.pid) && !stats.check_scrambled(&packet, scrambled) {
+            emit(&mut chunk, packet);
+            stats.kept += 1;
+        }
+        if chunk.len() + ts::TS_PACKET_LENGTH > OUTPUT_CHUNK {
+            let full = chunk.split().freeze();
+            if tx.send(full).await.is_err() {
+                break;
+            }
+            chunk.reserve(OUTPUT_CHUNK);
         }
     }
-    Ok(())
+    if let Some(restamper) = restamper.as_mut() {
+        for raw in restamper.finish() {
+            chunk.extend_from_slice(&raw[..]);
+        }
+    }
+    if !chunk.is_empty() {
+        // the writer reports its error if it is gone.
+        let _ = tx.send(chunk.freeze()).await;
+    }
+    for (pid, rewriter) in rewriters.iter() {
+        if rewriter.buffer.crc_errors() > 0 {
+            warn!(
+                "dropped {} sections of pid {:#06x} by crc error",
+                rewriter.buffer.crc_errors(),
+                pid
+            );
+        }
+    }
+    Ok(Stats::new(pid_stats))
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn run(
-    input: Option<PathBuf>,
+    input: Vec<PathBuf>,
     output: Option<PathBuf>,
     service_index: Option<usize>,
+    keep_si: bool,
+    scrambled: Scrambled,
+    restamp: Option<f64>,
+    progress: bool,
+    json_stats: bool,
 ) -> Result<()> {
-    let input = path_to_async_read(input).await?;
+    if restamp.is_some_and(|base| base < 0.0) {
+        bail!("restamp base must not be negative: {:?}", restamp);
+    }
+    let restamper = restamp.map(|base| ts::Restamper::new((base * ts::PCR_HZ as f64) as u64));
     let output = path_to_async_write(output).await?;
-    let packets = FramedRead::new(input, ts::TSPacketDecoder::new());
-    let packets = strip_error_packets(packets);
+    let packets = paths_to_packets(input, progress).await?;
+    let packets = interrupt_on_ctrl_c(strip_error_packets(packets));
     let mut cueable_packets = cueable(packets);
-    let pids = find_keep_pids(&mut cueable_packets, service_index).await?;
+    let (pids, services) = find_keep_pids(&mut cueable_packets, service_index).await?;
     let packets = cueable_packets.cue_up();
-    dump_packets(packets, pids, output).await
+
+    // decoding, filtering and writing run on their own tasks, so that reading
+    // and writing the disk overlap.
+    let (packet_tx, packet_rx) = channel(PACKET_QUEUE);
+    let (chunk_tx, chunk_rx) = channel(CHUNK_QUEUE);
+    let reader = tokio::spawn(read_packets(packets, packet_tx));
+    let filter = tokio::spawn(filter_packets(
+        ReceiverStream::new(packet_rx),
+        pids,
+        services,
+        keep_si,
+        scrambled,
+        restamper,
+        chunk_tx,
+    ));
+    let writer = tokio::spawn(write_chunks(chunk_rx, output));
+    let (reader, filter, writer) = tokio::join!(reader, filter, writer);
+    writer??;
+    let stats = filter??;
+    reader?;
+    stats.print(json_stats)
 }
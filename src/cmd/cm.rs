@@ -0,0 +1,306 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use log::{debug, info};
+use serde_derive::Serialize;
+use tokio_stream::{Stream, StreamExt};
+
+use super::common;
+use super::errors;
+use super::io::path_to_packets;
+use tstools::adts;
+use tstools::pes;
+use tstools::stream::cueable;
+use tstools::ts;
+
+const SAMPLES_PER_FRAME: u64 = 1024;
+// AAC frames of digital silence are a few bytes per channel.
+const SILENT_FRAME_BYTES_PER_CHANNEL: usize = 12;
+// shorter pauses are a part of speech.
+const MIN_SILENCE: f64 = 0.1;
+// how far a hint may be from a silence to belong to the same boundary.
+const HINT_MARGIN: f64 = 0.5;
+// CMs in Japanese broadcasting are multiples of 15 seconds.
+const CM_UNIT: f64 = 15.0;
+const MAX_CM_UNITS: u32 = 4;
+const CM_TOLERANCE: f64 = 0.5;
+// a key picture earlier than this ratio of the usual GOP is a scene cut.
+const SHORT_GOP_RATIO: f64 = 0.9;
+
+#[derive(Serialize, Clone)]
+struct Boundary {
+    time: f64,
+    silence_start: Option<f64>,
+    silence_end: Option<f64>,
+    audio_mode_change: bool,
+    scene_cut: bool,
+}
+
+/// A CM break in seconds from the first key picture.
+#[derive(Serialize)]
+pub struct Cm {
+    pub start: f64,
+    pub end: f64,
+    duration: f64,
+    spots: usize,
+    boundaries: Vec<Boundary>,
+}
+
+#[derive(Default)]
+pub struct Audio {
+    first_pts: Option<u64>,
+    silences: Vec<(u64, u64)>,
+    mode_changes: Vec<u64>,
+}
+
+/// Collects the PTS of key pictures.
+pub async fn scan_video<S: Stream<Item = ts::TSPacket> + Unpin>(
+    stream_type: u8,
+    s: S,
+) -> Result<Vec<u64>> {
+    let mut buffer = pes::Buffer::new(s);
+    let mut finder = common::KeyPictureFinder::new(stream_type);
+    let mut key_pictures = Vec::new();
+    while let Some(bytes) = buffer.next().await {
+        let bytes = match bytes {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                info!("pes buffer error: {:?}", e);
+                continue;
+            }
+        };
+        let pes = match pes::PESPacket::parse(&bytes[..]) {
+            Ok(pes) => pes,
+            Err(e) => {
+                info!("pes parse error: {:?}", e);
+                errors::count(errors::Kind::Pes);
+                continue;
+            }
+        };
+        if let pes::PESPacketBody::NormalPESPacketBody(ref body) = pes.body {
+            if finder.feed(body.pes_packet_data_byte) {
+                if let Some(pts) = body.pts {
+                    key_pictures.push(pts);
+                }
+            }
+        }
+    }
+    Ok(key_pictures)
+}
+
+/// Collects silent ranges and changes of channel configuration, with the
+/// timestamp of each frame interpolated from the PES.
+pub async fn scan_audio<S: Stream<Item = ts::TSPacket> + Unpin>(s: S) -> Result<Audio> {
+    let mut buffer = pes::Buffer::new(s);
+    let mut audio = Audio::default();
+    let mut channel_configuration = None;
+    let mut silence_start = None;
+    let mut last_pts = 0;
+    while let Some(bytes) = buffer.next().await {
+        let bytes = match bytes {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                info!("pes buffer error: {:?}", e);
+                continue;
+            }
+        };
+        let pes = match pes::PESPacket::parse(&bytes[..]) {
+            Ok(pes) => pes,
+            Err(e) => {
+                info!("pes parse error: {:?}", e);
+                errors::count(errors::Kind::Pes);
+                continue;
+            }
+        };
+        let body = match pes.body {
+            pes::PESPacketBody::NormalPESPacketBody(ref body) => body,
+            _ => continue,
+        };
+        let mut pts = match body.pts {
+            Some(pts) => pts,
+            None => continue,
+        };
+        audio.first_pts.get_or_insert(pts);
+        for (header, _) in adts::frames(body.pes_packet_data_byte) {
+            let sample_rate = match header.sample_rate() {
+                Some(rate) => u64::from(rate),
+                None => continue,
+            };
+            let previous = channel_configuration.replace(header.channel_configuration);
+            if previous.is_some_and(|c| c != header.channel_configuration) {
+                debug!("audio mode change at {}", pts);
+                audio.mode_changes.push(pts);
+            }
+            // dual mono has no channel count in the header.
+            let channels = usize::from(header.channels().unwrap_or(2));
+            let silent = header.frame_length - adts::HEADER_LENGTH
+                <= SILENT_FRAME_BYTES_PER_CHANNEL * channels;
+            match (silent, silence_start) {
+                (true, None) => silence_start = Some(pts),
+                (false, Some(start)) => {
+                    audio.silences.push((start, pts));
+                    silence_start = None;
+                }
+                _ => {}
+            }
+            pts += SAMPLES_PER_FRAME * pes::PTS_HZ / sample_rate;
+            last_pts = pts;
+        }
+    }
+    if let Some(start) = silence_start {
+        audio.silences.push((start, last_pts));
+    }
+    Ok(audio)
+}
+
+// returns the key pictures which come before the usual GOP length.
+fn scene_cuts(key_pictures: &[u64]) -> Vec<u64> {
+    let mut intervals: Vec<f64> = key_pictures
+        .windows(2)
+        .map(|w| common::pts_diff(w[1], w[0]))
+        .filter(|d| *d > 0.0)
+        .collect();
+    if intervals.is_empty() {
+        return Vec::new();
+    }
+    intervals.sort_by(|a, b| a.total_cmp(b));
+    let gop = intervals[intervals.len() / 2];
+    key_pictures
+        .windows(2)
+        .filter(|w| {
+            let d = common::pts_diff(w[1], w[0]);
+            d > 0.0 && d < gop * SHORT_GOP_RATIO
+        })
+        .map(|w| w[1])
+        .collect()
+}
+
+fn find_boundaries(audio: &Audio, cuts: &[u64], base: u64) -> Vec<Boundary> {
+    let time = |pts: u64| common::pts_diff(pts, base);
+    let near = |times: &[f64], start: f64, end: f64| {
+        times
+            .iter()
+            .any(|t| *t >= start - HINT_MARGIN && *t <= end + HINT_MARGIN)
+    };
+    let changes: Vec<f64> = audio.mode_changes.iter().map(|pts| time(*pts)).collect();
+    let cuts: Vec<f64> = cuts.iter().map(|pts| time(*pts)).collect();
+    let mut boundaries: Vec<Boundary> = audio
+        .silences
+        .iter()
+        .map(|(start, end)| (time(*start), time(*end)))
+        .filter(|(start, end)| end - start >= MIN_SILENCE)
+        .map(|(start, end)| Boundary {
+            time: (start + end) / 2.0,
+            silence_start: Some(start),
+            silence_end: Some(end),
+            audio_mode_change: near(&changes, start, end),
+            scene_cut: near(&cuts, start, end),
+        })
+        .collect();
+    // mode changes are boundaries even without silence.
+    for change in changes.iter() {
+        if boundaries.iter().any(|b| {
+            b.audio_mode_change
+                && near(
+                    &[*change],
+                    b.silence_start.unwrap_or(b.time),
+                    b.silence_end.unwrap_or(b.time),
+                )
+        }) {
+            continue;
+        }
+        boundaries.push(Boundary {
+            time: *change,
+            silence_start: None,
+            silence_end: None,
+            audio_mode_change: true,
+            scene_cut: near(&cuts, *change, *change),
+        });
+    }
+    boundaries.sort_by(|a, b| a.time.total_cmp(&b.time));
+    boundaries
+}
+
+fn is_cm_length(duration: f64) -> bool {
+    (1..=MAX_CM_UNITS).any(|n| (duration - CM_UNIT * f64::from(n)).abs() <= CM_TOLERANCE)
+}
+
+// chains boundaries spaced by CM lengths, and takes chains of two or more
+// spots as CM breaks.
+fn find_cms(boundaries: &[Boundary]) -> Vec<Cm> {
+    let mut cms = Vec::new();
+    let mut i = 0;
+    while i < boundaries.len() {
+        let mut chain = vec![i];
+        let mut current = i;
+        while let Some(next) = (current + 1..boundaries.len())
+            .take_while(|j| {
+                boundaries[*j].time - boundaries[current].time
+                    <= CM_UNIT * f64::from(MAX_CM_UNITS) + CM_TOLERANCE
+            })
+            .find(|j| is_cm_length(boundaries[*j].time - boundaries[current].time))
+        {
+            chain.push(next);
+            current = next;
+        }
+        if chain.len() > 2 {
+            let start = boundaries[chain[0]].time;
+            let end = boundaries[current].time;
+            cms.push(Cm {
+                start,
+                end,
+                duration: end - start,
+                spots: chain.len() - 1,
+                boundaries: chain.iter().map(|j| boundaries[*j].clone()).collect(),
+            });
+            i = current + 1;
+        } else {
+            i += 1;
+        }
+    }
+    cms
+}
+
+/// Returns the PTS which times are measured from.
+pub fn base_pts(key_pictures: &[u64], audio: &Audio) -> Result<u64> {
+    match key_pictures.first().copied().or(audio.first_pts) {
+        Some(pts) => Ok(pts),
+        None => bail!("no pts found"),
+    }
+}
+
+pub fn find_cm_breaks(key_pictures: &[u64], audio: &Audio, base: u64) -> Vec<Cm> {
+    find_cms(&find_boundaries(audio, &scene_cuts(key_pictures), base))
+}
+
+pub async fn run(input: Option<PathBuf>, print_boundaries: bool) -> Result<()> {
+    let packets = path_to_packets(input, false).await?;
+    let packets = common::strip_error_packets(packets);
+    let mut cueable_packets = cueable(packets);
+    let meta = common::find_main_meta(
+        &mut cueable_packets,
+        None,
+        &[common::StreamKind::Video, common::StreamKind::Audio],
+    )
+    .await?;
+    let packets = cueable_packets.cue_up();
+
+    let mut demuxer = ts::Demuxer::new();
+    let video = scan_video(meta.video_stream_type(), demuxer.register(meta.video_pid()));
+    let audio = scan_audio(demuxer.register(meta.audio_pid()));
+    let (_, key_pictures, audio) = tokio::join!(demuxer.run(packets), video, audio);
+    let (key_pictures, audio) = (key_pictures?, audio?);
+    let base = base_pts(&key_pictures, &audio)?;
+
+    if print_boundaries {
+        let boundaries = find_boundaries(&audio, &scene_cuts(&key_pictures), base);
+        for boundary in boundaries.iter() {
+            println!("{}", serde_json::to_string(boundary)?);
+        }
+        return Ok(());
+    }
+    for cm in find_cm_breaks(&key_pictures, &audio, base).iter() {
+        println!("{}", serde_json::to_string(cm)?);
+    }
+    Ok(())
+}
@@ -1,27 +1,141 @@
-use anyhow::{bail, Result};
-use log::{debug, info};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::pin::Pin;
+
+use anyhow::{anyhow, bail, Result};
+use bytes::{Bytes, BytesMut};
+use chrono::offset::FixedOffset;
+use chrono::DateTime;
+use clap::ValueEnum;
+use log::{debug, info, warn};
+use serde_derive::Serialize;
 use tokio_stream::{Stream, StreamExt};
 
-use crate::arib::caption::is_caption;
-use crate::h262;
-use crate::pes;
-use crate::psi;
-use crate::ts;
+use super::errors;
+use tstools::arib;
+use tstools::arib::caption::is_caption;
+use tstools::h262;
+use tstools::h264;
+use tstools::pes;
+use tstools::psi;
+use tstools::psi::descriptor::LocalTimeOffset;
+use tstools::stream::{interruptible, Interruptible};
+use tstools::ts;
+
+/// Kinds of streams a command needs in the service.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StreamKind {
+    Video,
+    Audio,
+    Caption,
+}
+
+impl StreamKind {
+    fn name(self) -> &'static str {
+        match self {
+            StreamKind::Video => "video",
+            StreamKind::Audio => "audio",
+            StreamKind::Caption => "caption",
+        }
+    }
+}
+
+/// Normalizations of decoded strings.
+#[derive(ValueEnum, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Normalization {
+    /// Full-width alphanumerics into ASCII.
+    Width,
+    /// Compatibility characters such as half-width katakana and enclosed
+    /// symbols into their canonical forms.
+    Nfkc,
+    /// FULLWIDTH TILDE into WAVE DASH.
+    WaveDash,
+}
+
+pub fn normalizer(normalizations: &[Normalization]) -> arib::normalize::Normalizer {
+    arib::normalize::Normalizer {
+        half_width_alphanumerics: normalizations.contains(&Normalization::Width),
+        nfkc: normalizations.contains(&Normalization::Nfkc),
+        wave_dash: normalizations.contains(&Normalization::WaveDash),
+    }
+}
+
+/// Parses a number in decimal, or in hex with 0x.
+pub fn parse_number<T: TryFrom<u32>>(s: &str) -> Result<T> {
+    let n = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => u32::from_str_radix(hex, 16)?,
+        None => s.parse::<u32>()?,
+    };
+    T::try_from(n).map_err(|_| anyhow!("{} is out of range", s))
+}
+
+/// Parses a PID of arguments, in decimal or in hex with 0x.
+pub fn parse_pid(s: &str) -> Result<u16> {
+    let pid = parse_number(s)?;
+    if pid > ts::NULL_PID {
+        bail!("PID {} is out of 13 bits", s);
+    }
+    Ok(pid)
+}
 
+/// PIDs of the first streams of each kind in the service.
 pub struct Meta {
-    pub audio_pid: u16,
-    pub video_pid: u16,
-    pub caption_pid: u16,
+    pub service_id: u16,
+    pub pmt_pid: u16,
+    /// None if the service has no PCR.
+    pub pcr_pid: Option<u16>,
+    /// The PID and stream type of the video.
+    pub video: Option<(u16, u8)>,
+    pub audio: Option<u16>,
+    pub caption: Option<u16>,
 }
 
-pub async fn find_main_meta<S: Stream<Item = ts::TSPacket> + Unpin>(s: &mut S) -> Result<Meta> {
-    let pid = find_main_pmt_pid(s).await?;
-    find_meta(pid, s).await
+// the accessors are for the kinds checked by find_main_meta.
+impl Meta {
+    fn has(&self, kind: StreamKind) -> bool {
+        match kind {
+            StreamKind::Video => self.video.is_some(),
+            StreamKind::Audio => self.audio.is_some(),
+            StreamKind::Caption => self.caption.is_some(),
+        }
+    }
+
+    pub fn video_pid(&self) -> u16 {
+        self.video.expect("video is not required").0
+    }
+
+    pub fn video_stream_type(&self) -> u8 {
+        self.video.expect("video is not required").1
+    }
+
+    pub fn audio_pid(&self) -> u16 {
+        self.audio.expect("audio is not required")
+    }
+
+    pub fn caption_pid(&self) -> u16 {
+        self.caption.expect("caption is not required")
+    }
+}
+
+/// Finds the streams of the service, or of the first service if `service_id`
+/// is None, waiting for a PMT which has all of the required kinds.
+pub async fn find_main_meta<S: Stream<Item = ts::TSPacket> + Unpin>(
+    s: &mut S,
+    service_id: Option<u16>,
+    required: &[StreamKind],
+) -> Result<Meta> {
+    let pid = find_pmt_pid(s, service_id).await?;
+    find_meta(pid, s, required).await
 }
 
-async fn find_meta<S: Stream<Item = ts::TSPacket> + Unpin>(pid: u16, s: &mut S) -> Result<Meta> {
+async fn find_meta<S: Stream<Item = ts::TSPacket> + Unpin>(
+    pid: u16,
+    s: &mut S,
+    required: &[StreamKind],
+) -> Result<Meta> {
     let pmt_stream = s.filter(move |packet| packet.pid == pid);
-    let mut buffer = psi::Buffer::new(pmt_stream);
+    let mut buffer = psi::Buffer::new(pmt_stream).verify_crc();
+    let mut found: Option<Meta> = None;
     loop {
         match buffer.next().await {
             Some(Ok(bytes)) => {
@@ -35,42 +149,63 @@ async fn find_meta<S: Stream<Item = ts::TSPacket> + Unpin>(pid: u16, s: &mut S)
                             continue;
                         }
                     };
-                    let mut video_pid = None;
-                    let mut audio_pid = None;
-                    let mut caption_pid = None;
                     debug!("stream info: {:#?}", pms.stream_info);
+                    let mut meta = Meta {
+                        service_id: pms.program_number,
+                        pmt_pid: pid,
+                        pcr_pid: Some(pms.pcr_pid).filter(|pid| *pid != ts::NULL_PID),
+                        video: None,
+                        audio: None,
+                        caption: None,
+                    };
                     for si in pms.stream_info.iter() {
-                        if caption_pid.is_none() && is_caption(&si) {
-                            caption_pid = Some(si.elementary_pid);
+                        if meta.caption.is_none() && is_caption(si) {
+                            meta.caption = Some(si.elementary_pid);
                         }
-                        if video_pid.is_none() && si.stream_type == psi::STREAM_TYPE_VIDEO {
-                            video_pid = Some(si.elementary_pid);
+                        if meta.video.is_none()
+                            && (si.stream_type == psi::STREAM_TYPE_VIDEO
+                                || si.stream_type == psi::STREAM_TYPE_H264)
+                        {
+                            meta.video = Some((si.elementary_pid, si.stream_type));
                         }
-                        if audio_pid.is_none() && si.stream_type == psi::STREAM_TYPE_ADTS {
-                            audio_pid = Some(si.elementary_pid);
+                        if meta.audio.is_none() && si.stream_type == psi::STREAM_TYPE_ADTS {
+                            meta.audio = Some(si.elementary_pid);
                         }
                     }
-                    match (video_pid, audio_pid, caption_pid) {
-                        (Some(video_pid), Some(audio_pid), Some(caption_pid)) => {
-                            return Ok(Meta {
-                                audio_pid,
-                                video_pid,
-                                caption_pid,
-                            });
-                        }
-                        _ => {}
+                    if required.iter().all(|kind| meta.has(*kind)) {
+                        return Ok(meta);
                     }
+                    found = Some(meta);
                 }
             }
             Some(Err(e)) => return Err(e.into()),
-            None => bail!("no meta found"),
+            None => match found {
+                Some(meta) => {
+                    let needs: Vec<&str> = required.iter().map(|kind| kind.name()).collect();
+                    let missing: Vec<&str> = required
+                        .iter()
+                        .filter(|kind| !meta.has(**kind))
+                        .map(|kind| kind.name())
+                        .collect();
+                    bail!(
+                        "needs {}, but the service has no {}",
+                        needs.join("+"),
+                        missing.join("+")
+                    )
+                }
+                None => bail!("no meta found"),
+            },
         }
     }
 }
 
-async fn find_main_pmt_pid<S: Stream<Item = ts::TSPacket> + Unpin>(s: &mut S) -> Result<u16> {
+/// Finds the PMT PID of the service, or of the first service if `service_id` is None.
+pub async fn find_pmt_pid<S: Stream<Item = ts::TSPacket> + Unpin>(
+    s: &mut S,
+    service_id: Option<u16>,
+) -> Result<u16> {
     let pat_stream = s.filter(|packet| packet.pid == ts::PAT_PID);
-    let mut buffer = psi::Buffer::new(pat_stream);
+    let mut buffer = psi::Buffer::new(pat_stream).verify_crc();
     loop {
         match buffer.next().await {
             Some(Ok(bytes)) => {
@@ -85,12 +220,18 @@ async fn find_main_pmt_pid<S: Stream<Item = ts::TSPacket> + Unpin>(s: &mut S) ->
                         }
                     };
                     for (program_number, pid) in pas.program_association {
-                        if program_number != 0 {
-                            // not network pid
+                        if program_number == 0 {
+                            // network pid
+                            continue;
+                        }
+                        if service_id.is_none() || service_id == Some(program_number) {
                             debug!("main pmt: pid={}, program_number={}", pid, program_number);
                             return Ok(pid);
                         }
                     }
+                    if let Some(service_id) = service_id {
+                        bail!("service {} not found", service_id);
+                    }
                 }
             }
             Some(Err(e)) => return Err(e.into()),
@@ -99,12 +240,380 @@ async fn find_main_pmt_pid<S: Stream<Item = ts::TSPacket> + Unpin>(s: &mut S) ->
     }
 }
 
+pub struct ElementaryStream {
+    pub pid: u16,
+    pub stream_type: u8,
+    pub component_tag: Option<u8>,
+    pub caption: bool,
+    pub copy_control: Option<&'static str>,
+}
+
+pub struct Program {
+    pub program_number: u16,
+    pub pmt_pid: u16,
+    pub pcr_pid: u16,
+    pub copy_control: Option<&'static str>,
+    pub streams: Vec<ElementaryStream>,
+}
+
+pub struct ServiceDescription {
+    pub transport_stream_id: u16,
+    pub service_type: u8,
+    pub provider_name: Option<String>,
+    pub name: Option<String>,
+}
+
+async fn find_programs_from_pat<S: Stream<Item = ts::TSPacket> + Unpin>(
+    s: &mut S,
+) -> Result<Vec<(u16, u16)>> {
+    let pat_stream = s.filter(|packet| packet.pid == ts::PAT_PID);
+    let mut buffer = psi::Buffer::new(pat_stream).verify_crc();
+    loop {
+        match buffer.next().await {
+            Some(Ok(bytes)) => {
+                let bytes = &bytes[..];
+                let table_id = bytes[0];
+                if table_id == psi::PROGRAM_ASSOCIATION_SECTION {
+                    let pas = match psi::ProgramAssociationSection::parse(bytes) {
+                        Ok(pas) => pas,
+                        Err(e) => {
+                            info!("pat parse error: {:?}", e);
+                            continue;
+                        }
+                    };
+                    return Ok(pas
+                        .program_association
+                        .into_iter()
+                        .filter(|(program_number, _)| *program_number != 0)
+                        .collect());
+                }
+            }
+            Some(Err(e)) => return Err(e.into()),
+            None => bail!("no pat found"),
+        }
+    }
+}
+
+async fn find_program<S: Stream<Item = ts::TSPacket> + Unpin>(
+    program_number: u16,
+    pmt_pid: u16,
+    pmt_stream: S,
+) -> Result<Program> {
+    let mut buffer = psi::Buffer::new(pmt_stream).verify_crc();
+    loop {
+        match buffer.next().await {
+            Some(Ok(bytes)) => {
+                let bytes = &bytes[..];
+                let table_id = bytes[0];
+                if table_id == psi::TS_PROGRAM_MAP_SECTION {
+                    let pms = match psi::TSProgramMapSection::parse(bytes) {
+                        Ok(pms) => pms,
+                        Err(e) => {
+                            info!("pmt parse error: {:?}", e);
+                            continue;
+                        }
+                    };
+                    if pms.program_number != program_number {
+                        continue;
+                    }
+                    let streams = pms
+                        .stream_info
+                        .iter()
+                        .map(|si| ElementaryStream {
+                            pid: si.elementary_pid,
+                            stream_type: si.stream_type,
+                            component_tag: component_tag(si),
+                            caption: is_caption(si),
+                            copy_control: copy_control(&si.descriptors),
+                        })
+                        .collect();
+                    return Ok(Program {
+                        program_number,
+                        pmt_pid,
+                        pcr_pid: pms.pcr_pid,
+                        copy_control: copy_control(&pms.descriptors),
+                        streams,
+                    });
+                }
+            }
+            Some(Err(e)) => return Err(e.into()),
+            None => bail!("no pmt found"),
+        }
+    }
+}
+
+/// Finds all programs in the PAT and their PMTs.
+pub async fn find_programs<S: Stream<Item = ts::TSPacket> + Unpin>(
+    s: &mut S,
+) -> Result<Vec<Program>> {
+    let mut demuxer = ts::Demuxer::new();
+    let mut handles = Vec::new();
+    for (program_number, pmt_pid) in find_programs_from_pat(s).await? {
+        handles.push(tokio::spawn(find_program(
+            program_number,
+            pmt_pid,
+            demuxer.register(pmt_pid),
+        )));
+    }
+
+    let receiver = async move {
+        let mut programs = Vec::new();
+        for handle in handles.into_iter() {
+            programs.push(handle.await??);
+        }
+        Ok(programs)
+    };
+
+    tokio::join!(demuxer.run(s), receiver).1
+}
+
+/// Collects service descriptors from the SDT of the actual stream.
+pub async fn find_service_descriptions<S: Stream<Item = ts::TSPacket> + Unpin>(
+    sdt_stream: S,
+) -> HashMap<u16, ServiceDescription> {
+    let mut services = HashMap::new();
+    let mut buffer = psi::Buffer::new(sdt_stream).verify_crc();
+    while let Some(bytes) = buffer.next().await {
+        let bytes = match bytes {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                info!("sdt buffer error: {:?}", e);
+                continue;
+            }
+        };
+        if bytes[0] != psi::SELF_STREAM_TABLE_ID {
+            continue;
+        }
+        let sdt = match psi::ServiceDescriptionSection::parse(&bytes[..]) {
+            Ok(sdt) => sdt,
+            Err(e) => {
+                info!("sdt parse error: {:?}", e);
+                continue;
+            }
+        };
+        for service in sdt.services.iter() {
+            for desc in service.descriptors.iter() {
+                if let psi::Descriptor::ServiceDescriptor(sd) = desc {
+                    services.insert(
+                        service.service_id,
+                        ServiceDescription {
+                            transport_stream_id: sdt.transport_stream_id,
+                            service_type: sd.service_type,
+                            provider_name: decode_string(sd.service_provider_name),
+                            name: decode_string(sd.service_name),
+                        },
+                    );
+                }
+            }
+        }
+        if sdt.section_number == sdt.last_section_number {
+            break;
+        }
+    }
+    services
+}
+
+// the center frequency of UHF channel 13 in MHz, and the channel width.
+const UHF_BASE_MHZ: f64 = 473.0 + 1.0 / 7.0;
+const UHF_BASE_CHANNEL: f64 = 13.0;
+const UHF_CHANNEL_MHZ: f64 = 6.0;
+// BS transponders are odd numbered from 11.72748 GHz, and the CS ones at
+// 110 degrees east are even numbered from 12.291 GHz.
+const BS_BASE_GHZ: f64 = 11.72748;
+const BS_STEP_GHZ: f64 = 0.03836;
+const ND_BASE_GHZ: f64 = 12.291;
+const ND_STEP_GHZ: f64 = 0.04;
+const SATELLITE_TOLERANCE_GHZ: f64 = 0.002;
+
+/// The physical channel which carries a transport stream.
+#[derive(Serialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DeliverySystem {
+    Terrestrial {
+        area_code: u16,
+        guard_interval: &'static str,
+        transmission_mode: &'static str,
+        frequencies: Vec<f64>,
+        channel: Option<u8>,
+    },
+    Satellite {
+        frequency: f64,
+        orbital_position: f64,
+        west_east: &'static str,
+        polarisation: &'static str,
+        modulation: u8,
+        symbol_rate: f64,
+        fec_inner: u8,
+        transponder: Option<String>,
+    },
+}
+
+fn uhf_channel(mhz: f64) -> Option<u8> {
+    let channel = ((mhz - UHF_BASE_MHZ) / UHF_CHANNEL_MHZ).round() + UHF_BASE_CHANNEL;
+    if (UHF_BASE_CHANNEL..=62.0).contains(&channel) {
+        Some(channel as u8)
+    } else {
+        None
+    }
+}
+
+fn satellite_transponder(ghz: f64) -> Option<String> {
+    [
+        ("BS", BS_BASE_GHZ, BS_STEP_GHZ, 1.0),
+        ("ND", ND_BASE_GHZ, ND_STEP_GHZ, 2.0),
+    ]
+    .iter()
+    .find_map(|(prefix, base, step, first)| {
+        let n = ((ghz - base) / step).round();
+        if !(0.0..=11.0).contains(&n) || (ghz - base - n * step).abs() > SATELLITE_TOLERANCE_GHZ {
+            return None;
+        }
+        Some(format!("{}{}", prefix, (first + n * 2.0) as u32))
+    })
+}
+
+impl DeliverySystem {
+    fn from_descriptor(desc: psi::Descriptor) -> Option<DeliverySystem> {
+        match desc {
+            psi::Descriptor::TerrestrialDeliverySystemDescriptor(d) => {
+                let frequencies = d.frequencies_mhz();
+                Some(DeliverySystem::Terrestrial {
+                    area_code: d.area_code,
+                    guard_interval: d.guard_interval_name(),
+                    transmission_mode: d.transmission_mode_name(),
+                    channel: frequencies.first().and_then(|f| uhf_channel(*f)),
+                    frequencies,
+                })
+            }
+            psi::Descriptor::SatelliteDeliverySystemDescriptor(d) => {
+                Some(DeliverySystem::Satellite {
+                    frequency: d.frequency_ghz(),
+                    orbital_position: d.orbital_position_degrees(),
+                    west_east: if d.west_east_flag == 1 {
+                        "east"
+                    } else {
+                        "west"
+                    },
+                    polarisation: d.polarisation_name(),
+                    modulation: d.modulation,
+                    symbol_rate: d.symbol_rate_msps(),
+                    fec_inner: d.fec_inner,
+                    transponder: satellite_transponder(d.frequency_ghz()),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Collects delivery systems of transport streams from the NIT of the actual
+/// network, keyed by transport_stream_id.
+pub async fn find_delivery_systems<S: Stream<Item = ts::TSPacket> + Unpin>(
+    nit_stream: S,
+) -> HashMap<u16, DeliverySystem> {
+    let mut systems = HashMap::new();
+    let mut buffer = psi::Buffer::new(nit_stream).verify_crc();
+    while let Some(bytes) = buffer.next().await {
+        let bytes = match bytes {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                info!("nit buffer error: {:?}", e);
+                continue;
+            }
+        };
+        if bytes[0] != psi::SELF_NETWORK_TABLE_ID {
+            continue;
+        }
+        let nit = match psi::NetworkInformationSection::parse(&bytes[..]) {
+            Ok(nit) => nit,
+            Err(e) => {
+                info!("nit parse error: {:?}", e);
+                continue;
+            }
+        };
+        for ts in nit.transport_streams.iter() {
+            if let Some(system) = ts
+                .descriptors
+                .iter()
+                .find_map(DeliverySystem::from_descriptor)
+            {
+                systems.insert(ts.transport_stream_id, system);
+            }
+        }
+        if nit.section_number == nit.last_section_number {
+            break;
+        }
+    }
+    systems
+}
+
+pub fn decode_string(bytes: &[u8]) -> Option<String> {
+    let decoder = arib::string::AribDecoder::with_event_initialization();
+    match decoder.decode(bytes.iter()) {
+        Ok(s) => Some(s),
+        Err(e) => {
+            info!("string decode error: {:?}", e);
+            errors::count(errors::Kind::String);
+            None
+        }
+    }
+}
+
+pub fn component_tag(si: &psi::StreamInfo) -> Option<u8> {
+    si.descriptors.iter().find_map(|desc| match desc {
+        psi::Descriptor::StreamIdentifierDescriptor(sid) => Some(sid.component_tag),
+        _ => None,
+    })
+}
+
+/// Returns the digital copy control in the descriptors, like "copy-once".
+pub fn copy_control(descriptors: &psi::Descriptors) -> Option<&'static str> {
+    descriptors.iter().find_map(|desc| match desc {
+        psi::Descriptor::DigitalCopyControlDescriptor(d) => Some(d.recording_control_name()),
+        _ => None,
+    })
+}
+
+pub fn is_key_picture(stream_type: u8, bytes: &[u8]) -> bool {
+    match stream_type {
+        psi::STREAM_TYPE_H264 => h264::is_idr_picture(bytes),
+        _ => h262::is_i_picture(bytes),
+    }
+}
+
+/// Finds key pictures in the successive PES payloads of a video stream,
+/// including MPEG-2 picture headers split across the payloads.
+pub struct KeyPictureFinder {
+    stream_type: u8,
+    scanner: h262::PictureScanner,
+}
+
+impl KeyPictureFinder {
+    pub fn new(stream_type: u8) -> KeyPictureFinder {
+        KeyPictureFinder {
+            stream_type,
+            scanner: h262::PictureScanner::default(),
+        }
+    }
+
+    /// Returns whether a key picture starts or completes its header in
+    /// `bytes`, the next payload of the stream.
+    pub fn feed(&mut self, bytes: &[u8]) -> bool {
+        match self.stream_type {
+            psi::STREAM_TYPE_H264 => h264::is_idr_picture(bytes),
+            _ => self.scanner.feed(bytes).contains(&h262::I_PICTURE),
+        }
+    }
+}
+
 pub async fn find_first_picture_pts<S: Stream<Item = ts::TSPacket> + Unpin>(
     pid: u16,
+    stream_type: u8,
     s: &mut S,
 ) -> Result<u64> {
     let video_stream = s.filter(move |packet| packet.pid == pid);
     let mut buffer = pes::Buffer::new(video_stream);
+    let mut finder = KeyPictureFinder::new(stream_type);
     loop {
         match buffer.next().await {
             Some(Ok(bytes)) => {
@@ -112,26 +621,284 @@ pub async fn find_first_picture_pts<S: Stream<Item = ts::TSPacket> + Unpin>(
                     Ok(pes) => pes,
                     Err(e) => {
                         info!("pes parse error: {:?}", e);
+                        errors::count(errors::Kind::Pes);
                         continue;
                     }
                 };
                 if let pes::PESPacketBody::NormalPESPacketBody(ref body) = pes.body {
-                    if h262::is_i_picture(body.pes_packet_data_byte) {
+                    if finder.feed(body.pes_packet_data_byte) {
                         if let Some(pts) = pes.get_pts() {
                             return Ok(pts);
                         }
                     }
                 }
             }
-            Some(Err(e)) => return Err(e),
+            Some(Err(e)) => return Err(e.into()),
             None => bail!("no pts found"),
         }
     }
 }
 
+/// Finds the PTS of the first PES packet on the PID.
+pub async fn find_first_pts<S: Stream<Item = ts::TSPacket> + Unpin>(
+    pid: u16,
+    s: &mut S,
+) -> Result<u64> {
+    let stream = s.filter(move |packet| packet.pid == pid);
+    let mut buffer = pes::Buffer::new(stream);
+    loop {
+        match buffer.next().await {
+            Some(Ok(bytes)) => match pes::PESPacket::parse(&bytes[..]) {
+                Ok(pes) => {
+                    if let Some(pts) = pes.get_pts() {
+                        return Ok(pts);
+                    }
+                }
+                Err(e) => {
+                    info!("pes parse error: {:?}", e);
+                    errors::count(errors::Kind::Pes);
+                }
+            },
+            Some(Err(e)) => return Err(e.into()),
+            None => bail!("no pts found"),
+        }
+    }
+}
+
+/// Finds the first PCR on the PID, in 27 MHz.
+pub async fn find_first_pcr<S: Stream<Item = ts::TSPacket> + Unpin>(
+    pid: u16,
+    s: &mut S,
+) -> Result<u64> {
+    while let Some(packet) = s.next().await {
+        if packet.pid != pid {
+            continue;
+        }
+        if let Some(pcr) = packet.adaptation_field.as_ref().and_then(|af| af.pcr()) {
+            return Ok(pcr);
+        }
+    }
+    bail!("no pcr found")
+}
+
+// TOT and TDT are sent at least every 30 seconds.
+const MAX_TOT_INTERVAL: f64 = 30.0;
+
+/// The JST time of a TOT or TDT and the 90 kHz time when it was received.
+pub type ClockAnchor = (DateTime<FixedOffset>, u64);
+
+// reads a TOT or TDT from a packet starting it.
+fn read_tot(packet: &ts::TSPacket) -> Option<psi::TimeOffsetSection<'_>> {
+    if !packet.payload_unit_start_indicator {
+        return None;
+    }
+    let data = packet.data.as_ref()?;
+    let pointer_field = usize::from(data[0]);
+    if data.len() <= pointer_field + 1 {
+        return None;
+    }
+    match psi::TimeOffsetSection::parse(&data[pointer_field + 1..]) {
+        Ok(tot) => Some(tot),
+        Err(e) => {
+            debug!("tot parse error: {:?}", e);
+            None
+        }
+    }
+}
+
+// reads JST_time from a packet starting a TOT or TDT.
+pub fn tot_jst_time(packet: &ts::TSPacket) -> Option<DateTime<FixedOffset>> {
+    read_tot(packet).map(|tot| tot.jst_time)
+}
+
+// reads the local time offset from a packet starting a TOT.
+pub fn tot_local_time_offset(packet: &ts::TSPacket) -> Option<LocalTimeOffset> {
+    read_tot(packet)?.local_time_offset()
+}
+
+/// Relates the JST time to the video PTS with the first TOT or TDT, or to
+/// the PCR when there is no video, giving up when none comes within the
+/// interval they are sent at.
+pub async fn find_clock_anchor<S: Stream<Item = ts::TSPacket> + Unpin>(
+    video_pid: Option<u16>,
+    pcr_pid: Option<u16>,
+    s: &mut S,
+) -> Option<ClockAnchor> {
+    let mut first_time = None;
+    let mut time = None;
+    while let Some(packet) = s.next().await {
+        if packet.pid == psi::TOT_PID {
+            if let (Some(jst_time), Some(time)) = (tot_jst_time(&packet), time) {
+                return Some((jst_time, time));
+            }
+            continue;
+        }
+        let now = match video_pid {
+            Some(pid) if packet.pid == pid => packet_timestamps(&packet).and_then(|t| t.pts),
+            None if Some(packet.pid) == pcr_pid => packet
+                .adaptation_field
+                .as_ref()
+                .and_then(|af| af.pcr())
+                .map(|pcr| pcr / (ts::PCR_HZ / pes::PTS_HZ)),
+            _ => None,
+        };
+        if let Some(now) = now {
+            let first = *first_time.get_or_insert(now);
+            if pts_diff(now, first) > MAX_TOT_INTERVAL {
+                break;
+            }
+            time = Some(now);
+        }
+    }
+    None
+}
+
+// difference of two 33 bits timestamps in seconds, wrapping around.
+pub fn pts_diff(a: u64, b: u64) -> f64 {
+    pes::PtsTime::new(a).seconds_since(pes::PtsTime::new(b))
+}
+
+// reads PTS/DTS from a packet starting a PES packet.
+pub fn packet_timestamps(packet: &ts::TSPacket) -> Option<pes::Timestamps> {
+    if !packet.payload_unit_start_indicator {
+        return None;
+    }
+    let data = packet.data.as_ref()?;
+    match pes::Timestamps::parse(&data[..]) {
+        Ok(timestamps) => Some(timestamps),
+        Err(e) => {
+            debug!("pes header parse error: {:?}", e);
+            None
+        }
+    }
+}
+
+/// Keeps the latest PSI packets of some PIDs, so that they can be written
+/// again at the head of a cut or a segment.
+pub struct PsiRepeater {
+    // in the order to be written, e.g. PAT before PMT.
+    packets: Vec<(u16, Vec<Bytes>)>,
+}
+
+impl PsiRepeater {
+    pub fn new(pids: &[u16]) -> PsiRepeater {
+        PsiRepeater {
+            packets: pids.iter().map(|pid| (*pid, Vec::new())).collect(),
+        }
+    }
+
+    pub fn update(&mut self, packet: &ts::TSPacket) {
+        let packets = match self.packets.iter_mut().find(|(pid, _)| *pid == packet.pid) {
+            Some((_, packets)) => packets,
+            None => return,
+        };
+        if packet.payload_unit_start_indicator {
+            packets.clear();
+        } else if packets.is_empty() {
+            return;
+        }
+        packets.push(packet.clone().into_raw());
+    }
+
+    pub fn packets(&self) -> impl Iterator<Item = &Bytes> {
+        self.packets.iter().flat_map(|(_, packets)| packets.iter())
+    }
+}
+
+/// Rewrites the PMT sections of some programs carried on a PID, and packetizes
+/// them again with the continuity counter of the PID. The other sections on
+/// the PID are passed through.
+pub struct PmtRewriter {
+    program_numbers: HashSet<u16>,
+    buffer: psi::Buffer<()>,
+    packetizer: Option<psi::SectionPacketizer>,
+}
+
+impl PmtRewriter {
+    pub fn new(program_numbers: HashSet<u16>) -> PmtRewriter {
+        PmtRewriter {
+            program_numbers,
+            buffer: psi::Buffer::detached().verify_crc(),
+            packetizer: None,
+        }
+    }
+
+    /// Feeds a packet of the PID, and appends to `out` the packets of the
+    /// sections completed by it, rewritten by `rewrite` if they are PMT
+    /// sections of the programs. A section failing to be rewritten is
+    /// dropped.
+    pub fn feed<F: Fn(&[u8]) -> Result<Bytes>>(
+        &mut self,
+        packet: ts::TSPacket,
+        rewrite: F,
+        out: &mut BytesMut,
+    ) -> Result<()> {
+        let packetizer = self.packetizer.get_or_insert_with(|| {
+            psi::SectionPacketizer::new(packet.pid, packet.continuity_counter)
+        });
+        if let Err(e) = self.buffer.feed(packet) {
+            warn!("drop PMT packet: {:?}", e);
+            return Ok(());
+        }
+        while let Some(section) = self.buffer.next_section() {
+            // up to last_section_number of the long form header.
+            if section.len() < 8 {
+                warn!("drop too short PSI section: {} bytes", section.len());
+                continue;
+            }
+            let program_number = (u16::from(section[3]) << 8) | u16::from(section[4]);
+            let section = if section[0] == psi::TS_PROGRAM_MAP_SECTION
+                && self.program_numbers.contains(&program_number)
+            {
+                match rewrite(&section[..]) {
+                    Ok(section) => section,
+                    Err(e) => {
+                        warn!("drop PMT section: {:?}", e);
+                        continue;
+                    }
+                }
+            } else {
+                section
+            };
+            for packet in packetizer.packetize(&section[..])?.into_iter() {
+                out.extend_from_slice(&packet.into_raw()[..]);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Ends the stream on Ctrl-C, so that commands can finalize their output.
+pub fn interrupt_on_ctrl_c<S: Stream + Unpin>(
+    s: S,
+) -> Interruptible<S, Pin<Box<dyn Future<Output = ()> + Send>>> {
+    let ctrl_c: Pin<Box<dyn Future<Output = ()> + Send>> = Box::pin(async {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            info!("interrupted");
+        }
+    });
+    interruptible(s, ctrl_c)
+}
+
 // FIXME: erroneous packets will be error, this function should be removed.
-pub fn strip_error_packets<S: Stream<Item = Result<ts::TSPacket>>>(
+// packets which failed to be decoded, and CC errors if errors are counted, are
+// counted as recoverable errors. The stream ends once there are too many.
+pub fn strip_error_packets<S: Stream<Item = ts::Result<ts::TSPacket>>>(
     s: S,
 ) -> impl Stream<Item = ts::TSPacket> {
-    s.filter_map(|x| if let Ok(x) = x { Some(x) } else { None })
+    let mut continuity = errors::is_counting().then(errors::Continuity::new);
+    s.filter_map(move |x| match x {
+        Ok(packet) => {
+            if continuity.as_mut().is_some_and(|c| c.check(&packet)) {
+                errors::count(errors::Kind::Continuity);
+            }
+            Some(packet)
+        }
+        Err(e) => {
+            debug!("packet error: {:?}", e);
+            errors::count(errors::Kind::Packet);
+            None
+        }
+    })
+    .take_while(|_| !errors::is_exceeded())
 }
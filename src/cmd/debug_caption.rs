@@ -0,0 +1,137 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use log::info;
+use serde_derive::Serialize;
+use tokio_stream::StreamExt;
+
+use super::caption::get_caption;
+use super::common;
+use super::io::path_to_packets;
+use tstools::arib;
+use tstools::pes;
+use tstools::stream::cueable;
+use tstools::ts;
+
+#[derive(Serialize)]
+struct DataUnit {
+    parameter: String,
+    raw: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Serialize)]
+struct DataGroup {
+    pts: Option<u64>,
+    data_group_id: u8,
+    data_group_version: u8,
+    kind: &'static str,
+    data_units: Vec<DataUnit>,
+}
+
+#[derive(Serialize)]
+struct Failure {
+    pts: Option<u64>,
+    error: String,
+    raw: String,
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn debug_data_unit(du: &arib::caption::DataUnit) -> DataUnit {
+    let (text, error) = match du.data_unit_parameter {
+        arib::caption::DataUnitParameter::Text => {
+            let decoder = arib::string::AribDecoder::with_caption_initialization();
+            match decoder.decode(du.data_unit_data.iter()) {
+                Ok(text) => (Some(text), None),
+                Err(e) => (None, Some(e.to_string())),
+            }
+        }
+        _ => (None, None),
+    };
+    DataUnit {
+        parameter: format!("{:?}", du.data_unit_parameter),
+        raw: to_hex(du.data_unit_data),
+        text,
+        error,
+    }
+}
+
+fn pes_payload<'a>(pes: &'a pes::PESPacket) -> &'a [u8] {
+    match pes.body {
+        pes::PESPacketBody::NormalPESPacketBody(ref body) => body.pes_packet_data_byte,
+        pes::PESPacketBody::DataBytes(bytes) => bytes,
+        pes::PESPacketBody::PaddingByte => &[],
+    }
+}
+
+pub async fn run(input: Option<PathBuf>, pid: Option<u16>) -> Result<()> {
+    let packets = path_to_packets(input, false).await?;
+    let packets = common::strip_error_packets(packets);
+    let mut cueable_packets = cueable(packets);
+    let pid = match pid {
+        Some(pid) => pid,
+        None => common::find_main_meta(&mut cueable_packets, None, &[common::StreamKind::Caption])
+            .await?
+            .caption_pid(),
+    };
+    let packets = cueable_packets.cue_up();
+    let mut demuxer = ts::Demuxer::new();
+    let caption_stream = demuxer.register(pid);
+    tokio::spawn(demuxer.run(packets));
+
+    let mut buffer = pes::Buffer::new(caption_stream);
+    while let Some(bytes) = buffer.next().await {
+        let bytes = match bytes {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                info!("pes buffer error: {:?}", e);
+                continue;
+            }
+        };
+        let pes = match pes::PESPacket::parse(&bytes[..]) {
+            Ok(pes) => pes,
+            Err(e) => {
+                let failure = Failure {
+                    pts: None,
+                    error: e.to_string(),
+                    raw: to_hex(&bytes[..]),
+                };
+                println!("{}", serde_json::to_string(&failure)?);
+                continue;
+            }
+        };
+        let pts = pes.get_pts();
+        let line = match get_caption(&pes) {
+            Ok(dg) => {
+                let (kind, data_units) = match dg.data_group_data {
+                    arib::caption::DataGroupData::CaptionManagementData(ref cmd) => {
+                        ("management", &cmd.data_units)
+                    }
+                    arib::caption::DataGroupData::CaptionData(ref cd) => {
+                        ("caption", &cd.data_units)
+                    }
+                };
+                serde_json::to_string(&DataGroup {
+                    pts,
+                    data_group_id: dg.data_group_id,
+                    data_group_version: dg.data_group_version,
+                    kind,
+                    data_units: data_units.iter().map(debug_data_unit).collect(),
+                })?
+            }
+            Err(e) => serde_json::to_string(&Failure {
+                pts,
+                error: e.to_string(),
+                raw: to_hex(pes_payload(&pes)),
+            })?,
+        };
+        println!("{}", line);
+    }
+    Ok(())
+}
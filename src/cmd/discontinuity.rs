@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use chrono::offset::FixedOffset;
+use chrono::{DateTime, Duration};
+use serde_derive::Serialize;
+use tokio_stream::StreamExt;
+
+use super::common;
+use super::io::path_to_packets;
+use tstools::psi;
+use tstools::ts;
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Timestamp {
+    Pts,
+    Dts,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Kind {
+    Backward,
+    Gap,
+}
+
+#[derive(Serialize)]
+struct Discontinuity {
+    pid: u16,
+    timestamp: Timestamp,
+    kind: Kind,
+    previous: u64,
+    current: u64,
+    delta: f64,
+    offset: u64,
+    elapsed: Option<f64>,
+    wallclock: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Default)]
+struct LastTimestamps {
+    pts: Option<u64>,
+    dts: Option<u64>,
+}
+
+// keeps the latest PCR and TOT to estimate the time at a packet.
+#[derive(Default)]
+struct Clock {
+    pcr_pid: Option<u16>,
+    first_pcr: Option<u64>,
+    pcr: Option<u64>,
+    // JST time and the PCR when it was received.
+    tot: Option<(DateTime<FixedOffset>, u64)>,
+}
+
+impl Clock {
+    fn update(&mut self, packet: &ts::TSPacket) {
+        if self.pcr_pid.is_none() || self.pcr_pid == Some(packet.pid) {
+            if let Some(pcr) = packet.adaptation_field.as_ref().and_then(|af| af.pcr()) {
+                self.pcr_pid = Some(packet.pid);
+                self.first_pcr.get_or_insert(pcr);
+                self.pcr = Some(pcr);
+            }
+        }
+        if packet.pid == psi::TOT_PID {
+            if let (Some(jst_time), Some(pcr)) = (common::tot_jst_time(packet), self.pcr) {
+                self.tot = Some((jst_time, pcr));
+            }
+        }
+    }
+
+    fn elapsed(&self) -> Option<f64> {
+        Some(pcr_diff(self.pcr?, self.first_pcr?))
+    }
+
+    fn wallclock(&self) -> Option<DateTime<FixedOffset>> {
+        let (jst_time, tot_pcr) = self.tot?;
+        let ms = (pcr_diff(self.pcr?, tot_pcr) * 1000.0) as i64;
+        Some(jst_time + Duration::milliseconds(ms))
+    }
+}
+
+// PCR wraps around at 2^33 * 300.
+fn pcr_diff(a: u64, b: u64) -> f64 {
+    const PCR_MAX: u64 = (1 << 33) * 300;
+    ((a + PCR_MAX - b) % PCR_MAX) as f64 / ts::PCR_HZ as f64
+}
+
+pub async fn run(input: Option<PathBuf>, threshold_ms: u64) -> Result<()> {
+    let threshold = threshold_ms as f64 / 1000.0;
+    let packets = path_to_packets(input, false).await?;
+    let mut packets = common::strip_error_packets(packets);
+
+    let mut clock = Clock::default();
+    let mut lasts: HashMap<u16, LastTimestamps> = HashMap::new();
+    let mut offset = 0u64;
+    while let Some(packet) = packets.next().await {
+        let packet_offset = offset;
+        offset += ts::TS_PACKET_LENGTH as u64;
+        clock.update(&packet);
+        let timestamps = match common::packet_timestamps(&packet) {
+            Some(timestamps) => timestamps,
+            None => continue,
+        };
+        let last = lasts.entry(packet.pid).or_default();
+        let checks = [
+            (Timestamp::Pts, timestamps.pts, &mut last.pts),
+            (Timestamp::Dts, timestamps.dts, &mut last.dts),
+        ];
+        for (timestamp, current, previous) in checks.into_iter() {
+            let current = match current {
+                Some(current) => current,
+                None => continue,
+            };
+            if let Some(previous) = previous.replace(current) {
+                let delta = common::pts_diff(current, previous);
+                let kind = if delta < -threshold {
+                    Kind::Backward
+                } else if delta > threshold {
+                    Kind::Gap
+                } else {
+                    continue;
+                };
+                let discontinuity = Discontinuity {
+                    pid: packet.pid,
+                    timestamp,
+                    kind,
+                    previous,
+                    current,
+                    delta,
+                    offset: packet_offset,
+                    elapsed: clock.elapsed(),
+                    wallclock: clock.wallclock(),
+                };
+                println!("{}", serde_json::to_string(&discontinuity)?);
+            }
+        }
+    }
+    Ok(())
+}
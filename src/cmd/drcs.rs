@@ -0,0 +1,244 @@
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use log::{debug, info};
+use serde_derive::Serialize;
+use tokio_stream::{Stream, StreamExt};
+
+use super::caption::{font_hash, get_caption};
+use super::common;
+use super::errors;
+use super::io::path_to_packets;
+use tstools::arib;
+use tstools::pes;
+use tstools::stream::cueable;
+use tstools::ts;
+
+// DRCS in text are decoded into private use characters to be counted.
+const PRIVATE_USE_START: u32 = 0xf0000;
+
+#[derive(Serialize)]
+struct Glyph {
+    /// The key in a DRCS map.
+    hash: String,
+    pids: BTreeSet<u16>,
+    character_codes: BTreeSet<u16>,
+    width: u8,
+    height: u8,
+    depth: u8,
+    /// How many times the glyph is transmitted.
+    definitions: u64,
+    /// How many times captions show the glyph.
+    uses: u64,
+    /// The PTS of the first caption showing the glyph.
+    first_use_pts: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image: Option<PathBuf>,
+    #[serde(skip)]
+    pattern_data: Vec<u8>,
+    // the order of the first definition.
+    #[serde(skip)]
+    order: (u64, usize),
+}
+
+impl Glyph {
+    fn merge(&mut self, other: Glyph) {
+        self.pids.extend(other.pids);
+        self.character_codes.extend(other.character_codes);
+        self.definitions += other.definitions;
+        self.uses += other.uses;
+        self.first_use_pts = match (self.first_use_pts, other.first_use_pts) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        self.order = self.order.min(other.order);
+    }
+}
+
+// glyphs are indexed to be numbered by private use characters.
+#[derive(Default)]
+struct Glyphs {
+    glyphs: Vec<Glyph>,
+    index: HashMap<u128, usize>,
+}
+
+impl Glyphs {
+    fn define(&mut self, pid: u16, pts: u64, code: u16, font: &arib::caption::Font) -> usize {
+        let hash = font_hash(font);
+        let i = *self.index.entry(hash).or_insert_with(|| {
+            self.glyphs.push(Glyph {
+                hash: format!("{:032x}", hash),
+                pids: BTreeSet::new(),
+                character_codes: BTreeSet::new(),
+                width: font.width,
+                height: font.height,
+                depth: font.depth,
+                definitions: 0,
+                uses: 0,
+                first_use_pts: None,
+                image: None,
+                pattern_data: font.pattern_data.to_vec(),
+                order: (pts, self.glyphs.len()),
+            });
+            self.glyphs.len() - 1
+        });
+        let glyph = &mut self.glyphs[i];
+        glyph.pids.insert(pid);
+        glyph.character_codes.insert(code);
+        glyph.definitions += 1;
+        i
+    }
+
+    fn count_uses(&mut self, text: &str, pts: u64) {
+        for c in text.chars() {
+            let i = match u32::from(c).checked_sub(PRIVATE_USE_START) {
+                Some(i) => i as usize,
+                None => continue,
+            };
+            if let Some(glyph) = self.glyphs.get_mut(i) {
+                glyph.uses += 1;
+                glyph.first_use_pts.get_or_insert(pts);
+            }
+        }
+    }
+}
+
+// collects glyphs of a caption ES, with the codes currently assigned to them
+// in each data group.
+async fn scan_glyphs<S: Stream<Item = ts::TSPacket> + Unpin>(pid: u16, s: S) -> Result<Glyphs> {
+    let mut glyphs = Glyphs::default();
+    let mut buffer = pes::Buffer::new(s);
+    while let Some(bytes) = buffer.next().await {
+        let bytes = match bytes {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                info!("pes buffer error: {:?}", e);
+                continue;
+            }
+        };
+        let pes = match pes::PESPacket::parse(&bytes[..]) {
+            Ok(pes) => pes,
+            Err(e) => {
+                info!("pes parse error: {:?}", e);
+                errors::count(errors::Kind::Pes);
+                continue;
+            }
+        };
+        let pts = pes.get_pts().unwrap_or_default();
+        let dg = match get_caption(&pes) {
+            Ok(dg) => dg,
+            Err(e) => {
+                info!("retrieving caption error: {:?}", e);
+                continue;
+            }
+        };
+        let data_units = match dg.data_group_data {
+            arib::caption::DataGroupData::CaptionManagementData(ref cmd) => &cmd.data_units,
+            arib::caption::DataGroupData::CaptionData(ref cd) => &cd.data_units,
+        };
+        let mut code_map = HashMap::new();
+        for du in data_units.iter() {
+            match du.data_unit_parameter {
+                arib::caption::DataUnitParameter::DRCS1
+                | arib::caption::DataUnitParameter::DRCS2 => {
+                    let drcs = match arib::caption::DrcsDataStructure::parse(du.data_unit_data) {
+                        Ok(drcs) => drcs,
+                        Err(e) => {
+                            info!("drcs parse error: {:?}", e);
+                            continue;
+                        }
+                    };
+                    for code in drcs.codes.iter() {
+                        for font in code.fonts.iter() {
+                            let i = glyphs.define(pid, pts, code.character_code, font);
+                            let c =
+                                char::from_u32(PRIVATE_USE_START + i as u32).unwrap_or('\u{fffd}');
+                            code_map.entry(code.character_code).or_insert(c.to_string());
+                        }
+                    }
+                }
+                arib::caption::DataUnitParameter::Text => {
+                    let mut decoder = arib::string::AribDecoder::with_caption_initialization();
+                    decoder.set_drcs(code_map.clone());
+                    match decoder.decode(du.data_unit_data.iter()) {
+                        Ok(text) => glyphs.count_uses(&text, pts),
+                        Err(e) => debug!("string decode error: {:?}", e),
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(glyphs)
+}
+
+// levels of gray are depth + 2, packed in the fewest bits.
+fn bits_per_pixel(depth: u8) -> usize {
+    let levels = u32::from(depth) + 2;
+    (u32::BITS - (levels - 1).leading_zeros()) as usize
+}
+
+// writes the glyph as a PGM image, in black on white.
+async fn write_image(glyph: &Glyph, dir: &Path) -> Result<PathBuf> {
+    let bits = bits_per_pixel(glyph.depth);
+    let max = glyph.depth + 1;
+    let pixels = usize::from(glyph.width) * usize::from(glyph.height);
+    let mut out = format!("P5\n{} {}\n{}\n", glyph.width, glyph.height, max).into_bytes();
+    for pos in 0..pixels {
+        let bit = pos * bits;
+        let byte = glyph.pattern_data.get(bit / 8).copied().unwrap_or(0);
+        let v = (byte >> (8 - bits - bit % 8)) & ((1 << bits) - 1);
+        out.push(max - v.min(max));
+    }
+    let path = dir.join(format!("{}.pgm", glyph.hash));
+    info!("write {:?}", path);
+    tokio::fs::write(&path, out).await?;
+    Ok(path)
+}
+
+pub async fn run(input: Option<PathBuf>, output_dir: Option<PathBuf>) -> Result<()> {
+    if let Some(dir) = output_dir.as_ref() {
+        tokio::fs::create_dir_all(dir).await?;
+    }
+    let packets = path_to_packets(input, false).await?;
+    let packets = common::strip_error_packets(packets);
+    let mut cueable_packets = cueable(packets);
+    let programs = common::find_programs(&mut cueable_packets).await?;
+    let packets = cueable_packets.cue_up();
+
+    let pids: BTreeSet<u16> = programs
+        .iter()
+        .flat_map(|p| p.streams.iter())
+        .filter(|es| es.caption)
+        .map(|es| es.pid)
+        .collect();
+    let mut demuxer = ts::Demuxer::new();
+    let tasks: Vec<_> = pids
+        .iter()
+        .map(|pid| tokio::spawn(scan_glyphs(*pid, demuxer.register(*pid))))
+        .collect();
+    demuxer.run(packets).await;
+
+    let mut merged: Vec<Glyph> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    for task in tasks.into_iter() {
+        for glyph in task.await??.glyphs.into_iter() {
+            match index.get(&glyph.hash) {
+                Some(i) => merged[*i].merge(glyph),
+                None => {
+                    index.insert(glyph.hash.clone(), merged.len());
+                    merged.push(glyph);
+                }
+            }
+        }
+    }
+    merged.sort_by_key(|g| g.order);
+    for glyph in merged.iter_mut() {
+        if let Some(dir) = output_dir.as_ref() {
+            glyph.image = Some(write_image(glyph, dir).await?);
+        }
+        println!("{}", serde_json::to_string(glyph)?);
+    }
+    Ok(())
+}
@@ -0,0 +1,267 @@
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Error, Result};
+use bytes::{Bytes, BytesMut};
+use log::info;
+use tokio::io::AsyncWriteExt;
+use tokio_stream::StreamExt;
+
+use super::common;
+use super::io::{path_to_async_write, path_to_packets};
+use tstools::psi;
+use tstools::stream::cueable;
+use tstools::ts;
+
+const STREAM_IDENTIFIER_DESCRIPTOR: u8 = 0x52;
+const OUTPUT_CHUNK: usize = 1 << 20;
+
+fn parse_hex(s: &str) -> Result<Vec<u8>> {
+    if !s.len().is_multiple_of(2) {
+        bail!("odd length of hex {:?}", s);
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| Ok(u8::from_str_radix(&s[i..i + 2], 16)?))
+        .collect()
+}
+
+/// A descriptor removed from or added to the program loop, or the ES loop
+/// of a PID, in the form of `[PID:]TAG[:DATA]` where DATA is in hex.
+#[derive(Clone, Debug)]
+pub struct DescriptorEdit {
+    pid: Option<u16>,
+    tag: u8,
+    data: Vec<u8>,
+}
+
+impl FromStr for DescriptorEdit {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<DescriptorEdit> {
+        let fields: Vec<&str> = s.split(':').collect();
+        let (pid, tag, data) = match fields[..] {
+            [tag] => (None, tag, ""),
+            [pid, tag] => (Some(pid), tag, ""),
+            [pid, tag, data] => (Some(pid), tag, data),
+            _ => bail!("descriptor must be [PID:]TAG[:DATA]: {:?}", s),
+        };
+        let data = parse_hex(data)?;
+        if data.len() > usize::from(u8::MAX) {
+            bail!("too long descriptor data: {} bytes", data.len());
+        }
+        Ok(DescriptorEdit {
+            // an empty PID selects the program loop.
+            pid: pid
+                .filter(|pid| !pid.is_empty())
+                .map(common::parse_pid)
+                .transpose()?,
+            tag: common::parse_number(tag)?,
+            data,
+        })
+    }
+}
+
+/// A component_tag set to the ES of a PID, in the form of `PID:TAG`.
+#[derive(Clone, Debug)]
+pub struct ComponentTag {
+    pid: u16,
+    tag: u8,
+}
+
+impl FromStr for ComponentTag {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<ComponentTag> {
+        let (pid, tag) = s
+            .split_once(':')
+            .ok_or_else(|| anyhow!("component tag must be PID:TAG: {:?}", s))?;
+        Ok(ComponentTag {
+            pid: common::parse_pid(pid)?,
+            tag: common::parse_number(tag)?,
+        })
+    }
+}
+
+/// An ES added to the PMT, in the form of `PID:STREAM_TYPE[:DESCRIPTORS]`
+/// where DESCRIPTORS is the ES descriptor loop in hex.
+#[derive(Clone, Debug)]
+pub struct NewStream {
+    pid: u16,
+    stream_type: u8,
+    descriptors: Vec<u8>,
+}
+
+impl FromStr for NewStream {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<NewStream> {
+        let fields: Vec<&str> = s.split(':').collect();
+        let (pid, stream_type, descriptors) = match fields[..] {
+            [pid, stream_type] => (pid, stream_type, ""),
+            [pid, stream_type, descriptors] => (pid, stream_type, descriptors),
+            _ => bail!("stream must be PID:STREAM_TYPE[:DESCRIPTORS]: {:?}", s),
+        };
+        let descriptors = parse_hex(descriptors)?;
+        let mut rest = &descriptors[..];
+        while !rest.is_empty() {
+            if rest.len() < 2 || rest.len() < 2 + usize::from(rest[1]) {
+                bail!("broken descriptor loop: {:?}", s);
+            }
+            rest = &rest[2 + usize::from(rest[1])..];
+        }
+        Ok(NewStream {
+            pid: common::parse_pid(pid)?,
+            stream_type: common::parse_number(stream_type)?,
+            descriptors,
+        })
+    }
+}
+
+/// Edits applied to PMT sections, in the order of the fields.
+pub struct Edits {
+    pub drop_streams: Vec<u16>,
+    pub add_streams: Vec<NewStream>,
+    pub remove_descriptors: Vec<DescriptorEdit>,
+    pub add_descriptors: Vec<DescriptorEdit>,
+    pub component_tags: Vec<ComponentTag>,
+}
+
+impl Edits {
+    // rewrites a descriptor loop of the program if pid is None, or of the
+    // ES.
+    fn descriptors(&self, pid: Option<u16>, descriptors: &[u8]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(descriptors.len());
+        let mut rest = descriptors;
+        // the loop is checked by parsing the section.
+        while rest.len() >= 2 {
+            let (descriptor, next) = rest.split_at((2 + usize::from(rest[1])).min(rest.len()));
+            rest = next;
+            let tag = descriptor[0];
+            let removed = self
+                .remove_descriptors
+                .iter()
+                .any(|e| e.pid == pid && e.tag == tag);
+            let retagged = tag == STREAM_IDENTIFIER_DESCRIPTOR
+                && self.component_tags.iter().any(|c| Some(c.pid) == pid);
+            if !removed && !retagged {
+                out.extend_from_slice(descriptor);
+            }
+        }
+        for e in self.add_descriptors.iter().filter(|e| e.pid == pid) {
+            out.push(e.tag);
+            out.push(e.data.len() as u8);
+            out.extend_from_slice(&e.data);
+        }
+        for c in self.component_tags.iter().filter(|c| Some(c.pid) == pid) {
+            out.extend_from_slice(&[STREAM_IDENTIFIER_DESCRIPTOR, 1, c.tag]);
+        }
+        out
+    }
+
+    fn apply(&self, section: &[u8]) -> Result<Bytes> {
+        let pmt = psi::TSProgramMapSection::parse(section)?;
+        let descriptors = self.descriptors(None, pmt.descriptors.as_bytes());
+        let es_descriptors: Vec<(&psi::StreamInfo, Vec<u8>)> = pmt
+            .stream_info
+            .iter()
+            .filter(|si| !self.drop_streams.contains(&si.elementary_pid))
+            .map(|si| {
+                let descriptors =
+                    self.descriptors(Some(si.elementary_pid), si.descriptors.as_bytes());
+                (si, descriptors)
+            })
+            .collect();
+        let added: Vec<(&NewStream, Vec<u8>)> = self
+            .add_streams
+            .iter()
+            .map(|ns| (ns, self.descriptors(Some(ns.pid), &ns.descriptors)))
+            .collect();
+        if let Some((ns, _)) = added.iter().find(|(ns, _)| {
+            es_descriptors
+                .iter()
+                .any(|(si, _)| si.elementary_pid == ns.pid)
+        }) {
+            bail!("pid {:#x} is in the PMT already", ns.pid);
+        }
+        let streams: Vec<psi::PmtStream> = es_descriptors
+            .iter()
+            .map(|(si, descriptors)| psi::PmtStream {
+                stream_type: si.stream_type,
+                elementary_pid: si.elementary_pid,
+                descriptors,
+            })
+            .chain(added.iter().map(|(ns, descriptors)| psi::PmtStream {
+                stream_type: ns.stream_type,
+                elementary_pid: ns.pid,
+                descriptors,
+            }))
+            .collect();
+        let section =
+            psi::SectionBuilder::pmt(pmt.program_number, pmt.pcr_pid, &descriptors, &streams)
+                .version_number(pmt.version_number)
+                .current_next_indicator(pmt.current_next_indicator)
+                .section_number(pmt.section_number)
+                .last_section_number(pmt.last_section_number)
+                .build()?;
+        Ok(section)
+    }
+}
+
+pub async fn run(
+    input: Option<PathBuf>,
+    output: Option<PathBuf>,
+    service_id: Option<u16>,
+    edits: Edits,
+) -> Result<()> {
+    let packets = path_to_packets(input, false).await?;
+    let packets = common::strip_error_packets(packets);
+    let mut cueable_packets = cueable(packets);
+    let programs = common::find_programs(&mut cueable_packets).await?;
+    let mut packets = cueable_packets.cue_up();
+
+    let programs: Vec<_> = programs
+        .into_iter()
+        .filter(|p| service_id.is_none_or(|id| id == p.program_number))
+        .collect();
+    if programs.is_empty() {
+        bail!("no service {:?} found", service_id);
+    }
+    let program_numbers: HashSet<u16> = programs.iter().map(|p| p.program_number).collect();
+    let mut rewriters: HashMap<u16, common::PmtRewriter> = programs
+        .iter()
+        .map(|p| (p.pmt_pid, common::PmtRewriter::new(program_numbers.clone())))
+        .collect();
+    // packets of dropped streams go away too, unless they carry PCR.
+    let pcr_pids: HashSet<u16> = programs.iter().map(|p| p.pcr_pid).collect();
+    let drop_pids: HashSet<u16> = edits
+        .drop_streams
+        .iter()
+        .filter(|pid| !pcr_pids.contains(pid))
+        .copied()
+        .collect();
+    info!("drop packets of pids {:?}", drop_pids);
+    let mut output = path_to_async_write(output).await?;
+
+    let mut chunk = BytesMut::with_capacity(OUTPUT_CHUNK);
+    while let Some(packet) = packets.next().await {
+        if drop_pids.contains(&packet.pid) {
+            continue;
+        }
+        let rewriter = match rewriters.get_mut(&packet.pid) {
+            Some(rewriter) => rewriter,
+            None => {
+                chunk.extend_from_slice(&packet.into_raw()[..]);
+                if chunk.len() + ts::TS_PACKET_LENGTH > OUTPUT_CHUNK {
+                    output.write_all(&chunk.split()[..]).await?;
+                }
+                continue;
+            }
+        };
+        rewriter.feed(packet, |section| edits.apply(section), &mut chunk)?;
+    }
+    output.write_all(&chunk[..]).await?;
+    output.flush().await?;
+    Ok(())
+}
@@ -0,0 +1,163 @@
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::offset::{FixedOffset, TimeZone};
+use chrono::DateTime;
+use serde_derive::{Deserialize, Serialize};
+
+use super::events;
+use super::io::RecordWriter;
+
+// bytes looked at to tell JSON from TS.
+const SNIFF_LENGTH: usize = 256;
+
+/// The timing and title of an event, which are compared.
+#[derive(Serialize)]
+pub(super) struct Program {
+    // unknown in events printed in the json format.
+    #[serde(skip)]
+    pub(super) service_id: Option<u16>,
+    #[serde(skip)]
+    pub(super) event_id: u16,
+    pub(super) start: DateTime<FixedOffset>,
+    // in seconds.
+    pub(super) duration: i64,
+    pub(super) title: String,
+}
+
+// an event printed by events in the json format.
+#[derive(Deserialize)]
+struct JsonEvent {
+    id: u16,
+    start: DateTime<FixedOffset>,
+    duration: i64,
+    title: String,
+}
+
+// a program printed by events in the mirakurun format.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MirakurunProgram {
+    service_id: u16,
+    event_id: u16,
+    start_at: i64,
+    duration: i64,
+    name: String,
+}
+
+#[derive(Serialize)]
+struct Change<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    service_id: Option<u16>,
+    event_id: u16,
+    changes: Vec<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    old: Option<&'a Program>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new: Option<&'a Program>,
+}
+
+fn parse_json(text: &str) -> Result<Vec<Program>> {
+    if text.trim_start().starts_with('[') {
+        let jst = FixedOffset::east_opt(9 * 3600).unwrap();
+        let programs: Vec<MirakurunProgram> = serde_json::from_str(text)?;
+        return programs
+            .into_iter()
+            .map(|p| {
+                let start = jst
+                    .timestamp_millis_opt(p.start_at)
+                    .single()
+                    .with_context(|| format!("invalid startAt: {}", p.start_at))?;
+                Ok(Program {
+                    service_id: Some(p.service_id),
+                    event_id: p.event_id,
+                    start,
+                    duration: p.duration / 1000,
+                    title: p.name,
+                })
+            })
+            .collect();
+    }
+    let mut programs = Vec::new();
+    for line in text.lines().filter(|line| !line.trim().is_empty()) {
+        let e: JsonEvent = serde_json::from_str(line)?;
+        programs.push(Program {
+            service_id: None,
+            event_id: e.id,
+            start: e.start,
+            duration: e.duration,
+            title: e.title,
+        });
+    }
+    Ok(programs)
+}
+
+// reads events exported in the json or mirakurun format, or from a TS.
+async fn read_programs(path: &Path) -> Result<Vec<Program>> {
+    let mut head = Vec::with_capacity(SNIFF_LENGTH);
+    std::fs::File::open(path)
+        .and_then(|f| f.take(SNIFF_LENGTH as u64).read_to_end(&mut head))
+        .with_context(|| format!("failed to read {:?}", path))?;
+    match head.iter().find(|b| !b.is_ascii_whitespace()) {
+        Some(b'[') | Some(b'{') => {
+            let text = std::fs::read_to_string(path)
+                .with_context(|| format!("failed to read {:?}", path))?;
+            parse_json(&text).with_context(|| format!("failed to parse {:?}", path))
+        }
+        _ => events::read_programs(path.to_path_buf()).await,
+    }
+}
+
+fn changes(old: &Program, new: &Program) -> Vec<&'static str> {
+    let mut changes = Vec::new();
+    if old.start != new.start || old.duration != new.duration {
+        changes.push("retimed");
+    }
+    if old.title != new.title {
+        changes.push("renamed");
+    }
+    changes
+}
+
+/// Prints the events added, removed, retimed or renamed in the new schedule
+/// from the old one, ordered by service and event. Each input is a TS or
+/// events printed by the events command in the json or mirakurun format.
+/// The json format has no service, so events are matched only by event id
+/// if either input is in it.
+pub async fn run(old: PathBuf, new: PathBuf) -> Result<()> {
+    let old = read_programs(&old).await?;
+    let new = read_programs(&new).await?;
+    let by_service = old.iter().chain(new.iter()).all(|p| p.service_id.is_some());
+    let key = |p: &Program| (p.service_id.filter(|_| by_service), p.event_id);
+
+    let mut pairs: BTreeMap<_, (Option<&Program>, Option<&Program>)> = BTreeMap::new();
+    for p in old.iter() {
+        pairs.entry(key(p)).or_default().0 = Some(p);
+    }
+    for p in new.iter() {
+        pairs.entry(key(p)).or_default().1 = Some(p);
+    }
+
+    let mut out = RecordWriter::stdout(false);
+    for ((service_id, event_id), (old, new)) in pairs {
+        let changes = match (old, new) {
+            (Some(old), Some(new)) => changes(old, new),
+            (None, _) => vec!["added"],
+            (_, None) => vec!["removed"],
+        };
+        if changes.is_empty() {
+            continue;
+        }
+        let change = Change {
+            service_id,
+            event_id,
+            changes,
+            old,
+            new,
+        };
+        out.write_line(&serde_json::to_string(&change)?)?;
+    }
+    out.flush()
+}
@@ -0,0 +1,130 @@
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+use anyhow::{bail, Result};
+
+use tstools::ts;
+
+/// Exit code of a run which read the input with recoverable errors in the
+/// strict mode. An unusable input exits with 1, and a clean run with 0.
+pub const EXIT_PARTIAL: u8 = 3;
+
+/// Recoverable conditions, which are logged and skipped over.
+#[derive(Clone, Copy)]
+pub enum Kind {
+    /// A TS packet which failed to be decoded, or bytes skipped to find the
+    /// sync byte.
+    Packet,
+    /// A gap of the continuity counter.
+    Continuity,
+    /// A PES packet which failed to be parsed.
+    Pes,
+    /// A string with an unknown code point or a broken escape.
+    String,
+}
+
+const KINDS: [(Kind, &str); 4] = [
+    (Kind::Packet, "packet errors"),
+    (Kind::Continuity, "cc errors"),
+    (Kind::Pes, "pes errors"),
+    (Kind::String, "string errors"),
+];
+
+static COUNTS: [AtomicU64; KINDS.len()] = [const { AtomicU64::new(0) }; KINDS.len()];
+static TOTAL: AtomicU64 = AtomicU64::new(0);
+static STRICT: AtomicBool = AtomicBool::new(false);
+static MAX_ERRORS: AtomicU64 = AtomicU64::new(u64::MAX);
+
+/// Makes the run exit with EXIT_PARTIAL if any recoverable error is seen.
+pub fn set_strict() {
+    STRICT.store(true, Ordering::Relaxed);
+}
+
+/// Makes inputs end once this many recoverable errors are seen, and the run
+/// fail.
+pub fn set_max_errors(max: u64) {
+    MAX_ERRORS.store(max, Ordering::Relaxed);
+}
+
+// whether errors which cost to find, such as CC errors, are looked for.
+pub(super) fn is_counting() -> bool {
+    STRICT.load(Ordering::Relaxed) || MAX_ERRORS.load(Ordering::Relaxed) != u64::MAX
+}
+
+pub(super) fn count(kind: Kind) {
+    COUNTS[kind as usize].fetch_add(1, Ordering::Relaxed);
+    TOTAL.fetch_add(1, Ordering::Relaxed);
+}
+
+// whether inputs should end as there are too many errors.
+pub(super) fn is_exceeded() -> bool {
+    TOTAL.load(Ordering::Relaxed) >= MAX_ERRORS.load(Ordering::Relaxed)
+}
+
+/// Fails if the run was cut short by too many recoverable errors.
+pub fn check_limit() -> Result<()> {
+    if is_exceeded() {
+        bail!(
+            "aborted after {} recoverable errors",
+            TOTAL.load(Ordering::Relaxed)
+        );
+    }
+    Ok(())
+}
+
+/// Reports the counts of recoverable errors on stderr in the strict mode,
+/// and returns the exit code of a run which finished.
+pub fn exit_code() -> ExitCode {
+    if !STRICT.load(Ordering::Relaxed) {
+        return ExitCode::SUCCESS;
+    }
+    let counts: Vec<(u64, &str)> = KINDS
+        .iter()
+        .map(|(kind, name)| (COUNTS[*kind as usize].load(Ordering::Relaxed), *name))
+        .filter(|(count, _)| *count > 0)
+        .collect();
+    if counts.is_empty() {
+        return ExitCode::SUCCESS;
+    }
+    let summary: Vec<String> = counts
+        .iter()
+        .map(|(count, name)| format!("{} {}", count, name))
+        .collect();
+    eprintln!("recoverable errors: {}", summary.join(", "));
+    ExitCode::from(EXIT_PARTIAL)
+}
+
+/// Tracks the continuity counter of each PID to find its gaps.
+pub(super) struct Continuity {
+    last: Vec<Option<u8>>,
+}
+
+impl Continuity {
+    pub(super) fn new() -> Continuity {
+        Continuity {
+            last: vec![None; usize::from(ts::NULL_PID) + 1],
+        }
+    }
+
+    // returns true if the counter of the packet skips some.
+    pub(super) fn check(&mut self, packet: &ts::TSPacket) -> bool {
+        // the counter only goes up with a payload, and a packet may be sent
+        // twice.
+        if packet.pid == ts::NULL_PID || packet.adaptation_field_control & 0x1 == 0 {
+            return false;
+        }
+        let counter = packet.continuity_counter;
+        let last = self.last[usize::from(packet.pid)].replace(counter);
+        let discontinuity = packet
+            .adaptation_field
+            .as_ref()
+            .is_some_and(|af| af.discontinuity_indicator());
+        last.is_some_and(|last| counter != last && counter != (last + 1) % 16 && !discontinuity)
+    }
+}
+
+impl Default for Continuity {
+    fn default() -> Continuity {
+        Continuity::new()
+    }
+}
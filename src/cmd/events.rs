@@ -1,24 +1,33 @@
+use std::collections::hash_map::DefaultHasher;
 use std::collections::{BTreeMap, HashMap};
+use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 
-use anyhow::{bail, Result};
+use anyhow::{bail, Error, Result};
 use chrono;
 use chrono::offset::FixedOffset;
 use chrono::DateTime;
+use clap::ValueEnum;
 use log::info;
+use schemars::schema::RootSchema;
+use schemars::JsonSchema;
 use serde_derive::Serialize;
 use tokio::sync::mpsc::channel;
 use tokio_stream::wrappers::ReceiverStream;
 use tokio_stream::{Stream, StreamExt};
-use tokio_util::codec::FramedRead;
 
-use super::common::strip_error_packets;
-use super::io::path_to_async_read;
-use crate::arib;
-use crate::psi;
-use crate::stream::cueable;
-use crate::ts;
-use psi::descriptor::Genre;
+use super::common::{
+    interrupt_on_ctrl_c, strip_error_packets, tot_jst_time, tot_local_time_offset,
+};
+use super::epg_diff::Program;
+use super::errors;
+use super::io::{paths_to_packets, RecordWriter};
+use tstools::arib;
+use tstools::psi;
+use tstools::stream::cueable;
+use tstools::ts;
 
 #[derive(Debug)]
 struct Duration(chrono::Duration);
@@ -29,20 +38,136 @@ impl serde::Serialize for Duration {
     }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
+struct Audio {
+    mode: Option<&'static str>,
+    languages: Vec<String>,
+    #[serde(skip)]
+    component_type: u8,
+    #[serde(skip)]
+    component_tag: u8,
+    #[serde(skip)]
+    main: bool,
+    #[serde(skip)]
+    sampling_rate: u8,
+}
+
+/// The stream and service of an event, given with `--include-other-streams`.
+#[derive(Debug, Serialize, JsonSchema)]
+struct StreamIds {
+    original_network_id: u16,
+    transport_stream_id: u16,
+    service_id: u16,
+}
+
+/// The data broadcasting of an event.
+#[derive(Debug, Serialize, JsonSchema)]
+struct DataContent {
+    data_component_id: u16,
+    entry_component: u8,
+    /// Component tags of the streams the data broadcasting uses.
+    component_refs: Vec<u8>,
+    text: String,
+}
+
+/// A link from an event, with the ids its destination type has.
+#[derive(Debug, Serialize, JsonSchema)]
+struct Link {
+    hyper_linkage_type: u8,
+    link_destination_type: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    original_network_id: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transport_stream_id: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    service_id: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event_id: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    component_tag: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    module_id: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_id: Option<u32>,
+}
+
+impl From<psi::descriptor::HyperlinkDescriptor<'_>> for Link {
+    fn from(d: psi::descriptor::HyperlinkDescriptor) -> Self {
+        Link {
+            hyper_linkage_type: d.hyper_linkage_type,
+            link_destination_type: d.link_destination_type,
+            original_network_id: d.original_network_id,
+            transport_stream_id: d.transport_stream_id,
+            service_id: d.service_id,
+            event_id: d.event_id,
+            component_tag: d.component_tag,
+            module_id: d.module_id,
+            content_id: d.content_id,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
 struct Event {
+    #[serde(skip)]
+    network_id: u16,
+    #[serde(skip)]
+    transport_stream_id: u16,
+    #[serde(skip)]
+    service_id: u16,
+    #[serde(flatten)]
+    stream: Option<StreamIds>,
     id: u16,
     start: DateTime<FixedOffset>,
+    /// In seconds.
+    #[schemars(with = "i64")]
     duration: Duration,
     title: String,
+    /// The short description, followed by the free text of extended event
+    /// descriptors if any.
     summary: String,
     detail: BTreeMap<String, String>,
     category: String,
+    genres: Vec<String>,
+    video: Option<&'static str>,
+    audio: Vec<Audio>,
+    /// The digital copy control of the event, like "copy-once".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    copy_control: Option<&'static str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    data_contents: Vec<DataContent>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    links: Vec<Link>,
+    #[serde(skip)]
+    categories: Vec<&'static str>,
+    #[serde(skip)]
+    table_id: u8,
+    #[serde(skip)]
+    version_number: u8,
+    #[serde(skip)]
+    free_ca_mode: bool,
+    // content nibbles.
+    #[serde(skip)]
+    contents: Vec<[u8; 4]>,
+    // stream_content and component_type of the video.
+    #[serde(skip)]
+    video_component: Option<(u8, u8)>,
 }
 
 impl Event {
-    fn new(id: u16, start: DateTime<FixedOffset>, duration: chrono::Duration) -> Self {
+    fn new(
+        network_id: u16,
+        transport_stream_id: u16,
+        service_id: u16,
+        id: u16,
+        start: DateTime<FixedOffset>,
+        duration: chrono::Duration,
+    ) -> Self {
         Event {
+            network_id,
+            transport_stream_id,
+            service_id,
+            stream: None,
             id,
             start,
             duration: Duration(duration),
@@ -50,33 +175,293 @@ impl Event {
             summary: String::new(),
             detail: BTreeMap::new(),
             category: String::new(),
+            genres: Vec::new(),
+            video: None,
+            audio: Vec::new(),
+            copy_control: None,
+            data_contents: Vec::new(),
+            links: Vec::new(),
+            categories: Vec::new(),
+            table_id: 0,
+            version_number: 0,
+            free_ca_mode: false,
+            contents: Vec::new(),
+            video_component: None,
+        }
+    }
+
+    fn normalize(mut self, normalizer: &arib::normalize::Normalizer) -> Self {
+        self.title = normalizer.normalize(&self.title);
+        self.summary = normalizer.normalize(&self.summary);
+        self.detail = self
+            .detail
+            .iter()
+            .map(|(k, v)| (normalizer.normalize(k), normalizer.normalize(v)))
+            .collect();
+        self
+    }
+}
+
+#[derive(ValueEnum, Clone, Copy)]
+pub enum Format {
+    Json,
+    Csv,
+    Table,
+    /// An array of Mirakurun's Program objects.
+    Mirakurun,
+}
+
+// the formats which print an event per line, as --stream and --incremental
+// print events before the others are found.
+#[derive(Clone, Copy)]
+enum LineFormat {
+    Json,
+    Csv,
+    Mirakurun,
+}
+
+impl LineFormat {
+    fn new(format: Format) -> Option<LineFormat> {
+        match format {
+            Format::Json => Some(LineFormat::Json),
+            Format::Csv => Some(LineFormat::Csv),
+            Format::Mirakurun => Some(LineFormat::Mirakurun),
+            Format::Table => None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct MirakurunGenre {
+    lv1: u8,
+    lv2: u8,
+    un1: u8,
+    un2: u8,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MirakurunVideo {
+    #[serde(rename = "type")]
+    kind: Option<&'static str>,
+    resolution: Option<&'static str>,
+    stream_content: u8,
+    component_type: u8,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MirakurunAudio {
+    component_type: u8,
+    component_tag: u8,
+    is_main: bool,
+    sampling_rate: Option<u32>,
+    langs: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MirakurunProgram<'a> {
+    id: u64,
+    event_id: u16,
+    service_id: u16,
+    network_id: u16,
+    start_at: i64,
+    duration: i64,
+    is_free: bool,
+    name: &'a str,
+    description: &'a str,
+    genres: Vec<MirakurunGenre>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    video: Option<MirakurunVideo>,
+    audios: Vec<MirakurunAudio>,
+    extended: &'a BTreeMap<String, String>,
+}
+
+impl<'a> From<&'a Event> for MirakurunProgram<'a> {
+    fn from(e: &'a Event) -> Self {
+        let network_id = u64::from(e.network_id);
+        let service_id = u64::from(e.service_id);
+        MirakurunProgram {
+            // digits of network_id, 5 digits of service_id and event_id.
+            id: (network_id * 100000 + service_id) * 100000 + u64::from(e.id),
+            event_id: e.id,
+            service_id: e.service_id,
+            network_id: e.network_id,
+            start_at: e.start.timestamp_millis(),
+            duration: e.duration.0.num_milliseconds(),
+            is_free: !e.free_ca_mode,
+            name: &e.title,
+            description: &e.summary,
+            genres: e
+                .contents
+                .iter()
+                .map(|c| MirakurunGenre {
+                    lv1: c[0],
+                    lv2: c[1],
+                    un1: c[2],
+                    un2: c[3],
+                })
+                .collect(),
+            video: e
+                .video_component
+                .map(|(stream_content, component_type)| MirakurunVideo {
+                    kind: match stream_content {
+                        0x1 => Some("mpeg2"),
+                        0x5 => Some("h.264"),
+                        0x9 => Some("h.265"),
+                        _ => None,
+                    },
+                    resolution: e.video,
+                    stream_content,
+                    component_type,
+                }),
+            audios: e
+                .audio
+                .iter()
+                .map(|a| MirakurunAudio {
+                    component_type: a.component_type,
+                    component_tag: a.component_tag,
+                    is_main: a.main,
+                    sampling_rate: match a.sampling_rate {
+                        1 => Some(16000),
+                        2 => Some(22050),
+                        3 => Some(24000),
+                        5 => Some(32000),
+                        6 => Some(44100),
+                        7 => Some(48000),
+                        _ => None,
+                    },
+                    langs: a.languages.clone(),
+                })
+                .collect(),
+            extended: &e.detail,
+        }
+    }
+}
+
+const COLUMNS: [&str; 5] = ["service", "start", "duration", "title", "genre"];
+
+fn columns(event: &Event) -> [String; 5] {
+    [
+        event.service_id.to_string(),
+        event.start.to_rfc3339(),
+        event.duration.0.num_seconds().to_string(),
+        event.title.clone(),
+        event
+            .genres
+            .first()
+            .cloned()
+            .unwrap_or_else(|| event.category.clone()),
+    ]
+}
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn print_events<'a, I: Iterator<Item = &'a Event>>(
+    events: I,
+    format: Format,
+    out: &mut RecordWriter,
+) -> Result<()> {
+    match format {
+        Format::Json => {
+            for e in events {
+                out.write_line(&serde_json::to_string(e)?)?;
+            }
+        }
+        Format::Csv => {
+            out.write_line(&COLUMNS.join(","))?;
+            for e in events {
+                let row: Vec<_> = columns(e).iter().map(|c| escape_csv(c)).collect();
+                out.write_line(&row.join(","))?;
+            }
+        }
+        Format::Mirakurun => {
+            let programs: Vec<_> = events.map(MirakurunProgram::from).collect();
+            out.write_line(&serde_json::to_string(&programs)?)?;
+        }
+        Format::Table => {
+            let rows: Vec<_> = events.map(columns).collect();
+            let mut widths = COLUMNS.map(|c| c.chars().count());
+            for row in rows.iter() {
+                for (width, c) in widths.iter_mut().zip(row.iter()) {
+                    *width = (*width).max(c.chars().count());
+                }
+            }
+            let mut print_row = |row: &[&str]| {
+                let cells: Vec<_> = row
+                    .iter()
+                    .zip(widths.iter())
+                    .map(|(c, width)| format!("{:width$}", c, width = width))
+                    .collect();
+                out.write_line(cells.join("  ").trim_end())
+            };
+            print_row(&COLUMNS)?;
+            for row in rows.iter() {
+                print_row(&row.each_ref().map(|c| c.as_str()))?;
+            }
         }
     }
+    out.flush()
+}
+
+/// Names accepted by `--genre`.
+pub const GENRE_NAMES: [&str; 15] = [
+    "news",
+    "sports",
+    "information",
+    "drama",
+    "music",
+    "variety",
+    "movies",
+    "animation",
+    "documentary",
+    "theatre",
+    "hobby",
+    "welfare",
+    "reserved",
+    "extention",
+    "others",
+];
+
+/// Conditions of events to print.
+#[derive(Default)]
+pub struct Filter {
+    /// Events with any of these genres, or any events if empty.
+    pub genres: Vec<String>,
+    /// Events which end after this.
+    pub from: Option<DateTime<FixedOffset>>,
+    /// Events which start before this.
+    pub to: Option<DateTime<FixedOffset>>,
 }
 
-fn stringify_genre(genre: &Genre) -> &'static str {
-    match genre {
-        Genre::News => "news",
-        Genre::Sports => "sports",
-        Genre::Information => "information",
-        Genre::Drama => "drama",
-        Genre::Music => "music",
-        Genre::Variety => "variety",
-        Genre::Movies => "movies",
-        Genre::Animation => "animation",
-        Genre::Documentary => "documentary",
-        Genre::Theatre => "theatre",
-        Genre::Hobby => "hobby",
-        Genre::Welfare => "welfare",
-        Genre::Reserved => "reserved",
-        Genre::Extention => "extention",
-        Genre::Others => "others",
+impl Filter {
+    fn matches(&self, event: &Event) -> bool {
+        let genre = self.genres.is_empty()
+            || event
+                .categories
+                .iter()
+                .any(|genre| self.genres.iter().any(|g| g == genre));
+        let from = self
+            .from
+            .is_none_or(|from| event.start + event.duration.0 > from);
+        let to = self.to.is_none_or(|to| event.start < to);
+        genre && from && to
     }
 }
 
 fn decode_to_utf8<'a, I: Iterator<Item = &'a u8>>(i: I) -> Result<String> {
     let decoder = arib::string::AribDecoder::with_event_initialization();
-    decoder.decode(i)
+    decoder.decode(i).map_err(|e| {
+        errors::count(errors::Kind::String);
+        e.into()
+    })
 }
 
 fn try_into_event(eit: psi::EventInformationSection) -> Result<Vec<Event>> {
@@ -86,15 +471,24 @@ fn try_into_event(eit: psi::EventInformationSection) -> Result<Vec<Event>> {
             continue;
         }
         let mut event = Event::new(
+            eit.original_network_id,
+            eit.transport_stream_id,
+            eit.service_id,
             eit_event.event_id,
             eit_event.start_time.unwrap(),
             eit_event.duration.unwrap(),
         );
+        event.table_id = eit.table_id;
+        event.version_number = eit.version_number;
+        event.free_ca_mode = eit_event.free_ca_mode;
         let mut item_descs = Vec::new();
         let mut items = Vec::new();
+        // free text which follows items, split over descriptors as items are.
+        let mut texts = Vec::new();
         for desc in eit_event.descriptors.iter() {
             match desc {
                 psi::Descriptor::ExtendedEventDescriptor(e) => {
+                    texts.push(e.text);
                     for item in e.items.iter() {
                         if !item.item_description.is_empty() {
                             let d = decode_to_utf8(item_descs.iter().cloned().flatten())?;
@@ -115,9 +509,49 @@ fn try_into_event(eit: psi::EventInformationSection) -> Result<Vec<Event>> {
                 }
                 psi::Descriptor::ContentDescriptor(c) => {
                     if event.category.is_empty() && !c.items.is_empty() {
-                        event.category = String::from(stringify_genre(&c.items[0]));
+                        event.category = String::from(c.items[0].genre.name());
                     }
+                    event
+                        .categories
+                        .extend(c.items.iter().map(|c| c.genre.name()));
+                    event.genres.extend(c.items.iter().map(|c| c.name()));
+                    event.contents.extend(c.items.iter().map(|c| {
+                        [
+                            c.content_nibble_level_1,
+                            c.content_nibble_level_2,
+                            c.user_nibble_1,
+                            c.user_nibble_2,
+                        ]
+                    }));
+                }
+                psi::Descriptor::ComponentDescriptor(c) if event.video.is_none() => {
+                    event.video = c.video_format();
+                    event.video_component = Some((c.stream_content, c.component_type));
+                }
+                psi::Descriptor::AudioComponentDescriptor(c) => {
+                    let mut languages = vec![c.iso_639_language_code.clone()];
+                    languages.extend(c.iso_639_language_code_2.iter().cloned());
+                    event.audio.push(Audio {
+                        mode: c.mode(),
+                        languages,
+                        component_type: c.component_type,
+                        component_tag: c.component_tag,
+                        main: c.main_component_flag,
+                        sampling_rate: c.sampling_rate,
+                    });
                 }
+                psi::Descriptor::DigitalCopyControlDescriptor(c) => {
+                    event.copy_control = Some(c.recording_control_name());
+                }
+                psi::Descriptor::DataContentDescriptor(c) => {
+                    event.data_contents.push(DataContent {
+                        data_component_id: c.data_component_id,
+                        entry_component: c.entry_component,
+                        component_refs: c.component_refs.to_vec(),
+                        text: decode_to_utf8(c.text.iter())?,
+                    });
+                }
+                psi::Descriptor::HyperlinkDescriptor(d) => event.links.push(Link::from(d)),
                 _ => {}
             }
         }
@@ -126,6 +560,13 @@ fn try_into_event(eit: psi::EventInformationSection) -> Result<Vec<Event>> {
         if !d.is_empty() && !i.is_empty() {
             event.detail.insert(d, i);
         }
+        let text = decode_to_utf8(texts.iter().cloned().flatten())?;
+        if !text.is_empty() {
+            if !event.summary.is_empty() {
+                event.summary.push('\n');
+            }
+            event.summary.push_str(&text);
+        }
         events.push(event)
     }
     Ok(events)
@@ -133,7 +574,7 @@ fn try_into_event(eit: psi::EventInformationSection) -> Result<Vec<Event>> {
 
 async fn find_service_ids<S: Stream<Item = ts::TSPacket> + Unpin>(s: &mut S) -> Result<Vec<u16>> {
     let sdt_stream = s.filter(|packet| packet.pid == psi::SDT_PID);
-    let mut buffer = psi::Buffer::new(sdt_stream);
+    let mut buffer = psi::Buffer::new(sdt_stream).verify_crc();
     loop {
         match buffer.next().await {
             Some(Ok(bytes)) => {
@@ -154,93 +595,567 @@ async fn find_service_ids<S: Stream<Item = ts::TSPacket> + Unpin>(s: &mut S) ->
     }
 }
 
-fn packets_to_events<S: Stream<Item = ts::TSPacket> + Unpin>(
+// EIT p/f of the actual and other streams.
+const PRESENT_FOLLOWING_TABLE_IDS: [u8; 2] = [0x4e, 0x4f];
+
+/// The present and following events of a service, from EIT p/f.
+#[derive(Default, Serialize)]
+pub struct NowPlaying {
+    service_id: u16,
+    present: Option<Event>,
+    following: Option<Event>,
+    // whether each section is seen, 0 for present and 1 for following.
+    #[serde(skip)]
+    seen: [bool; 2],
+}
+
+impl NowPlaying {
+    pub fn new(service_id: u16) -> NowPlaying {
+        NowPlaying {
+            service_id,
+            ..Default::default()
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        self.seen == [true, true]
+    }
+}
+
+/// Updates the events of the services by a section, if it is of EIT p/f.
+pub fn update_now_playing(services: &mut BTreeMap<u16, NowPlaying>, section: &[u8]) {
+    if !PRESENT_FOLLOWING_TABLE_IDS.contains(&section[0]) {
+        return;
+    }
+    let eit = match psi::EventInformationSection::parse(section) {
+        Ok(eit) => eit,
+        Err(e) => {
+            info!("eit parse error: {:?}", e);
+            return;
+        }
+    };
+    let now = match services.get_mut(&eit.service_id) {
+        Some(now) => now,
+        None => return,
+    };
+    let section_number = usize::from(eit.section_number);
+    if section_number > 1 {
+        return;
+    }
+    now.seen[section_number] = true;
+    // an empty section means no event.
+    let event = match try_into_event(eit) {
+        Ok(events) => events.into_iter().next(),
+        Err(e) => {
+            info!("eit decode error: {:?}", e);
+            None
+        }
+    };
+    if section_number == 0 {
+        now.present = event;
+    } else {
+        now.following = event;
+    }
+}
+
+// reads EIT p/f until both sections of every service are seen, without
+// decoding the schedule.
+async fn find_present_following<S: Stream<Item = ts::TSPacket> + Unpin>(
     sids: Vec<u16>,
+    mut s: S,
+) -> BTreeMap<u16, NowPlaying> {
+    let mut buffers: HashMap<u16, psi::Buffer<()>> = ts::EIT_PIDS
+        .iter()
+        .map(|pid| (*pid, psi::Buffer::detached().verify_crc()))
+        .collect();
+    let mut services: BTreeMap<u16, NowPlaying> = sids
+        .iter()
+        .map(|sid| (*sid, NowPlaying::new(*sid)))
+        .collect();
+    while let Some(packet) = s.next().await {
+        let pid = packet.pid;
+        let buffer = match buffers.get_mut(&pid) {
+            Some(buffer) => buffer,
+            None => continue,
+        };
+        if let Err(e) = buffer.feed(packet) {
+            info!("pid {:#x}: {:?}", pid, e);
+            continue;
+        }
+        while let Some(bytes) = buffer.next_section() {
+            update_now_playing(&mut services, &bytes[..]);
+        }
+        if services.values().all(NowPlaying::is_complete) {
+            break;
+        }
+    }
+    services
+}
+
+// prints the present and following events of each service, a line of both
+// for each service in the json format.
+fn print_now(
+    services: BTreeMap<u16, NowPlaying>,
+    filter: &Filter,
+    format: Format,
+    normalizer: &arib::normalize::Normalizer,
+    local_time: &LocalTime,
+    out: &mut RecordWriter,
+) -> Result<()> {
+    let services = services.into_values().map(|now| {
+        let pick = |event: Option<Event>| {
+            event
+                .filter(|e| filter.matches(e))
+                .map(|e| local_time.localize(e.normalize(normalizer)))
+        };
+        NowPlaying {
+            present: pick(now.present),
+            following: pick(now.following),
+            ..now
+        }
+    });
+    if let Format::Json = format {
+        for now in services {
+            out.write_line(&serde_json::to_string(&now)?)?;
+        }
+        return out.flush();
+    }
+    let events: Vec<Event> = services
+        .flat_map(|now| now.present.into_iter().chain(now.following))
+        .collect();
+    print_events(events.iter(), format, out)
+}
+
+// forgotten events are pruned at most once in this interval of the clock.
+const PRUNE_INTERVAL_MINUTES: i64 = 10;
+
+// the JST time of the latest TOT or TDT, which is never set unless the
+// events are printed incrementally.
+#[derive(Clone, Default)]
+struct Clock(Arc<Mutex<Option<DateTime<FixedOffset>>>>);
+
+impl Clock {
+    fn set(&self, time: DateTime<FixedOffset>) {
+        *self.0.lock().unwrap() = Some(time);
+    }
+
+    fn now(&self) -> Option<DateTime<FixedOffset>> {
+        *self.0.lock().unwrap()
+    }
+}
+
+/// A zone start times are printed in, `UTC`, `local` for the zone of the
+/// system, or an offset like `+05:30`.
+#[derive(Clone, Copy, Debug)]
+pub enum Zone {
+    Local,
+    Fixed(FixedOffset),
+}
+
+impl Zone {
+    pub fn utc() -> Zone {
+        Zone::Fixed(FixedOffset::east_opt(0).unwrap())
+    }
+}
+
+impl FromStr for Zone {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Zone> {
+        if s.eq_ignore_ascii_case("utc") || s == "Z" {
+            return Ok(Zone::utc());
+        }
+        if s.eq_ignore_ascii_case("local") {
+            return Ok(Zone::Local);
+        }
+        // an offset is parsed as a part of a time.
+        match DateTime::parse_from_rfc3339(&format!("2000-01-01T00:00:00{}", s)) {
+            Ok(t) => Ok(Zone::Fixed(*t.offset())),
+            Err(_) => bail!(
+                "time zone must be UTC, local or an offset like +05:30: {:?}",
+                s
+            ),
+        }
+    }
+}
+
+// the local time offset of the latest TOT, by which start times are given
+// in the local time where it differs from JST, unless a zone is given.
+#[derive(Clone, Default)]
+struct LocalTime {
+    offset: Arc<Mutex<Option<psi::descriptor::LocalTimeOffset>>>,
+    zone: Option<Zone>,
+}
+
+impl LocalTime {
+    fn new(zone: Option<Zone>) -> Self {
+        LocalTime {
+            zone,
+            ..Default::default()
+        }
+    }
+
+    fn set(&self, offset: psi::descriptor::LocalTimeOffset) {
+        *self.offset.lock().unwrap() = Some(offset);
+    }
+
+    fn localize(&self, mut event: Event) -> Event {
+        event.start = match self.zone {
+            Some(Zone::Local) => event.start.with_timezone(&chrono::Local).fixed_offset(),
+            Some(Zone::Fixed(zone)) => event.start.with_timezone(&zone),
+            None => match self.offset.lock().unwrap().as_ref() {
+                Some(offset) => event.start.with_timezone(&offset.zone_at(&event.start)),
+                None => event.start,
+            },
+        };
+        event
+    }
+}
+
+// remembers a value for each event until the event ends by the clock, so
+// that the memory is bounded by the events in the schedule, not by the
+// length of the input.
+struct EventMemory<K, V> {
+    clock: Clock,
+    pruned_at: Option<DateTime<FixedOffset>>,
+    entries: HashMap<K, (V, Option<DateTime<FixedOffset>>)>,
+}
+
+impl<K: Eq + Hash, V: PartialEq> EventMemory<K, V> {
+    fn new(clock: Clock) -> Self {
+        EventMemory {
+            clock,
+            pruned_at: None,
+            entries: HashMap::new(),
+        }
+    }
+
+    // returns true if the event has ended, forgetting ended events from
+    // time to time.
+    fn ended(&mut self, end: Option<DateTime<FixedOffset>>) -> bool {
+        let now = match self.clock.now() {
+            Some(now) => now,
+            None => return false,
+        };
+        let interval = chrono::Duration::minutes(PRUNE_INTERVAL_MINUTES);
+        if self.pruned_at.is_none_or(|t| now - t >= interval) {
+            self.entries
+                .retain(|_, (_, end)| end.is_none_or(|end| end > now));
+            self.pruned_at = Some(now);
+        }
+        end.is_some_and(|end| end <= now)
+    }
+
+    // remembers the value, and returns false if it is already remembered.
+    fn update(&mut self, key: K, value: V, end: Option<DateTime<FixedOffset>>) -> bool {
+        let changed = self.entries.get(&key).is_none_or(|(old, _)| *old != value);
+        self.entries.insert(key, (value, end));
+        changed
+    }
+}
+
+// decodes events in EITs, skipping versions of events already seen, as
+// sections repeat constantly. Events which have ended by the clock are
+// skipped too. Only events of the services are decoded, or events of every
+// service in the actual and other streams if none are given.
+fn packets_to_events<S: Stream<Item = ts::TSPacket> + Unpin>(
+    sids: Option<Vec<u16>>,
     s: S,
+    clock: Clock,
 ) -> impl Stream<Item = Vec<Event>> {
-    psi::Buffer::new(s).filter_map(move |bytes| match bytes {
-        Ok(bytes) => {
-            let bytes = &bytes[..];
-            let table_id = bytes[0];
-            if 0x4e <= table_id && table_id <= 0x6f {
-                match psi::EventInformationSection::parse(bytes) {
-                    Ok(eit) => {
-                        if sids.contains(&eit.service_id) {
+    // the version of each event in each table.
+    let mut versions: EventMemory<(u16, u16, u16, u8), u8> = EventMemory::new(clock);
+    psi::Buffer::new(s)
+        .verify_crc()
+        .filter_map(move |bytes| match bytes {
+            Ok(bytes) => {
+                let bytes = &bytes[..];
+                let table_id = bytes[0];
+                if (0x4e..=0x6f).contains(&table_id) {
+                    match psi::EventInformationSection::parse(bytes) {
+                        Ok(mut eit) => {
+                            if sids.as_ref().is_some_and(|s| !s.contains(&eit.service_id)) {
+                                return None;
+                            }
+                            let (service_id, version) = (eit.service_id, eit.version_number);
+                            let transport_stream_id = eit.transport_stream_id;
+                            eit.events.retain(|e| {
+                                let end = e.start_time.zip(e.duration).map(|(s, d)| s + d);
+                                if versions.ended(end) {
+                                    return false;
+                                }
+                                let key = (transport_stream_id, service_id, e.event_id, table_id);
+                                versions.update(key, version, end)
+                            });
+                            if eit.events.is_empty() {
+                                return None;
+                            }
                             if let Ok(events) = try_into_event(eit) {
                                 return Some(events);
                             }
                         }
-                    }
-                    Err(e) => {
-                        info!("eit parse error: {:?}", e);
+                        Err(e) => {
+                            info!("eit parse error: {:?}", e);
+                        }
                     }
                 }
+                None
             }
-            None
-        }
-        Err(e) => {
-            info!("packets_to_events: {:?}", e);
-            None
-        }
-    })
+            Err(e) => {
+                info!("packets_to_events: {:?}", e);
+                None
+            }
+        })
 }
 
+// decodes events in all EIT PIDs, localizing their start times by TOT. The
+// clock is kept by TOT too if it is given. Packets of each PID and decoded
+// events are buffered up to capacity.
 fn into_event_stream<S: Stream<Item = ts::TSPacket> + Send + 'static + Unpin>(
-    service_ids: Vec<u16>,
-    mut s: S,
+    service_ids: Option<Vec<u16>>,
+    s: S,
+    clock: Option<Clock>,
+    local_time: LocalTime,
+    capacity: usize,
 ) -> impl Stream<Item = Vec<Event>> {
-    let (event_tx, event_rx) = channel(1);
-    let mut tx_map = HashMap::new();
+    let (event_tx, event_rx) = channel(capacity);
+    let mut demuxer = ts::Demuxer::with_capacity(capacity);
+    let mut tot_stream = demuxer.register(psi::TOT_PID);
+    let tot_clock = clock.clone();
+    let tot_local_time = local_time.clone();
+    tokio::spawn(async move {
+        while let Some(packet) = tot_stream.next().await {
+            if let (Some(clock), Some(time)) = (tot_clock.as_ref(), tot_jst_time(&packet)) {
+                clock.set(time);
+            }
+            if let Some(offset) = tot_local_time_offset(&packet) {
+                tot_local_time.set(offset);
+            }
+        }
+    });
     for pid in ts::EIT_PIDS.iter() {
-        let (tx, rx) = channel(1);
-        tx_map.insert(pid, tx);
-        let mut events_stream = packets_to_events(service_ids.clone(), ReceiverStream::new(rx));
+        let mut events_stream = packets_to_events(
+            service_ids.clone(),
+            demuxer.register(*pid),
+            clock.clone().unwrap_or_default(),
+        );
         let event_tx = event_tx.clone();
+        let local_time = local_time.clone();
         tokio::spawn(async move {
             while let Some(events) = events_stream.next().await {
+                let events = events.into_iter().map(|e| local_time.localize(e)).collect();
                 if event_tx.send(events).await.is_err() {
                     break;
                 }
             }
         });
     }
-
-    tokio::spawn(async move {
-        while let Some(packet) = s.next().await {
-            if let Some(tx) = tx_map.get_mut(&packet.pid) {
-                if tx.send(packet).await.is_err() {
-                    break;
-                }
-            }
-        }
-    });
+    tokio::spawn(demuxer.run(s));
 
     ReceiverStream::new(event_rx)
 }
 
+// collects the latest version of each event, ordered by event id.
 async fn into_event_map<S: Stream<Item = Vec<Event>> + Unpin>(
     mut s: S,
-) -> Result<BTreeMap<u16, Event>> {
+) -> Result<BTreeMap<(u16, u16, u16, u16), Event>> {
     let mut out = BTreeMap::new();
     while let Some(events) = s.next().await {
         for event in events.into_iter() {
-            out.insert(event.id, event);
+            let key = (
+                event.id,
+                event.network_id,
+                event.transport_stream_id,
+                event.service_id,
+            );
+            out.insert(key, event);
         }
     }
     Ok(out)
 }
 
-pub async fn run(input: Option<PathBuf>) -> Result<()> {
-    let input = path_to_async_read(input).await?;
-    let packets = FramedRead::new(input, ts::TSPacketDecoder::new());
-    let packets = strip_error_packets(packets);
+/// Reads the latest version of each event of every service in a TS.
+pub(super) async fn read_programs(input: PathBuf) -> Result<Vec<Program>> {
+    let packets = paths_to_packets(vec![input], false).await?;
+    let packets = interrupt_on_ctrl_c(strip_error_packets(packets));
     let mut cueable_packets = cueable(packets);
     let sids = find_service_ids(&mut cueable_packets).await?;
     let packets = cueable_packets.cue_up();
-    let events = into_event_stream(sids, packets);
-    let event_map = into_event_map(events).await?;
-    for e in event_map.values() {
-        println!("{}", serde_json::to_string(e)?);
+    let mut events = into_event_stream(Some(sids), packets, None, LocalTime::default(), 1);
+    let mut programs = BTreeMap::new();
+    while let Some(events) = events.next().await {
+        for e in events.into_iter() {
+            let program = Program {
+                service_id: Some(e.service_id),
+                event_id: e.id,
+                start: e.start,
+                duration: e.duration.0.num_seconds(),
+                title: e.title,
+            };
+            programs.insert((e.service_id, e.id), program);
+        }
+    }
+    Ok(programs.into_values().collect())
+}
+
+fn format_line(format: LineFormat, e: &Event) -> Result<String> {
+    let line = match format {
+        LineFormat::Json => serde_json::to_string(e)?,
+        LineFormat::Csv => {
+            let row: Vec<_> = columns(e).iter().map(|c| escape_csv(c)).collect();
+            row.join(",")
+        }
+        LineFormat::Mirakurun => serde_json::to_string(&MirakurunProgram::from(e))?,
+    };
+    Ok(line)
+}
+
+// prints events as soon as they are found or updated.
+async fn print_event_stream<S: Stream<Item = Vec<Event>> + Unpin>(
+    mut s: S,
+    filter: &Filter,
+    format: LineFormat,
+    out: &mut RecordWriter,
+) -> Result<()> {
+    // the version of each event in each table, p/f and schedule tables are
+    // versioned separately.
+    let mut versions = HashMap::new();
+    if let LineFormat::Csv = format {
+        out.write_line(&COLUMNS.join(","))?;
+    }
+    while let Some(events) = s.next().await {
+        for e in events.into_iter() {
+            let key = (e.transport_stream_id, e.service_id, e.id, e.table_id);
+            if versions.insert(key, e.version_number) == Some(e.version_number) {
+                continue;
+            }
+            if !filter.matches(&e) {
+                continue;
+            }
+            let line = format_line(format, &e)?;
+            out.write_line(&line)?;
+        }
+        out.end_batch()?;
     }
-    Ok(())
+    out.flush()
+}
+
+// prints each event once as soon as it is found, and again only if the
+// printed record changes. Events are forgotten when they end by the clock.
+async fn print_incremental<S: Stream<Item = Vec<Event>> + Unpin>(
+    mut s: S,
+    filter: &Filter,
+    format: LineFormat,
+    out: &mut RecordWriter,
+    clock: Clock,
+) -> Result<()> {
+    // the hash of the last record printed for each event, as p/f and
+    // schedule tables carry the same events with their own versions.
+    let mut printed: EventMemory<(u16, u16, u16), u64> = EventMemory::new(clock);
+    if let LineFormat::Csv = format {
+        out.write_line(&COLUMNS.join(","))?;
+    }
+    while let Some(events) = s.next().await {
+        for e in events.into_iter() {
+            let end = Some(e.start + e.duration.0);
+            if printed.ended(end) || !filter.matches(&e) {
+                continue;
+            }
+            let line = format_line(format, &e)?;
+            let mut hasher = DefaultHasher::new();
+            line.hash(&mut hasher);
+            let key = (e.transport_stream_id, e.service_id, e.id);
+            if printed.update(key, hasher.finish(), end) {
+                out.write_line(&line)?;
+            }
+        }
+        out.end_batch()?;
+    }
+    out.flush()
+}
+
+/// The JSON Schema of an event printed in the json format.
+pub fn schema() -> RootSchema {
+    schemars::schema_for!(Event)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn run(
+    input: Vec<PathBuf>,
+    progress: bool,
+    filter: Filter,
+    format: Format,
+    stream: bool,
+    incremental: bool,
+    now: bool,
+    include_other_streams: bool,
+    zone: Option<Zone>,
+    buffer: usize,
+    normalizer: arib::normalize::Normalizer,
+) -> Result<()> {
+    let line_format = LineFormat::new(format);
+    if (stream || incremental) && line_format.is_none() {
+        bail!("table format needs the whole events, which --stream or --incremental does not wait for");
+    }
+    let packets = paths_to_packets(input, progress).await?;
+    let packets = interrupt_on_ctrl_c(strip_error_packets(packets));
+    let mut cueable_packets = cueable(packets);
+    let sids = find_service_ids(&mut cueable_packets).await?;
+    let packets = cueable_packets.cue_up();
+    if now {
+        let services = find_present_following(sids, packets).await;
+        let mut out = RecordWriter::stdout(false);
+        let local_time = LocalTime::new(zone);
+        return print_now(
+            services,
+            &filter,
+            format,
+            &normalizer,
+            &local_time,
+            &mut out,
+        );
+    }
+    let clock = incremental.then(Clock::default);
+    let sids = (!include_other_streams).then_some(sids);
+    let local_time = LocalTime::new(zone);
+    let events = into_event_stream(sids, packets, clock.clone(), local_time.clone(), buffer).map(
+        move |events| {
+            events
+                .into_iter()
+                .map(|mut e| {
+                    if include_other_streams {
+                        e.stream = Some(StreamIds {
+                            original_network_id: e.network_id,
+                            transport_stream_id: e.transport_stream_id,
+                            service_id: e.service_id,
+                        });
+                    }
+                    if normalizer.is_noop() {
+                        return e;
+                    }
+                    e.normalize(&normalizer)
+                })
+                .collect()
+        },
+    );
+    if let (Some(clock), Some(format)) = (clock, line_format) {
+        let mut out = RecordWriter::stdout(true);
+        return print_incremental(events, &filter, format, &mut out, clock).await;
+    }
+    if let (true, Some(format)) = (stream, line_format) {
+        let mut out = RecordWriter::stdout(true);
+        return print_event_stream(events, &filter, format, &mut out).await;
+    }
+    let event_map = into_event_map(events).await?;
+    // events found before the first TOT are localized by it too.
+    let events: Vec<Event> = event_map
+        .into_values()
+        .map(|e| local_time.localize(e))
+        .collect();
+    let mut out = RecordWriter::stdout(false);
+    print_events(
+        events.iter().filter(|e| filter.matches(e)),
+        format,
+        &mut out,
+    )
 }
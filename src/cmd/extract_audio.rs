@@ -0,0 +1,105 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use log::{info, warn};
+use tokio::fs::File;
+use tokio::io::AsyncWriteExt;
+use tokio_stream::{Stream, StreamExt};
+
+use super::common;
+use super::errors;
+use super::io::{path_to_async_write, path_to_packets};
+use tstools::adts;
+use tstools::pes;
+use tstools::psi;
+use tstools::stream::cueable;
+use tstools::ts;
+
+async fn find_audio_pid<S: Stream<Item = ts::TSPacket> + Unpin>(
+    pid: u16,
+    component_tag: Option<u8>,
+    s: &mut S,
+) -> Result<u16> {
+    let pmt_stream = s.filter(move |packet| packet.pid == pid);
+    let mut buffer = psi::Buffer::new(pmt_stream).verify_crc();
+    loop {
+        match buffer.next().await {
+            Some(Ok(bytes)) => {
+                let bytes = &bytes[..];
+                let table_id = bytes[0];
+                if table_id == psi::TS_PROGRAM_MAP_SECTION {
+                    let pms = match psi::TSProgramMapSection::parse(bytes) {
+                        Ok(pms) => pms,
+                        Err(e) => {
+                            info!("pmt parse error: {:?}", e);
+                            continue;
+                        }
+                    };
+                    for si in pms.stream_info.iter() {
+                        if si.stream_type != psi::STREAM_TYPE_ADTS {
+                            continue;
+                        }
+                        match component_tag {
+                            Some(tag) if common::component_tag(si) != Some(tag) => continue,
+                            _ => return Ok(si.elementary_pid),
+                        }
+                    }
+                    bail!("no audio stream found");
+                }
+            }
+            Some(Err(e)) => return Err(e.into()),
+            None => bail!("no audio stream found"),
+        }
+    }
+}
+
+// writes only complete ADTS frames, so a broken PES does not corrupt the output.
+async fn dump_adts<S: Stream<Item = ts::TSPacket> + Unpin>(
+    pid: u16,
+    s: S,
+    mut out: File,
+) -> Result<()> {
+    let audio_stream = s.filter(move |packet| packet.pid == pid);
+    let mut buffer = pes::Buffer::new(audio_stream);
+    while let Some(bytes) = buffer.next().await {
+        let bytes = match bytes {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                warn!("pes buffer error: {:?}", e);
+                continue;
+            }
+        };
+        let pes = match pes::PESPacket::parse(&bytes[..]) {
+            Ok(pes) => pes,
+            Err(e) => {
+                warn!("pes parse error: {:?}", e);
+                errors::count(errors::Kind::Pes);
+                continue;
+            }
+        };
+        if let pes::PESPacketBody::NormalPESPacketBody(ref body) = pes.body {
+            for (_, frame) in adts::frames(body.pes_packet_data_byte) {
+                out.write_all(frame).await?;
+            }
+        }
+    }
+    out.flush().await?;
+    Ok(())
+}
+
+pub async fn run(
+    input: Option<PathBuf>,
+    output: Option<PathBuf>,
+    service_id: Option<u16>,
+    component_tag: Option<u8>,
+) -> Result<()> {
+    let output = path_to_async_write(output).await?;
+    let packets = path_to_packets(input, false).await?;
+    let packets = common::strip_error_packets(packets);
+    let mut cueable_packets = cueable(packets);
+    let pmt_pid = common::find_pmt_pid(&mut cueable_packets, service_id).await?;
+    let pid = find_audio_pid(pmt_pid, component_tag, &mut cueable_packets).await?;
+    info!("extract audio pid {}", pid);
+    let packets = cueable_packets.cue_up();
+    dump_adts(pid, packets, output).await
+}
@@ -0,0 +1,81 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::ValueEnum;
+use log::{info, warn};
+use tokio::fs::File;
+use tokio::io::AsyncWriteExt;
+use tokio_stream::{Stream, StreamExt};
+
+use super::common;
+use super::errors;
+use super::io::{path_to_async_write, path_to_packets};
+use tstools::pes;
+use tstools::stream::cueable;
+use tstools::ts;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum EsKind {
+    Video,
+    Audio,
+}
+
+pub async fn dump_es<S: Stream<Item = ts::TSPacket> + Unpin>(
+    pid: u16,
+    s: S,
+    mut out: File,
+) -> Result<()> {
+    let es_stream = s.filter(move |packet| packet.pid == pid);
+    let mut buffer = pes::Buffer::new(es_stream);
+    while let Some(bytes) = buffer.next().await {
+        let bytes = match bytes {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                warn!("pes buffer error: {:?}", e);
+                continue;
+            }
+        };
+        let pes = match pes::PESPacket::parse(&bytes[..]) {
+            Ok(pes) => pes,
+            Err(e) => {
+                warn!("pes parse error: {:?}", e);
+                errors::count(errors::Kind::Pes);
+                continue;
+            }
+        };
+        if let pes::PESPacketBody::NormalPESPacketBody(ref body) = pes.body {
+            out.write_all(body.pes_packet_data_byte).await?;
+        }
+    }
+    out.flush().await?;
+    Ok(())
+}
+
+pub async fn run(
+    input: Option<PathBuf>,
+    output: Option<PathBuf>,
+    pid: Option<u16>,
+    kind: EsKind,
+) -> Result<()> {
+    let output = path_to_async_write(output).await?;
+    let packets = path_to_packets(input, false).await?;
+    let packets = common::strip_error_packets(packets);
+    let mut cueable_packets = cueable(packets);
+    let pid = match pid {
+        Some(pid) => pid,
+        None => {
+            let required = match kind {
+                EsKind::Video => common::StreamKind::Video,
+                EsKind::Audio => common::StreamKind::Audio,
+            };
+            let meta = common::find_main_meta(&mut cueable_packets, None, &[required]).await?;
+            match kind {
+                EsKind::Video => meta.video_pid(),
+                EsKind::Audio => meta.audio_pid(),
+            }
+        }
+    };
+    info!("extract pid {}", pid);
+    let packets = cueable_packets.cue_up();
+    dump_es(pid, packets, output).await
+}
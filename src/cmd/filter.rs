@@ -0,0 +1,41 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use bytes::BytesMut;
+use tokio::io::AsyncWriteExt;
+use tokio_stream::StreamExt;
+
+use super::common;
+use super::io::{path_to_async_write, path_to_packets};
+use tstools::ts;
+
+const OUTPUT_CHUNK: usize = 1 << 20;
+
+/// Passes packets of the kept PIDs, or all but the dropped PIDs, without
+/// touching PSI.
+pub async fn run(
+    input: Option<PathBuf>,
+    output: Option<PathBuf>,
+    keep: Vec<u16>,
+    drop: Vec<u16>,
+) -> Result<()> {
+    let keep: HashSet<u16> = keep.into_iter().collect();
+    let drop: HashSet<u16> = drop.into_iter().collect();
+    let mut output = path_to_async_write(output).await?;
+    let packets = path_to_packets(input, false).await?;
+    let mut packets = common::strip_error_packets(packets);
+    let mut chunk = BytesMut::with_capacity(OUTPUT_CHUNK);
+    while let Some(packet) = packets.next().await {
+        if (!keep.is_empty() && !keep.contains(&packet.pid)) || drop.contains(&packet.pid) {
+            continue;
+        }
+        chunk.extend_from_slice(&packet.into_raw()[..]);
+        if chunk.len() + ts::TS_PACKET_LENGTH > OUTPUT_CHUNK {
+            output.write_all(&chunk.split()[..]).await?;
+        }
+    }
+    output.write_all(&chunk[..]).await?;
+    output.flush().await?;
+    Ok(())
+}
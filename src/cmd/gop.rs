@@ -0,0 +1,161 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use log::info;
+use serde_derive::Serialize;
+use tokio_stream::StreamExt;
+
+use super::common;
+use super::errors;
+use super::io::path_to_packets;
+use tstools::h262;
+use tstools::pes;
+use tstools::psi;
+use tstools::stream::cueable;
+
+#[derive(Serialize, Default)]
+struct Gop {
+    // PTS of the PES packet the GOP starts in.
+    pts: Option<u64>,
+    // from the GOP header, if the stream has them.
+    time_code: Option<String>,
+    closed: Option<bool>,
+    broken_link: Option<bool>,
+    // picture types in the coded order.
+    pictures: String,
+    frames: usize,
+    // bytes of the elementary stream.
+    bytes: usize,
+}
+
+// splits pictures into GOPs at GOP headers, or at I pictures if the stream
+// has no GOP headers.
+#[derive(Default)]
+struct Splitter {
+    current: Option<Gop>,
+    has_gop_headers: bool,
+}
+
+impl Splitter {
+    // returns the finished GOP if a new one starts.
+    fn start(
+        &mut self,
+        pts: Option<u64>,
+        header: Option<&h262::GroupOfPicturesHeader>,
+    ) -> Option<Gop> {
+        let gop = Gop {
+            pts,
+            time_code: header.map(|h| h.time_code_string()),
+            closed: header.map(|h| h.closed_gop),
+            broken_link: header.map(|h| h.broken_link),
+            ..Default::default()
+        };
+        self.current.replace(gop)
+    }
+
+    // splits the ES of a PES packet into GOPs.
+    fn feed(&mut self, pts: Option<u64>, data: &[u8]) -> Vec<Gop> {
+        let mut done = Vec::new();
+        // start of the bytes not counted yet, and of the sequence header
+        // which leads the next GOP.
+        let mut mark = 0;
+        let mut sequence_start = None;
+        for offset in h262::start_codes(data) {
+            let code = &data[offset..];
+            if code.starts_with(h262::SEQUENCE_HEADER_CODE) {
+                sequence_start = Some(offset);
+            } else if code.starts_with(h262::GROUP_START_CODE) {
+                let header = match h262::GroupOfPicturesHeader::parse(code) {
+                    Ok(header) => header,
+                    Err(e) => {
+                        info!("gop header parse error: {:?}", e);
+                        continue;
+                    }
+                };
+                self.has_gop_headers = true;
+                let start = sequence_start.take().unwrap_or(offset);
+                if let Some(gop) = self.current.as_mut() {
+                    gop.bytes += start - mark;
+                }
+                mark = start;
+                done.extend(self.start(pts, Some(&header)));
+            } else if code.starts_with(h262::PICTURE_START_CODE) {
+                let header = match h262::PictureHeader::parse(code) {
+                    Ok(header) => header,
+                    Err(e) => {
+                        info!("picture header parse error: {:?}", e);
+                        continue;
+                    }
+                };
+                if !self.has_gop_headers && header.picture_coding_type == h262::I_PICTURE {
+                    let start = sequence_start.take().unwrap_or(offset);
+                    if let Some(gop) = self.current.as_mut() {
+                        gop.bytes += start - mark;
+                    }
+                    mark = start;
+                    done.extend(self.start(pts, None));
+                }
+                sequence_start = None;
+                // pictures before the first GOP are skipped.
+                if let Some(gop) = self.current.as_mut() {
+                    gop.pictures.push(header.picture_type_char());
+                    gop.frames += 1;
+                }
+            }
+        }
+        if let Some(gop) = self.current.as_mut() {
+            gop.bytes += data.len() - mark;
+        }
+        done
+    }
+}
+
+/// Prints each GOP of the main video of a service, with the picture types
+/// and the size, for MPEG-2 video.
+pub async fn run(input: Option<PathBuf>, service_id: Option<u16>) -> Result<()> {
+    let packets = path_to_packets(input, false).await?;
+    let packets = common::interrupt_on_ctrl_c(common::strip_error_packets(packets));
+    let mut cueable_packets = cueable(packets);
+    let meta = common::find_main_meta(
+        &mut cueable_packets,
+        service_id,
+        &[common::StreamKind::Video],
+    )
+    .await?;
+    if meta.video_stream_type() != psi::STREAM_TYPE_VIDEO {
+        bail!(
+            "gop supports only MPEG-2 video, not stream type {:#04x}",
+            meta.video_stream_type()
+        );
+    }
+    let video_pid = meta.video_pid();
+    let packets = cueable_packets.cue_up();
+    let mut buffer = pes::Buffer::new(packets.filter(move |packet| packet.pid == video_pid));
+    let mut splitter = Splitter::default();
+    while let Some(bytes) = buffer.next().await {
+        let bytes = match bytes {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                info!("pes buffer error: {:?}", e);
+                continue;
+            }
+        };
+        let pes = match pes::PESPacket::parse(&bytes[..]) {
+            Ok(pes) => pes,
+            Err(e) => {
+                info!("pes parse error: {:?}", e);
+                errors::count(errors::Kind::Pes);
+                continue;
+            }
+        };
+        if let pes::PESPacketBody::NormalPESPacketBody(ref body) = pes.body {
+            for gop in splitter.feed(body.pts, body.pes_packet_data_byte) {
+                println!("{}", serde_json::to_string(&gop)?);
+            }
+        }
+    }
+    if let Some(gop) = splitter.current.take() {
+        println!("{}", serde_json::to_string(&gop)?);
+    }
+    Ok(())
+}
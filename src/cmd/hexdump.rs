@@ -0,0 +1,113 @@
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use tokio_stream::StreamExt;
+
+use super::common;
+use super::io::path_to_packets;
+use tstools::ts;
+
+const BYTES_PER_LINE: usize = 16;
+
+fn flag(raw: &[u8], bit: u8) -> u8 {
+    u8::from(raw.get(1).is_some_and(|b| b & bit > 0))
+}
+
+fn header_line(index: u64, offset: u64, packet: &ts::TSPacket) -> String {
+    format!(
+        "packet {} offset {} pid {:#06x} tei {} pusi {} priority {} scrambling {} afc {:02b} cc {}",
+        index,
+        offset,
+        packet.pid,
+        u8::from(packet.transport_error_indicator),
+        u8::from(packet.payload_unit_start_indicator),
+        u8::from(packet.transport_priority),
+        packet.transport_scrambling_control,
+        packet.adaptation_field_control,
+        packet.continuity_counter,
+    )
+}
+
+fn adaptation_field_line(af: &ts::AdaptationField) -> String {
+    // raw starts with adaptation_field_length, and the flags follow if any.
+    let raw = &af.raw[..];
+    let mut line = format!(
+        "  af length {} discontinuity {} random_access {} es_priority {} pcr_flag {} opcr_flag {} splicing_point {} private_data {} extension {}",
+        raw[0],
+        flag(raw, 0x80),
+        flag(raw, 0x40),
+        flag(raw, 0x20),
+        flag(raw, 0x10),
+        flag(raw, 0x08),
+        flag(raw, 0x04),
+        flag(raw, 0x02),
+        flag(raw, 0x01),
+    );
+    if let Some(pcr) = af.pcr() {
+        let _ = write!(
+            line,
+            " pcr {} ({:.6}s)",
+            pcr,
+            pcr as f64 / ts::PCR_HZ as f64
+        );
+    }
+    line
+}
+
+// dumps bytes like xxd, with offsets in the packet.
+fn hex_lines(start: usize, bytes: &[u8]) -> Vec<String> {
+    bytes
+        .chunks(BYTES_PER_LINE)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let mut line = format!("  {:04x}:", start + i * BYTES_PER_LINE);
+            for b in chunk.iter() {
+                let _ = write!(line, " {:02x}", b);
+            }
+            for _ in chunk.len()..BYTES_PER_LINE {
+                line.push_str("   ");
+            }
+            line.push_str("  |");
+            line.extend(chunk.iter().map(|b| match b {
+                0x20..=0x7e => char::from(*b),
+                _ => '.',
+            }));
+            line.push('|');
+            line
+        })
+        .collect()
+}
+
+/// Prints packets of the PIDs, or all packets if none, with the header
+/// fields decoded and the payload in hex.
+pub async fn run(input: Option<PathBuf>, pids: Vec<u16>, count: Option<usize>) -> Result<()> {
+    let packets = path_to_packets(input, false).await?;
+    let mut packets = common::strip_error_packets(packets);
+    let mut index = 0u64;
+    let mut printed = 0usize;
+    while let Some(packet) = packets.next().await {
+        if count.is_some_and(|count| printed >= count) {
+            break;
+        }
+        let i = index;
+        index += 1;
+        if !pids.is_empty() && !pids.contains(&packet.pid) {
+            continue;
+        }
+        printed += 1;
+        let offset = i * ts::TS_PACKET_LENGTH as u64;
+        println!("{}", header_line(i, offset, &packet));
+        let mut payload_start = 4;
+        if let Some(af) = packet.adaptation_field.as_ref() {
+            println!("{}", adaptation_field_line(af));
+            payload_start += af.raw.len();
+        }
+        if let Some(data) = packet.data.as_ref() {
+            for line in hex_lines(payload_start, &data[..]) {
+                println!("{}", line);
+            }
+        }
+    }
+    Ok(())
+}
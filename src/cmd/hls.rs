@@ -0,0 +1,200 @@
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use bytes::{Bytes, BytesMut};
+use log::{info, warn};
+use tokio::fs::File;
+use tokio::io::AsyncWriteExt;
+use tokio_stream::{Stream, StreamExt};
+
+use super::common;
+use super::errors;
+use super::io::path_to_packets;
+use tstools::pes;
+use tstools::stream::cueable;
+use tstools::ts;
+
+const PLAYLIST_NAME: &str = "index.m3u8";
+
+struct Segment {
+    name: String,
+    duration: f64,
+}
+
+struct Segmenter {
+    dir: PathBuf,
+    target_duration: f64,
+    segments: Vec<Segment>,
+    out: Option<File>,
+    start_pts: Option<u64>,
+    last_pts: Option<u64>,
+}
+
+impl Segmenter {
+    fn new(dir: &Path, target_duration: f64) -> Segmenter {
+        Segmenter {
+            dir: dir.to_path_buf(),
+            target_duration,
+            segments: Vec::new(),
+            out: None,
+            start_pts: None,
+            last_pts: None,
+        }
+    }
+
+    fn should_split(&self, pts: u64) -> bool {
+        match self.start_pts {
+            Some(start_pts) => common::pts_diff(pts, start_pts) >= self.target_duration,
+            None => true,
+        }
+    }
+
+    async fn close(&mut self, end_pts: u64) -> Result<()> {
+        if let (Some(mut out), Some(start_pts)) = (self.out.take(), self.start_pts) {
+            out.flush().await?;
+            let segment = self.segments.last_mut().unwrap();
+            segment.duration = common::pts_diff(end_pts, start_pts);
+        }
+        Ok(())
+    }
+
+    async fn open(&mut self, pts: u64, repeater: &common::PsiRepeater) -> Result<()> {
+        self.close(pts).await?;
+        let name = format!("segment{:05}.ts", self.segments.len());
+        info!("open {}", name);
+        let mut out = File::create(self.dir.join(&name)).await?;
+        // make each segment decodable on its own.
+        for packet in repeater.packets() {
+            out.write_all(&packet[..]).await?;
+        }
+        self.segments.push(Segment {
+            name,
+            duration: 0.0,
+        });
+        self.out = Some(out);
+        self.start_pts = Some(pts);
+        Ok(())
+    }
+
+    async fn write(&mut self, packets: &[Bytes]) -> Result<()> {
+        if let Some(out) = self.out.as_mut() {
+            for packet in packets.iter() {
+                out.write_all(&packet[..]).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn finish(mut self, frame_duration: f64) -> Result<()> {
+        if let Some(last_pts) = self.last_pts {
+            self.close(last_pts).await?;
+            if let Some(segment) = self.segments.last_mut() {
+                segment.duration += frame_duration;
+            }
+        }
+        let target = self
+            .segments
+            .iter()
+            .map(|segment| segment.duration)
+            .fold(self.target_duration, f64::max)
+            .ceil();
+        let mut playlist = String::new();
+        writeln!(playlist, "#EXTM3U")?;
+        writeln!(playlist, "#EXT-X-VERSION:3")?;
+        writeln!(playlist, "#EXT-X-TARGETDURATION:{}", target)?;
+        writeln!(playlist, "#EXT-X-MEDIA-SEQUENCE:0")?;
+        for segment in self.segments.iter() {
+            writeln!(playlist, "#EXTINF:{:.3},", segment.duration)?;
+            writeln!(playlist, "{}", segment.name)?;
+        }
+        writeln!(playlist, "#EXT-X-ENDLIST")?;
+        tokio::fs::write(self.dir.join(PLAYLIST_NAME), playlist).await?;
+        Ok(())
+    }
+}
+
+// holds packets from a video PES start until the next one, so that the
+// segment of them is decided after seeing whether the PES is a key picture.
+async fn segment<S: Stream<Item = ts::TSPacket> + Unpin>(
+    meta: &common::Meta,
+    mut s: S,
+    mut segmenter: Segmenter,
+) -> Result<()> {
+    let mut repeater = common::PsiRepeater::new(&[ts::PAT_PID, meta.pmt_pid]);
+    let mut held: Vec<Bytes> = Vec::new();
+    let mut pes_bytes = BytesMut::new();
+    let mut pes_pts = None;
+    let mut frame_duration = 0.0;
+    let mut finder = common::KeyPictureFinder::new(meta.video_stream_type());
+
+    while let Some(packet) = s.next().await {
+        repeater.update(&packet);
+        if packet.pid == meta.video_pid() && packet.payload_unit_start_indicator {
+            if let Some(pts) = pes_pts.take() {
+                let key = match pes::PESPacket::parse(&pes_bytes[..]) {
+                    Ok(pes) => match pes.body {
+                        pes::PESPacketBody::NormalPESPacketBody(ref body) => {
+                            finder.feed(body.pes_packet_data_byte)
+                        }
+                        _ => false,
+                    },
+                    Err(e) => {
+                        warn!("pes parse error: {:?}", e);
+                        errors::count(errors::Kind::Pes);
+                        false
+                    }
+                };
+                if key && segmenter.should_split(pts) {
+                    segmenter.open(pts, &repeater).await?;
+                }
+                // pictures are in decoding order, keep the latest presentation.
+                match segmenter.last_pts {
+                    Some(last_pts) => {
+                        let d = common::pts_diff(pts, last_pts);
+                        if d > 0.0 {
+                            if frame_duration == 0.0 || d < frame_duration {
+                                frame_duration = d;
+                            }
+                            segmenter.last_pts = Some(pts);
+                        }
+                    }
+                    None => segmenter.last_pts = Some(pts),
+                }
+            }
+            segmenter.write(&held).await?;
+            held.clear();
+            pes_bytes.clear();
+            pes_pts = common::packet_timestamps(&packet).and_then(|t| t.pts);
+        }
+        if pes_pts.is_none() {
+            segmenter.write(&[packet.into_raw()]).await?;
+            continue;
+        }
+        if packet.pid == meta.video_pid() {
+            if let Some(data) = packet.data.as_ref() {
+                pes_bytes.extend_from_slice(&data[..]);
+            }
+        }
+        held.push(packet.into_raw());
+    }
+    if let (Some(pts), Some(last_pts)) = (pes_pts, segmenter.last_pts) {
+        if common::pts_diff(pts, last_pts) > 0.0 {
+            segmenter.last_pts = Some(pts);
+        }
+    }
+    segmenter.write(&held).await?;
+    segmenter.finish(frame_duration).await
+}
+
+pub async fn run(input: Option<PathBuf>, output_dir: PathBuf, duration: f64) -> Result<()> {
+    tokio::fs::create_dir_all(&output_dir).await?;
+    let packets = path_to_packets(input, false).await?;
+    let packets = common::strip_error_packets(packets);
+    let mut cueable_packets = cueable(packets);
+    let meta =
+        common::find_main_meta(&mut cueable_packets, None, &[common::StreamKind::Video]).await?;
+    let packets = cueable_packets.cue_up();
+    let segmenter = Segmenter::new(&output_dir, duration);
+    segment(&meta, packets, segmenter).await
+}
@@ -0,0 +1,196 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Result};
+use bytes::BytesMut;
+use clap::{Args, ValueEnum};
+use log::info;
+use serde_derive::{Deserialize, Serialize};
+use tokio_stream::StreamExt;
+
+use super::common;
+use super::io::{self, path_to_packets, RecordWriter};
+use tstools::stream::cueable;
+use tstools::ts;
+
+// headers of a picture come first in a PES packet, so only this many bytes
+// of each are kept to find key pictures.
+const PICTURE_HEADER_SEARCH: usize = 64 * 1024;
+const CSV_COLUMNS: [&str; 3] = ["offset", "pts", "pcr"];
+
+#[derive(ValueEnum, Clone, Copy)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+/// A key picture, I-picture of MPEG-2 or IDR picture of H.264, in the
+/// input.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct Entry {
+    /// Byte offset of the TS packet starting the PES packet.
+    pub offset: u64,
+    pub pts: u64,
+    /// The latest PCR of the service before the offset, in 27 MHz.
+    pub pcr: Option<u64>,
+}
+
+impl Entry {
+    fn csv(&self) -> String {
+        format!(
+            "{},{},{}",
+            self.offset,
+            self.pts,
+            self.pcr.map(|pcr| pcr.to_string()).unwrap_or_default()
+        )
+    }
+
+    fn parse_csv(line: &str) -> Result<Entry> {
+        let fields: Vec<&str> = line.split(',').collect();
+        match fields[..] {
+            [offset, pts, pcr] => Ok(Entry {
+                offset: offset.parse()?,
+                pts: pts.parse()?,
+                pcr: (!pcr.is_empty()).then(|| pcr.parse()).transpose()?,
+            }),
+            _ => bail!("index entry must be offset,pts,pcr: {:?}", line),
+        }
+    }
+}
+
+/// Reads an index printed by the index command, in either format.
+pub fn read_index(path: &Path) -> Result<Vec<Entry>> {
+    let text = std::fs::read_to_string(path)?;
+    let mut entries = Vec::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() || line == CSV_COLUMNS.join(",") {
+            continue;
+        }
+        let entry = if line.starts_with('{') {
+            serde_json::from_str(line)?
+        } else {
+            Entry::parse_csv(line)?
+        };
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+/// Options to start reading an input at a key picture found in its index.
+#[derive(Args)]
+pub struct SeekOptions {
+    /// Index of the input printed by the index command.
+    #[arg(long = "index", requires = "start")]
+    index: Option<PathBuf>,
+    /// Start reading at the last key picture at or before this many seconds
+    /// from the first key picture, seeking the input by the index.
+    #[arg(long = "start", requires = "index")]
+    start: Option<f64>,
+}
+
+impl SeekOptions {
+    /// Returns the byte offset to start reading at, or None to read the
+    /// input from the head.
+    pub fn offset(&self) -> Result<Option<u64>> {
+        let (path, start) = match (self.index.as_deref(), self.start) {
+            (Some(path), Some(start)) => (path, start),
+            _ => return Ok(None),
+        };
+        let entries = read_index(path)?;
+        let first = entries
+            .first()
+            .ok_or_else(|| anyhow!("no entry in {:?}", path))?;
+        let entry = entries
+            .iter()
+            .take_while(|e| common::pts_diff(e.pts, first.pts) <= start)
+            .last()
+            .unwrap_or(first);
+        info!("seek to {:?}", entry);
+        Ok(Some(entry.offset))
+    }
+}
+
+// a video PES packet being read.
+struct Pending {
+    entry: Entry,
+    bytes: BytesMut,
+}
+
+impl Pending {
+    fn key_picture(&self, stream_type: u8) -> Option<Entry> {
+        // the payload follows the PES header of PES_header_data_length.
+        let header_length = 9 + usize::from(*self.bytes.get(8)?);
+        let data = self.bytes.get(header_length..)?;
+        common::is_key_picture(stream_type, data).then_some(self.entry)
+    }
+}
+
+fn print_entry(entry: &Entry, format: Format, out: &mut RecordWriter) -> Result<()> {
+    let line = match format {
+        Format::Json => serde_json::to_string(entry)?,
+        Format::Csv => entry.csv(),
+    };
+    out.write_line(&line)?;
+    out.end_batch()
+}
+
+/// Prints the offset, PTS and PCR of each key picture of the main video of
+/// a service, for seeking in the input.
+pub async fn run(input: Option<PathBuf>, service_id: Option<u16>, format: Format) -> Result<()> {
+    let mut out = RecordWriter::stdout(io::is_live(input.as_slice()));
+    let packets = path_to_packets(input, false).await?;
+    let packets = common::interrupt_on_ctrl_c(common::strip_error_packets(packets));
+    let mut cueable_packets = cueable(packets);
+    let meta = common::find_main_meta(
+        &mut cueable_packets,
+        service_id,
+        &[common::StreamKind::Video],
+    )
+    .await?;
+    let video_pid = meta.video_pid();
+    let stream_type = meta.video_stream_type();
+    let pcr_pid = meta.pcr_pid;
+    let mut packets = cueable_packets.cue_up();
+
+    if let Format::Csv = format {
+        out.write_line(&CSV_COLUMNS.join(","))?;
+    }
+    let mut last_pcr = None;
+    let mut pending: Option<Pending> = None;
+    let mut offset = 0u64;
+    while let Some(packet) = packets.next().await {
+        let packet_offset = offset;
+        offset += ts::TS_PACKET_LENGTH as u64;
+        if Some(packet.pid) == pcr_pid {
+            if let Some(pcr) = packet.adaptation_field.as_ref().and_then(|af| af.pcr()) {
+                last_pcr = Some(pcr);
+            }
+        }
+        if packet.pid != video_pid {
+            continue;
+        }
+        if packet.payload_unit_start_indicator {
+            if let Some(entry) = pending.take().and_then(|p| p.key_picture(stream_type)) {
+                print_entry(&entry, format, &mut out)?;
+            }
+            pending = common::packet_timestamps(&packet)
+                .and_then(|t| t.pts)
+                .map(|pts| Pending {
+                    entry: Entry {
+                        offset: packet_offset,
+                        pts,
+                        pcr: last_pcr,
+                    },
+                    bytes: BytesMut::new(),
+                });
+        }
+        if let (Some(p), Some(data)) = (pending.as_mut(), packet.data.as_ref()) {
+            let room = PICTURE_HEADER_SEARCH.saturating_sub(p.bytes.len());
+            p.bytes.extend_from_slice(&data[..data.len().min(room)]);
+        }
+    }
+    if let Some(entry) = pending.and_then(|p| p.key_picture(stream_type)) {
+        print_entry(&entry, format, &mut out)?;
+    }
+    out.flush()
+}
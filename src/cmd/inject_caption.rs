@@ -0,0 +1,293 @@
+use std::collections::{HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use bytes::{BufMut, Bytes, BytesMut};
+use log::{info, warn};
+use tokio::io::AsyncWriteExt;
+use tokio_stream::StreamExt;
+
+use super::common;
+use super::io::{path_to_async_write, path_to_packets};
+use tstools::arib;
+use tstools::pes;
+use tstools::psi;
+use tstools::stream::cueable;
+use tstools::ts;
+
+const DEFAULT_CAPTION_PID: u16 = 0x130;
+const CAPTION_COMPONENT_TAG: u8 = 0x30;
+const STREAM_IDENTIFIER_DESCRIPTOR: u8 = 0x52;
+// data_identifier and private_stream_id of captions, and
+// PES_data_packet_header_length of 0.
+const CAPTION_PES_HEADER: [u8; 3] = [0x80, 0xff, 0xf0];
+const CS: u8 = 0x0c;
+// captions are sent ahead of their PTS by this, in seconds.
+const CAPTION_LEAD: f64 = 1.0;
+// management data is repeated for players starting midway.
+const MANAGEMENT_INTERVAL: f64 = 10.0;
+const OUTPUT_CHUNK: usize = 1 << 20;
+
+struct Cue {
+    start_ms: i64,
+    end_ms: i64,
+    text: String,
+}
+
+// parses "hh:mm:ss,mmm" of SRT and "[hh:]mm:ss.mmm" of WebVTT.
+fn parse_time(s: &str) -> Option<i64> {
+    let (hms, ms) = s.trim().split_once([',', '.'])?;
+    let mut seconds = 0i64;
+    for part in hms.split(':') {
+        seconds = seconds * 60 + part.parse::<i64>().ok()?;
+    }
+    Some(seconds * 1000 + ms.parse::<i64>().ok()?)
+}
+
+// removes markup such as <i> and {\an8}.
+fn strip_tags(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut close = None;
+    for c in s.chars() {
+        match (close, c) {
+            (None, '<') => close = Some('>'),
+            (None, '{') => close = Some('}'),
+            (None, c) => out.push(c),
+            (Some(end), c) if c == end => close = None,
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Parses cues of SRT or WebVTT, which differ in the header and the
+/// separator of milliseconds.
+fn parse_subtitles(s: &str) -> Vec<Cue> {
+    let mut cues = Vec::new();
+    let s = s.trim_start_matches('\u{feff}').replace("\r\n", "\n");
+    for block in s.split("\n\n") {
+        let mut lines = block.lines().skip_while(|l| !l.contains("-->"));
+        let timing = match lines.next() {
+            Some(timing) => timing,
+            None => continue,
+        };
+        let (start, end) = match timing.split_once("-->") {
+            Some((start, end)) => (start, end),
+            None => continue,
+        };
+        // WebVTT has cue settings after the end time.
+        let end = end.split_whitespace().next().unwrap_or_default();
+        let (start_ms, end_ms) = match (parse_time(start), parse_time(end)) {
+            (Some(start), Some(end)) => (start, end),
+            _ => {
+                warn!("invalid cue timing: {}", timing);
+                continue;
+            }
+        };
+        let text: Vec<String> = lines.map(strip_tags).collect();
+        cues.push(Cue {
+            start_ms,
+            end_ms,
+            text: text.join("\n"),
+        });
+    }
+    cues.sort_by_key(|c| c.start_ms);
+    cues
+}
+
+// the PTS of each statement, which clears the screen and shows the text if
+// any.
+fn schedule_statements(cues: &[Cue], base_pts: u64, offset_ms: i64) -> VecDeque<(u64, String)> {
+    let to_pts = |ms: i64| {
+        let ms = ms + offset_ms;
+        (ms >= 0).then(|| {
+            pes::PtsTime::new(base_pts)
+                .add_ticks(ms * (pes::PTS_HZ / 1000) as i64)
+                .value()
+        })
+    };
+    let mut statements: Vec<(i64, String)> = Vec::new();
+    for (i, cue) in cues.iter().enumerate() {
+        statements.push((cue.start_ms, cue.text.clone()));
+        // the next cue clears the screen by itself.
+        if cues
+            .get(i + 1)
+            .is_none_or(|next| next.start_ms > cue.end_ms)
+        {
+            statements.push((cue.end_ms, String::new()));
+        }
+    }
+    statements
+        .into_iter()
+        .filter_map(|(ms, text)| match to_pts(ms) {
+            Some(pts) => Some((pts, text)),
+            None => {
+                info!("skip a statement before the start: {:?}", text);
+                None
+            }
+        })
+        .collect()
+}
+
+fn caption_pes(data_group: &[u8], pts: u64) -> Result<Bytes> {
+    let mut payload = BytesMut::with_capacity(CAPTION_PES_HEADER.len() + data_group.len());
+    payload.put_slice(&CAPTION_PES_HEADER);
+    payload.put_slice(data_group);
+    let pes = pes::PESPacketBuilder::new(arib::pes::SYNCHRONIZED_PES_STREAM_ID, payload.freeze())
+        .data_alignment_indicator(true)
+        .pts(pts)
+        .build()?;
+    Ok(pes)
+}
+
+// adds the caption ES to the PMT of the service.
+fn add_caption_stream(section: &[u8], caption_pid: u16) -> Result<Bytes> {
+    let pmt = psi::TSProgramMapSection::parse(section)?;
+    let descriptor = [STREAM_IDENTIFIER_DESCRIPTOR, 1, CAPTION_COMPONENT_TAG];
+    let mut streams: Vec<psi::PmtStream> = pmt
+        .stream_info
+        .iter()
+        .map(|si| psi::PmtStream {
+            stream_type: si.stream_type,
+            elementary_pid: si.elementary_pid,
+            descriptors: si.descriptors.as_bytes(),
+        })
+        .collect();
+    streams.push(psi::PmtStream {
+        stream_type: psi::STREAM_TYPE_PES_PRIVATE_DATA,
+        elementary_pid: caption_pid,
+        descriptors: &descriptor,
+    });
+    let section = psi::SectionBuilder::pmt(
+        pmt.program_number,
+        pmt.pcr_pid,
+        pmt.descriptors.as_bytes(),
+        &streams,
+    )
+    .version_number(pmt.version_number)
+    .current_next_indicator(pmt.current_next_indicator)
+    .section_number(pmt.section_number)
+    .last_section_number(pmt.last_section_number)
+    .build()?;
+    Ok(section)
+}
+
+async fn read_cues(path: &Path) -> Result<Vec<Cue>> {
+    let s = tokio::fs::read_to_string(path)
+        .await
+        .with_context(|| format!("failed to read {:?}", path))?;
+    let cues = parse_subtitles(&s);
+    if cues.is_empty() {
+        bail!("no cues found in {:?}", path);
+    }
+    Ok(cues)
+}
+
+pub async fn run(
+    subtitles: PathBuf,
+    input: Option<PathBuf>,
+    output: Option<PathBuf>,
+    pid: Option<u16>,
+    language: String,
+    service_id: Option<u16>,
+    offset_ms: i64,
+) -> Result<()> {
+    let language: [u8; 3] = match language.as_bytes().try_into() {
+        Ok(language) => language,
+        Err(_) => bail!("language must be an ISO 639 code of 3 letters"),
+    };
+    let cues = read_cues(&subtitles).await?;
+    let mut output = path_to_async_write(output).await?;
+    let packets = path_to_packets(input, false).await?;
+    let packets = common::strip_error_packets(packets);
+    let mut cueable_packets = cueable(packets);
+    let programs = common::find_programs(&mut cueable_packets).await?;
+    let packets = cueable_packets.cue_up();
+    let mut cueable_packets = cueable(packets);
+    let meta = common::find_main_meta(&mut cueable_packets, service_id, &[]).await?;
+    let packets = cueable_packets.cue_up();
+    if let Some(caption_pid) = meta.caption {
+        bail!("the service has captions on pid {:#x} already", caption_pid);
+    }
+    let pcr_pid = match meta.pcr_pid {
+        Some(pid) => pid,
+        None => bail!("the service has no pcr to time captions"),
+    };
+    let caption_pid = pid.unwrap_or(DEFAULT_CAPTION_PID);
+    let used: HashSet<u16> = programs
+        .iter()
+        .flat_map(|p| std::iter::once(p.pmt_pid).chain(p.streams.iter().map(|es| es.pid)))
+        .collect();
+    if used.contains(&caption_pid) {
+        bail!("pid {:#x} is used, give another one by --pid", caption_pid);
+    }
+
+    // times in the subtitles are from the first key picture, as caption
+    // prints, or the first PCR without video.
+    let mut cueable_packets = cueable(packets);
+    let base_pts = match meta.video {
+        Some((pid, stream_type)) => {
+            common::find_first_picture_pts(pid, stream_type, &mut cueable_packets).await?
+        }
+        None => {
+            common::find_first_pcr(pcr_pid, &mut cueable_packets).await?
+                / (ts::PCR_HZ / pes::PTS_HZ)
+        }
+    };
+    let mut packets = cueable_packets.cue_up();
+    let mut statements = schedule_statements(&cues, base_pts, offset_ms);
+
+    let mut chunk = BytesMut::with_capacity(OUTPUT_CHUNK);
+    let mut pmt_rewriter = common::PmtRewriter::new(HashSet::from([meta.service_id]));
+    let mut caption_packetizer = pes::PESPacketizer::new(caption_pid, 0);
+    let mut last_management: Option<u64> = None;
+    while let Some(packet) = packets.next().await {
+        if packet.pid == meta.pmt_pid {
+            pmt_rewriter.feed(
+                packet,
+                |section| add_caption_stream(section, caption_pid),
+                &mut chunk,
+            )?;
+            continue;
+        }
+        let pcr = match packet.adaptation_field {
+            Some(ref af) if packet.pid == pcr_pid => af.pcr(),
+            _ => None,
+        };
+        chunk.extend_from_slice(&packet.into_raw()[..]);
+        if let Some(pcr) = pcr {
+            let now = pcr / (ts::PCR_HZ / pes::PTS_HZ);
+            if last_management.is_none_or(|t| common::pts_diff(now, t) >= MANAGEMENT_INTERVAL) {
+                let data_group = arib::caption::DataGroupBuilder::management(&language).build()?;
+                for packet in caption_packetizer.packetize(&caption_pes(&data_group, now)?)? {
+                    chunk.extend_from_slice(&packet.into_raw()[..]);
+                }
+                last_management = Some(now);
+            }
+            while let Some((pts, text)) = statements.front() {
+                if common::pts_diff(*pts, now) > CAPTION_LEAD {
+                    break;
+                }
+                let mut data = vec![CS];
+                data.extend(arib::string::encode_caption(text));
+                let data_group = arib::caption::DataGroupBuilder::statement(&data)?.build()?;
+                for packet in caption_packetizer.packetize(&caption_pes(&data_group, *pts)?)? {
+                    chunk.extend_from_slice(&packet.into_raw()[..]);
+                }
+                statements.pop_front();
+            }
+        }
+        if chunk.len() + ts::TS_PACKET_LENGTH > OUTPUT_CHUNK {
+            output.write_all(&chunk.split()[..]).await?;
+        }
+    }
+    output.write_all(&chunk[..]).await?;
+    output.flush().await?;
+    if !statements.is_empty() {
+        warn!(
+            "{} statements after the end of the input are dropped",
+            statements.len()
+        );
+    }
+    Ok(())
+}
@@ -1,9 +1,185 @@
-use std::os::unix::io::{AsRawFd, FromRawFd};
-use std::path::PathBuf;
+use std::io;
+use std::io::{SeekFrom, Write};
+use std::os::unix::io::{AsFd, AsRawFd, FromRawFd};
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::OnceLock;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
 
-use anyhow::Result;
+use anyhow::{anyhow, bail, Result};
+use bytes::{Bytes, BytesMut};
+use log::{info, warn};
 use tokio::fs::{File, OpenOptions};
-use tokio::io::{stdin, stdout};
+use tokio::io::{stdin, stdout, AsyncRead, AsyncReadExt, AsyncSeekExt, ReadBuf};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_stream::{Stream, StreamExt};
+use tokio_util::codec::{Decoder, FramedRead};
+
+use super::common;
+use super::errors;
+use tstools::pes;
+use tstools::ts;
+
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(1);
+// an input of `tcp-listen://<addr>:<port>` is the TS pushed by a sender.
+const TCP_LISTEN_SCHEME: &str = "tcp-listen://";
+const OUTPUT_BUFFER: usize = 64 * 1024;
+// bytes searched for a PCR from an offset, to find the offset of a time.
+const PCR_SEARCH_BYTES: usize = 4 * 1024 * 1024;
+// the search of a time stops once the range is this narrow.
+const SEEK_PRECISION: usize = 256 * 1024;
+
+// where and how much of each input is read, set once from the command line.
+static START: OnceLock<Start> = OnceLock::new();
+static LIMITS: OnceLock<Limits> = OnceLock::new();
+
+/// Where each input starts to be read.
+#[derive(Clone, Copy)]
+pub enum Start {
+    /// At a byte offset, resynchronizing on the sync byte after it.
+    Byte(u64),
+    /// Around this many seconds from the first PCR.
+    Time(f64),
+}
+
+/// Sets where every input opened afterwards starts to be read.
+pub fn set_start(start: Start) -> Result<()> {
+    if let Start::Time(seconds) = start {
+        if seconds < 0.0 {
+            bail!("start time must not be negative");
+        }
+    }
+    if START.set(start).is_err() {
+        bail!("start is already set");
+    }
+    Ok(())
+}
+
+/// Limits on how much of each input is read.
+pub struct Limits {
+    pub packets: Option<u64>,
+    /// In seconds of the stream.
+    pub duration: Option<f64>,
+}
+
+/// Sets the limits applied to every input opened afterwards.
+pub fn set_limits(limits: Limits) -> Result<()> {
+    if limits.packets.is_none() && limits.duration.is_none() {
+        return Ok(());
+    }
+    if limits.duration.is_some_and(|d| d <= 0.0) {
+        bail!("duration must be positive");
+    }
+    if LIMITS.set(limits).is_err() {
+        bail!("limits are already set");
+    }
+    Ok(())
+}
+
+// ends an input at the limits. The duration is measured by the PCR of the
+// first PID carrying one, or by the PTS if a PES header comes first.
+struct Limiter {
+    packets: u64,
+    // the PID which times the stream, whether it is by PCR, and the first
+    // time in 90kHz.
+    clock: Option<(u16, bool, pes::PtsTime)>,
+}
+
+impl Limiter {
+    fn admit(&mut self, limits: &Limits, packet: &ts::Result<ts::TSPacket>) -> bool {
+        if limits.packets.is_some_and(|max| self.packets >= max) {
+            info!("stop after {} packets", self.packets);
+            return false;
+        }
+        self.packets += 1;
+        let (max, packet) = match (limits.duration, packet) {
+            (Some(max), Ok(packet)) => (max, packet),
+            _ => return true,
+        };
+        let pcr_time = || {
+            let pcr = packet.adaptation_field.as_ref().and_then(|af| af.pcr());
+            pcr.map(|pcr| pcr / (ts::PCR_HZ / pes::PTS_HZ))
+        };
+        let pts_time = || common::packet_timestamps(packet).and_then(|t| t.pts);
+        let (pid, by_pcr, first) = match self.clock {
+            Some(clock) => clock,
+            None => {
+                if let Some(time) = pcr_time() {
+                    self.clock = Some((packet.pid, true, pes::PtsTime::new(time)));
+                } else if let Some(time) = pts_time() {
+                    self.clock = Some((packet.pid, false, pes::PtsTime::new(time)));
+                }
+                return true;
+            }
+        };
+        if pid != packet.pid {
+            return true;
+        }
+        let time = if by_pcr { pcr_time() } else { pts_time() };
+        match time {
+            Some(time) if pes::PtsTime::new(time).seconds_since(first) >= max => {
+                info!("stop after {} seconds", max);
+                false
+            }
+            _ => true,
+        }
+    }
+}
+
+// returns the first PCR at or after the offset, of the PID if given.
+fn find_pcr_after(bytes: &Bytes, offset: usize, pid: Option<u16>) -> Option<(u16, u64)> {
+    let mut bytes = bytes.slice(offset.min(bytes.len())..);
+    let end = bytes.len().saturating_sub(PCR_SEARCH_BYTES);
+    let mut decoder = ts::TSPacketDecoder::new();
+    while bytes.len() > end {
+        let packet = match decoder.decode_bytes(&mut bytes)? {
+            Ok(packet) => packet,
+            Err(_) => continue,
+        };
+        if pid.is_some_and(|pid| pid != packet.pid) {
+            continue;
+        }
+        if let Some(pcr) = packet.adaptation_field.as_ref().and_then(|af| af.pcr()) {
+            return Some((packet.pid, pcr));
+        }
+    }
+    None
+}
+
+// finds the offset of a time from the first PCR by bisection, assuming the
+// PCR goes up through the file.
+fn find_offset_at(p: &Path, seconds: f64) -> Result<u64> {
+    let bytes = map_file(p)?.ok_or_else(|| anyhow!("{:?} is not a regular file to seek", p))?;
+    let to_pts = |pcr: u64| pes::PtsTime::new(pcr / (ts::PCR_HZ / pes::PTS_HZ));
+    let (pid, first) =
+        find_pcr_after(&bytes, 0, None).ok_or_else(|| anyhow!("no pcr found in {:?}", p))?;
+    let (mut low, mut high) = (0, bytes.len());
+    while high - low > SEEK_PRECISION {
+        let middle = low + (high - low) / 2;
+        let later = find_pcr_after(&bytes, middle, Some(pid))
+            .is_none_or(|(_, pcr)| to_pts(pcr).seconds_since(to_pts(first)) >= seconds);
+        if later {
+            high = middle;
+        } else {
+            low = middle;
+        }
+    }
+    Ok(low as u64)
+}
+
+// applies the limits, if any, to an input.
+fn limit(packets: PacketStream) -> PacketStream {
+    let limits = match LIMITS.get() {
+        Some(limits) => limits,
+        None => return packets,
+    };
+    let mut limiter = Limiter {
+        packets: 0,
+        clock: None,
+    };
+    Box::pin(packets.take_while(move |packet| limiter.admit(limits, packet)))
+}
 
 pub async fn path_to_async_read(p: Option<PathBuf>) -> Result<File> {
     match p {
@@ -24,9 +200,455 @@ pub async fn path_to_async_write(p: Option<PathBuf>) -> Result<File> {
             if p.to_str() == Some("-") {
                 unsafe { Ok(File::from_raw_fd(stdout().as_raw_fd())) }
             } else {
-                Ok(OpenOptions::new().write(true).create(true).open(p).await?)
+                Ok(OpenOptions::new()
+                    .write(true)
+                    .create(true)
+                    .truncate(true)
+                    .open(p)
+                    .await?)
             }
         }
         None => unsafe { Ok(File::from_raw_fd(stdout().as_raw_fd())) },
     }
 }
+
+/// Writes records to stdout through a buffer, which takes the lock of
+/// stdout once per buffer instead of once per line. A live writer flushes
+/// at the end of each batch of records, so that they show up as soon as
+/// they are found.
+pub struct RecordWriter {
+    out: io::BufWriter<io::Stdout>,
+    live: bool,
+}
+
+impl RecordWriter {
+    pub fn stdout(live: bool) -> RecordWriter {
+        RecordWriter {
+            out: io::BufWriter::with_capacity(OUTPUT_BUFFER, io::stdout()),
+            live,
+        }
+    }
+
+    pub fn write_line(&mut self, line: &str) -> Result<()> {
+        self.out.write_all(line.as_bytes())?;
+        self.out.write_all(b"\n")?;
+        Ok(())
+    }
+
+    pub fn end_batch(&mut self) -> Result<()> {
+        if self.live {
+            self.out.flush()?;
+        }
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<()> {
+        self.out.flush()?;
+        Ok(())
+    }
+}
+
+/// Returns whether the inputs are live, such as a pipe or a TCP sender,
+/// rather than regular files.
+pub fn is_live(paths: &[PathBuf]) -> bool {
+    let is_file = |metadata: io::Result<std::fs::Metadata>| metadata.is_ok_and(|m| m.is_file());
+    if paths.is_empty() {
+        return !is_file(stdin_metadata());
+    }
+    paths.iter().any(|p| match p.to_str() {
+        Some("-") => !is_file(stdin_metadata()),
+        Some(p) if p.starts_with(TCP_LISTEN_SCHEME) => true,
+        // wildcards match regular files.
+        _ => std::fs::metadata(p).is_ok_and(|m| !m.is_file()),
+    })
+}
+
+fn stdin_metadata() -> io::Result<std::fs::Metadata> {
+    let fd = io::stdin().as_fd().try_clone_to_owned()?;
+    std::fs::File::from(fd).metadata()
+}
+
+pub type PacketStream = Pin<Box<dyn Stream<Item = ts::Result<ts::TSPacket>> + Send>>;
+
+// a read-only private mapping of a whole file.
+struct Mmap {
+    ptr: *mut libc::c_void,
+    len: usize,
+}
+
+// the mapping is never written, and is unmapped only on drop.
+unsafe impl Send for Mmap {}
+unsafe impl Sync for Mmap {}
+
+impl AsRef<[u8]> for Mmap {
+    fn as_ref(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.ptr as *const u8, self.len) }
+    }
+}
+
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        unsafe {
+            libc::munmap(self.ptr, self.len);
+        }
+    }
+}
+
+// maps a regular file into memory, or returns None for the others.
+fn map_file(p: &Path) -> Result<Option<Bytes>> {
+    let file = std::fs::File::open(p)?;
+    let metadata = file.metadata()?;
+    if !metadata.is_file() || metadata.len() == 0 {
+        return Ok(None);
+    }
+    let len = usize::try_from(metadata.len())?;
+    let ptr = unsafe {
+        libc::mmap(
+            std::ptr::null_mut(),
+            len,
+            libc::PROT_READ,
+            libc::MAP_PRIVATE,
+            file.as_raw_fd(),
+            0,
+        )
+    };
+    if ptr == libc::MAP_FAILED {
+        warn!("mmap failed: {}", io::Error::last_os_error());
+        return Ok(None);
+    }
+    unsafe {
+        libc::madvise(ptr, len, libc::MADV_SEQUENTIAL);
+    }
+    Ok(Some(Bytes::from_owner(Mmap { ptr, len })))
+}
+
+// waits for a sender to connect, and reads from it until it disconnects.
+async fn accept_tcp(addr: &str) -> Result<TcpStream> {
+    let listener = TcpListener::bind(addr).await?;
+    info!("listen on {}", listener.local_addr()?);
+    let (stream, peer) = listener.accept().await?;
+    info!("accepted {}", peer);
+    Ok(stream)
+}
+
+// matches a file name against a pattern of `*` and `?`.
+fn wildcard_match(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', rest)) => (0..=name.len()).any(|i| wildcard_match(rest, &name[i..])),
+        Some(('?', rest)) => !name.is_empty() && wildcard_match(rest, &name[1..]),
+        Some((c, rest)) => name.first() == Some(c) && wildcard_match(rest, &name[1..]),
+    }
+}
+
+// expands wildcards in the file name of the path into the matching files in
+// lexical order, which is the order of chunks named by recorders.
+fn expand_glob(p: &Path) -> Result<Vec<PathBuf>> {
+    let name = match p.file_name().and_then(|n| n.to_str()) {
+        Some(name) if name.contains(['*', '?']) => name,
+        _ => return Ok(vec![p.to_path_buf()]),
+    };
+    let pattern: Vec<char> = name.chars().collect();
+    let dir = match p.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let mut paths = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_name = entry.file_name();
+        let matched = file_name
+            .to_str()
+            .is_some_and(|n| wildcard_match(&pattern, &n.chars().collect::<Vec<_>>()));
+        if matched {
+            paths.push(dir.join(file_name));
+        }
+    }
+    if paths.is_empty() {
+        bail!("no file matches {:?}", p);
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+// decodes packets, counting each resynchronization as a packet error.
+struct PacketDecoder {
+    inner: ts::TSPacketDecoder,
+    resyncs: usize,
+    // bytes before the first packet are skipped silently, as an offset into
+    // the input may land in a packet.
+    mid_packet: bool,
+}
+
+impl PacketDecoder {
+    fn new(mid_packet: bool) -> PacketDecoder {
+        PacketDecoder {
+            inner: ts::TSPacketDecoder::new(),
+            resyncs: 0,
+            mid_packet,
+        }
+    }
+
+    fn count_resyncs<T>(&mut self, decoded: &Option<T>) {
+        let resyncs = self.inner.resyncs();
+        if !self.mid_packet {
+            for _ in self.resyncs..resyncs {
+                errors::count(errors::Kind::Packet);
+            }
+        }
+        self.resyncs = resyncs;
+        self.mid_packet &= decoded.is_none();
+    }
+
+    fn decode_bytes(&mut self, src: &mut Bytes) -> Option<ts::Result<ts::TSPacket>> {
+        let packet = self.inner.decode_bytes(src);
+        self.count_resyncs(&packet);
+        packet
+    }
+}
+
+impl Decoder for PacketDecoder {
+    type Item = ts::TSPacket;
+    type Error = ts::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> ts::Result<Option<ts::TSPacket>> {
+        let packet = self.inner.decode(src)?;
+        self.count_resyncs(&packet);
+        Ok(packet)
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> ts::Result<Option<ts::TSPacket>> {
+        let packet = self.inner.decode_eof(src)?;
+        self.count_resyncs(&packet);
+        Ok(packet)
+    }
+}
+
+// frames packets of an input read through tokio, decrypting it first if a
+// b25 command is set.
+fn frame_packets<R: AsyncRead + Send + Unpin + 'static>(
+    input: R,
+    mid_packet: bool,
+) -> Result<PacketStream> {
+    #[cfg(feature = "b25")]
+    if super::b25::is_enabled() {
+        let output = super::b25::decrypt(input)?;
+        return Ok(Box::pin(FramedRead::new(
+            output,
+            PacketDecoder::new(mid_packet),
+        )));
+    }
+    Ok(Box::pin(FramedRead::new(
+        input,
+        PacketDecoder::new(mid_packet),
+    )))
+}
+
+/// Opens the inputs as one stream of packets. Paths may have wildcards in the
+/// file name, and several files are concatenated in order as bytes, so that
+/// a packet split across files is kept. No path means stdin.
+pub async fn paths_to_packets(paths: Vec<PathBuf>, progress: bool) -> Result<PacketStream> {
+    let start = match START.get() {
+        Some(start) => start,
+        None => return Ok(limit(concat_packets(paths, progress).await?)),
+    };
+    let path = match &paths[..] {
+        [p] if !p.to_str().is_some_and(|p| p.contains(['*', '?'])) => p,
+        _ => bail!("--skip-bytes and --start-at need exactly one regular file"),
+    };
+    let offset = match *start {
+        Start::Byte(offset) => offset,
+        Start::Time(seconds) => find_offset_at(path, seconds)?,
+    };
+    info!("start at byte {} of {:?}", offset, path);
+    Ok(limit(seek_packets(path, offset, progress).await?))
+}
+
+async fn concat_packets(paths: Vec<PathBuf>, progress: bool) -> Result<PacketStream> {
+    let mut expanded = Vec::new();
+    for p in paths.iter() {
+        expanded.extend(expand_glob(p)?);
+    }
+    if expanded.len() <= 1 {
+        return open_packets(expanded.pop(), progress).await;
+    }
+    info!("concatenate {:?}", expanded);
+    let mut total = Some(0);
+    let mut reader: Pin<Box<dyn AsyncRead + Send>> = Box::pin(tokio::io::empty());
+    for p in expanded.into_iter() {
+        let file = OpenOptions::new().read(true).open(p).await?;
+        let metadata = file.metadata().await?;
+        total = total
+            .zip(metadata.is_file().then_some(metadata.len()))
+            .map(|(total, len)| total + len);
+        reader = Box::pin(reader.chain(file));
+    }
+    frame_packets(ProgressReader::new(reader, progress, total), false)
+}
+
+/// Opens a file as a stream of packets from the byte offset, which is at
+/// the head of a packet.
+pub async fn path_to_packets_at(p: &Path, offset: u64, progress: bool) -> Result<PacketStream> {
+    Ok(limit(seek_packets(p, offset, progress).await?))
+}
+
+async fn seek_packets(p: &Path, offset: u64, progress: bool) -> Result<PacketStream> {
+    if !progress && !decrypting() {
+        if let Some(bytes) = map_file(p)? {
+            let mut bytes = bytes.slice(usize::try_from(offset)?.min(bytes.len())..);
+            let mut decoder = PacketDecoder::new(offset > 0);
+            let packets = std::iter::from_fn(move || decoder.decode_bytes(&mut bytes));
+            return Ok(Box::pin(tokio_stream::iter(packets)));
+        }
+    }
+    let mut file = OpenOptions::new().read(true).open(p).await?;
+    let metadata = file.metadata().await?;
+    if !metadata.is_file() {
+        bail!("{:?} is not a regular file to seek", p);
+    }
+    file.seek(SeekFrom::Start(offset)).await?;
+    let total = metadata.len().saturating_sub(offset);
+    frame_packets(ProgressReader::new(file, progress, Some(total)), offset > 0)
+}
+
+/// Opens the input as a stream of packets, like `paths_to_packets` with at
+/// most one path.
+pub async fn path_to_packets(p: Option<PathBuf>, progress: bool) -> Result<PacketStream> {
+    paths_to_packets(p.into_iter().collect(), progress).await
+}
+
+// regular files are mapped into memory and framed in place, and the others,
+// or any input with progress or to decrypt, are read through tokio. A mapped file is read
+// up to its size at the time it is opened.
+async fn open_packets(p: Option<PathBuf>, progress: bool) -> Result<PacketStream> {
+    let tcp_addr = p
+        .as_deref()
+        .and_then(|p| p.to_str())
+        .and_then(|p| p.strip_prefix(TCP_LISTEN_SCHEME));
+    if let Some(addr) = tcp_addr {
+        let input = ProgressReader::new(accept_tcp(addr).await?, progress, None);
+        return frame_packets(input, false);
+    }
+    if let Some(path) = p
+        .as_deref()
+        .filter(|p| !progress && !decrypting() && p.to_str() != Some("-"))
+    {
+        if let Some(mut bytes) = map_file(path)? {
+            info!("read {:?} by mmap", path);
+            let mut decoder = PacketDecoder::new(false);
+            let packets = std::iter::from_fn(move || decoder.decode_bytes(&mut bytes));
+            return Ok(Box::pin(tokio_stream::iter(packets)));
+        }
+    }
+    frame_packets(
+        with_progress(path_to_async_read(p).await?, progress).await?,
+        false,
+    )
+}
+
+fn decrypting() -> bool {
+    #[cfg(feature = "b25")]
+    return super::b25::is_enabled();
+    #[cfg(not(feature = "b25"))]
+    false
+}
+
+/// Reports the amount of read bytes on stderr, with the ETA if the size of
+/// the input is known, or the throughput for live inputs.
+pub struct ProgressReader<R> {
+    inner: R,
+    enabled: bool,
+    total: Option<u64>,
+    read: u64,
+    started: Instant,
+    reported: Instant,
+}
+
+pub async fn with_progress(file: File, enabled: bool) -> Result<ProgressReader<File>> {
+    let total = if enabled {
+        let metadata = file.metadata().await?;
+        if metadata.is_file() {
+            Some(metadata.len())
+        } else {
+            None
+        }
+    } else {
+        None
+    };
+    Ok(ProgressReader::new(file, enabled, total))
+}
+
+impl<R> ProgressReader<R> {
+    pub fn new(inner: R, enabled: bool, total: Option<u64>) -> ProgressReader<R> {
+        let now = Instant::now();
+        ProgressReader {
+            inner,
+            enabled,
+            total,
+            read: 0,
+            started: now,
+            reported: now,
+        }
+    }
+
+    fn report(&self, done: bool) {
+        let elapsed = self.started.elapsed().as_secs_f64();
+        let rate = if elapsed > 0.0 {
+            self.read as f64 / elapsed
+        } else {
+            0.0
+        };
+        let mib = |b: f64| b / (1024.0 * 1024.0);
+        match self.total {
+            Some(total) if total > 0 => {
+                let eta = if rate > 0.0 {
+                    total.saturating_sub(self.read) as f64 / rate
+                } else {
+                    0.0
+                };
+                eprint!(
+                    "\r{:.1}/{:.1} MiB ({:.1}%) ETA {:.0}s ",
+                    mib(self.read as f64),
+                    mib(total as f64),
+                    self.read as f64 * 100.0 / total as f64,
+                    eta
+                );
+            }
+            _ => {
+                eprint!(
+                    "\r{:.1} MiB ({:.1} MiB/s) ",
+                    mib(self.read as f64),
+                    mib(rate)
+                );
+            }
+        }
+        if done {
+            eprintln!();
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for ProgressReader<R> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let before = buf.filled().len();
+        let poll = Pin::new(&mut self.inner).poll_read(cx, buf);
+        if !self.enabled {
+            return poll;
+        }
+        if let Poll::Ready(Ok(())) = poll {
+            let n = buf.filled().len() - before;
+            self.read += n as u64;
+            if n == 0 {
+                self.report(true);
+            } else if self.reported.elapsed() >= PROGRESS_INTERVAL {
+                self.reported = Instant::now();
+                self.report(false);
+            }
+        }
+        poll
+    }
+}
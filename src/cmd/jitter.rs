@@ -1,17 +1,20 @@
+use std::cmp::Ordering;
 use std::path::PathBuf;
 
 use anyhow::{bail, Result};
 use log::{info, warn};
+use schemars::schema::RootSchema;
+use schemars::JsonSchema;
 use serde_derive::Serialize;
 use serde_json;
 use tokio_stream::{Stream, StreamExt};
-use tokio_util::codec::FramedRead;
 
 use super::common;
-use super::io::path_to_async_read;
-use crate::pes;
-use crate::stream::cueable;
-use crate::ts;
+use super::errors;
+use super::io::path_to_packets;
+use tstools::pes;
+use tstools::stream::cueable;
+use tstools::ts;
 
 async fn find_first_audio_pts<S: Stream<Item = ts::TSPacket> + Unpin>(
     pid: u16,
@@ -26,6 +29,7 @@ async fn find_first_audio_pts<S: Stream<Item = ts::TSPacket> + Unpin>(
                     Ok(pes) => pes,
                     Err(e) => {
                         warn!("pes parse error: {:?}", e);
+                        errors::count(errors::Kind::Pes);
                         continue;
                     }
                 };
@@ -33,33 +37,153 @@ async fn find_first_audio_pts<S: Stream<Item = ts::TSPacket> + Unpin>(
                     return Ok(pts);
                 }
             }
-            Some(Err(e)) => return Err(e),
+            Some(Err(e)) => return Err(e.into()),
             None => bail!("no pts found"),
         }
     }
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+enum Direction {
+    /// The audio PTS is smaller than the video PTS.
+    VideoLeads,
+    /// The audio PTS is larger than the video PTS.
+    AudioLeads,
+    Aligned,
+}
+
+#[derive(Serialize, JsonSchema)]
 struct Jitter {
+    /// The first video PTS minus the first audio PTS, in seconds.
     jitter: f64,
+    direction: Direction,
+}
+
+impl Jitter {
+    fn new(video_pts: u64, audio_pts: u64) -> Jitter {
+        let diff = pes::PtsTime::new(video_pts).diff(pes::PtsTime::new(audio_pts));
+        let direction = match diff.cmp(&0) {
+            Ordering::Greater => Direction::VideoLeads,
+            Ordering::Less => Direction::AudioLeads,
+            Ordering::Equal => Direction::Aligned,
+        };
+        Jitter {
+            jitter: diff as f64 / pes::PTS_HZ as f64,
+            direction,
+        }
+    }
+}
+
+#[derive(Serialize, JsonSchema)]
+struct Drift {
+    time: f64,
+    drift: f64,
 }
 
-pub async fn run(input: Option<PathBuf>) -> Result<()> {
-    let input = path_to_async_read(input).await?;
-    let packets = FramedRead::new(input, ts::TSPacketDecoder::new());
+#[derive(Serialize, JsonSchema)]
+struct DriftSummary {
+    samples: usize,
+    min: f64,
+    max: f64,
+    mean: f64,
+}
+
+fn packet_pts(packet: &ts::TSPacket) -> Option<u64> {
+    common::packet_timestamps(packet).and_then(|timestamps| timestamps.pts)
+}
+
+// samples the distance between the latest video and audio PTS in the mux,
+// once a second of video.
+async fn track_drift<S: Stream<Item = ts::TSPacket> + Unpin>(
+    meta: &common::Meta,
+    mut s: S,
+) -> Result<()> {
+    let mut first_video_pts = None;
+    let mut last_sample_time = None;
+    let mut audio_pts = None;
+    let mut drifts = Vec::new();
+    while let Some(packet) = s.next().await {
+        if packet.pid == meta.audio_pid() {
+            if let Some(pts) = packet_pts(&packet) {
+                audio_pts = Some(pts);
+            }
+        } else if packet.pid == meta.video_pid() {
+            let (video_pts, audio_pts) = match (packet_pts(&packet), audio_pts) {
+                (Some(video_pts), Some(audio_pts)) => (video_pts, audio_pts),
+                _ => continue,
+            };
+            let first = *first_video_pts.get_or_insert(video_pts);
+            let time = common::pts_diff(video_pts, first);
+            if let Some(last) = last_sample_time {
+                if time - last < 1.0 && time >= last {
+                    continue;
+                }
+            }
+            last_sample_time = Some(time);
+            let drift = common::pts_diff(video_pts, audio_pts);
+            println!("{}", serde_json::to_string(&Drift { time, drift })?);
+            drifts.push(drift);
+        }
+    }
+    if drifts.is_empty() {
+        bail!("no pts pairs found");
+    }
+    let summary = DriftSummary {
+        samples: drifts.len(),
+        min: drifts.iter().cloned().fold(f64::INFINITY, f64::min),
+        max: drifts.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+        mean: drifts.iter().sum::<f64>() / drifts.len() as f64,
+    };
+    println!("{}", serde_json::to_string(&summary)?);
+    Ok(())
+}
+
+// a line printed with --continuous, which only describes the schema.
+#[allow(dead_code)]
+#[derive(JsonSchema)]
+#[schemars(untagged)]
+enum ContinuousLine {
+    Drift(Drift),
+    Summary(DriftSummary),
+}
+
+/// The JSON Schema of a line printed, drifts and the summary with
+/// `continuous`.
+pub fn schema(continuous: bool) -> RootSchema {
+    if continuous {
+        schemars::schema_for!(ContinuousLine)
+    } else {
+        schemars::schema_for!(Jitter)
+    }
+}
+
+pub async fn run(input: Option<PathBuf>, continuous: bool, service_id: Option<u16>) -> Result<()> {
+    let packets = path_to_packets(input, false).await?;
     let packets = common::strip_error_packets(packets);
     let mut cueable_packets = cueable(packets);
-    let meta = common::find_main_meta(&mut cueable_packets).await?;
+    let meta = common::find_main_meta(
+        &mut cueable_packets,
+        service_id,
+        &[common::StreamKind::Video, common::StreamKind::Audio],
+    )
+    .await?;
     let packets = cueable_packets.cue_up();
+    if continuous {
+        return track_drift(&meta, packets).await;
+    }
     let mut cueable_packets = cueable(packets);
-    let video_pts = common::find_first_picture_pts(meta.video_pid, &mut cueable_packets).await?;
+    let video_pts = common::find_first_picture_pts(
+        meta.video_pid(),
+        meta.video_stream_type(),
+        &mut cueable_packets,
+    )
+    .await?;
     info!("video pts {}", video_pts);
     let packets = cueable_packets.cue_up();
-    let audio_pts = find_first_audio_pts(meta.audio_pid, packets).await?;
+    let audio_pts = find_first_audio_pts(meta.audio_pid(), packets).await?;
     info!("audio pts {}", audio_pts);
-    let jitter = Jitter {
-        jitter: f64::from((video_pts - audio_pts) as u32) / 90000f64,
-    };
+    let jitter = Jitter::new(video_pts, audio_pts);
     println!("{}", serde_json::to_string(&jitter)?);
     Ok(())
 }
@@ -0,0 +1,128 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use bytes::Bytes;
+use log::{info, warn};
+use serde_derive::Serialize;
+use tokio_stream::StreamExt;
+
+use super::common;
+use super::io::path_to_packets;
+use tstools::arib;
+use tstools::psi;
+
+#[derive(Serialize)]
+struct Logo {
+    network_id: u16,
+    logo_id: u16,
+    logo_type: u8,
+    logo_version: u16,
+    download_data_id: u16,
+    path: PathBuf,
+    service_ids: BTreeSet<u16>,
+}
+
+// collects services transmitting each logo_id in each network.
+fn update_services(section: &[u8], services: &mut BTreeMap<(u16, u16), BTreeSet<u16>>) {
+    let table_id = section[0];
+    if table_id != psi::SELF_STREAM_TABLE_ID && table_id != psi::OTHER_STREAM_TABLE_ID {
+        return;
+    }
+    let sdt = match psi::ServiceDescriptionSection::parse(section) {
+        Ok(sdt) => sdt,
+        Err(e) => {
+            info!("sdt parse error: {:?}", e);
+            return;
+        }
+    };
+    for service in sdt.services.iter() {
+        for desc in service.descriptors.iter() {
+            if let psi::Descriptor::LogoTransmissionDescriptor(d) = desc {
+                if let Some(logo_id) = d.logo_id {
+                    services
+                        .entry((sdt.original_network_id, logo_id))
+                        .or_default()
+                        .insert(service.service_id);
+                }
+            }
+        }
+    }
+}
+
+async fn save_logo(
+    section: &[u8],
+    dir: &Path,
+    logos: &mut BTreeMap<(u16, u16, u8), Logo>,
+) -> Result<()> {
+    let cdt = psi::CommonDataSection::parse(section)?;
+    if cdt.data_type != psi::DATA_TYPE_LOGO {
+        return Ok(());
+    }
+    let logo = psi::LogoData::parse(cdt.data_module)?;
+    let key = (cdt.original_network_id, logo.logo_id, logo.logo_type);
+    if logos.get(&key).map(|l| l.logo_version) == Some(logo.logo_version) {
+        return Ok(());
+    }
+    let png = arib::logo::logo_to_png(logo.data)?;
+    let path = dir.join(format!(
+        "{}_{}_{}.png",
+        cdt.original_network_id, logo.logo_id, logo.logo_type
+    ));
+    info!("write {:?}", path);
+    tokio::fs::write(&path, png).await?;
+    logos.insert(
+        key,
+        Logo {
+            network_id: cdt.original_network_id,
+            logo_id: logo.logo_id,
+            logo_type: logo.logo_type,
+            logo_version: logo.logo_version,
+            download_data_id: cdt.download_data_id,
+            path,
+            service_ids: BTreeSet::new(),
+        },
+    );
+    Ok(())
+}
+
+pub async fn run(input: Option<PathBuf>, output_dir: PathBuf) -> Result<()> {
+    tokio::fs::create_dir_all(&output_dir).await?;
+    let packets = path_to_packets(input, false).await?;
+    let mut packets = common::strip_error_packets(packets);
+
+    let mut sdt_buffer = psi::Buffer::detached().verify_crc();
+    let mut cdt_buffer = psi::Buffer::detached().verify_crc();
+    let mut services = BTreeMap::new();
+    let mut logos = BTreeMap::new();
+    while let Some(packet) = packets.next().await {
+        let pid = packet.pid;
+        let buffer = match pid {
+            psi::SDT_PID => &mut sdt_buffer,
+            psi::CDT_PID => &mut cdt_buffer,
+            _ => continue,
+        };
+        if let Err(e) = buffer.feed(packet) {
+            info!("pid {:#x}: {:?}", pid, e);
+            continue;
+        }
+        let sections: Vec<Bytes> = std::iter::from_fn(|| buffer.next_section()).collect();
+        for section in sections.into_iter() {
+            if pid == psi::SDT_PID {
+                update_services(&section[..], &mut services);
+            } else if section[0] == psi::COMMON_DATA_TABLE_ID {
+                if let Err(e) = save_logo(&section[..], &output_dir, &mut logos).await {
+                    warn!("logo error: {:?}", e);
+                }
+            }
+        }
+    }
+
+    for ((network_id, logo_id, _), mut logo) in logos.into_iter() {
+        if let Some(service_ids) = services.get(&(network_id, logo_id)) {
+            logo.service_ids = service_ids.clone();
+        }
+        println!("{}", serde_json::to_string(&logo)?);
+    }
+    Ok(())
+}
@@ -0,0 +1,461 @@
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::process::Stdio;
+
+use anyhow::{anyhow, bail, Context, Result};
+use bytes::{Bytes, BytesMut};
+use log::{info, warn};
+use serde_derive::Serialize;
+use symphonia_codec_aac::AacDecoder;
+use symphonia_core::audio::{Channels, SampleBuffer};
+use symphonia_core::codecs::{CodecParameters, Decoder, DecoderOptions, CODEC_TYPE_AAC};
+use symphonia_core::formats::Packet;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::process::Command;
+use tokio_stream::{Stream, StreamExt};
+
+use super::common;
+use super::errors;
+use super::io::{self, path_to_packets, RecordWriter};
+use tstools::adts;
+use tstools::pes;
+use tstools::stream::cueable;
+use tstools::ts;
+
+// loudness is measured over blocks of 400ms, overlapping by 75%, and the
+// short-term one over 3s, per ITU-R BS.1770.
+const STEPS_PER_SECOND: u32 = 10;
+const MOMENTARY_STEPS: usize = 4;
+const SHORT_TERM_STEPS: usize = 30;
+const ABSOLUTE_GATE: f64 = -70.0;
+const RELATIVE_GATE: f64 = -10.0;
+const READ_CHUNK: usize = 64 * 1024;
+
+#[derive(Serialize)]
+struct Sample {
+    // seconds from the start of the audio.
+    time: f64,
+    momentary: Option<f64>,
+    short_term: Option<f64>,
+}
+
+#[derive(Serialize)]
+struct Summary {
+    duration: f64,
+    // in LKFS, which ARIB TR-B32 targets at -24.
+    integrated: Option<f64>,
+}
+
+// a biquad filter in the transposed direct form II.
+struct Biquad {
+    b: [f64; 3],
+    a: [f64; 2],
+    z: [f64; 2],
+}
+
+impl Biquad {
+    fn process(&mut self, x: f64) -> f64 {
+        let y = self.b[0] * x + self.z[0];
+        self.z[0] = self.b[1] * x - self.a[0] * y + self.z[1];
+        self.z[1] = self.b[2] * x - self.a[1] * y;
+        y
+    }
+}
+
+// the two stages of the K-weighting filter for the sample rate, designed
+// as libebur128 does so that rates other than 48kHz work too.
+fn k_weighting(sample_rate: u32) -> [Biquad; 2] {
+    let fs = f64::from(sample_rate);
+
+    let (f0, gain, q) = (1681.974450955533, 3.999843853973347, 0.7071752369554196);
+    let k = (std::f64::consts::PI * f0 / fs).tan();
+    let vh = 10f64.powf(gain / 20.0);
+    let vb = vh.powf(0.4996667741545416);
+    let a0 = 1.0 + k / q + k * k;
+    let shelf = Biquad {
+        b: [
+            (vh + vb * k / q + k * k) / a0,
+            2.0 * (k * k - vh) / a0,
+            (vh - vb * k / q + k * k) / a0,
+        ],
+        a: [2.0 * (k * k - 1.0) / a0, (1.0 - k / q + k * k) / a0],
+        z: [0.0; 2],
+    };
+
+    let (f0, q) = (38.13547087602444, 0.5003270373238773);
+    let k = (std::f64::consts::PI * f0 / fs).tan();
+    let a0 = 1.0 + k / q + k * k;
+    let high_pass = Biquad {
+        b: [1.0, -2.0, 1.0],
+        a: [2.0 * (k * k - 1.0) / a0, (1.0 - k / q + k * k) / a0],
+        z: [0.0; 2],
+    };
+    [shelf, high_pass]
+}
+
+// weights of the channels in the order of the decoder output, L, R, C, LFE,
+// Ls and Rs for 5.1ch.
+fn channel_weights(channels: usize) -> Vec<f64> {
+    match channels {
+        5 => vec![1.0, 1.0, 1.0, 1.41, 1.41],
+        6 => vec![1.0, 1.0, 1.0, 0.0, 1.41, 1.41],
+        n => vec![1.0; n],
+    }
+}
+
+fn lkfs(mean_square: f64) -> Option<f64> {
+    (mean_square > 0.0).then(|| -0.691 + 10.0 * mean_square.log10())
+}
+
+struct Meter {
+    filters: Vec<[Biquad; 2]>,
+    weights: Vec<f64>,
+    step_frames: usize,
+    // weighted sum of squares in the current step.
+    sum: f64,
+    frames: usize,
+    // mean squares of the latest steps.
+    steps: VecDeque<f64>,
+    // mean squares of every 400ms block, for the integrated loudness.
+    blocks: Vec<f64>,
+    total_frames: u64,
+    sample_rate: u32,
+}
+
+impl Meter {
+    fn new(channels: usize, sample_rate: u32) -> Meter {
+        Meter {
+            filters: (0..channels).map(|_| k_weighting(sample_rate)).collect(),
+            weights: channel_weights(channels),
+            step_frames: (sample_rate / STEPS_PER_SECOND) as usize,
+            sum: 0.0,
+            frames: 0,
+            steps: VecDeque::with_capacity(SHORT_TERM_STEPS),
+            blocks: Vec::new(),
+            total_frames: 0,
+            sample_rate,
+        }
+    }
+
+    // feeds a frame of interleaved samples, and returns whether a step ends.
+    fn feed(&mut self, frame: &[f32]) -> bool {
+        for ((x, [shelf, high_pass]), w) in frame
+            .iter()
+            .zip(self.filters.iter_mut())
+            .zip(self.weights.iter())
+        {
+            let y = high_pass.process(shelf.process(f64::from(*x)));
+            self.sum += w * y * y;
+        }
+        self.frames += 1;
+        self.total_frames += 1;
+        if self.frames < self.step_frames {
+            return false;
+        }
+        if self.steps.len() == SHORT_TERM_STEPS {
+            self.steps.pop_front();
+        }
+        self.steps.push_back(self.sum / self.frames as f64);
+        self.sum = 0.0;
+        self.frames = 0;
+        if let Some(block) = self.mean_of_latest(MOMENTARY_STEPS) {
+            self.blocks.push(block);
+        }
+        true
+    }
+
+    fn mean_of_latest(&self, n: usize) -> Option<f64> {
+        if self.steps.len() < n {
+            return None;
+        }
+        Some(self.steps.iter().rev().take(n).sum::<f64>() / n as f64)
+    }
+
+    fn seconds(&self) -> f64 {
+        self.total_frames as f64 / f64::from(self.sample_rate)
+    }
+
+    fn sample(&self) -> Sample {
+        Sample {
+            time: self.seconds(),
+            momentary: self.mean_of_latest(MOMENTARY_STEPS).and_then(lkfs),
+            short_term: self.mean_of_latest(SHORT_TERM_STEPS).and_then(lkfs),
+        }
+    }
+
+    // gates the blocks absolutely, then relatively to their loudness.
+    fn integrated(&self) -> Option<f64> {
+        let gated_mean = |blocks: &[f64]| -> Option<f64> {
+            (!blocks.is_empty()).then(|| blocks.iter().sum::<f64>() / blocks.len() as f64)
+        };
+        let absolute: Vec<f64> = self
+            .blocks
+            .iter()
+            .cloned()
+            .filter(|z| lkfs(*z).is_some_and(|l| l > ABSOLUTE_GATE))
+            .collect();
+        let threshold = lkfs(gated_mean(&absolute)?)? + RELATIVE_GATE;
+        let relative: Vec<f64> = absolute
+            .into_iter()
+            .filter(|z| lkfs(*z).is_some_and(|l| l > threshold))
+            .collect();
+        lkfs(gated_mean(&relative)?)
+    }
+}
+
+// prints the samples every interval while metering.
+struct Report {
+    meter: Meter,
+    out: RecordWriter,
+    interval_steps: u64,
+    steps: u64,
+}
+
+impl Report {
+    fn feed(&mut self, frame: &[f32]) -> Result<()> {
+        if !self.meter.feed(frame) {
+            return Ok(());
+        }
+        self.steps += 1;
+        if self.steps.is_multiple_of(self.interval_steps) {
+            self.out
+                .write_line(&serde_json::to_string(&self.meter.sample())?)?;
+            self.out.end_batch()?;
+        }
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<()> {
+        let summary = Summary {
+            duration: self.meter.seconds(),
+            integrated: self.meter.integrated(),
+        };
+        self.out.write_line(&serde_json::to_string(&summary)?)?;
+        self.out.flush()
+    }
+}
+
+fn pes_data(bytes: &[u8]) -> Option<&[u8]> {
+    match pes::PESPacket::parse(bytes) {
+        Ok(pes) => match pes.body {
+            pes::PESPacketBody::NormalPESPacketBody(body) => Some(body.pes_packet_data_byte),
+            _ => None,
+        },
+        Err(e) => {
+            info!("pes parse error: {:?}", e);
+            errors::count(errors::Kind::Pes);
+            None
+        }
+    }
+}
+
+// decodes the ADTS frames of the PES packets, mono or stereo AAC-LC only.
+async fn decode<S>(
+    mut buffer: pes::Buffer<S>,
+    first: Bytes,
+    format: adts::AudioFormat,
+    report: &mut Report,
+) -> Result<()>
+where
+    S: Stream<Item = ts::TSPacket> + Unpin,
+{
+    let channels = match format.channels {
+        Some(1) => Channels::FRONT_LEFT,
+        Some(2) => Channels::FRONT_LEFT | Channels::FRONT_RIGHT,
+        _ => bail!(
+            "only mono and stereo are decoded, give --decoder for {:?}",
+            format
+        ),
+    };
+    let mut params = CodecParameters::new();
+    params
+        .for_codec(CODEC_TYPE_AAC)
+        .with_channels(channels)
+        .with_sample_rate(report.meter.sample_rate);
+    let mut decoder = AacDecoder::try_new(&params, &DecoderOptions::default())
+        .map_err(|e| anyhow!("unsupported audio format {:?}: {}", format, e))?;
+    let mut samples: Option<SampleBuffer<f32>> = None;
+    let mut next = Some(Ok(first));
+    while let Some(bytes) = next {
+        let bytes = match bytes {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                info!("pes buffer error: {:?}", e);
+                next = buffer.next().await;
+                continue;
+            }
+        };
+        for (header, frame) in adts::frames(pes_data(&bytes[..]).unwrap_or(&[])) {
+            if header.channels() != format.channels || header.sample_rate() != format.sample_rate {
+                info!("skip a frame of another format: {:?}", header);
+                continue;
+            }
+            // the CRC follows the header unless protection_absent.
+            let header_length = if header.protection_absent {
+                adts::HEADER_LENGTH
+            } else {
+                adts::HEADER_LENGTH + 2
+            };
+            let packet = Packet::new_from_slice(0, 0, 0, frame.get(header_length..).unwrap_or(&[]));
+            let decoded = match decoder.decode(&packet) {
+                Ok(decoded) => decoded,
+                Err(e) => {
+                    warn!("decode error: {}", e);
+                    continue;
+                }
+            };
+            let samples = samples.get_or_insert_with(|| {
+                SampleBuffer::new(decoded.capacity() as u64, *decoded.spec())
+            });
+            samples.copy_interleaved_ref(decoded);
+            for frame in samples.samples().chunks_exact(channels.count()) {
+                report.feed(frame)?;
+            }
+        }
+        next = buffer.next().await;
+    }
+    Ok(())
+}
+
+// pipes the PES data to the decoder command, and reads the samples back.
+async fn decode_by_command<S>(
+    mut buffer: pes::Buffer<S>,
+    first: Bytes,
+    args: Vec<String>,
+    channels: usize,
+    report: &mut Report,
+) -> Result<()>
+where
+    S: Stream<Item = ts::TSPacket> + Unpin + Send + 'static,
+{
+    let mut child = Command::new(&args[0])
+        .args(&args[1..])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .with_context(|| format!("failed to run {:?}", args[0]))?;
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let mut stdout = child.stdout.take().expect("stdout is piped");
+    let feeder = tokio::spawn(async move {
+        let mut next = Some(Ok(first));
+        while let Some(bytes) = next {
+            match bytes {
+                Ok(bytes) => {
+                    if let Some(data) = pes_data(&bytes[..]) {
+                        stdin.write_all(data).await?;
+                    }
+                }
+                Err(e) => info!("pes buffer error: {:?}", e),
+            }
+            next = buffer.next().await;
+        }
+        // closing stdin lets the decoder flush the rest and exit.
+        drop(stdin);
+        Ok::<_, std::io::Error>(())
+    });
+
+    let frame_bytes = channels * 4;
+    let mut pcm = BytesMut::new();
+    let mut frame = vec![0f32; channels];
+    loop {
+        pcm.reserve(READ_CHUNK);
+        if stdout.read_buf(&mut pcm).await? == 0 {
+            break;
+        }
+        let whole = pcm.len() / frame_bytes * frame_bytes;
+        let data = pcm.split_to(whole);
+        for bytes in data.chunks_exact(frame_bytes) {
+            for (x, b) in frame.iter_mut().zip(bytes.chunks_exact(4)) {
+                *x = f32::from_le_bytes([b[0], b[1], b[2], b[3]]);
+            }
+            report.feed(&frame)?;
+        }
+    }
+    match feeder.await? {
+        Err(e) if e.kind() != std::io::ErrorKind::BrokenPipe => {
+            warn!("feed decoder: {:?}", e)
+        }
+        _ => {}
+    }
+    let status = child.wait().await?;
+    if !status.success() {
+        bail!("decoder exited with {}", status);
+    }
+    Ok(())
+}
+
+/// Prints the momentary and short-term loudness of the main audio of a
+/// service every interval seconds, and the integrated loudness at the end.
+/// Mono and stereo AAC-LC is decoded in process. Other audio, such as 5.1ch
+/// or dual mono, needs the decoder command, which reads ADTS from stdin and
+/// writes 32-bit float little endian samples, interleaved in the channels and
+/// the sample rate of the stream, to stdout.
+pub async fn run(
+    input: Option<PathBuf>,
+    service_id: Option<u16>,
+    decoder: Option<String>,
+    interval: f64,
+) -> Result<()> {
+    if interval <= 0.0 {
+        bail!("interval must be positive: {}", interval);
+    }
+    let args: Option<Vec<String>> =
+        decoder.map(|decoder| decoder.split_whitespace().map(String::from).collect());
+    if args.as_ref().is_some_and(|args| args.is_empty()) {
+        bail!("empty decoder command");
+    }
+    let out = RecordWriter::stdout(io::is_live(input.as_slice()));
+    let packets = path_to_packets(input, false).await?;
+    let packets = common::interrupt_on_ctrl_c(common::strip_error_packets(packets));
+    let mut cueable_packets = cueable(packets);
+    let meta = common::find_main_meta(
+        &mut cueable_packets,
+        service_id,
+        &[common::StreamKind::Audio],
+    )
+    .await?;
+    let audio_pid = meta.audio_pid();
+    let packets = cueable_packets.cue_up();
+    let mut buffer = pes::Buffer::new(packets.filter(move |packet| packet.pid == audio_pid));
+
+    // the first PES packet gives the format the decoder outputs.
+    let (first, format) = loop {
+        let bytes = match buffer.next().await {
+            Some(Ok(bytes)) => bytes,
+            Some(Err(e)) => {
+                info!("pes buffer error: {:?}", e);
+                continue;
+            }
+            None => bail!("no audio found"),
+        };
+        if let Some(format) = pes_data(&bytes[..]).and_then(adts::find_audio_format) {
+            break (bytes, format);
+        }
+    };
+    let (channels, sample_rate) = match (format.channels, format.sample_rate) {
+        (Some(channels), Some(sample_rate)) => (usize::from(channels), sample_rate),
+        (None, Some(sample_rate)) if args.is_some() => (2, sample_rate),
+        _ => bail!("unsupported audio format: {:?}", format),
+    };
+    let mut report = Report {
+        meter: Meter::new(channels, sample_rate),
+        out,
+        interval_steps: ((interval * f64::from(STEPS_PER_SECOND)).round() as u64).max(1),
+        steps: 0,
+    };
+    match args {
+        Some(args) => {
+            info!(
+                "decode {} channels at {}Hz by {:?}",
+                channels, sample_rate, args
+            );
+            decode_by_command(buffer, first, args, channels, &mut report).await?;
+        }
+        None => {
+            info!("decode {} channels at {}Hz", channels, sample_rate);
+            decode(buffer, first, format, &mut report).await?;
+        }
+    }
+    report.finish()
+}
@@ -0,0 +1,439 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+use anyhow::Result;
+use log::info;
+use serde_derive::Serialize;
+use tokio::sync::mpsc::channel;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::{Stream, StreamExt};
+
+use super::common::{self, strip_error_packets};
+use super::errors;
+use super::io::path_to_packets;
+use tstools::adts;
+use tstools::h262;
+use tstools::pes;
+use tstools::psi;
+use tstools::stream::cueable;
+use tstools::ts;
+
+#[derive(Serialize)]
+struct VideoInfo {
+    pid: u16,
+    stream_type: u8,
+    codec: &'static str,
+    width: Option<u32>,
+    height: Option<u32>,
+    aspect_ratio: Option<String>,
+    frame_rate: Option<f64>,
+    // "progressive", "interlaced", "telecine" or "mixed", for MPEG-2 video.
+    scan_type: Option<&'static str>,
+    // "top_first" or "bottom_first" for interlaced pictures.
+    field_order: Option<&'static str>,
+    bit_rate: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct AudioInfo {
+    pid: u16,
+    stream_type: u8,
+    codec: &'static str,
+    profile: Option<&'static str>,
+    sample_rate: Option<u32>,
+    channels: Option<u8>,
+    bit_rate: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct ServiceInfo {
+    service_id: u16,
+    service_name: Option<String>,
+    delivery_system: Option<common::DeliverySystem>,
+    pmt_pid: u16,
+    pcr_pid: u16,
+    video: Vec<VideoInfo>,
+    audio: Vec<AudioInfo>,
+    caption_pids: Vec<u16>,
+    data_pids: Vec<u16>,
+    bit_rate: Option<u64>,
+}
+
+// pictures sampled to tell how MPEG-2 video is scanned.
+const SCAN_PICTURES: usize = 300;
+
+// counts of the picture coding extensions of MPEG-2 video.
+#[derive(Default)]
+struct ScanStats {
+    pictures: usize,
+    progressive_frames: usize,
+    repeat_first_fields: usize,
+    // interlaced frames and first fields of field pictures by field order.
+    top_first: usize,
+    bottom_first: usize,
+    // the next field picture is the second field of a pair.
+    second_field: bool,
+}
+
+impl ScanStats {
+    fn update(&mut self, ext: &h262::PictureCodingExtension) {
+        if ext.picture_structure != h262::FRAME_PICTURE {
+            self.second_field = !self.second_field;
+            if self.second_field {
+                self.pictures += 1;
+                match ext.picture_structure {
+                    h262::TOP_FIELD => self.top_first += 1,
+                    _ => self.bottom_first += 1,
+                }
+            }
+            return;
+        }
+        self.second_field = false;
+        self.pictures += 1;
+        if ext.progressive_frame {
+            self.progressive_frames += 1;
+        } else if ext.top_field_first {
+            self.top_first += 1;
+        } else {
+            self.bottom_first += 1;
+        }
+        if ext.repeat_first_field {
+            self.repeat_first_fields += 1;
+        }
+    }
+
+    fn scan_type(&self, progressive_sequence: Option<bool>) -> Option<&'static str> {
+        if progressive_sequence == Some(true) {
+            return Some("progressive");
+        }
+        if self.pictures == 0 {
+            return None;
+        }
+        Some(match self.progressive_frames {
+            // progressive frames shown as fields by 3:2 pulldown.
+            n if n == self.pictures && self.repeat_first_fields > 0 => "telecine",
+            n if n == self.pictures => "progressive",
+            0 => "interlaced",
+            _ => "mixed",
+        })
+    }
+
+    fn field_order(&self) -> Option<&'static str> {
+        match (self.top_first, self.bottom_first) {
+            (0, 0) => None,
+            (top, bottom) if top >= bottom => Some("top_first"),
+            _ => Some("bottom_first"),
+        }
+    }
+}
+
+// finds the format of MPEG-2 video, and samples pictures for its scan type.
+async fn find_mpeg2_format<S>(es_stream: S) -> Option<(h262::VideoFormat, ScanStats)>
+where
+    S: Stream<Item = ts::TSPacket> + Unpin,
+{
+    let mut buffer = pes::Buffer::new(es_stream);
+    let mut format = None;
+    let mut stats = ScanStats::default();
+    while let Some(bytes) = buffer.next().await {
+        let bytes = match bytes {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                info!("pes buffer error: {:?}", e);
+                continue;
+            }
+        };
+        let pes = match pes::PESPacket::parse(&bytes[..]) {
+            Ok(pes) => pes,
+            Err(e) => {
+                info!("pes parse error: {:?}", e);
+                errors::count(errors::Kind::Pes);
+                continue;
+            }
+        };
+        let data = match pes.body {
+            pes::PESPacketBody::NormalPESPacketBody(ref body) => body.pes_packet_data_byte,
+            _ => continue,
+        };
+        if format.is_none() {
+            format = h262::find_video_format(data);
+        }
+        for offset in h262::start_codes(data) {
+            let code = &data[offset..];
+            if code.starts_with(h262::EXTENSION_START_CODE) {
+                if let Ok(ext) = h262::PictureCodingExtension::parse(code) {
+                    stats.update(&ext);
+                }
+            }
+        }
+        if format.is_some() && stats.pictures >= SCAN_PICTURES {
+            break;
+        }
+    }
+    format.map(|format| (format, stats))
+}
+
+#[derive(Default)]
+struct PcrRange {
+    first: Option<u64>,
+    last: Option<u64>,
+}
+
+impl PcrRange {
+    fn update(&mut self, pcr: u64) {
+        if self.first.is_none() {
+            self.first = Some(pcr);
+        }
+        self.last = Some(pcr);
+    }
+
+    fn seconds(&self) -> Option<f64> {
+        match (self.first, self.last) {
+            (Some(first), Some(last)) if last > first => {
+                Some((last - first) as f64 / ts::PCR_HZ as f64)
+            }
+            _ => None,
+        }
+    }
+}
+
+fn codec_name(stream_type: u8) -> &'static str {
+    match stream_type {
+        psi::STREAM_TYPE_VIDEO => "mpeg2",
+        psi::STREAM_TYPE_H264 => "h264",
+        psi::STREAM_TYPE_H265 => "h265",
+        psi::STREAM_TYPE_ADTS => "aac",
+        _ => "unknown",
+    }
+}
+
+fn is_video(stream_type: u8) -> bool {
+    matches!(
+        stream_type,
+        psi::STREAM_TYPE_VIDEO | psi::STREAM_TYPE_H264 | psi::STREAM_TYPE_H265
+    )
+}
+
+fn is_audio(stream_type: u8) -> bool {
+    stream_type == psi::STREAM_TYPE_ADTS
+}
+
+fn is_data(stream_type: u8) -> bool {
+    matches!(
+        stream_type,
+        psi::STREAM_TYPE_PES_PRIVATE_DATA | psi::STREAM_TYPE_DSMCC_TYPE_D
+    )
+}
+
+async fn find_es_format<S, T, F>(es_stream: S, find: F) -> Option<T>
+where
+    S: Stream<Item = ts::TSPacket> + Unpin,
+    F: Fn(&[u8]) -> Option<T>,
+{
+    let mut buffer = pes::Buffer::new(es_stream);
+    while let Some(bytes) = buffer.next().await {
+        let bytes = match bytes {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                info!("pes buffer error: {:?}", e);
+                continue;
+            }
+        };
+        let pes = match pes::PESPacket::parse(&bytes[..]) {
+            Ok(pes) => pes,
+            Err(e) => {
+                info!("pes parse error: {:?}", e);
+                errors::count(errors::Kind::Pes);
+                continue;
+            }
+        };
+        if let pes::PESPacketBody::NormalPESPacketBody(ref body) = pes.body {
+            if let Some(format) = find(body.pes_packet_data_byte) {
+                return Some(format);
+            }
+        }
+    }
+    None
+}
+
+fn bit_rate(bytes: u64, seconds: Option<f64>) -> Option<u64> {
+    seconds.map(|seconds| (bytes as f64 * 8.0 / seconds) as u64)
+}
+
+pub async fn run(input: Option<PathBuf>) -> Result<()> {
+    let packets = path_to_packets(input, false).await?;
+    let packets = strip_error_packets(packets);
+    let mut cueable_packets = cueable(packets);
+    let programs = common::find_programs(&mut cueable_packets).await?;
+    let mut packets = cueable_packets.cue_up();
+
+    let (sdt_tx, sdt_rx) = channel(1);
+    let sdt_handle = tokio::spawn(common::find_service_descriptions(ReceiverStream::new(
+        sdt_rx,
+    )));
+    let mut sdt_tx = Some(sdt_tx);
+    let (nit_tx, nit_rx) = channel(1);
+    let nit_handle = tokio::spawn(common::find_delivery_systems(ReceiverStream::new(nit_rx)));
+    let mut nit_tx = Some(nit_tx);
+
+    let mut es_txs = HashMap::new();
+    let mut video_handles = HashMap::new();
+    let mut audio_handles = HashMap::new();
+    for program in programs.iter() {
+        for es in program.streams.iter() {
+            let (pid, stream_type) = (es.pid, es.stream_type);
+            if es_txs.contains_key(&pid) {
+                continue;
+            }
+            if stream_type == psi::STREAM_TYPE_VIDEO {
+                let (tx, rx) = channel(1);
+                es_txs.insert(pid, tx);
+                video_handles.insert(
+                    pid,
+                    tokio::spawn(find_mpeg2_format(ReceiverStream::new(rx))),
+                );
+            } else if stream_type == psi::STREAM_TYPE_ADTS {
+                let (tx, rx) = channel(1);
+                es_txs.insert(pid, tx);
+                audio_handles.insert(
+                    pid,
+                    tokio::spawn(find_es_format(
+                        ReceiverStream::new(rx),
+                        adts::find_audio_format,
+                    )),
+                );
+            }
+        }
+    }
+
+    let mut pcr_pids = HashMap::new();
+    for program in programs.iter() {
+        pcr_pids.insert(program.pcr_pid, PcrRange::default());
+    }
+    let mut bytes_per_pid: HashMap<u16, u64> = HashMap::new();
+
+    while let Some(packet) = packets.next().await {
+        let pid = packet.pid;
+        *bytes_per_pid.entry(pid).or_default() += ts::TS_PACKET_LENGTH as u64;
+        if let Some(range) = pcr_pids.get_mut(&pid) {
+            if let Some(pcr) = packet.adaptation_field.as_ref().and_then(|af| af.pcr()) {
+                range.update(pcr);
+            }
+        }
+        if pid == psi::SDT_PID {
+            if let Some(tx) = &sdt_tx {
+                if tx.send(packet).await.is_err() {
+                    sdt_tx = None;
+                }
+            }
+        } else if pid == psi::NIT_PID {
+            if let Some(tx) = &nit_tx {
+                if tx.send(packet).await.is_err() {
+                    nit_tx = None;
+                }
+            }
+        } else if let Some(tx) = es_txs.get(&pid) {
+            if tx.send(packet).await.is_err() {
+                es_txs.remove(&pid);
+            }
+        }
+    }
+    drop(sdt_tx);
+    drop(nit_tx);
+    drop(es_txs);
+
+    let services = sdt_handle.await?;
+    let delivery_systems = nit_handle.await?;
+    let mut video_formats = BTreeMap::new();
+    for (pid, handle) in video_handles.into_iter() {
+        if let Some(format) = handle.await? {
+            video_formats.insert(pid, format);
+        }
+    }
+    let mut audio_formats = BTreeMap::new();
+    for (pid, handle) in audio_handles.into_iter() {
+        if let Some(format) = handle.await? {
+            audio_formats.insert(pid, format);
+        }
+    }
+
+    for program in programs.into_iter() {
+        let seconds = pcr_pids
+            .get(&program.pcr_pid)
+            .and_then(|range| range.seconds());
+        let pid_bit_rate = |pid| bit_rate(*bytes_per_pid.get(&pid).unwrap_or(&0), seconds);
+
+        let mut service_bytes = *bytes_per_pid.get(&program.pmt_pid).unwrap_or(&0);
+        if program.streams.iter().all(|es| es.pid != program.pcr_pid) {
+            service_bytes += *bytes_per_pid.get(&program.pcr_pid).unwrap_or(&0);
+        }
+        let mut video = Vec::new();
+        let mut audio = Vec::new();
+        let mut caption_pids = Vec::new();
+        let mut data_pids = Vec::new();
+        for es in program.streams.iter() {
+            let (pid, stream_type, caption) = (es.pid, es.stream_type, es.caption);
+            service_bytes += *bytes_per_pid.get(&pid).unwrap_or(&0);
+            if is_video(stream_type) {
+                let (format, scan) = match video_formats.get(&pid) {
+                    Some((format, scan)) => (Some(format), Some(scan)),
+                    None => (None, None),
+                };
+                let scan_type =
+                    scan.and_then(|s| s.scan_type(format.and_then(|f| f.progressive_sequence)));
+                video.push(VideoInfo {
+                    pid,
+                    stream_type,
+                    codec: codec_name(stream_type),
+                    width: format.map(|f| f.width),
+                    height: format.map(|f| f.height),
+                    aspect_ratio: format
+                        .and_then(|f| f.aspect_ratio)
+                        .map(|(w, h)| format!("{}:{}", w, h)),
+                    frame_rate: format
+                        .and_then(|f| f.frame_rate)
+                        .map(|(num, den)| f64::from(num) / f64::from(den)),
+                    scan_type,
+                    field_order: scan
+                        .filter(|_| matches!(scan_type, Some("interlaced" | "mixed")))
+                        .and_then(|s| s.field_order()),
+                    bit_rate: pid_bit_rate(pid),
+                });
+            } else if is_audio(stream_type) {
+                let format = audio_formats.get(&pid);
+                audio.push(AudioInfo {
+                    pid,
+                    stream_type,
+                    codec: codec_name(stream_type),
+                    profile: format.map(|f| f.profile),
+                    sample_rate: format.and_then(|f| f.sample_rate),
+                    channels: format.and_then(|f| f.channels),
+                    bit_rate: pid_bit_rate(pid),
+                });
+            } else if caption {
+                caption_pids.push(pid);
+            } else if is_data(stream_type) {
+                data_pids.push(pid);
+            }
+        }
+
+        let info = ServiceInfo {
+            service_id: program.program_number,
+            service_name: services
+                .get(&program.program_number)
+                .and_then(|service| service.name.clone()),
+            delivery_system: services
+                .get(&program.program_number)
+                .and_then(|service| delivery_systems.get(&service.transport_stream_id).cloned()),
+            pmt_pid: program.pmt_pid,
+            pcr_pid: program.pcr_pid,
+            video,
+            audio,
+            caption_pids,
+            data_pids,
+            bit_rate: bit_rate(service_bytes, seconds),
+        };
+        println!("{}", serde_json::to_string(&info)?);
+    }
+    Ok(())
+}
@@ -1,6 +1,44 @@
+#[cfg(feature = "b25")]
+pub mod b25;
+pub mod bitrate;
+#[cfg(feature = "browse")]
+pub mod browse;
+pub mod buffer_delay;
 pub mod caption;
+pub mod carousel;
+pub mod chapters;
 pub mod clean;
+pub mod cm;
 mod common;
+pub mod debug_caption;
+pub mod discontinuity;
+pub mod drcs;
+pub mod edit_pmt;
+pub mod epg_diff;
+pub mod errors;
 pub mod events;
+pub mod extract_audio;
+pub mod extract_es;
+pub mod filter;
+pub mod gop;
+pub mod hexdump;
+pub mod hls;
+pub mod index;
+pub mod inject_caption;
 mod io;
 pub mod jitter;
+pub mod logos;
+#[cfg(feature = "loudness")]
+pub mod loudness;
+pub mod mediainfo;
+mod monitor;
+pub mod mux_rate;
+pub mod pids;
+pub mod restamp;
+pub mod schema;
+pub mod serve;
+pub mod services;
+pub mod verify;
+
+pub use self::common::{normalizer, parse_pid, Normalization};
+pub use self::io::{set_limits, set_start, Limits, Start};
@@ -0,0 +1,159 @@
+use std::collections::BTreeMap;
+
+use log::info;
+use serde_derive::Serialize;
+use tokio_stream::{Stream, StreamExt};
+
+use super::common;
+use super::errors;
+use super::pids::{stream_role, well_known_role};
+use tstools::pes;
+use tstools::psi;
+use tstools::ts;
+
+// PID bitrates are measured over this in PCR, which paces live inputs and
+// files alike.
+const RATE_INTERVAL: f64 = 1.0;
+
+/// Packets of a PID seen by a monitor.
+#[derive(Serialize, Default)]
+pub struct PidStats {
+    pub pid: u16,
+    pub role: String,
+    pub packets: u64,
+    pub cc_errors: u64,
+    pub scrambled: u64,
+    // over the last RATE_INTERVAL.
+    pub bits_per_second: f64,
+    #[serde(skip)]
+    last_packets: u64,
+}
+
+impl PidStats {
+    fn count(&mut self, packet: &ts::TSPacket, gap: bool) {
+        self.packets += 1;
+        if packet.transport_scrambling_control != 0 {
+            self.scrambled += 1;
+        }
+        if gap {
+            self.cc_errors += 1;
+        }
+    }
+}
+
+/// Counts the packets of every PID of a live input, and measures their
+/// bitrates by the PCR of the first program.
+#[derive(Default)]
+pub struct Monitor {
+    pub packets: u64,
+    pub pids: BTreeMap<u16, PidStats>,
+    continuity: errors::Continuity,
+    // the PID whose PCR times rates, and the PCR the rates are last taken.
+    pcr_pid: Option<u16>,
+    last_pcr: Option<u64>,
+}
+
+impl Monitor {
+    /// Creates a monitor with the PIDs of the programs named by their roles.
+    pub fn new(programs: &[common::Program]) -> Monitor {
+        let mut monitor = Monitor {
+            pcr_pid: programs.first().map(|p| p.pcr_pid),
+            ..Default::default()
+        };
+        for program in programs.iter() {
+            let service_id = program.program_number;
+            monitor.pid(program.pmt_pid).role = format!("pmt {}", service_id);
+            monitor.pid(program.pcr_pid).role = format!("pcr {}", service_id);
+            for es in program.streams.iter() {
+                monitor.pid(es.pid).role = format!("{} {}", stream_role(es), service_id);
+            }
+        }
+        monitor
+    }
+
+    fn pid(&mut self, pid: u16) -> &mut PidStats {
+        self.pids.entry(pid).or_insert_with(|| PidStats {
+            pid,
+            role: String::from(well_known_role(pid).unwrap_or("unknown")),
+            ..Default::default()
+        })
+    }
+
+    pub fn count(&mut self, packet: &ts::TSPacket) {
+        self.packets += 1;
+        let gap = self.continuity.check(packet);
+        self.pid(packet.pid).count(packet, gap);
+        let pcr = match packet.adaptation_field {
+            Some(ref af) if Some(packet.pid) == self.pcr_pid => af.pcr(),
+            _ => None,
+        };
+        if let Some(pcr) = pcr {
+            self.update_rates(pcr);
+        }
+    }
+
+    fn update_rates(&mut self, pcr: u64) {
+        let base = pcr / (ts::PCR_HZ / pes::PTS_HZ);
+        let last = match self.last_pcr {
+            Some(last) => last / (ts::PCR_HZ / pes::PTS_HZ),
+            None => {
+                self.last_pcr = Some(pcr);
+                return;
+            }
+        };
+        let seconds = common::pts_diff(base, last);
+        if seconds < RATE_INTERVAL {
+            // a PCR going back is a discontinuity, where rates start over.
+            if seconds < 0.0 {
+                self.last_pcr = Some(pcr);
+            }
+            return;
+        }
+        for pid in self.pids.values_mut() {
+            let bits = (pid.packets - pid.last_packets) * ts::TS_PACKET_LENGTH as u64 * 8;
+            pid.bits_per_second = bits as f64 / seconds;
+            pid.last_packets = pid.packets;
+        }
+        self.last_pcr = Some(pcr);
+    }
+}
+
+/// Calls `update` with the service_id, the name and the provider name of
+/// each service in the SDTs of the actual stream.
+pub async fn watch_sdt<S, F>(s: S, mut update: F)
+where
+    S: Stream<Item = ts::TSPacket> + Unpin,
+    F: FnMut(u16, Option<String>, Option<String>),
+{
+    let mut buffer = psi::Buffer::new(s).verify_crc();
+    while let Some(bytes) = buffer.next().await {
+        let bytes = match bytes {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                info!("sdt buffer error: {:?}", e);
+                continue;
+            }
+        };
+        if bytes[0] != psi::SELF_STREAM_TABLE_ID {
+            continue;
+        }
+        let sdt = match psi::ServiceDescriptionSection::parse(&bytes[..]) {
+            Ok(sdt) => sdt,
+            Err(e) => {
+                info!("sdt parse error: {:?}", e);
+                continue;
+            }
+        };
+        for service in sdt.services.iter() {
+            for desc in service.descriptors.iter() {
+                if let psi::Descriptor::ServiceDescriptor(sd) = desc {
+                    update(
+                        service.service_id,
+                        common::decode_string(sd.service_name),
+                        common::decode_string(sd.service_provider_name),
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use log::info;
+use serde_derive::Serialize;
+use tokio_stream::StreamExt;
+
+use super::common;
+use super::io::{self, path_to_packets, RecordWriter};
+use tstools::ts;
+
+// PCR wraps around at 2^33 * 300.
+const PCR_MAX: u64 = (1 << 33) * 300;
+// PCR intervals longer than this are treated as discontinuities.
+const MAX_PCR_INTERVAL: u64 = ts::PCR_HZ;
+
+// an interval between PCRs whose rate is off the mean so far.
+#[derive(Serialize)]
+struct Deviation {
+    pid: u16,
+    offset: u64,
+    pcr: u64,
+    bit_rate: f64,
+    mean: f64,
+    // relative to the mean, in percent.
+    deviation: f64,
+}
+
+#[derive(Serialize)]
+struct Summary {
+    pid: u16,
+    samples: u64,
+    min: f64,
+    max: f64,
+    mean: f64,
+    deviations: u64,
+}
+
+struct PcrPid {
+    // offset and value of the latest PCR.
+    last: Option<(u64, u64)>,
+    // sum of bits and PCR ticks over the intervals, for the mean rate.
+    bits: u64,
+    ticks: u64,
+    samples: u64,
+    min: f64,
+    max: f64,
+    deviations: u64,
+}
+
+impl PcrPid {
+    fn mean(&self) -> f64 {
+        self.bits as f64 * ts::PCR_HZ as f64 / self.ticks as f64
+    }
+}
+
+/// Prints the mux rate derived from the bytes between PCRs of each PCR PID,
+/// with the intervals deviating from the mean so far by more than tolerance
+/// percent.
+pub async fn run(input: Option<PathBuf>, tolerance: f64) -> Result<()> {
+    if tolerance < 0.0 {
+        bail!("tolerance must not be negative: {}", tolerance);
+    }
+    let mut out = RecordWriter::stdout(io::is_live(input.as_slice()));
+    let packets = path_to_packets(input, false).await?;
+    let mut packets = common::interrupt_on_ctrl_c(common::strip_error_packets(packets));
+
+    let mut pids: BTreeMap<u16, PcrPid> = BTreeMap::new();
+    let mut offset = 0u64;
+    while let Some(packet) = packets.next().await {
+        let packet_offset = offset;
+        offset += ts::TS_PACKET_LENGTH as u64;
+        let pcr = match packet.adaptation_field.as_ref().and_then(|af| af.pcr()) {
+            Some(pcr) => pcr,
+            None => continue,
+        };
+        let pid = pids.entry(packet.pid).or_insert_with(|| PcrPid {
+            last: None,
+            bits: 0,
+            ticks: 0,
+            samples: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            deviations: 0,
+        });
+        let (prev_offset, prev_pcr) = match pid.last.replace((packet_offset, pcr)) {
+            Some(last) => last,
+            None => continue,
+        };
+        let interval = (pcr + PCR_MAX - prev_pcr) % PCR_MAX;
+        if interval == 0 || interval > MAX_PCR_INTERVAL {
+            info!(
+                "pcr discontinuity of pid {} at {}",
+                packet.pid, packet_offset
+            );
+            continue;
+        }
+        let bits = (packet_offset - prev_offset) * 8;
+        let rate = bits as f64 * ts::PCR_HZ as f64 / interval as f64;
+        if pid.samples > 0 {
+            let mean = pid.mean();
+            let deviation = (rate - mean) / mean * 100.0;
+            if deviation.abs() > tolerance {
+                pid.deviations += 1;
+                let d = Deviation {
+                    pid: packet.pid,
+                    offset: packet_offset,
+                    pcr,
+                    bit_rate: rate,
+                    mean,
+                    deviation,
+                };
+                out.write_line(&serde_json::to_string(&d)?)?;
+                out.end_batch()?;
+            }
+        }
+        pid.bits += bits;
+        pid.ticks += interval;
+        pid.samples += 1;
+        pid.min = pid.min.min(rate);
+        pid.max = pid.max.max(rate);
+    }
+
+    for (pid, stats) in pids.iter().filter(|(_, stats)| stats.samples > 0) {
+        let summary = Summary {
+            pid: *pid,
+            samples: stats.samples,
+            min: stats.min,
+            max: stats.max,
+            mean: stats.mean(),
+            deviations: stats.deviations,
+        };
+        out.write_line(&serde_json::to_string(&summary)?)?;
+    }
+    out.flush()
+}
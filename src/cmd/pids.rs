@@ -0,0 +1,117 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use serde_derive::Serialize;
+use tokio_stream::StreamExt;
+
+use super::common;
+use super::io::path_to_packets;
+use tstools::psi;
+use tstools::stream::cueable;
+use tstools::ts;
+
+const NIT_PID: u16 = 0x0010;
+
+#[derive(Serialize)]
+struct Role {
+    role: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    service_id: Option<u16>,
+}
+
+#[derive(Serialize)]
+struct Pid {
+    pid: u16,
+    roles: Vec<Role>,
+    packets: u64,
+    first_offset: u64,
+    last_offset: u64,
+}
+
+pub fn well_known_role(pid: u16) -> Option<&'static str> {
+    match pid {
+        ts::PAT_PID => Some("pat"),
+        ts::CAT_PID => Some("cat"),
+        NIT_PID => Some("nit"),
+        psi::SDT_PID => Some("sdt"),
+        psi::TOT_PID => Some("tot"),
+        ts::NULL_PID => Some("null"),
+        pid if ts::EIT_PIDS.contains(&pid) => Some("eit"),
+        _ => None,
+    }
+}
+
+pub fn stream_role(es: &common::ElementaryStream) -> &'static str {
+    match es.stream_type {
+        _ if es.caption => "caption",
+        psi::STREAM_TYPE_VIDEO | psi::STREAM_TYPE_H264 | psi::STREAM_TYPE_H265 => "video",
+        psi::STREAM_TYPE_ADTS => "audio",
+        _ => "data",
+    }
+}
+
+fn roles(programs: &[common::Program]) -> BTreeMap<u16, Vec<Role>> {
+    let mut roles: BTreeMap<u16, Vec<Role>> = BTreeMap::new();
+    for program in programs.iter() {
+        let service_id = Some(program.program_number);
+        roles.entry(program.pmt_pid).or_default().push(Role {
+            role: "pmt",
+            service_id,
+        });
+        roles.entry(program.pcr_pid).or_default().push(Role {
+            role: "pcr",
+            service_id,
+        });
+        for es in program.streams.iter() {
+            roles.entry(es.pid).or_default().push(Role {
+                role: stream_role(es),
+                service_id,
+            });
+        }
+    }
+    roles
+}
+
+pub async fn run(input: Option<PathBuf>) -> Result<()> {
+    let packets = path_to_packets(input, false).await?;
+    let packets = common::strip_error_packets(packets);
+    let mut cueable_packets = cueable(packets);
+    let programs = common::find_programs(&mut cueable_packets).await?;
+    let mut packets = cueable_packets.cue_up();
+    let mut service_roles = roles(&programs);
+
+    let mut pids: BTreeMap<u16, Pid> = BTreeMap::new();
+    let mut offset = 0u64;
+    while let Some(packet) = packets.next().await {
+        let pid = pids.entry(packet.pid).or_insert_with(|| Pid {
+            pid: packet.pid,
+            roles: Vec::new(),
+            packets: 0,
+            first_offset: offset,
+            last_offset: offset,
+        });
+        pid.packets += 1;
+        pid.last_offset = offset;
+        offset += ts::TS_PACKET_LENGTH as u64;
+    }
+
+    for (_, mut pid) in pids.into_iter() {
+        if let Some(role) = well_known_role(pid.pid) {
+            pid.roles.push(Role {
+                role,
+                service_id: None,
+            });
+        }
+        pid.roles
+            .extend(service_roles.remove(&pid.pid).unwrap_or_default());
+        if pid.roles.is_empty() {
+            pid.roles.push(Role {
+                role: "unknown",
+                service_id: None,
+            });
+        }
+        println!("{}", serde_json::to_string(&pid)?);
+    }
+    Ok(())
+}
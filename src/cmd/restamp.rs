@@ -0,0 +1,50 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use bytes::BytesMut;
+use tokio::io::AsyncWriteExt;
+use tokio_stream::StreamExt;
+
+use super::common;
+use super::io::{path_to_async_write, path_to_packets};
+use tstools::ts;
+
+const OUTPUT_CHUNK: usize = 1 << 20;
+
+/// Concatenates the inputs, rewriting PCR, PTS and DTS so that the output
+/// timeline starts at base seconds and runs on continuously across the
+/// joins.
+pub async fn run(input: Vec<PathBuf>, output: Option<PathBuf>, base: f64) -> Result<()> {
+    if base < 0.0 {
+        bail!("base must not be negative: {}", base);
+    }
+    let mut output = path_to_async_write(output).await?;
+    let mut restamper = ts::Restamper::new((base * ts::PCR_HZ as f64) as u64);
+    let inputs: Vec<Option<PathBuf>> = if input.is_empty() {
+        vec![None]
+    } else {
+        input.into_iter().map(Some).collect()
+    };
+    let mut chunk = BytesMut::with_capacity(OUTPUT_CHUNK);
+    for (i, input) in inputs.into_iter().enumerate() {
+        if i > 0 {
+            restamper.join();
+        }
+        let packets = path_to_packets(input, false).await?;
+        let mut packets = common::strip_error_packets(packets);
+        while let Some(packet) = packets.next().await {
+            for raw in restamper.push(packet) {
+                chunk.extend_from_slice(&raw[..]);
+            }
+            if chunk.len() + ts::TS_PACKET_LENGTH > OUTPUT_CHUNK {
+                output.write_all(&chunk.split()[..]).await?;
+            }
+        }
+    }
+    for raw in restamper.finish() {
+        chunk.extend_from_slice(&raw[..]);
+    }
+    output.write_all(&chunk[..]).await?;
+    output.flush().await?;
+    Ok(())
+}
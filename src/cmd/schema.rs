@@ -0,0 +1,33 @@
+use anyhow::Result;
+use clap::ValueEnum;
+
+use super::caption;
+use super::events;
+use super::jitter;
+
+/// JSON outputs described by a schema.
+#[derive(ValueEnum, Clone, Copy)]
+pub enum Output {
+    /// Events of events in the json format.
+    Events,
+    /// Captions of caption in the json format.
+    Caption,
+    /// Captions of caption in the json-rich format.
+    CaptionRich,
+    /// The jitter of jitter.
+    Jitter,
+    /// Drifts and the summary of jitter --continuous.
+    JitterContinuous,
+}
+
+pub fn run(output: Output) -> Result<()> {
+    let schema = match output {
+        Output::Events => events::schema(),
+        Output::Caption => caption::schema(caption::Format::Json),
+        Output::CaptionRich => caption::schema(caption::Format::JsonRich),
+        Output::Jitter => jitter::schema(false),
+        Output::JitterContinuous => jitter::schema(true),
+    };
+    println!("{}", serde_json::to_string_pretty(&schema)?);
+    Ok(())
+}
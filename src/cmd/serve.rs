@@ -0,0 +1,477 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use anyhow::{bail, Result};
+use log::{debug, info};
+use serde_derive::Serialize;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast;
+use tokio_stream::{Stream, StreamExt};
+
+use super::caption::{builtin_drcs_map, caption_text};
+use super::common;
+use super::errors;
+use super::events::{update_now_playing, NowPlaying};
+use super::io::path_to_packets;
+use super::monitor::{self, Monitor, PidStats};
+use super::pids::stream_role;
+use tstools::pes;
+use tstools::psi;
+use tstools::stream::cueable;
+use tstools::ts;
+
+// captions kept for a slow client of /captions, which misses older ones.
+const CAPTION_BACKLOG: usize = 256;
+// requests are only a request line and a few headers.
+const MAX_REQUEST_LENGTH: usize = 8 * 1024;
+
+#[derive(Serialize)]
+struct ServiceStream {
+    pid: u16,
+    stream_type: u8,
+    role: &'static str,
+}
+
+#[derive(Serialize)]
+struct Service {
+    service_id: u16,
+    name: Option<String>,
+    provider_name: Option<String>,
+    pmt_pid: u16,
+    pcr_pid: u16,
+    streams: Vec<ServiceStream>,
+    captions: u64,
+}
+
+#[derive(Serialize)]
+struct Caption<'a> {
+    service_id: u16,
+    pts: Option<u64>,
+    caption: &'a str,
+}
+
+#[derive(Serialize)]
+struct Stats<'a> {
+    packets: u64,
+    cc_errors: u64,
+    scrambled: u64,
+    // whether the input has ended.
+    done: bool,
+    error: Option<&'a str>,
+    pids: Vec<&'a PidStats>,
+}
+
+#[derive(Default)]
+struct State {
+    monitor: Monitor,
+    done: bool,
+    error: Option<String>,
+    services: BTreeMap<u16, Service>,
+    events: BTreeMap<u16, NowPlaying>,
+}
+
+impl State {
+    fn stats(&self) -> Stats<'_> {
+        Stats {
+            packets: self.monitor.packets,
+            cc_errors: self.monitor.pids.values().map(|pid| pid.cc_errors).sum(),
+            scrambled: self.monitor.pids.values().map(|pid| pid.scrambled).sum(),
+            done: self.done,
+            error: self.error.as_deref(),
+            pids: self.monitor.pids.values().collect(),
+        }
+    }
+}
+
+// writes a metric family in the Prometheus text format.
+#[cfg(feature = "metrics")]
+fn write_metric<I: Iterator<Item = (String, f64)>>(
+    out: &mut String,
+    name: &str,
+    kind: &str,
+    help: &str,
+    samples: I,
+) {
+    use std::fmt::Write;
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+    for (labels, value) in samples {
+        let _ = writeln!(out, "{}{} {}", name, labels, value);
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl State {
+    fn metrics(&self) -> String {
+        let mut out = String::new();
+        let pid_label = |pid: &u16| format!("{{pid=\"{:#06x}\"}}", pid);
+        let service_label = |service_id: &u16| format!("{{service_id=\"{}\"}}", service_id);
+        write_metric(
+            &mut out,
+            "tstools_packets_total",
+            "counter",
+            "Packets read from the input.",
+            std::iter::once((String::new(), self.monitor.packets as f64)),
+        );
+        write_metric(
+            &mut out,
+            "tstools_pid_packets_total",
+            "counter",
+            "Packets of each PID.",
+            self.monitor
+                .pids
+                .iter()
+                .map(|(pid, stats)| (pid_label(pid), stats.packets as f64)),
+        );
+        write_metric(
+            &mut out,
+            "tstools_cc_errors_total",
+            "counter",
+            "Continuity counter errors of each PID.",
+            self.monitor
+                .pids
+                .iter()
+                .map(|(pid, stats)| (pid_label(pid), stats.cc_errors as f64)),
+        );
+        write_metric(
+            &mut out,
+            "tstools_scrambled_packets_total",
+            "counter",
+            "Scrambled packets of each PID.",
+            self.monitor
+                .pids
+                .iter()
+                .map(|(pid, stats)| (pid_label(pid), stats.scrambled as f64)),
+        );
+        write_metric(
+            &mut out,
+            "tstools_service_bits_per_second",
+            "gauge",
+            "Bitrate of the PMT, PCR and elementary streams of each service.",
+            self.services
+                .iter()
+                .map(|(service_id, service)| (service_label(service_id), self.bitrate(service))),
+        );
+        write_metric(
+            &mut out,
+            "tstools_captions_total",
+            "counter",
+            "Captions of each service.",
+            self.services
+                .iter()
+                .map(|(service_id, service)| (service_label(service_id), service.captions as f64)),
+        );
+        write_metric(
+            &mut out,
+            "tstools_input_done",
+            "gauge",
+            "Whether the input has ended.",
+            std::iter::once((String::new(), if self.done { 1.0 } else { 0.0 })),
+        );
+        out
+    }
+
+    fn bitrate(&self, service: &Service) -> f64 {
+        let mut pids: Vec<u16> = service.streams.iter().map(|es| es.pid).collect();
+        pids.extend([service.pmt_pid, service.pcr_pid]);
+        pids.sort_unstable();
+        pids.dedup();
+        pids.iter()
+            .filter_map(|pid| self.monitor.pids.get(pid))
+            .map(|pid| pid.bits_per_second)
+            .sum()
+    }
+}
+
+type Shared = Arc<Mutex<State>>;
+
+fn lock(state: &Shared) -> MutexGuard<'_, State> {
+    // a panicked reader leaves the state as consistent as a live input.
+    state.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn new_state(programs: &[common::Program]) -> State {
+    let mut state = State {
+        monitor: Monitor::new(programs),
+        ..Default::default()
+    };
+    for program in programs.iter() {
+        let service_id = program.program_number;
+        state.services.insert(
+            service_id,
+            Service {
+                service_id,
+                name: None,
+                provider_name: None,
+                pmt_pid: program.pmt_pid,
+                pcr_pid: program.pcr_pid,
+                streams: program
+                    .streams
+                    .iter()
+                    .map(|es| ServiceStream {
+                        pid: es.pid,
+                        stream_type: es.stream_type,
+                        role: stream_role(es),
+                    })
+                    .collect(),
+                captions: 0,
+            },
+        );
+        state.events.insert(service_id, NowPlaying::new(service_id));
+    }
+    state
+}
+
+fn count_packet(state: &Shared, packet: &ts::TSPacket) {
+    lock(state).monitor.count(packet);
+}
+
+async fn watch_sdt<S: Stream<Item = ts::TSPacket> + Unpin>(s: S, state: Shared) {
+    monitor::watch_sdt(s, |service_id, name, provider_name| {
+        if let Some(entry) = lock(&state).services.get_mut(&service_id) {
+            entry.name = name;
+            entry.provider_name = provider_name;
+        }
+    })
+    .await
+}
+
+async fn watch_eit<S: Stream<Item = ts::TSPacket> + Unpin>(s: S, state: Shared) {
+    let mut buffer = psi::Buffer::new(s).verify_crc();
+    while let Some(bytes) = buffer.next().await {
+        match bytes {
+            Ok(bytes) => update_now_playing(&mut lock(&state).events, &bytes[..]),
+            Err(e) => info!("eit buffer error: {:?}", e),
+        }
+    }
+}
+
+async fn watch_captions<S: Stream<Item = ts::TSPacket> + Unpin>(
+    service_id: u16,
+    s: S,
+    state: Shared,
+    captions: broadcast::Sender<String>,
+) -> Result<()> {
+    let drcs_map = builtin_drcs_map();
+    let mut buffer = pes::Buffer::new(s);
+    while let Some(bytes) = buffer.next().await {
+        let bytes = match bytes {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                info!("pes buffer error: {:?}", e);
+                continue;
+            }
+        };
+        let pes = match pes::PESPacket::parse(&bytes[..]) {
+            Ok(pes) => pes,
+            Err(e) => {
+                info!("pes parse error: {:?}", e);
+                errors::count(errors::Kind::Pes);
+                continue;
+            }
+        };
+        let text = match caption_text(&pes, &drcs_map) {
+            Some(text) if !text.trim().is_empty() => text,
+            _ => continue,
+        };
+        if let Some(service) = lock(&state).services.get_mut(&service_id) {
+            service.captions += 1;
+        }
+        let caption = Caption {
+            service_id,
+            pts: pes.get_pts(),
+            caption: &text,
+        };
+        // no client may be listening.
+        let _ = captions.send(serde_json::to_string(&caption)?);
+    }
+    Ok(())
+}
+
+// reads the input to the end, updating the state.
+async fn read<S: Stream<Item = ts::TSPacket> + Unpin>(
+    packets: S,
+    programs: Vec<common::Program>,
+    state: Shared,
+    captions: broadcast::Sender<String>,
+) -> Result<()> {
+    let mut demuxer = ts::Demuxer::new();
+    let mut tasks = vec![tokio::spawn(watch_sdt(
+        demuxer.register(psi::SDT_PID),
+        state.clone(),
+    ))];
+    for pid in ts::EIT_PIDS.iter() {
+        tasks.push(tokio::spawn(watch_eit(
+            demuxer.register(*pid),
+            state.clone(),
+        )));
+    }
+    let mut caption_tasks = Vec::new();
+    for program in programs.iter() {
+        for es in program.streams.iter().filter(|es| es.caption) {
+            caption_tasks.push(tokio::spawn(watch_captions(
+                program.program_number,
+                demuxer.register(es.pid),
+                state.clone(),
+                captions.clone(),
+            )));
+        }
+    }
+    let counter = state.clone();
+    demuxer
+        .run(packets.map(move |packet| {
+            count_packet(&counter, &packet);
+            packet
+        }))
+        .await;
+    for task in tasks.into_iter() {
+        task.await?;
+    }
+    for task in caption_tasks.into_iter() {
+        task.await??;
+    }
+    Ok(())
+}
+
+// reads the request line and the headers, and returns the method and the
+// path without the query.
+async fn read_request(stream: &mut TcpStream) -> Result<(String, String)> {
+    let mut request = Vec::new();
+    let mut buf = [0u8; 1024];
+    while memchr::memmem::find(&request, b"\r\n\r\n").is_none() {
+        if request.len() > MAX_REQUEST_LENGTH {
+            bail!("too long request");
+        }
+        let n = stream.read(&mut buf).await?;
+        if n == 0 {
+            bail!("closed before the end of the request");
+        }
+        request.extend_from_slice(&buf[..n]);
+    }
+    let end = memchr::memmem::find(&request, b"\r\n").unwrap_or(request.len());
+    let line = std::str::from_utf8(&request[..end])?;
+    let mut parts = line.split(' ');
+    match (parts.next(), parts.next()) {
+        (Some(method), Some(target)) => {
+            let path = target.split('?').next().unwrap_or(target);
+            Ok((String::from(method), String::from(path)))
+        }
+        _ => bail!("bad request line: {:?}", line),
+    }
+}
+
+async fn respond(
+    stream: &mut TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &[u8],
+) -> Result<()> {
+    let header = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        content_type,
+        body.len()
+    );
+    stream.write_all(header.as_bytes()).await?;
+    stream.write_all(body).await?;
+    Ok(())
+}
+
+// sends captions as server-sent events until the client disconnects.
+async fn send_captions(
+    stream: &mut TcpStream,
+    mut captions: broadcast::Receiver<String>,
+) -> Result<()> {
+    let header = "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\n";
+    stream.write_all(header.as_bytes()).await?;
+    loop {
+        let caption = match captions.recv().await {
+            Ok(caption) => caption,
+            Err(broadcast::error::RecvError::Lagged(n)) => {
+                info!("a client missed {} captions", n);
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => return Ok(()),
+        };
+        stream
+            .write_all(format!("data: {}\n\n", caption).as_bytes())
+            .await?;
+    }
+}
+
+async fn handle(
+    mut stream: TcpStream,
+    state: Shared,
+    captions: broadcast::Sender<String>,
+) -> Result<()> {
+    let (method, path) = read_request(&mut stream).await?;
+    debug!("{} {}", method, path);
+    if method != "GET" {
+        let body = b"method not allowed\n";
+        return respond(&mut stream, "405 Method Not Allowed", "text/plain", body).await;
+    }
+    let body = match path.as_str() {
+        "/services" => serde_json::to_vec(&lock(&state).services.values().collect::<Vec<_>>())?,
+        "/events" => serde_json::to_vec(&lock(&state).events.values().collect::<Vec<_>>())?,
+        "/stats" => serde_json::to_vec(&lock(&state).stats())?,
+        "/captions" => return send_captions(&mut stream, captions.subscribe()).await,
+        #[cfg(feature = "metrics")]
+        "/metrics" => {
+            let body = lock(&state).metrics();
+            let content_type = "text/plain; version=0.0.4";
+            return respond(&mut stream, "200 OK", content_type, body.as_bytes()).await;
+        }
+        _ => {
+            let body = b"not found\n";
+            return respond(&mut stream, "404 Not Found", "text/plain", body).await;
+        }
+    };
+    respond(&mut stream, "200 OK", "application/json", &body).await
+}
+
+/// Reads the input and serves its services, present and following events,
+/// captions and packet statistics over HTTP until interrupted.
+pub async fn run(input: Option<PathBuf>, listen: String) -> Result<()> {
+    let listener = TcpListener::bind(&listen).await?;
+    info!("serve on {}", listener.local_addr()?);
+    let packets = path_to_packets(input, false).await?;
+    let packets = common::strip_error_packets(packets);
+    let mut cueable_packets = cueable(packets);
+    let programs = common::find_programs(&mut cueable_packets).await?;
+    let packets = cueable_packets.cue_up();
+
+    let state = Arc::new(Mutex::new(new_state(&programs)));
+    let (captions, _) = broadcast::channel(CAPTION_BACKLOG);
+    let reader_state = state.clone();
+    let reader_captions = captions.clone();
+    let reader = tokio::spawn(async move {
+        let result = read(packets, programs, reader_state.clone(), reader_captions).await;
+        let mut state = lock(&reader_state);
+        state.done = true;
+        state.error = result.err().map(|e| e.to_string());
+        info!("input done");
+    });
+
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+    loop {
+        let (stream, peer) = tokio::select! {
+            accepted = listener.accept() => accepted?,
+            _ = &mut ctrl_c => {
+                info!("interrupted");
+                break;
+            }
+        };
+        let state = state.clone();
+        let captions = captions.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle(stream, state, captions).await {
+                info!("{}: {:?}", peer, e);
+            }
+        });
+    }
+    reader.abort();
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use serde_derive::Serialize;
+
+use super::common;
+use super::io::path_to_packets;
+use tstools::psi;
+use tstools::stream::cueable;
+use tstools::ts;
+
+#[derive(Serialize)]
+struct Stream {
+    stream_type: u8,
+    pid: u16,
+    component_tag: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    copy_control: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+struct Service {
+    service_id: u16,
+    name: Option<String>,
+    provider_name: Option<String>,
+    service_type: Option<u8>,
+    pmt_pid: u16,
+    pcr_pid: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    copy_control: Option<&'static str>,
+    delivery_system: Option<common::DeliverySystem>,
+    streams: Vec<Stream>,
+}
+
+pub async fn run(input: Option<PathBuf>) -> Result<()> {
+    let packets = path_to_packets(input, false).await?;
+    let packets = common::strip_error_packets(packets);
+    let mut cueable_packets = cueable(packets);
+    let programs = common::find_programs(&mut cueable_packets).await?;
+    let packets = cueable_packets.cue_up();
+    let mut demuxer = ts::Demuxer::new();
+    let descriptions = common::find_service_descriptions(demuxer.register(psi::SDT_PID));
+    let delivery_systems = common::find_delivery_systems(demuxer.register(psi::NIT_PID));
+    let (_, mut descriptions, delivery_systems) =
+        tokio::join!(demuxer.run(packets), descriptions, delivery_systems);
+
+    for program in programs.into_iter() {
+        let description = descriptions.remove(&program.program_number);
+        let delivery_system = description
+            .as_ref()
+            .and_then(|d| delivery_systems.get(&d.transport_stream_id).cloned());
+        let service = Service {
+            service_id: program.program_number,
+            service_type: description.as_ref().map(|d| d.service_type),
+            provider_name: description.as_ref().and_then(|d| d.provider_name.clone()),
+            name: description.and_then(|d| d.name),
+            pmt_pid: program.pmt_pid,
+            pcr_pid: program.pcr_pid,
+            copy_control: program.copy_control,
+            delivery_system,
+            streams: program
+                .streams
+                .into_iter()
+                .map(|es| Stream {
+                    stream_type: es.stream_type,
+                    pid: es.pid,
+                    component_tag: es.component_tag,
+                    copy_control: es.copy_control,
+                })
+                .collect(),
+        };
+        println!("{}", serde_json::to_string(&service)?);
+    }
+    Ok(())
+}
@@ -0,0 +1,159 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use log::info;
+use serde_derive::Serialize;
+use tokio_stream::StreamExt;
+
+use super::common;
+use super::io::path_to_packets;
+use tstools::psi;
+use tstools::stream::cueable;
+use tstools::ts;
+
+// PIDs up to this are reserved for PSI and SI tables.
+const LAST_SI_PID: u16 = 0x1f;
+// PIDs carrying sections besides PMTs, whose CRC_32 is checked.
+const SECTION_PIDS: [u16; 9] = [
+    ts::PAT_PID,
+    ts::CAT_PID,
+    psi::NIT_PID,
+    psi::SDT_PID,
+    ts::EIT_PIDS[0],
+    psi::TOT_PID,
+    ts::EIT_PIDS[1],
+    ts::EIT_PIDS[2],
+    psi::CDT_PID,
+];
+const PCR_CYCLE: u64 = (1 << 33) * 300;
+
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum Problem {
+    /// A PID referenced by the PAT or a PMT does not appear.
+    MissingPid { pid: u16, referenced_by: String },
+    /// Sections of the PID failed the CRC check.
+    CrcError { pid: u16, sections: usize },
+    /// The PCR went backwards other than wrapping around.
+    PcrBackward {
+        pid: u16,
+        packet: u64,
+        previous: u64,
+        current: u64,
+    },
+    /// A PID which no table refers to.
+    OrphanPid { pid: u16, packets: u64 },
+}
+
+#[derive(Serialize)]
+struct Summary {
+    packets: u64,
+    pids: usize,
+    problems: usize,
+}
+
+fn referenced_pids(programs: &[common::Program]) -> BTreeMap<u16, String> {
+    let mut pids = BTreeMap::new();
+    for program in programs.iter() {
+        pids.entry(program.pmt_pid)
+            .or_insert_with(|| String::from("pat"));
+        let pmt = format!("pmt of program {}", program.program_number);
+        // a program without PCR has the null PID as PCR_PID.
+        if program.pcr_pid != ts::NULL_PID {
+            pids.entry(program.pcr_pid).or_insert_with(|| pmt.clone());
+        }
+        for es in program.streams.iter() {
+            pids.entry(es.pid).or_insert_with(|| pmt.clone());
+        }
+    }
+    pids
+}
+
+// a backward PCR is a wraparound if it is far behind the previous one.
+fn is_backward(previous: u64, current: u64) -> bool {
+    current < previous && previous - current < PCR_CYCLE / 2
+}
+
+pub async fn run(input: Option<PathBuf>) -> Result<()> {
+    let packets = path_to_packets(input, false).await?;
+    let packets = common::strip_error_packets(packets);
+    let mut cueable_packets = cueable(packets);
+    let programs = common::find_programs(&mut cueable_packets).await?;
+    let mut packets = cueable_packets.cue_up();
+
+    let references = referenced_pids(&programs);
+    let mut section_buffers: BTreeMap<u16, psi::Buffer<()>> = SECTION_PIDS
+        .iter()
+        .chain(programs.iter().map(|p| &p.pmt_pid))
+        .map(|pid| (*pid, psi::Buffer::detached().verify_crc()))
+        .collect();
+    let mut last_pcrs: HashMap<u16, Option<u64>> =
+        programs.iter().map(|p| (p.pcr_pid, None)).collect();
+    let mut counts: BTreeMap<u16, u64> = BTreeMap::new();
+    let mut problems = Vec::new();
+    let mut n = 0;
+    while let Some(packet) = packets.next().await {
+        let pid = packet.pid;
+        *counts.entry(pid).or_default() += 1;
+        if let Some(last) = last_pcrs.get_mut(&pid) {
+            if let Some(pcr) = packet.adaptation_field.as_ref().and_then(|af| af.pcr()) {
+                if let Some(previous) = last.filter(|previous| is_backward(*previous, pcr)) {
+                    problems.push(Problem::PcrBackward {
+                        pid,
+                        packet: n,
+                        previous,
+                        current: pcr,
+                    });
+                }
+                *last = Some(pcr);
+            }
+        }
+        if let Some(buffer) = section_buffers.get_mut(&pid) {
+            if let Err(e) = buffer.feed(packet) {
+                info!("pid {:#x}: {:?}", pid, e);
+            }
+            while buffer.next_section().is_some() {}
+        }
+        n += 1;
+    }
+
+    for (pid, referenced_by) in references.iter() {
+        if !counts.contains_key(pid) {
+            problems.push(Problem::MissingPid {
+                pid: *pid,
+                referenced_by: referenced_by.clone(),
+            });
+        }
+    }
+    for (pid, buffer) in section_buffers.iter() {
+        if buffer.crc_errors() > 0 {
+            problems.push(Problem::CrcError {
+                pid: *pid,
+                sections: buffer.crc_errors(),
+            });
+        }
+    }
+    for (pid, packets) in counts.iter() {
+        if *pid > LAST_SI_PID && *pid != ts::NULL_PID && !references.contains_key(pid) {
+            problems.push(Problem::OrphanPid {
+                pid: *pid,
+                packets: *packets,
+            });
+        }
+    }
+
+    for problem in problems.iter() {
+        println!("{}", serde_json::to_string(problem)?);
+    }
+    let summary = Summary {
+        packets: n,
+        pids: counts.len(),
+        problems: problems.len(),
+    };
+    println!("{}", serde_json::to_string(&summary)?);
+    if !problems.is_empty() {
+        bail!("{} problems found", problems.len());
+    }
+    Ok(())
+}
@@ -1,5 +1,7 @@
-const CRC32_TABLE: [u32; 256] = {
-    let mut table = [0u32; 256];
+// tables for slicing-by-16. CRC32_TABLES[0] is the table for a byte, and
+// CRC32_TABLES[k] advances it by k more zero bytes.
+const CRC32_TABLES: [[u32; 256]; 16] = {
+    let mut tables = [[0u32; 256]; 16];
     let mut i = 0;
     while i < 256 {
         let mut crc = (i as u32) << 24;
@@ -12,18 +14,48 @@ const CRC32_TABLE: [u32; 256] = {
             }
             n += 1;
         }
-        table[i] = crc;
+        tables[0][i] = crc;
         i += 1;
     }
-    table
+    let mut k = 1;
+    while k < 16 {
+        let mut i = 0;
+        while i < 256 {
+            let prev = tables[k - 1][i];
+            tables[k][i] = (prev << 8) ^ tables[0][(prev >> 24) as usize];
+            i += 1;
+        }
+        k += 1;
+    }
+    tables
 };
 
-#[allow(dead_code)]
 pub fn crc32(data: &[u8]) -> u32 {
+    let t = &CRC32_TABLES;
     let mut crc: u32 = 0xffffffff;
-    for x in data.iter() {
+    let mut chunks = data.chunks_exact(16);
+    for b in chunks.by_ref() {
+        let a = crc ^ u32::from_be_bytes([b[0], b[1], b[2], b[3]]);
+        crc = t[15][(a >> 24) as usize]
+            ^ t[14][((a >> 16) & 0xff) as usize]
+            ^ t[13][((a >> 8) & 0xff) as usize]
+            ^ t[12][(a & 0xff) as usize]
+            ^ t[11][b[4] as usize]
+            ^ t[10][b[5] as usize]
+            ^ t[9][b[6] as usize]
+            ^ t[8][b[7] as usize]
+            ^ t[7][b[8] as usize]
+            ^ t[6][b[9] as usize]
+            ^ t[5][b[10] as usize]
+            ^ t[4][b[11] as usize]
+            ^ t[3][b[12] as usize]
+            ^ t[2][b[13] as usize]
+            ^ t[1][b[14] as usize]
+            ^ t[0][b[15] as usize];
+    }
+    for x in chunks.remainder().iter() {
         let i = ((crc >> 24) as u8) ^ x;
-        crc = CRC32_TABLE[i as usize] ^ (crc << 8);
+        crc = t[0][i as usize] ^ (crc << 8);
     }
-    return crc;
+    crc
 }
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use crate::dsmcc::{
+    Compression, DownloadDataBlock, DownloadInfoIndication, Error, ModuleInfo, Result,
+    DOWNLOAD_DATA_TABLE_ID, DOWNLOAD_INFO_TABLE_ID, MESSAGE_ID_DSI,
+};
+
+/// A module whose blocks are all received.
+#[derive(Debug, Clone)]
+pub struct Module {
+    pub download_id: u32,
+    pub module_id: u16,
+    pub module_version: u8,
+    pub module_type: Option<String>,
+    pub name: Option<String>,
+    pub compression: Option<Compression>,
+    /// The module as transmitted, still compressed if `compression` is set.
+    pub data: Vec<u8>,
+}
+
+struct ModuleState {
+    module: Module,
+    module_size: usize,
+    block_size: usize,
+    blocks: Vec<Option<Vec<u8>>>,
+    received: usize,
+    completed: bool,
+}
+
+impl ModuleState {
+    fn new(download_id: u32, block_size: u16, info: &ModuleInfo) -> ModuleState {
+        let text = |bytes: Option<&[u8]>| bytes.map(|b| String::from_utf8_lossy(b).into_owned());
+        let module_size = info.module_size as usize;
+        let block_size = usize::from(block_size);
+        ModuleState {
+            module: Module {
+                download_id,
+                module_id: info.module_id,
+                module_version: info.module_version,
+                module_type: text(info.module_type),
+                name: text(info.name),
+                compression: info.compression,
+                data: Vec::new(),
+            },
+            module_size,
+            block_size,
+            blocks: vec![None; module_size.div_ceil(block_size)],
+            received: 0,
+            completed: false,
+        }
+    }
+
+    fn complete(&mut self) -> Module {
+        let mut data = Vec::with_capacity(self.module_size);
+        for block in self.blocks.iter_mut() {
+            data.extend_from_slice(&block.take().unwrap_or_default());
+        }
+        data.truncate(self.module_size);
+        self.completed = true;
+        Module {
+            data,
+            ..self.module.clone()
+        }
+    }
+}
+
+/// Reassembles modules of data carousels from DII and DDB sections.
+///
+/// A module is emitted once per version, and is received again when a DII
+/// announces a new version of it.
+#[derive(Default)]
+pub struct Carousel {
+    modules: HashMap<(u32, u16), ModuleState>,
+}
+
+impl Carousel {
+    pub fn new() -> Carousel {
+        Carousel::default()
+    }
+
+    /// Registers the modules announced by a DII. Empty modules are returned
+    /// as completed.
+    pub fn update_info(&mut self, dii: &DownloadInfoIndication) -> Vec<Module> {
+        let mut completed = Vec::new();
+        for info in dii.modules.iter() {
+            let key = (dii.download_id, info.module_id);
+            if let Some(state) = self.modules.get(&key) {
+                if state.module.module_version == info.module_version {
+                    continue;
+                }
+            }
+            let mut state = ModuleState::new(dii.download_id, dii.block_size, info);
+            if state.blocks.is_empty() {
+                completed.push(state.complete());
+            }
+            self.modules.insert(key, state);
+        }
+        completed
+    }
+
+    /// Stores a block, and returns the module if it is the last missing one.
+    /// Blocks of unknown modules are dropped until their DII arrives.
+    pub fn push_block(&mut self, ddb: &DownloadDataBlock) -> Option<Module> {
+        let state = self.modules.get_mut(&(ddb.download_id, ddb.module_id))?;
+        if state.completed || state.module.module_version != ddb.module_version {
+            return None;
+        }
+        let block = state.blocks.get_mut(usize::from(ddb.block_number))?;
+        if block.is_some() || ddb.block_data.len() > state.block_size {
+            return None;
+        }
+        *block = Some(ddb.block_data.to_vec());
+        state.received += 1;
+        if state.received == state.blocks.len() {
+            Some(state.complete())
+        } else {
+            None
+        }
+    }
+
+    /// Dispatches a section of a data carousel PID.
+    pub fn feed_section(&mut self, section: &[u8]) -> Result<Vec<Module>> {
+        match section.first() {
+            Some(&DOWNLOAD_INFO_TABLE_ID) => {
+                let dii = match DownloadInfoIndication::parse(section) {
+                    Ok(dii) => dii,
+                    // DSI is not used by ARIB data carousels.
+                    Err(Error::InvalidMessageId(MESSAGE_ID_DSI)) => return Ok(Vec::new()),
+                    Err(e) => return Err(e),
+                };
+                Ok(self.update_info(&dii))
+            }
+            Some(&DOWNLOAD_DATA_TABLE_ID) => {
+                let ddb = DownloadDataBlock::parse(section)?;
+                Ok(self.push_block(&ddb).into_iter().collect())
+            }
+            _ => Ok(Vec::new()),
+        }
+    }
+}
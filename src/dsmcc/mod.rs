@@ -0,0 +1,230 @@
+//! DSM-CC data carousel of ARIB STD-B24 volume 3.
+//!
+//! A carousel repeats a DownloadInfoIndication (DII), which lists modules,
+//! and DownloadDataBlocks (DDB), which carry the modules split into blocks.
+
+use crate::util;
+
+mod carousel;
+pub use self::carousel::*;
+
+mod resource;
+pub use self::resource::*;
+
+pub const DOWNLOAD_INFO_TABLE_ID: u8 = 0x3b;
+pub const DOWNLOAD_DATA_TABLE_ID: u8 = 0x3c;
+
+pub const PROTOCOL_DISCRIMINATOR: u8 = 0x11;
+pub const DSMCC_TYPE_DOWNLOAD: u8 = 0x03;
+pub const MESSAGE_ID_DII: u16 = 0x1002;
+pub const MESSAGE_ID_DDB: u16 = 0x1003;
+pub const MESSAGE_ID_DSI: u16 = 0x1006;
+
+const TYPE_DESCRIPTOR: u8 = 0x01;
+const NAME_DESCRIPTOR: u8 = 0x02;
+const COMPRESSION_TYPE_DESCRIPTOR: u8 = 0xc2;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("too short {len}, expect {expr}({expected})")]
+    TooShort {
+        len: usize,
+        expected: usize,
+        expr: &'static str,
+    },
+    #[error("invalid table_id: {0}")]
+    InvalidTableId(u8),
+    #[error("invalid protocol discriminator: {0}")]
+    InvalidProtocolDiscriminator(u8),
+    #[error("invalid message id: 0x{0:x}")]
+    InvalidMessageId(u16),
+    #[error("invalid block size: {0}")]
+    InvalidBlockSize(u16),
+    #[error("invalid multipart entity")]
+    InvalidMultipart,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+struct MessageHeader<'a> {
+    message_id: u16,
+    // transaction_id for DII, download_id for DDB.
+    id: u32,
+    message: &'a [u8],
+}
+
+// parses the section header and the dsmccMessageHeader or
+// dsmccDownloadDataHeader, returning the message body following them.
+fn parse_section(bytes: &[u8], expected_table_id: u8) -> Result<MessageHeader<'_>> {
+    check_len!(bytes.len(), 8);
+    let table_id = bytes[0];
+    if table_id != expected_table_id {
+        return Err(Error::InvalidTableId(table_id));
+    }
+    let section_length = (usize::from(bytes[1] & 0xf) << 8) | usize::from(bytes[2]);
+    check_len!(bytes.len(), 3 + section_length);
+    check_len!(section_length, 9);
+    // CRC_32 or checksum.
+    let end = 3 + section_length - 4;
+    let bytes = &bytes[8..end];
+
+    check_len!(bytes.len(), 12);
+    let protocol_discriminator = bytes[0];
+    if protocol_discriminator != PROTOCOL_DISCRIMINATOR || bytes[1] != DSMCC_TYPE_DOWNLOAD {
+        return Err(Error::InvalidProtocolDiscriminator(protocol_discriminator));
+    }
+    let message_id = (u16::from(bytes[2]) << 8) | u16::from(bytes[3]);
+    let id = util::read_u32(&bytes[4..]);
+    let adaptation_length = usize::from(bytes[9]);
+    let message_length = (usize::from(bytes[10]) << 8) | usize::from(bytes[11]);
+    check_len!(bytes.len(), 12 + message_length);
+    check_len!(message_length, adaptation_length);
+    let message = &bytes[12 + adaptation_length..12 + message_length];
+    Ok(MessageHeader {
+        message_id,
+        id,
+        message,
+    })
+}
+
+/// Compression_Type_descriptor of a module.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct Compression {
+    /// 0 is zlib.
+    pub compression_type: u8,
+    pub original_size: u32,
+}
+
+/// A module entry of a DII.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct ModuleInfo<'a> {
+    pub module_id: u16,
+    pub module_size: u32,
+    pub module_version: u8,
+    /// text_char of Type_descriptor, the media type of the module.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub module_type: Option<&'a [u8]>,
+    /// text_char of Name_descriptor.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub name: Option<&'a [u8]>,
+    pub compression: Option<Compression>,
+}
+
+impl ModuleInfo<'_> {
+    // returns the module info and the consumed length.
+    fn parse(bytes: &[u8]) -> Result<(ModuleInfo<'_>, usize)> {
+        check_len!(bytes.len(), 8);
+        let module_id = (u16::from(bytes[0]) << 8) | u16::from(bytes[1]);
+        let module_size = util::read_u32(&bytes[2..]);
+        let module_version = bytes[6];
+        let module_info_length = usize::from(bytes[7]);
+        check_len!(bytes.len(), 8 + module_info_length);
+        let mut info = ModuleInfo {
+            module_id,
+            module_size,
+            module_version,
+            module_type: None,
+            name: None,
+            compression: None,
+        };
+        let mut rest = &bytes[8..8 + module_info_length];
+        while !rest.is_empty() {
+            check_len!(rest.len(), 2);
+            let tag = rest[0];
+            let length = usize::from(rest[1]);
+            check_len!(rest.len(), 2 + length);
+            let body = &rest[2..2 + length];
+            match tag {
+                TYPE_DESCRIPTOR => info.module_type = Some(body),
+                NAME_DESCRIPTOR => info.name = Some(body),
+                COMPRESSION_TYPE_DESCRIPTOR => {
+                    check_len!(body.len(), 5);
+                    info.compression = Some(Compression {
+                        compression_type: body[0],
+                        original_size: util::read_u32(&body[1..]),
+                    });
+                }
+                _ => {}
+            }
+            rest = &rest[2 + length..];
+        }
+        Ok((info, 8 + module_info_length))
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct DownloadInfoIndication<'a> {
+    pub transaction_id: u32,
+    pub download_id: u32,
+    pub block_size: u16,
+    pub modules: Vec<ModuleInfo<'a>>,
+}
+
+impl DownloadInfoIndication<'_> {
+    pub fn parse(bytes: &[u8]) -> Result<DownloadInfoIndication<'_>> {
+        let header = parse_section(bytes, DOWNLOAD_INFO_TABLE_ID)?;
+        if header.message_id != MESSAGE_ID_DII {
+            return Err(Error::InvalidMessageId(header.message_id));
+        }
+        let bytes = header.message;
+        check_len!(bytes.len(), 18);
+        let download_id = util::read_u32(bytes);
+        let block_size = (u16::from(bytes[4]) << 8) | u16::from(bytes[5]);
+        if block_size == 0 {
+            return Err(Error::InvalidBlockSize(block_size));
+        }
+        // windowSize, ackPeriod, tCDownloadWindow and tCDownloadScenario are
+        // not used in broadcasting.
+        let compatibility_descriptor_length =
+            (usize::from(bytes[16]) << 8) | usize::from(bytes[17]);
+        let bytes = &bytes[18..];
+        check_len!(bytes.len(), compatibility_descriptor_length + 2);
+        let bytes = &bytes[compatibility_descriptor_length..];
+        let number_of_modules = (usize::from(bytes[0]) << 8) | usize::from(bytes[1]);
+        let mut bytes = &bytes[2..];
+        let mut modules = Vec::with_capacity(number_of_modules);
+        for _ in 0..number_of_modules {
+            let (module, n) = ModuleInfo::parse(bytes)?;
+            modules.push(module);
+            bytes = &bytes[n..];
+        }
+        Ok(DownloadInfoIndication {
+            transaction_id: header.id,
+            download_id,
+            block_size,
+            modules,
+        })
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct DownloadDataBlock<'a> {
+    pub download_id: u32,
+    pub module_id: u16,
+    pub module_version: u8,
+    pub block_number: u16,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub block_data: &'a [u8],
+}
+
+impl DownloadDataBlock<'_> {
+    pub fn parse(bytes: &[u8]) -> Result<DownloadDataBlock<'_>> {
+        let header = parse_section(bytes, DOWNLOAD_DATA_TABLE_ID)?;
+        if header.message_id != MESSAGE_ID_DDB {
+            return Err(Error::InvalidMessageId(header.message_id));
+        }
+        let bytes = header.message;
+        check_len!(bytes.len(), 6);
+        Ok(DownloadDataBlock {
+            download_id: header.id,
+            module_id: (u16::from(bytes[0]) << 8) | u16::from(bytes[1]),
+            module_version: bytes[2],
+            block_number: (u16::from(bytes[4]) << 8) | u16::from(bytes[5]),
+            block_data: &bytes[6..],
+        })
+    }
+}
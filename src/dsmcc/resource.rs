@@ -0,0 +1,144 @@
+use crate::dsmcc::{Error, Module, Result};
+
+const MULTIPART_MIXED: &str = "multipart/mixed";
+
+// header names in lowercase and their values.
+type Headers = Vec<(String, String)>;
+
+/// A file of data broadcasting carried in a module.
+#[derive(Debug)]
+pub struct Resource<'a> {
+    /// Content-Location of the part, or the Name_descriptor of the module.
+    pub location: Option<String>,
+    pub content_type: Option<String>,
+    pub data: &'a [u8],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceKind {
+    Bml,
+    Png,
+    Clut,
+    Other,
+}
+
+impl Resource<'_> {
+    /// Identifies the resource by its media type, or by its extension.
+    pub fn kind(&self) -> ResourceKind {
+        let content_type = self
+            .content_type
+            .as_deref()
+            .map(|t| t.to_ascii_lowercase())
+            .unwrap_or_default();
+        let location = self
+            .location
+            .as_deref()
+            .map(|l| l.to_ascii_lowercase())
+            .unwrap_or_default();
+        if content_type.starts_with("text/x-arib-bml") || location.ends_with(".bml") {
+            ResourceKind::Bml
+        } else if content_type.starts_with("image/x-arib-png") || location.ends_with(".png") {
+            ResourceKind::Png
+        } else if content_type.starts_with("application/x-arib-clut") || location.ends_with(".clt")
+        {
+            ResourceKind::Clut
+        } else {
+            ResourceKind::Other
+        }
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+// splits off the header block ending with an empty line, accepting both
+// CRLF and LF.
+fn split_headers(bytes: &[u8]) -> Result<(Headers, &[u8])> {
+    let mut headers = Vec::new();
+    let mut rest = bytes;
+    loop {
+        let end = rest
+            .iter()
+            .position(|b| *b == b'\n')
+            .ok_or(Error::InvalidMultipart)?;
+        let line = &rest[..end];
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        rest = &rest[end + 1..];
+        if line.is_empty() {
+            return Ok((headers, rest));
+        }
+        let line = String::from_utf8_lossy(line);
+        let (name, value) = line.split_once(':').ok_or(Error::InvalidMultipart)?;
+        headers.push((name.trim().to_ascii_lowercase(), value.trim().to_string()));
+    }
+}
+
+fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(n, _)| n == name)
+        .map(|(_, v)| v.as_str())
+}
+
+fn boundary(content_type: &str) -> Option<&str> {
+    content_type.split(';').skip(1).find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        if name.trim().eq_ignore_ascii_case("boundary") {
+            Some(value.trim().trim_matches('"'))
+        } else {
+            None
+        }
+    })
+}
+
+fn split_multipart<'a>(body: &'a [u8], boundary: &str) -> Result<Vec<Resource<'a>>> {
+    let delimiter = format!("--{}", boundary);
+    let delimiter = delimiter.as_bytes();
+    let mut resources = Vec::new();
+    let start = find(body, delimiter).ok_or(Error::InvalidMultipart)?;
+    let mut rest = &body[start + delimiter.len()..];
+    // the close delimiter is followed by "--".
+    while !rest.starts_with(b"--") {
+        let end = find(rest, delimiter).ok_or(Error::InvalidMultipart)?;
+        let part = &rest[..end];
+        // the line break before a delimiter belongs to the delimiter.
+        let part = part.strip_suffix(b"\n").unwrap_or(part);
+        let part = part.strip_suffix(b"\r").unwrap_or(part);
+        // skip the line break after the previous delimiter.
+        let part = part.strip_prefix(b"\r").unwrap_or(part);
+        let part = part.strip_prefix(b"\n").unwrap_or(part);
+        let (headers, data) = split_headers(part)?;
+        resources.push(Resource {
+            location: header(&headers, "content-location").map(String::from),
+            content_type: header(&headers, "content-type").map(String::from),
+            data,
+        });
+        rest = &rest[end + delimiter.len()..];
+    }
+    Ok(resources)
+}
+
+impl Module {
+    /// Splits the module into resources. A multipart/mixed module is an
+    /// entity holding resources with their own headers, and the others are a
+    /// single resource named by the Name_descriptor.
+    pub fn resources(&self) -> Result<Vec<Resource<'_>>> {
+        let multipart = self
+            .module_type
+            .as_deref()
+            .is_some_and(|t| t.to_ascii_lowercase().starts_with(MULTIPART_MIXED));
+        if !multipart {
+            return Ok(vec![Resource {
+                location: self.name.clone(),
+                content_type: self.module_type.clone(),
+                data: &self.data[..],
+            }]);
+        }
+        let (headers, body) = split_headers(&self.data[..])?;
+        let boundary = header(&headers, "content-type")
+            .and_then(boundary)
+            .ok_or(Error::InvalidMultipart)?;
+        split_multipart(body, boundary)
+    }
+}
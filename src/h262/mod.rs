@@ -1,20 +1,27 @@
-fn index_pattern(pattern: &[u8], seq: &[u8]) -> Option<usize> {
-    if pattern.len() > seq.len() {
-        return None;
-    }
-    'outer: for i in 0..seq.len() - pattern.len() {
-        for j in 0..pattern.len() {
-            if seq[i + j] != pattern[j] {
-                continue 'outer;
-            }
-        }
-        return Some(i);
-    }
-    None
+mod picture;
+mod sequence;
+pub use self::picture::*;
+pub use self::sequence::*;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("too short {len}, expect {expr}({expected})")]
+    TooShort {
+        len: usize,
+        expected: usize,
+        expr: &'static str,
+    },
+    #[error("invalid start code: 0x{0:x}")]
+    InvalidStartCode(u8),
+    #[error("invalid extension id: {0}")]
+    InvalidExtensionId(u8),
 }
 
-const PICTURE_START_CODE: &[u8] = &[0, 0, 1, 0];
-const I_PICTURE: u8 = 1;
+pub type Result<T> = std::result::Result<T, Error>;
+
+fn index_pattern(pattern: &[u8], seq: &[u8]) -> Option<usize> {
+    memchr::memmem::find(seq, pattern)
+}
 
 pub fn is_i_picture(bytes: &[u8]) -> bool {
     if let Some(index) = index_pattern(PICTURE_START_CODE, bytes) {
@@ -0,0 +1,203 @@
+use memchr::memmem;
+
+use super::{Error, Result, EXTENSION_START_CODE};
+
+pub const PICTURE_START_CODE: &[u8] = &[0, 0, 1, 0];
+pub const GROUP_START_CODE: &[u8] = &[0, 0, 1, 0xb8];
+
+pub const I_PICTURE: u8 = 1;
+pub const P_PICTURE: u8 = 2;
+pub const B_PICTURE: u8 = 3;
+
+pub const TOP_FIELD: u8 = 1;
+pub const BOTTOM_FIELD: u8 = 2;
+pub const FRAME_PICTURE: u8 = 3;
+
+const PICTURE_CODING_EXTENSION_ID: u8 = 8;
+
+// bytes of a picture header up to the picture_coding_type.
+const PICTURE_CODING_TYPE_END: usize = 6;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct GroupOfPicturesHeader {
+    /// drop_frame_flag, hours, minutes, marker_bit, seconds and pictures in
+    /// 25 bits.
+    pub time_code: u32,
+    pub closed_gop: bool,
+    pub broken_link: bool,
+}
+
+impl GroupOfPicturesHeader {
+    /// Parses a group of pictures header, `bytes` starts with the
+    /// group_start_code.
+    pub fn parse(bytes: &[u8]) -> Result<GroupOfPicturesHeader> {
+        check_len!(bytes.len(), 8);
+        if &bytes[..4] != GROUP_START_CODE {
+            return Err(Error::InvalidStartCode(bytes[3]));
+        }
+        let time_code = (u32::from(bytes[4]) << 17)
+            | (u32::from(bytes[5]) << 9)
+            | (u32::from(bytes[6]) << 1)
+            | (u32::from(bytes[7]) >> 7);
+        let closed_gop = bytes[7] & 0x40 > 0;
+        let broken_link = bytes[7] & 0x20 > 0;
+        Ok(GroupOfPicturesHeader {
+            time_code,
+            closed_gop,
+            broken_link,
+        })
+    }
+
+    /// Formats the time code as `HH:MM:SS:PP`.
+    pub fn time_code_string(&self) -> String {
+        let t = self.time_code;
+        format!(
+            "{:02}:{:02}:{:02}:{:02}",
+            (t >> 19) & 0x1f,
+            (t >> 13) & 0x3f,
+            (t >> 6) & 0x3f,
+            t & 0x3f
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct PictureHeader {
+    pub temporal_reference: u16,
+    pub picture_coding_type: u8,
+    pub vbv_delay: u16,
+}
+
+impl PictureHeader {
+    /// Parses a picture header, `bytes` starts with the picture_start_code.
+    pub fn parse(bytes: &[u8]) -> Result<PictureHeader> {
+        check_len!(bytes.len(), 8);
+        if &bytes[..4] != PICTURE_START_CODE {
+            return Err(Error::InvalidStartCode(bytes[3]));
+        }
+        let temporal_reference = (u16::from(bytes[4]) << 2) | (u16::from(bytes[5]) >> 6);
+        let picture_coding_type = (bytes[5] >> 3) & 0x7;
+        let vbv_delay = (u16::from(bytes[5] & 0x7) << 13)
+            | (u16::from(bytes[6]) << 5)
+            | (u16::from(bytes[7]) >> 3);
+        Ok(PictureHeader {
+            temporal_reference,
+            picture_coding_type,
+            vbv_delay,
+        })
+    }
+
+    /// Returns `I`, `P`, `B` or `D` for the picture_coding_type, or `?`.
+    pub fn picture_type_char(&self) -> char {
+        match self.picture_coding_type {
+            I_PICTURE => 'I',
+            P_PICTURE => 'P',
+            B_PICTURE => 'B',
+            4 => 'D',
+            _ => '?',
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct PictureCodingExtension {
+    /// f_code[s][t] for forward and backward, horizontal and vertical.
+    pub f_code: [[u8; 2]; 2],
+    pub intra_dc_precision: u8,
+    /// `TOP_FIELD`, `BOTTOM_FIELD` or `FRAME_PICTURE`.
+    pub picture_structure: u8,
+    pub top_field_first: bool,
+    pub frame_pred_frame_dct: bool,
+    pub concealment_motion_vectors: bool,
+    pub q_scale_type: bool,
+    pub intra_vlc_format: bool,
+    pub alternate_scan: bool,
+    pub repeat_first_field: bool,
+    pub chroma_420_type: bool,
+    pub progressive_frame: bool,
+    pub composite_display_flag: bool,
+}
+
+impl PictureCodingExtension {
+    /// Parses a picture coding extension, `bytes` starts with the
+    /// extension_start_code.
+    pub fn parse(bytes: &[u8]) -> Result<PictureCodingExtension> {
+        check_len!(bytes.len(), 9);
+        if &bytes[..4] != EXTENSION_START_CODE {
+            return Err(Error::InvalidStartCode(bytes[3]));
+        }
+        let extension_start_code_identifier = bytes[4] >> 4;
+        if extension_start_code_identifier != PICTURE_CODING_EXTENSION_ID {
+            return Err(Error::InvalidExtensionId(extension_start_code_identifier));
+        }
+        let f_code = [
+            [bytes[4] & 0xf, bytes[5] >> 4],
+            [bytes[5] & 0xf, bytes[6] >> 4],
+        ];
+        Ok(PictureCodingExtension {
+            f_code,
+            intra_dc_precision: (bytes[6] >> 2) & 0x3,
+            picture_structure: bytes[6] & 0x3,
+            top_field_first: bytes[7] & 0x80 > 0,
+            frame_pred_frame_dct: bytes[7] & 0x40 > 0,
+            concealment_motion_vectors: bytes[7] & 0x20 > 0,
+            q_scale_type: bytes[7] & 0x10 > 0,
+            intra_vlc_format: bytes[7] & 0x8 > 0,
+            alternate_scan: bytes[7] & 0x4 > 0,
+            repeat_first_field: bytes[7] & 0x2 > 0,
+            chroma_420_type: bytes[7] & 0x1 > 0,
+            progressive_frame: bytes[8] & 0x80 > 0,
+            composite_display_flag: bytes[8] & 0x40 > 0,
+        })
+    }
+}
+
+/// Iterates the offsets of start codes in `bytes`, each of which is followed
+/// by at least the start code value.
+pub fn start_codes(bytes: &[u8]) -> impl Iterator<Item = usize> + '_ {
+    memmem::find_iter(bytes, &[0, 0, 1]).filter(move |i| i + 3 < bytes.len())
+}
+
+// reads the picture_coding_type, `bytes` starts with the picture_start_code.
+fn picture_coding_type(bytes: &[u8]) -> Option<u8> {
+    let b = bytes.get(PICTURE_CODING_TYPE_END - 1)?;
+    Some((b >> 3) & 0x7)
+}
+
+/// Finds picture headers in an elementary stream fed piece by piece, such as
+/// PES payloads, including the headers split across the pieces.
+#[derive(Debug, Default)]
+pub struct PictureScanner {
+    // the tail of the pieces so far, which may start a header.
+    carry: Vec<u8>,
+}
+
+impl PictureScanner {
+    /// Returns the picture_coding_type of each picture header completed by
+    /// `bytes`.
+    pub fn feed(&mut self, bytes: &[u8]) -> Vec<u8> {
+        let tail = PICTURE_CODING_TYPE_END - 1;
+        let carried = self.carry.len();
+        let mut joined = std::mem::take(&mut self.carry);
+        joined.extend_from_slice(&bytes[..bytes.len().min(tail)]);
+        // headers starting in the carry, the ones in bytes are found below.
+        let mut types: Vec<u8> = memmem::find_iter(&joined, PICTURE_START_CODE)
+            .take_while(|i| *i < carried)
+            .filter_map(|i| picture_coding_type(&joined[i..]))
+            .collect();
+        types.extend(
+            memmem::find_iter(bytes, PICTURE_START_CODE)
+                .filter_map(|i| picture_coding_type(&bytes[i..])),
+        );
+        // a header starting in the last bytes is not complete yet.
+        self.carry = if bytes.len() >= tail {
+            bytes[bytes.len() - tail..].to_vec()
+        } else {
+            joined[joined.len().saturating_sub(tail)..].to_vec()
+        };
+        types
+    }
+}
@@ -0,0 +1,162 @@
+use super::{Error, Result};
+
+pub const SEQUENCE_HEADER_CODE: &[u8] = &[0, 0, 1, 0xb3];
+pub const EXTENSION_START_CODE: &[u8] = &[0, 0, 1, 0xb5];
+const SEQUENCE_EXTENSION_ID: u8 = 1;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct SequenceHeader {
+    pub horizontal_size_value: u16,
+    pub vertical_size_value: u16,
+    pub aspect_ratio_information: u8,
+    pub frame_rate_code: u8,
+    pub bit_rate_value: u32,
+    pub vbv_buffer_size_value: u16,
+    pub constrained_parameters_flag: bool,
+}
+
+impl SequenceHeader {
+    /// Parses a sequence header, `bytes` starts with the sequence_header_code.
+    pub fn parse(bytes: &[u8]) -> Result<SequenceHeader> {
+        check_len!(bytes.len(), 12);
+        if &bytes[..4] != SEQUENCE_HEADER_CODE {
+            return Err(Error::InvalidStartCode(bytes[3]));
+        }
+        let horizontal_size_value = (u16::from(bytes[4]) << 4) | (u16::from(bytes[5]) >> 4);
+        let vertical_size_value = (u16::from(bytes[5] & 0xf) << 8) | u16::from(bytes[6]);
+        let aspect_ratio_information = bytes[7] >> 4;
+        let frame_rate_code = bytes[7] & 0xf;
+        let bit_rate_value =
+            (u32::from(bytes[8]) << 10) | (u32::from(bytes[9]) << 2) | (u32::from(bytes[10]) >> 6);
+        let vbv_buffer_size_value =
+            (u16::from(bytes[10] & 0x1f) << 5) | (u16::from(bytes[11]) >> 3);
+        let constrained_parameters_flag = bytes[11] & 0x4 > 0;
+        Ok(SequenceHeader {
+            horizontal_size_value,
+            vertical_size_value,
+            aspect_ratio_information,
+            frame_rate_code,
+            bit_rate_value,
+            vbv_buffer_size_value,
+            constrained_parameters_flag,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct SequenceExtension {
+    pub profile_and_level_indication: u8,
+    pub progressive_sequence: bool,
+    pub chroma_format: u8,
+    pub horizontal_size_extension: u8,
+    pub vertical_size_extension: u8,
+    pub bit_rate_extension: u16,
+    pub vbv_buffer_size_extension: u8,
+    pub low_delay: bool,
+    pub frame_rate_extension_n: u8,
+    pub frame_rate_extension_d: u8,
+}
+
+impl SequenceExtension {
+    /// Parses a sequence extension, `bytes` starts with the extension_start_code.
+    pub fn parse(bytes: &[u8]) -> Result<SequenceExtension> {
+        check_len!(bytes.len(), 10);
+        if &bytes[..4] != EXTENSION_START_CODE {
+            return Err(Error::InvalidStartCode(bytes[3]));
+        }
+        let extension_start_code_identifier = bytes[4] >> 4;
+        if extension_start_code_identifier != SEQUENCE_EXTENSION_ID {
+            return Err(Error::InvalidExtensionId(extension_start_code_identifier));
+        }
+        let profile_and_level_indication = (bytes[4] << 4) | (bytes[5] >> 4);
+        let progressive_sequence = bytes[5] & 0x8 > 0;
+        let chroma_format = (bytes[5] >> 1) & 0x3;
+        let horizontal_size_extension = ((bytes[5] & 0x1) << 1) | (bytes[6] >> 7);
+        let vertical_size_extension = (bytes[6] >> 5) & 0x3;
+        let bit_rate_extension = (u16::from(bytes[6] & 0x1f) << 7) | (u16::from(bytes[7]) >> 1);
+        let vbv_buffer_size_extension = bytes[8];
+        let low_delay = bytes[9] & 0x80 > 0;
+        let frame_rate_extension_n = (bytes[9] >> 5) & 0x3;
+        let frame_rate_extension_d = bytes[9] & 0x1f;
+        Ok(SequenceExtension {
+            profile_and_level_indication,
+            progressive_sequence,
+            chroma_format,
+            horizontal_size_extension,
+            vertical_size_extension,
+            bit_rate_extension,
+            vbv_buffer_size_extension,
+            low_delay,
+            frame_rate_extension_n,
+            frame_rate_extension_d,
+        })
+    }
+}
+
+/// Video format derived from the sequence header and its extension.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct VideoFormat {
+    pub width: u32,
+    pub height: u32,
+    /// Display aspect ratio, None for square samples or reserved values.
+    pub aspect_ratio: Option<(u32, u32)>,
+    /// Frame rate as numerator and denominator.
+    pub frame_rate: Option<(u32, u32)>,
+    /// Bits per second.
+    pub bit_rate: u64,
+    pub progressive_sequence: Option<bool>,
+}
+
+impl VideoFormat {
+    pub fn new(header: &SequenceHeader, extension: Option<&SequenceExtension>) -> VideoFormat {
+        let (h_ext, v_ext, br_ext, n, d) = match extension {
+            Some(e) => (
+                u32::from(e.horizontal_size_extension),
+                u32::from(e.vertical_size_extension),
+                u64::from(e.bit_rate_extension),
+                u32::from(e.frame_rate_extension_n),
+                u32::from(e.frame_rate_extension_d),
+            ),
+            None => (0, 0, 0, 0, 0),
+        };
+        let aspect_ratio = match header.aspect_ratio_information {
+            2 => Some((4, 3)),
+            3 => Some((16, 9)),
+            4 => Some((221, 100)),
+            _ => None,
+        };
+        let frame_rate = match header.frame_rate_code {
+            1 => Some((24000, 1001)),
+            2 => Some((24, 1)),
+            3 => Some((25, 1)),
+            4 => Some((30000, 1001)),
+            5 => Some((30, 1)),
+            6 => Some((50, 1)),
+            7 => Some((60000, 1001)),
+            8 => Some((60, 1)),
+            _ => None,
+        }
+        .map(|(num, den)| (num * (n + 1), den * (d + 1)));
+        VideoFormat {
+            width: (h_ext << 12) | u32::from(header.horizontal_size_value),
+            height: (v_ext << 12) | u32::from(header.vertical_size_value),
+            aspect_ratio,
+            frame_rate,
+            bit_rate: ((br_ext << 18) | u64::from(header.bit_rate_value)) * 400,
+            progressive_sequence: extension.map(|e| e.progressive_sequence),
+        }
+    }
+}
+
+/// Finds a sequence header and the following sequence extension in `bytes`.
+pub fn find_video_format(bytes: &[u8]) -> Option<VideoFormat> {
+    let index = super::index_pattern(SEQUENCE_HEADER_CODE, bytes)?;
+    let bytes = &bytes[index..];
+    let header = SequenceHeader::parse(bytes).ok()?;
+    let extension = super::index_pattern(EXTENSION_START_CODE, bytes)
+        .and_then(|index| SequenceExtension::parse(&bytes[index..]).ok());
+    Some(VideoFormat::new(&header, extension.as_ref()))
+}
@@ -0,0 +1,56 @@
+pub const NAL_UNIT_TYPE_NON_IDR_SLICE: u8 = 1;
+pub const NAL_UNIT_TYPE_IDR_SLICE: u8 = 5;
+pub const NAL_UNIT_TYPE_SEI: u8 = 6;
+pub const NAL_UNIT_TYPE_SPS: u8 = 7;
+pub const NAL_UNIT_TYPE_PPS: u8 = 8;
+pub const NAL_UNIT_TYPE_AUD: u8 = 9;
+
+/// Iterates NAL units of an Annex B byte stream, without start codes.
+pub struct NalUnits<'a> {
+    bytes: &'a [u8],
+}
+
+pub fn nal_units(bytes: &[u8]) -> NalUnits<'_> {
+    let bytes = match find_start_code(bytes) {
+        Some(i) => &bytes[i + 3..],
+        None => &[],
+    };
+    NalUnits { bytes }
+}
+
+// returns the index of the next 0x000001.
+fn find_start_code(bytes: &[u8]) -> Option<usize> {
+    bytes.windows(3).position(|w| w == [0, 0, 1])
+}
+
+impl<'a> Iterator for NalUnits<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+        let (nal, rest) = match find_start_code(self.bytes) {
+            Some(i) => {
+                // a zero_byte before the start code belongs to the next unit.
+                let mut end = i;
+                while end > 0 && self.bytes[end - 1] == 0 {
+                    end -= 1;
+                }
+                (&self.bytes[..end], &self.bytes[i + 3..])
+            }
+            None => (self.bytes, &[][..]),
+        };
+        self.bytes = rest;
+        Some(nal)
+    }
+}
+
+pub fn nal_unit_type(nal: &[u8]) -> Option<u8> {
+    nal.first().map(|b| b & 0x1f)
+}
+
+/// Returns true if `bytes` contains a slice of an IDR picture.
+pub fn is_idr_picture(bytes: &[u8]) -> bool {
+    nal_units(bytes).any(|nal| nal_unit_type(nal) == Some(NAL_UNIT_TYPE_IDR_SLICE))
+}
@@ -0,0 +1,18 @@
+//! Parsers for ARIB flavored MPEG-2 transport streams.
+//!
+//! `ts` splits a byte stream into packets, `psi` and `pes` reassemble and
+//! parse sections and PES packets on top of it, and `arib` decodes the
+//! caption and string formats used by Japanese digital broadcasting.
+
+#[macro_use]
+mod util;
+pub mod adts;
+pub mod arib;
+pub mod crc32;
+pub mod dsmcc;
+pub mod h262;
+pub mod h264;
+pub mod pes;
+pub mod psi;
+pub mod stream;
+pub mod ts;
@@ -1,66 +1,645 @@
 use std::path::PathBuf;
+use std::process::ExitCode;
 
 use anyhow::Result;
+use chrono::{DateTime, FixedOffset};
 use clap::{Parser, Subcommand};
-use env_logger;
 
-#[macro_use]
-mod util;
-mod arib;
 mod cmd;
-mod crc32;
-mod h262;
-mod pes;
-mod psi;
-mod stream;
-mod ts;
 
 #[derive(Parser)]
 struct Cli {
     #[command(subcommand)]
     command: Command,
+    /// Decrypt inputs through this command, which reads the scrambled stream
+    /// from stdin and writes the decrypted one to stdout.
+    #[cfg(feature = "b25")]
+    #[arg(long = "b25", global = true)]
+    b25: Option<String>,
+    /// Exit with 3 after reporting the counts on stderr if recoverable errors,
+    /// such as broken packets, CC errors, unparsable PES packets or unknown
+    /// code points, are seen.
+    #[arg(long = "strict", global = true)]
+    strict: bool,
+    /// Stop reading inputs and fail once this many recoverable errors are
+    /// seen.
+    #[arg(
+        long = "max-errors",
+        global = true,
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    max_errors: Option<u64>,
+    /// Start reading the input at this byte offset.
+    #[arg(long = "skip-bytes", global = true, conflicts_with = "start_at")]
+    skip_bytes: Option<u64>,
+    /// Start reading the input around this many seconds from its first PCR.
+    #[arg(long = "start-at", global = true)]
+    start_at: Option<f64>,
+    /// Read at most this many packets of each input.
+    #[arg(long = "max-packets", global = true)]
+    max_packets: Option<u64>,
+    /// Read at most this many seconds of each input, measured by PCR or PTS.
+    #[arg(long = "max-duration", global = true)]
+    max_duration: Option<f64>,
 }
 
 #[derive(Subcommand)]
 enum Command {
     Events {
-        input: Option<PathBuf>,
+        /// Input files concatenated in order, or stdin if none.
+        input: Vec<PathBuf>,
+        /// Show progress on stderr.
+        #[arg(long = "progress")]
+        progress: bool,
+        /// Print only events of these comma separated genres.
+        #[arg(
+            long = "genre",
+            value_delimiter = ',',
+            value_parser = clap::builder::PossibleValuesParser::new(cmd::events::GENRE_NAMES)
+        )]
+        genre: Vec<String>,
+        /// Print only events overlapping the range from this time, in ISO 8601.
+        #[arg(long = "from")]
+        from: Option<DateTime<FixedOffset>>,
+        /// Print only events overlapping the range until this time, in ISO 8601.
+        #[arg(long = "to")]
+        to: Option<DateTime<FixedOffset>>,
+        #[arg(long = "format", value_enum, default_value = "json")]
+        format: cmd::events::Format,
+        /// Print events as soon as they are found or updated, for live inputs.
+        /// The mirakurun format prints a program per line in this mode.
+        #[arg(long = "stream")]
+        stream: bool,
+        /// Print each event once as soon as it is found, and forget it when it
+        /// ends by the TOT, to read long recordings with bounded memory. An
+        /// event is printed again only if its record changes.
+        #[arg(long = "incremental", conflicts_with = "stream")]
+        incremental: bool,
+        /// Print only the present and following events of each service from
+        /// EIT p/f, and stop once every service has both.
+        #[arg(long = "now", conflicts_with_all = ["stream", "incremental"])]
+        now: bool,
+        /// Print events of services in other streams too, from EITs of the
+        /// other streams, with their network, transport stream and service
+        /// ids.
+        #[arg(long = "include-other-streams", conflicts_with = "now")]
+        include_other_streams: bool,
+        /// Print start times in UTC.
+        #[arg(long = "utc")]
+        utc: bool,
+        /// Print start times in this zone, UTC, local for the zone of the
+        /// system, or an offset like +05:30, instead of the broadcast local
+        /// time.
+        #[arg(long = "timezone", conflicts_with = "utc")]
+        timezone: Option<cmd::events::Zone>,
+        /// Capacity of the channels between reading, the EIT decoders and
+        /// printing. Larger buffers let the decoders of EIT PIDs run in
+        /// parallel for throughput, at the cost of memory of up to this many
+        /// packets per PID.
+        #[arg(long = "buffer", default_value = "1", value_parser = clap::value_parser!(u16).range(1..))]
+        buffer: u16,
+        /// Normalize decoded strings in these comma separated ways.
+        #[arg(long = "normalize", value_enum, value_delimiter = ',')]
+        normalize: Vec<cmd::Normalization>,
     },
     Caption {
-        input: Option<PathBuf>,
+        /// Input files concatenated in order, or stdin if none.
+        input: Vec<PathBuf>,
         #[arg(long = "drcs-map")]
         drcs_map: Option<PathBuf>,
         #[arg(long = "handle-drcs", value_enum, default_value = "error-exit")]
         handle_drcs: cmd::caption::HandleDRCS,
+        #[arg(long = "format", value_enum, default_value = "json")]
+        format: cmd::caption::Format,
+        /// How ruby, small text above the base text, is printed in the json
+        /// format.
+        #[arg(long = "ruby", value_enum, default_value = "inline")]
+        ruby: cmd::caption::Ruby,
+        #[command(flatten)]
+        time: cmd::caption::TimeOptions,
+        /// Normalize decoded strings in these comma separated ways.
+        #[arg(long = "normalize", value_enum, value_delimiter = ',')]
+        normalize: Vec<cmd::Normalization>,
+        /// Service to extract, the first one in the PAT by default.
+        #[arg(long = "service-id")]
+        service_id: Option<u16>,
+        /// Show progress on stderr.
+        #[arg(long = "progress")]
+        progress: bool,
+        #[command(flatten)]
+        seek: cmd::index::SeekOptions,
     },
     Jitter {
         input: Option<PathBuf>,
+        /// Track the drift over the whole input instead of the first pictures.
+        #[arg(long = "continuous")]
+        continuous: bool,
+        /// Service to measure, the first one in the PAT by default.
+        #[arg(long = "service-id")]
+        service_id: Option<u16>,
     },
     Clean {
+        /// Input file, or stdin if none.
         input: Option<PathBuf>,
+        /// Output file, or stdout if none.
         output: Option<PathBuf>,
+        /// Files read after the input in order, as if concatenated to it.
+        #[arg(long = "append", requires = "input")]
+        append: Vec<PathBuf>,
+        /// Deprecated spelling of the output argument.
+        #[arg(long = "output", hide = true, conflicts_with = "output")]
+        output_file: Option<PathBuf>,
         #[arg(long = "service-index")]
         service_index: Option<usize>,
+        /// Keep SDT, EIT and TOT too, with SDT and EIT rewritten to refer only
+        /// to the kept services.
+        #[arg(long = "keep-si")]
+        keep_si: bool,
+        /// Drop packets which are scrambled.
+        #[arg(long = "drop-scrambled")]
+        drop_scrambled: bool,
+        /// Drop the whole PID once this many scrambled packets are found in it.
+        #[arg(long = "scrambled-pid-threshold", requires = "drop_scrambled")]
+        scrambled_pid_threshold: Option<u64>,
+        /// Rewrite PCR, PTS and DTS so that the output timeline starts at
+        /// this many seconds and runs on continuously across the joins of
+        /// the inputs.
+        #[arg(long = "restamp")]
+        restamp: Option<f64>,
+        /// Show progress on stderr.
+        #[arg(long = "progress")]
+        progress: bool,
+        /// Print the statistics on stderr as JSON instead of text.
+        #[arg(long = "stats")]
+        stats: bool,
+    },
+    MuxRate {
+        input: Option<PathBuf>,
+        /// Print intervals whose rate is off the mean by more than this percent.
+        #[arg(long = "tolerance", default_value = "1.0")]
+        tolerance: f64,
+    },
+    Mediainfo {
+        input: Option<PathBuf>,
+    },
+    Bitrate {
+        input: Option<PathBuf>,
+        /// Length of a window in seconds of PCR.
+        #[arg(long = "window", default_value = "1.0")]
+        window: f64,
+        #[arg(long = "format", value_enum, default_value = "json")]
+        format: cmd::bitrate::Format,
+    },
+    BufferDelay {
+        input: Option<PathBuf>,
+    },
+    ExtractAudio {
+        input: Option<PathBuf>,
+        output: Option<PathBuf>,
+        #[arg(long = "service-id")]
+        service_id: Option<u16>,
+        #[arg(long = "component-tag")]
+        component_tag: Option<u8>,
+    },
+    ExtractEs {
+        input: Option<PathBuf>,
+        output: Option<PathBuf>,
+        /// Extract this PID instead of the main stream found via the PMT.
+        #[arg(long = "pid")]
+        pid: Option<u16>,
+        #[arg(long = "stream", value_enum, default_value = "video")]
+        stream: cmd::extract_es::EsKind,
+    },
+    Filter {
+        input: Option<PathBuf>,
+        output: Option<PathBuf>,
+        /// Keep only packets of these comma separated PIDs.
+        #[arg(long = "keep", value_delimiter = ',', value_parser = cmd::parse_pid, conflicts_with = "drop")]
+        keep: Vec<u16>,
+        /// Drop packets of these comma separated PIDs.
+        #[arg(long = "drop", value_delimiter = ',', value_parser = cmd::parse_pid)]
+        drop: Vec<u16>,
+    },
+    Gop {
+        input: Option<PathBuf>,
+        /// Service to read, the first one in the PAT by default.
+        #[arg(long = "service-id")]
+        service_id: Option<u16>,
+    },
+    Index {
+        input: Option<PathBuf>,
+        /// Service to read, the first one in the PAT by default.
+        #[arg(long = "service-id")]
+        service_id: Option<u16>,
+        #[arg(long = "format", value_enum, default_value = "json")]
+        format: cmd::index::Format,
+    },
+    Hexdump {
+        input: Option<PathBuf>,
+        /// Dump only packets of these comma separated PIDs.
+        #[arg(long = "pid", value_delimiter = ',', value_parser = cmd::parse_pid)]
+        pid: Vec<u16>,
+        /// Stop after dumping this many packets.
+        #[arg(long = "count")]
+        count: Option<usize>,
+    },
+    Hls {
+        input: Option<PathBuf>,
+        #[arg(long = "output-dir")]
+        output_dir: PathBuf,
+        /// Target segment duration in seconds.
+        #[arg(long = "duration", default_value = "6")]
+        duration: f64,
+    },
+    Restamp {
+        /// Input files joined in order, or stdin if none.
+        input: Vec<PathBuf>,
+        #[arg(long = "output")]
+        output: Option<PathBuf>,
+        /// Start the output PCR at this many seconds.
+        #[arg(long = "base", default_value = "0")]
+        base: f64,
+    },
+    /// Serve services, present and following events, captions as
+    /// server-sent events and packet statistics of the input over HTTP at
+    /// /services, /events, /captions and /stats, and Prometheus metrics at
+    /// /metrics if built with the metrics feature.
+    Serve {
+        input: Option<PathBuf>,
+        /// Address to listen on.
+        #[arg(long = "listen", default_value = "127.0.0.1:8080")]
+        listen: String,
+    },
+    InjectCaption {
+        /// SRT or WebVTT file, timed from the first key picture.
+        subtitles: PathBuf,
+        input: Option<PathBuf>,
+        output: Option<PathBuf>,
+        /// PID of the caption ES to add.
+        #[arg(long = "pid")]
+        pid: Option<u16>,
+        /// ISO 639 language code of the captions.
+        #[arg(long = "language", default_value = "jpn")]
+        language: String,
+        /// Service to add captions to, the first one in the PAT by default.
+        #[arg(long = "service-id")]
+        service_id: Option<u16>,
+        /// Shift caption times by this, in milliseconds.
+        #[arg(long = "offset-ms", default_value = "0", allow_negative_numbers = true)]
+        offset_ms: i64,
+    },
+    EditPmt {
+        input: Option<PathBuf>,
+        output: Option<PathBuf>,
+        /// Service whose PMT is edited, all services by default.
+        #[arg(long = "service-id")]
+        service_id: Option<u16>,
+        /// Remove the ES of this PID from the PMT, and its packets.
+        #[arg(long = "drop-stream", value_parser = cmd::parse_pid)]
+        drop_stream: Vec<u16>,
+        /// Add an ES of PID:STREAM_TYPE[:DESCRIPTORS] with the descriptor
+        /// loop in hex to the PMT.
+        #[arg(long = "add-stream")]
+        add_stream: Vec<cmd::edit_pmt::NewStream>,
+        /// Remove descriptors of [PID:]TAG, from the program loop without PID.
+        #[arg(long = "remove-descriptor")]
+        remove_descriptor: Vec<cmd::edit_pmt::DescriptorEdit>,
+        /// Add a descriptor of [PID:]TAG[:DATA] with DATA in hex, to the
+        /// program loop without PID.
+        #[arg(long = "add-descriptor")]
+        add_descriptor: Vec<cmd::edit_pmt::DescriptorEdit>,
+        /// Set the component_tag of an ES by PID:TAG.
+        #[arg(long = "component-tag")]
+        component_tag: Vec<cmd::edit_pmt::ComponentTag>,
+    },
+    Services {
+        input: Option<PathBuf>,
+    },
+    Schema {
+        /// The output to print the JSON Schema of.
+        #[arg(value_enum)]
+        output: cmd::schema::Output,
+    },
+    /// Browse PIDs, services, present and following events and captions
+    /// while reading the input.
+    #[cfg(feature = "browse")]
+    Browse {
+        input: Option<PathBuf>,
+    },
+    Carousel {
+        input: Option<PathBuf>,
+        #[arg(long = "output-dir")]
+        output_dir: PathBuf,
+        /// Data carousel PIDs, the ones of all services by default.
+        #[arg(long = "pid")]
+        pid: Vec<u16>,
+        /// Split modules into BML documents, images and the other resources,
+        /// and write them as a browsable tree in UTF-8.
+        #[arg(long = "bml")]
+        bml: bool,
+    },
+    Chapters {
+        input: Option<PathBuf>,
+        output: Option<PathBuf>,
+        #[arg(long = "format", value_enum, default_value = "ffmetadata")]
+        format: cmd::chapters::Format,
+    },
+    Cm {
+        input: Option<PathBuf>,
+        /// Print every boundary candidate instead of CM breaks.
+        #[arg(long = "boundaries")]
+        boundaries: bool,
+    },
+    Drcs {
+        input: Option<PathBuf>,
+        /// Write each glyph as a PGM image into this directory.
+        #[arg(long = "output-dir")]
+        output_dir: Option<PathBuf>,
+    },
+    Logos {
+        input: Option<PathBuf>,
+        #[arg(long = "output-dir")]
+        output_dir: PathBuf,
+    },
+    Pids {
+        input: Option<PathBuf>,
+    },
+    /// Measure the loudness of the main audio.
+    #[cfg(feature = "loudness")]
+    Loudness {
+        input: Option<PathBuf>,
+        /// Service to measure, the first one in the PAT by default.
+        #[arg(long = "service-id")]
+        service_id: Option<u16>,
+        /// Command which reads ADTS from stdin and writes 32-bit float little
+        /// endian samples to stdout, such as
+        /// "ffmpeg -loglevel error -f aac -i - -f f32le -", to decode audio
+        /// other than mono and stereo AAC-LC.
+        #[arg(long = "decoder")]
+        decoder: Option<String>,
+        /// Print the momentary and short-term loudness every this many
+        /// seconds.
+        #[arg(long = "interval", default_value = "1.0")]
+        interval: f64,
+    },
+    DebugCaption {
+        input: Option<PathBuf>,
+        /// Caption PID, the one of the main service by default.
+        #[arg(long = "pid")]
+        pid: Option<u16>,
+    },
+    Discontinuity {
+        input: Option<PathBuf>,
+        /// Report PTS/DTS jumps larger than this, in milliseconds.
+        #[arg(long = "threshold-ms", default_value = "1000")]
+        threshold_ms: u64,
+    },
+    Verify {
+        input: Option<PathBuf>,
+    },
+    EpgDiff {
+        /// The old schedule, a TS or events printed in the json or mirakurun
+        /// format.
+        old: PathBuf,
+        /// The new schedule, in the same kinds as the old one.
+        new: PathBuf,
     },
 }
 
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() -> Result<ExitCode> {
     env_logger::init();
 
     let cli = Cli::parse();
-    match cli.command {
-        Command::Events { input } => cmd::events::run(input).await,
+    #[cfg(feature = "b25")]
+    if let Some(command) = cli.b25.as_deref() {
+        cmd::b25::set_command(command)?;
+    }
+    if cli.strict {
+        cmd::errors::set_strict();
+    }
+    if let Some(max_errors) = cli.max_errors {
+        cmd::errors::set_max_errors(max_errors);
+    }
+    match (cli.skip_bytes, cli.start_at) {
+        (Some(offset), _) => cmd::set_start(cmd::Start::Byte(offset))?,
+        (_, Some(seconds)) => cmd::set_start(cmd::Start::Time(seconds))?,
+        _ => {}
+    }
+    cmd::set_limits(cmd::Limits {
+        packets: cli.max_packets,
+        duration: cli.max_duration,
+    })?;
+    let result = match cli.command {
+        Command::Events {
+            input,
+            progress,
+            genre,
+            from,
+            to,
+            format,
+            stream,
+            incremental,
+            now,
+            include_other_streams,
+            utc,
+            timezone,
+            buffer,
+            normalize,
+        } => {
+            let filter = cmd::events::Filter {
+                genres: genre,
+                from,
+                to,
+            };
+            cmd::events::run(
+                input,
+                progress,
+                filter,
+                format,
+                stream,
+                incremental,
+                now,
+                include_other_streams,
+                timezone.or(utc.then(cmd::events::Zone::utc)),
+                usize::from(buffer),
+                cmd::normalizer(&normalize),
+            )
+            .await
+        }
         Command::Caption {
             input,
             drcs_map,
             handle_drcs,
-        } => cmd::caption::run(input, drcs_map, handle_drcs).await,
-        Command::Jitter { input } => cmd::jitter::run(input).await,
+            format,
+            ruby,
+            time,
+            normalize,
+            service_id,
+            progress,
+            seek,
+        } => {
+            cmd::caption::run(
+                input,
+                drcs_map,
+                handle_drcs,
+                format,
+                ruby,
+                time,
+                cmd::normalizer(&normalize),
+                service_id,
+                progress,
+                seek,
+            )
+            .await
+        }
+        Command::Jitter {
+            input,
+            continuous,
+            service_id,
+        } => cmd::jitter::run(input, continuous, service_id).await,
         Command::Clean {
             input,
             output,
+            append,
+            output_file,
             service_index,
-        } => cmd::clean::run(input, output, service_index).await,
-    }
+            keep_si,
+            drop_scrambled,
+            scrambled_pid_threshold,
+            restamp,
+            progress,
+            stats,
+        } => {
+            let scrambled = match (drop_scrambled, scrambled_pid_threshold) {
+                (false, _) => cmd::clean::Scrambled::Keep,
+                (true, None) => cmd::clean::Scrambled::DropPackets,
+                (true, Some(threshold)) => cmd::clean::Scrambled::DropPid(threshold),
+            };
+            cmd::clean::run(
+                input.into_iter().chain(append).collect(),
+                output.or(output_file),
+                service_index,
+                keep_si,
+                scrambled,
+                restamp,
+                progress,
+                stats,
+            )
+            .await
+        }
+        Command::Mediainfo { input } => cmd::mediainfo::run(input).await,
+        Command::MuxRate { input, tolerance } => cmd::mux_rate::run(input, tolerance).await,
+        Command::Bitrate {
+            input,
+            window,
+            format,
+        } => cmd::bitrate::run(input, window, format).await,
+        Command::BufferDelay { input } => cmd::buffer_delay::run(input).await,
+        Command::ExtractAudio {
+            input,
+            output,
+            service_id,
+            component_tag,
+        } => cmd::extract_audio::run(input, output, service_id, component_tag).await,
+        Command::ExtractEs {
+            input,
+            output,
+            pid,
+            stream,
+        } => cmd::extract_es::run(input, output, pid, stream).await,
+        Command::Filter {
+            input,
+            output,
+            keep,
+            drop,
+        } => cmd::filter::run(input, output, keep, drop).await,
+        Command::Gop { input, service_id } => cmd::gop::run(input, service_id).await,
+        Command::Index {
+            input,
+            service_id,
+            format,
+        } => cmd::index::run(input, service_id, format).await,
+        Command::Hexdump { input, pid, count } => cmd::hexdump::run(input, pid, count).await,
+        Command::Hls {
+            input,
+            output_dir,
+            duration,
+        } => cmd::hls::run(input, output_dir, duration).await,
+        Command::InjectCaption {
+            subtitles,
+            input,
+            output,
+            pid,
+            language,
+            service_id,
+            offset_ms,
+        } => {
+            cmd::inject_caption::run(
+                subtitles, input, output, pid, language, service_id, offset_ms,
+            )
+            .await
+        }
+        Command::EditPmt {
+            input,
+            output,
+            service_id,
+            drop_stream,
+            add_stream,
+            remove_descriptor,
+            add_descriptor,
+            component_tag,
+        } => {
+            let edits = cmd::edit_pmt::Edits {
+                drop_streams: drop_stream,
+                add_streams: add_stream,
+                remove_descriptors: remove_descriptor,
+                add_descriptors: add_descriptor,
+                component_tags: component_tag,
+            };
+            cmd::edit_pmt::run(input, output, service_id, edits).await
+        }
+        Command::Restamp {
+            input,
+            output,
+            base,
+        } => cmd::restamp::run(input, output, base).await,
+        Command::Serve { input, listen } => cmd::serve::run(input, listen).await,
+        Command::Services { input } => cmd::services::run(input).await,
+        Command::Schema { output } => cmd::schema::run(output),
+        #[cfg(feature = "browse")]
+        Command::Browse { input } => cmd::browse::run(input).await,
+        Command::Carousel {
+            input,
+            output_dir,
+            pid,
+            bml,
+        } => cmd::carousel::run(input, output_dir, pid, bml).await,
+        Command::Chapters {
+            input,
+            output,
+            format,
+        } => cmd::chapters::run(input, output, format).await,
+        Command::Cm { input, boundaries } => cmd::cm::run(input, boundaries).await,
+        Command::Drcs { input, output_dir } => cmd::drcs::run(input, output_dir).await,
+        Command::Logos { input, output_dir } => cmd::logos::run(input, output_dir).await,
+        Command::Pids { input } => cmd::pids::run(input).await,
+        #[cfg(feature = "loudness")]
+        Command::Loudness {
+            input,
+            service_id,
+            decoder,
+            interval,
+        } => cmd::loudness::run(input, service_id, decoder, interval).await,
+        Command::DebugCaption { input, pid } => cmd::debug_caption::run(input, pid).await,
+        Command::Discontinuity {
+            input,
+            threshold_ms,
+        } => cmd::discontinuity::run(input, threshold_ms).await,
+        Command::Verify { input } => cmd::verify::run(input).await,
+        Command::EpgDiff { old, new } => cmd::epg_diff::run(old, new).await,
+    };
+    cmd::errors::check_limit()?;
+    result?;
+    Ok(cmd::errors::exit_code())
 }
@@ -3,11 +3,11 @@ use std::mem;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
-use anyhow::{anyhow, bail, Result};
 use bytes::{Bytes, BytesMut};
 use log::warn;
 use tokio_stream::Stream;
 
+use super::{Error, Result};
 use crate::ts;
 
 const INITIAL_BUFFER: usize = 4096;
@@ -38,21 +38,13 @@ impl<S> Buffer<S> {
     }
 
     fn get_bytes(&mut self) -> Result<Bytes> {
-        if self.buf.len() < 6 {
-            bail!("not enough data");
-        }
+        check_len!(self.buf.len(), 6);
         let pes_packet_length = (usize::from(self.buf[4]) << 8) | usize::from(self.buf[5]);
         if pes_packet_length == 0 {
             return Ok(self.buf.split().freeze());
         }
-        if self.buf.len() < pes_packet_length + 6 {
-            bail!(
-                "not enough data. needs: {}, has: {}",
-                pes_packet_length + 6,
-                self.buf.len()
-            );
-        }
-        return Ok(self.buf.split_to(pes_packet_length + 6).freeze());
+        check_len!(self.buf.len(), pes_packet_length + 6);
+        Ok(self.buf.split_to(pes_packet_length + 6).freeze())
     }
 }
 
@@ -86,7 +78,7 @@ where
 
             let data = match packet.data {
                 Some(ref data) => data.as_ref(),
-                None => return Poll::Ready(Some(Err(anyhow!("no data")))),
+                None => return Poll::Ready(Some(Err(Error::MalformedNoData))),
             };
 
             if packet.payload_unit_start_indicator {
@@ -123,7 +115,7 @@ where
                 } else {
                     self.state = State::Initial;
                     self.buf.clear();
-                    return Poll::Ready(Some(Err(anyhow!("pes packet discontinued"))));
+                    return Poll::Ready(Some(Err(Error::Discontinued)));
                 }
             }
         }
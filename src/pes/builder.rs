@@ -0,0 +1,110 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::pes::{Error, Result};
+use crate::ts;
+
+// flags to PES_header_data_length.
+const HEADER_FLAGS_LENGTH: usize = 3;
+const PTS_LENGTH: usize = 5;
+const MARKER_BITS: u8 = 0x80;
+const DATA_ALIGNMENT_INDICATOR: u8 = 0x04;
+const PTS_ONLY: u8 = 0x80;
+
+/// Constructs a PES packet which fits in PES_packet_length, such as of
+/// audio, captions and data.
+#[derive(Debug, Clone)]
+pub struct PESPacketBuilder {
+    stream_id: u8,
+    data_alignment_indicator: bool,
+    pts: Option<u64>,
+    payload: Bytes,
+}
+
+impl PESPacketBuilder {
+    pub fn new(stream_id: u8, payload: Bytes) -> PESPacketBuilder {
+        PESPacketBuilder {
+            stream_id,
+            data_alignment_indicator: false,
+            pts: None,
+            payload,
+        }
+    }
+
+    pub fn data_alignment_indicator(mut self, v: bool) -> Self {
+        self.data_alignment_indicator = v;
+        self
+    }
+
+    pub fn pts(mut self, pts: u64) -> Self {
+        self.pts = Some(pts);
+        self
+    }
+
+    /// Serializes the packet from packet_start_code_prefix.
+    pub fn build(self) -> Result<Bytes> {
+        let header_data_length = if self.pts.is_some() { PTS_LENGTH } else { 0 };
+        let pes_packet_length = HEADER_FLAGS_LENGTH + header_data_length + self.payload.len();
+        if pes_packet_length > usize::from(u16::MAX) {
+            return Err(Error::TooLong(pes_packet_length));
+        }
+        let mut out = BytesMut::with_capacity(6 + pes_packet_length);
+        out.put_slice(&[0, 0, 1, self.stream_id]);
+        out.put_u16(pes_packet_length as u16);
+        let alignment = if self.data_alignment_indicator {
+            DATA_ALIGNMENT_INDICATOR
+        } else {
+            0
+        };
+        out.put_u8(MARKER_BITS | alignment);
+        match self.pts {
+            Some(pts) => {
+                out.put_u8(PTS_ONLY);
+                out.put_u8(header_data_length as u8);
+                out.put_u8(0x21 | ((pts >> 29) & 0xe) as u8);
+                out.put_u16(0x1 | ((pts >> 14) & 0xfffe) as u16);
+                out.put_u16(0x1 | ((pts << 1) & 0xfffe) as u16);
+            }
+            None => {
+                out.put_u8(0);
+                out.put_u8(0);
+            }
+        }
+        out.extend_from_slice(&self.payload[..]);
+        Ok(out.freeze())
+    }
+}
+
+/// Splits PES packets into TS packets of a PID, keeping the continuity
+/// counter across calls.
+#[derive(Debug, Clone)]
+pub struct PESPacketizer {
+    pid: u16,
+    continuity_counter: u8,
+}
+
+impl PESPacketizer {
+    /// The first packet is numbered with `continuity_counter`.
+    pub fn new(pid: u16, continuity_counter: u8) -> PESPacketizer {
+        PESPacketizer {
+            pid,
+            continuity_counter: continuity_counter & 0xf,
+        }
+    }
+
+    /// Packetizes a PES packet, padding the last TS packet with stuffing in
+    /// the adaptation field.
+    pub fn packetize(&mut self, pes: &[u8]) -> Result<Vec<ts::TSPacket>> {
+        let payload_length = ts::TS_PACKET_LENGTH - 4;
+        let mut packets = Vec::new();
+        for (i, chunk) in pes.chunks(payload_length).enumerate() {
+            let packet = ts::TSPacketBuilder::new(self.pid)
+                .payload_unit_start_indicator(i == 0)
+                .continuity_counter(self.continuity_counter)
+                .payload(Some(Bytes::copy_from_slice(chunk)))
+                .build()?;
+            packets.push(packet);
+            self.continuity_counter = (self.continuity_counter + 1) % 16;
+        }
+        Ok(packets)
+    }
+}
@@ -1,7 +1,31 @@
-use anyhow::{bail, Result};
-
 mod buffer;
+mod builder;
+mod time;
 pub use self::buffer::*;
+pub use self::builder::*;
+pub use self::time::*;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("too short {len}, expect {expr}({expected})")]
+    TooShort {
+        len: usize,
+        expected: usize,
+        expr: &'static str,
+    },
+    #[error("invalid start code prefix: {0}")]
+    InvalidStartCodePrefix(u32),
+    #[error("malformed pes packet, no data")]
+    MalformedNoData,
+    #[error("discontinued pes packet")]
+    Discontinued,
+    #[error("too long pes packet: {0}")]
+    TooLong(usize),
+    #[error("packet error: {0}")]
+    Packet(#[from] crate::ts::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
 
 pub const PTS_HZ: u64 = 90 * 1000;
 
@@ -15,16 +39,20 @@ const ITU_T_REC_H_222_1_TYPE_E_STREAM: u8 = 0b11111000;
 const PADDING_STREAM: u8 = 0b10111110;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct Todo {}
 
 type DSMTrickMode = Todo;
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct ESCR {
-    base: u64,
-    extension: u16,
+    pub base: u64,
+    pub extension: u16,
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct PESPacket<'a> {
     pub packet_start_code_prefix: u32,
     pub stream_id: u8,
@@ -32,6 +60,7 @@ pub struct PESPacket<'a> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct PESPacketExtension<'a> {
     pub pes_private_data: Option<&'a [u8]>,
     pub pack_header: Option<&'a [u8]>,
@@ -40,9 +69,12 @@ pub struct PESPacketExtension<'a> {
     pub original_stuff_length: Option<u8>,
     pub p_std_buffer_scale: Option<u8>,
     pub p_std_buffer_size: Option<u16>,
+    pub stream_id_extension: Option<u8>,
+    pub tref: Option<u64>,
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct NormalPESPacketBody<'a> {
     pub pes_scrambling_control: u8,
     pub pes_priority: u8,
@@ -57,25 +89,25 @@ pub struct NormalPESPacketBody<'a> {
     pub additional_copy_info: Option<u8>,
     pub previous_pes_packet_crc: Option<u16>,
     pub pes_extension: Option<PESPacketExtension<'a>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub pes_packet_data_byte: &'a [u8],
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub enum PESPacketBody<'a> {
     NormalPESPacketBody(NormalPESPacketBody<'a>),
-    DataBytes(&'a [u8]),
+    DataBytes(#[cfg_attr(feature = "serde", serde(skip))] &'a [u8]),
     PaddingByte,
 }
 
 impl<'a> PESPacket<'a> {
     pub fn parse(bytes: &[u8]) -> Result<PESPacket<'_>> {
-        if bytes.len() < 3 + 1 + 2 {
-            bail!("too short for PES packet {}", bytes.len());
-        }
+        check_len!(bytes.len(), 3 + 1 + 2);
         let packet_start_code_prefix =
             (u32::from(bytes[0]) << 16) | (u32::from(bytes[1]) << 8) | u32::from(bytes[2]);
         if packet_start_code_prefix != 1 {
-            bail!("invalid start code prefix: {}", packet_start_code_prefix);
+            return Err(Error::InvalidStartCodePrefix(packet_start_code_prefix));
         }
         let stream_id = bytes[3];
         let mut pes_packet_length = (usize::from(bytes[4]) << 8) | usize::from(bytes[5]);
@@ -114,11 +146,63 @@ impl<'a> PESPacket<'a> {
     }
 }
 
+/// PTS and DTS read from the head of a PES packet, without requiring the
+/// whole packet. Useful to timestamp a stream from payload unit start packets.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct Timestamps {
+    pub stream_id: u8,
+    pub pts: Option<u64>,
+    pub dts: Option<u64>,
+}
+
+impl Timestamps {
+    pub fn parse(bytes: &[u8]) -> Result<Timestamps> {
+        check_len!(bytes.len(), 3 + 1 + 2);
+        let packet_start_code_prefix =
+            (u32::from(bytes[0]) << 16) | (u32::from(bytes[1]) << 8) | u32::from(bytes[2]);
+        if packet_start_code_prefix != 1 {
+            return Err(Error::InvalidStartCodePrefix(packet_start_code_prefix));
+        }
+        let stream_id = bytes[3];
+        let (pts, dts) = match stream_id {
+            PROGRAM_STREAM_MAP
+            | PRIVATE_STREAM_2
+            | ECM
+            | EMM
+            | PROGRAM_STREAM_DIRECTORY
+            | DSMCC_STREAM
+            | ITU_T_REC_H_222_1_TYPE_E_STREAM
+            | PADDING_STREAM => (None, None),
+            _ => {
+                let bytes = &bytes[6..];
+                check_len!(bytes.len(), 3);
+                let pts_dts_flags = (bytes[1] >> 6) & 3;
+                let bytes = &bytes[3..];
+                match pts_dts_flags {
+                    0b10 => (Some(NormalPESPacketBody::parse_timestamp(bytes)?), None),
+                    0b11 => {
+                        check_len!(bytes.len(), 10);
+                        (
+                            Some(NormalPESPacketBody::parse_timestamp(bytes)?),
+                            Some(NormalPESPacketBody::parse_timestamp(&bytes[5..])?),
+                        )
+                    }
+                    _ => (None, None),
+                }
+            }
+        };
+        Ok(Timestamps {
+            stream_id,
+            pts,
+            dts,
+        })
+    }
+}
+
 impl<'a> NormalPESPacketBody<'a> {
     fn parse(bytes: &[u8]) -> Result<NormalPESPacketBody<'_>> {
-        if bytes.len() < 3 {
-            bail!("too short for pes packet {}", bytes.len());
-        }
+        check_len!(bytes.len(), 3);
         let pes_scrambling_control = (bytes[0] >> 6) & 3;
         let pes_priority = (bytes[0] >> 5) & 1;
         let data_alignment_indicator = (bytes[0] >> 4) & 1;
@@ -171,6 +255,7 @@ impl<'a> NormalPESPacketBody<'a> {
         })
     }
 
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
     fn parse_optional_fields(
         mut bytes: &[u8],
         pts_dts_flags: u8,
@@ -276,11 +361,10 @@ impl<'a> NormalPESPacketBody<'a> {
         let program_packet_sequence_counter_flag = bytes[0] & 0x20 > 0;
         let p_std_buffer_flag = bytes[0] & 0x10 > 0;
         let pes_extension_flag_2 = bytes[0] & 1 > 0;
+        bytes = &bytes[1..];
         let pes_private_data = match pes_private_data_flag {
             true => {
-                if bytes.len() < 16 {
-                    bail!("too short for PES_private_data");
-                }
+                check_len!(bytes.len(), 16);
                 let pes_private_data = &bytes[..16];
                 bytes = &bytes[16..];
                 Some(pes_private_data)
@@ -323,10 +407,24 @@ impl<'a> NormalPESPacketBody<'a> {
             }
             _ => (None, None),
         };
-        if pes_extension_flag_2 {
+        let (stream_id_extension, tref) = if pes_extension_flag_2 {
             check_len!(bytes.len(), 1);
-            let _pes_extension_field_length = usize::from(bytes[0]) & 0x7f;
-        }
+            let pes_extension_field_length = usize::from(bytes[0]) & 0x7f;
+            check_len!(bytes.len(), 1 + pes_extension_field_length);
+            let fields = &bytes[1..1 + pes_extension_field_length];
+            match fields.first() {
+                // stream_id_extension_flag is 0.
+                Some(b) if b & 0x80 == 0 => (Some(b & 0x7f), None),
+                // tref_extension_flag is 0 when TREF is present.
+                Some(b) if b & 0x1 == 0 => {
+                    check_len!(fields.len(), 6);
+                    (None, Some(Self::parse_timestamp(&fields[1..])?))
+                }
+                _ => (None, None),
+            }
+        } else {
+            (None, None)
+        };
         Ok(PESPacketExtension {
             pes_private_data,
             pack_header,
@@ -335,13 +433,13 @@ impl<'a> NormalPESPacketBody<'a> {
             original_stuff_length,
             p_std_buffer_scale,
             p_std_buffer_size,
+            stream_id_extension,
+            tref,
         })
     }
 
     fn parse_timestamp(bytes: &[u8]) -> Result<u64> {
-        if bytes.len() < 5 {
-            bail!("too short for timestamp {}", bytes.len());
-        }
+        check_len!(bytes.len(), 5);
         Ok((u64::from(bytes[0] & 0xe) << 29)
             | (u64::from(bytes[1]) << 22)
             | (u64::from(bytes[2] & 0xfe) << 14)
@@ -350,9 +448,7 @@ impl<'a> NormalPESPacketBody<'a> {
     }
 
     fn parse_escr(bytes: &[u8]) -> Result<ESCR> {
-        if bytes.len() < 6 {
-            bail!("too short for ESCR");
-        }
+        check_len!(bytes.len(), 6);
         let base = (u64::from(bytes[0] & 0x18) << 27)
             | (u64::from(bytes[0] & 0x3) << 28)
             | (u64::from(bytes[1]) << 20)
@@ -0,0 +1,47 @@
+use super::PTS_HZ;
+
+// PTS and DTS are 33 bits wide.
+const PTS_MASK: u64 = (1 << 33) - 1;
+
+/// A PTS or DTS in 90kHz units, subtracted across the 33-bit wrap around by
+/// taking the shorter way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PtsTime(u64);
+
+impl PtsTime {
+    pub fn new(pts: u64) -> PtsTime {
+        PtsTime(pts & PTS_MASK)
+    }
+
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    /// Returns the ticks from `earlier` to self, negative if self is before
+    /// it.
+    pub fn diff(self, earlier: PtsTime) -> i64 {
+        let diff = self.0.wrapping_sub(earlier.0) & PTS_MASK;
+        if diff > PTS_MASK / 2 {
+            diff as i64 - (PTS_MASK as i64 + 1)
+        } else {
+            diff as i64
+        }
+    }
+
+    /// Returns the seconds from `earlier` to self, negative if self is
+    /// before it.
+    pub fn seconds_since(self, earlier: PtsTime) -> f64 {
+        self.diff(earlier) as f64 / PTS_HZ as f64
+    }
+
+    /// Returns the time `ticks` later, wrapping around.
+    pub fn add_ticks(self, ticks: i64) -> PtsTime {
+        PtsTime::new(self.0.wrapping_add(ticks as u64))
+    }
+}
+
+impl From<u64> for PtsTime {
+    fn from(pts: u64) -> PtsTime {
+        PtsTime::new(pts)
+    }
+}
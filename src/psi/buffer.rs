@@ -3,9 +3,11 @@ use std::pin::Pin;
 use std::task::{Context, Poll};
 
 use bytes::{Bytes, BytesMut};
+use log::warn;
 use thiserror;
 use tokio_stream::Stream;
 
+use crate::crc32;
 use crate::ts;
 
 const INITIAL_BUFFER: usize = 4096;
@@ -24,14 +26,21 @@ pub enum BufferError {
 enum State {
     Initial,
     Partial,
-    Full,
 }
 
 pub struct Buffer<S> {
     s: S,
     state: State,
     counter: u8,
+    // sections are sliced out of the payload of the packet carrying their
+    // start, and only ones spanning packets are copied into buf.
+    head: Bytes,
     buf: BytesMut,
+    // a section completed by the bytes before the pointer_field of a packet
+    // starting another one.
+    tail_section: Option<Bytes>,
+    verify_crc: bool,
+    crc_errors: usize,
 }
 
 impl<S> Buffer<S> {
@@ -40,22 +49,43 @@ impl<S> Buffer<S> {
             s: stream,
             state: State::Initial,
             counter: 0,
+            head: Bytes::new(),
             buf: BytesMut::with_capacity(INITIAL_BUFFER),
+            tail_section: None,
+            verify_crc: false,
+            crc_errors: 0,
         }
     }
 
+    /// Drops sections whose CRC_32 does not match.
+    pub fn verify_crc(mut self) -> Self {
+        self.verify_crc = true;
+        self
+    }
+
+    /// Returns the number of sections dropped by CRC_32 mismatch.
+    pub fn crc_errors(&self) -> usize {
+        self.crc_errors
+    }
+
     fn feed_packet(&mut self, packet: ts::TSPacket) -> Result<(), BufferError> {
-        let bytes = match packet.data {
-            Some(ref data) => data.as_ref(),
+        let data = match packet.data {
+            Some(data) => data,
             None => return Err(BufferError::MalformedNoData),
         };
+        let bytes = &data[..];
         if packet.payload_unit_start_indicator {
             let pointer_field = usize::from(bytes[0]);
             if bytes.len() < pointer_field + 1 {
                 return Err(BufferError::MalformedNoSectionHeader);
             }
+            let continued = matches!(self.state, State::Partial)
+                && (self.counter + 1) % 16 == packet.continuity_counter;
+            if continued && pointer_field > 0 {
+                self.finish_pending(&bytes[1..=pointer_field]);
+            }
             self.buf.clear();
-            self.buf.extend_from_slice(&bytes[pointer_field + 1..]);
+            self.head = data.slice(pointer_field + 1..);
             self.counter = packet.continuity_counter;
             self.state = State::Partial;
         } else {
@@ -68,66 +98,113 @@ impl<S> Buffer<S> {
                 self.state = State::Initial;
                 return Err(BufferError::Discontinued);
             }
+            if !self.head.is_empty() {
+                self.buf.extend_from_slice(&self.head);
+                self.head.clear();
+            }
             self.buf.extend_from_slice(bytes);
         }
         Ok(())
     }
-}
 
-impl<S> Stream for Buffer<S>
-where
-    S: Stream<Item = ts::TSPacket> + Unpin,
-{
-    type Item = Result<Bytes, BufferError>;
+    // appends the bytes before the pointer_field to the pending section, and
+    // keeps the section if they complete it.
+    fn finish_pending(&mut self, tail: &[u8]) {
+        if !self.head.is_empty() {
+            self.buf.extend_from_slice(&self.head);
+            self.head.clear();
+        }
+        if self.buf.is_empty() {
+            return;
+        }
+        self.buf.extend_from_slice(tail);
+        if self.buf.len() < 3 {
+            return;
+        }
+        let section_length = (usize::from(self.buf[1] & 0xf) << 8) | usize::from(self.buf[2]);
+        if self.buf.len() >= section_length + 3 {
+            self.tail_section = Some(self.buf.split_to(section_length + 3).freeze());
+        }
+    }
 
-    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        macro_rules! next_valid_packet {
-            () => {{
-                loop {
-                    let packet = match Pin::new(&mut self.s).poll_next(cx) {
-                        Poll::Ready(Some(packet)) => packet,
-                        Poll::Ready(None) => return Poll::Ready(None),
-                        Poll::Pending => return Poll::Pending,
-                    };
-                    if !packet.transport_error_indicator {
-                        break packet;
-                    }
-                }
-            }};
+    /// Feeds a packet of the PID.
+    pub fn feed(&mut self, packet: ts::TSPacket) -> Result<(), BufferError> {
+        if packet.transport_error_indicator {
+            return Ok(());
         }
+        match self.state {
+            State::Initial if !packet.payload_unit_start_indicator => Ok(()),
+            _ => self.feed_packet(packet),
+        }
+    }
 
+    /// Takes a complete section if any.
+    pub fn next_section(&mut self) -> Option<Bytes> {
+        if let State::Initial = self.state {
+            return None;
+        }
         loop {
-            match self.state {
-                State::Initial => {
-                    let packet = next_valid_packet!();
-                    if packet.payload_unit_start_indicator {
-                        self.feed_packet(packet)?;
-                    }
-                }
-                State::Partial => {
-                    if self.buf.len() < 3 {
+            let section = match self.tail_section.take() {
+                Some(section) => section,
+                None => {
+                    let pending = if self.head.is_empty() {
+                        &self.buf[..]
+                    } else {
+                        &self.head[..]
+                    };
+                    if pending.len() < 3 {
                         // not sufficient data for psi header.
-                        let packet = next_valid_packet!();
-                        self.feed_packet(packet)?;
-                        continue;
+                        return None;
                     }
                     let section_length =
-                        (usize::from(self.buf[1] & 0xf) << 8) | usize::from(self.buf[2]);
-                    if self.buf.len() < section_length + 3 {
-                        let packet = next_valid_packet!();
-                        self.feed_packet(packet)?;
-                        continue;
+                        (usize::from(pending[1] & 0xf) << 8) | usize::from(pending[2]);
+                    if pending.len() < section_length + 3 {
+                        return None;
+                    }
+                    if self.head.is_empty() {
+                        self.buf.split_to(section_length + 3).freeze()
+                    } else {
+                        self.head.split_to(section_length + 3)
                     }
-                    self.state = State::Full;
-                }
-                State::Full => {
-                    self.state = State::Partial;
-                    let section_length =
-                        (usize::from(self.buf[1] & 0xf) << 8) | usize::from(self.buf[2]);
-                    let buf = self.buf.split_to(section_length + 3).freeze();
-                    return Poll::Ready(Some(Ok(buf)));
                 }
+            };
+            // only sections of the long form have CRC_32.
+            let section_syntax_indicator = section[1] & 0x80 > 0;
+            if self.verify_crc && section_syntax_indicator && crc32::crc32(&section[..]) != 0 {
+                self.crc_errors += 1;
+                warn!("drop section of table_id {:#x} by crc error", section[0]);
+                continue;
+            }
+            return Some(section);
+        }
+    }
+}
+
+impl Buffer<()> {
+    /// Creates a buffer which is fed packets with `feed` instead of polling a
+    /// stream.
+    pub fn detached() -> Self {
+        Buffer::new(())
+    }
+}
+
+impl<S> Stream for Buffer<S>
+where
+    S: Stream<Item = ts::TSPacket> + Unpin,
+{
+    type Item = Result<Bytes, BufferError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(section) = self.next_section() {
+                return Poll::Ready(Some(Ok(section)));
             }
+            let packet = match Pin::new(&mut self.s).poll_next(cx) {
+                Poll::Ready(Some(packet)) => packet,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            };
+            self.feed(packet)?;
         }
     }
 }
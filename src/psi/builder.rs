@@ -0,0 +1,199 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::crc32;
+use crate::psi::{
+    Error, Result, PROGRAM_ASSOCIATION_SECTION, SELF_STREAM_TABLE_ID, TS_PROGRAM_MAP_SECTION,
+};
+use crate::ts;
+
+// section_length is 10 bits, and must not exceed 1021 for PAT/PMT/SDT.
+const MAX_SECTION_LENGTH: usize = 1021;
+// table_id_extension to last_section_number.
+const EXTENSION_HEADER_LENGTH: usize = 5;
+const CRC_LENGTH: usize = 4;
+const STUFFING_BYTE: u8 = 0xff;
+
+/// An elementary stream entry of a PMT.
+#[derive(Debug, Clone)]
+pub struct PmtStream<'a> {
+    pub stream_type: u8,
+    pub elementary_pid: u16,
+    /// Serialized descriptors.
+    pub descriptors: &'a [u8],
+}
+
+/// A service entry of an SDT.
+#[derive(Debug, Clone)]
+pub struct SdtService<'a> {
+    pub service_id: u16,
+    pub eit_user_defined_flags: u8,
+    pub eit_schedule_flag: u8,
+    pub eit_present_following_flag: u8,
+    pub running_status: u8,
+    pub free_ca_mode: u8,
+    /// Serialized descriptors.
+    pub descriptors: &'a [u8],
+}
+
+/// Constructs a long form section, filling section_length and CRC_32.
+#[derive(Debug, Clone)]
+pub struct SectionBuilder {
+    table_id: u8,
+    table_id_extension: u16,
+    version_number: u8,
+    current_next_indicator: u8,
+    section_number: u8,
+    last_section_number: u8,
+    body: BytesMut,
+}
+
+impl SectionBuilder {
+    /// Starts a section with the body following last_section_number.
+    pub fn new(table_id: u8, table_id_extension: u16, body: &[u8]) -> SectionBuilder {
+        SectionBuilder {
+            table_id,
+            table_id_extension,
+            version_number: 0,
+            current_next_indicator: 1,
+            section_number: 0,
+            last_section_number: 0,
+            body: BytesMut::from(body),
+        }
+    }
+
+    pub fn pat(transport_stream_id: u16, program_association: &[(u16, u16)]) -> SectionBuilder {
+        let mut body = BytesMut::with_capacity(program_association.len() * 4);
+        for (program_number, pid) in program_association.iter() {
+            body.put_u16(*program_number);
+            body.put_u16(0xe000 | pid);
+        }
+        SectionBuilder::new(PROGRAM_ASSOCIATION_SECTION, transport_stream_id, &body[..])
+    }
+
+    pub fn pmt(
+        program_number: u16,
+        pcr_pid: u16,
+        descriptors: &[u8],
+        streams: &[PmtStream],
+    ) -> SectionBuilder {
+        let mut body = BytesMut::new();
+        body.put_u16(0xe000 | pcr_pid);
+        body.put_u16(0xf000 | descriptors.len() as u16);
+        body.extend_from_slice(descriptors);
+        for stream in streams.iter() {
+            body.put_u8(stream.stream_type);
+            body.put_u16(0xe000 | stream.elementary_pid);
+            body.put_u16(0xf000 | stream.descriptors.len() as u16);
+            body.extend_from_slice(stream.descriptors);
+        }
+        SectionBuilder::new(TS_PROGRAM_MAP_SECTION, program_number, &body[..])
+    }
+
+    pub fn sdt(
+        transport_stream_id: u16,
+        original_network_id: u16,
+        services: &[SdtService],
+    ) -> SectionBuilder {
+        let mut body = BytesMut::new();
+        body.put_u16(original_network_id);
+        body.put_u8(0xff);
+        for service in services.iter() {
+            body.put_u16(service.service_id);
+            body.put_u8(
+                0xe0 | (service.eit_user_defined_flags << 2)
+                    | (service.eit_schedule_flag << 1)
+                    | service.eit_present_following_flag,
+            );
+            body.put_u16(
+                (u16::from(service.running_status) << 13)
+                    | (u16::from(service.free_ca_mode) << 12)
+                    | service.descriptors.len() as u16,
+            );
+            body.extend_from_slice(service.descriptors);
+        }
+        SectionBuilder::new(SELF_STREAM_TABLE_ID, transport_stream_id, &body[..])
+    }
+
+    pub fn version_number(mut self, v: u8) -> Self {
+        self.version_number = v;
+        self
+    }
+
+    pub fn current_next_indicator(mut self, v: u8) -> Self {
+        self.current_next_indicator = v;
+        self
+    }
+
+    pub fn section_number(mut self, v: u8) -> Self {
+        self.section_number = v;
+        self
+    }
+
+    pub fn last_section_number(mut self, v: u8) -> Self {
+        self.last_section_number = v;
+        self
+    }
+
+    /// Serializes the section including CRC_32.
+    pub fn build(self) -> Result<Bytes> {
+        let section_length = EXTENSION_HEADER_LENGTH + self.body.len() + CRC_LENGTH;
+        if section_length > MAX_SECTION_LENGTH {
+            return Err(Error::InvalidLength);
+        }
+        let mut out = BytesMut::with_capacity(3 + section_length);
+        out.put_u8(self.table_id);
+        // section_syntax_indicator, '0' and reserved bits.
+        out.put_u16(0xb000 | section_length as u16);
+        out.put_u16(self.table_id_extension);
+        out.put_u8(0xc0 | ((self.version_number & 0x1f) << 1) | (self.current_next_indicator & 1));
+        out.put_u8(self.section_number);
+        out.put_u8(self.last_section_number);
+        out.extend_from_slice(&self.body[..]);
+        let crc = crc32::crc32(&out[..]);
+        out.put_u32(crc);
+        Ok(out.freeze())
+    }
+}
+
+/// Splits sections into TS packets of a PID, keeping the continuity counter
+/// across calls.
+#[derive(Debug, Clone)]
+pub struct SectionPacketizer {
+    pid: u16,
+    continuity_counter: u8,
+}
+
+impl SectionPacketizer {
+    /// The first packet is numbered with `continuity_counter`.
+    pub fn new(pid: u16, continuity_counter: u8) -> SectionPacketizer {
+        SectionPacketizer {
+            pid,
+            continuity_counter: continuity_counter & 0xf,
+        }
+    }
+
+    /// Packetizes a section, starting it right after the pointer_field and
+    /// filling the rest of the last packet with stuffing bytes.
+    pub fn packetize(&mut self, section: &[u8]) -> Result<Vec<ts::TSPacket>> {
+        let payload_length = ts::TS_PACKET_LENGTH - 4;
+        let mut payload = BytesMut::with_capacity(section.len() + 1);
+        // pointer_field
+        payload.put_u8(0);
+        payload.extend_from_slice(section);
+        let mut packets = Vec::new();
+        let mut start = true;
+        while start || !payload.is_empty() {
+            let mut chunk = payload.split_to(payload_length.min(payload.len()));
+            chunk.resize(payload_length, STUFFING_BYTE);
+            let packet = ts::TSPacketBuilder::new(self.pid)
+                .payload_unit_start_indicator(start)
+                .continuity_counter(self.continuity_counter)
+                .payload(Some(chunk.freeze()))
+                .build()?;
+            packets.push(packet);
+            self.continuity_counter = (self.continuity_counter + 1) % 16;
+            start = false;
+        }
+        Ok(packets)
+    }
+}
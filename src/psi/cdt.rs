@@ -0,0 +1,93 @@
+use crate::psi::{Error, Result};
+
+use crate::psi::Descriptors;
+use crate::util;
+
+pub const CDT_PID: u16 = 0x0029;
+pub const COMMON_DATA_TABLE_ID: u8 = 0xc8;
+pub const DATA_TYPE_LOGO: u8 = 0x01;
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct CommonDataSection<'a> {
+    pub table_id: u8,
+    pub download_data_id: u16,
+    pub version_number: u8,
+    pub current_next_indicator: u8,
+    pub section_number: u8,
+    pub last_section_number: u8,
+    pub original_network_id: u16,
+    pub data_type: u8,
+    pub descriptors: Descriptors<'a>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub data_module: &'a [u8],
+    pub crc_32: u32,
+}
+
+impl CommonDataSection<'_> {
+    pub fn parse(bytes: &[u8]) -> Result<CommonDataSection<'_>> {
+        check_len!(bytes.len(), 13);
+        let table_id = bytes[0];
+        if table_id != COMMON_DATA_TABLE_ID {
+            return Err(Error::InvalidTableId(table_id));
+        }
+        let section_length = (usize::from(bytes[1] & 0xf) << 8) | usize::from(bytes[2]);
+        check_len!(bytes.len(), 3 + section_length);
+        check_len!(section_length, 14);
+        let download_data_id = (u16::from(bytes[3]) << 8) | u16::from(bytes[4]);
+        let version_number = (bytes[5] >> 1) & 0x1f;
+        let current_next_indicator = bytes[5] & 0x1;
+        let section_number = bytes[6];
+        let last_section_number = bytes[7];
+        let original_network_id = (u16::from(bytes[8]) << 8) | u16::from(bytes[9]);
+        let data_type = bytes[10];
+        let descriptors_loop_length = (usize::from(bytes[11] & 0xf) << 8) | usize::from(bytes[12]);
+        let end = 3 + section_length - 4;
+        check_len!(end, 13 + descriptors_loop_length);
+        let descriptors = Descriptors::parse(&bytes[13..13 + descriptors_loop_length])?;
+        let data_module = &bytes[13 + descriptors_loop_length..end];
+        let crc_32 = util::read_u32(&bytes[end..]);
+        Ok(CommonDataSection {
+            table_id,
+            download_data_id,
+            version_number,
+            current_next_indicator,
+            section_number,
+            last_section_number,
+            original_network_id,
+            data_type,
+            descriptors,
+            data_module,
+            crc_32,
+        })
+    }
+}
+
+/// The data module of a CDT whose data_type is logo.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct LogoData<'a> {
+    pub logo_type: u8,
+    pub logo_id: u16,
+    pub logo_version: u16,
+    /// PNG without the palette.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub data: &'a [u8],
+}
+
+impl LogoData<'_> {
+    pub fn parse(bytes: &[u8]) -> Result<LogoData<'_>> {
+        check_len!(bytes.len(), 7);
+        let logo_type = bytes[0];
+        let logo_id = (u16::from(bytes[1] & 0x1) << 8) | u16::from(bytes[2]);
+        let logo_version = (u16::from(bytes[3] & 0xf) << 8) | u16::from(bytes[4]);
+        let data_size = (usize::from(bytes[5]) << 8) | usize::from(bytes[6]);
+        check_len!(bytes.len(), 7 + data_size);
+        Ok(LogoData {
+            logo_type,
+            logo_id,
+            logo_version,
+            data: &bytes[7..7 + data_size],
+        })
+    }
+}
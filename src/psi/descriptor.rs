@@ -1,15 +1,30 @@
-use anyhow::{bail, Result};
+use chrono::offset::FixedOffset;
+use chrono::DateTime;
+
+use crate::psi::{jst, Error, Event, Result};
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub enum Descriptor<'a> {
     ShortEventDescriptor(ShortEventDescriptor<'a>),
     ExtendedEventDescriptor(ExtendedEventDescriptor<'a>),
     ContentDescriptor(ContentDescriptor),
     StreamIdentifierDescriptor(StreamIdentifierDescriptor),
+    ServiceDescriptor(ServiceDescriptor<'a>),
+    ComponentDescriptor(ComponentDescriptor<'a>),
+    AudioComponentDescriptor(AudioComponentDescriptor<'a>),
+    LogoTransmissionDescriptor(LogoTransmissionDescriptor<'a>),
+    TerrestrialDeliverySystemDescriptor(TerrestrialDeliverySystemDescriptor),
+    SatelliteDeliverySystemDescriptor(SatelliteDeliverySystemDescriptor),
+    DigitalCopyControlDescriptor(DigitalCopyControlDescriptor),
+    HyperlinkDescriptor(HyperlinkDescriptor<'a>),
+    DataContentDescriptor(DataContentDescriptor<'a>),
+    LocalTimeOffsetDescriptor(LocalTimeOffsetDescriptor),
     Unsupported(UnsupportedDescriptor<'a>),
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct ShortEventDescriptor<'a> {
     pub iso_639_language_code: String,
     pub event_name: &'a [u8],
@@ -20,15 +35,21 @@ impl<'a> ShortEventDescriptor<'a> {
     fn parse(bytes: &[u8]) -> Result<ShortEventDescriptor<'_>> {
         let tag = bytes[0];
         if tag != 0x4d {
-            bail!("invalid tag");
+            return Err(Error::InvalidTag(tag));
         }
-        let iso_639_language_code = String::from_utf8(bytes[2..5].to_vec())?;
-        let event_name_length = usize::from(bytes[5]);
-        let event_name = &bytes[6..6 + event_name_length];
+        let length = usize::from(bytes[1]);
+        check_len!(length, 5);
+        check_len!(bytes.len(), 2 + length);
+        let bytes = &bytes[2..2 + length];
+        let iso_639_language_code = String::from_utf8(bytes[0..3].to_vec())?;
+        let event_name_length = usize::from(bytes[3]);
+        check_len!(bytes.len(), 5 + event_name_length);
+        let event_name = &bytes[4..4 + event_name_length];
         let text;
         {
-            let bytes = &bytes[6 + event_name_length..];
+            let bytes = &bytes[4 + event_name_length..];
             let text_length = usize::from(bytes[0]);
+            check_len!(bytes.len(), 1 + text_length);
             text = &bytes[1..1 + text_length];
         }
         Ok(ShortEventDescriptor {
@@ -40,6 +61,7 @@ impl<'a> ShortEventDescriptor<'a> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct ExtendedEventDescriptorItem<'a> {
     pub item_description: &'a [u8],
     pub item: &'a [u8],
@@ -47,13 +69,16 @@ pub struct ExtendedEventDescriptorItem<'a> {
 
 impl ExtendedEventDescriptorItem<'_> {
     fn parse(bytes: &[u8]) -> Result<(ExtendedEventDescriptorItem<'_>, usize)> {
+        check_len!(bytes.len(), 1);
         let item_description_length = usize::from(bytes[0]);
+        check_len!(bytes.len(), 2 + item_description_length);
         let item_description = &bytes[1..1 + item_description_length];
         let item_length;
         let item;
         {
             let bytes = &bytes[1 + item_description_length..];
             item_length = usize::from(bytes[0]);
+            check_len!(bytes.len(), 1 + item_length);
             item = &bytes[1..1 + item_length];
         }
         Ok((
@@ -67,6 +92,7 @@ impl ExtendedEventDescriptorItem<'_> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct ExtendedEventDescriptor<'a> {
     pub descriptor_number: u8,
     pub last_descriptor_number: u8,
@@ -79,23 +105,29 @@ impl<'a> ExtendedEventDescriptor<'a> {
     fn parse(bytes: &[u8]) -> Result<ExtendedEventDescriptor<'_>> {
         let tag = bytes[0];
         if tag != 0x4e {
-            bail!("invalid tag");
+            return Err(Error::InvalidTag(tag));
         }
-        let descriptor_number = bytes[2] >> 4;
-        let last_descriptor_number = bytes[2] & 0xf;
-        let iso_639_language_code = String::from_utf8((&bytes[3..6]).to_vec())?;
-        let length_of_items = usize::from(bytes[6]);
+        let length = usize::from(bytes[1]);
+        check_len!(length, 6);
+        check_len!(bytes.len(), 2 + length);
+        let bytes = &bytes[2..2 + length];
+        let descriptor_number = bytes[0] >> 4;
+        let last_descriptor_number = bytes[0] & 0xf;
+        let iso_639_language_code = String::from_utf8(bytes[1..4].to_vec())?;
+        let length_of_items = usize::from(bytes[4]);
+        check_len!(bytes.len(), 6 + length_of_items);
         let mut items = Vec::new();
         {
-            let mut bytes = &bytes[7..7 + length_of_items];
-            while bytes.len() > 0 {
+            let mut bytes = &bytes[5..5 + length_of_items];
+            while !bytes.is_empty() {
                 let (item, n) = ExtendedEventDescriptorItem::parse(bytes)?;
                 items.push(item);
                 bytes = &bytes[n..];
             }
         }
-        let bytes = &bytes[7 + length_of_items..];
+        let bytes = &bytes[5 + length_of_items..];
         let text_length = usize::from(bytes[0]);
+        check_len!(bytes.len(), 1 + text_length);
         let text = &bytes[1..1 + text_length];
         Ok(ExtendedEventDescriptor {
             descriptor_number,
@@ -108,11 +140,23 @@ impl<'a> ExtendedEventDescriptor<'a> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct ContentDescriptor {
-    pub items: Vec<Genre>,
+    pub items: Vec<Content>,
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct Content {
+    pub genre: Genre,
+    pub content_nibble_level_1: u8,
+    pub content_nibble_level_2: u8,
+    pub user_nibble_1: u8,
+    pub user_nibble_2: u8,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub enum Genre {
     News,
     Sports,
@@ -131,17 +175,159 @@ pub enum Genre {
     Others,
 }
 
+impl Genre {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Genre::News => "news",
+            Genre::Sports => "sports",
+            Genre::Information => "information",
+            Genre::Drama => "drama",
+            Genre::Music => "music",
+            Genre::Variety => "variety",
+            Genre::Movies => "movies",
+            Genre::Animation => "animation",
+            Genre::Documentary => "documentary",
+            Genre::Theatre => "theatre",
+            Genre::Hobby => "hobby",
+            Genre::Welfare => "welfare",
+            Genre::Reserved => "reserved",
+            Genre::Extention => "extention",
+            Genre::Others => "others",
+        }
+    }
+}
+
+impl Content {
+    /// Returns the name of content_nibble_level_2 defined in ARIB STD-B10
+    /// Annex H.
+    pub fn sub_genre_name(&self) -> Option<&'static str> {
+        let name = match (self.content_nibble_level_1, self.content_nibble_level_2) {
+            (0x0, 0x0) => "regular",
+            (0x0, 0x1) => "weather",
+            (0x0, 0x2) => "feature",
+            (0x0, 0x3) => "politics",
+            (0x0, 0x4) => "economy",
+            (0x0, 0x5) => "international",
+            (0x0, 0x6) => "commentary",
+            (0x0, 0x7) => "discussion",
+            (0x0, 0x8) => "special",
+            (0x0, 0x9) => "local",
+            (0x0, 0xa) => "traffic",
+            (0x1, 0x0) => "news",
+            (0x1, 0x1) => "baseball",
+            (0x1, 0x2) => "soccer",
+            (0x1, 0x3) => "golf",
+            (0x1, 0x4) => "ball-games",
+            (0x1, 0x5) => "sumo-martial-arts",
+            (0x1, 0x6) => "olympics",
+            (0x1, 0x7) => "marathon-athletics-swimming",
+            (0x1, 0x8) => "motor-sports",
+            (0x1, 0x9) => "marine-winter-sports",
+            (0x1, 0xa) => "horse-racing",
+            (0x2, 0x0) => "entertainment",
+            (0x2, 0x1) => "fashion",
+            (0x2, 0x2) => "living",
+            (0x2, 0x3) => "health",
+            (0x2, 0x4) => "shopping",
+            (0x2, 0x5) => "gourmet",
+            (0x2, 0x6) => "events",
+            (0x2, 0x7) => "program-guide",
+            (0x3, 0x0) => "domestic",
+            (0x3, 0x1) => "foreign",
+            (0x3, 0x2) => "period",
+            (0x4, 0x0) => "domestic-rock-pop",
+            (0x4, 0x1) => "foreign-rock-pop",
+            (0x4, 0x2) => "classical-opera",
+            (0x4, 0x3) => "jazz-fusion",
+            (0x4, 0x4) => "kayokyoku-enka",
+            (0x4, 0x5) => "live-concert",
+            (0x4, 0x6) => "ranking-request",
+            (0x4, 0x7) => "karaoke",
+            (0x4, 0x8) => "folk-traditional",
+            (0x4, 0x9) => "children",
+            (0x4, 0xa) => "world",
+            (0x5, 0x0) => "quiz",
+            (0x5, 0x1) => "game",
+            (0x5, 0x2) => "talk",
+            (0x5, 0x3) => "comedy",
+            (0x5, 0x4) => "music",
+            (0x5, 0x5) => "travel",
+            (0x5, 0x6) => "cooking",
+            (0x6, 0x0) => "foreign",
+            (0x6, 0x1) => "domestic",
+            (0x6, 0x2) => "animation",
+            (0x7, 0x0) => "domestic",
+            (0x7, 0x1) => "foreign",
+            (0x7, 0x2) => "tokusatsu",
+            (0x8, 0x0) => "society",
+            (0x8, 0x1) => "history-travel",
+            (0x8, 0x2) => "nature",
+            (0x8, 0x3) => "science",
+            (0x8, 0x4) => "culture",
+            (0x8, 0x5) => "literature",
+            (0x8, 0x6) => "sports",
+            (0x8, 0x7) => "documentary",
+            (0x8, 0x8) => "interview",
+            (0x9, 0x0) => "modern",
+            (0x9, 0x1) => "musical",
+            (0x9, 0x2) => "dance-ballet",
+            (0x9, 0x3) => "rakugo",
+            (0x9, 0x4) => "kabuki-classical",
+            (0xa, 0x0) => "travel-fishing-outdoor",
+            (0xa, 0x1) => "gardening-pets-crafts",
+            (0xa, 0x2) => "music-art-crafts",
+            (0xa, 0x3) => "go-shogi",
+            (0xa, 0x4) => "mahjong-pachinko",
+            (0xa, 0x5) => "cars-motorcycles",
+            (0xa, 0x6) => "computers-games",
+            (0xa, 0x7) => "languages",
+            (0xa, 0x8) => "kids",
+            (0xa, 0x9) => "students",
+            (0xa, 0xa) => "university-exams",
+            (0xa, 0xb) => "lifelong-learning",
+            (0xa, 0xc) => "education-issues",
+            (0xb, 0x0) => "elderly",
+            (0xb, 0x1) => "disabled",
+            (0xb, 0x2) => "social-welfare",
+            (0xb, 0x3) => "volunteer",
+            (0xb, 0x4) => "sign-language",
+            (0xb, 0x5) => "captions",
+            (0xb, 0x6) => "audio-description",
+            (0xe, 0x0) => "bs-terrestrial",
+            (0xe, 0x1) => "wideband-cs",
+            (0xc..=0xd, _) => return None,
+            (_, 0xf) => "others",
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    /// Returns the genre name followed by the sub genre name if any, like
+    /// "sports/soccer".
+    pub fn name(&self) -> String {
+        match self.sub_genre_name() {
+            Some(sub) => format!("{}/{}", self.genre.name(), sub),
+            None => String::from(self.genre.name()),
+        }
+    }
+}
+
 impl ContentDescriptor {
     fn parse(bytes: &[u8]) -> Result<ContentDescriptor> {
         let tag = bytes[0];
         if tag != 0x54 {
-            bail!("invalid tag");
+            return Err(Error::InvalidTag(tag));
         }
         let length = usize::from(bytes[1]);
+        check_len!(bytes.len(), 2 + length);
         let mut bytes = &bytes[2..2 + length];
         let mut items = Vec::new();
-        while bytes.len() > 0 {
+        while !bytes.is_empty() {
+            check_len!(bytes.len(), 2);
             let content_nibble_level_1 = bytes[0] >> 4;
+            let content_nibble_level_2 = bytes[0] & 0xf;
+            let user_nibble_1 = bytes[1] >> 4;
+            let user_nibble_2 = bytes[1] & 0xf;
             let genre = match content_nibble_level_1 {
                 0x0 => Genre::News,
                 0x1 => Genre::Sports,
@@ -160,7 +346,13 @@ impl ContentDescriptor {
                 0xf => Genre::Others,
                 _ => unreachable!(),
             };
-            items.push(genre);
+            items.push(Content {
+                genre,
+                content_nibble_level_1,
+                content_nibble_level_2,
+                user_nibble_1,
+                user_nibble_2,
+            });
             bytes = &bytes[2..];
         }
         Ok(ContentDescriptor { items })
@@ -168,6 +360,7 @@ impl ContentDescriptor {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct StreamIdentifierDescriptor {
     pub component_tag: u8,
 }
@@ -176,14 +369,658 @@ impl StreamIdentifierDescriptor {
     fn parse(bytes: &[u8]) -> Result<StreamIdentifierDescriptor> {
         let tag = bytes[0];
         if tag != 0x52 {
-            bail!("invalid tag");
+            return Err(Error::InvalidTag(tag));
         }
+        let length = usize::from(bytes[1]);
+        check_len!(length, 1);
+        check_len!(bytes.len(), 2 + length);
         let component_tag = bytes[2];
         Ok(StreamIdentifierDescriptor { component_tag })
     }
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct ServiceDescriptor<'a> {
+    pub service_type: u8,
+    pub service_provider_name: &'a [u8],
+    pub service_name: &'a [u8],
+}
+
+impl ServiceDescriptor<'_> {
+    fn parse(bytes: &[u8]) -> Result<ServiceDescriptor<'_>> {
+        let tag = bytes[0];
+        if tag != 0x48 {
+            return Err(Error::InvalidTag(tag));
+        }
+        check_len!(bytes.len(), 4);
+        let service_type = bytes[2];
+        let service_provider_name_length = usize::from(bytes[3]);
+        check_len!(bytes.len(), 5 + service_provider_name_length);
+        let service_provider_name = &bytes[4..4 + service_provider_name_length];
+        let bytes = &bytes[4 + service_provider_name_length..];
+        let service_name_length = usize::from(bytes[0]);
+        check_len!(bytes.len(), 1 + service_name_length);
+        let service_name = &bytes[1..1 + service_name_length];
+        Ok(ServiceDescriptor {
+            service_type,
+            service_provider_name,
+            service_name,
+        })
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct ComponentDescriptor<'a> {
+    pub stream_content: u8,
+    pub component_type: u8,
+    pub component_tag: u8,
+    pub iso_639_language_code: String,
+    pub text: &'a [u8],
+}
+
+impl ComponentDescriptor<'_> {
+    fn parse(bytes: &[u8]) -> Result<ComponentDescriptor<'_>> {
+        let tag = bytes[0];
+        if tag != 0x50 {
+            return Err(Error::InvalidTag(tag));
+        }
+        let length = usize::from(bytes[1]);
+        check_len!(length, 6);
+        check_len!(bytes.len(), 2 + length);
+        let stream_content = bytes[2] & 0xf;
+        let component_type = bytes[3];
+        let component_tag = bytes[4];
+        let iso_639_language_code = String::from_utf8(bytes[5..8].to_vec())?;
+        let text = &bytes[8..2 + length];
+        Ok(ComponentDescriptor {
+            stream_content,
+            component_type,
+            component_tag,
+            iso_639_language_code,
+            text,
+        })
+    }
+
+    /// Returns the video format like "1080i" for video components.
+    pub fn video_format(&self) -> Option<&'static str> {
+        // MPEG-2, H.264 and H.265 video.
+        if !matches!(self.stream_content, 0x1 | 0x5 | 0x9) {
+            return None;
+        }
+        let format = match self.component_type >> 4 {
+            0x0 => "480i",
+            0x9 => "2160p",
+            0xa => "480p",
+            0xb => "1080i",
+            0xc => "720p",
+            0xd => "240p",
+            0xe => "1080p",
+            0xf => "180p",
+            _ => return None,
+        };
+        Some(format)
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct AudioComponentDescriptor<'a> {
+    pub stream_content: u8,
+    pub component_type: u8,
+    pub component_tag: u8,
+    pub stream_type: u8,
+    pub simulcast_group_tag: u8,
+    pub es_multi_lingual_flag: bool,
+    pub main_component_flag: bool,
+    pub quality_indicator: u8,
+    pub sampling_rate: u8,
+    pub iso_639_language_code: String,
+    pub iso_639_language_code_2: Option<String>,
+    pub text: &'a [u8],
+}
+
+impl AudioComponentDescriptor<'_> {
+    fn parse(bytes: &[u8]) -> Result<AudioComponentDescriptor<'_>> {
+        let tag = bytes[0];
+        if tag != 0xc4 {
+            return Err(Error::InvalidTag(tag));
+        }
+        let length = usize::from(bytes[1]);
+        check_len!(length, 9);
+        check_len!(bytes.len(), 2 + length);
+        let stream_content = bytes[2] & 0xf;
+        let component_type = bytes[3];
+        let component_tag = bytes[4];
+        let stream_type = bytes[5];
+        let simulcast_group_tag = bytes[6];
+        let es_multi_lingual_flag = bytes[7] & 0x80 > 0;
+        let main_component_flag = bytes[7] & 0x40 > 0;
+        let quality_indicator = (bytes[7] >> 4) & 0x3;
+        let sampling_rate = (bytes[7] >> 1) & 0x7;
+        let iso_639_language_code = String::from_utf8(bytes[8..11].to_vec())?;
+        let (iso_639_language_code_2, text_start) = if es_multi_lingual_flag {
+            check_len!(length, 12);
+            (Some(String::from_utf8(bytes[11..14].to_vec())?), 14)
+        } else {
+            (None, 11)
+        };
+        let text = &bytes[text_start..2 + length];
+        Ok(AudioComponentDescriptor {
+            stream_content,
+            component_type,
+            component_tag,
+            stream_type,
+            simulcast_group_tag,
+            es_multi_lingual_flag,
+            main_component_flag,
+            quality_indicator,
+            sampling_rate,
+            iso_639_language_code,
+            iso_639_language_code_2,
+            text,
+        })
+    }
+
+    /// Returns the audio mode like "stereo".
+    pub fn mode(&self) -> Option<&'static str> {
+        let mode = match self.component_type {
+            0x01 => "mono",
+            0x02 => "dual-mono",
+            0x03 => "stereo",
+            0x04 => "2/1",
+            0x05 => "3/0",
+            0x06 => "2/2",
+            0x07 => "3/1",
+            0x08 => "5ch",
+            0x09 => "5.1ch",
+            _ => return None,
+        };
+        Some(mode)
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct LogoTransmissionDescriptor<'a> {
+    pub logo_transmission_type: u8,
+    pub logo_id: Option<u16>,
+    pub logo_version: Option<u16>,
+    pub download_data_id: Option<u16>,
+    pub logo_char: Option<&'a [u8]>,
+}
+
+impl LogoTransmissionDescriptor<'_> {
+    fn parse(bytes: &[u8]) -> Result<LogoTransmissionDescriptor<'_>> {
+        let tag = bytes[0];
+        if tag != 0xcf {
+            return Err(Error::InvalidTag(tag));
+        }
+        let length = usize::from(bytes[1]);
+        check_len!(length, 1);
+        check_len!(bytes.len(), 2 + length);
+        let bytes = &bytes[2..2 + length];
+        let logo_transmission_type = bytes[0];
+        let mut descriptor = LogoTransmissionDescriptor {
+            logo_transmission_type,
+            logo_id: None,
+            logo_version: None,
+            download_data_id: None,
+            logo_char: None,
+        };
+        match logo_transmission_type {
+            0x01 => {
+                check_len!(bytes.len(), 7);
+                descriptor.logo_id = Some((u16::from(bytes[1] & 0x1) << 8) | u16::from(bytes[2]));
+                descriptor.logo_version =
+                    Some((u16::from(bytes[3] & 0xf) << 8) | u16::from(bytes[4]));
+                descriptor.download_data_id =
+                    Some((u16::from(bytes[5]) << 8) | u16::from(bytes[6]));
+            }
+            0x02 => {
+                check_len!(bytes.len(), 3);
+                descriptor.logo_id = Some((u16::from(bytes[1] & 0x1) << 8) | u16::from(bytes[2]));
+            }
+            0x03 => descriptor.logo_char = Some(&bytes[1..]),
+            _ => {}
+        }
+        Ok(descriptor)
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct TerrestrialDeliverySystemDescriptor {
+    pub area_code: u16,
+    pub guard_interval: u8,
+    pub transmission_mode: u8,
+    /// Center frequencies in 1/7 MHz.
+    pub frequencies: Vec<u16>,
+}
+
+impl TerrestrialDeliverySystemDescriptor {
+    fn parse(bytes: &[u8]) -> Result<TerrestrialDeliverySystemDescriptor> {
+        let tag = bytes[0];
+        if tag != 0xfa {
+            return Err(Error::InvalidTag(tag));
+        }
+        let length = usize::from(bytes[1]);
+        check_len!(length, 2);
+        check_len!(bytes.len(), 2 + length);
+        let bytes = &bytes[2..2 + length];
+        let area_code = (u16::from(bytes[0]) << 4) | u16::from(bytes[1] >> 4);
+        let guard_interval = (bytes[1] >> 2) & 0x3;
+        let transmission_mode = bytes[1] & 0x3;
+        let frequencies = bytes[2..]
+            .chunks_exact(2)
+            .map(|f| (u16::from(f[0]) << 8) | u16::from(f[1]))
+            .collect();
+        Ok(TerrestrialDeliverySystemDescriptor {
+            area_code,
+            guard_interval,
+            transmission_mode,
+            frequencies,
+        })
+    }
+
+    pub fn guard_interval_name(&self) -> &'static str {
+        match self.guard_interval {
+            0 => "1/32",
+            1 => "1/16",
+            2 => "1/8",
+            _ => "1/4",
+        }
+    }
+
+    pub fn transmission_mode_name(&self) -> &'static str {
+        match self.transmission_mode {
+            0 => "mode1",
+            1 => "mode2",
+            2 => "mode3",
+            _ => "undefined",
+        }
+    }
+
+    pub fn frequencies_mhz(&self) -> Vec<f64> {
+        self.frequencies
+            .iter()
+            .map(|f| f64::from(*f) / 7.0)
+            .collect()
+    }
+}
+
+// reads `digits` BCD digits from the start of bytes.
+fn bcd(bytes: &[u8], digits: usize) -> u32 {
+    (0..digits).fold(0, |n, i| {
+        let b = bytes[i / 2];
+        let digit = if i % 2 == 0 { b >> 4 } else { b & 0xf };
+        n * 10 + u32::from(digit)
+    })
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct SatelliteDeliverySystemDescriptor {
+    /// In 10 kHz.
+    pub frequency: u32,
+    /// In 0.1 degrees.
+    pub orbital_position: u16,
+    pub west_east_flag: u8,
+    pub polarisation: u8,
+    pub modulation: u8,
+    /// In 100 symbols per second.
+    pub symbol_rate: u32,
+    pub fec_inner: u8,
+}
+
+impl SatelliteDeliverySystemDescriptor {
+    fn parse(bytes: &[u8]) -> Result<SatelliteDeliverySystemDescriptor> {
+        let tag = bytes[0];
+        if tag != 0x43 {
+            return Err(Error::InvalidTag(tag));
+        }
+        let length = usize::from(bytes[1]);
+        check_len!(length, 11);
+        check_len!(bytes.len(), 2 + length);
+        let bytes = &bytes[2..2 + length];
+        let frequency = bcd(&bytes[0..4], 8);
+        let orbital_position = bcd(&bytes[4..6], 4) as u16;
+        let west_east_flag = bytes[6] >> 7;
+        let polarisation = (bytes[6] >> 5) & 0x3;
+        let modulation = bytes[6] & 0x1f;
+        let symbol_rate = bcd(&bytes[7..11], 7);
+        let fec_inner = bytes[10] & 0xf;
+        Ok(SatelliteDeliverySystemDescriptor {
+            frequency,
+            orbital_position,
+            west_east_flag,
+            polarisation,
+            modulation,
+            symbol_rate,
+            fec_inner,
+        })
+    }
+
+    pub fn frequency_ghz(&self) -> f64 {
+        f64::from(self.frequency) / 100_000.0
+    }
+
+    pub fn orbital_position_degrees(&self) -> f64 {
+        f64::from(self.orbital_position) / 10.0
+    }
+
+    pub fn polarisation_name(&self) -> &'static str {
+        match self.polarisation {
+            0 => "horizontal",
+            1 => "vertical",
+            2 => "left",
+            _ => "right",
+        }
+    }
+
+    /// Symbol rate in Msymbol/s.
+    pub fn symbol_rate_msps(&self) -> f64 {
+        f64::from(self.symbol_rate) / 10_000.0
+    }
+}
+
+/// Returns the name of digital_recording_control_data like "copy-once".
+pub fn recording_control_name(digital_recording_control_data: u8) -> &'static str {
+    match digital_recording_control_data {
+        0 => "copy-free",
+        1 => "provider-defined",
+        2 => "copy-once",
+        _ => "copy-never",
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct ComponentCopyControl {
+    pub component_tag: u8,
+    pub digital_recording_control_data: u8,
+    /// In 1/4 Mbps.
+    pub maximum_bitrate: Option<u8>,
+    pub user_defined: u8,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct DigitalCopyControlDescriptor {
+    pub digital_recording_control_data: u8,
+    /// In 1/4 Mbps.
+    pub maximum_bitrate: Option<u8>,
+    pub user_defined: u8,
+    /// Controls of components which differ from the whole.
+    pub components: Vec<ComponentCopyControl>,
+}
+
+impl DigitalCopyControlDescriptor {
+    fn parse(bytes: &[u8]) -> Result<DigitalCopyControlDescriptor> {
+        let tag = bytes[0];
+        if tag != 0xc1 {
+            return Err(Error::InvalidTag(tag));
+        }
+        let length = usize::from(bytes[1]);
+        check_len!(length, 1);
+        check_len!(bytes.len(), 2 + length);
+        let bytes = &bytes[2..2 + length];
+        let digital_recording_control_data = bytes[0] >> 6;
+        let maximum_bitrate_flag = bytes[0] & 0x20 > 0;
+        let component_control_flag = bytes[0] & 0x10 > 0;
+        let user_defined = bytes[0] & 0xf;
+        let mut bytes = &bytes[1..];
+        let maximum_bitrate = if maximum_bitrate_flag {
+            check_len!(bytes.len(), 1);
+            let bitrate = bytes[0];
+            bytes = &bytes[1..];
+            Some(bitrate)
+        } else {
+            None
+        };
+        let mut components = Vec::new();
+        if component_control_flag {
+            check_len!(bytes.len(), 1);
+            let component_control_length = usize::from(bytes[0]);
+            check_len!(bytes.len(), 1 + component_control_length);
+            let mut bytes = &bytes[1..1 + component_control_length];
+            while !bytes.is_empty() {
+                check_len!(bytes.len(), 2);
+                let maximum_bitrate_flag = bytes[1] & 0x20 > 0;
+                let n = if maximum_bitrate_flag { 3 } else { 2 };
+                check_len!(bytes.len(), n);
+                components.push(ComponentCopyControl {
+                    component_tag: bytes[0],
+                    digital_recording_control_data: bytes[1] >> 6,
+                    maximum_bitrate: maximum_bitrate_flag.then(|| bytes[2]),
+                    user_defined: bytes[1] & 0xf,
+                });
+                bytes = &bytes[n..];
+            }
+        }
+        Ok(DigitalCopyControlDescriptor {
+            digital_recording_control_data,
+            maximum_bitrate,
+            user_defined,
+            components,
+        })
+    }
+
+    pub fn recording_control_name(&self) -> &'static str {
+        recording_control_name(self.digital_recording_control_data)
+    }
+}
+
+/// A link to a service, an event, a module of data broadcasting, a content
+/// or a node. The ids are given for the link destination type.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct HyperlinkDescriptor<'a> {
+    pub hyper_linkage_type: u8,
+    pub link_destination_type: u8,
+    pub original_network_id: Option<u16>,
+    pub transport_stream_id: Option<u16>,
+    pub service_id: Option<u16>,
+    pub event_id: Option<u16>,
+    pub component_tag: Option<u8>,
+    pub module_id: Option<u16>,
+    pub content_id: Option<u32>,
+    pub information_provider_id: Option<u16>,
+    pub event_relation_id: Option<u16>,
+    pub node_id: Option<u16>,
+    pub uri: Option<&'a [u8]>,
+    pub private_data: &'a [u8],
+}
+
+impl HyperlinkDescriptor<'_> {
+    fn parse(bytes: &[u8]) -> Result<HyperlinkDescriptor<'_>> {
+        let tag = bytes[0];
+        if tag != 0xc5 {
+            return Err(Error::InvalidTag(tag));
+        }
+        let length = usize::from(bytes[1]);
+        check_len!(length, 3);
+        check_len!(bytes.len(), 2 + length);
+        let bytes = &bytes[2..2 + length];
+        let selector_length = usize::from(bytes[2]);
+        check_len!(bytes.len(), 3 + selector_length);
+        let selector = &bytes[3..3 + selector_length];
+        let u16_at = |i: usize| (u16::from(selector[i]) << 8) | u16::from(selector[i + 1]);
+        let mut descriptor = HyperlinkDescriptor {
+            hyper_linkage_type: bytes[0],
+            link_destination_type: bytes[1],
+            original_network_id: None,
+            transport_stream_id: None,
+            service_id: None,
+            event_id: None,
+            component_tag: None,
+            module_id: None,
+            content_id: None,
+            information_provider_id: None,
+            event_relation_id: None,
+            node_id: None,
+            uri: None,
+            private_data: &bytes[3 + selector_length..],
+        };
+        match descriptor.link_destination_type {
+            0x01..=0x05 => {
+                check_len!(selector.len(), 6);
+                descriptor.original_network_id = Some(u16_at(0));
+                descriptor.transport_stream_id = Some(u16_at(2));
+                descriptor.service_id = Some(u16_at(4));
+                let content_id = || (u32::from(u16_at(6)) << 16) | u32::from(u16_at(8));
+                match descriptor.link_destination_type {
+                    0x02 => {
+                        check_len!(selector.len(), 8);
+                        descriptor.event_id = Some(u16_at(6));
+                    }
+                    0x03 => {
+                        check_len!(selector.len(), 11);
+                        descriptor.event_id = Some(u16_at(6));
+                        descriptor.component_tag = Some(selector[8]);
+                        descriptor.module_id = Some(u16_at(9));
+                    }
+                    0x04 => {
+                        check_len!(selector.len(), 10);
+                        descriptor.content_id = Some(content_id());
+                    }
+                    0x05 => {
+                        check_len!(selector.len(), 13);
+                        descriptor.content_id = Some(content_id());
+                        descriptor.component_tag = Some(selector[10]);
+                        descriptor.module_id = Some(u16_at(11));
+                    }
+                    _ => {}
+                }
+            }
+            0x06 => {
+                check_len!(selector.len(), 6);
+                descriptor.information_provider_id = Some(u16_at(0));
+                descriptor.event_relation_id = Some(u16_at(2));
+                descriptor.node_id = Some(u16_at(4));
+            }
+            0x07 => descriptor.uri = Some(selector),
+            _ => {}
+        }
+        Ok(descriptor)
+    }
+}
+
+/// The data broadcasting of an event.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct DataContentDescriptor<'a> {
+    pub data_component_id: u16,
+    pub entry_component: u8,
+    pub selector: &'a [u8],
+    /// Component tags of the streams the data broadcasting uses.
+    pub component_refs: &'a [u8],
+    pub iso_639_language_code: String,
+    pub text: &'a [u8],
+}
+
+impl DataContentDescriptor<'_> {
+    fn parse(bytes: &[u8]) -> Result<DataContentDescriptor<'_>> {
+        let tag = bytes[0];
+        if tag != 0xc7 {
+            return Err(Error::InvalidTag(tag));
+        }
+        let length = usize::from(bytes[1]);
+        check_len!(length, 4);
+        check_len!(bytes.len(), 2 + length);
+        let bytes = &bytes[2..2 + length];
+        let data_component_id = (u16::from(bytes[0]) << 8) | u16::from(bytes[1]);
+        let entry_component = bytes[2];
+        let selector_length = usize::from(bytes[3]);
+        check_len!(bytes.len(), 5 + selector_length);
+        let selector = &bytes[4..4 + selector_length];
+        let bytes = &bytes[4 + selector_length..];
+        let num_of_component_ref = usize::from(bytes[0]);
+        check_len!(bytes.len(), 1 + num_of_component_ref + 4);
+        let component_refs = &bytes[1..1 + num_of_component_ref];
+        let bytes = &bytes[1 + num_of_component_ref..];
+        let iso_639_language_code = String::from_utf8(bytes[0..3].to_vec())?;
+        let text_length = usize::from(bytes[3]);
+        check_len!(bytes.len(), 4 + text_length);
+        let text = &bytes[4..4 + text_length];
+        Ok(DataContentDescriptor {
+            data_component_id,
+            entry_component,
+            selector,
+            component_refs,
+            iso_639_language_code,
+            text,
+        })
+    }
+}
+
+/// The offset of the local time of a region from JST, and its change.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct LocalTimeOffset {
+    pub country_code: String,
+    pub country_region_id: u8,
+    /// 0 if the local time is ahead of JST, 1 if behind.
+    pub local_time_offset_polarity: u8,
+    /// In minutes.
+    pub local_time_offset: u16,
+    pub time_of_change: Option<DateTime<FixedOffset>>,
+    /// In minutes, with the same polarity.
+    pub next_time_offset: u16,
+}
+
+impl LocalTimeOffset {
+    /// Returns the zone of the local time at the time.
+    pub fn zone_at(&self, time: &DateTime<FixedOffset>) -> FixedOffset {
+        let offset = match self.time_of_change {
+            Some(change) if *time >= change => self.next_time_offset,
+            _ => self.local_time_offset,
+        };
+        let mut seconds = i32::from(offset) * 60;
+        if self.local_time_offset_polarity == 1 {
+            seconds = -seconds;
+        }
+        FixedOffset::east_opt(jst().local_minus_utc() + seconds).unwrap_or_else(jst)
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct LocalTimeOffsetDescriptor {
+    pub offsets: Vec<LocalTimeOffset>,
+}
+
+impl LocalTimeOffsetDescriptor {
+    fn parse(bytes: &[u8]) -> Result<LocalTimeOffsetDescriptor> {
+        let tag = bytes[0];
+        if tag != 0x58 {
+            return Err(Error::InvalidTag(tag));
+        }
+        let length = usize::from(bytes[1]);
+        check_len!(bytes.len(), 2 + length);
+        let mut offsets = Vec::new();
+        for bytes in bytes[2..2 + length].chunks_exact(13) {
+            // offsets are 4 BCD digits of hours and minutes.
+            let minutes = |bytes: &[u8]| {
+                let hhmm = bcd(bytes, 4) as u16;
+                hhmm / 100 * 60 + hhmm % 100
+            };
+            offsets.push(LocalTimeOffset {
+                country_code: String::from_utf8(bytes[0..3].to_vec())?,
+                country_region_id: bytes[3] >> 2,
+                local_time_offset_polarity: bytes[3] & 0x1,
+                local_time_offset: minutes(&bytes[4..6]),
+                time_of_change: Event::parse_datetime(&bytes[6..11])?,
+                next_time_offset: minutes(&bytes[11..13]),
+            });
+        }
+        Ok(LocalTimeOffsetDescriptor { offsets })
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct UnsupportedDescriptor<'a> {
     pub descriptor_tag: u8,
     pub data: &'a [u8],
@@ -193,6 +1030,7 @@ impl<'a> UnsupportedDescriptor<'a> {
     fn parse(bytes: &[u8]) -> Result<UnsupportedDescriptor<'_>> {
         let descriptor_tag = bytes[0];
         let length = usize::from(bytes[1]);
+        check_len!(bytes.len(), 2 + length);
         Ok(UnsupportedDescriptor {
             descriptor_tag,
             data: &bytes[2..2 + length],
@@ -212,8 +1050,110 @@ impl<'a> Descriptor<'a> {
             0x52 => {
                 Descriptor::StreamIdentifierDescriptor(StreamIdentifierDescriptor::parse(bytes)?)
             }
+            0x48 => Descriptor::ServiceDescriptor(ServiceDescriptor::parse(bytes)?),
+            0x50 => Descriptor::ComponentDescriptor(ComponentDescriptor::parse(bytes)?),
+            0xc4 => Descriptor::AudioComponentDescriptor(AudioComponentDescriptor::parse(bytes)?),
+            0xcf => {
+                Descriptor::LogoTransmissionDescriptor(LogoTransmissionDescriptor::parse(bytes)?)
+            }
+            0xfa => Descriptor::TerrestrialDeliverySystemDescriptor(
+                TerrestrialDeliverySystemDescriptor::parse(bytes)?,
+            ),
+            0x43 => Descriptor::SatelliteDeliverySystemDescriptor(
+                SatelliteDeliverySystemDescriptor::parse(bytes)?,
+            ),
+            0xc1 => Descriptor::DigitalCopyControlDescriptor(DigitalCopyControlDescriptor::parse(
+                bytes,
+            )?),
+            0xc5 => Descriptor::HyperlinkDescriptor(HyperlinkDescriptor::parse(bytes)?),
+            0xc7 => Descriptor::DataContentDescriptor(DataContentDescriptor::parse(bytes)?),
+            0x58 => Descriptor::LocalTimeOffsetDescriptor(LocalTimeOffsetDescriptor::parse(bytes)?),
             _ => Descriptor::Unsupported(UnsupportedDescriptor::parse(bytes)?),
         };
-        return Ok((descriptor, descriptor_length + 2));
+        Ok((descriptor, descriptor_length + 2))
+    }
+}
+
+/// A descriptor loop, whose descriptors are decoded as it is iterated.
+#[derive(Clone, Copy)]
+pub struct Descriptors<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Descriptors<'a> {
+    /// Checks that the descriptors fill the loop, without decoding them.
+    pub fn parse(bytes: &'a [u8]) -> Result<Descriptors<'a>> {
+        let mut rest = bytes;
+        while !rest.is_empty() {
+            check_len!(rest.len(), 2);
+            let n = 2 + usize::from(rest[1]);
+            check_len!(rest.len(), n);
+            rest = &rest[n..];
+        }
+        Ok(Descriptors { bytes })
+    }
+
+    pub fn iter(&self) -> DescriptorIter<'a> {
+        DescriptorIter { bytes: self.bytes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the serialized descriptors.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a> IntoIterator for &Descriptors<'a> {
+    type Item = Descriptor<'a>;
+    type IntoIter = DescriptorIter<'a>;
+
+    fn into_iter(self) -> DescriptorIter<'a> {
+        self.iter()
+    }
+}
+
+impl std::fmt::Debug for Descriptors<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Descriptors<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+pub struct DescriptorIter<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Iterator for DescriptorIter<'a> {
+    type Item = Descriptor<'a>;
+
+    fn next(&mut self) -> Option<Descriptor<'a>> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+        // the loop is checked by Descriptors::parse.
+        let (bytes, rest) = self.bytes.split_at(2 + usize::from(self.bytes[1]));
+        self.bytes = rest;
+        // a malformed descriptor is left undecoded.
+        let descriptor = match Descriptor::parse(bytes) {
+            Ok((descriptor, _)) => descriptor,
+            Err(_) => Descriptor::Unsupported(UnsupportedDescriptor {
+                descriptor_tag: bytes[0],
+                data: &bytes[2..],
+            }),
+        };
+        Some(descriptor)
     }
 }
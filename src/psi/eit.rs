@@ -3,23 +3,35 @@ use chrono;
 use self::chrono::offset::{FixedOffset, TimeZone};
 use self::chrono::{DateTime, Duration};
 
-use anyhow::{bail, Result};
+use crate::psi::{Error, Result};
 
 use crate::util;
 
-use crate::psi::Descriptor;
+use crate::psi::Descriptors;
+
+/// Returns the zone of JST, which times in SI are coded in. Local times
+/// which differ from it are signalled by local time offset descriptors.
+pub fn jst() -> FixedOffset {
+    FixedOffset::east_opt(9 * 3600).unwrap()
+}
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct Event<'a> {
     pub event_id: u16,
     pub start_time: Option<DateTime<FixedOffset>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "util::serialize_duration_seconds")
+    )]
     pub duration: Option<Duration>,
     pub running_status: u8,
     pub free_ca_mode: bool,
-    pub descriptors: Vec<Descriptor<'a>>,
+    pub descriptors: Descriptors<'a>,
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub enum ScheduleType {
     SelfNow,
     OtherNow,
@@ -28,6 +40,7 @@ pub enum ScheduleType {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct EventInformationSection<'a> {
     pub table_id: u8,
     pub section_syntax_indicator: u8,
@@ -43,6 +56,7 @@ pub struct EventInformationSection<'a> {
     pub events: Vec<Event<'a>>,
     pub crc_32: u32,
 
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub raw_bytes: &'a [u8],
     pub schedule_type: ScheduleType,
 }
@@ -59,13 +73,7 @@ impl<'a> Event<'a> {
         let free_ca_mode = (bytes[10] >> 4) & 1 > 0;
         let descriptors_loop_length = (usize::from(bytes[10] & 0xf) << 8) | usize::from(bytes[11]);
         check_len!(bytes.len() - 12, descriptors_loop_length);
-        let mut bytes = &bytes[12..descriptors_loop_length + 12];
-        let mut descriptors = Vec::new();
-        while bytes.len() > 0 {
-            let (desc, n) = Descriptor::parse(bytes)?;
-            descriptors.push(desc);
-            bytes = &bytes[n..];
-        }
+        let descriptors = Descriptors::parse(&bytes[12..descriptors_loop_length + 12])?;
         Ok((
             Event {
                 event_id,
@@ -79,8 +87,9 @@ impl<'a> Event<'a> {
         ))
     }
 
-    fn parse_datetime(bytes: &[u8]) -> Result<Option<DateTime<FixedOffset>>> {
-        if (&bytes[..5]).iter().all(|x| *x == 0xff) {
+    pub(crate) fn parse_datetime(bytes: &[u8]) -> Result<Option<DateTime<FixedOffset>>> {
+        check_len!(bytes.len(), 5);
+        if bytes[..5].iter().all(|x| *x == 0xff) {
             return Ok(None);
         }
         // Date part is lower 16 bits of MJD.
@@ -89,16 +98,15 @@ impl<'a> Event<'a> {
         let jd = mjd + 2400000 + 1;
         let (y, m, d) = Event::jd_to_gregorian(jd);
 
-        // Time part is JST BCD.
-        let (hh, mm, ss) = Event::parse_hms(&bytes[2..])?.unwrap();
+        // Time part is JST BCD, which is unspecified only with the date.
+        let invalid = || Error::InvalidTime(bytes[..5].to_vec());
+        let (hh, mm, ss) = Event::parse_hms(&bytes[2..])?.ok_or_else(invalid)?;
 
-        Ok(Some(
-            FixedOffset::east_opt(9 * 3600)
-                .unwrap()
-                .with_ymd_and_hms(y as i32, m, d, u32::from(hh), u32::from(mm), u32::from(ss))
-                .single()
-                .unwrap(),
-        ))
+        jst()
+            .with_ymd_and_hms(y as i32, m, d, u32::from(hh), u32::from(mm), u32::from(ss))
+            .single()
+            .map(Some)
+            .ok_or_else(invalid)
     }
 
     fn jd_to_gregorian(jd: u32) -> (u32, u32, u32) {
@@ -126,15 +134,17 @@ impl<'a> Event<'a> {
     }
 
     fn parse_hms(bytes: &[u8]) -> Result<Option<(u8, u8, u8)>> {
+        check_len!(bytes.len(), 3);
         // if the duration is unspecified, all bits are 1.
         if bytes[0] == 0xff && bytes[1] == 0xff && bytes[2] == 0xff {
             return Ok(None);
         }
         // It is encoded by BCD.
-        let h = ((bytes[0] >> 4) * 10) + (bytes[0] & 0xf);
-        let m = ((bytes[1] >> 4) * 10) + (bytes[1] & 0xf);
-        let s = ((bytes[2] >> 4) * 10) + (bytes[2] & 0xf);
-        Ok(Some((h, m, s)))
+        if bytes[..3].iter().any(|b| b >> 4 > 9 || b & 0xf > 9) {
+            return Err(Error::InvalidTime(bytes[..3].to_vec()));
+        }
+        let bcd = |b: u8| (b >> 4) * 10 + (b & 0xf);
+        Ok(Some((bcd(bytes[0]), bcd(bytes[1]), bcd(bytes[2]))))
     }
 }
 
@@ -156,13 +166,13 @@ impl<'a> EventInformationSection<'a> {
         let mut events = Vec::new();
         {
             let mut bytes = &bytes[14..3 + section_length - 4];
-            while bytes.len() > 0 {
+            while !bytes.is_empty() {
                 let (event, n) = Event::parse(bytes)?;
                 events.push(event);
                 bytes = &bytes[n..];
             }
         }
-        let crc_32 = util::read_u32(&bytes[3 + section_length - 4..])?;
+        let crc_32 = util::read_u32(&bytes[3 + section_length - 4..]);
         Ok(EventInformationSection {
             table_id,
             section_syntax_indicator,
@@ -186,8 +196,8 @@ impl<'a> EventInformationSection<'a> {
         match table_id {
             0x4e => ScheduleType::SelfNow,
             0x4f => ScheduleType::OtherNow,
-            n if 0x50 <= n && n <= 0x5f => ScheduleType::SelfFuture,
-            n if 0x60 <= n && n <= 0x6f => ScheduleType::OtherFuture,
+            n if (0x50..=0x5f).contains(&n) => ScheduleType::SelfFuture,
+            n if (0x60..=0x6f).contains(&n) => ScheduleType::OtherFuture,
             _ => {
                 unreachable!("invalid table_id: {}", table_id);
             }
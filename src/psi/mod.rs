@@ -1,6 +1,11 @@
+use std::string::FromUtf8Error;
+
 mod buffer;
 pub use self::buffer::*;
 
+mod table;
+pub use self::table::*;
+
 mod pat;
 pub use self::pat::*;
 
@@ -8,7 +13,7 @@ mod pmt;
 pub use self::pmt::*;
 
 pub mod descriptor;
-pub use self::descriptor::Descriptor;
+pub use self::descriptor::{Descriptor, Descriptors};
 
 mod eit;
 pub use self::eit::*;
@@ -16,6 +21,42 @@ pub use self::eit::*;
 mod sdt;
 pub use self::sdt::*;
 
+mod nit;
+pub use self::nit::*;
+
+mod tot;
+pub use self::tot::*;
+
+mod cdt;
+pub use self::cdt::*;
+
+mod builder;
+pub use self::builder::*;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("too short {len}, expect {expr}({expected})")]
+    TooShort {
+        len: usize,
+        expected: usize,
+        expr: &'static str,
+    },
+    #[error("invalid table_id: {0}")]
+    InvalidTableId(u8),
+    #[error("invalid descriptor tag: {0}")]
+    InvalidTag(u8),
+    #[error("invalid length")]
+    InvalidLength,
+    #[error("invalid time: {0:02x?}")]
+    InvalidTime(Vec<u8>),
+    #[error("invalid string: {0}")]
+    InvalidString(#[from] FromUtf8Error),
+    #[error("packet error: {0}")]
+    Packet(#[from] crate::ts::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
 pub const PROGRAM_ASSOCIATION_SECTION: u8 = 0;
 #[allow(dead_code)]
 pub const CONDITIONAL_ACCESS_SECTION: u8 = 1;
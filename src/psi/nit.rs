@@ -0,0 +1,96 @@
+use crate::psi::{Error, Result};
+
+use crate::psi::Descriptors;
+use crate::util;
+
+pub const NIT_PID: u16 = 0x0010;
+pub const SELF_NETWORK_TABLE_ID: u8 = 0x40;
+pub const OTHER_NETWORK_TABLE_ID: u8 = 0x41;
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct TransportStream<'a> {
+    pub transport_stream_id: u16,
+    pub original_network_id: u16,
+    pub descriptors: Descriptors<'a>,
+}
+
+impl TransportStream<'_> {
+    fn parse(bytes: &[u8]) -> Result<(TransportStream<'_>, usize)> {
+        check_len!(bytes.len(), 6);
+        let transport_stream_id = (u16::from(bytes[0]) << 8) | u16::from(bytes[1]);
+        let original_network_id = (u16::from(bytes[2]) << 8) | u16::from(bytes[3]);
+        let transport_descriptors_length =
+            (usize::from(bytes[4] & 0xf) << 8) | usize::from(bytes[5]);
+        check_len!(bytes.len(), 6 + transport_descriptors_length);
+        let descriptors = Descriptors::parse(&bytes[6..6 + transport_descriptors_length])?;
+        Ok((
+            TransportStream {
+                transport_stream_id,
+                original_network_id,
+                descriptors,
+            },
+            6 + transport_descriptors_length,
+        ))
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct NetworkInformationSection<'a> {
+    pub table_id: u8,
+    pub network_id: u16,
+    pub version_number: u8,
+    pub current_next_indicator: u8,
+    pub section_number: u8,
+    pub last_section_number: u8,
+    pub network_descriptors: Descriptors<'a>,
+    pub transport_streams: Vec<TransportStream<'a>>,
+    pub crc32: u32,
+}
+
+impl NetworkInformationSection<'_> {
+    pub fn parse(bytes: &[u8]) -> Result<NetworkInformationSection<'_>> {
+        check_len!(bytes.len(), 12);
+        let table_id = bytes[0];
+        if table_id != SELF_NETWORK_TABLE_ID && table_id != OTHER_NETWORK_TABLE_ID {
+            return Err(Error::InvalidTableId(table_id));
+        }
+        let section_length = (usize::from(bytes[1] & 0xf) << 8) | usize::from(bytes[2]);
+        check_len!(bytes.len(), 3 + section_length);
+        check_len!(section_length, 13);
+        let network_id = (u16::from(bytes[3]) << 8) | u16::from(bytes[4]);
+        let version_number = (bytes[5] >> 1) & 0x1f;
+        let current_next_indicator = bytes[5] & 0x1;
+        let section_number = bytes[6];
+        let last_section_number = bytes[7];
+        let end = 3 + section_length - 4;
+        let network_descriptors_length = (usize::from(bytes[8] & 0xf) << 8) | usize::from(bytes[9]);
+        check_len!(end, 12 + network_descriptors_length);
+        let network_descriptors = Descriptors::parse(&bytes[10..10 + network_descriptors_length])?;
+        let rest = &bytes[10 + network_descriptors_length..end];
+        let transport_stream_loop_length = (usize::from(rest[0] & 0xf) << 8) | usize::from(rest[1]);
+        check_len!(rest.len(), 2 + transport_stream_loop_length);
+        let mut transport_streams = Vec::new();
+        {
+            let mut bytes = &rest[2..2 + transport_stream_loop_length];
+            while !bytes.is_empty() {
+                let (ts, n) = TransportStream::parse(bytes)?;
+                transport_streams.push(ts);
+                bytes = &bytes[n..];
+            }
+        }
+        let crc32 = util::read_u32(&bytes[end..]);
+        Ok(NetworkInformationSection {
+            table_id,
+            network_id,
+            version_number,
+            current_next_indicator,
+            section_number,
+            last_section_number,
+            network_descriptors,
+            transport_streams,
+            crc32,
+        })
+    }
+}
@@ -1,9 +1,10 @@
-use anyhow::{bail, Result};
+use crate::psi::{Error, Result};
 
 use crate::crc32;
 use crate::util;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct ProgramAssociationSection<'a> {
     pub table_id: u8,
     pub section_syntax_indicator: u8,
@@ -15,6 +16,7 @@ pub struct ProgramAssociationSection<'a> {
     pub program_association: Vec<(u16, u16)>,
     pub crc_32: u32,
 
+    #[cfg_attr(feature = "serde", serde(skip))]
     _raw_bytes: &'a [u8],
 }
 
@@ -22,7 +24,7 @@ impl<'a> ProgramAssociationSection<'a> {
     pub fn parse(bytes: &[u8]) -> Result<ProgramAssociationSection<'_>> {
         let table_id = bytes[0];
         if table_id != 0 {
-            bail!("invalid table_id: {}", table_id);
+            return Err(Error::InvalidTableId(table_id));
         }
         let section_syntax_indicator = bytes[1] >> 7;
         let section_length = (usize::from(bytes[1] & 0xf) << 8) | usize::from(bytes[2]);
@@ -36,10 +38,10 @@ impl<'a> ProgramAssociationSection<'a> {
         check_len!(bytes.len(), 3 + section_length);
         let mut map = &bytes[8..3 + section_length - 4];
         let mut program_association = Vec::new();
-        if map.len() % 4 != 0 {
-            bail!("invalid length");
+        if !map.len().is_multiple_of(4) {
+            return Err(Error::InvalidLength);
         }
-        while map.len() > 0 {
+        while !map.is_empty() {
             let program_number = (u16::from(map[0]) << 8) | u16::from(map[1]);
             let pid = (u16::from(map[2] & 0x1f) << 8) | u16::from(map[3]);
             program_association.push((program_number, pid));
@@ -47,7 +49,7 @@ impl<'a> ProgramAssociationSection<'a> {
         }
 
         let crc_bytes = &bytes[3 + section_length - 4..];
-        let crc_32 = util::read_u32(crc_bytes)?;
+        let crc_32 = util::read_u32(crc_bytes);
 
         Ok(ProgramAssociationSection {
             table_id,
@@ -65,6 +67,6 @@ impl<'a> ProgramAssociationSection<'a> {
 
     #[allow(dead_code)]
     fn calculate_crc32(&self) -> u32 {
-        return crc32::crc32(self._raw_bytes);
+        crc32::crc32(self._raw_bytes)
     }
 }
@@ -1,19 +1,22 @@
-use anyhow::{bail, Result};
+use crate::psi::{Error, Result};
 
 use crate::util;
 
-use crate::psi::descriptor::Descriptor;
+use crate::psi::descriptor::Descriptors;
 
 pub const STREAM_TYPE_VIDEO: u8 = 0x2;
 pub const STREAM_TYPE_PES_PRIVATE_DATA: u8 = 0x6;
 pub const STREAM_TYPE_ADTS: u8 = 0xf;
 pub const STREAM_TYPE_H264: u8 = 0x1b;
+pub const STREAM_TYPE_H265: u8 = 0x24;
+pub const STREAM_TYPE_DSMCC_TYPE_D: u8 = 0xd;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct StreamInfo<'a> {
     pub stream_type: u8,
     pub elementary_pid: u16,
-    pub descriptors: Vec<Descriptor<'a>>,
+    pub descriptors: Descriptors<'a>,
 }
 
 impl<'a> StreamInfo<'a> {
@@ -23,14 +26,7 @@ impl<'a> StreamInfo<'a> {
         let elementary_pid = (u16::from(bytes[1] & 0x1f) << 8) | u16::from(bytes[2]);
         let es_info_length = (usize::from(bytes[3] & 0xf) << 8) | usize::from(bytes[4]);
         check_len!(bytes.len(), 5 + es_info_length);
-        let mut descriptors = vec![];
-        let mut bytes = &bytes[5..5 + es_info_length];
-        while bytes.len() > 0 {
-            let (descriptor, n) = Descriptor::parse(bytes)?;
-            descriptors.push(descriptor);
-            check_len!(bytes.len(), n);
-            bytes = &bytes[n..];
-        }
+        let descriptors = Descriptors::parse(&bytes[5..5 + es_info_length])?;
         Ok((
             StreamInfo {
                 stream_type,
@@ -43,6 +39,7 @@ impl<'a> StreamInfo<'a> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct TSProgramMapSection<'a> {
     pub table_id: u8,
     pub section_syntax_indicator: u8,
@@ -52,7 +49,7 @@ pub struct TSProgramMapSection<'a> {
     pub section_number: u8,
     pub last_section_number: u8,
     pub pcr_pid: u16,
-    pub descriptors: Vec<Descriptor<'a>>,
+    pub descriptors: Descriptors<'a>,
     pub stream_info: Vec<StreamInfo<'a>>,
     pub crc_32: u32,
 }
@@ -61,7 +58,7 @@ impl<'a> TSProgramMapSection<'a> {
     pub fn parse(bytes: &[u8]) -> Result<TSProgramMapSection<'_>> {
         let table_id = bytes[0];
         if table_id != 0x02 {
-            bail!("table_id should 0x02, {}", table_id);
+            return Err(Error::InvalidTableId(table_id));
         }
         let section_syntax_indicator = bytes[1] >> 7;
         let section_length = (usize::from(bytes[1] & 0xf) << 8) | usize::from(bytes[2]);
@@ -76,28 +73,20 @@ impl<'a> TSProgramMapSection<'a> {
 
         check_len!(bytes.len(), 3 + section_length);
         check_len!(bytes.len(), 12 + program_info_length);
-        let mut descriptors = vec![];
-        {
-            let mut bytes = &bytes[12..12 + program_info_length];
-            while bytes.len() > 0 {
-                let (descriptor, n) = Descriptor::parse(bytes)?;
-                descriptors.push(descriptor);
-                bytes = &bytes[n..];
-            }
-        }
+        let descriptors = Descriptors::parse(&bytes[12..12 + program_info_length])?;
 
         let mut stream_info = vec![];
         {
             let mut bytes = &bytes[12 + program_info_length..3 + section_length - 4];
-            while bytes.len() > 0 {
+            while !bytes.is_empty() {
                 let (info, n) = StreamInfo::parse(bytes)?;
                 stream_info.push(info);
                 check_len!(bytes.len(), n);
                 bytes = &bytes[n..];
             }
         }
-        let crc_32 = util::read_u32(&bytes[3 + section_length - 4..])?;
-        return Ok(TSProgramMapSection {
+        let crc_32 = util::read_u32(&bytes[3 + section_length - 4..]);
+        Ok(TSProgramMapSection {
             table_id,
             section_syntax_indicator,
             program_number,
@@ -109,6 +98,6 @@ impl<'a> TSProgramMapSection<'a> {
             descriptors,
             stream_info,
             crc_32,
-        });
+        })
     }
 }
@@ -1,14 +1,14 @@
-use anyhow::{bail, Result};
+use crate::psi::{Error, Result};
 
-use crate::psi::Descriptor;
+use crate::psi::Descriptors;
 use crate::util;
 
 pub const SDT_PID: u16 = 0x0011;
 pub const SELF_STREAM_TABLE_ID: u8 = 0x42;
-#[allow(dead_code)]
 pub const OTHER_STREAM_TABLE_ID: u8 = 0x46;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct Service<'a> {
     pub service_id: u16,
     pub eit_user_defined_flags: u8,
@@ -16,7 +16,7 @@ pub struct Service<'a> {
     pub eit_present_following_flag: u8,
     pub running_status: u8,
     pub free_ca_mode: u8,
-    pub descriptors: Vec<Descriptor<'a>>,
+    pub descriptors: Descriptors<'a>,
 }
 
 impl Service<'_> {
@@ -29,15 +29,8 @@ impl Service<'_> {
         let running_status = bytes[3] >> 5;
         let free_ca_mode = (bytes[3] >> 4) & 0x1;
         let descriptors_loop_length = (usize::from(bytes[3] & 0xf) << 8) | usize::from(bytes[4]);
-        let mut descriptors = Vec::new();
-        {
-            let mut bytes = &bytes[5..5 + descriptors_loop_length];
-            while bytes.len() > 0 {
-                let (descriptor, n) = Descriptor::parse(bytes)?;
-                descriptors.push(descriptor);
-                bytes = &bytes[n..];
-            }
-        }
+        check_len!(bytes.len(), 5 + descriptors_loop_length);
+        let descriptors = Descriptors::parse(&bytes[5..5 + descriptors_loop_length])?;
         Ok((
             Service {
                 service_id,
@@ -54,6 +47,7 @@ impl Service<'_> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
 pub struct ServiceDescriptionSection<'a> {
     pub table_id: u8,
     pub section_syntax_indicator: u8,
@@ -66,6 +60,7 @@ pub struct ServiceDescriptionSection<'a> {
     pub services: Vec<Service<'a>>,
     pub crc32: u32,
 
+    #[cfg_attr(feature = "serde", serde(skip))]
     _raw_bytes: &'a [u8],
 }
 
@@ -84,13 +79,13 @@ impl ServiceDescriptionSection<'_> {
         let mut services = Vec::new();
         {
             let mut bytes = &bytes[11..3 + section_length - 4];
-            while bytes.len() > 0 {
+            while !bytes.is_empty() {
                 let (service, n) = Service::parse(bytes)?;
                 services.push(service);
                 bytes = &bytes[n..];
             }
         }
-        let crc32 = util::read_u32(&bytes[3 + section_length - 4..])?;
+        let crc32 = util::read_u32(&bytes[3 + section_length - 4..]);
         Ok(ServiceDescriptionSection {
             table_id,
             section_syntax_indicator,
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use log::debug;
+use tokio_stream::Stream;
+
+use crate::psi::{Buffer, BufferError};
+use crate::ts;
+
+// EIT sections are grouped into segments of 8 sections, where only the
+// first sections up to segment_last_section_number are sent.
+const SECTIONS_PER_SEGMENT: usize = 8;
+
+fn is_eit(table_id: u8) -> bool {
+    (0x4e..=0x6f).contains(&table_id)
+}
+
+/// A table is identified by table_id and table_id_extension, which is the
+/// program_number of PMT or the service_id of EIT for example.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TableKey {
+    pub table_id: u8,
+    pub table_id_extension: u16,
+}
+
+/// All sections of a version of a table.
+#[derive(Clone, Debug)]
+pub struct Table {
+    pub key: TableKey,
+    pub version_number: u8,
+    /// Sections in the order of section_number.
+    pub sections: Vec<Bytes>,
+}
+
+// sections of a version being collected.
+struct Pending {
+    version_number: u8,
+    last_section_number: u8,
+    sections: Vec<Option<Bytes>>,
+    // segment_last_section_number of each EIT segment seen.
+    segment_last: Vec<Option<u8>>,
+}
+
+impl Pending {
+    fn new(version_number: u8, last_section_number: u8) -> Pending {
+        let n = usize::from(last_section_number) + 1;
+        Pending {
+            version_number,
+            last_section_number,
+            sections: vec![None; n],
+            segment_last: vec![None; n.div_ceil(SECTIONS_PER_SEGMENT)],
+        }
+    }
+
+    fn is_complete(&self, eit: bool) -> bool {
+        if !eit {
+            return self.sections.iter().all(|s| s.is_some());
+        }
+        self.segment_last
+            .iter()
+            .enumerate()
+            .all(|(segment, last)| match last {
+                Some(last) => {
+                    let first = segment * SECTIONS_PER_SEGMENT;
+                    let last = usize::from(*last).min(self.sections.len() - 1);
+                    first > last || self.sections[first..=last].iter().all(|s| s.is_some())
+                }
+                None => false,
+            })
+    }
+
+    fn into_sections(self) -> Vec<Bytes> {
+        self.sections.into_iter().flatten().collect()
+    }
+}
+
+/// Assembles tables out of sections on a PID, and yields each version of
+/// a table once all of its sections are received.
+///
+/// Sections whose current_next_indicator is 0 are not applicable yet and
+/// are ignored. Sections of the short form, such as TDT, have no version
+/// and are yielded each time as a table of a section.
+pub struct TableTracker<S> {
+    buffer: Buffer<S>,
+    pending: HashMap<TableKey, Pending>,
+    versions: HashMap<TableKey, u8>,
+}
+
+impl<S> TableTracker<S> {
+    pub fn new(stream: S) -> Self {
+        TableTracker {
+            buffer: Buffer::new(stream).verify_crc(),
+            pending: HashMap::new(),
+            versions: HashMap::new(),
+        }
+    }
+
+    /// Returns the current version of the table if it has been complete.
+    pub fn version(&self, key: &TableKey) -> Option<u8> {
+        self.versions.get(key).copied()
+    }
+
+    /// Returns the number of sections dropped by CRC_32 mismatch.
+    pub fn crc_errors(&self) -> usize {
+        self.buffer.crc_errors()
+    }
+
+    // takes a section, and returns the table if it is newly complete.
+    fn update(&mut self, section: Bytes) -> Option<Table> {
+        let table_id = section[0];
+        let section_syntax_indicator = section[1] & 0x80 > 0;
+        if !section_syntax_indicator {
+            return Some(Table {
+                key: TableKey {
+                    table_id,
+                    table_id_extension: 0,
+                },
+                version_number: 0,
+                sections: vec![section],
+            });
+        }
+        // the long form header and CRC_32.
+        if section.len() < 12 {
+            debug!("too short section of table_id {:#x}", table_id);
+            return None;
+        }
+        let key = TableKey {
+            table_id,
+            table_id_extension: (u16::from(section[3]) << 8) | u16::from(section[4]),
+        };
+        let version_number = (section[5] >> 1) & 0x1f;
+        let current_next_indicator = section[5] & 0x1;
+        let section_number = section[6];
+        let last_section_number = section[7];
+        if current_next_indicator == 0
+            || section_number > last_section_number
+            || self.versions.get(&key) == Some(&version_number)
+        {
+            return None;
+        }
+        let pending = self
+            .pending
+            .entry(key)
+            .and_modify(|p| {
+                if p.version_number != version_number
+                    || p.last_section_number != last_section_number
+                {
+                    *p = Pending::new(version_number, last_section_number);
+                }
+            })
+            .or_insert_with(|| Pending::new(version_number, last_section_number));
+        if is_eit(table_id) && section.len() >= 14 + 4 {
+            let segment = usize::from(section_number) / SECTIONS_PER_SEGMENT;
+            pending.segment_last[segment] = Some(section[12]);
+        }
+        pending.sections[usize::from(section_number)] = Some(section);
+        if !pending.is_complete(is_eit(table_id)) {
+            return None;
+        }
+        let pending = self.pending.remove(&key)?;
+        self.versions.insert(key, version_number);
+        Some(Table {
+            key,
+            version_number,
+            sections: pending.into_sections(),
+        })
+    }
+}
+
+impl<S> Stream for TableTracker<S>
+where
+    S: Stream<Item = ts::TSPacket> + Unpin,
+{
+    type Item = Result<Table, BufferError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let section = match Pin::new(&mut self.buffer).poll_next(cx) {
+                Poll::Ready(Some(Ok(section))) => section,
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            };
+            if let Some(table) = self.update(section) {
+                return Poll::Ready(Some(Ok(table)));
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use chrono;
+
+use self::chrono::offset::FixedOffset;
+use self::chrono::DateTime;
+
+use crate::psi::{Error, Result};
+
+use crate::psi::descriptor::LocalTimeOffset;
+use crate::psi::Event;
+use crate::psi::{Descriptor, Descriptors};
+
+pub const TOT_PID: u16 = 0x0014;
+pub const TIME_DATE_TABLE_ID: u8 = 0x70;
+pub const TIME_OFFSET_TABLE_ID: u8 = 0x73;
+
+/// Time date section or time offset section, which share the leading
+/// JST_time field.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+pub struct TimeOffsetSection<'a> {
+    pub table_id: u8,
+    pub jst_time: DateTime<FixedOffset>,
+    pub descriptors: Descriptors<'a>,
+}
+
+impl<'a> TimeOffsetSection<'a> {
+    pub fn parse(bytes: &[u8]) -> Result<TimeOffsetSection<'_>> {
+        check_len!(bytes.len(), 8);
+        let table_id = bytes[0];
+        if table_id != TIME_DATE_TABLE_ID && table_id != TIME_OFFSET_TABLE_ID {
+            return Err(Error::InvalidTableId(table_id));
+        }
+        let section_length = (usize::from(bytes[1] & 0xf) << 8) | usize::from(bytes[2]);
+        check_len!(bytes.len(), 3 + section_length);
+        let jst_time = match Event::parse_datetime(&bytes[3..8])? {
+            Some(jst_time) => jst_time,
+            None => return Err(Error::InvalidLength),
+        };
+        let mut descriptors = Descriptors::parse(&[])?;
+        if table_id == TIME_OFFSET_TABLE_ID {
+            check_len!(bytes.len(), 10);
+            let descriptors_loop_length =
+                (usize::from(bytes[8] & 0xf) << 8) | usize::from(bytes[9]);
+            check_len!(bytes.len(), 10 + descriptors_loop_length);
+            descriptors = Descriptors::parse(&bytes[10..10 + descriptors_loop_length])?;
+        }
+        Ok(TimeOffsetSection {
+            table_id,
+            jst_time,
+            descriptors,
+        })
+    }
+
+    /// Returns the first local time offset in the descriptors, which covers
+    /// the whole country in Japan.
+    pub fn local_time_offset(&self) -> Option<LocalTimeOffset> {
+        self.descriptors.iter().find_map(|desc| match desc {
+            Descriptor::LocalTimeOffsetDescriptor(d) => d.offsets.into_iter().next(),
+            _ => None,
+        })
+    }
+}
@@ -52,7 +52,7 @@ where
                 self.items.push_back(item.clone());
                 Poll::Ready(Some(item))
             }
-            r @ _ => r,
+            r => r,
         }
     }
 }
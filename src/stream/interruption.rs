@@ -0,0 +1,45 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tokio_stream::Stream;
+
+/// A stream which ends early once the interruption future completes.
+pub struct Interruptible<S, F> {
+    s: S,
+    interruption: Option<F>,
+}
+
+pub fn interruptible<S: Stream, F: Future>(s: S, interruption: F) -> Interruptible<S, F> {
+    Interruptible {
+        s,
+        interruption: Some(interruption),
+    }
+}
+
+impl<S, F> Interruptible<S, F> {
+    pub fn is_interrupted(&self) -> bool {
+        self.interruption.is_none()
+    }
+}
+
+impl<S, F> Stream for Interruptible<S, F>
+where
+    S: Stream + Unpin,
+    F: Future + Unpin,
+{
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.interruption {
+            Some(ref mut interruption) => {
+                if Pin::new(interruption).poll(cx).is_ready() {
+                    self.interruption = None;
+                    return Poll::Ready(None);
+                }
+            }
+            None => return Poll::Ready(None),
+        }
+        Pin::new(&mut self.s).poll_next(cx)
+    }
+}
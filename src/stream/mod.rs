@@ -1,2 +1,5 @@
 mod cue;
 pub use cue::*;
+
+mod interruption;
+pub use interruption::*;
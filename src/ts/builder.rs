@@ -0,0 +1,171 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use super::packet::SYNC_BYTE;
+use super::{AdaptationField, Error, Result, TSPacket, TS_PACKET_LENGTH};
+
+const HEADER_LENGTH: usize = 4;
+const STUFFING_BYTE: u8 = 0xff;
+const PCR_FLAG: u8 = 0x10;
+
+/// Constructs a TS packet, filling the unused bytes with stuffing in the
+/// adaptation field.
+#[derive(Debug, Clone)]
+pub struct TSPacketBuilder {
+    transport_error_indicator: bool,
+    payload_unit_start_indicator: bool,
+    transport_priority: bool,
+    pid: u16,
+    transport_scrambling_control: u8,
+    continuity_counter: u8,
+    adaptation_field: Option<Bytes>,
+    payload: Option<Bytes>,
+}
+
+impl TSPacketBuilder {
+    pub fn new(pid: u16) -> TSPacketBuilder {
+        TSPacketBuilder {
+            transport_error_indicator: false,
+            payload_unit_start_indicator: false,
+            transport_priority: false,
+            pid,
+            transport_scrambling_control: 0,
+            continuity_counter: 0,
+            adaptation_field: None,
+            payload: None,
+        }
+    }
+
+    /// Starts from the fields of an existing packet.
+    pub fn from_packet(packet: &TSPacket) -> TSPacketBuilder {
+        TSPacketBuilder {
+            transport_error_indicator: packet.transport_error_indicator,
+            payload_unit_start_indicator: packet.payload_unit_start_indicator,
+            transport_priority: packet.transport_priority,
+            pid: packet.pid,
+            transport_scrambling_control: packet.transport_scrambling_control,
+            continuity_counter: packet.continuity_counter,
+            adaptation_field: packet.adaptation_field.as_ref().map(|af| af.raw.clone()),
+            payload: packet.data.clone(),
+        }
+    }
+
+    pub fn transport_error_indicator(mut self, v: bool) -> Self {
+        self.transport_error_indicator = v;
+        self
+    }
+
+    pub fn payload_unit_start_indicator(mut self, v: bool) -> Self {
+        self.payload_unit_start_indicator = v;
+        self
+    }
+
+    pub fn transport_priority(mut self, v: bool) -> Self {
+        self.transport_priority = v;
+        self
+    }
+
+    pub fn pid(mut self, pid: u16) -> Self {
+        self.pid = pid;
+        self
+    }
+
+    pub fn transport_scrambling_control(mut self, v: u8) -> Self {
+        self.transport_scrambling_control = v;
+        self
+    }
+
+    pub fn continuity_counter(mut self, v: u8) -> Self {
+        self.continuity_counter = v;
+        self
+    }
+
+    pub fn adaptation_field(mut self, af: Option<AdaptationField>) -> Self {
+        self.adaptation_field = af.map(|af| af.raw);
+        self
+    }
+
+    /// Replaces the adaptation field with one which carries only the PCR in
+    /// 27MHz units.
+    pub fn pcr(mut self, pcr: u64) -> Self {
+        let base = pcr / 300;
+        let extension = pcr % 300;
+        let mut af = BytesMut::with_capacity(8);
+        af.put_u8(7);
+        af.put_u8(PCR_FLAG);
+        af.put_u8((base >> 25) as u8);
+        af.put_u8((base >> 17) as u8);
+        af.put_u8((base >> 9) as u8);
+        af.put_u8((base >> 1) as u8);
+        af.put_u8(((base & 0x1) as u8) << 7 | 0x7e | (extension >> 8) as u8);
+        af.put_u8(extension as u8);
+        self.adaptation_field = Some(af.freeze());
+        self
+    }
+
+    pub fn payload(mut self, payload: Option<Bytes>) -> Self {
+        self.payload = payload;
+        self
+    }
+
+    /// Returns the number of payload bytes which fit in the packet with the
+    /// current adaptation field.
+    pub fn payload_capacity(&self) -> usize {
+        let af_len = self.adaptation_field.as_ref().map_or(0, |af| af.len());
+        TS_PACKET_LENGTH.saturating_sub(HEADER_LENGTH + af_len)
+    }
+
+    /// Serializes the packet into 188 bytes.
+    pub fn build(self) -> Result<TSPacket> {
+        let payload_len = self.payload.as_ref().map_or(0, |p| p.len());
+        let capacity = self.payload_capacity();
+        if payload_len > capacity {
+            return Err(Error::PayloadTooLong(payload_len));
+        }
+        let mut stuffing = capacity - payload_len;
+        let mut af = match self.adaptation_field {
+            Some(ref af) => Some(BytesMut::from(&af[..])),
+            None if stuffing > 0 => {
+                // the length byte is counted in the stuffing.
+                stuffing -= 1;
+                Some(BytesMut::from(&[0u8][..]))
+            }
+            None => None,
+        };
+        if let Some(af) = af.as_mut() {
+            if stuffing > 0 && af.len() == 1 {
+                // flags are required before stuffing bytes.
+                af.put_u8(0);
+                stuffing -= 1;
+            }
+            af.resize(af.len() + stuffing, STUFFING_BYTE);
+            af[0] = (af.len() - 1) as u8;
+        }
+
+        let adaptation_field_control = match (af.is_some(), self.payload.is_some()) {
+            (false, _) => 0b01,
+            (true, false) => 0b10,
+            (true, true) => 0b11,
+        };
+        let mut out = BytesMut::with_capacity(TS_PACKET_LENGTH);
+        out.put_u8(SYNC_BYTE);
+        out.put_u8(
+            (u8::from(self.transport_error_indicator) << 7)
+                | (u8::from(self.payload_unit_start_indicator) << 6)
+                | (u8::from(self.transport_priority) << 5)
+                | ((self.pid >> 8) as u8 & 0x1f),
+        );
+        out.put_u8(self.pid as u8);
+        out.put_u8(
+            (self.transport_scrambling_control << 6)
+                | (adaptation_field_control << 4)
+                | (self.continuity_counter & 0xf),
+        );
+        if let Some(af) = af {
+            out.extend_from_slice(&af[..]);
+        }
+        if let Some(payload) = self.payload {
+            out.extend_from_slice(&payload[..]);
+        }
+        TSPacket::parse(out.freeze())
+    }
+}
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use tokio::sync::mpsc::{channel, Sender};
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::{Stream, StreamExt};
+
+use super::TSPacket;
+
+const DEFAULT_CAPACITY: usize = 1;
+
+/// Fans packets of a stream out to per-PID channels.
+///
+/// Register the PIDs of interest, spawn the consumers of the returned
+/// streams, then drive the demuxer with `run`. A consumer stops receiving
+/// packets by dropping its stream, and `run` returns once every consumer has
+/// done so or the input ends.
+pub struct Demuxer {
+    senders: HashMap<u16, Vec<Sender<TSPacket>>>,
+    capacity: usize,
+}
+
+impl Default for Demuxer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Demuxer {
+    pub fn new() -> Demuxer {
+        Demuxer::with_capacity(DEFAULT_CAPACITY)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Demuxer {
+        Demuxer {
+            senders: HashMap::new(),
+            capacity,
+        }
+    }
+
+    /// Returns a stream of the packets of `pid`. A PID may be registered
+    /// more than once, each stream gets every packet.
+    pub fn register(&mut self, pid: u16) -> ReceiverStream<TSPacket> {
+        let (tx, rx) = channel(self.capacity);
+        self.senders.entry(pid).or_default().push(tx);
+        ReceiverStream::new(rx)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.senders.is_empty()
+    }
+
+    pub async fn run<S: Stream<Item = TSPacket> + Unpin>(mut self, mut s: S) {
+        while !self.senders.is_empty() {
+            let packet = match s.next().await {
+                Some(packet) => packet,
+                None => break,
+            };
+            let pid = packet.pid;
+            let senders = match self.senders.get_mut(&pid) {
+                Some(senders) => senders,
+                None => continue,
+            };
+            if senders.len() == 1 {
+                if senders[0].send(packet).await.is_err() {
+                    self.senders.remove(&pid);
+                }
+                continue;
+            }
+            let mut alive = Vec::with_capacity(senders.len());
+            for tx in senders.drain(..) {
+                if tx.send(packet.clone()).await.is_ok() {
+                    alive.push(tx);
+                }
+            }
+            if alive.is_empty() {
+                self.senders.remove(&pid);
+            } else {
+                *senders = alive;
+            }
+        }
+    }
+}
@@ -1,9 +1,37 @@
 mod packet;
 pub use self::packet::*;
 
+mod builder;
+pub use self::builder::*;
+
+mod demuxer;
+pub use self::demuxer::*;
+
+mod restamp;
+pub use self::restamp::*;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("too short {len}, expect {expr}({expected})")]
+    TooShort {
+        len: usize,
+        expected: usize,
+        expr: &'static str,
+    },
+    #[error("invalid sync byte 0x{0:02x}")]
+    InvalidSyncByte(u8),
+    #[error("payload too long {0}")]
+    PayloadTooLong(usize),
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
 pub const PAT_PID: u16 = 0;
+pub const NULL_PID: u16 = 0x1fff;
+pub const PCR_HZ: u64 = 27_000_000;
 pub const EIT_PIDS: [u16; 3] = [0x0012, 0x0026, 0x0027];
-#[allow(dead_code)]
 pub const CAT_PID: u16 = 1;
 #[allow(dead_code)]
 pub const TSDT_PID: u16 = 2;
@@ -1,9 +1,13 @@
-use anyhow::{bail, Error, Result};
-use bytes::{Bytes, BytesMut};
+use bytes::{Buf, Bytes, BytesMut};
+use log::warn;
 use tokio_util::codec::Decoder;
 
+use super::{Error, Result};
+
 pub const TS_PACKET_LENGTH: usize = 188;
-const SYNC_BYTE: u8 = 0x47;
+pub(crate) const SYNC_BYTE: u8 = 0x47;
+// the number of sync bytes at 188 bytes intervals to accept a position.
+const RESYNC_PACKETS: usize = 3;
 
 #[derive(Debug, Clone)]
 pub struct AdaptationField {
@@ -30,26 +34,138 @@ impl TSPacket {
     }
 }
 
-pub struct TSPacketDecoder {}
+/// Decodes TS packets, skipping broken bytes until the sync byte appears
+/// periodically again.
+pub struct TSPacketDecoder {
+    synced: bool,
+    skipped: usize,
+    resyncs: usize,
+}
+
+impl Default for TSPacketDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl TSPacketDecoder {
     pub fn new() -> Self {
-        TSPacketDecoder {}
+        TSPacketDecoder {
+            synced: false,
+            skipped: 0,
+            resyncs: 0,
+        }
     }
-}
 
-impl Decoder for TSPacketDecoder {
-    type Item = TSPacket;
-    type Error = Error;
+    /// Returns the number of times the decoder skipped bytes to find the
+    /// sync byte.
+    pub fn resyncs(&self) -> usize {
+        self.resyncs
+    }
 
-    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>> {
+    fn skip<B: Buf>(&mut self, src: &mut B, n: usize) {
+        src.advance(n);
+        self.skipped += n;
+    }
+
+    // finds a sync byte followed by sync bytes of next packets, or returns
+    // the number of bytes which can be skipped.
+    // at eof, missing following packets are not required.
+    fn find_sync(src: &[u8], eof: bool) -> std::result::Result<usize, usize> {
+        let mut i = 0;
+        while i < src.len() {
+            if src[i] != SYNC_BYTE {
+                i += 1;
+                continue;
+            }
+            let mut matched = true;
+            for k in 1..RESYNC_PACKETS {
+                let pos = i + k * TS_PACKET_LENGTH;
+                if pos >= src.len() {
+                    if eof {
+                        break;
+                    }
+                    // wait for more bytes.
+                    return Err(i);
+                }
+                if src[pos] != SYNC_BYTE {
+                    matched = false;
+                    break;
+                }
+            }
+            if matched {
+                return Ok(i);
+            }
+            i += 1;
+        }
+        Err(src.len())
+    }
+
+    fn resync<B: Buf + AsRef<[u8]>>(&mut self, src: &mut B, eof: bool) -> bool {
+        match Self::find_sync(src.as_ref(), eof) {
+            Ok(i) => {
+                self.skip(src, i);
+                if self.skipped > 0 {
+                    self.resyncs += 1;
+                    warn!("resynchronized after skipping {} bytes", self.skipped);
+                    self.skipped = 0;
+                }
+                true
+            }
+            Err(n) => {
+                self.skip(src, n);
+                false
+            }
+        }
+    }
+
+    fn decode_packet(&mut self, src: &mut BytesMut, eof: bool) -> Result<Option<TSPacket>> {
+        if self.synced && !src.is_empty() && src[0] != SYNC_BYTE {
+            self.synced = false;
+        }
+        if !self.synced {
+            if !self.resync(src, eof) {
+                return Ok(None);
+            }
+            self.synced = true;
+        }
         if src.len() < TS_PACKET_LENGTH {
             return Ok(None);
         }
+        TSPacket::parse(src.split_to(TS_PACKET_LENGTH).freeze()).map(Some)
+    }
+
+    /// Decodes the next packet from bytes in memory, such as a mapped file,
+    /// without copying them.
+    pub fn decode_bytes(&mut self, src: &mut Bytes) -> Option<Result<TSPacket>> {
+        if self.synced && !src.is_empty() && src[0] != SYNC_BYTE {
+            self.synced = false;
+        }
+        if !self.synced {
+            if !self.resync(src, true) {
+                return None;
+            }
+            self.synced = true;
+        }
+        if src.len() < TS_PACKET_LENGTH {
+            if !src.is_empty() {
+                warn!("drop {} trailing bytes", src.len());
+                let n = src.len();
+                self.skip(src, n);
+            }
+            return None;
+        }
+        Some(TSPacket::parse(src.split_to(TS_PACKET_LENGTH)))
+    }
+}
+
+impl TSPacket {
+    /// Parses a packet from 188 bytes which start with the sync byte.
+    pub fn parse(src: Bytes) -> Result<TSPacket> {
+        check_len!(src.len(), TS_PACKET_LENGTH);
         if src[0] != SYNC_BYTE {
-            bail!("sync byte does not {}", SYNC_BYTE);
+            return Err(Error::InvalidSyncByte(src[0]));
         }
-        let src = src.split_to(TS_PACKET_LENGTH).freeze();
         let transport_error_indicator = src[1] & 0x80 > 0;
         let payload_unit_start_indicator = src[1] & 0x40 > 0;
         let transport_priority = src[1] & 0x20 > 0;
@@ -59,7 +175,7 @@ impl Decoder for TSPacketDecoder {
         let continuity_counter = src[3] & 0xf;
         // FIXME: return error.
         if transport_error_indicator {
-            return Ok(Some(TSPacket {
+            return Ok(TSPacket {
                 transport_error_indicator,
                 payload_unit_start_indicator,
                 transport_priority,
@@ -70,7 +186,7 @@ impl Decoder for TSPacketDecoder {
                 adaptation_field: None,
                 data: None,
                 raw: src,
-            }));
+            });
         }
         let (adaptation_field, adaptation_field_length) = match adaptation_field_control {
             0b10 | 0b11 => {
@@ -83,7 +199,7 @@ impl Decoder for TSPacketDecoder {
             0b01 | 0b11 => Some(src.clone().split_off(4 + adaptation_field_length)),
             _ => None,
         };
-        Ok(Some(TSPacket {
+        Ok(TSPacket {
             transport_error_indicator,
             payload_unit_start_indicator,
             transport_priority,
@@ -94,11 +210,54 @@ impl Decoder for TSPacketDecoder {
             adaptation_field,
             data,
             raw: src,
-        }))
+        })
+    }
+}
+
+impl Decoder for TSPacketDecoder {
+    type Item = TSPacket;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>> {
+        self.decode_packet(src, false)
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>> {
+        if let Some(packet) = self.decode_packet(src, true)? {
+            return Ok(Some(packet));
+        }
+        if !src.is_empty() {
+            warn!("drop {} trailing bytes", src.len());
+            let n = src.len();
+            self.skip(src, n);
+        }
+        Ok(None)
     }
 }
 
 impl AdaptationField {
+    /// Returns whether the continuity counter or the PCR is discontinuous at
+    /// the packet.
+    pub fn discontinuity_indicator(&self) -> bool {
+        self.raw.len() >= 2 && self.raw[1] & 0x80 != 0
+    }
+
+    /// Returns the program clock reference in 27MHz units.
+    pub fn pcr(&self) -> Option<u64> {
+        // raw starts with adaptation_field_length.
+        if self.raw.len() < 8 || self.raw[1] & 0x10 == 0 {
+            return None;
+        }
+        let b = &self.raw[2..8];
+        let base = (u64::from(b[0]) << 25)
+            | (u64::from(b[1]) << 17)
+            | (u64::from(b[2]) << 9)
+            | (u64::from(b[3]) << 1)
+            | (u64::from(b[4]) >> 7);
+        let extension = (u64::from(b[4] & 0x1) << 8) | u64::from(b[5]);
+        Some(base * 300 + extension)
+    }
+
     fn decode(src: &mut Bytes) -> Result<(AdaptationField, usize)> {
         check_len!(src.len(), 1);
         let adaptation_field_length = usize::from(src[0]);
@@ -0,0 +1,190 @@
+use bytes::{Bytes, BytesMut};
+use log::{info, warn};
+
+use super::{TSPacket, PCR_HZ};
+use crate::pes;
+
+// PCR wraps around at 2^33 * 300, and PTS/DTS at 2^33.
+const PCR_MAX: u64 = (1 << 33) * 300;
+const PTS_MAX: u64 = 1 << 33;
+// PCR intervals longer than this, or going backwards, are treated as
+// discontinuities.
+const MAX_PCR_INTERVAL: u64 = PCR_HZ;
+// the interval assumed across a discontinuity before any is measured.
+const DEFAULT_PCR_INTERVAL: u64 = PCR_HZ / 10;
+// packets held until the first PCR of a segment gives its offset.
+const MAX_PENDING_PACKETS: usize = 16 * 1024;
+const HEADER_LENGTH: usize = 4;
+const PTS_OFFSET: usize = 9;
+const DTS_OFFSET: usize = 14;
+
+/// Rewrites PCR in adaptation fields and PTS/DTS in PES headers so that the
+/// output timeline starts at a base and runs on continuously across joins
+/// and discontinuities of the input.
+///
+/// The timeline follows the PCR of the first PID carrying one; the same
+/// offset is applied to every PID. Packets before the first PCR of a segment
+/// are held back until the offset is known.
+pub struct Restamper {
+    base: u64,
+    pcr_pid: Option<u16>,
+    // added to input PCR, modulo PCR_MAX. None until the first PCR of the
+    // segment.
+    offset: Option<u64>,
+    // the latest PCR of pcr_pid, in input and output.
+    last: Option<(u64, u64)>,
+    interval: u64,
+    pending: Vec<TSPacket>,
+}
+
+impl Restamper {
+    /// Creates a restamper whose output starts at the PCR `base` in 27MHz
+    /// units.
+    pub fn new(base: u64) -> Restamper {
+        Restamper {
+            base: base % PCR_MAX,
+            pcr_pid: None,
+            offset: None,
+            last: None,
+            interval: DEFAULT_PCR_INTERVAL,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Marks the start of another input. Its first PCR continues the output
+    /// timeline by one PCR interval.
+    pub fn join(&mut self) {
+        self.pcr_pid = None;
+        self.offset = None;
+    }
+
+    /// Returns the restamped bytes of the packets which are ready.
+    pub fn push(&mut self, packet: TSPacket) -> Vec<Bytes> {
+        let pcr = packet.adaptation_field.as_ref().and_then(|af| af.pcr());
+        if let Some(pcr) = pcr {
+            if self.pcr_pid.is_none() {
+                self.pcr_pid = Some(packet.pid);
+            }
+            if self.pcr_pid == Some(packet.pid) {
+                self.update(pcr);
+            }
+        }
+        if self.offset.is_none() {
+            self.pending.push(packet);
+            if self.pending.len() < MAX_PENDING_PACKETS {
+                return Vec::new();
+            }
+            warn!(
+                "no pcr in {} packets, pass them through",
+                self.pending.len()
+            );
+            return self.pending.drain(..).map(|p| p.into_raw()).collect();
+        }
+        let mut out: Vec<Bytes> = std::mem::take(&mut self.pending)
+            .into_iter()
+            .map(|p| self.rewrite(p))
+            .collect();
+        out.push(self.rewrite(packet));
+        out
+    }
+
+    /// Returns the packets still held, passed through if no PCR came.
+    pub fn finish(&mut self) -> Vec<Bytes> {
+        if !self.pending.is_empty() {
+            warn!("no pcr in the last {} packets", self.pending.len());
+        }
+        self.pending.drain(..).map(|p| p.into_raw()).collect()
+    }
+
+    fn update(&mut self, pcr: u64) {
+        let offset = match (self.offset, self.last) {
+            (Some(offset), Some((input, _))) => {
+                let interval = (pcr + PCR_MAX - input) % PCR_MAX;
+                if interval <= MAX_PCR_INTERVAL {
+                    self.interval = interval;
+                    offset
+                } else {
+                    info!("pcr discontinuity from {} to {}", input, pcr);
+                    self.continue_at(pcr)
+                }
+            }
+            _ => self.continue_at(pcr),
+        };
+        self.offset = Some(offset);
+        self.last = Some((pcr, (pcr + offset) % PCR_MAX));
+    }
+
+    // returns the offset which maps pcr to one interval after the latest
+    // output, or to the base at the start. The offset is rounded to whole
+    // 90kHz ticks, so that PTS and DTS shift by exactly the PCR base does.
+    fn continue_at(&self, pcr: u64) -> u64 {
+        let target = match self.last {
+            Some((_, output)) => (output + self.interval) % PCR_MAX,
+            None => self.base,
+        };
+        let offset = (target + PCR_MAX - pcr) % PCR_MAX;
+        ((offset + 150) / 300 * 300) % PCR_MAX
+    }
+
+    fn rewrite(&self, packet: TSPacket) -> Bytes {
+        let offset = match self.offset {
+            Some(offset) => offset,
+            None => return packet.into_raw(),
+        };
+        let af_length = packet
+            .adaptation_field
+            .as_ref()
+            .map_or(0, |af| af.raw.len());
+        let pcr = packet.adaptation_field.as_ref().and_then(|af| af.pcr());
+        // the PES header is unreadable if scrambled.
+        let timestamps = match packet.data.as_ref() {
+            Some(data)
+                if packet.payload_unit_start_indicator
+                    && packet.transport_scrambling_control == 0 =>
+            {
+                pes::Timestamps::parse(data).ok()
+            }
+            _ => None,
+        };
+        if pcr.is_none() && timestamps.is_none_or(|t| t.pts.is_none()) {
+            return packet.into_raw();
+        }
+        let mut raw = BytesMut::from(&packet.into_raw()[..]);
+        if let Some(pcr) = pcr {
+            write_pcr(&mut raw[HEADER_LENGTH + 2..], (pcr + offset) % PCR_MAX);
+        }
+        if let Some(timestamps) = timestamps {
+            // the offset is a multiple of 300.
+            let shift = offset / 300;
+            let payload = HEADER_LENGTH + af_length;
+            if let Some(pts) = timestamps.pts {
+                write_timestamp(&mut raw[payload + PTS_OFFSET..], (pts + shift) % PTS_MAX);
+            }
+            if let Some(dts) = timestamps.dts {
+                write_timestamp(&mut raw[payload + DTS_OFFSET..], (dts + shift) % PTS_MAX);
+            }
+        }
+        raw.freeze()
+    }
+}
+
+// writes the PCR base and extension, keeping the reserved bits.
+fn write_pcr(bytes: &mut [u8], pcr: u64) {
+    let base = pcr / 300;
+    let extension = pcr % 300;
+    bytes[0] = (base >> 25) as u8;
+    bytes[1] = (base >> 17) as u8;
+    bytes[2] = (base >> 9) as u8;
+    bytes[3] = (base >> 1) as u8;
+    bytes[4] = ((base & 0x1) as u8) << 7 | (bytes[4] & 0x7e) | (extension >> 8) as u8;
+    bytes[5] = extension as u8;
+}
+
+// writes a PTS or DTS, keeping the 4 bits prefix and the marker bits.
+fn write_timestamp(bytes: &mut [u8], ts: u64) {
+    bytes[0] = (bytes[0] & 0xf0) | ((ts >> 29) & 0xe) as u8 | 0x1;
+    bytes[1] = (ts >> 22) as u8;
+    bytes[2] = ((ts >> 14) & 0xfe) as u8 | 0x1;
+    bytes[3] = (ts >> 7) as u8;
+    bytes[4] = ((ts << 1) & 0xfe) as u8 | 0x1;
+}
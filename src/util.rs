@@ -1,26 +1,35 @@
-use anyhow::{bail, Result};
+#[cfg(feature = "serde")]
+use chrono::Duration;
 
+// expects `Error::TooShort` of the calling module to be in scope.
 macro_rules! check_len {
     ($b:expr, $l:expr) => {
         if $b < $l {
-            bail!(
-                "{}: too short {}({}), expect {}({})",
-                line!(),
-                stringify!($b),
-                $b,
-                stringify!($l),
-                $l
-            );
+            return Err(Error::TooShort {
+                len: $b,
+                expected: $l,
+                expr: stringify!($l),
+            }
+            .into());
         }
     };
 }
 
-pub fn read_u32(bytes: &[u8]) -> Result<u32> {
-    if bytes.len() < 4 {
-        bail!("too short {}", bytes.len());
-    }
-    return Ok((u32::from(bytes[0]) << 24)
+// the caller must ensure bytes has at least 4 bytes.
+pub fn read_u32(bytes: &[u8]) -> u32 {
+    (u32::from(bytes[0]) << 24)
         | (u32::from(bytes[1]) << 16)
         | (u32::from(bytes[2]) << 8)
-        | u32::from(bytes[3]));
+        | u32::from(bytes[3])
+}
+
+#[cfg(feature = "serde")]
+pub fn serialize_duration_seconds<S: serde::Serializer>(
+    duration: &Option<Duration>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    match duration {
+        Some(d) => serializer.serialize_some(&d.num_seconds()),
+        None => serializer.serialize_none(),
+    }
 }
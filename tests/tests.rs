@@ -0,0 +1,150 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use tstools::arib::string::AribDecoder;
+use tstools::crc32::crc32;
+use tstools::psi;
+use tstools::ts;
+
+fn psi_packet(cc: u8, pusi: bool, payload: &[u8]) -> ts::TSPacket {
+    ts::TSPacketBuilder::new(ts::PAT_PID)
+        .payload_unit_start_indicator(pusi)
+        .continuity_counter(cc)
+        .payload(Some(Bytes::copy_from_slice(payload)))
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_buffer_section_ending_before_pointer_field() {
+    let programs: Vec<(u16, u16)> = (1..=60).map(|i| (i, 0x100 + i)).collect();
+    let first = psi::SectionBuilder::pat(1, &programs).build().unwrap();
+    let second = psi::SectionBuilder::pat(1, &programs[..1])
+        .version_number(1)
+        .build()
+        .unwrap();
+    let (head, tail) = first.split_at(183);
+
+    let mut payload = BytesMut::new();
+    payload.put_u8(0);
+    payload.extend_from_slice(head);
+    let packet1 = psi_packet(0, true, &payload[..]);
+    let mut payload = BytesMut::new();
+    payload.put_u8(tail.len() as u8);
+    payload.extend_from_slice(tail);
+    payload.extend_from_slice(&second[..]);
+    let packet2 = psi_packet(1, true, &payload[..]);
+
+    let mut buffer = psi::Buffer::detached().verify_crc();
+    buffer.feed(packet1).unwrap();
+    assert_eq!(buffer.next_section(), None);
+    buffer.feed(packet2).unwrap();
+    assert_eq!(buffer.next_section(), Some(first));
+    assert_eq!(buffer.next_section(), Some(second));
+    assert_eq!(buffer.next_section(), None);
+    assert_eq!(buffer.crc_errors(), 0);
+}
+
+#[test]
+fn test_buffer_drops_tail_after_discontinuity() {
+    let programs: Vec<(u16, u16)> = (1..=60).map(|i| (i, 0x100 + i)).collect();
+    let first = psi::SectionBuilder::pat(1, &programs).build().unwrap();
+    let second = psi::SectionBuilder::pat(1, &programs[..1]).build().unwrap();
+    let (head, tail) = first.split_at(183);
+
+    let mut payload = BytesMut::new();
+    payload.put_u8(0);
+    payload.extend_from_slice(head);
+    let packet1 = psi_packet(0, true, &payload[..]);
+    let mut payload = BytesMut::new();
+    payload.put_u8(tail.len() as u8);
+    payload.extend_from_slice(tail);
+    payload.extend_from_slice(&second[..]);
+    // a packet between them is lost.
+    let packet2 = psi_packet(2, true, &payload[..]);
+
+    let mut buffer = psi::Buffer::detached().verify_crc();
+    buffer.feed(packet1).unwrap();
+    buffer.feed(packet2).unwrap();
+    assert_eq!(buffer.next_section(), Some(second));
+    assert_eq!(buffer.next_section(), None);
+}
+
+#[test]
+fn test_rpc_repeats_to_end_of_line() {
+    // LS1, "A", RPC 0, "B" in the 24 characters wide line of captions.
+    let bytes = [0x0e, 0x41, 0x98, 0x40, 0x42];
+    let text = AribDecoder::with_caption_initialization()
+        .decode(bytes.iter())
+        .unwrap();
+    assert_eq!(text, format!("A{}", "B".repeat(23)));
+
+    // MSZ halves the characters.
+    let bytes = [0x89, 0x0e, 0x41, 0x98, 0x40, 0x42];
+    let text = AribDecoder::with_caption_initialization()
+        .decode(bytes.iter())
+        .unwrap();
+    assert_eq!(text, format!("A{}", "B".repeat(47)));
+
+    let bytes = [0x0e, 0x98, 0x43, 0x42, 0x41];
+    let text = AribDecoder::with_caption_initialization()
+        .decode(bytes.iter())
+        .unwrap();
+    assert_eq!(text, "BBBA");
+}
+
+// CRC-32/MPEG-2 a bit at a time.
+fn bitwise_crc32(data: &[u8]) -> u32 {
+    let mut crc: u32 = 0xffffffff;
+    for b in data.iter() {
+        crc ^= u32::from(*b) << 24;
+        for _ in 0..8 {
+            crc = if crc & 0x80000000 != 0 {
+                (crc << 1) ^ 0x04c11db7
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+#[test]
+fn test_crc32() {
+    assert_eq!(crc32(b""), 0xffffffff);
+    assert_eq!(crc32(b"123456789"), 0x0376e6e7);
+    let data: Vec<u8> = (0..64u32).map(|i| (i * 37 + 11) as u8).collect();
+    for len in [1, 15, 16, 17, 31, 32, 33, 47, 48, 49, 64] {
+        let crc = crc32(&data[..len]);
+        assert_eq!(crc, bitwise_crc32(&data[..len]), "length {}", len);
+        // a section followed by its CRC_32 gives 0.
+        let mut section = data[..len].to_vec();
+        section.extend_from_slice(&crc.to_be_bytes());
+        assert_eq!(crc32(&section), 0, "length {}", len);
+    }
+}
+
+#[test]
+fn test_truncated_descriptors_are_unsupported() {
+    let loops: [&[u8]; 4] = [
+        // stream identifier without component_tag.
+        &[0x52, 0x00],
+        // short event without event_name_length.
+        &[0x4d, 0x03, b'j', b'p', b'n'],
+        // extended event whose item overruns length_of_items.
+        &[0x4e, 0x08, 0x00, b'j', b'p', b'n', 0x02, 0x05, 0x41, 0x00],
+        // extended event without text_length.
+        &[0x4e, 0x05, 0x00, b'j', b'p', b'n', 0x00],
+    ];
+    for bytes in loops {
+        let descriptors = psi::Descriptors::parse(bytes).unwrap();
+        let parsed: Vec<psi::Descriptor> = descriptors.iter().collect();
+        assert_eq!(parsed.len(), 1, "{:02x?}", bytes);
+        match &parsed[0] {
+            psi::Descriptor::Unsupported(d) => {
+                assert_eq!(d.descriptor_tag, bytes[0]);
+                assert_eq!(d.data, &bytes[2..]);
+            }
+            d => panic!("{:02x?} parsed as {:?}", bytes, d),
+        }
+    }
+}